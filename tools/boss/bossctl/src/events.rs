@@ -0,0 +1,171 @@
+//! `bossctl events` — read-only inspection of the engine event bus's
+//! durable journal (`<state_root>/event-bus/events-<date>.jsonl`), written
+//! only when the engine runs with `BOSS_ENABLE_DURABLE_EVENT_BUS`.
+//!
+//! File-scan only, like `dispatch tail`: works when the engine is wedged
+//! or stopped, which is exactly when post-incident forensics need it.
+//! `--after-cursor` shows what a durable subscriber would replay on the
+//! next engine start, for debugging a reconciler that did or did not act
+//! on a transition.
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use boss_engine::event_bus::{self, JournalRecord};
+use clap::Subcommand;
+
+use super::{dispatch_stats::parse_duration_ms, now_epoch_ms, resolve_state_root};
+
+#[derive(Subcommand, Debug)]
+pub(crate) enum EventsAction {
+    /// Print journaled bus events, oldest first (by publish sequence
+    /// number). Covers every retained day file — a week by default.
+    Replay {
+        /// Only events published at or after this relative duration ago
+        /// (e.g. `30m`, `6h`, `2d`). Defaults to everything retained.
+        #[arg(long)]
+        since: Option<String>,
+        /// Only events after this durable subscriber's persisted cursor
+        /// (e.g. `automation_events`): what it would replay on restart.
+        #[arg(long, value_name = "SUBSCRIBER")]
+        after_cursor: Option<String>,
+        /// Only this topic (e.g. `pr_merged`, `work_item_status_changed`).
+        /// Repeatable.
+        #[arg(long = "topic")]
+        topics: Vec<String>,
+        /// Override the Boss state root.
+        #[arg(long)]
+        state_root: Option<PathBuf>,
+    },
+}
+
+pub(crate) fn events_replay(
+    json: bool,
+    state_root: Option<PathBuf>,
+    since: Option<&str>,
+    after_cursor: Option<&str>,
+    topics: &[String],
+) -> Result<()> {
+    let dir = resolve_state_root(state_root)?.join(event_bus::JOURNAL_DIR_NAME);
+    let since_ms = since
+        .map(|value| parse_duration_ms("--since", value).map(|ago| now_epoch_ms().saturating_sub(ago)))
+        .transpose()?;
+    let after_seq = match after_cursor {
+        Some(name) => Some(
+            event_bus::read_cursor(&dir, name)
+                .with_context(|| format!("reading cursor {name:?} under {}", dir.display()))?
+                .with_context(|| format!("durable subscriber {name:?} has no cursor under {}", dir.display()))?,
+        ),
+        None => None,
+    };
+    let read = event_bus::read_journal(&dir).with_context(|| format!("reading {}", dir.display()))?;
+    let selected = select_records(&read.records, since_ms, after_seq, topics);
+
+    if json {
+        println!(
+            "{}",
+            serde_json::json!({
+                "journal_dir": dir,
+                "after_seq": after_seq,
+                "events": selected,
+                "unreadable_lines": read.unreadable_lines,
+            })
+        );
+        return Ok(());
+    }
+
+    if !dir.is_dir() {
+        println!(
+            "no event journal at {} (the engine writes one only with BOSS_ENABLE_DURABLE_EVENT_BUS=true)",
+            dir.display()
+        );
+        return Ok(());
+    }
+    if read.unreadable_lines > 0 {
+        println!(
+            "note: skipped {} unreadable journal line(s) (torn write or unknown topic)",
+            read.unreadable_lines
+        );
+    }
+    if selected.is_empty() {
+        println!("no journaled events");
+        return Ok(());
+    }
+    for record in selected {
+        println!(
+            "#{}  {}  {}  {}",
+            record.seq,
+            record.ts_epoch_ms,
+            record.event.kind().topic_name(),
+            serde_json::to_string(&record.event)?,
+        );
+    }
+    Ok(())
+}
+
+/// Records published at or after `since_ms`, with `seq` past `after_seq`,
+/// on one of `topics` (any topic when empty). Input order is kept.
+fn select_records<'a>(
+    records: &'a [JournalRecord],
+    since_ms: Option<u128>,
+    after_seq: Option<u64>,
+    topics: &[String],
+) -> Vec<&'a JournalRecord> {
+    records
+        .iter()
+        .filter(|record| since_ms.is_none_or(|since| record.ts_epoch_ms >= since))
+        .filter(|record| after_seq.is_none_or(|after| record.seq > after))
+        .filter(|record| topics.is_empty() || topics.iter().any(|topic| topic == record.event.kind().topic_name()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use boss_engine::event_bus::Event;
+
+    fn record(seq: u64, ts_epoch_ms: u128, event: Event) -> JournalRecord {
+        JournalRecord {
+            seq,
+            ts_epoch_ms,
+            event,
+        }
+    }
+
+    fn records() -> Vec<JournalRecord> {
+        vec![
+            record(1, 1_000, Event::DispatchReady),
+            record(
+                2,
+                2_000,
+                Event::PrMerged {
+                    pr_url: "https://github.com/o/r/pull/1".to_owned(),
+                    task_id: "task_1".to_owned(),
+                },
+            ),
+            record(3, 3_000, Event::AutomationMutation),
+        ]
+    }
+
+    fn seqs(selected: &[&JournalRecord]) -> Vec<u64> {
+        selected.iter().map(|record| record.seq).collect()
+    }
+
+    #[test]
+    fn select_records_with_no_filters_keeps_everything() {
+        let records = records();
+        assert_eq!(seqs(&select_records(&records, None, None, &[])), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn select_records_applies_since_cursor_and_topic_together() {
+        let records = records();
+        assert_eq!(seqs(&select_records(&records, Some(2_000), None, &[])), vec![2, 3]);
+        assert_eq!(seqs(&select_records(&records, None, Some(2), &[])), vec![3]);
+        assert_eq!(
+            seqs(&select_records(&records, Some(1_500), None, &["pr_merged".to_owned()])),
+            vec![2]
+        );
+        assert!(select_records(&records, None, Some(2), &["pr_merged".to_owned()]).is_empty());
+    }
+}
@@ -3,12 +3,13 @@
 use std::path::PathBuf;
 
 use anyhow::{Context, Result};
+use boss_engine::container_host::{CommandContainerCli, ContainerRuntime, provision_container_host};
 use boss_engine::host_provisioning::{RemoteProvisionOutcome, provision_remote_host};
 use boss_engine::host_registry::{Host, HostCapability};
 use boss_engine::remote_wrapper::expected_version;
 use boss_engine::work::WorkDb;
 
-use crate::open_state_db;
+use crate::{AddContainerArgs, open_state_db};
 
 pub(crate) async fn hosts_add(
     json: bool,
@@ -21,14 +22,37 @@ pub(crate) async fn hosts_add(
 ) -> Result<()> {
     let db = open_state_db(state_root)?;
     let host = db.add_host(&id, &ssh_target, pool_size, &tags)?;
-
     let push_outcome = if skip_wrapper_push {
         None
     } else {
-        Some(eager_push_wrapper(&db, &host.id, &ssh_target, &SshHostProvisioner).await)
+        Some(eager_push_wrapper(&db, &host, &RegistryHostProvisioner).await)
     };
+    report_registration(json, &db, &id, push_outcome)
+}
 
-    let host = db.get_host(&host.id)?.context("host disappeared after registration")?;
+/// Register a container host: workers run isolated on this machine under
+/// `runtime` (in `image` for podman / docker). Provisioning checks the
+/// runtime and image and probes capabilities from inside the container,
+/// with the same leave-disabled-on-failure policy as `hosts add`.
+pub(crate) async fn hosts_add_container(json: bool, args: AddContainerArgs) -> Result<()> {
+    let db = open_state_db(args.state_root)?;
+    let host = db.add_container_host(
+        &args.id,
+        &args.runtime,
+        args.image.as_deref(),
+        args.pool_size,
+        &args.tags,
+    )?;
+    let push_outcome = if args.skip_probe {
+        None
+    } else {
+        Some(eager_push_wrapper(&db, &host, &RegistryHostProvisioner).await)
+    };
+    report_registration(json, &db, &args.id, push_outcome)
+}
+
+fn report_registration(json: bool, db: &WorkDb, id: &str, push_outcome: Option<EagerPushOutcome>) -> Result<()> {
+    let host = db.get_host(id)?.context("host disappeared after registration")?;
     let caps = db.list_host_capabilities(&host.id)?;
     if json {
         let mut obj = host_to_json(&host, &caps);
@@ -42,7 +66,10 @@ pub(crate) async fn hosts_add(
         if let Some(outcome) = push_outcome.as_ref() {
             match outcome {
                 EagerPushOutcome::Ok { version, capabilities } => {
-                    println!("wrapper push: ok (version {version})");
+                    match version {
+                        Some(version) => println!("wrapper push: ok (version {version})"),
+                        None => println!("container probe: ok"),
+                    }
                     println!("capability discovery: {}", capabilities.join(", "));
                 }
                 EagerPushOutcome::Skipped { reason } => {
@@ -65,7 +92,10 @@ pub(crate) async fn hosts_add(
 #[serde(tag = "status", rename_all = "snake_case")]
 enum EagerPushOutcome {
     Ok {
-        version: String,
+        /// Wrapper version pushed; absent for container hosts, which run
+        /// no remote wrapper.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        version: Option<String>,
         /// Capabilities discovered on the host during registration.
         capabilities: Vec<String>,
     },
@@ -90,25 +120,40 @@ enum EagerPushOutcome {
 /// the CLI's reporting shape and performs the DB writes it implies.
 #[async_trait::async_trait]
 trait HostProvisioner: Send + Sync {
-    async fn provision(&self, host_id: &str, ssh_target: &str) -> RemoteProvisionOutcome;
+    async fn provision(&self, host: &Host) -> RemoteProvisionOutcome;
 }
 
-struct SshHostProvisioner;
+/// Provisions by host kind: a container host through
+/// [`provision_container_host`], every other host over SSH.
+struct RegistryHostProvisioner;
 
 #[async_trait::async_trait]
-impl HostProvisioner for SshHostProvisioner {
-    async fn provision(&self, host_id: &str, ssh_target: &str) -> RemoteProvisionOutcome {
-        provision_remote_host(host_id, ssh_target).await
+impl HostProvisioner for RegistryHostProvisioner {
+    async fn provision(&self, host: &Host) -> RemoteProvisionOutcome {
+        if let Some(runtime) = host.container_runtime.as_deref() {
+            return match ContainerRuntime::parse(runtime) {
+                Ok(runtime) => {
+                    provision_container_host(&host.id, runtime, host.container_image.as_deref(), &CommandContainerCli)
+                        .await
+                }
+                Err(err) => RemoteProvisionOutcome::Failed {
+                    kind: "unclassified",
+                    detail: format!("{err:#}"),
+                },
+            };
+        }
+        match host.ssh_target.as_deref() {
+            Some(ssh_target) => provision_remote_host(&host.id, ssh_target).await,
+            None => RemoteProvisionOutcome::Skipped {
+                reason: "host has neither an ssh_target nor a container runtime".to_owned(),
+            },
+        }
     }
 }
 
-async fn eager_push_wrapper(
-    db: &WorkDb,
-    host_id: &str,
-    ssh_target: &str,
-    provisioner: &dyn HostProvisioner,
-) -> EagerPushOutcome {
-    match provisioner.provision(host_id, ssh_target).await {
+async fn eager_push_wrapper(db: &WorkDb, host: &Host, provisioner: &dyn HostProvisioner) -> EagerPushOutcome {
+    let host_id = host.id.as_str();
+    match provisioner.provision(host).await {
         RemoteProvisionOutcome::Ok { capabilities } => {
             // A successful provision is a real contact with the host, so
             // record the complete healthy state before callers re-read the
@@ -120,7 +165,7 @@ async fn eager_push_wrapper(
                 eprintln!("bossctl: warning: failed to persist discovered capabilities for {host_id}: {err:#}");
             }
             EagerPushOutcome::Ok {
-                version: expected_version(),
+                version: host.container_runtime.is_none().then(expected_version),
                 capabilities,
             }
         }
@@ -144,12 +189,11 @@ async fn probe_host_with(
     provisioner: &dyn HostProvisioner,
 ) -> Result<(EagerPushOutcome, Host, Vec<HostCapability>)> {
     let host = db.get_host(id)?.context("host not found")?;
-    let ssh_target = host
-        .ssh_target
-        .as_deref()
-        .context("the built-in local host does not need remote probing")?;
+    if host.ssh_target.is_none() && host.container_runtime.is_none() {
+        anyhow::bail!("the built-in local host does not need remote probing");
+    }
 
-    let outcome = eager_push_wrapper(db, id, ssh_target, provisioner).await;
+    let outcome = eager_push_wrapper(db, &host, provisioner).await;
     if matches!(outcome, EagerPushOutcome::Ok { .. }) {
         db.set_host_enabled(id, true)?;
     }
@@ -158,10 +202,10 @@ async fn probe_host_with(
     Ok((outcome, host, caps))
 }
 
-/// Re-run provisioning and discovery for an existing remote host.
+/// Re-run provisioning and discovery for an existing remote or container host.
 pub(crate) async fn hosts_probe(json: bool, state_root: Option<PathBuf>, id: String) -> Result<()> {
     let db = open_state_db(state_root)?;
-    let (outcome, host, caps) = probe_host_with(&db, &id, &SshHostProvisioner).await?;
+    let (outcome, host, caps) = probe_host_with(&db, &id, &RegistryHostProvisioner).await?;
     if json {
         let mut obj = host_to_json(&host, &caps);
         obj["wrapper_push"] = serde_json::to_value(&outcome).unwrap_or(serde_json::Value::Null);
@@ -259,6 +303,52 @@ pub(crate) fn hosts_tag_remove(json: bool, state_root: Option<PathBuf>, id: Stri
     Ok(())
 }
 
+/// Record capability requirements on a product, project, or chore. Host
+/// selection only places that subject's work on hosts carrying every tag;
+/// `container-*` tags set the network policy and resource limits a
+/// container host launches it with.
+pub(crate) fn hosts_require_add(
+    json: bool,
+    state_root: Option<PathBuf>,
+    kind: String,
+    id: String,
+    tags: Vec<String>,
+) -> Result<()> {
+    let db = open_state_db(state_root)?;
+    for tag in &tags {
+        db.add_capability_requirement(&kind, &id, tag)?;
+    }
+    print_requirements(json, &db, &id)
+}
+
+pub(crate) fn hosts_require_remove(
+    json: bool,
+    state_root: Option<PathBuf>,
+    id: String,
+    tags: Vec<String>,
+) -> Result<()> {
+    let db = open_state_db(state_root)?;
+    for tag in &tags {
+        db.remove_capability_requirement(&id, tag)?;
+    }
+    print_requirements(json, &db, &id)
+}
+
+fn print_requirements(json: bool, db: &WorkDb, id: &str) -> Result<()> {
+    let required = db.required_capabilities_for_subject_ids(&[id])?;
+    if json {
+        println!("{}", serde_json::json!({ "id": id, "required_capabilities": required }));
+    } else if required.is_empty() {
+        println!("{id}: no required capabilities");
+    } else {
+        println!("{id} requires:");
+        for tag in &required {
+            println!("  {tag}");
+        }
+    }
+    Ok(())
+}
+
 pub(crate) fn hosts_set_enabled(json: bool, state_root: Option<PathBuf>, id: String, enabled: bool) -> Result<()> {
     let db = open_state_db(state_root)?;
     db.set_host_enabled(&id, enabled)?;
@@ -288,6 +378,8 @@ fn host_to_json(host: &Host, caps: &[HostCapability]) -> serde_json::Value {
     serde_json::json!({
         "id": host.id,
         "ssh_target": host.ssh_target,
+        "container_runtime": host.container_runtime,
+        "container_image": host.container_image,
         "pool_size": host.pool_size,
         "enabled": host.enabled,
         "last_seen_at": host.last_seen_at,
@@ -310,7 +402,11 @@ fn print_host_short(host: &Host, caps: &[HostCapability]) {
 /// stdout-writing wrapper.
 fn format_host_short(host: &Host, caps: &[HostCapability]) -> String {
     let enabled = if host.enabled { "enabled" } else { "disabled" };
-    let target = host.ssh_target.as_deref().unwrap_or("(local)");
+    let target = match (&host.ssh_target, &host.container_runtime) {
+        (Some(ssh_target), _) => ssh_target.clone(),
+        (None, Some(runtime)) => format!("container:{runtime}"),
+        (None, None) => "(local)".to_owned(),
+    };
     format!(
         "{}  {}  pool={}  caps={}  target={}",
         host.id,
@@ -340,6 +436,12 @@ fn format_host_detail(host: &Host, caps: &[HostCapability]) -> String {
     if let Some(t) = &host.ssh_target {
         let _ = writeln!(out, "  ssh_target:  {t}");
     }
+    if let Some(runtime) = &host.container_runtime {
+        let _ = writeln!(out, "  container:   {runtime}");
+    }
+    if let Some(image) = &host.container_image {
+        let _ = writeln!(out, "  image:       {image}");
+    }
     let _ = writeln!(out, "  created_at:  {}", host.created_at);
     if let Some(s) = &host.last_seen_at {
         let _ = writeln!(out, "  last_seen:   {s}");
@@ -370,7 +472,7 @@ mod tests {
 
     #[async_trait::async_trait]
     impl HostProvisioner for SuccessProvisioner {
-        async fn provision(&self, _host_id: &str, _ssh_target: &str) -> RemoteProvisionOutcome {
+        async fn provision(&self, _host: &Host) -> RemoteProvisionOutcome {
             RemoteProvisionOutcome::Ok {
                 capabilities: vec!["os=macos".to_owned(), "arch=arm64".to_owned()],
             }
@@ -385,6 +487,8 @@ mod tests {
         Host {
             id: "h1".to_owned(),
             ssh_target: Some("user@example.com".to_owned()),
+            container_runtime: None,
+            container_image: None,
             pool_size: 4,
             enabled: true,
             last_seen_at: None,
@@ -427,6 +531,25 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn probe_container_host_records_capabilities_without_a_wrapper_version() {
+        let db = WorkDb::open(":memory:".into()).unwrap();
+        db.add_container_host("sandbox", "podman", Some("img"), 2, &[]).unwrap();
+
+        let (outcome, host, caps) = probe_host_with(&db, "sandbox", &SuccessProvisioner).await.unwrap();
+
+        assert!(matches!(outcome, EagerPushOutcome::Ok { version: None, .. }));
+        assert!(host.enabled);
+        assert_eq!(caps.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn probe_refuses_the_local_host() {
+        let db = WorkDb::open(":memory:".into()).unwrap();
+        let err = probe_host_with(&db, "local", &SuccessProvisioner).await.unwrap_err();
+        assert!(err.to_string().contains("does not need remote probing"), "got: {err}");
+    }
+
     // ---- host_to_json ------------------------------------------------------
 
     #[test]
@@ -444,6 +567,8 @@ mod tests {
             json!({
                 "id": "h1",
                 "ssh_target": "user@example.com",
+                "container_runtime": null,
+                "container_image": null,
                 "pool_size": 4,
                 "enabled": true,
                 "last_seen_at": "2026-02-02T00:00:00Z",
@@ -531,6 +656,15 @@ mod tests {
         assert!(line.ends_with("target=(local)"), "line was: {line}");
     }
 
+    #[test]
+    fn short_line_container_host_renders_runtime() {
+        let mut h = host();
+        h.ssh_target = None;
+        h.container_runtime = Some("podman".to_owned());
+        let line = format_host_short(&h, &[]);
+        assert!(line.ends_with("target=container:podman"), "line was: {line}");
+    }
+
     // ---- format_host_detail ------------------------------------------------
 
     #[test]
@@ -571,6 +705,17 @@ mod tests {
         assert!(!out.contains("ssh_target:"), "out was:\n{out}");
     }
 
+    #[test]
+    fn detail_container_host_shows_runtime_and_image() {
+        let mut h = host();
+        h.ssh_target = None;
+        h.container_runtime = Some("docker".to_owned());
+        h.container_image = Some("boss-worker:latest".to_owned());
+        let out = format_host_detail(&h, &[]);
+        assert!(out.contains("  container:   docker\n"), "out was:\n{out}");
+        assert!(out.contains("  image:       boss-worker:latest\n"), "out was:\n{out}");
+    }
+
     #[test]
     fn detail_omits_last_seen_and_last_error_when_none() {
         let h = host(); // last_seen_at / last_error_text default to None
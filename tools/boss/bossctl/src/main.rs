@@ -26,6 +26,7 @@ mod command_types;
 mod comments;
 mod dispatch_stats;
 mod doctor;
+mod events;
 mod hosts;
 mod logs;
 mod pause;
@@ -36,9 +37,11 @@ mod stream_integrity;
 use boss_engine::dispatch_events::DispatchEvent;
 use boss_engine::dispatch_reader;
 use boss_protocol::{
-    DispatchAdmissionEntryPoint, FrontendEvent, FrontendRequest, HostedPaneState, HostedPaneStatus,
-    LiveStatusDebugReport, LiveStatusSlotDebug, LiveWorkerState, MetricLiveEntry, ProposalKind, ProposalState, ROSTER,
-    RequestExecutionInput, WorkExecution, WorkItem, WorkRun, WorkerProposal, WorkspacePoolEntry,
+    DEFAULT_DISPATCH_SHARE_WEIGHT, DispatchAdmissionEntryPoint, DispatchExplanation, DispatchWindow, FrontendEvent,
+    FrontendRequest, HostedPaneState, HostedPaneStatus, LiveStatusDebugReport, LiveStatusSlotDebug, LiveWorkerState,
+    MetricLiveEntry, ProductDispatchCalendar, ProductDispatchShare, ProposalKind, ProposalState, ROSTER,
+    RequestExecutionInput, SetProductDispatchCalendarInput, SetProductDispatchShareInput, WorkExecution, WorkItem,
+    WorkRun, WorkerProposal, WorkspacePoolEntry,
 };
 use clap::{Parser, Subcommand};
 use command_types::{LogSource, TranscriptFormat};
@@ -292,6 +295,16 @@ enum Command {
         #[command(subcommand)]
         action: comments::CommentsAction,
     },
+    /// Replay the engine event bus's durable journal for debugging
+    /// reconciler behaviour and post-incident forensics.
+    ///
+    /// File-scan only over `<state_root>/event-bus/` — works when the
+    /// engine is wedged or stopped. The journal exists only when the
+    /// engine runs with `BOSS_ENABLE_DURABLE_EVENT_BUS=true`.
+    Events {
+        #[command(subcommand)]
+        action: events::EventsAction,
+    },
     /// Print the product the Boss UI's product chooser is currently set
     /// to — the product a short ID (`T<n>`) should be resolved against,
     /// and the value to pass to `boss --product`.
@@ -560,6 +573,81 @@ enum DispatchAction {
         #[arg(long)]
         set: Option<usize>,
     },
+    /// Get or set per-product fair-share settings. Within a dispatch
+    /// class the scheduler offers each free slot to the product with the
+    /// fewest live workers per unit of weight, so a product with a large
+    /// backlog gets its weighted share rather than every slot. With no
+    /// product, lists every product that has a share, live workers or
+    /// ready work; with a product and no flags, prints that product's
+    /// share. Flags change only what they name.
+    Share {
+        /// Product id or slug.
+        product: Option<String>,
+        /// Relative share of dispatch slots (default 1). A weight-2
+        /// product is offered twice the workers of a weight-1 product
+        /// while both have ready work.
+        #[arg(long, requires = "product")]
+        weight: Option<u32>,
+        /// Hard ceiling on this product's live workers. Rows past it stay
+        /// `ready` with a wait reason naming the ceiling; reviews are
+        /// exempt.
+        #[arg(long, requires = "product", conflicts_with = "no_max_concurrent")]
+        max_concurrent: Option<u32>,
+        /// Remove the product's concurrency ceiling.
+        #[arg(long, requires = "product")]
+        no_max_concurrent: bool,
+    },
+    /// Get or set a product's dispatch calendar. With windows, new work
+    /// starts only inside one; in quiet hours nothing new starts, reviews
+    /// included; within the drain lead of either cut-off new work is held
+    /// too. Held rows stay `ready` with a wait reason saying when the
+    /// calendar opens. With no product, lists every product that has a
+    /// calendar; with a product and no flags, prints its calendar. Flags
+    /// change only what they name.
+    Calendar {
+        /// Product id or slug.
+        product: Option<String>,
+        /// IANA timezone the windows are read in, e.g.
+        /// `America/Los_Angeles`. Required for a product with no calendar
+        /// yet.
+        #[arg(long, requires = "product")]
+        tz: Option<String>,
+        /// A dispatch window, `<days> <HH:MM>-<HH:MM>`: days are a cron
+        /// day-of-week field (`1-5`, `0,6`) or `weekdays`, `weekends`,
+        /// `daily`. Repeat for several; replaces the stored windows.
+        #[arg(long = "window", requires = "product", value_parser = DispatchWindow::parse, conflicts_with = "no_windows")]
+        windows: Vec<DispatchWindow>,
+        /// Remove every dispatch window, so only quiet hours apply.
+        #[arg(long, requires = "product")]
+        no_windows: bool,
+        /// A quiet-hours window, same form as `--window`. Repeat for
+        /// several; replaces the stored quiet hours.
+        #[arg(long = "quiet", requires = "product", value_parser = DispatchWindow::parse, conflicts_with = "no_quiet")]
+        quiet_hours: Vec<DispatchWindow>,
+        /// Remove every quiet-hours window.
+        #[arg(long, requires = "product")]
+        no_quiet: bool,
+        /// Minutes before a window closes or quiet hours begin during
+        /// which no new work starts. `0` turns draining off.
+        #[arg(long, requires = "product")]
+        drain_before_close: Option<u32>,
+        /// Remove the product's calendar, so it dispatches around the
+        /// clock.
+        #[arg(
+            long,
+            requires = "product",
+            conflicts_with_all = ["tz", "windows", "no_windows", "quiet_hours", "no_quiet", "drain_before_close"]
+        )]
+        clear: bool,
+    },
+    /// Explain why a work item is or is not dispatching: pause and
+    /// admission blockers, the execution's recorded wait reason, its
+    /// dispatch class, base and aged priority, its position in the
+    /// fair-share order, its product's share and its dispatch calendar.
+    Explain {
+        /// Work item id or slug.
+        work_item_id: String,
+    },
 }
 
 #[derive(Subcommand, Debug)]
@@ -994,6 +1082,33 @@ enum MetricsAction {
     },
 }
 
+/// Arguments to `bossctl hosts add-container`.
+#[derive(clap::Args, Debug)]
+struct AddContainerArgs {
+    /// Unique identifier for this host (e.g. `sandbox`).
+    id: String,
+    /// `podman`, `docker`, or `bubblewrap`.
+    #[arg(long)]
+    runtime: String,
+    /// Image workers run in. Required for podman / docker; bubblewrap
+    /// sandboxes this machine's own (read-only) root filesystem.
+    #[arg(long)]
+    image: Option<String>,
+    /// Number of concurrent worker slots on this host.
+    #[arg(long, default_value_t = 1)]
+    pool_size: i64,
+    /// User-defined capability tags.
+    #[arg(long = "tag", value_name = "TAG")]
+    tags: Vec<String>,
+    /// Register without probing; the host stays enabled but reports no
+    /// discovered capabilities until `bossctl hosts probe` runs.
+    #[arg(long)]
+    skip_probe: bool,
+    /// Override the Boss state-root directory.
+    #[arg(long)]
+    state_root: Option<PathBuf>,
+}
+
 #[derive(Subcommand, Debug)]
 enum HostsAction {
     /// Register a new remote host. The host row is persisted to
@@ -1028,6 +1143,17 @@ enum HostsAction {
         #[arg(long)]
         state_root: Option<PathBuf>,
     },
+    /// Register a container host: each worker runs isolated in a rootless
+    /// container on this machine, over an ordinary local cube workspace
+    /// bind-mounted into it. The runtime is checked, the image must already
+    /// be present, and capabilities (`driver=…`, `isolation=container`,
+    /// `container-network=…`, `container-limits=true`) are probed from
+    /// inside the container. Left disabled if any of that fails.
+    ///
+    /// Seed driver credentials into the host's container home
+    /// (`<state root>/containers/homes/<id>`); the operator's own home is
+    /// never mounted.
+    AddContainer(AddContainerArgs),
     /// List all registered hosts with their enabled state and capability count.
     List {
         /// Only show enabled hosts.
@@ -1044,7 +1170,8 @@ enum HostsAction {
         #[arg(long)]
         state_root: Option<PathBuf>,
     },
-    /// Re-run remote provisioning and capability discovery for a host.
+    /// Re-run provisioning and capability discovery for a remote or
+    /// container host.
     Probe {
         id: String,
         /// Override the Boss state-root directory.
@@ -1056,6 +1183,13 @@ enum HostsAction {
         #[command(subcommand)]
         action: HostsTagAction,
     },
+    /// Add or remove the capabilities a product, project, or chore requires
+    /// of whichever host runs its work (e.g. `isolation=container`,
+    /// `container-network=none`, `container-memory=4g`).
+    Require {
+        #[command(subcommand)]
+        action: HostsRequireAction,
+    },
     /// Enable a previously disabled host.
     Enable {
         id: String,
@@ -1079,6 +1213,32 @@ enum HostsAction {
     },
 }
 
+#[derive(Subcommand, Debug)]
+enum HostsRequireAction {
+    /// Require one or more capability tags on a subject's work.
+    Add {
+        /// `product`, `project`, or `chore`.
+        kind: String,
+        id: String,
+        /// Required capability tag(s).
+        #[arg(required = true)]
+        tags: Vec<String>,
+        /// Override the Boss state-root directory.
+        #[arg(long)]
+        state_root: Option<PathBuf>,
+    },
+    /// Drop one or more required capability tags from a subject.
+    Remove {
+        id: String,
+        /// Capability tag(s) to stop requiring.
+        #[arg(required = true)]
+        tags: Vec<String>,
+        /// Override the Boss state-root directory.
+        #[arg(long)]
+        state_root: Option<PathBuf>,
+    },
+}
+
 #[derive(Subcommand, Debug)]
 enum HostsTagAction {
     /// Add one or more user capability tags to a host.
@@ -1454,6 +1614,50 @@ async fn dispatch(cli: Cli) -> Result<()> {
         Command::Dispatch {
             action: DispatchAction::Concurrency { set },
         } => dispatch_concurrency(&cli.socket_path, cli.json, set).await,
+        Command::Dispatch {
+            action:
+                DispatchAction::Share {
+                    product,
+                    weight,
+                    max_concurrent,
+                    no_max_concurrent,
+                },
+        } => {
+            dispatch_share(
+                &cli.socket_path,
+                cli.json,
+                product,
+                weight,
+                max_concurrent,
+                no_max_concurrent,
+            )
+            .await
+        }
+        Command::Dispatch {
+            action:
+                DispatchAction::Calendar {
+                    product,
+                    tz,
+                    windows,
+                    no_windows,
+                    quiet_hours,
+                    no_quiet,
+                    drain_before_close,
+                    clear,
+                },
+        } => {
+            let edit = CalendarEdit {
+                tz,
+                windows: (!windows.is_empty() || no_windows).then_some(windows),
+                quiet_hours: (!quiet_hours.is_empty() || no_quiet).then_some(quiet_hours),
+                drain_before_close,
+                clear,
+            };
+            dispatch_calendar(&cli.socket_path, cli.json, product, edit).await
+        }
+        Command::Dispatch {
+            action: DispatchAction::Explain { work_item_id },
+        } => dispatch_explain(&cli.socket_path, cli.json, &work_item_id).await,
         Command::Automation {
             action: AutomationAction::Pause { reason },
         } => pause::automation_set_paused(&cli.socket_path, cli.json, Some(reason)).await,
@@ -1495,6 +1699,9 @@ async fn dispatch(cli: Cli) -> Result<()> {
                     state_root,
                 },
         } => hosts::hosts_add(cli.json, state_root, id, ssh_target, pool_size, tags, skip_wrapper_push).await,
+        Command::Hosts {
+            action: HostsAction::AddContainer(args),
+        } => hosts::hosts_add_container(cli.json, args).await,
         Command::Hosts {
             action: HostsAction::List { enabled, state_root },
         } => hosts::hosts_list(cli.json, state_root, enabled),
@@ -1516,6 +1723,24 @@ async fn dispatch(cli: Cli) -> Result<()> {
                     action: HostsTagAction::Remove { id, tags, state_root },
                 },
         } => hosts::hosts_tag_remove(cli.json, state_root, id, tags),
+        Command::Hosts {
+            action:
+                HostsAction::Require {
+                    action:
+                        HostsRequireAction::Add {
+                            kind,
+                            id,
+                            tags,
+                            state_root,
+                        },
+                },
+        } => hosts::hosts_require_add(cli.json, state_root, kind, id, tags),
+        Command::Hosts {
+            action:
+                HostsAction::Require {
+                    action: HostsRequireAction::Remove { id, tags, state_root },
+                },
+        } => hosts::hosts_require_remove(cli.json, state_root, id, tags),
         Command::Hosts {
             action: HostsAction::Enable { id, state_root },
         } => hosts::hosts_set_enabled(cli.json, state_root, id, true),
@@ -1607,6 +1832,15 @@ async fn dispatch(cli: Cli) -> Result<()> {
         Command::Comments {
             action: comments::CommentsAction::Runs { comment_id, state_root },
         } => comments::comments_runs(cli.json, state_root, &comment_id),
+        Command::Events {
+            action:
+                events::EventsAction::Replay {
+                    since,
+                    after_cursor,
+                    topics,
+                    state_root,
+                },
+        } => events::events_replay(cli.json, state_root, since.as_deref(), after_cursor.as_deref(), &topics),
         Command::SelectedProduct => selected_product::selected_product(&cli.socket_path, cli.json).await,
         Command::Reveal { id } => agents::reveal_work_item(&cli.socket_path, cli.json, id).await,
         Command::Open { path } => agents::open_document(&cli.socket_path, cli.json, path).await,
@@ -1859,6 +2093,323 @@ async fn dispatch_concurrency(socket_path: &Option<String>, json: bool, set: Opt
     Ok(())
 }
 
+async fn list_product_dispatch_shares_raw(socket_path: &Option<String>) -> Result<Vec<ProductDispatchShare>> {
+    let mut client = connect(socket_path).await?;
+    let response = client
+        .send_request(&FrontendRequest::ListProductDispatchShares)
+        .await
+        .context("sending ListProductDispatchShares")?;
+    match response {
+        FrontendEvent::ProductDispatchSharesList { shares } => Ok(shares),
+        FrontendEvent::Error { message, .. } | FrontendEvent::WorkError { message } => {
+            bail!("engine rejected ListProductDispatchShares: {message}")
+        }
+        other => bail!("engine returned unexpected response: {other:?}"),
+    }
+}
+
+async fn set_product_dispatch_share_raw(
+    socket_path: &Option<String>,
+    input: SetProductDispatchShareInput,
+) -> Result<ProductDispatchShare> {
+    let mut client = connect(socket_path).await?;
+    let response = client
+        .send_request(&FrontendRequest::SetProductDispatchShare { input })
+        .await
+        .context("sending SetProductDispatchShare")?;
+    match response {
+        FrontendEvent::ProductDispatchShareSet { share } => Ok(share),
+        FrontendEvent::Error { message, .. } | FrontendEvent::WorkError { message } => {
+            bail!("engine rejected SetProductDispatchShare: {message}")
+        }
+        other => bail!("engine returned unexpected response: {other:?}"),
+    }
+}
+
+fn print_product_dispatch_share(share: &ProductDispatchShare) {
+    let ceiling = share
+        .max_concurrent
+        .map_or_else(|| "none".to_owned(), |max| max.to_string());
+    println!(
+        "{} ({}): weight {}, ceiling {ceiling}, {} live, {} ready",
+        share.product_name, share.product_id, share.weight, share.live_workers, share.ready_count
+    );
+}
+
+async fn dispatch_share(
+    socket_path: &Option<String>,
+    json: bool,
+    product: Option<String>,
+    weight: Option<u32>,
+    max_concurrent: Option<u32>,
+    no_max_concurrent: bool,
+) -> Result<()> {
+    let Some(product) = product else {
+        let shares = list_product_dispatch_shares_raw(socket_path).await?;
+        if json {
+            println!("{}", serde_json::to_string_pretty(&shares)?);
+        } else if shares.is_empty() {
+            println!("no products with dispatch shares, live workers or ready work");
+        } else {
+            shares.iter().for_each(print_product_dispatch_share);
+        }
+        return Ok(());
+    };
+    // A product with no stored share, live workers or ready work is not
+    // listed; it runs at the defaults.
+    let current = list_product_dispatch_shares_raw(socket_path)
+        .await?
+        .into_iter()
+        .find(|share| share.product_id == product || share.product_name == product);
+    if weight.is_none() && max_concurrent.is_none() && !no_max_concurrent {
+        match (&current, json) {
+            (Some(share), true) => println!("{}", serde_json::to_string_pretty(share)?),
+            (Some(share), false) => print_product_dispatch_share(share),
+            (None, true) => println!("null"),
+            (None, false) => println!(
+                "{product}: weight {DEFAULT_DISPATCH_SHARE_WEIGHT} (default), ceiling none, no live or ready work"
+            ),
+        }
+        return Ok(());
+    }
+    // The set request replaces both fields, so start from the current
+    // values and change only what was passed.
+    let (current_weight, current_max) = current.as_ref().map_or((DEFAULT_DISPATCH_SHARE_WEIGHT, None), |share| {
+        (share.weight, share.max_concurrent)
+    });
+    let input = SetProductDispatchShareInput::builder()
+        .product_id(current.map_or(product, |share| share.product_id))
+        .weight(weight.unwrap_or(current_weight))
+        .maybe_max_concurrent(if no_max_concurrent {
+            None
+        } else {
+            max_concurrent.or(current_max)
+        })
+        .build();
+    let share = set_product_dispatch_share_raw(socket_path, input).await?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&share)?);
+    } else {
+        print_product_dispatch_share(&share);
+    }
+    Ok(())
+}
+
+async fn list_product_dispatch_calendars_raw(socket_path: &Option<String>) -> Result<Vec<ProductDispatchCalendar>> {
+    let mut client = connect(socket_path).await?;
+    let response = client
+        .send_request(&FrontendRequest::ListProductDispatchCalendars)
+        .await
+        .context("sending ListProductDispatchCalendars")?;
+    match response {
+        FrontendEvent::ProductDispatchCalendarsList { calendars } => Ok(calendars),
+        FrontendEvent::Error { message, .. } | FrontendEvent::WorkError { message } => {
+            bail!("engine rejected ListProductDispatchCalendars: {message}")
+        }
+        other => bail!("engine returned unexpected response: {other:?}"),
+    }
+}
+
+async fn set_product_dispatch_calendar_raw(
+    socket_path: &Option<String>,
+    input: SetProductDispatchCalendarInput,
+) -> Result<Option<ProductDispatchCalendar>> {
+    let mut client = connect(socket_path).await?;
+    let response = client
+        .send_request(&FrontendRequest::SetProductDispatchCalendar { input })
+        .await
+        .context("sending SetProductDispatchCalendar")?;
+    match response {
+        FrontendEvent::ProductDispatchCalendarSet { calendar } => Ok(calendar),
+        FrontendEvent::Error { message, .. } | FrontendEvent::WorkError { message } => {
+            bail!("engine rejected SetProductDispatchCalendar: {message}")
+        }
+        other => bail!("engine returned unexpected response: {other:?}"),
+    }
+}
+
+fn print_product_dispatch_calendar(calendar: &ProductDispatchCalendar) {
+    let join = |windows: &[DispatchWindow]| {
+        if windows.is_empty() {
+            "none".to_owned()
+        } else {
+            windows.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+        }
+    };
+    println!(
+        "{} ({}): {} — {}",
+        calendar.product_name,
+        calendar.product_id,
+        calendar.state.phase.as_str(),
+        calendar.state.message
+    );
+    println!("  timezone: {}", calendar.timezone);
+    println!("  windows: {}", join(&calendar.windows));
+    println!("  quiet hours: {}", join(&calendar.quiet_hours));
+    println!("  drain before close: {} min", calendar.drain_before_close_minutes);
+}
+
+/// What `bossctl dispatch calendar <product>` was asked to change. `None`
+/// leaves that part of the stored calendar as it is.
+struct CalendarEdit {
+    tz: Option<String>,
+    windows: Option<Vec<DispatchWindow>>,
+    quiet_hours: Option<Vec<DispatchWindow>>,
+    drain_before_close: Option<u32>,
+    clear: bool,
+}
+
+impl CalendarEdit {
+    fn is_empty(&self) -> bool {
+        self.tz.is_none()
+            && self.windows.is_none()
+            && self.quiet_hours.is_none()
+            && self.drain_before_close.is_none()
+            && !self.clear
+    }
+}
+
+async fn dispatch_calendar(
+    socket_path: &Option<String>,
+    json: bool,
+    product: Option<String>,
+    edit: CalendarEdit,
+) -> Result<()> {
+    let Some(product) = product else {
+        let calendars = list_product_dispatch_calendars_raw(socket_path).await?;
+        if json {
+            println!("{}", serde_json::to_string_pretty(&calendars)?);
+        } else if calendars.is_empty() {
+            println!("no products with dispatch calendars");
+        } else {
+            calendars.iter().for_each(print_product_dispatch_calendar);
+        }
+        return Ok(());
+    };
+    let current = list_product_dispatch_calendars_raw(socket_path)
+        .await?
+        .into_iter()
+        .find(|calendar| calendar.product_id == product || calendar.product_name == product);
+    if edit.is_empty() {
+        match (&current, json) {
+            (Some(calendar), true) => println!("{}", serde_json::to_string_pretty(calendar)?),
+            (Some(calendar), false) => print_product_dispatch_calendar(calendar),
+            (None, true) => println!("null"),
+            (None, false) => println!("{product}: no dispatch calendar — dispatches around the clock"),
+        }
+        return Ok(());
+    }
+    // The set request replaces the whole calendar, so start from the
+    // stored one and change only what was passed.
+    let input = if edit.clear {
+        SetProductDispatchCalendarInput::builder()
+            .product_id(current.map_or(product, |calendar| calendar.product_id))
+            .timezone(String::new())
+            .build()
+    } else {
+        let timezone = match (edit.tz, &current) {
+            (Some(tz), _) => tz,
+            (None, Some(calendar)) => calendar.timezone.clone(),
+            (None, None) => bail!("{product} has no dispatch calendar yet — pass --tz with its first windows"),
+        };
+        let (windows, quiet_hours, drain) = match current.as_ref() {
+            Some(calendar) => (
+                calendar.windows.clone(),
+                calendar.quiet_hours.clone(),
+                calendar.drain_before_close_minutes,
+            ),
+            None => (Vec::new(), Vec::new(), 0),
+        };
+        SetProductDispatchCalendarInput::builder()
+            .product_id(current.map_or(product, |calendar| calendar.product_id))
+            .timezone(timezone)
+            .windows(edit.windows.unwrap_or(windows))
+            .quiet_hours(edit.quiet_hours.unwrap_or(quiet_hours))
+            .drain_before_close_minutes(edit.drain_before_close.unwrap_or(drain))
+            .build()
+    };
+    if input.clears() && !edit.clear {
+        bail!("a dispatch calendar needs at least one --window or --quiet; use --clear to remove it");
+    }
+    let calendar = set_product_dispatch_calendar_raw(socket_path, input).await?;
+    match (&calendar, json) {
+        (_, true) => println!("{}", serde_json::to_string_pretty(&calendar)?),
+        (Some(calendar), false) => print_product_dispatch_calendar(calendar),
+        (None, false) => println!("dispatch calendar removed — the product dispatches around the clock"),
+    }
+    Ok(())
+}
+
+async fn dispatch_explain(socket_path: &Option<String>, json: bool, work_item_id: &str) -> Result<()> {
+    let mut client = connect(socket_path).await?;
+    let response = client
+        .send_request(&FrontendRequest::ExplainDispatch {
+            work_item_id: work_item_id.to_owned(),
+        })
+        .await
+        .context("sending ExplainDispatch")?;
+    let explanation = match response {
+        FrontendEvent::DispatchExplained { explanation } => explanation,
+        FrontendEvent::Error { message, .. } | FrontendEvent::WorkError { message } => {
+            bail!("engine rejected ExplainDispatch: {message}")
+        }
+        other => bail!("engine returned unexpected response: {other:?}"),
+    };
+    if json {
+        println!("{}", serde_json::to_string_pretty(&explanation)?);
+    } else {
+        print_dispatch_explanation(&explanation);
+    }
+    Ok(())
+}
+
+fn print_dispatch_explanation(explanation: &DispatchExplanation) {
+    let admission = &explanation.admission;
+    println!("work item: {}", admission.work_item_id);
+    match (&explanation.execution_id, &explanation.execution_status) {
+        (Some(id), Some(status)) => println!("execution: {id} ({status})"),
+        (Some(id), None) => println!("execution: {id}"),
+        _ => println!("execution: none"),
+    }
+    println!(
+        "would dispatch now: {}",
+        if admission.would_dispatch { "yes" } else { "no" }
+    );
+    if admission.pause.active {
+        println!(
+            "dispatch paused: {} ({})",
+            admission.pause.reason.as_deref().unwrap_or("no reason given"),
+            admission.pause.origin.as_deref().unwrap_or("unknown origin"),
+        );
+    }
+    for blocker in &admission.blockers {
+        println!("blocker [{}]: {}", blocker.code, blocker.message);
+    }
+    if let Some(reason) = &explanation.wait_reason {
+        match &explanation.wait_since {
+            Some(since) => println!("waiting since {since}: {reason}"),
+            None => println!("waiting: {reason}"),
+        }
+    }
+    if let Some(class) = &explanation.dispatch_class {
+        println!("dispatch class: {class}");
+    }
+    if let (Some(base), Some(effective)) = (explanation.base_priority, explanation.effective_priority) {
+        println!("priority: {base} (aged to {effective})");
+    }
+    match explanation.queue_position {
+        Some(position) => println!("queue position: {position} of {}", explanation.queue_len),
+        None => println!("queue: not ready ({} rows ready)", explanation.queue_len),
+    }
+    if let Some(share) = &explanation.product_share {
+        print!("product share: ");
+        print_product_dispatch_share(share);
+    }
+    if let Some(calendar) = &explanation.calendar {
+        println!("dispatch calendar: {} — {}", calendar.phase.as_str(), calendar.message);
+    }
+}
+
 fn filter_and_tail<'a>(
     events: &'a [DispatchEvent],
     n: usize,
@@ -101,6 +101,28 @@ pub(crate) fn compile_schedule(schedule: &str) -> Result<String, CliError> {
     validate_cron_expression(trimmed)
 }
 
+/// The event trigger selected by `--on-*` flags, or `None` when none was
+/// given. Clap already rejects more than one, and any alongside `--schedule`.
+pub(crate) fn event_trigger_from_args(args: &AutomationEventTriggerArgs) -> Option<AutomationTrigger> {
+    if args.on_pr_merged {
+        return Some(AutomationTrigger::PrMerged {
+            paths: args.paths.clone(),
+        });
+    }
+    if args.on_ci_red {
+        return Some(AutomationTrigger::CiRed);
+    }
+    if let Some(status) = &args.on_status {
+        return Some(AutomationTrigger::WorkItemStatus { status: status.clone() });
+    }
+    if let Some(label) = &args.on_upstream_label {
+        return Some(AutomationTrigger::UpstreamLabel { label: label.clone() });
+    }
+    args.on_webhook
+        .as_ref()
+        .map(|name| AutomationTrigger::Webhook { name: name.clone() })
+}
+
 /// Validate a raw 5-field cron expression.
 ///
 /// Checks that the string has exactly 5 whitespace-separated fields and each
@@ -329,14 +351,10 @@ impl DetailTable {
 }
 
 pub(crate) fn print_automations_table(automations: &[Automation]) {
-    let mut table = new_dynamic_table(["#", "NAME", "SCHEDULE", "ENABLED", "OPEN", "LAST OUTCOME", "NEXT DUE"]);
+    let mut table = new_dynamic_table(["#", "NAME", "TRIGGER", "ENABLED", "OPEN", "LAST OUTCOME", "NEXT DUE"]);
     for a in automations {
         let short = a.short_id.map(|n| format!("A{n}")).unwrap_or_default();
-        let schedule = match &a.trigger {
-            AutomationTrigger::Schedule { cron, timezone } => {
-                format!("{cron} ({timezone})")
-            }
-        };
+        let schedule = a.trigger.describe();
         let enabled = if a.enabled { "yes" } else { "no" };
         let last_outcome = a.last_outcome.as_deref().unwrap_or("-");
         let next_due = a.next_due_at.as_deref().unwrap_or("-");
@@ -351,11 +369,13 @@ pub(crate) fn print_automation_details(label: &str, a: &Automation) {
     println!("  ID:          {} ({})", a.id, short);
     println!("  Product:     {}", a.product_id);
     println!("  Name:        {}", a.name);
-    let (cron, tz) = match &a.trigger {
-        AutomationTrigger::Schedule { cron, timezone } => (cron.as_str(), timezone.as_str()),
-    };
-    println!("  Cron:        {cron}");
-    println!("  Timezone:    {tz}");
+    match &a.trigger {
+        AutomationTrigger::Schedule { cron, timezone } => {
+            println!("  Cron:        {cron}");
+            println!("  Timezone:    {timezone}");
+        }
+        trigger => println!("  Trigger:     {}", trigger.describe()),
+    }
     println!("  Instruction: {}", a.standing_instruction);
     println!("  Enabled:     {}", if a.enabled { "yes" } else { "no" });
     println!("  Open limit:  {}", a.open_task_limit);
@@ -376,9 +396,17 @@ pub(crate) fn print_automation_details(label: &str, a: &Automation) {
 }
 
 pub(crate) fn print_automation_runs_table(runs: &[AutomationRun]) {
-    let mut table = new_dynamic_table(["SCHEDULED FOR", "OUTCOME", "STARTED", "PRODUCED TASK", "DETAIL"]);
+    let mut table = new_dynamic_table([
+        "SCHEDULED FOR",
+        "OUTCOME",
+        "STARTED",
+        "PRODUCED TASK",
+        "TRIGGERED BY",
+        "DETAIL",
+    ]);
     for r in runs {
         let produced = r.produced_task_id.as_deref().unwrap_or("-");
+        let triggered_by = r.trigger_event.as_deref().unwrap_or("-");
         let detail = r.detail.as_deref().unwrap_or("-");
         // `repeat_count` collapses consecutive same-outcome rows, and each row
         // is one distinct cron occurrence — not one attempt at the same
@@ -394,6 +422,7 @@ pub(crate) fn print_automation_runs_table(runs: &[AutomationRun]) {
             outcome.as_str(),
             r.started_at.as_str(),
             produced,
+            triggered_by,
             detail,
         ]);
     }
@@ -531,11 +560,15 @@ pub(crate) async fn run_automation_command(command: AutomationCommand, ctx: &Run
             let product = resolve_product(&mut client, args.product, ctx).await?;
             let name = required_text(args.name, "Automation name", ctx)?;
             let instruction = required_text(args.instruction, "Standing instruction", ctx)?;
-            let schedule_raw = required_text(args.schedule, "Schedule", ctx)?;
-            let cron = compile_schedule(&schedule_raw)?;
-            let trigger = AutomationTrigger::Schedule {
-                cron,
-                timezone: args.timezone,
+            let trigger = match event_trigger_from_args(&args.event_trigger) {
+                Some(trigger) => trigger,
+                None => {
+                    let schedule_raw = required_text(args.schedule, "Schedule", ctx)?;
+                    AutomationTrigger::Schedule {
+                        cron: compile_schedule(&schedule_raw)?,
+                        timezone: args.timezone,
+                    }
+                }
             };
             let automation = create_automation(
                 &mut client,
@@ -580,21 +613,29 @@ pub(crate) async fn run_automation_command(command: AutomationCommand, ctx: &Run
             let product = resolve_optional_product(&mut client, args.product, ctx).await?;
             let automation = resolve_automation(&mut client, &args.selector, product.as_ref()).await?;
 
-            // Build a trigger patch only when schedule or timezone changed.
-            let trigger_patch = match (&args.schedule, &args.timezone) {
-                (None, None) => None,
-                _ => {
+            // Build a trigger patch only when the trigger changed. An event
+            // flag replaces the trigger outright.
+            let trigger_patch = match (event_trigger_from_args(&args.event_trigger), &args.schedule, &args.timezone) {
+                (Some(trigger), _, _) => Some(trigger),
+                (None, None, None) => None,
+                (None, schedule, timezone) => {
                     // Start from the existing trigger so partial updates work.
-                    let AutomationTrigger::Schedule {
-                        cron: existing_cron,
-                        timezone: existing_tz,
-                    } = &automation.trigger;
-                    let cron = if let Some(sched) = &args.schedule {
-                        compile_schedule(sched)?
-                    } else {
-                        existing_cron.clone()
+                    let (existing_cron, existing_tz) = match &automation.trigger {
+                        AutomationTrigger::Schedule { cron, timezone } => (Some(cron), timezone.as_str()),
+                        _ => (None, "UTC"),
+                    };
+                    let cron = match (schedule, existing_cron) {
+                        (Some(sched), _) => compile_schedule(sched)?,
+                        (None, Some(existing)) => existing.clone(),
+                        (None, None) => {
+                            return Err(CliError::usage(format!(
+                                "automation {} is triggered {}; pass --schedule to switch it to a schedule",
+                                automation.id,
+                                automation.trigger.describe()
+                            )));
+                        }
                     };
-                    let timezone = args.timezone.clone().unwrap_or_else(|| existing_tz.clone());
+                    let timezone = timezone.clone().unwrap_or_else(|| existing_tz.to_owned());
                     Some(AutomationTrigger::Schedule { cron, timezone })
                 }
             };
@@ -717,6 +758,33 @@ pub(crate) async fn run_automation_command(command: AutomationCommand, ctx: &Run
                 }
             })
         }
+
+        AutomationCommand::Webhook(args) => {
+            let product = resolve_product(&mut client, args.product, ctx).await?;
+            match client
+                .send_request(&FrontendRequest::PostAutomationWebhook {
+                    product_id: product.id.clone(),
+                    name: args.name.clone(),
+                    payload: args.payload,
+                })
+                .await
+                .map_err(CliError::internal)?
+            {
+                FrontendEvent::AutomationWebhookAccepted { .. } => print_entity(
+                    ctx,
+                    &serde_json::json!({ "product_id": product.id, "webhook": args.name, "accepted": true }),
+                    || {
+                        if !ctx.quiet {
+                            println!("Webhook '{}' posted to product '{}'", args.name, product.slug);
+                        }
+                    },
+                ),
+                FrontendEvent::WorkError { message } | FrontendEvent::Error { message, .. } => {
+                    Err(CliError::application(message))
+                }
+                other => Err(unexpected_event("automation webhook", &other)),
+            }
+        }
     }
 }
 
@@ -59,6 +59,10 @@ pub(crate) struct GlobalFlags {
     #[arg(long, global = true)]
     pub(crate) no_engine_autostart: bool,
 
+    /// Engine socket to connect to. An `ssh://[user@]host[:port][/socket]`
+    /// endpoint reaches an engine on another machine through an SSH tunnel
+    /// (the remote host needs `boss` on its `PATH`, or
+    /// `BOSS_REMOTE_BOSS_BIN` set locally to where it lives).
     #[arg(long, global = true)]
     pub(crate) socket_path: Option<String>,
 }
@@ -263,6 +267,18 @@ pub(crate) enum Commands {
         #[command(subcommand)]
         command: CostCommand,
     },
+    /// Full-screen terminal frontend: the product's kanban board, live
+    /// worker status, open attentions, and a transcript tail for the
+    /// selected card's worker. Updates from the engine's push topics and
+    /// drives the same RPCs as the macOS app — move cards, pause
+    /// dispatch, nudge or cancel a worker, answer attentions. For Linux
+    /// hosts and SSH sessions where the app isn't available.
+    Tui(tui::TuiArgs),
+    /// Bridge stdin/stdout to this host's engine socket. Not for direct
+    /// use: it is the far end of the SSH tunnel a client opens for an
+    /// `ssh://` `--socket-path`. Never autostarts the engine.
+    #[command(hide = true)]
+    StdioProxy,
 }
 
 #[derive(Debug, Subcommand)]
@@ -325,13 +341,20 @@ pub(crate) enum ProductCommand {
     /// Bind (or unbind) an external issue tracker on a product.
     ///
     /// Use `--kind github --org ORG --repo REPO --project N` to bind the
-    /// product to a GitHub Projects board. The engine validates the config
-    /// and stores the binding; the reconciler (once running) will begin
-    /// syncing upstream issues as Boss chores.
+    /// product to a GitHub Projects board, `--kind linear --team KEY` to
+    /// bind it to a Linear team, or `--kind jira --base-url URL
+    /// --project-key KEY [--email EMAIL] [--deployment server]` to bind it
+    /// to a Jira project. The engine validates the config and stores the
+    /// binding; the reconciler (once running) will begin syncing upstream
+    /// issues as Boss chores.
+    ///
+    /// Linear and Jira authenticate with an API token read from
+    /// `BOSS_LINEAR_API_TOKEN` / `BOSS_JIRA_API_TOKEN` in the engine's
+    /// environment, or from the OS keychain.
     ///
     /// `--reverse-close` enables opt-in writeback: when a Boss work item
     /// under this product is marked done without a merged PR, Boss will
-    /// explicitly close the upstream GitHub issue. Off by default.
+    /// explicitly close the upstream issue. Off by default.
     ///
     /// `--unset` removes any existing binding. All other flags are ignored.
     #[command(name = "set-external-tracker")]
@@ -841,6 +864,10 @@ pub(crate) enum AutomationCommand {
     /// (`"0 14 * * 1-5"`). Raw expressions are validated before being sent to
     /// the engine. `--timezone` is an IANA name (e.g. `America/Los_Angeles`);
     /// defaults to `UTC`.
+    ///
+    /// Instead of a schedule, pass exactly one event trigger: `--on-pr-merged`
+    /// (narrowed with `--path`), `--on-ci-red`, `--on-status`,
+    /// `--on-upstream-label`, or `--on-webhook`.
     Create(AutomationCreateArgs),
     /// List all automations for a product.
     List(AutomationListArgs),
@@ -870,6 +897,9 @@ pub(crate) enum AutomationCommand {
     /// tracked the finding. Answers "why has this automation filed nothing
     /// in a while?".
     Suppressions(AutomationSelectorArgs),
+    /// Post a local webhook to the engine. Fires every enabled automation on
+    /// the product created with `--on-webhook <name>`.
+    Webhook(AutomationWebhookArgs),
 }
 
 /// Subcommands under `boss attention …`.
@@ -1075,6 +1105,8 @@ pub(crate) struct AutomationCreateArgs {
     /// Defaults to `UTC`.
     #[arg(long, default_value = "UTC")]
     pub(crate) timezone: String,
+    #[command(flatten)]
+    pub(crate) event_trigger: AutomationEventTriggerArgs,
     /// Explicit target repo for the triage worker lease. Defaults to the
     /// product's primary repo when omitted.
     #[arg(long)]
@@ -1127,6 +1159,9 @@ pub(crate) struct AutomationUpdateArgs {
     /// New IANA timezone name.
     #[arg(long)]
     pub(crate) timezone: Option<String>,
+    /// Replace the trigger with an event trigger.
+    #[command(flatten)]
+    pub(crate) event_trigger: AutomationEventTriggerArgs,
     /// New target repo URL (or `""` to clear and fall back to the product
     /// primary).
     #[arg(long)]
@@ -1136,6 +1171,44 @@ pub(crate) struct AutomationUpdateArgs {
     pub(crate) open_task_limit: Option<i64>,
 }
 
+/// Event-trigger flags shared by `automation create` and `automation update`.
+/// At most one may be given, and none together with `--schedule`.
+#[derive(Debug, Args)]
+pub(crate) struct AutomationEventTriggerArgs {
+    /// Fire when a PR on the product merges.
+    #[arg(long, conflicts_with_all = ["schedule", "on_ci_red", "on_status", "on_upstream_label", "on_webhook"])]
+    pub(crate) on_pr_merged: bool,
+    /// With `--on-pr-merged`, only fire when the PR touches a file matching
+    /// this glob (e.g. `docs/**`). Repeatable; any match fires.
+    #[arg(long = "path", value_name = "GLOB", requires = "on_pr_merged")]
+    pub(crate) paths: Vec<String>,
+    /// Fire when CI on the product repo's default branch goes red.
+    #[arg(long, conflicts_with_all = ["schedule", "on_status", "on_upstream_label", "on_webhook"])]
+    pub(crate) on_ci_red: bool,
+    /// Fire when any work item on the product reaches this status.
+    #[arg(long, value_name = "STATUS", conflicts_with_all = ["schedule", "on_upstream_label", "on_webhook"])]
+    pub(crate) on_status: Option<String>,
+    /// Fire when the external tracker imports a new item carrying this label.
+    #[arg(long, value_name = "LABEL", conflicts_with_all = ["schedule", "on_webhook"])]
+    pub(crate) on_upstream_label: Option<String>,
+    /// Fire when `boss automation webhook <NAME>` is posted for the product.
+    #[arg(long, value_name = "NAME", conflicts_with = "schedule")]
+    pub(crate) on_webhook: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub(crate) struct AutomationWebhookArgs {
+    /// Webhook name, as given to `--on-webhook`.
+    pub(crate) name: String,
+    /// Product to post the webhook to.
+    #[arg(long)]
+    pub(crate) product: Option<String>,
+    /// Free-form text recorded on each run it fires and shown to the triage
+    /// agent.
+    #[arg(long)]
+    pub(crate) payload: Option<String>,
+}
+
 #[derive(Debug, Args)]
 pub(crate) struct AutomationRunArgs {
     /// Automation selector: `A<n>` or `auto_…` id.
@@ -1799,7 +1872,7 @@ pub(crate) struct ProductSetExternalTrackerArgs {
     /// Product id or slug to bind.
     pub(crate) selector: String,
 
-    /// Tracker kind. Currently only `github` is supported.
+    /// Tracker kind: `github`, `linear` or `jira`.
     #[arg(long, value_name = "KIND", conflicts_with = "unset")]
     pub(crate) kind: Option<String>,
 
@@ -1815,12 +1888,46 @@ pub(crate) struct ProductSetExternalTrackerArgs {
     #[arg(long, value_name = "N", conflicts_with = "unset")]
     pub(crate) project: Option<u64>,
 
+    /// Linear team key, e.g. `ENG` (required when `--kind linear`).
+    #[arg(long, value_name = "KEY", conflicts_with = "unset")]
+    pub(crate) team: Option<String>,
+
+    /// Jira site URL, e.g. `https://acme.atlassian.net` (required when
+    /// `--kind jira`).
+    #[arg(long, value_name = "URL", conflicts_with = "unset")]
+    pub(crate) base_url: Option<String>,
+
+    /// Jira project key, e.g. `OPS` (required when `--kind jira`).
+    #[arg(long, value_name = "KEY", conflicts_with = "unset")]
+    pub(crate) project_key: Option<String>,
+
+    /// Jira deployment: `cloud` (default) or `server`.
+    #[arg(long, value_name = "DEPLOYMENT", value_parser = ["cloud", "server"], conflicts_with = "unset")]
+    pub(crate) deployment: Option<String>,
+
+    /// Account email for Jira Cloud basic auth (required when `--kind jira`
+    /// on Cloud).
+    #[arg(long, value_name = "EMAIL", conflicts_with = "unset")]
+    pub(crate) email: Option<String>,
+
     /// Opt in to reverse-close: when a Boss work item is marked done
     /// without a merged PR, Boss closes the upstream issue. Off by
-    /// default. Only meaningful for `--kind github`.
+    /// default.
     #[arg(long, conflicts_with = "unset")]
     pub(crate) reverse_close: bool,
 
+    /// Opt a field into two-way sync as `FIELD=POLICY`. Fields: `title`,
+    /// `description`, `priority`, `assignee`. Policies decide a field
+    /// edited on both sides: `upstream_wins`, `boss_wins` or
+    /// `last_writer_wins`. Repeatable.
+    #[arg(long = "sync", value_name = "FIELD=POLICY", conflicts_with = "unset")]
+    pub(crate) sync: Vec<String>,
+
+    /// Mirror comments both ways between the upstream issue and the Boss
+    /// work item.
+    #[arg(long, conflicts_with = "unset")]
+    pub(crate) sync_comments: bool,
+
     /// Remove the external-tracker binding from this product.
     /// Mutually exclusive with all other tracker flags.
     #[arg(long)]
@@ -2918,6 +3025,111 @@ pub(crate) enum CostCommand {
     /// The highest-token-cost runs recorded in a time window, ranked
     /// descending.
     Top(CostTopArgs),
+    /// Per-product and per-project spend budgets, enforced at dispatch
+    /// time: a soft limit files an attention item, a hard limit holds new
+    /// dispatch in scope until the period rolls over or the budget is
+    /// raised.
+    Budget {
+        #[command(subcommand)]
+        command: CostBudgetCommand,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub(crate) enum CostBudgetCommand {
+    /// Set (or replace) the budget for one scope, metric and period.
+    Set(CostBudgetSetArgs),
+    /// List budgets with this period's spend against each.
+    List(CostBudgetListArgs),
+    /// Delete a budget by its `bgt_…` id. Held work in its scope
+    /// dispatches on the next drain unless another budget still holds it.
+    Rm(CostBudgetRmArgs),
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub(crate) enum SpendBudgetMetricArg {
+    /// Input + output + cache-write + cache-read tokens.
+    Tokens,
+    /// Estimated USD against the engine's pricing table.
+    Usd,
+}
+
+impl From<SpendBudgetMetricArg> for boss_protocol::SpendBudgetMetric {
+    fn from(value: SpendBudgetMetricArg) -> Self {
+        match value {
+            SpendBudgetMetricArg::Tokens => Self::Tokens,
+            SpendBudgetMetricArg::Usd => Self::Usd,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub(crate) enum SpendBudgetPeriodArg {
+    /// The UTC calendar day.
+    Day,
+    /// Monday 00:00 UTC to the next Monday.
+    Week,
+    /// The UTC calendar month.
+    Month,
+}
+
+impl From<SpendBudgetPeriodArg> for boss_protocol::SpendBudgetPeriod {
+    fn from(value: SpendBudgetPeriodArg) -> Self {
+        match value {
+            SpendBudgetPeriodArg::Day => Self::Day,
+            SpendBudgetPeriodArg::Week => Self::Week,
+            SpendBudgetPeriodArg::Month => Self::Month,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct CostBudgetSetArgs {
+    /// Product (slug or id) the budget covers, or the parent of
+    /// `--project`. Prompts when omitted and more than one product exists.
+    #[arg(long)]
+    pub(crate) product: Option<String>,
+
+    /// Budget one project of the product instead of the whole product.
+    #[arg(long)]
+    pub(crate) project: Option<String>,
+
+    #[arg(long, value_enum)]
+    pub(crate) metric: SpendBudgetMetricArg,
+
+    #[arg(long, value_enum)]
+    pub(crate) period: SpendBudgetPeriodArg,
+
+    /// Spend at which an attention item is filed. Dispatch continues.
+    #[arg(long)]
+    pub(crate) soft: Option<f64>,
+
+    /// Spend at which new dispatch in scope is held. Workers already
+    /// running are not interrupted.
+    #[arg(long)]
+    pub(crate) hard: Option<f64>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct CostBudgetListArgs {
+    /// Only budgets on this product (slug or id). Lists every budget when
+    /// omitted.
+    #[arg(long)]
+    pub(crate) product: Option<String>,
+
+    /// Only budgets on this project; requires `--product`.
+    #[arg(long, requires = "product")]
+    pub(crate) project: Option<String>,
+
+    /// Print period bounds in UTC instead of this host's local time.
+    #[arg(long)]
+    pub(crate) utc: bool,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct CostBudgetRmArgs {
+    /// The budget's `bgt_…` id, as printed by `boss cost budget list`.
+    pub(crate) id: String,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -2938,8 +3150,10 @@ pub(crate) struct CostTaskArgs {
 
     /// Include an estimated USD figure alongside the token totals,
     /// priced from `boss-engine`'s human-maintained pricing table
-    /// (`tools/boss/engine/core/src/cost_pricing.rs`). Always labelled
-    /// as an estimate in the output — never billing truth.
+    /// (`tools/boss/engine/core/src/cost_pricing.rs`), with any
+    /// per-model rates in the engine state directory's `pricing.toml`
+    /// taking precedence (read at engine startup). Always labelled as an
+    /// estimate in the output — never billing truth.
     #[arg(long)]
     pub(crate) usd: bool,
 
@@ -82,6 +82,95 @@ pub(crate) async fn run_cost_command(command: CostCommand, ctx: &RunContext) ->
                 print_top_cost_report(&report, &tz)
             })
         }
+        CostCommand::Budget { command } => run_cost_budget_command(command, &mut client, ctx).await,
+    }
+}
+
+async fn run_cost_budget_command(
+    command: CostBudgetCommand,
+    client: &mut BossClient,
+    ctx: &RunContext,
+) -> Result<(), CliError> {
+    match command {
+        CostBudgetCommand::Set(args) => {
+            let product = resolve_product(client, args.product, ctx).await?;
+            let scope_id = match args.project {
+                Some(project) => resolve_project(client, &product.id, Some(project), ctx).await?.id,
+                None => product.id,
+            };
+            let input = SetSpendBudgetInput::builder()
+                .scope_id(scope_id)
+                .metric(args.metric.into())
+                .period(args.period.into())
+                .maybe_soft_limit(args.soft)
+                .maybe_hard_limit(args.hard)
+                .build();
+            // Same check the engine runs, so a bad flag combination is a
+            // usage error rather than a round trip.
+            input.validate().map_err(CliError::usage)?;
+            let response = client
+                .send_request(&FrontendRequest::SetSpendBudget { input })
+                .await
+                .map_err(CliError::internal)?;
+            let status = match response {
+                FrontendEvent::SpendBudgetSet { status } => status,
+                FrontendEvent::WorkError { message } | FrontendEvent::Error { message, .. } => {
+                    return Err(CliError::application(message));
+                }
+                other => return Err(unexpected_event("cost budget set", &other)),
+            };
+            print_entity(ctx, &serde_json::json!({ "budget": status }), || {
+                println!("Set budget {}", status.budget.id);
+                print_budget_statuses(std::slice::from_ref(&status), &resolve_display_tz(false));
+            })
+        }
+        CostBudgetCommand::List(args) => {
+            let scope_id = match args.product {
+                Some(product) => {
+                    let product = resolve_product(client, Some(product), ctx).await?;
+                    match args.project {
+                        Some(project) => Some(resolve_project(client, &product.id, Some(project), ctx).await?.id),
+                        None => Some(product.id),
+                    }
+                }
+                None => None,
+            };
+            let response = client
+                .send_request(&FrontendRequest::ListSpendBudgets { scope_id })
+                .await
+                .map_err(CliError::internal)?;
+            let budgets = match response {
+                FrontendEvent::SpendBudgetsList { budgets } => budgets,
+                FrontendEvent::WorkError { message } | FrontendEvent::Error { message, .. } => {
+                    return Err(CliError::application(message));
+                }
+                other => return Err(unexpected_event("cost budget list", &other)),
+            };
+            let tz = resolve_display_tz(args.utc);
+            print_entity(ctx, &serde_json::json!({ "budgets": budgets }), || {
+                if budgets.is_empty() {
+                    println!("No spend budgets set.");
+                } else {
+                    print_budget_statuses(&budgets, &tz);
+                }
+            })
+        }
+        CostBudgetCommand::Rm(args) => {
+            let response = client
+                .send_request(&FrontendRequest::DeleteSpendBudget { budget_id: args.id })
+                .await
+                .map_err(CliError::internal)?;
+            let budget_id = match response {
+                FrontendEvent::SpendBudgetDeleted { budget_id } => budget_id,
+                FrontendEvent::WorkError { message } | FrontendEvent::Error { message, .. } => {
+                    return Err(CliError::application(message));
+                }
+                other => return Err(unexpected_event("cost budget rm", &other)),
+            };
+            print_entity(ctx, &serde_json::json!({ "deleted": budget_id }), || {
+                println!("Deleted budget {budget_id}");
+            })
+        }
     }
 }
 
@@ -128,6 +217,48 @@ fn resolve_window(since: &str, until: Option<&str>) -> Result<(i64, i64), CliErr
 
 // ── Rendering ─────────────────────────────────────────────────────────
 
+fn format_budget_amount(metric: SpendBudgetMetric, amount: f64) -> String {
+    match metric {
+        SpendBudgetMetric::Tokens => format_tokens(amount.round() as i64),
+        SpendBudgetMetric::Usd => format!("${amount:.2}"),
+    }
+}
+
+fn print_budget_statuses(statuses: &[SpendBudgetStatus], tz: &DisplayTz) {
+    let mut table = new_dynamic_table(vec![
+        "ID", "SCOPE", "METRIC", "PERIOD", "SPENT", "SOFT", "HARD", "STATE", "RESETS",
+    ]);
+    for status in statuses {
+        let budget = &status.budget;
+        let limit = |limit: Option<f64>| {
+            limit
+                .map(|limit| format_budget_amount(budget.metric, limit))
+                .unwrap_or_else(|| "—".to_owned())
+        };
+        let mut spent = format_budget_amount(budget.metric, status.spent);
+        if status.spent_partial {
+            spent.push_str(" (partial)");
+        }
+        let state = match status.state {
+            SpendBudgetState::UnderLimit => "ok",
+            SpendBudgetState::SoftLimitReached => "soft limit",
+            SpendBudgetState::HardLimitReached => "HELD",
+        };
+        table.add_row(vec![
+            budget.id.clone(),
+            format!("{} {}", budget.scope_kind, budget.scope_name),
+            budget.metric.as_str().to_owned(),
+            budget.period.as_str().to_owned(),
+            spent,
+            limit(budget.soft_limit),
+            limit(budget.hard_limit),
+            state.to_owned(),
+            format_epoch(status.period_end_epoch_s, tz),
+        ]);
+    }
+    print_table(table);
+}
+
 fn format_tokens(n: i64) -> String {
     let negative = n < 0;
     let digits = n.unsigned_abs().to_string();
@@ -167,10 +167,50 @@ pub(crate) async fn set_product_merge_mechanism(
     )
 }
 
+fn required_tracker_flag<'a>(value: &'a Option<String>, flag: &str, kind: &str) -> Result<&'a str, CliError> {
+    value
+        .as_deref()
+        .filter(|s| !s.is_empty())
+        .ok_or_else(|| CliError::usage(format!("{flag} is required for --kind {kind}")))
+}
+
 /// Build the kind-specific JSON config for `set-external-tracker` from CLI args.
 pub(crate) fn build_external_tracker_config(
     kind: &str,
     args: &ProductSetExternalTrackerArgs,
+) -> Result<serde_json::Value, CliError> {
+    let mut config = build_external_tracker_transport_config(kind, args)?;
+    if let Some(sync) = build_external_tracker_sync_config(args)? {
+        config["sync"] = sync;
+    }
+    Ok(config)
+}
+
+/// The optional `sync` object from `--sync FIELD=POLICY` and
+/// `--sync-comments`. Field and policy names are validated by the engine.
+fn build_external_tracker_sync_config(
+    args: &ProductSetExternalTrackerArgs,
+) -> Result<Option<serde_json::Value>, CliError> {
+    if args.sync.is_empty() && !args.sync_comments {
+        return Ok(None);
+    }
+    let mut sync = serde_json::Map::new();
+    for entry in &args.sync {
+        let (field, policy) = entry
+            .split_once('=')
+            .filter(|(field, policy)| !field.is_empty() && !policy.is_empty())
+            .ok_or_else(|| CliError::usage(format!("--sync expects FIELD=POLICY, got '{entry}'")))?;
+        sync.insert(field.to_owned(), serde_json::json!(policy));
+    }
+    if args.sync_comments {
+        sync.insert("comments".to_owned(), serde_json::json!(true));
+    }
+    Ok(Some(serde_json::Value::Object(sync)))
+}
+
+fn build_external_tracker_transport_config(
+    kind: &str,
+    args: &ProductSetExternalTrackerArgs,
 ) -> Result<serde_json::Value, CliError> {
     match kind {
         "github" => {
@@ -194,8 +234,34 @@ pub(crate) fn build_external_tracker_config(
                 "reverse_close": args.reverse_close,
             }))
         }
+        "linear" => {
+            let team = required_tracker_flag(&args.team, "--team", kind)?;
+            Ok(serde_json::json!({
+                "team_key": team,
+                "reverse_close": args.reverse_close,
+            }))
+        }
+        "jira" => {
+            let base_url = required_tracker_flag(&args.base_url, "--base-url", kind)?;
+            let project_key = required_tracker_flag(&args.project_key, "--project-key", kind)?;
+            let deployment = args.deployment.as_deref().unwrap_or("cloud");
+            let mut config = serde_json::json!({
+                "base_url": base_url,
+                "project_key": project_key,
+                "deployment": deployment,
+                "reverse_close": args.reverse_close,
+            });
+            match args.email.as_deref().filter(|s| !s.is_empty()) {
+                Some(email) => config["email"] = serde_json::json!(email),
+                None if deployment == "cloud" => {
+                    return Err(CliError::usage("--email is required for --kind jira on Jira Cloud"));
+                }
+                None => {}
+            }
+            Ok(config)
+        }
         other => Err(CliError::usage(format!(
-            "unknown tracker kind '{other}'; supported: github"
+            "unknown tracker kind '{other}'; supported: github, linear, jira"
         ))),
     }
 }
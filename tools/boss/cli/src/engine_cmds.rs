@@ -21,8 +21,14 @@ async fn resolve_optional_work_item_filter(
 pub(crate) async fn run_engine_command(command: EngineCommand, ctx: &RunContext) -> Result<(), CliError> {
     match command {
         EngineCommand::Status => {
-            let running = engine_socket_reachable(&ctx.discovery.socket_path).await;
-            let pid = running_engine_pid(&ctx.discovery.pid_file_path);
+            // A remote engine's pid file is on its own host.
+            let (running, pid) = match &ctx.discovery.remote {
+                Some(_) => (BossClient::connect(&ctx.discovery).await.is_ok(), None),
+                None => (
+                    engine_socket_reachable(&ctx.discovery.socket_path).await,
+                    running_engine_pid(&ctx.discovery.pid_file_path),
+                ),
+            };
             print_entity(
                 ctx,
                 &serde_json::json!({
@@ -60,6 +66,12 @@ pub(crate) async fn run_engine_command(command: EngineCommand, ctx: &RunContext)
             )
         }
         EngineCommand::Stop => {
+            if let Some(endpoint) = &ctx.discovery.remote {
+                return Err(CliError::usage(format!(
+                    "the engine at {} is remote; stop it on that host",
+                    endpoint.display()
+                )));
+            }
             stop_engine(&ctx.discovery.pid_file_path)
                 .await
                 .map_err(|err| CliError::engine_unavailable(err.to_string()))?;
@@ -18,10 +18,10 @@ pub(crate) use boss_protocol::{
     ExecutionKind, FollowupMemberOverride, FrontendEvent, FrontendRequest, GitHubAuthStateDto, LinkExternalRefInput,
     ListDependenciesInput, OrgAuthState, PlannerOutput, PlannerRun, PrWorkItemMatch, Product, Project,
     ProjectDesignDocState, ReasoningMode, RemoveDependencyInput, ResolveProjectDesignDocOutput, ResolvedDesignDocKind,
-    SetProductEditorialRulesInput, SetProductExternalTrackerInput, SetProjectDesignDocInput, SetTaskDocPointerInput,
-    Task, TaskCostReport, TaskRuntime, TopCostReport, UnpopulatePreservedTask, WindowCostReport, WorkAttentionItem,
-    WorkComment, WorkExecution, WorkItem, WorkItemDependency, WorkItemDependencyDetail, WorkItemDependencyView,
-    WorkItemPatch,
+    SetProductEditorialRulesInput, SetProductExternalTrackerInput, SetProjectDesignDocInput, SetSpendBudgetInput,
+    SetTaskDocPointerInput, SpendBudgetMetric, SpendBudgetState, SpendBudgetStatus, Task, TaskCostReport, TaskRuntime,
+    TopCostReport, UnpopulatePreservedTask, WindowCostReport, WorkAttentionItem, WorkComment, WorkExecution, WorkItem,
+    WorkItemDependency, WorkItemDependencyDetail, WorkItemDependencyView, WorkItemPatch,
 };
 pub(crate) use clap::{Args, CommandFactory, Parser, Subcommand, ValueEnum};
 pub(crate) use comfy_table::{Cell, ContentArrangement, Table};
@@ -33,6 +33,7 @@ mod context;
 mod pr;
 mod propose;
 mod repo_resolution;
+mod tui;
 pub(crate) use boss_github as github_app;
 pub(crate) use git_utils::repo_slug::short_name_for;
 
@@ -318,6 +319,21 @@ pub(crate) async fn run_cli(cli: Cli) -> Result<(), CliError> {
             let ctx = RunContext::from_flags(&cli.global)?;
             cost_cmds::run_cost_command(command, &ctx).await
         }
+        Commands::Tui(args) => {
+            let ctx = RunContext::from_flags(&cli.global)?;
+            tui::run_tui_command(args, &ctx).await
+        }
+        Commands::StdioProxy => {
+            let ctx = RunContext::from_flags(&cli.global)?;
+            if ctx.discovery.remote.is_some() {
+                return Err(CliError::usage(
+                    "`stdio-proxy` bridges to a local engine socket, not an ssh:// endpoint",
+                ));
+            }
+            boss_client::remote::proxy_stdio(&ctx.discovery.socket_path)
+                .await
+                .map_err(|err| CliError::engine_unavailable(format!("{err:#}")))
+        }
     }
 }
 
@@ -8,14 +8,14 @@ use super::{
     ProductCommand, ProductStatus, ProjectCommand, ProjectStatusArg, RepoSelector, RunContext, TaskCommand,
     TaskListCriteria, TaskPriority, TaskStatusArg, apply_project_list_filters, apply_task_list_filters,
     classify_bind_pr, classify_lint_finding, compile_schedule, decide_open_design_action, default_comment_author,
-    dependency_status_is_satisfied, ensure_explicit_product_matches, expect_leaf_work_item,
+    dependency_status_is_satisfied, ensure_explicit_product_matches, event_trigger_from_args, expect_leaf_work_item,
     format_dependency_edge_line, format_project_design_doc_line, format_repo_line, format_stored_epoch,
     is_typed_work_item_id, lint_summary_line, parse_attention_group_selector, parse_automation_selector, pick_by_index,
     resolve_comments_artifact, split_shake_report, status_vocab, task_json_with_runtime, validate_github_pr_url,
     with_display_status,
 };
 use boss_protocol::{
-    DependencyEdge, Product, Project, ProjectDesignDocState, ProjectStatus, ResolvedDesignDoc, ResolvedDesignDocKind,
+    AutomationTrigger, DependencyEdge, Product, Project, ProjectDesignDocState, ProjectStatus, ResolvedDesignDoc, ResolvedDesignDocKind,
     Task, TaskKind, TaskRuntime, TaskStatus, WorkItem,
 };
 
@@ -2205,6 +2205,122 @@ fn task_update_rejects_effort_and_unset_effort_together() {
     assert!(result.is_err(), "expected clap to reject mutually exclusive flags");
 }
 
+fn set_external_tracker_args(argv: &[&str]) -> super::ProductSetExternalTrackerArgs {
+    let mut full = vec!["boss", "product", "set-external-tracker", "boss"];
+    full.extend_from_slice(argv);
+    match Cli::parse_from(full).command {
+        Commands::Product {
+            command: ProductCommand::SetExternalTracker(args),
+        } => args,
+        _ => panic!("expected product set-external-tracker command"),
+    }
+}
+
+#[test]
+fn external_tracker_config_for_linear_and_jira() {
+    let args = set_external_tracker_args(&["--kind", "linear", "--team", "ENG"]);
+    assert_eq!(
+        super::build_external_tracker_config("linear", &args).unwrap(),
+        serde_json::json!({ "team_key": "ENG", "reverse_close": false })
+    );
+
+    let args = set_external_tracker_args(&[
+        "--kind",
+        "jira",
+        "--base-url",
+        "https://acme.atlassian.net",
+        "--project-key",
+        "OPS",
+        "--email",
+        "bot@acme.dev",
+        "--reverse-close",
+    ]);
+    assert_eq!(
+        super::build_external_tracker_config("jira", &args).unwrap(),
+        serde_json::json!({
+            "base_url": "https://acme.atlassian.net",
+            "project_key": "OPS",
+            "deployment": "cloud",
+            "email": "bot@acme.dev",
+            "reverse_close": true,
+        })
+    );
+
+    let args = set_external_tracker_args(&[
+        "--kind",
+        "jira",
+        "--base-url",
+        "https://jira.acme.internal",
+        "--project-key",
+        "OPS",
+        "--deployment",
+        "server",
+    ]);
+    let config = super::build_external_tracker_config("jira", &args).unwrap();
+    assert_eq!(config["deployment"], "server");
+    assert!(config.get("email").is_none());
+}
+
+#[test]
+fn external_tracker_config_carries_sync_flags() {
+    let args = set_external_tracker_args(&[
+        "--kind",
+        "linear",
+        "--team",
+        "ENG",
+        "--sync",
+        "title=last_writer_wins",
+        "--sync",
+        "priority=boss_wins",
+        "--sync-comments",
+    ]);
+    assert_eq!(
+        super::build_external_tracker_config("linear", &args).unwrap()["sync"],
+        serde_json::json!({
+            "title": "last_writer_wins",
+            "priority": "boss_wins",
+            "comments": true,
+        })
+    );
+
+    let args = set_external_tracker_args(&["--kind", "linear", "--team", "ENG"]);
+    assert!(
+        super::build_external_tracker_config("linear", &args)
+            .unwrap()
+            .get("sync")
+            .is_none()
+    );
+
+    let args = set_external_tracker_args(&["--kind", "linear", "--team", "ENG", "--sync", "title"]);
+    let err = super::build_external_tracker_config("linear", &args).unwrap_err();
+    assert!(err.to_string().contains("FIELD=POLICY"), "{err}");
+}
+
+#[test]
+fn external_tracker_config_reports_missing_kind_flags() {
+    let args = set_external_tracker_args(&["--kind", "linear"]);
+    let err = super::build_external_tracker_config("linear", &args).unwrap_err();
+    assert!(err.to_string().contains("--team"), "{err}");
+
+    let args = set_external_tracker_args(&["--kind", "jira", "--base-url", "https://x", "--project-key", "OPS"]);
+    let err = super::build_external_tracker_config("jira", &args).unwrap_err();
+    assert!(err.to_string().contains("--email"), "{err}");
+
+    assert!(
+        Cli::try_parse_from([
+            "boss",
+            "product",
+            "set-external-tracker",
+            "boss",
+            "--kind",
+            "jira",
+            "--deployment",
+            "datacenter",
+        ])
+        .is_err()
+    );
+}
+
 #[test]
 fn parses_product_set_default_model_with_model() {
     let cli = Cli::parse_from(["boss", "product", "set-default-model", "boss", "--model", "sonnet"]);
@@ -2713,6 +2829,71 @@ fn parses_automation_suppressions_command() {
     ));
 }
 
+#[test]
+fn parses_automation_create_with_event_trigger() {
+    let cli = Cli::parse_from([
+        "boss",
+        "automation",
+        "create",
+        "--product",
+        "boss",
+        "--on-pr-merged",
+        "--path",
+        "docs/**",
+        "--path",
+        "*.md",
+    ]);
+    match cli.command {
+        Commands::Automation {
+            command: AutomationCommand::Create(args),
+        } => {
+            assert!(args.schedule.is_none());
+            assert_eq!(
+                event_trigger_from_args(&args.event_trigger),
+                Some(AutomationTrigger::PrMerged {
+                    paths: vec!["docs/**".to_owned(), "*.md".to_owned()],
+                })
+            );
+        }
+        _ => panic!("expected automation create command"),
+    }
+}
+
+#[test]
+fn automation_event_triggers_conflict_with_schedule_and_each_other() {
+    assert!(
+        Cli::try_parse_from(["boss", "automation", "create", "--schedule", "nightly", "--on-ci-red"]).is_err()
+    );
+    assert!(
+        Cli::try_parse_from(["boss", "automation", "create", "--on-ci-red", "--on-webhook", "deploy"]).is_err()
+    );
+    // `--path` only narrows `--on-pr-merged`.
+    assert!(Cli::try_parse_from(["boss", "automation", "create", "--path", "docs/**"]).is_err());
+}
+
+#[test]
+fn parses_automation_webhook_command() {
+    let cli = Cli::parse_from([
+        "boss",
+        "automation",
+        "webhook",
+        "deploy",
+        "--product",
+        "boss",
+        "--payload",
+        "build 42",
+    ]);
+    match cli.command {
+        Commands::Automation {
+            command: AutomationCommand::Webhook(args),
+        } => {
+            assert_eq!(args.name, "deploy");
+            assert_eq!(args.payload.as_deref(), Some("build 42"));
+        }
+        _ => panic!("expected automation webhook command"),
+    }
+}
+
 // --- cron validation tests ---
 
 #[test]
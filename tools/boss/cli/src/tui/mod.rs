@@ -0,0 +1,362 @@
+//! `boss tui` — a terminal frontend for the engine, for hosts without the
+//! macOS app.
+//!
+//! Two engine connections: one subscribed to the board, worker and health
+//! topics whose pushes feed the event loop, and one for the requests the
+//! UI issues (refetches and actions). A push never carries the new state
+//! itself for the board — it invalidates, and the loop refetches
+//! `GetWorkTree` — so the view can't drift from what the app would show.
+//!
+//! State and key handling live in [`model`]; [`render`] only draws.
+
+mod model;
+mod render;
+
+use std::time::Duration;
+
+use boss_protocol::{
+    FrontendEvent, FrontendRequest, TOPIC_ENGINE_HEALTH, TOPIC_WORK_PRODUCTS, TOPIC_WORKER_LIVE_STATES,
+    work_product_topic,
+};
+use clap::Args;
+use ratatui::crossterm::event::{self, Event, KeyEvent, KeyEventKind};
+use tokio::sync::mpsc;
+
+use crate::{BossClient, CliError, IsTerminal, RunContext, connect_for_work, io, list_products, match_products};
+use model::{Action, Model, card_label, column_title};
+
+/// How often the transcript pane re-tails the selected worker's run.
+const TRANSCRIPT_REFRESH: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct TuiArgs {
+    /// Product to open on (slug, id, or list index). Defaults to the
+    /// first product; `[` / `]` switch products once running.
+    #[arg(long)]
+    pub(crate) product: Option<String>,
+
+    /// Raw transcript lines to tail for the selected card's worker.
+    #[arg(long, default_value_t = 200)]
+    pub(crate) transcript_lines: usize,
+}
+
+enum Input {
+    Key(KeyEvent),
+    Push(Box<FrontendEvent>),
+    Tick,
+    Disconnected(String),
+}
+
+pub(crate) async fn run_tui_command(args: TuiArgs, ctx: &RunContext) -> Result<(), CliError> {
+    if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+        return Err(CliError::usage("`boss tui` needs an interactive terminal"));
+    }
+
+    let mut client = connect_for_work(ctx).await?;
+    let products = list_products(&mut client).await?;
+    if products.is_empty() {
+        return Err(CliError::not_found("no products exist"));
+    }
+    let index = match &args.product {
+        Some(selector) => {
+            let product = match_products(&products, selector)?;
+            products.iter().position(|p| p.id == product.id).unwrap_or(0)
+        }
+        None => 0,
+    };
+
+    let mut topics = vec![
+        TOPIC_WORK_PRODUCTS.to_owned(),
+        TOPIC_WORKER_LIVE_STATES.to_owned(),
+        TOPIC_ENGINE_HEALTH.to_owned(),
+    ];
+    topics.extend(products.iter().map(|product| work_product_topic(&product.id)));
+    let mut pushes = connect_for_work(ctx).await?;
+    pushes.subscribe(&topics).await.map_err(CliError::internal)?;
+
+    let mut model = Model::new(products, index);
+    refresh_all(&mut client, &mut model).await;
+
+    let (tx, rx) = mpsc::unbounded_channel();
+    spawn_push_reader(pushes, tx.clone());
+    spawn_key_reader(tx.clone());
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(TRANSCRIPT_REFRESH);
+        loop {
+            interval.tick().await;
+            if tx.send(Input::Tick).is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut terminal, &mut client, &mut model, rx, args.transcript_lines).await;
+    ratatui::restore();
+    result
+}
+
+async fn event_loop(
+    terminal: &mut ratatui::DefaultTerminal,
+    client: &mut BossClient,
+    model: &mut Model,
+    mut rx: mpsc::UnboundedReceiver<Input>,
+    transcript_lines: usize,
+) -> Result<(), CliError> {
+    loop {
+        terminal
+            .draw(|frame| render::draw(frame, model))
+            .map_err(CliError::internal)?;
+        let Some(input) = rx.recv().await else {
+            return Ok(());
+        };
+        match input {
+            Input::Key(key) => {
+                let Some(action) = model.handle_key(key) else {
+                    // Navigation may have landed on a different worker's card.
+                    if model.transcript_run_id() != model.transcript.as_ref().map(|(run_id, _)| run_id.clone()) {
+                        refresh_transcript(client, model, transcript_lines).await;
+                    }
+                    continue;
+                };
+                if action == Action::Quit {
+                    return Ok(());
+                }
+                perform(client, model, action).await;
+            }
+            Input::Push(event) => apply_push(client, model, *event).await,
+            Input::Tick => refresh_transcript(client, model, transcript_lines).await,
+            Input::Disconnected(message) => return Err(CliError::engine_unavailable(message)),
+        }
+    }
+}
+
+/// Forward every push on the subscribed connection into the loop. Replies
+/// to this connection's own requests (only the `Subscribe`) are already
+/// consumed, so anything left is a push.
+fn spawn_push_reader(mut pushes: BossClient, tx: mpsc::UnboundedSender<Input>) {
+    tokio::spawn(async move {
+        let input = loop {
+            match pushes.next_event().await {
+                Ok(Some(envelope)) => {
+                    if tx.send(Input::Push(Box::new(envelope.payload))).is_err() {
+                        return;
+                    }
+                }
+                Ok(None) => break Input::Disconnected("engine closed the subscription socket".to_owned()),
+                Err(err) => break Input::Disconnected(err.to_string()),
+            }
+        };
+        let _ = tx.send(input);
+    });
+}
+
+/// crossterm's `event::read` blocks, so keys are read on a plain thread.
+/// It exits with the process; there is nothing to join.
+fn spawn_key_reader(tx: mpsc::UnboundedSender<Input>) {
+    std::thread::spawn(move || {
+        loop {
+            let input = match event::read() {
+                Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => Input::Key(key),
+                // Redraw at the new size.
+                Ok(Event::Resize(..)) => Input::Tick,
+                Ok(_) => continue,
+                Err(_) => break,
+            };
+            if tx.send(input).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+/// Send `request`, folding the engine's error replies into `Err(message)`
+/// so a refused action lands in the status line instead of ending the
+/// session.
+async fn request(client: &mut BossClient, request: FrontendRequest) -> Result<FrontendEvent, String> {
+    match client.send_request(&request).await.map_err(|err| err.to_string())? {
+        FrontendEvent::WorkError { message } | FrontendEvent::Error { message, .. } => Err(message),
+        FrontendEvent::WorkerTierDenied { denial } => Err(denial.message),
+        event => Ok(event),
+    }
+}
+
+async fn refresh_all(client: &mut BossClient, model: &mut Model) {
+    if let Ok(FrontendEvent::EngineHealthResult { report }) = request(client, FrontendRequest::GetEngineHealth).await {
+        model.dispatch_paused = report.dispatch_paused;
+    }
+    if let Ok(FrontendEvent::WorkerLiveStatesList { states }) =
+        request(client, FrontendRequest::ListWorkerLiveStates).await
+    {
+        model.apply_live_states(states);
+    }
+    refresh_product(client, model).await;
+}
+
+/// Refetch the selected product's board and open attentions.
+async fn refresh_product(client: &mut BossClient, model: &mut Model) {
+    let Some(product_id) = model.product().map(|product| product.id.clone()) else {
+        return;
+    };
+    match request(
+        client,
+        FrontendRequest::GetWorkTree {
+            product_id: product_id.clone(),
+            fetch_seq: None,
+        },
+    )
+    .await
+    {
+        Ok(FrontendEvent::WorkTree { tasks, chores, .. }) => model.apply_work_tree(tasks, chores),
+        Ok(other) => model.status = Some(format!("unexpected reply to GetWorkTree: {other:?}")),
+        Err(message) => model.status = Some(message),
+    }
+    match request(
+        client,
+        FrontendRequest::ListAttentionGroups {
+            product_id,
+            project_id: None,
+            task_id: None,
+            kind: None,
+            state: None,
+        },
+    )
+    .await
+    {
+        Ok(FrontendEvent::AttentionGroupsList { groups, members, .. }) => model.apply_attentions(&groups, members),
+        Ok(other) => model.status = Some(format!("unexpected reply to ListAttentionGroups: {other:?}")),
+        Err(message) => model.status = Some(message),
+    }
+}
+
+async fn refresh_transcript(client: &mut BossClient, model: &mut Model, lines: usize) {
+    let Some(run_id) = model.transcript_run_id() else {
+        model.transcript = None;
+        return;
+    };
+    // The run may not have written a transcript yet; on any other reply the
+    // pane is kept as is.
+    if let Ok(FrontendEvent::RunTranscriptTail { run_id, lines, .. }) =
+        request(client, FrontendRequest::TailRunTranscript { run_id, lines }).await
+    {
+        model.apply_transcript(run_id, &lines);
+    }
+}
+
+async fn apply_push(client: &mut BossClient, model: &mut Model, event: FrontendEvent) {
+    match event {
+        FrontendEvent::WorkerLiveStatesList { states } => model.apply_live_states(states),
+        FrontendEvent::EngineHealthResult { report } => model.dispatch_paused = report.dispatch_paused,
+        FrontendEvent::TopicEvent { topic, .. } if topic == TOPIC_WORK_PRODUCTS => {
+            if let Ok(FrontendEvent::ProductsList { products }) = request(client, FrontendRequest::ListProducts).await {
+                let selected = model.product().map(|product| product.id.clone());
+                model.product_index = products
+                    .iter()
+                    .position(|product| Some(&product.id) == selected.as_ref())
+                    .unwrap_or(0);
+                model.products = products;
+            }
+        }
+        FrontendEvent::TopicEvent { topic, .. } => {
+            let current = model.product().map(|product| work_product_topic(&product.id));
+            if current.as_deref() == Some(topic.as_str()) {
+                refresh_product(client, model).await;
+            }
+        }
+        _ => {}
+    }
+}
+
+async fn perform(client: &mut BossClient, model: &mut Model, action: Action) {
+    let outcome = match action {
+        Action::Quit => return,
+        Action::Reload => {
+            model.status = None;
+            refresh_all(client, model).await;
+            return;
+        }
+        Action::Move { id, target } => {
+            let label = model
+                .columns
+                .iter()
+                .flatten()
+                .find(|task| task.id == id)
+                .map(card_label)
+                .unwrap_or_else(|| id.clone());
+            request(
+                client,
+                FrontendRequest::MoveWorkItemOnBoard {
+                    id,
+                    target,
+                    bypass_dispatch_pause: false,
+                    observed_pause_since_epoch_s: None,
+                },
+            )
+            .await
+            .map(|_| format!("moved {label} to {}", column_title(target.column)))
+        }
+        Action::SetDispatchPaused(paused) => {
+            request(client, FrontendRequest::SetDispatchPaused { paused, reason: None })
+                .await
+                .map(|event| {
+                    if let FrontendEvent::DispatchStateResult { paused, .. } = event {
+                        model.dispatch_paused = paused;
+                    }
+                    if model.dispatch_paused {
+                        "dispatch paused".to_owned()
+                    } else {
+                        "dispatch resumed".to_owned()
+                    }
+                })
+        }
+        Action::Nudge { run_id, text } => request(
+            client,
+            FrontendRequest::ProbeRun {
+                run_id,
+                text,
+                urgent: false,
+            },
+        )
+        .await
+        .and_then(|event| match event {
+            FrontendEvent::ProbeRefused { reason, .. } => Err(format!("nudge refused: {reason}")),
+            _ => Ok("nudge queued".to_owned()),
+        }),
+        Action::Cancel { execution_id } => request(
+            client,
+            FrontendRequest::CancelExecution {
+                execution_id,
+                reason: Some("cancelled from boss tui".to_owned()),
+                queued_only: false,
+            },
+        )
+        .await
+        .map(|_| "execution cancelled".to_owned()),
+        Action::Answer {
+            id,
+            answer,
+            skip,
+            dismiss,
+        } => request(
+            client,
+            FrontendRequest::AnswerAttention {
+                id,
+                answer,
+                skip,
+                dismiss,
+            },
+        )
+        .await
+        .map(|_| {
+            if skip {
+                "skipped".to_owned()
+            } else if dismiss {
+                "dismissed".to_owned()
+            } else {
+                "answered".to_owned()
+            }
+        }),
+    };
+    model.status = Some(outcome.unwrap_or_else(|message| format!("error: {message}")));
+    refresh_product(client, model).await;
+}
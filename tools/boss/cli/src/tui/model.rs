@@ -0,0 +1,645 @@
+//! Screen state and key handling for `boss tui`.
+//!
+//! Deliberately free of terminal and socket I/O: the run loop in
+//! [`super`] feeds the model engine snapshots and key presses, and
+//! performs the [`Action`]s it hands back. That keeps the whole
+//! interaction model — which card a key lands on, what a move means,
+//! when a prompt opens — unit-testable without a pty or an engine.
+
+use boss_engine_board_gesture::BoardRow;
+use boss_protocol::{
+    Attention, AttentionGroup, BoardColumn, BoardDropTarget, BoardGroup, LiveWorkerState, Product, Task, TaskStatus,
+};
+use boss_transcript_markdown::RenderOpts;
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Board columns, left to right. Same four the macOS app renders.
+pub(crate) const COLUMNS: [BoardColumn; 4] = [
+    BoardColumn::Backlog,
+    BoardColumn::Doing,
+    BoardColumn::Review,
+    BoardColumn::Done,
+];
+
+pub(crate) fn column_title(column: BoardColumn) -> &'static str {
+    match column {
+        BoardColumn::Backlog => "Todo",
+        BoardColumn::Doing => "Doing",
+        BoardColumn::Review => "In review",
+        BoardColumn::Done => "Done",
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum Focus {
+    #[default]
+    Board,
+    Attentions,
+}
+
+/// A one-line input the status bar is collecting. While one is open every
+/// key goes to it; `Esc` abandons it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Prompt {
+    /// Free-text answer for attention member `id`.
+    Answer { id: String, buffer: String },
+    /// Text to inject into the worker running `run_id`.
+    Nudge {
+        run_id: String,
+        worker: String,
+        buffer: String,
+    },
+    /// Waiting for `y` before cancelling `execution_id`.
+    ConfirmCancel { execution_id: String, label: String },
+}
+
+/// Something the run loop must do against the engine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Action {
+    Quit,
+    /// Refetch the selected product's board and attention groups.
+    Reload,
+    Move {
+        id: String,
+        target: BoardDropTarget,
+    },
+    SetDispatchPaused(bool),
+    Nudge {
+        run_id: String,
+        text: String,
+    },
+    Cancel {
+        execution_id: String,
+    },
+    Answer {
+        id: String,
+        answer: Option<String>,
+        skip: bool,
+        dismiss: bool,
+    },
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Model {
+    pub(crate) products: Vec<Product>,
+    pub(crate) product_index: usize,
+    /// Cards per [`COLUMNS`] entry, in the order the engine returned them
+    /// (Done newest-first).
+    pub(crate) columns: [Vec<Task>; 4],
+    pub(crate) live_states: Vec<LiveWorkerState>,
+    /// Open attention members across the product's open groups, in group
+    /// order then ordinal — the list the attention pane renders.
+    pub(crate) attentions: Vec<Attention>,
+    pub(crate) dispatch_paused: bool,
+    pub(crate) focus: Focus,
+    pub(crate) column: usize,
+    pub(crate) rows: [usize; 4],
+    pub(crate) attention_row: usize,
+    pub(crate) prompt: Option<Prompt>,
+    /// Rendered transcript tail of the selected card's worker, and the run
+    /// it belongs to.
+    pub(crate) transcript: Option<(String, Vec<String>)>,
+    pub(crate) show_tools: bool,
+    /// Last action outcome or error, shown in the status bar until the
+    /// next one replaces it.
+    pub(crate) status: Option<String>,
+}
+
+impl Model {
+    pub(crate) fn new(products: Vec<Product>, product_index: usize) -> Self {
+        Self {
+            products,
+            product_index,
+            ..Self::default()
+        }
+    }
+
+    pub(crate) fn product(&self) -> Option<&Product> {
+        self.products.get(self.product_index)
+    }
+
+    /// Replace the board from a `WorkTree` reply. Tasks and chores share
+    /// the board; deleted, archived and automation-produced rows are left
+    /// off it, as they are in the app.
+    pub(crate) fn apply_work_tree(&mut self, tasks: Vec<Task>, chores: Vec<Task>) {
+        let mut columns: [Vec<Task>; 4] = Default::default();
+        for task in tasks.into_iter().chain(chores) {
+            if task.deleted_at.is_some() || task.source_automation_id.is_some() || task.status == TaskStatus::Archived {
+                continue;
+            }
+            let position = BoardRow {
+                status: task.status.clone(),
+                autostart: task.autostart,
+                blocked_reason: task.blocked_reason.clone(),
+                merge_queue_state: task.merge_queue_state.clone(),
+            }
+            .position();
+            let index = COLUMNS
+                .iter()
+                .position(|column| *column == position.column)
+                .unwrap_or(0);
+            columns[index].push(task);
+        }
+        columns[3].sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+        self.columns = columns;
+        for (row, cards) in self.rows.iter_mut().zip(&self.columns) {
+            *row = (*row).min(cards.len().saturating_sub(1));
+        }
+    }
+
+    pub(crate) fn apply_live_states(&mut self, states: Vec<LiveWorkerState>) {
+        self.live_states = states;
+        self.live_states.sort_by_key(|state| state.slot_id);
+    }
+
+    /// Replace the attention list from an `AttentionGroupsList` reply.
+    pub(crate) fn apply_attentions(&mut self, groups: &[AttentionGroup], members: Vec<Attention>) {
+        let mut open: Vec<Attention> = members
+            .into_iter()
+            .filter(|member| member.answer_state == "open")
+            .collect();
+        open.sort_by_key(|member| {
+            (
+                groups
+                    .iter()
+                    .position(|group| group.id == member.group_id)
+                    .unwrap_or(usize::MAX),
+                member.ordinal,
+            )
+        });
+        self.attentions = open;
+        self.attention_row = self.attention_row.min(self.attentions.len().saturating_sub(1));
+    }
+
+    /// Store a `RunTranscriptTail` for `run_id`, rendered through
+    /// `transcript-markdown`.
+    pub(crate) fn apply_transcript(&mut self, run_id: String, lines: &[String]) {
+        self.transcript = Some((run_id, render_transcript(lines, !self.show_tools)));
+    }
+
+    pub(crate) fn selected_task(&self) -> Option<&Task> {
+        self.columns[self.column].get(self.rows[self.column])
+    }
+
+    pub(crate) fn selected_attention(&self) -> Option<&Attention> {
+        self.attentions.get(self.attention_row)
+    }
+
+    /// The live worker running `task_id`, if any.
+    pub(crate) fn worker_for(&self, task_id: &str) -> Option<&LiveWorkerState> {
+        self.live_states
+            .iter()
+            .find(|state| state.work_item_id.as_deref() == Some(task_id) && !state.activity.is_terminal())
+    }
+
+    /// Run id whose transcript the tail pane should show: the selected
+    /// card's worker.
+    pub(crate) fn transcript_run_id(&self) -> Option<String> {
+        let task = self.selected_task()?;
+        self.worker_for(&task.id).map(|state| state.run_id.clone())
+    }
+
+    pub(crate) fn handle_key(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            return Some(Action::Quit);
+        }
+        if self.prompt.is_some() {
+            return self.handle_prompt_key(key);
+        }
+        match key.code {
+            KeyCode::Char('q') => return Some(Action::Quit),
+            KeyCode::Tab => {
+                self.focus = match self.focus {
+                    Focus::Board => Focus::Attentions,
+                    Focus::Attentions => Focus::Board,
+                };
+                return None;
+            }
+            KeyCode::Char('[') | KeyCode::Char(']') => return self.switch_product(key.code == KeyCode::Char(']')),
+            KeyCode::Char('r') => return Some(Action::Reload),
+            KeyCode::Char('p') => return Some(Action::SetDispatchPaused(!self.dispatch_paused)),
+            KeyCode::Char('t') => {
+                self.show_tools = !self.show_tools;
+                return None;
+            }
+            _ => {}
+        }
+        match self.focus {
+            Focus::Board => self.handle_board_key(key),
+            Focus::Attentions => self.handle_attention_key(key),
+        }
+    }
+
+    fn switch_product(&mut self, forward: bool) -> Option<Action> {
+        if self.products.len() < 2 {
+            return None;
+        }
+        let len = self.products.len();
+        self.product_index = if forward {
+            (self.product_index + 1) % len
+        } else {
+            (self.product_index + len - 1) % len
+        };
+        self.columns = Default::default();
+        self.rows = [0; 4];
+        self.column = 0;
+        self.attentions.clear();
+        self.attention_row = 0;
+        self.transcript = None;
+        Some(Action::Reload)
+    }
+
+    fn handle_board_key(&mut self, key: KeyEvent) -> Option<Action> {
+        match key.code {
+            KeyCode::Left | KeyCode::Char('h') => self.column = self.column.saturating_sub(1),
+            KeyCode::Right | KeyCode::Char('l') => self.column = (self.column + 1).min(COLUMNS.len() - 1),
+            KeyCode::Up | KeyCode::Char('k') => self.rows[self.column] = self.rows[self.column].saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => {
+                let last = self.columns[self.column].len().saturating_sub(1);
+                self.rows[self.column] = (self.rows[self.column] + 1).min(last);
+            }
+            KeyCode::Char('H') | KeyCode::Char('<') => return self.move_selected(-1),
+            KeyCode::Char('L') | KeyCode::Char('>') => return self.move_selected(1),
+            KeyCode::Char('n') => {
+                let task = self.selected_task()?;
+                let Some(worker) = self.worker_for(&task.id) else {
+                    self.status = Some(format!("{} has no live worker to nudge", card_label(task)));
+                    return None;
+                };
+                self.prompt = Some(Prompt::Nudge {
+                    run_id: worker.run_id.clone(),
+                    worker: worker.name.clone(),
+                    buffer: String::new(),
+                });
+            }
+            KeyCode::Char('x') => {
+                let task = self.selected_task()?;
+                let label = card_label(task);
+                let Some(execution_id) = self.worker_for(&task.id).and_then(|worker| worker.execution_id.clone())
+                else {
+                    self.status = Some(format!("{label} has no running execution to cancel"));
+                    return None;
+                };
+                self.prompt = Some(Prompt::ConfirmCancel { execution_id, label });
+            }
+            _ => {}
+        }
+        None
+    }
+
+    /// Move the selected card one column left (`-1`) or right (`1`). A
+    /// move into Done lands in its completed group; the engine resolves
+    /// what the drop means for the row's status exactly as it does for a
+    /// drag in the app.
+    fn move_selected(&mut self, step: isize) -> Option<Action> {
+        let target = self
+            .column
+            .checked_add_signed(step)
+            .filter(|index| *index < COLUMNS.len())?;
+        let task = self.selected_task()?;
+        let column = COLUMNS[target];
+        let group = (column == BoardColumn::Done).then_some(BoardGroup::Completed);
+        Some(Action::Move {
+            id: task.id.clone(),
+            target: BoardDropTarget::new(column, group),
+        })
+    }
+
+    fn handle_attention_key(&mut self, key: KeyEvent) -> Option<Action> {
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => self.attention_row = self.attention_row.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.attention_row = (self.attention_row + 1).min(self.attentions.len().saturating_sub(1));
+            }
+            KeyCode::Char('y') | KeyCode::Char('n') => {
+                let member = self.selected_attention()?;
+                if member.question_type.as_deref() != Some("yes_no") {
+                    return None;
+                }
+                let answer = if key.code == KeyCode::Char('y') { "yes" } else { "no" };
+                return Some(answer_action(&member.id, Some(answer.to_owned())));
+            }
+            KeyCode::Enter | KeyCode::Char('a') => {
+                let id = self.selected_attention()?.id.clone();
+                self.prompt = Some(Prompt::Answer {
+                    id,
+                    buffer: String::new(),
+                });
+            }
+            KeyCode::Char('s') => {
+                let id = self.selected_attention()?.id.clone();
+                return Some(Action::Answer {
+                    id,
+                    answer: None,
+                    skip: true,
+                    dismiss: false,
+                });
+            }
+            KeyCode::Char('d') => {
+                let id = self.selected_attention()?.id.clone();
+                return Some(Action::Answer {
+                    id,
+                    answer: None,
+                    skip: false,
+                    dismiss: true,
+                });
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn handle_prompt_key(&mut self, key: KeyEvent) -> Option<Action> {
+        let prompt = self.prompt.take()?;
+        match prompt {
+            Prompt::ConfirmCancel { execution_id, .. } => {
+                (key.code == KeyCode::Char('y')).then_some(Action::Cancel { execution_id })
+            }
+            Prompt::Answer { id, mut buffer } => match key.code {
+                KeyCode::Esc => None,
+                KeyCode::Enter if buffer.trim().is_empty() => None,
+                KeyCode::Enter => Some(answer_action(&id, Some(buffer.trim().to_owned()))),
+                code => {
+                    edit_buffer(&mut buffer, code);
+                    self.prompt = Some(Prompt::Answer { id, buffer });
+                    None
+                }
+            },
+            Prompt::Nudge {
+                run_id,
+                worker,
+                mut buffer,
+            } => match key.code {
+                KeyCode::Esc => None,
+                KeyCode::Enter if buffer.trim().is_empty() => None,
+                KeyCode::Enter => Some(Action::Nudge {
+                    run_id,
+                    text: buffer.trim().to_owned(),
+                }),
+                code => {
+                    edit_buffer(&mut buffer, code);
+                    self.prompt = Some(Prompt::Nudge { run_id, worker, buffer });
+                    None
+                }
+            },
+        }
+    }
+}
+
+fn answer_action(id: &str, answer: Option<String>) -> Action {
+    Action::Answer {
+        id: id.to_owned(),
+        answer,
+        skip: false,
+        dismiss: false,
+    }
+}
+
+fn edit_buffer(buffer: &mut String, code: KeyCode) {
+    match code {
+        KeyCode::Char(ch) => buffer.push(ch),
+        KeyCode::Backspace => {
+            buffer.pop();
+        }
+        _ => {}
+    }
+}
+
+/// `T42 Fix the flaky test`, or the bare name for a row without a short id.
+pub(crate) fn card_label(task: &Task) -> String {
+    match task.short_id {
+        Some(n) => format!("T{n} {}", task.name),
+        None => task.name.clone(),
+    }
+}
+
+/// Render raw transcript JSONL through `transcript-markdown`'s plain-text
+/// renderer. A dialect it can't identify (a driver whose native log only
+/// the engine knows how to normalize) falls back to the raw lines under a
+/// one-line note rather than rendering as empty.
+pub(crate) fn render_transcript(lines: &[String], hide_tools: bool) -> Vec<String> {
+    let joined = lines.join("\n");
+    match boss_transcript_markdown::parse_transcript_checked(&joined) {
+        Ok(events) => {
+            let opts = RenderOpts {
+                hide_tools,
+                ..Default::default()
+            };
+            boss_transcript_markdown::render_text(&events, &opts)
+                .lines()
+                .map(str::to_owned)
+                .collect()
+        }
+        Err(err) => std::iter::once(format!("({err}; showing raw lines)"))
+            .chain(lines.iter().cloned())
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use boss_protocol::{TaskKind, WorkItemBinding, WorkerActivity};
+
+    fn task(id: &str, short_id: i64, status: TaskStatus) -> Task {
+        Task::builder()
+            .id(id)
+            .short_id(short_id)
+            .product_id("prod_1")
+            .kind(TaskKind::Chore)
+            .name(format!("card {short_id}"))
+            .description("")
+            .status(status)
+            .created_at("")
+            .updated_at("")
+            .build()
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn worker(slot_id: u8, task_id: &str) -> LiveWorkerState {
+        let mut state = LiveWorkerState::new_spawning(
+            slot_id,
+            format!("exec_{slot_id}"),
+            "opus",
+            0,
+            Some(WorkItemBinding {
+                work_item_id: task_id.to_owned(),
+                work_item_name: "card".to_owned(),
+                execution_id: format!("exec_{slot_id}"),
+            }),
+        );
+        state.activity = WorkerActivity::Working;
+        state
+    }
+
+    fn board() -> Model {
+        let mut model = Model::new(Vec::new(), 0);
+        let mut autostarted = task("task_queued", 2, TaskStatus::Todo);
+        autostarted.autostart = true;
+        let mut automation = task("task_auto", 5, TaskStatus::Todo);
+        automation.source_automation_id = Some("auto_1".to_owned());
+        model.apply_work_tree(
+            vec![
+                task("task_backlog", 1, TaskStatus::Todo),
+                autostarted,
+                task("task_active", 3, TaskStatus::Active),
+                automation,
+                task("task_archived", 6, TaskStatus::Archived),
+            ],
+            vec![task("chore_review", 4, TaskStatus::InReview)],
+        );
+        model
+    }
+
+    fn ids(cards: &[Task]) -> Vec<&str> {
+        cards.iter().map(|task| task.id.as_str()).collect()
+    }
+
+    #[test]
+    fn work_tree_lands_in_the_apps_columns() {
+        let model = board();
+        assert_eq!(ids(&model.columns[0]), vec!["task_backlog"]);
+        // An autostarted `todo` row is queued to dispatch, so it renders in Doing.
+        assert_eq!(ids(&model.columns[1]), vec!["task_queued", "task_active"]);
+        assert_eq!(ids(&model.columns[2]), vec!["chore_review"]);
+        assert!(model.columns[3].is_empty(), "archived rows stay off the board");
+    }
+
+    #[test]
+    fn shifted_arrows_move_the_selected_card_one_column() {
+        let mut model = board();
+        assert_eq!(
+            model.handle_key(key(KeyCode::Char('L'))),
+            Some(Action::Move {
+                id: "task_backlog".to_owned(),
+                target: BoardDropTarget::new(BoardColumn::Doing, None),
+            })
+        );
+        assert_eq!(
+            model.handle_key(key(KeyCode::Char('H'))),
+            None,
+            "no column left of Todo"
+        );
+
+        model.column = 2;
+        assert_eq!(
+            model.handle_key(key(KeyCode::Char('>'))),
+            Some(Action::Move {
+                id: "chore_review".to_owned(),
+                target: BoardDropTarget::new(BoardColumn::Done, Some(BoardGroup::Completed)),
+            })
+        );
+    }
+
+    #[test]
+    fn nudge_prompts_for_text_and_sends_it_to_the_cards_worker() {
+        let mut model = board();
+        model.apply_live_states(vec![worker(3, "task_active")]);
+        model.column = 1;
+        model.rows[1] = 1;
+
+        assert_eq!(model.handle_key(key(KeyCode::Char('n'))), None);
+        for ch in "rebase".chars() {
+            assert_eq!(model.handle_key(key(KeyCode::Char(ch))), None);
+        }
+        assert_eq!(
+            model.handle_key(key(KeyCode::Enter)),
+            Some(Action::Nudge {
+                run_id: "exec_3".to_owned(),
+                text: "rebase".to_owned(),
+            })
+        );
+        assert_eq!(model.prompt, None);
+    }
+
+    #[test]
+    fn nudge_without_a_worker_reports_instead_of_prompting() {
+        let mut model = board();
+        assert_eq!(model.handle_key(key(KeyCode::Char('n'))), None);
+        assert_eq!(model.prompt, None);
+        assert!(model.status.as_deref().unwrap().contains("T1"));
+    }
+
+    #[test]
+    fn cancel_needs_confirmation() {
+        let mut model = board();
+        model.apply_live_states(vec![worker(3, "task_active")]);
+        model.column = 1;
+        model.rows[1] = 1;
+
+        model.handle_key(key(KeyCode::Char('x')));
+        assert_eq!(
+            model.handle_key(key(KeyCode::Char('n'))),
+            None,
+            "anything but y abandons"
+        );
+        model.handle_key(key(KeyCode::Char('x')));
+        assert_eq!(
+            model.handle_key(key(KeyCode::Char('y'))),
+            Some(Action::Cancel {
+                execution_id: "exec_3".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn attention_pane_answers_yes_no_inline_and_prompts_otherwise() {
+        let mut model = board();
+        let yes_no = Attention::builder()
+            .id("atn_1")
+            .group_id("atg_1")
+            .ordinal(1)
+            .created_at("")
+            .question_type("yes_no")
+            .prompt_text("Ship it?")
+            .build();
+        let prompt = Attention::builder()
+            .id("atn_2")
+            .group_id("atg_1")
+            .ordinal(2)
+            .created_at("")
+            .question_type("prompt")
+            .prompt_text("Which branch?")
+            .build();
+        let answered = Attention::builder()
+            .id("atn_3")
+            .group_id("atg_1")
+            .ordinal(3)
+            .created_at("")
+            .answer_state("answered")
+            .build();
+        model.apply_attentions(&[], vec![prompt, answered, yes_no]);
+        assert_eq!(
+            model.attentions.iter().map(|a| a.id.as_str()).collect::<Vec<_>>(),
+            vec!["atn_1", "atn_2"]
+        );
+
+        model.handle_key(key(KeyCode::Tab));
+        assert_eq!(
+            model.handle_key(key(KeyCode::Char('y'))),
+            Some(answer_action("atn_1", Some("yes".to_owned())))
+        );
+
+        model.handle_key(key(KeyCode::Char('j')));
+        assert_eq!(model.handle_key(key(KeyCode::Char('y'))), None, "not a yes/no question");
+        model.handle_key(key(KeyCode::Enter));
+        for ch in "main".chars() {
+            model.handle_key(key(KeyCode::Char(ch)));
+        }
+        assert_eq!(
+            model.handle_key(key(KeyCode::Enter)),
+            Some(answer_action("atn_2", Some("main".to_owned())))
+        );
+    }
+
+    #[test]
+    fn unrecognised_transcript_dialect_falls_back_to_raw_lines() {
+        let raw = vec![r#"{"jsonrpc":"2.0","method":"session/update"}"#.to_owned()];
+        let rendered = render_transcript(&raw, false);
+        assert_eq!(rendered.len(), 2);
+        assert_eq!(rendered[1], raw[0]);
+    }
+}
@@ -0,0 +1,221 @@
+//! Draws a [`Model`] into a ratatui frame.
+//!
+//! Layout, top to bottom: a header line (product, dispatch state), the
+//! four-column board, a row splitting workers / attentions / transcript
+//! tail, and a status line that doubles as the prompt.
+
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+
+use super::model::{COLUMNS, Focus, Model, Prompt, card_label, column_title};
+
+const HELP: &str = "q quit · tab focus · [ ] product · h/l/j/k move · H/L move card · n nudge · x cancel · p pause · t tools · r reload";
+const ATTENTION_HELP: &str = "y/n answer yes-no · a/enter answer · s skip · d dismiss · tab board";
+
+pub(crate) fn draw(frame: &mut Frame, model: &Model) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Percentage(55),
+            Constraint::Min(6),
+            Constraint::Length(1),
+        ])
+        .split(frame.area());
+
+    draw_header(frame, rows[0], model);
+    draw_board(frame, rows[1], model);
+
+    let panes = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(30),
+            Constraint::Percentage(30),
+            Constraint::Percentage(40),
+        ])
+        .split(rows[2]);
+    draw_workers(frame, panes[0], model);
+    draw_attentions(frame, panes[1], model);
+    draw_transcript(frame, panes[2], model);
+
+    draw_status(frame, rows[3], model);
+}
+
+fn draw_header(frame: &mut Frame, area: Rect, model: &Model) {
+    let product = model
+        .product()
+        .map(|product| product.name.clone())
+        .unwrap_or_else(|| "(no products)".to_owned());
+    let dispatch = if model.dispatch_paused {
+        Span::styled(" dispatch paused ", Style::default().fg(Color::Black).bg(Color::Yellow))
+    } else {
+        Span::styled(" dispatch running ", Style::default().fg(Color::Black).bg(Color::Green))
+    };
+    let line = Line::from(vec![
+        Span::styled(
+            format!(" boss · {product} "),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        dispatch,
+        Span::raw(format!(" {} products", model.products.len())),
+    ]);
+    frame.render_widget(Paragraph::new(line), area);
+}
+
+fn draw_board(frame: &mut Frame, area: Rect, model: &Model) {
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(1, 4); 4])
+        .split(area);
+    for (index, column) in COLUMNS.iter().enumerate() {
+        let cards = &model.columns[index];
+        let items: Vec<ListItem> = cards
+            .iter()
+            .map(|task| {
+                let mut spans = vec![Span::raw(card_label(task))];
+                if let Some(worker) = model.worker_for(&task.id) {
+                    spans.push(Span::styled(
+                        format!(" [{}]", worker.name),
+                        Style::default().fg(Color::Cyan),
+                    ));
+                }
+                if let Some(reason) = &task.blocked_reason {
+                    spans.push(Span::styled(format!(" ({reason})"), Style::default().fg(Color::Red)));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+        let focused = model.focus == Focus::Board && model.column == index;
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(border_style(focused))
+            .title(format!("{} ({})", column_title(*column), cards.len()));
+        let mut state = ListState::default();
+        if focused && !cards.is_empty() {
+            state.select(Some(model.rows[index]));
+        }
+        frame.render_stateful_widget(
+            List::new(items).block(block).highlight_style(highlight_style()),
+            columns[index],
+            &mut state,
+        );
+    }
+}
+
+fn draw_workers(frame: &mut Frame, area: Rect, model: &Model) {
+    let items: Vec<ListItem> = model
+        .live_states
+        .iter()
+        .filter(|state| !state.activity.is_terminal())
+        .map(|state| {
+            let work = state.work_item_name.as_deref().unwrap_or("idle");
+            let mut lines = vec![Line::from(vec![
+                Span::styled(
+                    format!("{} ", state.name),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(state.activity.as_str(), activity_style(state.activity.as_str())),
+                Span::raw(format!(" · {work}")),
+            ])];
+            let detail = state.live_status.as_deref().or(state.current_tool.as_deref());
+            if let Some(detail) = detail {
+                lines.push(Line::styled(
+                    format!("  {detail}"),
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            ListItem::new(lines)
+        })
+        .collect();
+    let block = Block::default().borders(Borders::ALL).title("Workers");
+    frame.render_widget(List::new(items).block(block), area);
+}
+
+fn draw_attentions(frame: &mut Frame, area: Rect, model: &Model) {
+    let items: Vec<ListItem> = model
+        .attentions
+        .iter()
+        .map(|member| {
+            let text = member
+                .prompt_text
+                .as_deref()
+                .or(member.proposed_name.as_deref())
+                .unwrap_or("(no prompt)");
+            let kind = member.question_type.as_deref().unwrap_or("followup");
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("[{kind}] "), Style::default().fg(Color::Magenta)),
+                Span::raw(text.to_owned()),
+            ]))
+        })
+        .collect();
+    let focused = model.focus == Focus::Attentions;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(border_style(focused))
+        .title(format!("Attentions ({})", model.attentions.len()));
+    let mut state = ListState::default();
+    if focused && !model.attentions.is_empty() {
+        state.select(Some(model.attention_row));
+    }
+    frame.render_stateful_widget(
+        List::new(items).block(block).highlight_style(highlight_style()),
+        area,
+        &mut state,
+    );
+}
+
+fn draw_transcript(frame: &mut Frame, area: Rect, model: &Model) {
+    let (title, lines) = match &model.transcript {
+        Some((run_id, lines)) => (format!("Transcript · {run_id}"), lines.as_slice()),
+        None => ("Transcript".to_owned(), &[][..]),
+    };
+    // Show the tail: as many trailing lines as fit inside the borders.
+    let visible = usize::from(area.height.saturating_sub(2));
+    let start = lines.len().saturating_sub(visible);
+    let text: Vec<Line> = lines[start..].iter().map(|line| Line::raw(line.as_str())).collect();
+    let block = Block::default().borders(Borders::ALL).title(title);
+    frame.render_widget(Paragraph::new(text).block(block).wrap(Wrap { trim: false }), area);
+}
+
+fn draw_status(frame: &mut Frame, area: Rect, model: &Model) {
+    let line = match &model.prompt {
+        Some(Prompt::Answer { buffer, .. }) => Line::from(format!("answer> {buffer}▏")),
+        Some(Prompt::Nudge { worker, buffer, .. }) => Line::from(format!("nudge {worker}> {buffer}▏")),
+        Some(Prompt::ConfirmCancel { label, .. }) => Line::styled(
+            format!("cancel {label}? (y to confirm)"),
+            Style::default().fg(Color::Yellow),
+        ),
+        None => match &model.status {
+            Some(status) => Line::raw(status.as_str()),
+            None if model.focus == Focus::Attentions => {
+                Line::styled(ATTENTION_HELP, Style::default().fg(Color::DarkGray))
+            }
+            None => Line::styled(HELP, Style::default().fg(Color::DarkGray)),
+        },
+    };
+    frame.render_widget(Paragraph::new(line), area);
+}
+
+fn border_style(focused: bool) -> Style {
+    if focused {
+        Style::default().fg(Color::Cyan)
+    } else {
+        Style::default()
+    }
+}
+
+fn highlight_style() -> Style {
+    Style::default().add_modifier(Modifier::REVERSED)
+}
+
+fn activity_style(activity: &str) -> Style {
+    match activity {
+        "working" => Style::default().fg(Color::Green),
+        "waiting_for_input" => Style::default().fg(Color::Yellow),
+        "errored" => Style::default().fg(Color::Red),
+        _ => Style::default().fg(Color::DarkGray),
+    }
+}
@@ -4,7 +4,8 @@
 //! correlated request/response API on top of the framed JSON protocol defined
 //! in [`boss_protocol`]. Engine discovery (socket path resolution + optional
 //! autostart of the engine binary) lives behind [`Discovery`] so the CLI, tests,
-//! and future TUI/web frontends share one set of rules.
+//! and future TUI/web frontends share one set of rules. An engine on another
+//! machine is reached through an SSH tunnel; see [`remote`].
 
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
@@ -13,11 +14,14 @@ use std::time::Duration;
 
 use anyhow::{Context, Result, bail};
 use boss_protocol::{FrontendEvent, FrontendEventEnvelope, FrontendRequest, FrontendRequestEnvelope};
-use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader, Lines};
 use tokio::net::UnixStream;
-use tokio::net::unix::{OwnedReadHalf, OwnedWriteHalf};
 use tokio::time::sleep;
 
+pub mod remote;
+
+pub use remote::SshEndpoint;
+
 pub const DEFAULT_SOCKET_PATH: &str = "/tmp/boss-engine.sock";
 pub const DEFAULT_PID_PATH: &str = "/tmp/boss-engine.pid";
 pub const DEFAULT_ENGINE_START_TIMEOUT: Duration = Duration::from_secs(5);
@@ -57,6 +61,10 @@ pub struct Discovery {
     pub engine: EngineCommand,
     pub launch_directory: PathBuf,
     pub start_timeout: Duration,
+    /// Set when `socket_path` is an `ssh://` endpoint. The engine is then
+    /// on another machine: it is reached through a tunnel and never
+    /// autostarted from here.
+    pub remote: Option<SshEndpoint>,
 }
 
 impl Discovery {
@@ -66,6 +74,7 @@ impl Discovery {
             .map(str::to_owned)
             .or_else(|| std::env::var("BOSS_SOCKET_PATH").ok())
             .unwrap_or_else(|| DEFAULT_SOCKET_PATH.to_owned());
+        let remote = SshEndpoint::parse(&socket_path)?;
         let pid_file_path = std::env::var("BOSS_ENGINE_PID_PATH").unwrap_or_else(|_| DEFAULT_PID_PATH.to_owned());
         let launch_directory = resolve_launch_directory()?;
         let engine = resolve_engine_command(&socket_path)?;
@@ -77,6 +86,7 @@ impl Discovery {
             engine,
             launch_directory,
             start_timeout: DEFAULT_ENGINE_START_TIMEOUT,
+            remote,
         })
     }
 
@@ -86,16 +96,35 @@ impl Discovery {
     }
 }
 
-/// Single-connection client over the engine's frontend socket.
+type ClientReader = Box<dyn AsyncRead + Send + Unpin>;
+type ClientWriter = Box<dyn AsyncWrite + Send + Unpin>;
+
+/// Single-connection client over the engine's frontend socket, or an SSH
+/// tunnel to a remote engine's.
 pub struct BossClient {
-    reader: Lines<BufReader<OwnedReadHalf>>,
-    writer: OwnedWriteHalf,
+    reader: Lines<BufReader<ClientReader>>,
+    writer: ClientWriter,
     next_request_id: AtomicU64,
+    /// The `ssh` process carrying a remote connection. Held so the tunnel
+    /// lives exactly as long as the client; killed on drop.
+    _tunnel: Option<tokio::process::Child>,
 }
 
 impl BossClient {
+    fn from_halves(reader: ClientReader, writer: ClientWriter, tunnel: Option<tokio::process::Child>) -> Self {
+        Self {
+            reader: BufReader::new(reader).lines(),
+            writer,
+            next_request_id: AtomicU64::new(1),
+            _tunnel: tunnel,
+        }
+    }
+
     /// Connect to the engine, optionally autostarting it per the discovery profile.
     pub async fn connect(discovery: &Discovery) -> Result<Self> {
+        if let Some(endpoint) = &discovery.remote {
+            return Self::connect_ssh(endpoint).await;
+        }
         if let Ok(client) = Self::connect_socket(&discovery.socket_path).await {
             return Ok(client);
         }
@@ -114,11 +143,56 @@ impl BossClient {
             .await
             .with_context(|| format!("failed to connect to engine socket {socket_path}"))?;
         let (read_half, write_half) = stream.into_split();
-        Ok(Self {
-            reader: BufReader::new(read_half).lines(),
-            writer: write_half,
-            next_request_id: AtomicU64::new(1),
-        })
+        Ok(Self::from_halves(Box::new(read_half), Box::new(write_half), None))
+    }
+
+    /// Connect to a remote engine through `ssh` and the far side's
+    /// `boss stdio-proxy`. Waits for the engine's `Hello` so a bad host,
+    /// refused key or missing remote `boss` fails here, with `ssh`'s own
+    /// message, rather than on the first request — and so does a tunnel
+    /// whose first event is anything but `Hello`.
+    pub async fn connect_ssh(endpoint: &SshEndpoint) -> Result<Self> {
+        let remote::SshTunnel {
+            mut child,
+            stdin,
+            stdout,
+        } = remote::spawn_tunnel(endpoint)?;
+        let mut client = Self::from_halves(Box::new(stdout), Box::new(stdin), None);
+
+        let hello = tokio::time::timeout(remote::SSH_CONNECT_TIMEOUT, client.next_event()).await;
+        match hello {
+            Ok(Ok(Some(first))) => remote::expect_hello(endpoint, &first)?,
+            Ok(Ok(None)) | Ok(Err(_)) => {
+                let stderr = remote::tunnel_failure(&mut child).await;
+                bail!(
+                    "could not reach the boss engine at {} over ssh{}",
+                    endpoint.display(),
+                    if stderr.is_empty() {
+                        String::new()
+                    } else {
+                        format!(": {stderr}")
+                    }
+                );
+            }
+            Err(_) => bail!(
+                "timed out after {}s waiting for the boss engine at {} over ssh",
+                remote::SSH_CONNECT_TIMEOUT.as_secs(),
+                endpoint.display()
+            ),
+        }
+
+        // Keep draining the tunnel's stderr so a chatty remote shell can
+        // never fill the pipe and stall the connection.
+        if let Some(stderr) = child.stderr.take() {
+            tokio::spawn(async move {
+                let mut lines = BufReader::new(stderr).lines();
+                while let Ok(Some(line)) = lines.next_line().await {
+                    tracing::debug!(%line, "ssh tunnel stderr");
+                }
+            });
+        }
+        client._tunnel = Some(child);
+        Ok(client)
     }
 
     /// Send a request and wait for the matching response by `request_id`.
@@ -145,6 +219,38 @@ impl BossClient {
 
         bail!("engine closed the socket before returning a response")
     }
+
+    /// Join `topics` on this connection and return the topic list the
+    /// engine confirmed. Pushes for those topics then arrive interleaved
+    /// with responses; [`Self::send_request`] skips past them, so a
+    /// frontend that wants them keeps one connection for pushes (read with
+    /// [`Self::next_event`]) and another for requests.
+    pub async fn subscribe(&mut self, topics: &[String]) -> Result<Vec<String>> {
+        match self
+            .send_request(&FrontendRequest::Subscribe {
+                topics: topics.to_vec(),
+            })
+            .await?
+        {
+            FrontendEvent::Subscribed { topics, .. } => Ok(topics),
+            other => bail!("unexpected response to Subscribe: {other:?}"),
+        }
+    }
+
+    /// Read the next envelope off the socket, whatever it is: a topic push
+    /// (`request_id` is `None`) or a response. `Ok(None)` once the engine
+    /// has closed the socket.
+    pub async fn next_event(&mut self) -> Result<Option<FrontendEventEnvelope>> {
+        while let Some(line) = self.reader.next_line().await? {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let envelope: FrontendEventEnvelope =
+                serde_json::from_str(&line).with_context(|| format!("failed to decode engine event: {line}"))?;
+            return Ok(Some(envelope));
+        }
+        Ok(None)
+    }
 }
 
 impl BossClient {
@@ -206,6 +312,12 @@ pub fn read_pid_file(pid_file_path: &str) -> Option<u32> {
 }
 
 pub async fn ensure_engine_running(discovery: &Discovery) -> Result<()> {
+    if let Some(endpoint) = &discovery.remote {
+        bail!(
+            "the boss engine at {} is remote; start it on that host, not from here",
+            endpoint.display()
+        );
+    }
     if engine_socket_reachable(&discovery.socket_path).await {
         return Ok(());
     }
@@ -707,6 +819,53 @@ mod tests {
         );
     }
 
+    /// `subscribe` returns on its own response even when a push beat it
+    /// onto the wire, and `next_event` then hands back later pushes
+    /// untouched rather than discarding them the way `send_request` does.
+    #[tokio::test]
+    async fn subscribe_then_next_event_yields_topic_pushes() {
+        let tmp = tempfile::tempdir().unwrap();
+        let socket_path = tmp.path().join("engine.sock");
+        let listener = tokio::net::UnixListener::bind(&socket_path).unwrap();
+        let engine = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (read_half, mut write_half) = stream.into_split();
+            let mut lines = BufReader::new(read_half).lines();
+            let request: FrontendRequestEnvelope =
+                serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+            let push = FrontendEventEnvelope {
+                request_id: None,
+                revision: None,
+                payload: FrontendEvent::WorkerLiveStatesList { states: Vec::new() },
+            };
+            let reply = FrontendEventEnvelope::response(
+                request.request_id,
+                FrontendEvent::Subscribed {
+                    topics: vec!["worker.live_states".to_owned()],
+                    current_revision: 7,
+                },
+            );
+            for envelope in [&push, &reply, &push] {
+                let line = serde_json::to_string(envelope).unwrap();
+                write_half.write_all(format!("{line}\n").as_bytes()).await.unwrap();
+            }
+        });
+
+        let mut client = BossClient::connect_socket(socket_path.to_str().unwrap()).await.unwrap();
+        let topics = client.subscribe(&["worker.live_states".to_owned()]).await.unwrap();
+        assert_eq!(topics, vec!["worker.live_states".to_owned()]);
+
+        let pushed = client.next_event().await.unwrap().expect("a push after the reply");
+        assert_eq!(pushed.request_id, None);
+        assert!(matches!(pushed.payload, FrontendEvent::WorkerLiveStatesList { .. }));
+
+        engine.await.unwrap();
+        assert!(
+            client.next_event().await.unwrap().is_none(),
+            "closed socket reads as None"
+        );
+    }
+
     #[test]
     fn sibling_of_exe_resolves_engine_from_installed_app_bundle() {
         // Model the installed bundle layout rather than a Bazel runfiles
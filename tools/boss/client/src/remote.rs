@@ -0,0 +1,511 @@
+//! Reaching an engine on another machine over SSH.
+//!
+//! The engine only listens on a Unix-domain socket, and that stays true: a
+//! remote client runs `ssh <host> boss --socket-path <path> stdio-proxy`,
+//! and the proxy on the far side bridges its stdin/stdout to the engine's
+//! socket. SSH carries authentication, encryption and host verification, so
+//! the engine grows no network listener and no second credential model —
+//! whoever can log in to the engine host as its user can drive the engine,
+//! which was already true of anyone with a shell there.
+//!
+//! An endpoint is written as a socket path with an `ssh://` scheme, so it
+//! goes wherever a socket path already does (`--socket-path`,
+//! `BOSS_SOCKET_PATH`):
+//!
+//! ```text
+//! ssh://[user@]host[:port][/path/to/boss-engine.sock]
+//! ```
+//!
+//! An IPv6 host that takes a port goes in brackets (`ssh://[::1]:2222`).
+//!
+//! ## Peer classification
+//!
+//! Before relaying anything, the proxy declares itself with
+//! `DeclareRemotePeer`, so the engine classifies the connection as
+//! `PeerClass::Remote` — never a worker. The reply is checked, not relayed:
+//! if the engine did not accept the declaration, the proxy exits with an
+//! error rather than relay a connection scoped as local. The declaration can
+//! only add a label: a worker that runs the proxy locally is still found by
+//! the engine's process-ancestry walk, which it checks first.
+
+use std::process::Stdio;
+use std::time::Duration;
+
+use anyhow::{Context, Result, bail};
+use boss_protocol::{FrontendEvent, FrontendEventEnvelope, FrontendRequest, FrontendRequestEnvelope};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::UnixStream;
+use tokio::process::{Child, Command};
+
+use crate::DEFAULT_SOCKET_PATH;
+
+/// URL scheme marking a socket path as a remote endpoint.
+pub const SSH_SCHEME: &str = "ssh://";
+
+/// Env var naming the `boss` binary on the remote host, for hosts where it
+/// is not on the login shell's `PATH`.
+pub const REMOTE_BOSS_BIN_ENV: &str = "BOSS_REMOTE_BOSS_BIN";
+
+/// Transport label the proxy declares. Logged by the engine and echoed back
+/// in `RemotePeerDeclared`.
+pub const SSH_TRANSPORT: &str = "ssh";
+
+/// How long to wait for the engine's `Hello` through a fresh tunnel. Covers
+/// the SSH handshake and the proxy's own startup.
+pub const SSH_CONNECT_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Request id of the proxy's `DeclareRemotePeer`. The reply carrying it is
+/// checked rather than relayed.
+const DECLARATION_REQUEST_ID: &str = "stdio-proxy-declare";
+
+/// A parsed `ssh://` endpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SshEndpoint {
+    /// `user@host` or `host`, passed to `ssh` verbatim so `~/.ssh/config`
+    /// aliases work.
+    pub destination: String,
+    pub port: Option<u16>,
+    /// The engine socket on the remote host.
+    pub socket_path: String,
+    /// `boss` on the remote host.
+    pub remote_boss: String,
+}
+
+impl SshEndpoint {
+    /// Parse `raw` if it is an `ssh://` endpoint. `Ok(None)` for a plain
+    /// socket path.
+    pub fn parse(raw: &str) -> Result<Option<Self>> {
+        let Some(rest) = raw.strip_prefix(SSH_SCHEME) else {
+            return Ok(None);
+        };
+        let (authority, socket_path) = match rest.find('/') {
+            Some(index) => (&rest[..index], rest[index..].to_owned()),
+            None => (rest, DEFAULT_SOCKET_PATH.to_owned()),
+        };
+        let (destination, port) =
+            split_port(authority).with_context(|| format!("invalid host or port in remote engine endpoint {raw}"))?;
+        let host = destination
+            .rsplit_once('@')
+            .map_or(destination.as_str(), |(_, host)| host);
+        if host.is_empty() {
+            bail!("remote engine endpoint {raw} names no host");
+        }
+        // `ssh` would read a leading `-` as an option.
+        if destination.starts_with('-') {
+            bail!("remote engine endpoint {raw} has an invalid destination");
+        }
+        Ok(Some(Self {
+            destination,
+            port,
+            socket_path,
+            remote_boss: std::env::var(REMOTE_BOSS_BIN_ENV)
+                .ok()
+                .filter(|bin| !bin.trim().is_empty())
+                .unwrap_or_else(|| "boss".to_owned()),
+        }))
+    }
+
+    /// `ssh` argv (program excluded). `BatchMode` because there is no
+    /// terminal to prompt on once stdio is the protocol stream: a missing
+    /// key must fail fast with a message rather than hang.
+    pub fn ssh_args(&self) -> Vec<String> {
+        let mut args = vec!["-T".to_owned(), "-o".to_owned(), "BatchMode=yes".to_owned()];
+        if let Some(port) = self.port {
+            args.push("-p".to_owned());
+            args.push(port.to_string());
+        }
+        args.push(self.destination.clone());
+        args.push("--".to_owned());
+        args.push(self.remote_command());
+        args
+    }
+
+    /// The command line the remote login shell runs. One string, quoted,
+    /// because `ssh` joins its trailing arguments with spaces and hands the
+    /// result to that shell.
+    pub fn remote_command(&self) -> String {
+        [
+            self.remote_boss.as_str(),
+            "--socket-path",
+            self.socket_path.as_str(),
+            "stdio-proxy",
+        ]
+        .iter()
+        .map(|part| shlex::try_quote(part).map_or_else(|_| (*part).to_owned(), |quoted| quoted.into_owned()))
+        .collect::<Vec<_>>()
+        .join(" ")
+    }
+
+    /// Display form, for error messages.
+    pub fn display(&self) -> String {
+        // Re-bracket an IPv6 host so the result parses back the same.
+        let destination = match self.destination.rsplit_once('@') {
+            Some((user, host)) if host.contains(':') => format!("{user}@[{host}]"),
+            None if self.destination.contains(':') => format!("[{}]", self.destination),
+            _ => self.destination.clone(),
+        };
+        match self.port {
+            Some(port) => format!("{SSH_SCHEME}{destination}:{port}{}", self.socket_path),
+            None => format!("{SSH_SCHEME}{destination}{}", self.socket_path),
+        }
+    }
+}
+
+/// Split `[user@]host[:port]` into the `ssh` destination and port. A
+/// bracketed host (`[::1]:2222`, `user@[fe80::1]`) loses its brackets,
+/// since `ssh` wants a bare IPv6 address. Otherwise only a single
+/// trailing `:digits` is a port: `fe80::1` has more than one colon and
+/// is a bare IPv6 host, not a host and port.
+fn split_port(authority: &str) -> Result<(String, Option<u16>)> {
+    let (user, host) = match authority.rsplit_once('@') {
+        Some((user, host)) => (Some(user), host),
+        None => (None, authority),
+    };
+    let (host, port) = if let Some(bracketed) = host.strip_prefix('[') {
+        let Some((address, rest)) = bracketed.split_once(']') else {
+            bail!("unclosed `[` in `{authority}`");
+        };
+        match rest {
+            "" => (address, None),
+            _ => match rest.strip_prefix(':') {
+                Some(port) => (address, Some(port)),
+                None => bail!("unexpected `{rest}` after `]` in `{authority}`"),
+            },
+        }
+    } else {
+        match host.split_once(':') {
+            Some((name, port)) if !port.contains(':') => (name, Some(port)),
+            _ => (host, None),
+        }
+    };
+    let port = port
+        .map(|port| port.parse::<u16>().with_context(|| format!("`{port}` is not a port")))
+        .transpose()?;
+    let destination = match user {
+        Some(user) => format!("{user}@{host}"),
+        None => host.to_owned(),
+    };
+    Ok((destination, port))
+}
+
+/// A running `ssh` tunnel: the child (killed when dropped) and its stdio.
+pub(crate) struct SshTunnel {
+    pub(crate) child: Child,
+    pub(crate) stdin: tokio::process::ChildStdin,
+    pub(crate) stdout: tokio::process::ChildStdout,
+}
+
+pub(crate) fn spawn_tunnel(endpoint: &SshEndpoint) -> Result<SshTunnel> {
+    let mut child = Command::new("ssh")
+        .args(endpoint.ssh_args())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .with_context(|| format!("failed to run ssh for remote engine {}", endpoint.display()))?;
+    let stdin = child.stdin.take().context("ssh child has no stdin")?;
+    let stdout = child.stdout.take().context("ssh child has no stdout")?;
+    Ok(SshTunnel { child, stdin, stdout })
+}
+
+/// Check that the first event through a fresh tunnel is the engine's
+/// `Hello`. Anything else means the far side is not a boss engine socket —
+/// `--socket-path` names some other service, or a login script wrote to
+/// stdout — and no request should go down it.
+pub(crate) fn expect_hello(endpoint: &SshEndpoint, first: &FrontendEventEnvelope) -> Result<()> {
+    if let FrontendEvent::Hello { .. } = first.payload {
+        return Ok(());
+    }
+    bail!(
+        "the remote end of {} is not a boss engine: expected `hello` first, got `{}`",
+        endpoint.display(),
+        event_type(&first.payload)
+    )
+}
+
+/// The wire `type` tag of `event`, for error messages.
+fn event_type(event: &FrontendEvent) -> String {
+    serde_json::to_value(event)
+        .ok()
+        .and_then(|value| value.get("type").and_then(|kind| kind.as_str()).map(str::to_owned))
+        .unwrap_or_else(|| "an unrecognised event".to_owned())
+}
+
+/// What `ssh` printed before the tunnel failed. Best-effort: the child is
+/// given a moment to exit so its stderr is complete.
+pub(crate) async fn tunnel_failure(child: &mut Child) -> String {
+    let _ = tokio::time::timeout(Duration::from_secs(2), child.wait()).await;
+    let mut stderr = String::new();
+    if let Some(mut pipe) = child.stderr.take() {
+        let _ = tokio::time::timeout(Duration::from_secs(1), pipe.read_to_string(&mut stderr)).await;
+    }
+    stderr.trim().to_owned()
+}
+
+/// Bridge this process's stdin/stdout to the engine socket at
+/// `socket_path`. The far end of an SSH tunnel; see the module docs.
+pub async fn proxy_stdio(socket_path: &str) -> Result<()> {
+    proxy(socket_path, tokio::io::stdin(), tokio::io::stdout()).await
+}
+
+async fn proxy<R, W>(socket_path: &str, mut input: R, mut output: W) -> Result<()>
+where
+    R: AsyncRead + Unpin + Send + 'static,
+    W: AsyncWrite + Unpin,
+{
+    let stream = UnixStream::connect(socket_path)
+        .await
+        .with_context(|| format!("failed to connect to engine socket {socket_path}"))?;
+    let (read_half, mut write_half) = stream.into_split();
+
+    let declaration = serde_json::to_string(&FrontendRequestEnvelope {
+        request_id: DECLARATION_REQUEST_ID.to_owned(),
+        payload: FrontendRequest::DeclareRemotePeer {
+            transport: SSH_TRANSPORT.to_owned(),
+        },
+    })?;
+    write_half.write_all(format!("{declaration}\n").as_bytes()).await?;
+    write_half.flush().await?;
+
+    let upstream = tokio::spawn(async move {
+        let copied = tokio::io::copy(&mut input, &mut write_half).await;
+        // The client hung up: close our side so the engine ends the session.
+        let _ = write_half.shutdown().await;
+        copied
+    });
+
+    let mut lines = BufReader::new(read_half).lines();
+    let mut declared = false;
+    while let Some(line) = lines.next_line().await? {
+        if !declared
+            && let Ok(envelope) = serde_json::from_str::<FrontendEventEnvelope>(&line)
+            && envelope.request_id.as_deref() == Some(DECLARATION_REQUEST_ID)
+        {
+            if let Err(err) = check_declaration(socket_path, &envelope.payload) {
+                upstream.abort();
+                return Err(err);
+            }
+            declared = true;
+            continue;
+        }
+        output.write_all(line.as_bytes()).await?;
+        output.write_all(b"\n").await?;
+        output.flush().await?;
+    }
+    upstream.abort();
+    if !declared {
+        bail!("the engine at {socket_path} closed the connection without answering the remote-peer declaration");
+    }
+    Ok(())
+}
+
+/// Relaying is only safe once the engine has scoped the connection as
+/// remote; anything else means it is treating the proxy as a local peer.
+fn check_declaration(socket_path: &str, reply: &FrontendEvent) -> Result<()> {
+    match reply {
+        FrontendEvent::RemotePeerDeclared { peer_class, .. } if peer_class == "remote" => Ok(()),
+        FrontendEvent::RemotePeerDeclared { peer_class, .. } => bail!(
+            "the engine at {socket_path} kept this connection classified as `{peer_class}` instead of accepting the remote-peer declaration"
+        ),
+        FrontendEvent::WorkerTierDenied { denial } => bail!(
+            "the engine at {socket_path} refused the remote-peer declaration: {}",
+            denial.message
+        ),
+        FrontendEvent::Error { message } => {
+            bail!("the engine at {socket_path} refused the remote-peer declaration: {message}")
+        }
+        other => bail!(
+            "the engine at {socket_path} answered the remote-peer declaration with `{}`",
+            event_type(other)
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn endpoint(raw: &str) -> SshEndpoint {
+        SshEndpoint::parse(raw).unwrap().expect("an ssh endpoint")
+    }
+
+    #[test]
+    fn plain_socket_paths_are_not_endpoints() {
+        assert_eq!(SshEndpoint::parse("/tmp/boss-engine.sock").unwrap(), None);
+    }
+
+    #[test]
+    fn parses_user_host_port_and_socket() {
+        let parsed = endpoint("ssh://ana@build-box:2222/run/boss/engine.sock");
+        assert_eq!(parsed.destination, "ana@build-box");
+        assert_eq!(parsed.port, Some(2222));
+        assert_eq!(parsed.socket_path, "/run/boss/engine.sock");
+
+        let bare = endpoint("ssh://build-box");
+        assert_eq!(bare.destination, "build-box");
+        assert_eq!(bare.port, None);
+        assert_eq!(bare.socket_path, DEFAULT_SOCKET_PATH);
+    }
+
+    #[test]
+    fn parses_ipv6_hosts() {
+        let bracketed = endpoint("ssh://[::1]:2222/run/boss/engine.sock");
+        assert_eq!(bracketed.destination, "::1");
+        assert_eq!(bracketed.port, Some(2222));
+        assert_eq!(bracketed.socket_path, "/run/boss/engine.sock");
+
+        let with_user = endpoint("ssh://ana@[fe80::1]");
+        assert_eq!(with_user.destination, "ana@fe80::1");
+        assert_eq!(with_user.port, None);
+
+        let bare = endpoint("ssh://ana@fe80::1");
+        assert_eq!(bare.destination, "ana@fe80::1");
+        assert_eq!(bare.port, None);
+
+        assert_eq!(bracketed.display(), "ssh://[::1]:2222/run/boss/engine.sock");
+        assert_eq!(endpoint(&bare.display()), bare);
+    }
+
+    #[test]
+    fn rejects_missing_host_bad_port_and_option_lookalikes() {
+        assert!(SshEndpoint::parse("ssh://").is_err());
+        assert!(SshEndpoint::parse("ssh://user@/tmp/x.sock").is_err());
+        assert!(SshEndpoint::parse("ssh://host:http").is_err());
+        assert!(SshEndpoint::parse("ssh://-oProxyCommand=x").is_err());
+        assert!(SshEndpoint::parse("ssh://[::1").is_err());
+        assert!(SshEndpoint::parse("ssh://[::1]x").is_err());
+        assert!(SshEndpoint::parse("ssh://[]:22").is_err());
+    }
+
+    #[test]
+    fn ssh_args_quote_the_remote_command() {
+        let mut parsed = endpoint("ssh://box:2200/tmp/my engine.sock");
+        parsed.remote_boss = "boss".to_owned();
+        assert_eq!(
+            parsed.ssh_args(),
+            vec![
+                "-T",
+                "-o",
+                "BatchMode=yes",
+                "-p",
+                "2200",
+                "box",
+                "--",
+                "boss --socket-path '/tmp/my engine.sock' stdio-proxy",
+            ]
+        );
+    }
+
+    #[test]
+    fn only_a_hello_opens_the_tunnel() {
+        let parsed = endpoint("ssh://box/tmp/engine.sock");
+        let hello = FrontendEventEnvelope::push(FrontendEvent::Hello {
+            session_id: "session-1".to_owned(),
+        });
+        assert!(expect_hello(&parsed, &hello).is_ok());
+
+        let other = FrontendEventEnvelope::push(FrontendEvent::ProductsList { products: Vec::new() });
+        let err = expect_hello(&parsed, &other).unwrap_err().to_string();
+        assert!(err.contains("expected `hello` first, got `products_list`"), "{err}");
+    }
+
+    /// The proxy declares itself first, hides the engine's reply to that
+    /// declaration, and relays everything else in both directions.
+    #[tokio::test]
+    async fn proxy_declares_then_relays_both_ways() {
+        let tmp = tempfile::tempdir().unwrap();
+        let socket_path = tmp.path().join("engine.sock");
+        let listener = tokio::net::UnixListener::bind(&socket_path).unwrap();
+        let engine = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (read_half, mut write_half) = stream.into_split();
+            let mut lines = BufReader::new(read_half).lines();
+            let declaration: FrontendRequestEnvelope =
+                serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+            assert!(matches!(
+                declaration.payload,
+                FrontendRequest::DeclareRemotePeer { ref transport } if transport == SSH_TRANSPORT
+            ));
+            let ack = FrontendEventEnvelope::response(
+                declaration.request_id,
+                FrontendEvent::RemotePeerDeclared {
+                    transport: SSH_TRANSPORT.to_owned(),
+                    peer_class: "remote".to_owned(),
+                },
+            );
+            let request: FrontendRequestEnvelope =
+                serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+            let reply = FrontendEventEnvelope::response(
+                request.request_id,
+                FrontendEvent::ProductsList { products: Vec::new() },
+            );
+            for envelope in [&ack, &reply] {
+                let line = serde_json::to_string(envelope).unwrap();
+                write_half.write_all(format!("{line}\n").as_bytes()).await.unwrap();
+            }
+        });
+
+        let (mut client_writer, proxy_input) = tokio::io::duplex(4096);
+        let (proxy_output, mut client_reader) = tokio::io::duplex(4096);
+        let request = serde_json::to_string(&FrontendRequestEnvelope {
+            request_id: "client-1".to_owned(),
+            payload: FrontendRequest::ListProducts,
+        })
+        .unwrap();
+        client_writer
+            .write_all(format!("{request}\n").as_bytes())
+            .await
+            .unwrap();
+
+        proxy(socket_path.to_str().unwrap(), proxy_input, proxy_output)
+            .await
+            .unwrap();
+        engine.await.unwrap();
+
+        let mut relayed = String::new();
+        client_reader.read_to_string(&mut relayed).await.unwrap();
+        let lines: Vec<&str> = relayed.lines().collect();
+        assert_eq!(lines.len(), 1, "only the client's reply is relayed: {relayed}");
+        let envelope: FrontendEventEnvelope = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(envelope.request_id.as_deref(), Some("client-1"));
+    }
+
+    #[tokio::test]
+    async fn proxy_fails_when_the_engine_keeps_the_connection_local() {
+        let tmp = tempfile::tempdir().unwrap();
+        let socket_path = tmp.path().join("engine.sock");
+        let listener = tokio::net::UnixListener::bind(&socket_path).unwrap();
+        let engine = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (read_half, mut write_half) = stream.into_split();
+            let mut lines = BufReader::new(read_half).lines();
+            let declaration: FrontendRequestEnvelope =
+                serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+            let refusal = FrontendEventEnvelope::response(
+                declaration.request_id,
+                FrontendEvent::RemotePeerDeclared {
+                    transport: SSH_TRANSPORT.to_owned(),
+                    peer_class: "worker".to_owned(),
+                },
+            );
+            let line = serde_json::to_string(&refusal).unwrap();
+            write_half.write_all(format!("{line}\n").as_bytes()).await.unwrap();
+            // Hold the connection open: the proxy must stop on the reply,
+            // not on EOF.
+            let _ = lines.next_line().await;
+        });
+
+        let (_client_writer, proxy_input) = tokio::io::duplex(4096);
+        let (proxy_output, mut client_reader) = tokio::io::duplex(4096);
+        let err = proxy(socket_path.to_str().unwrap(), proxy_input, proxy_output)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("classified as `worker`"), "{err:#}");
+
+        let mut relayed = String::new();
+        client_reader.read_to_string(&mut relayed).await.unwrap();
+        assert!(relayed.is_empty(), "nothing is relayed: {relayed}");
+        engine.abort();
+    }
+}
@@ -0,0 +1,545 @@
+//! The dashboard: an opt-in, read-only view of a running engine served from
+//! the evidence surface's loopback port.
+//!
+//! Work items by board column, live worker state, recent executions with
+//! their token spend, open attentions, merge-queue state, and a rendered
+//! transcript for any execution — what the macOS app shows, for a teammate
+//! who doesn't run it. Reaching a shared engine from another machine is an
+//! SSH port forward (`ssh -L 8419:127.0.0.1:8419 host`), then
+//! `http://localhost:8419/dashboard`; the loopback and `Host` gates in
+//! [`crate::http`] apply unchanged, so nothing here widens who can connect.
+//!
+//! ## Shape
+//!
+//! - `GET /dashboard` — a static page plus `/dashboard/app.js`. The script
+//!   builds the DOM from JSON with `textContent` only, so engine-supplied
+//!   text (task names, prompts, transcripts) is never parsed as markup.
+//! - `GET /dashboard/state.json` — a [`DashboardSnapshot`], built fresh
+//!   per request by the engine's [`DashboardSource`].
+//! - `GET /dashboard/events` — Server-Sent Events. One `data:` line per
+//!   [`DashboardPush`]: the engine forwards what it publishes on the same
+//!   subscription topics the app listens to. Pushes are invalidations, so
+//!   the page refetches the snapshot on each (debounced) rather than
+//!   patching state — the same contract the app follows.
+//! - `GET /dashboard/transcript/<execution-id>` — that execution's
+//!   transcript rendered to plain text.
+//!
+//! Disabled unless [`DASHBOARD_ENV`] is set: the evidence gallery answers
+//! only for ids a caller already holds, while the dashboard lists every
+//! product's work, and that should be a deliberate choice per engine.
+
+use std::time::Duration;
+
+use boss_protocol::{Attention, AttentionGroup, LiveWorkerState};
+use serde::Serialize;
+use tokio::io::AsyncWriteExt;
+use tokio::net::TcpStream;
+use tokio::sync::broadcast;
+
+use crate::http::{HttpRequest, HttpResponse, is_plausible_id, page};
+
+/// Env var enabling the dashboard routes (`1`, `true`, or `yes`).
+pub const DASHBOARD_ENV: &str = "BOSS_DASHBOARD";
+
+/// Route prefix every dashboard path lives under.
+pub const DASHBOARD_PATH: &str = "/dashboard";
+
+/// How often an idle event stream writes an SSE comment. Keeps proxies and
+/// SSH forwards from timing the connection out, and surfaces a browser
+/// that went away as a write error instead of a task that lives forever.
+const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Whether the dashboard is enabled for this engine.
+pub fn configured() -> bool {
+    std::env::var(DASHBOARD_ENV)
+        .map(|raw| matches!(raw.trim().to_ascii_lowercase().as_str(), "1" | "true" | "yes"))
+        .unwrap_or(false)
+}
+
+/// What the engine provides the dashboard. Implemented in `boss-engine-core`
+/// over the work database and live registries; a trait here for the same
+/// reason as [`crate::EvidenceCatalog`].
+pub trait DashboardSource: Send + Sync + 'static {
+    /// Everything `state.json` serves.
+    fn snapshot(&self) -> DashboardSnapshot;
+    /// Plain-text transcript for one execution. `None` when the execution
+    /// has no transcript recorded or the file is gone.
+    fn transcript(&self, execution_id: &str) -> Option<String>;
+    /// A fresh receiver for topic pushes. One per event-stream connection.
+    fn subscribe(&self) -> broadcast::Receiver<DashboardPush>;
+}
+
+/// One forwarded topic push.
+#[derive(Debug, Clone, Serialize)]
+pub struct DashboardPush {
+    pub topic: String,
+    /// The pushed `FrontendEvent`, as the app would have received it.
+    pub event: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DashboardSnapshot {
+    pub generated_at_epoch_s: i64,
+    pub dispatch_paused: bool,
+    pub products: Vec<DashboardProduct>,
+    pub workers: Vec<LiveWorkerState>,
+    /// Newest first.
+    pub executions: Vec<DashboardExecution>,
+    pub attentions: Vec<DashboardAttentionGroup>,
+    /// Rows with a merge queue or auto-merge armed, across products.
+    pub merge_queue: Vec<DashboardCard>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DashboardProduct {
+    pub id: String,
+    pub name: String,
+    /// Board columns, left to right.
+    pub columns: Vec<DashboardColumn>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DashboardColumn {
+    pub title: String,
+    pub cards: Vec<DashboardCard>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DashboardCard {
+    pub id: String,
+    /// `T42 Fix the flaky test`, or the bare name.
+    pub label: String,
+    pub status: String,
+    pub blocked_reason: Option<String>,
+    pub merge_queue_state: Option<String>,
+    pub pr_url: Option<String>,
+    /// Name of the live worker running this row, if any.
+    pub worker: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DashboardExecution {
+    pub execution_id: String,
+    pub work_item_label: String,
+    pub kind: String,
+    pub status: String,
+    pub model: Option<String>,
+    pub created_at_epoch_s: i64,
+    /// `None` when no run of the execution recorded token counts — not zero.
+    pub total_tokens: Option<i64>,
+    /// List-price estimate; `None` when unmeasured or the model is unpriced.
+    pub estimated_usd: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DashboardAttentionGroup {
+    pub product_name: String,
+    pub group: AttentionGroup,
+    pub members: Vec<Attention>,
+}
+
+/// Whether `path` belongs to the dashboard.
+pub fn is_dashboard_path(path: &str) -> bool {
+    path == DASHBOARD_PATH || path.starts_with("/dashboard/")
+}
+
+/// Route one non-streaming dashboard request. The caller has already applied
+/// the method and `Host` gates.
+pub fn respond(path: &str, source: &dyn DashboardSource) -> HttpResponse {
+    match path {
+        DASHBOARD_PATH | "/dashboard/" => {
+            HttpResponse::dashboard(200, "OK", "text/html; charset=utf-8", PAGE.as_bytes().to_vec())
+        }
+        "/dashboard/app.js" => {
+            HttpResponse::dashboard(200, "OK", "text/javascript; charset=utf-8", SCRIPT.as_bytes().to_vec())
+        }
+        "/dashboard/state.json" => match serde_json::to_vec(&source.snapshot()) {
+            Ok(body) => HttpResponse::dashboard(200, "OK", "application/json", body),
+            Err(err) => {
+                tracing::warn!(error = %err, "dashboard: failed to serialise snapshot");
+                HttpResponse::dashboard(
+                    500,
+                    "Internal Server Error",
+                    "text/plain; charset=utf-8",
+                    b"snapshot failed".to_vec(),
+                )
+            }
+        },
+        _ => match path.strip_prefix("/dashboard/transcript/") {
+            Some(execution_id) if is_plausible_id(execution_id) => match source.transcript(execution_id) {
+                Some(text) => HttpResponse::dashboard(200, "OK", "text/plain; charset=utf-8", text.into_bytes()),
+                None => transcript_missing(execution_id),
+            },
+            Some(execution_id) => transcript_missing(execution_id),
+            None => HttpResponse::dashboard(
+                404,
+                "Not Found",
+                "text/html; charset=utf-8",
+                page("Not found", "<p>No such dashboard page.</p>".to_owned()).into_bytes(),
+            ),
+        },
+    }
+}
+
+fn transcript_missing(execution_id: &str) -> HttpResponse {
+    HttpResponse::dashboard(
+        404,
+        "Not Found",
+        "text/plain; charset=utf-8",
+        format!("no transcript recorded for {execution_id}").into_bytes(),
+    )
+}
+
+/// Whether `request` is for the event stream, which [`stream_events`]
+/// answers instead of [`respond`].
+pub(crate) fn is_event_stream(request: &HttpRequest) -> bool {
+    request.method == "GET" && request.target.split('?').next() == Some("/dashboard/events")
+}
+
+/// Answer `/dashboard/events`: hold the connection open and write each push
+/// as an SSE message until the browser goes away or the engine drops the
+/// sender.
+pub(crate) async fn stream_events(mut stream: TcpStream, source: &dyn DashboardSource) -> std::io::Result<()> {
+    let mut pushes = source.subscribe();
+    stream
+        .write_all(
+            b"HTTP/1.1 200 OK\r\n\
+              Content-Type: text/event-stream\r\n\
+              Cache-Control: no-store\r\n\
+              X-Content-Type-Options: nosniff\r\n\
+              Connection: keep-alive\r\n\
+              \r\n\
+              retry: 3000\n\n",
+        )
+        .await?;
+    stream.flush().await?;
+
+    let mut keepalive = tokio::time::interval(KEEPALIVE_INTERVAL);
+    keepalive.tick().await;
+    loop {
+        let chunk = tokio::select! {
+            received = pushes.recv() => match received {
+                Ok(push) => sse_message(&push),
+                // Pushes were dropped while this browser was slow; tell it
+                // to refetch rather than pretend nothing happened.
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::debug!(skipped, "dashboard: event stream lagged; asking the page to resync");
+                    sse_message(&DashboardPush {
+                        topic: "resync".to_owned(),
+                        event: serde_json::Value::Null,
+                    })
+                }
+                Err(broadcast::error::RecvError::Closed) => return Ok(()),
+            },
+            _ = keepalive.tick() => ": keepalive\n\n".to_owned(),
+        };
+        stream.write_all(chunk.as_bytes()).await?;
+        stream.flush().await?;
+    }
+}
+
+/// One SSE message. `serde_json` never emits a raw newline, so a single
+/// `data:` line is always well-formed.
+fn sse_message(push: &DashboardPush) -> String {
+    let data = serde_json::to_string(push).unwrap_or_else(|_| "{}".to_owned());
+    format!("data: {data}\n\n")
+}
+
+const PAGE: &str = r#"<!doctype html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width,initial-scale=1">
+<title>Boss dashboard</title>
+<style>
+:root{color-scheme:light dark}
+body{font:13px/1.45 -apple-system,BlinkMacSystemFont,'Segoe UI',sans-serif;margin:0;padding:1rem 1.25rem}
+h1{font-size:1.2rem;margin:0}
+h2{font-size:1rem;margin:1.5rem 0 .5rem}
+h3{font-size:.9rem;margin:0 0 .4rem;opacity:.8}
+header{display:flex;gap:1rem;align-items:baseline}
+.muted{opacity:.65}
+.board{display:grid;grid-template-columns:repeat(4,minmax(0,1fr));gap:.75rem}
+.column{border:1px solid rgba(128,128,128,.35);border-radius:6px;padding:.5rem;min-height:3rem}
+.card{padding:.3rem .4rem;margin:.25rem 0;border-radius:4px;background:rgba(128,128,128,.12)}
+.tag{font-size:.8em;padding:0 .3rem;border-radius:3px;background:rgba(128,128,128,.25);margin-left:.3rem}
+.paused{background:#c90;color:#000}
+table{border-collapse:collapse;width:100%}
+td,th{text-align:left;padding:.2rem .5rem;border-bottom:1px solid rgba(128,128,128,.2)}
+pre{white-space:pre-wrap;border:1px solid rgba(128,128,128,.35);border-radius:6px;padding:.75rem;max-height:40rem;overflow:auto}
+a{cursor:pointer}
+</style>
+</head>
+<body>
+<header><h1>Boss dashboard</h1><span id="dispatch"></span><span id="conn" class="muted">connecting…</span></header>
+<div id="products"></div>
+<h2>Workers</h2><table id="workers"></table>
+<h2>Merge queue</h2><table id="merge"></table>
+<h2>Attentions</h2><table id="attentions"></table>
+<h2>Recent executions</h2><table id="executions"></table>
+<h2 id="transcript-title">Transcript</h2><pre id="transcript" class="muted">Select an execution or worker to render its transcript.</pre>
+<script src="/dashboard/app.js"></script>
+</body>
+</html>
+"#;
+
+const SCRIPT: &str = r#""use strict";
+const $ = (id) => document.getElementById(id);
+
+function el(tag, text, cls) {
+  const node = document.createElement(tag);
+  if (text !== undefined && text !== null) node.textContent = String(text);
+  if (cls) node.className = cls;
+  return node;
+}
+
+function row(table, cells, header) {
+  const tr = el("tr");
+  for (const cell of cells) {
+    const td = el(header ? "th" : "td");
+    if (cell instanceof Node) td.appendChild(cell); else td.textContent = cell ?? "";
+    tr.appendChild(td);
+  }
+  table.appendChild(tr);
+}
+
+function transcriptLink(executionId, text) {
+  const a = el("a", text);
+  a.addEventListener("click", () => showTranscript(executionId));
+  return a;
+}
+
+async function showTranscript(executionId) {
+  $("transcript-title").textContent = "Transcript · " + executionId;
+  const response = await fetch("/dashboard/transcript/" + encodeURIComponent(executionId), { cache: "no-store" });
+  $("transcript").textContent = await response.text();
+  $("transcript").className = response.ok ? "" : "muted";
+}
+
+function render(s) {
+  const dispatch = $("dispatch");
+  dispatch.textContent = s.dispatch_paused ? "dispatch paused" : "dispatch running";
+  dispatch.className = s.dispatch_paused ? "tag paused" : "tag";
+
+  const products = $("products");
+  products.replaceChildren();
+  for (const product of s.products) {
+    products.appendChild(el("h2", product.name));
+    const board = el("div", null, "board");
+    for (const column of product.columns) {
+      const col = el("div", null, "column");
+      col.appendChild(el("h3", column.title + " (" + column.cards.length + ")"));
+      for (const card of column.cards) {
+        const c = el("div", card.label, "card");
+        if (card.worker) c.appendChild(el("span", card.worker, "tag"));
+        if (card.blocked_reason) c.appendChild(el("span", card.blocked_reason, "tag"));
+        if (card.merge_queue_state) c.appendChild(el("span", card.merge_queue_state, "tag"));
+        col.appendChild(c);
+      }
+      board.appendChild(col);
+    }
+    products.appendChild(board);
+  }
+
+  const workers = $("workers");
+  workers.replaceChildren();
+  row(workers, ["Worker", "Activity", "Work item", "Status"], true);
+  for (const w of s.workers) {
+    const item = w.execution_id ? transcriptLink(w.execution_id, w.work_item_name || w.execution_id) : (w.work_item_name || "");
+    row(workers, [w.name, w.activity, item, w.live_status || w.current_tool || ""]);
+  }
+
+  const merge = $("merge");
+  merge.replaceChildren();
+  row(merge, ["Work item", "State", "PR"], true);
+  for (const card of s.merge_queue) row(merge, [card.label, card.merge_queue_state, card.pr_url || ""]);
+
+  const attentions = $("attentions");
+  attentions.replaceChildren();
+  row(attentions, ["Product", "Group", "Kind", "Open question"], true);
+  for (const entry of s.attentions) {
+    const group = entry.group.short_id ? "A" + entry.group.short_id : entry.group.id;
+    for (const m of entry.members.filter((m) => m.answer_state === "open")) {
+      row(attentions, [entry.product_name, group, entry.group.kind, m.prompt_text || m.proposed_name || ""]);
+    }
+  }
+
+  const executions = $("executions");
+  executions.replaceChildren();
+  row(executions, ["Execution", "Work item", "Kind", "Status", "Model", "Tokens", "Est. USD"], true);
+  for (const e of s.executions) {
+    row(executions, [
+      transcriptLink(e.execution_id, e.execution_id),
+      e.work_item_label, e.kind, e.status, e.model || "",
+      e.total_tokens === null ? "—" : e.total_tokens.toLocaleString(),
+      e.estimated_usd === null ? "—" : "$" + e.estimated_usd.toFixed(2),
+    ]);
+  }
+}
+
+async function refresh() {
+  try {
+    const response = await fetch("/dashboard/state.json", { cache: "no-store" });
+    render(await response.json());
+  } catch (err) {
+    $("conn").textContent = "refresh failed: " + err;
+  }
+}
+
+let pending = null;
+function schedule() {
+  if (pending) return;
+  pending = setTimeout(() => { pending = null; refresh(); }, 250);
+}
+
+const events = new EventSource("/dashboard/events");
+events.onopen = () => { $("conn").textContent = "live"; schedule(); };
+events.onmessage = schedule;
+events.onerror = () => { $("conn").textContent = "reconnecting…"; };
+refresh();
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    use tokio::io::{AsyncBufReadExt, BufReader};
+
+    struct FakeSource {
+        pushes: broadcast::Sender<DashboardPush>,
+    }
+
+    impl DashboardSource for FakeSource {
+        fn snapshot(&self) -> DashboardSnapshot {
+            DashboardSnapshot {
+                dispatch_paused: true,
+                products: vec![DashboardProduct {
+                    id: "prod_1".to_owned(),
+                    name: "<b>Boss</b>".to_owned(),
+                    columns: Vec::new(),
+                }],
+                ..DashboardSnapshot::default()
+            }
+        }
+
+        fn transcript(&self, execution_id: &str) -> Option<String> {
+            (execution_id == "exec_1").then(|| "assistant: done".to_owned())
+        }
+
+        fn subscribe(&self) -> broadcast::Receiver<DashboardPush> {
+            self.pushes.subscribe()
+        }
+    }
+
+    fn source() -> FakeSource {
+        FakeSource {
+            pushes: broadcast::channel(8).0,
+        }
+    }
+
+    #[test]
+    fn serves_page_script_and_snapshot() {
+        let source = source();
+        let page = respond("/dashboard", &source);
+        assert_eq!(page.status, 200);
+        assert!(String::from_utf8(page.body).unwrap().contains("/dashboard/app.js"));
+
+        let script = respond("/dashboard/app.js", &source);
+        assert_eq!(script.content_type, "text/javascript; charset=utf-8");
+        let wire = String::from_utf8(script.to_bytes(false)).unwrap();
+        assert!(
+            wire.contains("script-src 'self'"),
+            "the page's own script must be allowed: {wire}"
+        );
+        assert!(!wire.to_ascii_lowercase().contains("access-control-allow"));
+
+        let state = respond("/dashboard/state.json", &source);
+        assert_eq!(state.content_type, "application/json");
+        let json: serde_json::Value = serde_json::from_slice(&state.body).unwrap();
+        assert_eq!(json["dispatch_paused"], true);
+        // Raw in JSON; the script renders it with textContent.
+        assert_eq!(json["products"][0]["name"], "<b>Boss</b>");
+    }
+
+    #[test]
+    fn transcript_route_renders_text_or_404s() {
+        let source = source();
+        let found = respond("/dashboard/transcript/exec_1", &source);
+        assert_eq!(found.status, 200);
+        assert_eq!(found.body, b"assistant: done");
+        assert_eq!(respond("/dashboard/transcript/exec_absent", &source).status, 404);
+        assert_eq!(respond("/dashboard/transcript/../../etc/passwd", &source).status, 404);
+        assert_eq!(respond("/dashboard/nope", &source).status, 404);
+    }
+
+    #[test]
+    fn dashboard_paths_match_the_prefix_only() {
+        assert!(is_dashboard_path("/dashboard"));
+        assert!(is_dashboard_path("/dashboard/events"));
+        assert!(!is_dashboard_path("/dashboards"));
+    }
+
+    #[tokio::test]
+    async fn event_stream_forwards_pushes_as_sse() {
+        let source = Arc::new(source());
+        let pushes = source.pushes.clone();
+        let listener = crate::http::bind(0).await.expect("loopback bind");
+        let port = listener.local_addr().unwrap().port();
+        let catalog: Arc<dyn crate::EvidenceCatalog> = Arc::new(NoEvidence);
+        let dashboard: Arc<dyn DashboardSource> = source;
+        tokio::spawn(crate::http::serve(
+            listener,
+            catalog,
+            crate::store::AttachmentStore::at(std::env::temp_dir().join("boss-dashboard-test-unused")),
+            Some(dashboard),
+        ));
+
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+        stream
+            .write_all(format!("GET /dashboard/events HTTP/1.1\r\nHost: localhost:{port}\r\n\r\n").as_bytes())
+            .await
+            .unwrap();
+        let mut lines = BufReader::new(stream).lines();
+        assert_eq!(lines.next_line().await.unwrap().as_deref(), Some("HTTP/1.1 200 OK"));
+        let mut saw_event_stream = false;
+        while let Some(line) = lines.next_line().await.unwrap() {
+            if line == "Content-Type: text/event-stream" {
+                saw_event_stream = true;
+            }
+            if line.starts_with("retry:") {
+                break;
+            }
+        }
+        assert!(saw_event_stream);
+
+        pushes
+            .send(DashboardPush {
+                topic: "work.product.prod_1".to_owned(),
+                event: serde_json::json!({"type": "topic_event"}),
+            })
+            .unwrap();
+        let data = loop {
+            let line = lines.next_line().await.unwrap().expect("stream stays open");
+            if let Some(data) = line.strip_prefix("data: ") {
+                break data.to_owned();
+            }
+        };
+        let push: serde_json::Value = serde_json::from_str(&data).unwrap();
+        assert_eq!(push["topic"], "work.product.prod_1");
+    }
+
+    struct NoEvidence;
+
+    impl crate::EvidenceCatalog for NoEvidence {
+        fn attachment(&self, _attachment_id: &str) -> Option<boss_protocol::WorkAttachment> {
+            None
+        }
+        fn attachments_for_work_item(&self, _work_item_id: &str) -> Vec<boss_protocol::WorkAttachment> {
+            Vec::new()
+        }
+        fn work_item_label(&self, _work_item_id: &str) -> Option<crate::WorkItemLabel> {
+            None
+        }
+    }
+}
@@ -32,6 +32,9 @@
 //! response even if it elicits one. Ids are unguessable (`atc_` + a monotonic
 //! id, images additionally content-addressed), the surface is read-only, and
 //! `GET`/`HEAD` are the only accepted methods.
+//!
+//! The opt-in [`crate::dashboard`] shares this listener and every gate above;
+//! its routes are answered only when the engine hands [`serve`] a source.
 
 use std::sync::Arc;
 
@@ -39,6 +42,7 @@ use boss_protocol::{WorkAttachment, attachment_gallery_path, attachment_image_pa
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::{TcpListener, TcpStream};
 
+use crate::dashboard::{self, DashboardSource};
 use crate::store::AttachmentStore;
 
 /// Port the evidence surface listens on by default.
@@ -56,6 +60,14 @@ pub const PORT_ENV: &str = "BOSS_ATTACHMENT_PORT";
 /// not a request this surface has any business answering.
 const MAX_REQUEST_HEAD_BYTES: usize = 8 * 1024;
 
+/// Evidence pages are static: no script at all.
+const EVIDENCE_CSP: &str = "default-src 'none'; img-src 'self'; style-src 'unsafe-inline'";
+
+/// The dashboard runs its own script and fetches its own JSON and event
+/// stream — same-origin only, still no inline script.
+const DASHBOARD_CSP: &str =
+    "default-src 'none'; script-src 'self'; connect-src 'self'; img-src 'self'; style-src 'unsafe-inline'";
+
 /// What the engine needs to look up to render a page. Implemented over the
 /// work database by `boss-engine-core`; kept as a trait here so this crate
 /// never depends on the crate that consumes it.
@@ -137,6 +149,7 @@ pub struct HttpResponse {
     pub status: u16,
     pub reason: &'static str,
     pub content_type: &'static str,
+    pub content_security_policy: &'static str,
     pub body: Vec<u8>,
 }
 
@@ -146,10 +159,21 @@ impl HttpResponse {
             status,
             reason,
             content_type: "text/html; charset=utf-8",
+            content_security_policy: EVIDENCE_CSP,
             body: body.into_bytes(),
         }
     }
 
+    pub(crate) fn dashboard(status: u16, reason: &'static str, content_type: &'static str, body: Vec<u8>) -> Self {
+        Self {
+            status,
+            reason,
+            content_type,
+            content_security_policy: DASHBOARD_CSP,
+            body,
+        }
+    }
+
     fn ok_html(body: String) -> Self {
         Self::html(200, "OK", body)
     }
@@ -159,6 +183,7 @@ impl HttpResponse {
             status: 200,
             reason: "OK",
             content_type,
+            content_security_policy: EVIDENCE_CSP,
             body: bytes,
         }
     }
@@ -172,13 +197,14 @@ impl HttpResponse {
              Cache-Control: no-store\r\n\
              X-Content-Type-Options: nosniff\r\n\
              Referrer-Policy: no-referrer\r\n\
-             Content-Security-Policy: default-src 'none'; img-src 'self'; style-src 'unsafe-inline'\r\n\
+             Content-Security-Policy: {}\r\n\
              Connection: close\r\n\
              \r\n",
             self.status,
             self.reason,
             self.content_type,
             self.body.len(),
+            self.content_security_policy,
         );
         let mut out = head.into_bytes();
         if include_body {
@@ -205,15 +231,35 @@ fn host_is_loopback(host: Option<&str>) -> bool {
 /// Route and render one request. Pure with respect to the network — every
 /// routing and rendering decision is testable without a socket.
 pub fn respond(request: &HttpRequest, catalog: &dyn EvidenceCatalog, store: &AttachmentStore) -> HttpResponse {
+    if let Some(refusal) = refuse(request) {
+        return refusal;
+    }
+
+    let path = request.path();
+    if path == "/" {
+        return HttpResponse::ok_html(index_page());
+    }
+    if let Some(work_item_id) = path.strip_prefix("/w/") {
+        return gallery_response(work_item_id, catalog);
+    }
+    if let Some(attachment_id) = path.strip_prefix("/a/") {
+        return image_response(attachment_id, catalog, store);
+    }
+    not_found()
+}
+
+/// The method and `Host` gates every route sits behind, the dashboard's
+/// included. `None` when the request may proceed.
+fn refuse(request: &HttpRequest) -> Option<HttpResponse> {
     if !matches!(request.method.as_str(), "GET" | "HEAD") {
-        return HttpResponse::html(
+        return Some(HttpResponse::html(
             405,
             "Method Not Allowed",
             page("Method not allowed", "<p>This surface is read-only.</p>".to_owned()),
-        );
+        ));
     }
     if !host_is_loopback(request.host.as_deref()) {
-        return HttpResponse::html(
+        return Some(HttpResponse::html(
             403,
             "Forbidden",
             page(
@@ -222,20 +268,9 @@ pub fn respond(request: &HttpRequest, catalog: &dyn EvidenceCatalog, store: &Att
                  <code>127.0.0.1</code>.</p>"
                     .to_owned(),
             ),
-        );
+        ));
     }
-
-    let path = request.path();
-    if path == "/" {
-        return HttpResponse::ok_html(index_page());
-    }
-    if let Some(work_item_id) = path.strip_prefix("/w/") {
-        return gallery_response(work_item_id, catalog);
-    }
-    if let Some(attachment_id) = path.strip_prefix("/a/") {
-        return image_response(attachment_id, catalog, store);
-    }
-    not_found()
+    None
 }
 
 fn not_found() -> HttpResponse {
@@ -325,7 +360,7 @@ fn reclaimed_response(attachment: &WorkAttachment, reclaimed_at: &str) -> HttpRe
 /// Ids are engine-minted (`atc_…`, `task_…`); anything with a path separator
 /// or `..` is not one, and rejecting the shape early keeps a traversal
 /// attempt from ever reaching a lookup.
-fn is_plausible_id(candidate: &str) -> bool {
+pub(crate) fn is_plausible_id(candidate: &str) -> bool {
     !candidate.is_empty()
         && candidate.len() <= 128
         && candidate
@@ -380,7 +415,7 @@ img{max-width:100%;height:auto;border:1px solid rgba(128,128,128,.35);border-rad
 code{font-size:.9em;opacity:.85}\
 .meta{opacity:.65;font-size:.85em}";
 
-fn page(title: &str, body: String) -> String {
+pub(crate) fn page(title: &str, body: String) -> String {
     format!(
         "<!doctype html><html lang=\"en\"><head><meta charset=\"utf-8\">\
          <meta name=\"viewport\" content=\"width=device-width,initial-scale=1\">\
@@ -487,7 +522,14 @@ pub async fn bind(port: u16) -> std::io::Result<TcpListener> {
 
 /// Accept and answer forever. One request per connection (`Connection: close`)
 /// — this surface serves a browser clicking a link, not a keep-alive workload.
-pub async fn serve(listener: TcpListener, catalog: Arc<dyn EvidenceCatalog>, store: AttachmentStore) {
+/// The one exception is the dashboard's event stream, held open for as long as
+/// the page is. `dashboard` is `None` unless the engine enabled it.
+pub async fn serve(
+    listener: TcpListener,
+    catalog: Arc<dyn EvidenceCatalog>,
+    store: AttachmentStore,
+    dashboard: Option<Arc<dyn DashboardSource>>,
+) {
     loop {
         let (stream, _peer) = match listener.accept().await {
             Ok(accepted) => accepted,
@@ -498,8 +540,9 @@ pub async fn serve(listener: TcpListener, catalog: Arc<dyn EvidenceCatalog>, sto
         };
         let catalog = Arc::clone(&catalog);
         let store = store.clone();
+        let dashboard = dashboard.clone();
         tokio::spawn(async move {
-            if let Err(err) = handle_connection(stream, catalog.as_ref(), &store).await {
+            if let Err(err) = handle_connection(stream, catalog.as_ref(), &store, dashboard.as_deref()).await {
                 tracing::debug!(error = %err, "evidence server: connection ended early");
             }
         });
@@ -510,6 +553,7 @@ async fn handle_connection(
     mut stream: TcpStream,
     catalog: &dyn EvidenceCatalog,
     store: &AttachmentStore,
+    dashboard: Option<&dyn DashboardSource>,
 ) -> std::io::Result<()> {
     let mut head = Vec::with_capacity(1024);
     let mut chunk = [0u8; 1024];
@@ -530,7 +574,14 @@ async fn handle_connection(
         return Ok(());
     };
     let include_body = request.method != "HEAD";
-    let response = respond(&request, catalog, store);
+    let response = match dashboard {
+        Some(source) if dashboard::is_dashboard_path(request.path()) => match refuse(&request) {
+            Some(refusal) => refusal,
+            None if dashboard::is_event_stream(&request) => return dashboard::stream_events(stream, source).await,
+            None => dashboard::respond(request.path(), source),
+        },
+        _ => respond(&request, catalog, store),
+    };
     stream.write_all(&response.to_bytes(include_body)).await?;
     stream.flush().await
 }
@@ -746,7 +797,7 @@ mod tests {
         let (_tmp, store, catalog, _digest) = fixture();
         let listener = bind(0).await.expect("loopback bind");
         let port = listener.local_addr().unwrap().port();
-        tokio::spawn(serve(listener, Arc::new(catalog), store));
+        tokio::spawn(serve(listener, Arc::new(catalog), store, None));
 
         let mut stream = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
         stream
@@ -26,7 +26,8 @@
 //! - **Surfacing** ([`http`]) — a loopback HTTP gallery on the capturing
 //!   machine, for the worker verifying its own capture and for an operator
 //!   inspecting the run locally. The gallery URL is not GitHub-reachable and
-//!   must not be pasted into a PR body.
+//!   must not be pasted into a PR body. The same listener optionally serves
+//!   a read-only engine [`dashboard`].
 //!
 //! Ingest is mediated exactly like a worker proposal — the engine attributes
 //! the call from the socket peer's pid and answers synchronously, so a bad
@@ -37,11 +38,13 @@
 //!
 //! Design: `tools/boss/docs/designs/worker-screenshot-evidence-attachments.md`.
 
+pub mod dashboard;
 pub mod http;
 mod image;
 pub mod retention;
 pub mod store;
 
+pub use dashboard::{DashboardPush, DashboardSnapshot, DashboardSource};
 pub use http::{EvidenceCatalog, WorkItemLabel};
 pub use retention::{AttachmentRetentionPolicy, ReclaimPlan, RetainedAttachment, plan_reclaim};
 pub use store::{AllowedRoots, AttachmentStore, IngestRejection, IngestedImage};
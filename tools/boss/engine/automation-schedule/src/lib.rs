@@ -33,13 +33,20 @@
 //! occurrence maps to exactly one UTC instant regardless of clock
 //! weirdness, which is what the scheduler keys `automation_runs.scheduled_for`
 //! on.
+//!
+//! [`window`] reuses the same timezone resolution for recurring weekly
+//! windows (per-product dispatch calendars).
 
 use std::collections::BTreeSet;
 use std::str::FromStr;
 
 use boss_engine_utils::local_time::resolve_local_to_utc;
 use chrono::{DateTime, Datelike, NaiveDate, Utc};
-use chrono_tz::Tz;
+/// Re-exported so callers can hold a [`parse_timezone`] result without
+/// depending on `chrono-tz` themselves.
+pub use chrono_tz::Tz;
+
+pub mod window;
 
 /// How far ahead [`next_occurrence_after`] will scan for a matching day
 /// before giving up. Five years comfortably covers the sparsest sane
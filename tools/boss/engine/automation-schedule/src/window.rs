@@ -0,0 +1,237 @@
+//! Recurring weekly wall-clock windows ("weekdays 08:00–20:00") in an
+//! IANA timezone — the time math behind per-product dispatch calendars.
+//!
+//! A window is a day-of-week set plus a start and end wall time. The
+//! days name the day the window *opens* on, so `5 22:00-02:00` runs
+//! from Friday 22:00 into Saturday 02:00. End `24:00` means the following
+//! midnight.
+//!
+//! Wall times resolve to UTC with the same DST policy as
+//! [`crate::next_occurrence_after`] (via
+//! [`boss_engine_utils::local_time::resolve_local_to_utc`]): a start or
+//! end inside a spring-forward gap moves to the gap's far edge, and one
+//! inside a fall-back fold takes the earlier instant. A window is
+//! therefore never silently dropped on a transition day, only shortened
+//! or lengthened by the hour the clocks moved.
+
+use boss_engine_utils::local_time::resolve_local_to_utc;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use chrono_tz::Tz;
+
+use super::{CronField, ScheduleParseError, err, parse_day_of_week};
+
+/// Minutes in a day; an end of `24:00` parses to this.
+const MINUTES_PER_DAY: u32 = 24 * 60;
+
+/// Days scanned past today when resolving a position. One week plus a
+/// day covers the next opening of any window, including one that opens
+/// once a week on the day that just ended.
+const SCAN_DAYS_AHEAD: i64 = 8;
+
+/// A parsed, validated weekly window. Construct via [`parse_weekly_window`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeeklyWindow {
+    days: CronField,
+    start_minute: u32,
+    end_minute: u32,
+}
+
+/// Where an instant sits relative to a set of windows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowPosition {
+    /// Whether any window covers the instant.
+    pub inside: bool,
+    /// UTC epoch seconds of the next edge: when coverage ends if
+    /// `inside`, otherwise when the next window opens. `None` when that
+    /// edge is more than a week away (a window set covering every hour
+    /// never closes) or, outside, when no window exists.
+    pub boundary_epoch: Option<i64>,
+}
+
+/// Parse a window from its day set and `HH:MM` start and end.
+///
+/// `days` is a cron day-of-week field (`1-5`, `0,6`, `*` — see
+/// [`crate::parse_cron`]) or one of the aliases `weekdays`, `weekends`
+/// and `daily`. `start` is `00:00`–`23:59`; `end` is `00:00`–`24:00` and
+/// wraps past midnight when it is not after `start`. A window whose start
+/// equals its end is rejected as ambiguous — write `00:00-24:00` for a
+/// whole day.
+pub fn parse_weekly_window(days: &str, start: &str, end: &str) -> Result<WeeklyWindow, ScheduleParseError> {
+    let days = match days.trim() {
+        "weekdays" => "1-5",
+        "weekends" => "0,6",
+        "daily" => "*",
+        other => other,
+    };
+    let start_minute = parse_wall_time(start, "start")?;
+    if start_minute == MINUTES_PER_DAY {
+        return Err(err("window start must be before 24:00"));
+    }
+    let end_minute = parse_wall_time(end, "end")?;
+    if start_minute == end_minute {
+        return Err(err(format!(
+            "window {start}-{end} is empty; use 00:00-24:00 for a whole day"
+        )));
+    }
+    Ok(WeeklyWindow {
+        days: parse_day_of_week(days)?,
+        start_minute,
+        end_minute,
+    })
+}
+
+/// `HH:MM` as minutes past midnight; `24:00` is allowed and yields
+/// [`MINUTES_PER_DAY`].
+fn parse_wall_time(s: &str, label: &str) -> Result<u32, ScheduleParseError> {
+    let bad = || err(format!("window {label} must be HH:MM, got {s:?}"));
+    let (h, m) = s.trim().split_once(':').ok_or_else(bad)?;
+    if h.is_empty() || h.len() > 2 || m.len() != 2 {
+        return Err(bad());
+    }
+    let h: u32 = h.parse().map_err(|_| bad())?;
+    let m: u32 = m.parse().map_err(|_| bad())?;
+    if m > 59 || h > 24 || (h == 24 && m != 0) {
+        return Err(bad());
+    }
+    Ok(h * 60 + m)
+}
+
+impl WeeklyWindow {
+    /// The UTC `[start, end)` this window covers when it opens on `date`,
+    /// or `None` when `date` is not one of its days.
+    fn interval_on(&self, date: NaiveDate, tz: Tz) -> Option<(i64, i64)> {
+        if !self.days.matches(date.weekday().num_days_from_sunday()) {
+            return None;
+        }
+        let (end_date, end_minute) = if self.end_minute == MINUTES_PER_DAY {
+            (date.succ_opt()?, 0)
+        } else if self.end_minute <= self.start_minute {
+            (date.succ_opt()?, self.end_minute)
+        } else {
+            (date, self.end_minute)
+        };
+        let start = resolve_local_to_utc(at_minute(date, self.start_minute)?, tz)?;
+        let end = resolve_local_to_utc(at_minute(end_date, end_minute)?, tz)?;
+        (end > start).then_some((start, end))
+    }
+}
+
+fn at_minute(date: NaiveDate, minute: u32) -> Option<chrono::NaiveDateTime> {
+    date.and_hms_opt(minute / 60, minute % 60, 0)
+}
+
+/// Where `now_epoch` (UTC seconds) sits relative to `windows`, interpreted
+/// in `tz`. Overlapping or back-to-back windows merge, so a boundary is
+/// only ever reported where coverage really changes.
+pub fn window_position(windows: &[WeeklyWindow], tz: Tz, now_epoch: i64) -> WindowPosition {
+    let Some(now) = DateTime::<Utc>::from_timestamp(now_epoch, 0) else {
+        return WindowPosition {
+            inside: false,
+            boundary_epoch: None,
+        };
+    };
+    let today = now.with_timezone(&tz).date_naive();
+    // Start a day early: yesterday's window may wrap past midnight into now.
+    let first = today - Duration::days(1);
+    let horizon = at_minute(today + Duration::days(SCAN_DAYS_AHEAD + 1), 0)
+        .and_then(|naive| resolve_local_to_utc(naive, tz))
+        .unwrap_or(i64::MAX);
+
+    let mut intervals: Vec<(i64, i64)> = (0..=SCAN_DAYS_AHEAD + 1)
+        .map(|offset| first + Duration::days(offset))
+        .flat_map(|date| windows.iter().filter_map(move |window| window.interval_on(date, tz)))
+        .collect();
+    intervals.sort_unstable();
+    let mut merged: Vec<(i64, i64)> = Vec::with_capacity(intervals.len());
+    for (start, end) in intervals {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    if let Some(&(_, end)) = merged
+        .iter()
+        .find(|(start, end)| *start <= now_epoch && now_epoch < *end)
+    {
+        return WindowPosition {
+            inside: true,
+            boundary_epoch: (end < horizon).then_some(end),
+        };
+    }
+    WindowPosition {
+        inside: false,
+        boundary_epoch: merged.iter().map(|(start, _)| *start).find(|start| *start > now_epoch),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use chrono_tz::America::Los_Angeles;
+
+    fn la(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> i64 {
+        Los_Angeles
+            .with_ymd_and_hms(y, mo, d, h, mi, 0)
+            .earliest()
+            .unwrap()
+            .timestamp()
+    }
+
+    #[test]
+    fn rejects_malformed_windows() {
+        assert!(parse_weekly_window("weekdays", "8:00", "20:00").is_ok());
+        assert!(parse_weekly_window("weekdays", "08:00", "08:00").is_err());
+        assert!(parse_weekly_window("weekdays", "24:00", "08:00").is_err());
+        assert!(parse_weekly_window("weekdays", "08:60", "20:00").is_err());
+        assert!(parse_weekly_window("weekdays", "0800", "20:00").is_err());
+        assert!(parse_weekly_window("mon-fri", "08:00", "20:00").is_err());
+        assert!(parse_weekly_window("daily", "00:00", "24:00").is_ok());
+    }
+
+    #[test]
+    fn weekday_window_opens_and_closes_in_local_time() {
+        let windows = [parse_weekly_window("weekdays", "08:00", "20:00").unwrap()];
+        // Wednesday 2026-07-15, 12:00 PDT: inside, closes at 20:00 PDT.
+        let pos = window_position(&windows, Los_Angeles, la(2026, 7, 15, 12, 0));
+        assert!(pos.inside);
+        assert_eq!(pos.boundary_epoch, Some(la(2026, 7, 15, 20, 0)));
+        // Friday 21:00: outside, next opening is Monday 08:00.
+        let pos = window_position(&windows, Los_Angeles, la(2026, 7, 17, 21, 0));
+        assert!(!pos.inside);
+        assert_eq!(pos.boundary_epoch, Some(la(2026, 7, 20, 8, 0)));
+    }
+
+    #[test]
+    fn overnight_window_belongs_to_the_day_it_opens() {
+        let windows = [parse_weekly_window("5", "22:00", "02:00").unwrap()];
+        // Saturday 01:00 is still inside Friday's window.
+        let pos = window_position(&windows, Los_Angeles, la(2026, 7, 18, 1, 0));
+        assert!(pos.inside);
+        assert_eq!(pos.boundary_epoch, Some(la(2026, 7, 18, 2, 0)));
+        // Saturday 23:00 is not: Saturday is not a window day.
+        assert!(!window_position(&windows, Los_Angeles, la(2026, 7, 18, 23, 0)).inside);
+    }
+
+    #[test]
+    fn adjacent_windows_merge_and_full_coverage_never_closes() {
+        let windows = [
+            parse_weekly_window("*", "00:00", "12:00").unwrap(),
+            parse_weekly_window("*", "12:00", "24:00").unwrap(),
+        ];
+        let pos = window_position(&windows, Los_Angeles, la(2026, 7, 15, 11, 0));
+        assert!(pos.inside);
+        assert_eq!(pos.boundary_epoch, None);
+        assert!(!window_position(&[], Los_Angeles, la(2026, 7, 15, 11, 0)).inside);
+    }
+
+    #[test]
+    fn spring_forward_shortens_a_window_instead_of_dropping_it() {
+        // 2026-03-08: LA clocks jump 02:00 -> 03:00, so 02:30 never happens.
+        let windows = [parse_weekly_window("0", "02:30", "04:00").unwrap()];
+        let pos = window_position(&windows, Los_Angeles, la(2026, 3, 8, 3, 30));
+        assert!(pos.inside);
+        assert_eq!(pos.boundary_epoch, Some(la(2026, 3, 8, 4, 0)));
+    }
+}
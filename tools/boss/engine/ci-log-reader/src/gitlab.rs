@@ -0,0 +1,334 @@
+//! GitLab CI reader: the job trace API.
+//!
+//! GitLab reports to GitHub as a commit status whose `targetUrl` is the
+//! job (`https://<host>/<group>/<project>/-/jobs/<id>`) or pipeline page.
+//! Everything the reader needs — the instance's API root and the project
+//! path — is in that URL, so [`parse_gitlab_job_url`] is the only
+//! coordinate source.
+//!
+//! Reads go to `GET /projects/:id/jobs/:job_id/trace`, retries to
+//! `POST /projects/:id/jobs/:job_id/retry`. Auth is a personal or project
+//! access token in the `PRIVATE-TOKEN` header, read from `GITLAB_TOKEN`
+//! (the variable `glab` uses too). Without one, only public projects'
+//! traces are readable.
+//!
+//! A trace is split into collapsible sections by runner markers:
+//!
+//! ```text
+//! section_start:1700000000:step_script\r\x1b[0KExecuting "step_script" stage
+//! …
+//! section_end:1700000003:step_script\r\x1b[0K
+//! ERROR: Job failed: exit code 1
+//! ```
+//!
+//! [`extract_failing_section`] keeps the last section that ran user code
+//! — the runner's own bookkeeping sections (`prepare_executor`,
+//! `get_sources`, `upload_artifacts_on_failure`, …) are skipped — plus
+//! the runner's closing `ERROR: Job failed` line.
+
+use anyhow::{Context, Result, anyhow};
+use async_trait::async_trait;
+
+use crate::{CiLogReader, body_snippet, http_get_text, strip_ansi, tail_lines};
+
+/// Environment variable holding the GitLab access token.
+pub const GITLAB_TOKEN_ENV: &str = "GITLAB_TOKEN";
+
+/// Sections the runner emits around the user's script. Never the failing
+/// step: they run on every job, and the failure-path ones run *after* the
+/// script failed.
+const RUNNER_SECTIONS: &[&str] = &[
+    "resolve_secrets",
+    "prepare_executor",
+    "prepare_script",
+    "get_sources",
+    "clear_worktree",
+    "restore_cache",
+    "download_artifacts",
+    "after_script",
+    "archive_cache",
+    "archive_cache_on_failure",
+    "upload_artifacts_on_success",
+    "upload_artifacts_on_failure",
+    "cleanup_file_variables",
+];
+
+/// Where a GitLab job lives: the instance's REST root and the project's
+/// full path. Parsed from a job or pipeline URL by [`parse_gitlab_job_url`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitlabCoordinates {
+    /// `https://<host>/api/v4`.
+    pub api_root: String,
+    /// `<group>/<subgroup>/<project>`, unencoded.
+    pub project_path: String,
+}
+
+/// Parse `https://<host>/<project-path>/-/jobs/<id>` (or `/-/pipelines/<id>`)
+/// into [`GitlabCoordinates`]. Returns `None` when the URL has no `/-/`
+/// separator or an empty project path. Instances served under a relative
+/// URL root (`https://host/gitlab/…`) are indistinguishable from a group
+/// named `gitlab` and are not supported.
+pub fn parse_gitlab_job_url(url: &str) -> Option<GitlabCoordinates> {
+    let (scheme, rest) = url.split_once("://")?;
+    let (host, path) = rest.split_once('/')?;
+    let (project_path, _) = path.split_once("/-/")?;
+    if host.is_empty() || project_path.is_empty() {
+        return None;
+    }
+    Some(GitlabCoordinates {
+        api_root: format!("{scheme}://{host}/api/v4"),
+        project_path: project_path.to_owned(),
+    })
+}
+
+/// `CiLogReader` for GitLab CI over the REST API. Construct with
+/// [`Self::new`] (tests point `api_root` at a local stand-in) or
+/// [`Self::from_env`] (production).
+#[derive(Debug, Clone)]
+pub struct GitlabLogReader {
+    coordinates: GitlabCoordinates,
+    token: Option<String>,
+}
+
+impl GitlabLogReader {
+    pub fn new(coordinates: GitlabCoordinates, token: Option<String>) -> Self {
+        Self { coordinates, token }
+    }
+
+    /// Reader authenticated with `$GITLAB_TOKEN`, or anonymous when it is
+    /// unset or empty.
+    pub fn from_env(coordinates: GitlabCoordinates) -> Self {
+        let token = std::env::var(GITLAB_TOKEN_ENV).ok().filter(|t| !t.is_empty());
+        Self::new(coordinates, token)
+    }
+
+    /// `…/projects/<url-encoded path>`. Project paths are limited to
+    /// `[A-Za-z0-9_.-]` segments, so `/` is the only byte to encode.
+    fn project_api(&self) -> String {
+        format!(
+            "{}/projects/{}",
+            self.coordinates.api_root,
+            self.coordinates.project_path.replace('/', "%2F")
+        )
+    }
+
+    fn authorize(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match &self.token {
+            Some(token) => request.header("PRIVATE-TOKEN", token),
+            None => request,
+        }
+    }
+}
+
+#[async_trait]
+impl CiLogReader for GitlabLogReader {
+    async fn read_log_tail(&self, job_id: &str, n_lines: usize) -> Result<String> {
+        let full = self.read_log_full(job_id).await?;
+        Ok(tail_lines(&full, n_lines))
+    }
+
+    async fn read_log_full(&self, job_id: &str) -> Result<String> {
+        let url = format!("{}/jobs/{job_id}/trace", self.project_api());
+        http_get_text(&url, |request| self.authorize(request)).await
+    }
+
+    async fn read_failing_step(&self, job_id: &str, n_lines: usize) -> Result<String> {
+        let full = self.read_log_full(job_id).await?;
+        Ok(tail_lines(&extract_failing_section(&full), n_lines))
+    }
+
+    async fn retrigger(&self, id: &str) -> Result<String> {
+        // Retrying a job creates a new job (new id) in the same pipeline;
+        // the reply is that job's JSON.
+        let url = format!("{}/jobs/{id}/retry", self.project_api());
+        let response = self
+            .authorize(boss_github::http_client().post(&url))
+            .send()
+            .await
+            .with_context(|| format!("POST {url}"))?;
+        let status = response.status();
+        let body = response.text().await.with_context(|| format!("reading body of POST {url}"))?;
+        if !status.is_success() {
+            return Err(anyhow!("POST {url} failed ({status}): {}", body_snippet(&body)));
+        }
+        let job: serde_json::Value =
+            serde_json::from_str(&body).with_context(|| format!("parsing POST {url} response as JSON"))?;
+        job.get("id")
+            .and_then(serde_json::Value::as_u64)
+            .map(|id| id.to_string())
+            .ok_or_else(|| anyhow!("POST {url} reply has no numeric `id`"))
+    }
+
+    fn worker_cli_invocation_hint(&self, job_id: &str) -> String {
+        format!(
+            "curl --header \"PRIVATE-TOKEN: ${GITLAB_TOKEN_ENV}\" {}/jobs/{job_id}/trace",
+            self.project_api()
+        )
+    }
+}
+
+/// Cut a GitLab job trace down to its failing section — the last section
+/// that isn't runner bookkeeping — followed by the runner's `ERROR: Job
+/// failed` verdict when present. Escape codes and the `\r\x1b[0K`
+/// line-clear prefixes the runner uses are stripped. A trace with no
+/// user-code section (a job that failed before its script ran) comes back
+/// whole, cleaned the same way.
+pub fn extract_failing_section(trace: &str) -> String {
+    let mut sections: Vec<(String, Vec<String>)> = Vec::new();
+    let mut open: Option<usize> = None;
+    let mut cleaned = Vec::new();
+    let mut verdict = None;
+
+    for raw in trace.lines() {
+        // `section_start:<ts>:<name>[options]\r\x1b[0K<text>` — the marker
+        // is everything up to the carriage return; `<text>` is the visible
+        // line.
+        let (marker, visible) = match raw.rsplit_once('\r') {
+            Some((marker, visible)) => (marker, visible),
+            None => ("", raw),
+        };
+        let visible = strip_ansi(visible);
+        if let Some(name) = section_name(marker, "section_start:") {
+            sections.push((name.to_owned(), Vec::new()));
+            open = Some(sections.len() - 1);
+        } else if section_name(marker, "section_end:").is_some() {
+            open = None;
+        }
+        if visible.starts_with("ERROR: Job failed") {
+            verdict = Some(visible.clone());
+        }
+        if let Some(index) = open
+            && !visible.is_empty()
+        {
+            sections[index].1.push(visible.clone());
+        }
+        cleaned.push(visible);
+    }
+
+    let Some((name, lines)) = sections
+        .into_iter()
+        .rev()
+        .find(|(name, _)| !RUNNER_SECTIONS.contains(&name.as_str()))
+    else {
+        return cleaned.join("\n");
+    };
+    let mut out = format!("[failing section: {name}]\n");
+    out.push_str(&lines.join("\n"));
+    if let Some(verdict) = verdict {
+        out.push('\n');
+        out.push_str(&verdict);
+    }
+    out
+}
+
+/// Name of a `section_start:`/`section_end:` marker, without the
+/// `[collapsed=true]`-style options suffix.
+fn section_name<'a>(marker: &'a str, prefix: &str) -> Option<&'a str> {
+    let (_, rest) = marker.split_once(prefix)?;
+    let (_timestamp, name) = rest.split_once(':')?;
+    Some(name.split('[').next().unwrap_or(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const TRACE: &str = include_str!("testdata/gitlab_job_trace.log");
+
+    fn reader(server: &MockServer, token: Option<&str>) -> GitlabLogReader {
+        GitlabLogReader::new(
+            GitlabCoordinates {
+                api_root: format!("{}/api/v4", server.uri()),
+                project_path: "acme/platform/mono".to_owned(),
+            },
+            token.map(str::to_owned),
+        )
+    }
+
+    #[test]
+    fn job_url_parses_into_api_root_and_project_path() {
+        let parsed = parse_gitlab_job_url("https://gitlab.acme.dev/acme/platform/mono/-/jobs/4242").unwrap();
+        assert_eq!(parsed.api_root, "https://gitlab.acme.dev/api/v4");
+        assert_eq!(parsed.project_path, "acme/platform/mono");
+        let pipeline = parse_gitlab_job_url("https://gitlab.com/acme/mono/-/pipelines/99").unwrap();
+        assert_eq!(pipeline.project_path, "acme/mono");
+        assert!(parse_gitlab_job_url("https://gitlab.com/acme/mono/jobs/1").is_none());
+        assert!(parse_gitlab_job_url("https://gitlab.com/-/jobs/1").is_none());
+    }
+
+    #[test]
+    fn failing_section_is_the_last_user_section_plus_the_verdict() {
+        let excerpt = extract_failing_section(TRACE);
+        assert_eq!(
+            excerpt,
+            "[failing section: step_script]\n\
+             Executing \"step_script\" stage of the job script\n\
+             $ cargo test --workspace\n\
+             test parser::tests::rejects_trailing_comma ... FAILED\n\
+             test result: FAILED. 41 passed; 1 failed\n\
+             ERROR: Job failed: exit code 101"
+        );
+    }
+
+    #[test]
+    fn trace_without_user_sections_comes_back_whole() {
+        let trace = "section_start:1:prepare_executor\r\u{1b}[0K\u{1b}[32;1mPreparing\u{1b}[0;m\n\
+                     ERROR: Job failed (system failure): no such image\n";
+        assert_eq!(
+            extract_failing_section(trace),
+            "Preparing\nERROR: Job failed (system failure): no such image"
+        );
+    }
+
+    #[tokio::test]
+    async fn reads_the_trace_with_the_private_token() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v4/projects/acme%2Fplatform%2Fmono/jobs/4242/trace"))
+            .and(header("PRIVATE-TOKEN", "glpat-test"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(TRACE))
+            .expect(2)
+            .mount(&server)
+            .await;
+        let reader = reader(&server, Some("glpat-test"));
+
+        assert_eq!(reader.read_log_full("4242").await.unwrap(), TRACE);
+        let step = reader.read_failing_step("4242", 2).await.unwrap();
+        assert_eq!(step, "test result: FAILED. 41 passed; 1 failed\nERROR: Job failed: exit code 101");
+    }
+
+    #[tokio::test]
+    async fn trace_errors_carry_the_status() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404).set_body_string(r#"{"message":"404 Project Not Found"}"#))
+            .mount(&server)
+            .await;
+        let err = reader(&server, None).read_log_tail("1", 10).await.unwrap_err();
+        let msg = format!("{err:#}");
+        assert!(msg.contains("404") && msg.contains("Project Not Found"), "{msg}");
+    }
+
+    #[tokio::test]
+    async fn retrigger_returns_the_new_job_id() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v4/projects/acme%2Fplatform%2Fmono/jobs/4242/retry"))
+            .respond_with(ResponseTemplate::new(201).set_body_string(r#"{"id":4250,"status":"pending"}"#))
+            .expect(1)
+            .mount(&server)
+            .await;
+        assert_eq!(reader(&server, Some("t")).retrigger("4242").await.unwrap(), "4250");
+    }
+
+    #[test]
+    fn invocation_hint_names_the_trace_endpoint() {
+        let reader = GitlabLogReader::new(parse_gitlab_job_url("https://gitlab.com/acme/mono/-/jobs/7").unwrap(), None);
+        assert_eq!(
+            reader.worker_cli_invocation_hint("7"),
+            "curl --header \"PRIVATE-TOKEN: $GITLAB_TOKEN\" https://gitlab.com/api/v4/projects/acme%2Fmono/jobs/7/trace"
+        );
+    }
+}
@@ -3,16 +3,19 @@
 //! §"CI provider abstraction" / Phase 9 #25-#26).
 //!
 //! Mono builds on Buildkite. Flunge builds on Buildkite *and* GitHub
-//! Actions. CI-failure *detection* is provider-agnostic (GitHub's
-//! `statusCheckRollup` aggregates both); CI-failure *fixing* requires
-//! reading the failing job's log, which is provider-specific. This
-//! module owns that seam.
+//! Actions; other products build on GitLab CI or Jenkins. CI-failure
+//! *detection* is provider-agnostic (GitHub's `statusCheckRollup`
+//! aggregates all of them); CI-failure *fixing* requires reading the
+//! failing job's log, which is provider-specific. This module owns that
+//! seam.
 //!
 //! The trait surface is intentionally thin: read the job log tail
 //! (pre-spawn excerpt), read the full log (worker deeper dive), and
 //! retry the failing unit. A fourth method returns a hint string the
 //! worker prompt can embed so the worker knows which CLI to shell out
-//! to.
+//! to. Buildkite and GitHub Actions shell out to `bk`/`gh`; GitLab CI
+//! ([`gitlab`]) and Jenkins ([`jenkins`]) have no CLI worth depending on
+//! and talk to their HTTP APIs directly.
 //!
 //! Provider inference from `targetUrl` host lives on
 //! [`boss_github::CiProvider`]; this module re-exports the type and
@@ -32,6 +35,11 @@ use async_trait::async_trait;
 use tokio::process::Command;
 
 pub use boss_github::CiProvider;
+pub use gitlab::{GitlabLogReader, parse_gitlab_job_url};
+pub use jenkins::{JenkinsLogReader, parse_jenkins_build_url};
+
+pub mod gitlab;
+pub mod jenkins;
 
 /// Reader + retrigger surface for one CI provider's failing-job
 /// signals. The engine implements one reader per known provider; an
@@ -48,12 +56,24 @@ pub trait CiLogReader: Send + Sync {
     /// Read the full job log (for the worker's deeper dive).
     async fn read_log_full(&self, job_id: &str) -> Result<String>;
 
+    /// Read just the failing step's section of the job log, trimmed to
+    /// its last `n_lines`. This is what the pre-spawn excerpt stores.
+    /// Providers whose logs mark step boundaries (GitLab's collapsible
+    /// sections, Jenkins Pipeline stages) override it; the default is
+    /// [`Self::read_log_tail`], which for GitHub Actions is already
+    /// step-scoped (`--log-failed`).
+    async fn read_failing_step(&self, job_id: &str, n_lines: usize) -> Result<String> {
+        self.read_log_tail(job_id, n_lines).await
+    }
+
     /// Re-trigger a failed job. The `id` argument is the
     /// provider-appropriate parent identifier — the **build id** for
-    /// Buildkite (`bk build retry <build-id>`) and the **run id** for
-    /// GitHub Actions (`gh run rerun <run-id> --failed`). Callers
+    /// Buildkite (`bk build retry <build-id>`), the **run id** for
+    /// GitHub Actions (`gh run rerun <run-id> --failed`), the **job id**
+    /// for GitLab CI, and the **build number** for Jenkins. Callers
     /// extract the correct id from the failing check's `target_url`
-    /// using [`parse_buildkite_build_id`] / [`parse_gha_run_id`].
+    /// using [`parse_buildkite_build_id`] / [`parse_gha_run_id`] /
+    /// [`boss_github::parse_provider_job_id`].
     /// Returns a provider-emitted identifier for the new run/build
     /// (or the same id, for GHA, which re-uses the run id).
     async fn retrigger(&self, id: &str) -> Result<String>;
@@ -289,34 +309,44 @@ impl CiLogReader for FixedContentLogReader {
 }
 
 /// Fallback reader for a *known* provider whose `target_url` didn't carry
-/// the coordinates that provider's reader needs (currently: Buildkite's
-/// pipeline slug + build number). Distinct from [`UnknownProviderReader`]
-/// because the provider itself is known — what failed is parsing the
-/// `target_url` — and the error message should say so rather than
-/// claiming the provider is unknown.
+/// the coordinates that provider's reader needs (Buildkite's pipeline
+/// slug + build number, GitLab's project path, Jenkins' job URL).
+/// Distinct from [`UnknownProviderReader`] because the provider itself is
+/// known — what failed is parsing the `target_url` — and the error message
+/// should say so rather than claiming the provider is unknown.
 #[derive(Debug, Clone)]
 pub struct UnparseableCoordinatesReader {
+    provider: &'static str,
     reason: String,
 }
 
 impl UnparseableCoordinatesReader {
-    pub fn new(reason: impl Into<String>) -> Self {
-        Self { reason: reason.into() }
+    /// `provider` names the reader in the error (`"buildkite"`,
+    /// `"gitlab"`, `"jenkins"`).
+    pub fn new(provider: &'static str, reason: impl Into<String>) -> Self {
+        Self {
+            provider,
+            reason: reason.into(),
+        }
+    }
+
+    fn unavailable(&self) -> anyhow::Error {
+        anyhow!("{} log reader unavailable: {}", self.provider, self.reason)
     }
 }
 
 #[async_trait]
 impl CiLogReader for UnparseableCoordinatesReader {
     async fn read_log_tail(&self, _job_id: &str, _n_lines: usize) -> Result<String> {
-        Err(anyhow!("buildkite log reader unavailable: {}", self.reason))
+        Err(self.unavailable())
     }
 
     async fn read_log_full(&self, _job_id: &str) -> Result<String> {
-        Err(anyhow!("buildkite log reader unavailable: {}", self.reason))
+        Err(self.unavailable())
     }
 
     async fn retrigger(&self, _id: &str) -> Result<String> {
-        Err(anyhow!("buildkite log reader unavailable: {}", self.reason))
+        Err(self.unavailable())
     }
 
     fn worker_cli_invocation_hint(&self, _job_id: &str) -> String {
@@ -341,11 +371,15 @@ pub type CiLogReaderFactory = fn(CiProvider, &str) -> Box<dyn CiLogReader>;
 /// slug + build number out of it internally (via
 /// [`parse_buildkite_pipeline_slug`] / [`parse_buildkite_build_id`]) —
 /// [`BuildkiteLogReader`] needs both to resolve its pipeline without a
-/// repo checkout in cwd. When the URL doesn't match the canonical shape,
-/// falls back to [`UnparseableCoordinatesReader`] (naming the real cause)
-/// rather than constructing a reader that would fail on every call with a
-/// misleading "unknown provider" error. Other providers ignore
-/// `target_url`; callers that don't have one may pass `""`.
+/// repo checkout in cwd. GitLab CI and Jenkins likewise take their API
+/// coordinates from it ([`parse_gitlab_job_url`] /
+/// [`parse_jenkins_build_url`]) and their credentials from the engine's
+/// environment ([`GitlabLogReader::from_env`] /
+/// [`JenkinsLogReader::from_env`]). When the URL doesn't match the
+/// canonical shape, falls back to [`UnparseableCoordinatesReader`] (naming
+/// the real cause) rather than constructing a reader that would fail on
+/// every call with a misleading "unknown provider" error. GitHub Actions
+/// ignores `target_url`; callers that don't have one may pass `""`.
 pub fn reader_for(provider: CiProvider, target_url: &str) -> Box<dyn CiLogReader> {
     reader_for_with_binaries(provider, target_url, "bk", "gh")
 }
@@ -377,11 +411,26 @@ pub fn reader_for_with_binaries(
             (Some(pipeline_slug), Some(build_number)) => {
                 Box::new(BuildkiteLogReader::with_binary(bk_binary, pipeline_slug, build_number))
             }
-            _ => Box::new(UnparseableCoordinatesReader::new(format!(
-                "could not parse pipeline slug and build number from target_url {target_url:?}"
-            ))),
+            _ => Box::new(UnparseableCoordinatesReader::new(
+                "buildkite",
+                format!("could not parse pipeline slug and build number from target_url {target_url:?}"),
+            )),
         },
         CiProvider::GithubActions => Box::new(GithubActionsLogReader::with_binary(gh_binary)),
+        CiProvider::GitlabCi => match parse_gitlab_job_url(target_url) {
+            Some(coordinates) => Box::new(GitlabLogReader::from_env(coordinates)),
+            None => Box::new(UnparseableCoordinatesReader::new(
+                "gitlab",
+                format!("could not parse project path from target_url {target_url:?}"),
+            )),
+        },
+        CiProvider::Jenkins => match parse_jenkins_build_url(target_url) {
+            Some(job_url) => Box::new(JenkinsLogReader::from_env(job_url)),
+            None => Box::new(UnparseableCoordinatesReader::new(
+                "jenkins",
+                format!("could not parse job URL from target_url {target_url:?}"),
+            )),
+        },
         CiProvider::Other => Box::new(UnknownProviderReader),
     }
 }
@@ -565,8 +614,8 @@ fn parse_trunk_eviction_build(builds: &serde_json::Value, branch_prefix: &str) -
 }
 
 /// Trim `s` to its last `n` lines (preserving order). Used by the
-/// `read_log_tail` impls — neither provider CLI exposes a server-side
-/// tail, so we fetch all and trim locally.
+/// `read_log_tail` impls — no provider exposes a server-side tail, so we
+/// fetch all and trim locally.
 fn tail_lines(s: &str, n: usize) -> String {
     if n == 0 {
         return String::new();
@@ -579,6 +628,56 @@ fn tail_lines(s: &str, n: usize) -> String {
     lines[start..].join("\n")
 }
 
+/// Remove ANSI escape sequences (CSI `ESC [ … <final>` and the bare
+/// two-byte `ESC <c>` forms) from `line`. GitLab traces and Jenkins
+/// consoles with the AnsiColor plugin are full of them, and the
+/// pre-spawn excerpt lands in a Markdown code fence where they are noise.
+pub(crate) fn strip_ansi(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\u{1b}' {
+            out.push(c);
+            continue;
+        }
+        if chars.next_if_eq(&'[').is_some() {
+            // Parameters and intermediates, then one final byte in @..=~.
+            for c in chars.by_ref() {
+                if ('@'..='~').contains(&c) {
+                    break;
+                }
+            }
+        } else {
+            chars.next();
+        }
+    }
+    out
+}
+
+/// GET `url` and return the body as text, failing with the status and a
+/// body snippet on a non-2xx reply. `auth` decorates the request with the
+/// provider's credentials. Shared by the HTTP-backed readers.
+pub(crate) async fn http_get_text(
+    url: &str,
+    auth: impl FnOnce(reqwest::RequestBuilder) -> reqwest::RequestBuilder,
+) -> Result<String> {
+    let response = auth(boss_github::http_client().get(url))
+        .send()
+        .await
+        .with_context(|| format!("GET {url}"))?;
+    let status = response.status();
+    let body = response.text().await.with_context(|| format!("reading body of GET {url}"))?;
+    if !status.is_success() {
+        return Err(anyhow!("GET {url} failed ({status}): {}", body_snippet(&body)));
+    }
+    Ok(body)
+}
+
+/// First 200 characters of an error body, trimmed, for error messages.
+pub(crate) fn body_snippet(body: &str) -> String {
+    body.trim().chars().take(200).collect()
+}
+
 /// Shell out to `binary` with `args`, capture stdout. Returns an
 /// error annotated with the binary + args + stderr on non-zero exit
 /// so the caller's logs make the failure mode obvious.
@@ -1053,6 +1152,39 @@ exit 2
         );
     }
 
+    #[test]
+    fn reader_for_gitlab_and_jenkins_build_http_readers_from_target_url() {
+        let r = reader_for(CiProvider::GitlabCi, "https://gitlab.acme.dev/acme/mono/-/jobs/4242");
+        assert!(
+            r.worker_cli_invocation_hint("4242")
+                .ends_with("https://gitlab.acme.dev/api/v4/projects/acme%2Fmono/jobs/4242/trace"),
+            "{}",
+            r.worker_cli_invocation_hint("4242")
+        );
+        let r = reader_for(
+            CiProvider::Jenkins,
+            "https://ci.acme.dev/job/acme/job/mono/job/PR-7/3/display/redirect",
+        );
+        assert!(
+            r.worker_cli_invocation_hint("3")
+                .ends_with("https://ci.acme.dev/job/acme/job/mono/job/PR-7/3/consoleText"),
+            "{}",
+            r.worker_cli_invocation_hint("3")
+        );
+    }
+
+    #[tokio::test]
+    async fn reader_for_gitlab_and_jenkins_without_coordinates_report_parse_failure() {
+        for (provider, label) in [(CiProvider::GitlabCi, "gitlab"), (CiProvider::Jenkins, "jenkins")] {
+            let r = reader_for(provider, "https://example.com/not-a-ci-url");
+            let msg = format!("{:#}", r.read_log_full("j").await.unwrap_err());
+            assert!(
+                msg.contains(&format!("{label} log reader unavailable")),
+                "expected a {label} parse-failure diagnostic, got: {msg}"
+            );
+        }
+    }
+
     #[tokio::test]
     #[cfg(unix)]
     async fn reader_for_buildkite_with_canonical_url_builds_working_reader() {
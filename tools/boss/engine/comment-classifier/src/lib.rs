@@ -18,8 +18,9 @@
 //! for length/diff sanity checks. Where the call goes — endpoint, model and
 //! credential — comes from the engine's `UtilityModel` seam
 //! ([`boss_engine_utility_model`]), configured independently of any work
-//! item's driver; transport goes through the shared [`boss_claude_client`]
-//! pipeline like every other Claude call in the engine.
+//! item's driver; the request is sent through the resolved call, which
+//! hands it to the shared [`boss_claude_client`] pipeline or to an
+//! OpenAI-compatible backend depending on the configured provider.
 
 use std::time::Duration;
 
@@ -27,7 +28,7 @@ use serde::Deserialize;
 
 use boss_protocol::{CommentAnchor, CommentThreadEntry, INTENT_QUESTION, INTENT_REVISION};
 
-use boss_claude_client::{self as claude_client, CallConfig, Message, MessagesRequest};
+use boss_claude_client::{CallConfig, Message, MessagesRequest};
 use boss_engine_utility_model::UtilityCall;
 
 // The model is no longer pinned here: it comes from the engine's
@@ -157,9 +158,12 @@ async fn call_classifier(call: &UtilityCall, prompt: String) -> Result<Classific
         .max_tokens(CLASSIFIER_MAX_TOKENS)
         .messages(vec![Message::user(prompt)])
         .build();
-    let config = CallConfig::new(CLASSIFIER_TIMEOUT).with_endpoint(call.endpoint.clone());
+    let config = CallConfig::new(CLASSIFIER_TIMEOUT);
 
-    let response = claude_client::send_messages(&call.api_key, &request, &config)
+    // JSON mode where the backend has one; the prompt asks for bare JSON
+    // either way.
+    let response = call
+        .send_messages_json(&request, &config)
         .await
         .map_err(|e| e.to_string())?;
 
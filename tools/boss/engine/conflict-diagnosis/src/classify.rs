@@ -24,7 +24,7 @@ pub fn classify_conflict_class(paths: &[String]) -> &'static str {
 
 fn classify_path(path: &str) -> &'static str {
     let file_name = path.rsplit('/').next().unwrap_or(path);
-    if file_name.ends_with(".lock") {
+    if file_name.ends_with(".lock") || matches!(file_name, "package-lock.json" | "pnpm-lock.yaml" | "go.sum") {
         "lockfile"
     } else if file_name == "BUILD.bazel" || file_name == "BUILD" || file_name.ends_with(".bzl") {
         "build_file"
@@ -60,6 +60,13 @@ mod tests {
         assert_eq!(classify_conflict_class(&paths), "lockfile");
     }
 
+    #[test]
+    fn js_and_go_lockfiles_are_lockfile() {
+        for path in ["web/package-lock.json", "pnpm-lock.yaml", "yarn.lock", "svc/go.sum"] {
+            assert_eq!(classify_conflict_class(&[path.to_owned()]), "lockfile", "{path}");
+        }
+    }
+
     #[test]
     fn build_bazel_is_build_file() {
         let paths = vec!["tools/boss/cli/BUILD.bazel".to_owned()];
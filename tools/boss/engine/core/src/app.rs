@@ -67,6 +67,7 @@ mod comments;
 mod conflict_resolution;
 mod context;
 mod cost;
+mod dashboard_feed;
 mod decisions;
 mod dependencies;
 mod design_docs;
@@ -210,7 +211,8 @@ pub use isolation::{DEFAULT_PID_PATH, DEFAULT_SOCKET_PATH};
 /// ring crypto provider lazily (the first TLS handshake panics otherwise,
 /// mirroring `live_status::http_client`) and applies a per-request timeout —
 /// the device-flow poll loop manages its own cadence, so this only bounds an
-/// individual round-trip, never the overall flow.
+/// individual round-trip, never the overall flow. The Linear and Jira
+/// tracker transports share the same client settings.
 fn github_oauth_http_client() -> reqwest::Client {
     let _ = rustls::crypto::ring::default_provider().install_default();
     reqwest::Client::builder()
@@ -229,6 +231,32 @@ fn boot_id_millis() -> u64 {
         .unwrap_or(0)
 }
 
+/// The engine's event bus: journaled under `<state_root>/event-bus/` when
+/// `durable` (see [`crate::config::DEFAULT_ENABLE_DURABLE_EVENT_BUS`]),
+/// in-memory otherwise. A journal that fails to open degrades to the
+/// in-memory bus rather than failing boot — the bus is best-effort in
+/// either mode, so losing durability is a warning, not an outage.
+fn open_event_bus(durable: bool, state_root: &Path) -> EventBus {
+    if !durable {
+        return EventBus::new();
+    }
+    let dir = state_root.join(boss_event_bus::JOURNAL_DIR_NAME);
+    match boss_event_bus::EventJournal::open(&dir) {
+        Ok(journal) => {
+            tracing::info!(dir = %dir.display(), "event bus: durable mode, journaling published events");
+            EventBus::durable(journal)
+        }
+        Err(err) => {
+            tracing::warn!(
+                dir = %dir.display(),
+                ?err,
+                "event bus: failed to open journal; falling back to the in-memory bus",
+            );
+            EventBus::new()
+        }
+    }
+}
+
 #[async_trait]
 impl LiveStatusBroadcaster for ServerState {
     async fn broadcast_live_worker_states(&self) {
@@ -1186,6 +1214,20 @@ impl ServerState {
             // TODO(@brianduff,2026-12-31): spawn one-shot dedup sweep background task.
         }
 
+        // Model-pricing overrides for USD cost estimates and USD spend
+        // budgets. Same boot contract: a missing file means the built-in
+        // table; a malformed one is logged and ignored.
+        let pricing_path = crate::cost_pricing::default_overrides_path(&state_root);
+        match crate::cost_pricing::load_overrides(&pricing_path) {
+            Ok(0) => {}
+            Ok(count) => tracing::info!(count, path = %pricing_path.display(), "cost pricing: loaded model overrides"),
+            Err(err) => tracing::warn!(
+                ?err,
+                path = %pricing_path.display(),
+                "cost pricing: override file load failed; using the built-in table",
+            ),
+        }
+
         // Load per-installation settings. Same boot contract as feature
         // flags: a missing or unreadable file falls back to registry
         // defaults; parse failures are logged but don't block startup.
@@ -1233,7 +1275,17 @@ impl ServerState {
         let mut tracker_registry = crate::external_tracker::TrackerRegistry::new();
         tracker_registry
             .register(Arc::new(crate::external_tracker::github::GitHubTracker::new()))
-            .expect("github tracker is the only registered kind; duplicate is impossible");
+            .expect("github tracker kind is registered once");
+        tracker_registry
+            .register(Arc::new(crate::external_tracker::linear::LinearTracker::new(
+                github_oauth_http_client(),
+            )))
+            .expect("linear tracker kind is registered once");
+        tracker_registry
+            .register(Arc::new(crate::external_tracker::jira::JiraTracker::new(
+                github_oauth_http_client(),
+            )))
+            .expect("jira tracker kind is registered once");
         let tracker_registry = Arc::new(tracker_registry);
         let tracker_registry_for_state = tracker_registry.clone();
 
@@ -1365,6 +1417,7 @@ impl ServerState {
         let dispatch_events_for_state = dispatch_events.clone();
         let dispatch_event_root_for_state = dispatch_event_root.clone();
         let ipc_logger = IpcLogger::new(&dispatch_event_root);
+        let event_bus = Arc::new(open_event_bus(cfg.work.enable_durable_event_bus, &state_root));
 
         let completion_handler_for_coordinator = completion_handler.clone();
         // Distributed-execution PR3 inputs for the SSH-capable host-adapter
@@ -1455,6 +1508,7 @@ impl ServerState {
                 .dispatch_events(dispatch_events_for_state)
                 .dispatch_event_root(dispatch_event_root_for_state)
                 .topic_broker(topic_broker)
+                .event_bus(event_bus)
                 .attachment_store(boss_engine_attachments::AttachmentStore::under_state_root(
                     &attachment_state_root,
                 ))
@@ -2171,7 +2225,7 @@ async fn handle_frontend_connection(
     // in that interval classifies as `Other` and keeps `User` tier — the
     // same fail-open direction `PeerClass::Other` documents for broken
     // lineage.
-    let peer_class = server_state.classify_peer(peer_pid);
+    let mut peer_class = server_state.classify_peer(peer_pid);
     if let Some(run_id) = peer_class.worker_run_id() {
         tracing::debug!(
             session_id = %session_id,
@@ -2313,6 +2367,7 @@ async fn handle_frontend_connection(
         // applied mutation escapes. One gate rather than 171 per-handler
         // checks — the policy itself is an exhaustive match, so a verb added
         // later cannot slip through unclassified.
+        // The same gate holds a remote connection to its narrower scope.
         let row_scope_denial = server_state.worker_row_scope_denial(&peer_class, &request);
         if let Some(denial) = server_state
             .worker_tier_denial(&peer_class, &request)
@@ -2320,6 +2375,7 @@ async fn handle_frontend_connection(
         {
             tracing::warn!(
                 session_id = %session_id,
+                peer_class = peer_class.as_str(),
                 run_id = peer_class.worker_run_id().unwrap_or("<unresolved>"),
                 verb = %denial.verb,
                 reason = %denial.reason,
@@ -2332,6 +2388,28 @@ async fn handle_frontend_connection(
             continue;
         }
 
+        // Connection-scoped rather than a handler: it rewrites this
+        // connection's classification, which no handler can reach. Workers
+        // were refused by the gate above.
+        if let FrontendRequest::DeclareRemotePeer { transport } = &request {
+            peer_class = peer_class.declare_remote(transport);
+            tracing::info!(
+                session_id = %session_id,
+                peer_pid = ?peer_pid,
+                transport = %transport,
+                peer_class = peer_class.as_str(),
+                "frontend connection declared remote",
+            );
+            let _ = sink.enqueue(FrontendEventEnvelope::response(
+                request_id,
+                FrontendEvent::RemotePeerDeclared {
+                    transport: transport.clone(),
+                    peer_class: peer_class.as_str().to_owned(),
+                },
+            ));
+            continue;
+        }
+
         let ctx = Dispatch::builder()
             .server_state(server_state.clone())
             .work_db(work_db.clone())
@@ -2397,13 +2475,16 @@ async fn handle_frontend_connection(
             r @ FrontendRequest::DebugLiveStatusPipeline => {
                 live_status::handle_debug_live_status_pipeline(ctx, r).await
             }
+            FrontendRequest::DeclareRemotePeer { .. } => unreachable!("handled before dispatch"),
             r @ FrontendRequest::DeleteAutomation { .. } => automations::handle_delete_automation(ctx, r).await,
+            r @ FrontendRequest::DeleteSpendBudget { .. } => cost::handle_delete_spend_budget(ctx, r).await,
             r @ FrontendRequest::DeleteWorkItem { .. } => work_items::handle_delete_work_item(ctx, r).await,
             r @ FrontendRequest::DisableAutomation { .. } => automations::handle_disable_automation(ctx, r).await,
             r @ FrontendRequest::DismissAttention { .. } => attentions::handle_dismiss_attention(ctx, r).await,
             r @ FrontendRequest::EnableAutomation { .. } => automations::handle_enable_automation(ctx, r).await,
             r @ FrontendRequest::EngineResponse { .. } => sessions::handle_engine_response(ctx, r).await,
             r @ FrontendRequest::ExecutionTranscript { .. } => executions::handle_execution_transcript(ctx, r).await,
+            r @ FrontendRequest::ExplainDispatch { .. } => executions::handle_explain_dispatch(ctx, r).await,
             r @ FrontendRequest::FindWorkItemsByPr { .. } => work_items::handle_find_work_items_by_pr(ctx, r).await,
             r @ FrontendRequest::FocusWorkerPane { .. } => panes::handle_focus_worker_pane(ctx, r).await,
             r @ FrontendRequest::GetAttentionGroup { .. } => attentions::handle_get_attention_group(ctx, r).await,
@@ -2497,6 +2578,12 @@ async fn handle_frontend_connection(
             r @ FrontendRequest::ListProductDesignDocs { .. } => {
                 design_docs::handle_list_product_design_docs(ctx, r).await
             }
+            r @ FrontendRequest::ListProductDispatchCalendars => {
+                engine_meta::handle_list_product_dispatch_calendars(ctx, r).await
+            }
+            r @ FrontendRequest::ListProductDispatchShares => {
+                engine_meta::handle_list_product_dispatch_shares(ctx, r).await
+            }
             r @ FrontendRequest::ListProducts => products::handle_list_products(ctx, r).await,
             r @ FrontendRequest::ListProjects { .. } => projects::handle_list_projects(ctx, r).await,
             r @ FrontendRequest::ListAttachments { .. } => attachments::handle_list_attachments(ctx, r).await,
@@ -2505,6 +2592,7 @@ async fn handle_frontend_connection(
             }
             r @ FrontendRequest::ListProposals { .. } => proposals::handle_list_proposals(ctx, r).await,
             r @ FrontendRequest::ListRuns { .. } => executions::handle_list_runs(ctx, r).await,
+            r @ FrontendRequest::ListSpendBudgets { .. } => cost::handle_list_spend_budgets(ctx, r).await,
             r @ FrontendRequest::ListTasks { .. } => work_items::handle_list_tasks(ctx, r).await,
             r @ FrontendRequest::ListRevisions { .. } => work_items::handle_list_revisions(ctx, r).await,
             r @ FrontendRequest::ListWorkerLiveStates => panes::handle_list_worker_live_states(ctx, r).await,
@@ -2563,6 +2651,9 @@ async fn handle_frontend_connection(
             r @ FrontendRequest::RevealWorkItem { .. } => work_items::handle_reveal_work_item(ctx, r).await,
             r @ FrontendRequest::RevokeDecision { .. } => decisions::handle_revoke_decision(ctx, r).await,
             r @ FrontendRequest::RunAutomation { .. } => automations::handle_run_automation(ctx, r).await,
+            r @ FrontendRequest::PostAutomationWebhook { .. } => {
+                automations::handle_post_automation_webhook(ctx, r).await
+            }
             r @ FrontendRequest::SendInputToWorker { .. } => panes::handle_send_input_to_worker(ctx, r).await,
             r @ FrontendRequest::SetAutomationPaused { .. } => engine_meta::handle_set_automation_paused(ctx, r).await,
             r @ FrontendRequest::SetCiBudget { .. } => ci_remediation::handle_set_ci_budget(ctx, r).await,
@@ -2596,11 +2687,18 @@ async fn handle_frontend_connection(
             r @ FrontendRequest::EvaluateDispatchAdmission { .. } => {
                 executions::handle_evaluate_dispatch_admission(ctx, r).await
             }
+            r @ FrontendRequest::SetProductDispatchCalendar { .. } => {
+                engine_meta::handle_set_product_dispatch_calendar(ctx, r).await
+            }
+            r @ FrontendRequest::SetProductDispatchShare { .. } => {
+                engine_meta::handle_set_product_dispatch_share(ctx, r).await
+            }
             r @ FrontendRequest::SetProductExternalTracker { .. } => {
                 external_tracker::handle_set_product_external_tracker(ctx, r).await
             }
             r @ FrontendRequest::SetProjectDesignDoc { .. } => projects::handle_set_project_design_doc(ctx, r).await,
             r @ FrontendRequest::SetSetting { .. } => engine_meta::handle_set_setting(ctx, r).await,
+            r @ FrontendRequest::SetSpendBudget { .. } => cost::handle_set_spend_budget(ctx, r).await,
             r @ FrontendRequest::SetTaskDocPointer { .. } => work_items::handle_set_task_doc_pointer(ctx, r).await,
             r @ FrontendRequest::Shutdown { .. } => sessions::handle_shutdown(ctx, r).await,
             r @ FrontendRequest::SpawnCapabilityRestored => sessions::handle_spawn_capability_restored(ctx, r).await,
@@ -415,6 +415,57 @@ pub(super) async fn handle_run_automation(ctx: Dispatch, req: FrontendRequest) {
     }
 }
 
+pub(super) async fn handle_post_automation_webhook(ctx: Dispatch, req: FrontendRequest) {
+    let Dispatch {
+        server_state,
+        work_db,
+        sink,
+        request_id,
+        ..
+    } = ctx;
+    let FrontendRequest::PostAutomationWebhook {
+        product_id,
+        name,
+        payload,
+    } = req
+    else {
+        unreachable!()
+    };
+    {
+        // Local webhook (`boss automation webhook`): publish the event and
+        // return. Matching `webhook` automations fire from
+        // `automation_events` off the bus, so the reply only confirms the
+        // product exists and the event was accepted — not that anything ran.
+        match work_db.get_product(&product_id) {
+            Ok(Some(_)) => {}
+            Ok(None) => {
+                send_response(
+                    &sink,
+                    &request_id,
+                    FrontendEvent::WorkError {
+                        message: format!("unknown product: {product_id}"),
+                    },
+                );
+                return;
+            }
+            Err(err) => {
+                send_work_error(&sink, &request_id, &err);
+                return;
+            }
+        }
+        server_state.event_bus.publish(boss_event_bus::Event::AutomationWebhook {
+            product_id: product_id.clone(),
+            name: name.clone(),
+            payload,
+        });
+        send_response(
+            &sink,
+            &request_id,
+            FrontendEvent::AutomationWebhookAccepted { product_id, name },
+        );
+    }
+}
+
 pub(super) async fn handle_create_automation_task(ctx: Dispatch, req: FrontendRequest) {
     let Dispatch {
         server_state,
@@ -1,4 +1,5 @@
-//! `FrontendRequest` handlers — token-cost reporting (`boss cost`).
+//! `FrontendRequest` handlers — token-cost reporting (`boss cost`) and
+//! spend budgets (`boss cost budget`).
 //!
 //! Each handler is a thin fetch-then-build shim: pull the flat
 //! `work_runs` rows the request needs via `WorkDb`, hand them to the pure
@@ -126,3 +127,110 @@ pub(super) async fn handle_get_top_cost_consumers(ctx: Dispatch, req: FrontendRe
         send_response(&sink, &request_id, FrontendEvent::TopCostConsumers { report });
     }
 }
+
+pub(super) async fn handle_list_spend_budgets(ctx: Dispatch, req: FrontendRequest) {
+    let Dispatch {
+        server_state,
+        work_db,
+        sink,
+        request_id,
+        ..
+    } = ctx;
+    let FrontendRequest::ListSpendBudgets { scope_id } = req else {
+        unreachable!()
+    };
+    let scope_id = match scope_id {
+        Some(scope_id) => match server_state.resolve_work_item_id(&scope_id).await {
+            Ok(id) => Some(id),
+            Err(err) => return send_work_error(&sink, &request_id, &err),
+        },
+        None => None,
+    };
+    let rows = match work_db.list_spend_budgets(scope_id.as_deref()) {
+        Ok(rows) => rows,
+        Err(err) => return send_work_error(&sink, &request_id, &err),
+    };
+    let now = boss_engine_utils::epoch_time::now_epoch_secs();
+    let mut budgets = Vec::with_capacity(rows.len());
+    for row in rows {
+        match crate::spend_budget::status_for(&work_db, &row.budget, now) {
+            Ok(status) => budgets.push(status),
+            Err(err) => return send_work_error(&sink, &request_id, &err),
+        }
+    }
+    send_response(&sink, &request_id, FrontendEvent::SpendBudgetsList { budgets });
+}
+
+pub(super) async fn handle_set_spend_budget(ctx: Dispatch, req: FrontendRequest) {
+    let Dispatch {
+        server_state,
+        work_db,
+        sink,
+        request_id,
+        ..
+    } = ctx;
+    let FrontendRequest::SetSpendBudget { input } = req else {
+        unreachable!()
+    };
+    if let Err(message) = input.validate() {
+        return send_work_error(&sink, &request_id, message);
+    }
+    let scope_id = match server_state.resolve_work_item_id(&input.scope_id).await {
+        Ok(id) => id,
+        Err(err) => return send_work_error(&sink, &request_id, &err),
+    };
+    let scope_kind = match work_db.get_work_item(&scope_id) {
+        Ok(WorkItem::Product(_)) => "product",
+        Ok(WorkItem::Project(_)) => "project",
+        Ok(WorkItem::Task(_) | WorkItem::Chore(_)) => {
+            return send_work_error(
+                &sink,
+                &request_id,
+                format!("{scope_id} is a task — budgets apply to a product or a project"),
+            );
+        }
+        Err(err) => return send_work_error(&sink, &request_id, &err),
+    };
+    let row = match work_db.upsert_spend_budget(
+        scope_kind,
+        &scope_id,
+        input.metric,
+        input.period,
+        input.soft_limit,
+        input.hard_limit,
+    ) {
+        Ok(row) => row,
+        Err(err) => return send_work_error(&sink, &request_id, &err),
+    };
+    let status =
+        match crate::spend_budget::status_for(&work_db, &row.budget, boss_engine_utils::epoch_time::now_epoch_secs()) {
+            Ok(status) => status,
+            Err(err) => return send_work_error(&sink, &request_id, &err),
+        };
+    // A raised limit may release held work, a lowered one may hold it:
+    // drop the cached evaluation and let the next drain re-read it.
+    server_state.execution_coordinator.invalidate_spend_budgets();
+    server_state.execution_coordinator.kick();
+    send_response(&sink, &request_id, FrontendEvent::SpendBudgetSet { status });
+}
+
+pub(super) async fn handle_delete_spend_budget(ctx: Dispatch, req: FrontendRequest) {
+    let Dispatch {
+        server_state,
+        work_db,
+        sink,
+        request_id,
+        ..
+    } = ctx;
+    let FrontendRequest::DeleteSpendBudget { budget_id } = req else {
+        unreachable!()
+    };
+    let deleted = match work_db.delete_spend_budget(&budget_id) {
+        Ok(row) => row,
+        Err(err) => return send_work_error(&sink, &request_id, &err),
+    };
+    crate::spend_budget::resolve_after_delete(&work_db, &deleted, boss_engine_utils::epoch_time::now_epoch_secs());
+    server_state.execution_coordinator.invalidate_spend_budgets();
+    server_state.execution_coordinator.kick();
+    send_response(&sink, &request_id, FrontendEvent::SpendBudgetDeleted { budget_id });
+}
@@ -0,0 +1,132 @@
+//! Feeds the web dashboard's event stream from the topic broker.
+//!
+//! The dashboard is registered as one more broker session — id
+//! [`DASHBOARD_SESSION_ID`] — subscribed to the same topics `boss tui`
+//! listens on: products, live worker states, engine health, and each
+//! product's work topic. A drain task forwards every envelope it receives
+//! into a broadcast channel, one [`DashboardPush`] per envelope; each
+//! browser's `/dashboard/events` connection is a receiver on it. The
+//! broker's per-session queue, overflow handling and eviction apply to
+//! this session exactly as to a socket client.
+
+use boss_engine_attachments::dashboard::DashboardPush;
+use tokio::sync::broadcast;
+
+use super::*;
+
+/// Broker session id the dashboard feed registers under.
+const DASHBOARD_SESSION_ID: &str = "dashboard";
+
+/// Pushes buffered per browser before a slow one starts lagging (and
+/// resyncs from `state.json`).
+const PUSH_CAPACITY: usize = 256;
+
+/// Register the dashboard session and spawn its drain task. Returns the
+/// sender every event-stream connection subscribes to.
+pub(super) async fn spawn(server_state: &Arc<ServerState>) -> broadcast::Sender<DashboardPush> {
+    let (pushes, _) = broadcast::channel(PUSH_CAPACITY);
+    let sink = register(server_state).await;
+
+    let server_state = Arc::clone(server_state);
+    let tx = pushes.clone();
+    tokio::spawn(async move {
+        let mut sink = sink;
+        loop {
+            let Some(envelope) = sink.next().await else {
+                // Evicted by the broker (a queue that stopped draining).
+                // Come back with a fresh session; the page's resync on
+                // reconnect covers anything missed in between.
+                tracing::warn!("dashboard feed: broker closed the session; re-registering");
+                server_state.topic_broker.remove_session(DASHBOARD_SESSION_ID).await;
+                sink = register(&server_state).await;
+                continue;
+            };
+            let topic = push_topic(&envelope.payload);
+            if topic == TOPIC_WORK_PRODUCTS {
+                // A product may have been created: follow its work topic.
+                subscribe_product_topics(&server_state).await;
+            }
+            let event = match serde_json::to_value(&envelope.payload) {
+                Ok(event) => event,
+                Err(err) => {
+                    tracing::warn!(?err, topic, "dashboard feed: push did not serialize");
+                    continue;
+                }
+            };
+            // No receivers just means no browser has the page open.
+            let _ = tx.send(DashboardPush { topic, event });
+        }
+    });
+    pushes
+}
+
+async fn register(server_state: &ServerState) -> Arc<SessionSink> {
+    // Nothing listens for the shutdown signal: the dashboard session has
+    // no socket to close.
+    let (shutdown_tx, _shutdown_rx) = oneshot::channel();
+    let sink = Arc::new(SessionSink::new(shutdown_tx));
+    server_state
+        .topic_broker
+        .register_session(DASHBOARD_SESSION_ID, sink.clone())
+        .await;
+    server_state
+        .topic_broker
+        .subscribe(
+            DASHBOARD_SESSION_ID,
+            &[
+                TOPIC_WORK_PRODUCTS.to_owned(),
+                TOPIC_WORKER_LIVE_STATES.to_owned(),
+                TOPIC_ENGINE_HEALTH.to_owned(),
+            ],
+        )
+        .await;
+    subscribe_product_topics(server_state).await;
+    sink
+}
+
+async fn subscribe_product_topics(server_state: &ServerState) {
+    let products = match server_state.work_db.list_products() {
+        Ok(products) => products,
+        Err(err) => {
+            tracing::warn!(?err, "dashboard feed: listing products failed");
+            return;
+        }
+    };
+    let topics: Vec<String> = products.iter().map(|product| work_product_topic(&product.id)).collect();
+    server_state.topic_broker.subscribe(DASHBOARD_SESSION_ID, &topics).await;
+}
+
+/// The topic a push arrived on. Topic events carry theirs; the two
+/// snapshot broadcasts are identified by payload.
+fn push_topic(payload: &FrontendEvent) -> String {
+    match payload {
+        FrontendEvent::WorkerLiveStatesList { .. } => TOPIC_WORKER_LIVE_STATES.to_owned(),
+        FrontendEvent::EngineHealthResult { .. } => TOPIC_ENGINE_HEALTH.to_owned(),
+        other => topic_event_topic(other).unwrap_or_else(|| "push".to_owned()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_topic_names_snapshot_broadcasts_and_topic_events() {
+        let event = FrontendEvent::TopicEvent {
+            topic: work_product_topic("prod_1"),
+            revision: 1,
+            origin_session_id: String::new(),
+            origin_request_id: None,
+            event: TopicEventPayload::WorkInvalidated {
+                reason: "test".to_owned(),
+                product_id: Some("prod_1".to_owned()),
+                item_ids: Vec::new(),
+            },
+        };
+        assert_eq!(push_topic(&event), work_product_topic("prod_1"));
+        assert_eq!(
+            push_topic(&FrontendEvent::WorkerLiveStatesList { states: Vec::new() }),
+            TOPIC_WORKER_LIVE_STATES
+        );
+    }
+}
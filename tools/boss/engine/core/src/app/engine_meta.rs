@@ -497,6 +497,160 @@ pub(super) async fn handle_set_dispatch_concurrency(ctx: Dispatch, req: Frontend
     );
 }
 
+pub(super) async fn handle_list_product_dispatch_shares(ctx: Dispatch, req: FrontendRequest) {
+    let Dispatch {
+        server_state,
+        sink,
+        request_id,
+        ..
+    } = ctx;
+    let FrontendRequest::ListProductDispatchShares = req else {
+        unreachable!()
+    };
+    match server_state.execution_coordinator.product_dispatch_shares() {
+        Ok(shares) => send_response(&sink, &request_id, FrontendEvent::ProductDispatchSharesList { shares }),
+        Err(err) => send_work_error(&sink, &request_id, &err),
+    }
+}
+
+pub(super) async fn handle_set_product_dispatch_share(ctx: Dispatch, req: FrontendRequest) {
+    let Dispatch {
+        server_state,
+        work_db,
+        sink,
+        request_id,
+        ..
+    } = ctx;
+    let FrontendRequest::SetProductDispatchShare { input } = req else {
+        unreachable!()
+    };
+    if let Err(message) = input.validate() {
+        return send_work_error(&sink, &request_id, message);
+    }
+    let product_id = match server_state.resolve_work_item_id(&input.product_id).await {
+        Ok(id) => id,
+        Err(err) => return send_work_error(&sink, &request_id, &err),
+    };
+    match work_db.get_work_item(&product_id) {
+        Ok(WorkItem::Product(_)) => {}
+        Ok(_) => {
+            return send_work_error(
+                &sink,
+                &request_id,
+                format!("{product_id} is not a product — dispatch shares apply per product"),
+            );
+        }
+        Err(err) => return send_work_error(&sink, &request_id, &err),
+    }
+    let setting = crate::work::DispatchShareSetting {
+        weight: input.weight,
+        max_concurrent: input.max_concurrent,
+    };
+    if let Err(err) = work_db.set_product_dispatch_share(&product_id, setting) {
+        return send_work_error(&sink, &request_id, &err);
+    }
+    let coordinator = &server_state.execution_coordinator;
+    // A raised weight or ceiling may let held rows through right away.
+    coordinator.kick();
+    tracing::info!(
+        product_id,
+        weight = input.weight,
+        max_concurrent = ?input.max_concurrent,
+        "dispatch: product share updated"
+    );
+    match coordinator.product_dispatch_share(&product_id) {
+        Ok(share) => send_response(&sink, &request_id, FrontendEvent::ProductDispatchShareSet { share }),
+        Err(err) => send_work_error(&sink, &request_id, &err),
+    }
+}
+
+pub(super) async fn handle_list_product_dispatch_calendars(ctx: Dispatch, req: FrontendRequest) {
+    let Dispatch {
+        server_state,
+        sink,
+        request_id,
+        ..
+    } = ctx;
+    let FrontendRequest::ListProductDispatchCalendars = req else {
+        unreachable!()
+    };
+    match server_state.execution_coordinator.product_dispatch_calendars() {
+        Ok(calendars) => send_response(
+            &sink,
+            &request_id,
+            FrontendEvent::ProductDispatchCalendarsList { calendars },
+        ),
+        Err(err) => send_work_error(&sink, &request_id, &err),
+    }
+}
+
+pub(super) async fn handle_set_product_dispatch_calendar(ctx: Dispatch, req: FrontendRequest) {
+    let Dispatch {
+        server_state,
+        work_db,
+        sink,
+        request_id,
+        ..
+    } = ctx;
+    let FrontendRequest::SetProductDispatchCalendar { input } = req else {
+        unreachable!()
+    };
+    if let Err(message) = input.validate() {
+        return send_work_error(&sink, &request_id, message);
+    }
+    let product_id = match server_state.resolve_work_item_id(&input.product_id).await {
+        Ok(id) => id,
+        Err(err) => return send_work_error(&sink, &request_id, &err),
+    };
+    match work_db.get_work_item(&product_id) {
+        Ok(WorkItem::Product(_)) => {}
+        Ok(_) => {
+            return send_work_error(
+                &sink,
+                &request_id,
+                format!("{product_id} is not a product — dispatch calendars apply per product"),
+            );
+        }
+        Err(err) => return send_work_error(&sink, &request_id, &err),
+    }
+    let setting = (!input.clears()).then(|| crate::work::DispatchCalendarSetting {
+        timezone: input.timezone.trim().to_owned(),
+        windows: input.windows.clone(),
+        quiet_hours: input.quiet_hours.clone(),
+        drain_before_close_minutes: input.drain_before_close_minutes,
+    });
+    // Parse the timezone and every window now, so a stored calendar
+    // always compiles and the gate never has to skip one.
+    if let Some(setting) = &setting
+        && let Err(err) = crate::dispatch_calendar::CompiledCalendar::compile(setting)
+    {
+        return send_work_error(&sink, &request_id, format!("invalid dispatch calendar: {err}"));
+    }
+    if let Err(err) = work_db.set_product_dispatch_calendar(&product_id, setting.as_ref()) {
+        return send_work_error(&sink, &request_id, &err);
+    }
+    let coordinator = &server_state.execution_coordinator;
+    // A widened or cleared calendar may let held rows through right away.
+    coordinator.invalidate_dispatch_calendars();
+    coordinator.kick();
+    tracing::info!(
+        product_id,
+        timezone = %input.timezone,
+        windows = input.windows.len(),
+        quiet_hours = input.quiet_hours.len(),
+        drain_before_close_minutes = input.drain_before_close_minutes,
+        "dispatch: product calendar updated"
+    );
+    match coordinator.product_dispatch_calendar(&product_id) {
+        Ok(calendar) => send_response(
+            &sink,
+            &request_id,
+            FrontendEvent::ProductDispatchCalendarSet { calendar },
+        ),
+        Err(err) => send_work_error(&sink, &request_id, &err),
+    }
+}
+
 /// Serve the per-driver provider quota snapshot.
 ///
 /// The request loop is sequential, so this handler must not await a probe
@@ -214,6 +214,28 @@ pub(super) async fn handle_evaluate_dispatch_admission(ctx: Dispatch, req: Front
     }
 }
 
+/// `bossctl dispatch explain`: the admission evaluation above plus the
+/// execution's wait reason and its place in the fair-share order.
+pub(super) async fn handle_explain_dispatch(ctx: Dispatch, req: FrontendRequest) {
+    let Dispatch {
+        server_state,
+        sink,
+        request_id,
+        ..
+    } = ctx;
+    let FrontendRequest::ExplainDispatch { work_item_id } = req else {
+        unreachable!()
+    };
+    let work_item_id = match server_state.resolve_work_item_id(&work_item_id).await {
+        Ok(id) => id,
+        Err(err) => return send_work_error(&sink, &request_id, &err),
+    };
+    match server_state.execution_coordinator.explain_dispatch(&work_item_id).await {
+        Ok(explanation) => send_response(&sink, &request_id, FrontendEvent::DispatchExplained { explanation }),
+        Err(err) => send_work_error(&sink, &request_id, &err),
+    }
+}
+
 pub(super) async fn handle_list_executions(ctx: Dispatch, req: FrontendRequest) {
     let Dispatch {
         server_state,
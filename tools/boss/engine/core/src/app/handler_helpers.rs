@@ -900,9 +900,17 @@ pub(super) fn work_item_id(item: &WorkItem) -> String {
     }
 }
 
-/// Validate a kind-specific external tracker config JSON.
+/// Validate a kind-specific external tracker config JSON, plus the optional
+/// two-way `sync` object every kind shares.
 /// Returns `Err` with a human-readable message when validation fails.
 pub(super) fn validate_external_tracker_config(kind: &str, config: &serde_json::Value) -> Result<(), String> {
+    validate_external_tracker_transport_config(kind, config)?;
+    crate::external_tracker::reconcile::parse_sync_config(config)
+        .map(|_| ())
+        .map_err(|e| format!("{e} for kind={kind}"))
+}
+
+fn validate_external_tracker_transport_config(kind: &str, config: &serde_json::Value) -> Result<(), String> {
     match kind {
         "github" => {
             for field in ["org", "repo"] {
@@ -924,7 +932,14 @@ pub(super) fn validate_external_tracker_config(kind: &str, config: &serde_json::
             }
             Ok(())
         }
-        other => Err(format!("unknown tracker kind '{other}'; supported: github")),
+        "linear" => crate::external_tracker::linear::validate_config(config)
+            .map_err(|e| format!("{} for kind=linear", e.message)),
+        "jira" => {
+            crate::external_tracker::jira::validate_config(config).map_err(|e| format!("{} for kind=jira", e.message))
+        }
+        other => Err(format!(
+            "unknown tracker kind '{other}'; supported: github, linear, jira"
+        )),
     }
 }
 
@@ -1448,8 +1463,57 @@ mod tests {
     fn validate_tracker_unknown_kind() {
         let config = serde_json::json!({});
         assert_eq!(
-            validate_external_tracker_config("jira", &config),
-            Err("unknown tracker kind 'jira'; supported: github".to_owned()),
+            validate_external_tracker_config("gitlab", &config),
+            Err("unknown tracker kind 'gitlab'; supported: github, linear, jira".to_owned()),
+        );
+    }
+
+    #[test]
+    fn validate_tracker_linear_and_jira_delegate_to_transport_schemas() {
+        assert_eq!(
+            validate_external_tracker_config("linear", &serde_json::json!({ "team_key": "ENG" })),
+            Ok(())
+        );
+        assert_eq!(
+            validate_external_tracker_config("linear", &serde_json::json!({})),
+            Err("missing required field 'team_key' for kind=linear".to_owned()),
+        );
+        let jira = serde_json::json!({
+            "base_url": "https://acme.atlassian.net",
+            "project_key": "OPS",
+            "email": "bot@acme.dev",
+        });
+        assert_eq!(validate_external_tracker_config("jira", &jira), Ok(()));
+        assert_eq!(
+            validate_external_tracker_config("jira", &serde_json::json!({ "base_url": "https://x" })),
+            Err("missing required field 'project_key' for kind=jira".to_owned()),
+        );
+    }
+
+    #[test]
+    fn validate_tracker_sync_object() {
+        let mut config = serde_json::json!({
+            "team_key": "ENG",
+            "sync": { "title": "upstream_wins", "priority": "last_writer_wins", "comments": true },
+        });
+        assert_eq!(validate_external_tracker_config("linear", &config), Ok(()));
+
+        config["sync"] = serde_json::json!({ "title": "newest" });
+        assert_eq!(
+            validate_external_tracker_config("linear", &config),
+            Err(
+                "invalid policy for 'sync.title'; supported: upstream_wins, boss_wins, last_writer_wins for kind=linear"
+                    .to_owned()
+            ),
+        );
+
+        config["sync"] = serde_json::json!({ "labels": "boss_wins" });
+        assert_eq!(
+            validate_external_tracker_config("linear", &config),
+            Err(
+                "unknown sync field 'labels'; supported: title, description, priority, assignee, comments for kind=linear"
+                    .to_owned()
+            ),
         );
     }
 
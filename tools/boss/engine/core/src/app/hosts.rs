@@ -16,6 +16,8 @@ fn to_host_snapshot(host: Host, caps: Vec<HostCapability>) -> HostSnapshot {
     HostSnapshot {
         id: host.id,
         ssh_target: host.ssh_target,
+        container_runtime: host.container_runtime,
+        container_image: host.container_image,
         pool_size: host.pool_size,
         enabled: host.enabled,
         last_seen_at: host.last_seen_at,
@@ -1568,11 +1568,26 @@ pub async fn serve_with_merge_probe(
     // The evidence surface: a loopback HTTP gallery for screenshots workers
     // attached with `boss attach`, and the link a reviewer clicks from a
     // GitHub PR. Bind failure is logged and the engine carries on — see
-    // `attachment_server::spawn`.
+    // `attachment_server::spawn`. With `BOSS_DASHBOARD` set the same
+    // listener also serves the read-only web dashboard, fed from the topic
+    // broker.
+    let dashboard: Option<Arc<dyn boss_engine_attachments::DashboardSource>> =
+        if boss_engine_attachments::dashboard::configured() {
+            let pushes = dashboard_feed::spawn(&server_state).await;
+            Some(Arc::new(crate::dashboard::EngineDashboardSource::new(
+                server_state.work_db.clone(),
+                server_state.live_worker_states.clone(),
+                server_state.execution_coordinator.clone(),
+                pushes,
+            )))
+        } else {
+            None
+        };
     let _attachment_server_handle = crate::attachment_server::spawn(
         server_state.work_db.clone(),
         server_state.attachment_store.clone(),
         server_state.evidence_port.clone(),
+        dashboard,
     )
     .await;
 
@@ -1638,7 +1653,10 @@ pub async fn serve_with_merge_probe(
     // once saturation is sustained; `build_engine_health_report` reads it
     // to raise a banner naming the cause and the `sudo kill -9 <pid>`
     // remedy. Detection only — the engine cannot safely kill the daemon
-    // unattended (SIP blocks `launchctl kickstart`).
+    // unattended (SIP blocks `launchctl kickstart`). There is no
+    // `syspolicyd` off macOS, so Linux engines skip the `ps` sampling and
+    // the health snapshot stays at its never-saturated default.
+    #[cfg(target_os = "macos")]
     let _syspolicyd_monitor_handle = crate::syspolicyd_monitor::spawn_loop(
         server_state.syspolicyd_health.clone(),
         crate::syspolicyd_monitor::DEFAULT_SAMPLE_INTERVAL,
@@ -1758,6 +1776,21 @@ pub async fn serve_with_merge_probe(
         crate::host_reconcile::DEFAULT_INTERVAL,
     );
 
+    // Periodic host-load sweep: samples CPU / memory / disk on every
+    // enabled host (SSH hosts over their ControlMaster) and refreshes
+    // per-host driver quota, which `select_host` folds into its ranking
+    // score. Each pass then re-places executions parked by a
+    // `no_eligible_host` backoff as soon as a host would take them.
+    let _host_load_handle = crate::host_load::spawn_loop(
+        crate::host_load::HostLoadSweep::new(
+            server_state.work_db.clone(),
+            server_state.execution_coordinator.clone(),
+            server_state.driver_quota.clone(),
+            crate::ssh_transport::default_control_socket_dir(),
+        ),
+        crate::host_load::DEFAULT_INTERVAL,
+    );
+
     // External-tracker reconciler: periodically pulls upstream issue state
     // into Boss's work-item taxonomy. Default cadence: 120 s (2 min) per
     // the design doc's §"Cadence" rationale (Design Q5). Fires immediately
@@ -1856,7 +1889,7 @@ pub async fn serve_with_merge_probe(
         Arc::new(boss_timer_wheel::TimerWheel::spawn(server_state.event_bus.clone()));
     let _automation_scheduler_handle = crate::automation_scheduler::spawn_loop(
         server_state.work_db.clone(),
-        automation_triage_dispatcher,
+        automation_triage_dispatcher.clone(),
         server_state.event_bus.subscribe(boss_event_bus::TopicFilter::kinds([
             boss_event_bus::EventKind::AutomationMutation,
             boss_event_bus::EventKind::Timer,
@@ -1865,6 +1898,33 @@ pub async fn serve_with_merge_probe(
         automation_scheduler_timer_wheel,
     );
 
+    // Event-triggered automations (`pr_merged`, `ci_red`, `work_item_status`,
+    // `upstream_label`, `webhook`): the same dispatcher and pause flag as the
+    // cron scheduler, driven by bus events instead of a schedule. The
+    // default-branch CI watch is the one producer that exists only for this;
+    // it polls only products with an enabled `ci_red` automation.
+    let coord_for_event_automation_pause_check = server_state.execution_coordinator.clone();
+    let _automation_events_handle = crate::automation_events::spawn_loop(
+        server_state.work_db.clone(),
+        automation_triage_dispatcher,
+        server_state.event_bus.subscribe_durable(
+            crate::automation_events::DURABLE_SUBSCRIBER_NAME,
+            boss_event_bus::TopicFilter::kinds([
+                boss_event_bus::EventKind::PrMerged,
+                boss_event_bus::EventKind::WorkItemStatusChanged,
+                boss_event_bus::EventKind::DefaultBranchCiRed,
+                boss_event_bus::EventKind::UpstreamItemImported,
+                boss_event_bus::EventKind::AutomationWebhook,
+            ]),
+        ),
+        Arc::new(move || coord_for_event_automation_pause_check.is_automation_paused()),
+    );
+    let _default_branch_ci_handle = crate::default_branch_ci::spawn_loop(
+        server_state.work_db.clone(),
+        server_state.event_bus.clone(),
+        Arc::new(crate::default_branch_ci::GhDefaultBranchCi),
+    );
+
     // Scheduler heartbeat: periodic `kick()` so a ready row stranded
     // by a dropped wakeup (the `status_transition` → `request_recorded`
     // stall class — see `exec_18af3ba5259d32a8_12`, 2026-05-13) is
@@ -1966,6 +2026,34 @@ pub async fn serve_with_merge_probe(
     let _metrics_flush_handle =
         crate::metrics::spawn_flush_task(server_state.metrics.clone(), server_state.work_db.clone());
 
+    // Opt-in OpenMetrics scrape endpoint (`BOSS_METRICS_EXPORTER_ADDR`) for
+    // a local Prometheus. Reads the same live registry the flush task
+    // snapshots; bind failure is logged and the engine carries on, like the
+    // evidence surface above.
+    let _metrics_exporter_handle = match cfg.work.metrics_exporter_endpoint.as_ref() {
+        Some(endpoint) => match crate::metrics::exporter::spawn(
+            server_state.metrics.clone(),
+            endpoint,
+            crate::metrics_init::LABEL_PATTERNS,
+        )
+        .await
+        {
+            Ok(handle) => {
+                tracing::info!(%endpoint, "metrics exporter: serving OpenMetrics");
+                Some(handle)
+            }
+            Err(err) => {
+                tracing::warn!(
+                    %endpoint,
+                    error = %err,
+                    "metrics exporter: bind failed; metrics are still flushed to state.db",
+                );
+                None
+            }
+        },
+        None => None,
+    };
+
     // Periodic stalled-spawn + stale-activity honesty timers. Two complementary
     // Spawning-lie fixes share one 10s tick so agents-list activity stays
     // trustworthy after events.sock degrades:
@@ -1,4 +1,4 @@
-#[cfg(target_os = "macos")]
+#[cfg(any(target_os = "macos", target_os = "linux"))]
 use super::super::server::pid_is_alive;
 use super::*;
 
@@ -28,7 +28,7 @@ fn set_boss_pid_round_trips() {
     assert_eq!(server_state.current_boss_pid(), Some(11111));
 }
 
-#[cfg(target_os = "macos")]
+#[cfg(any(target_os = "macos", target_os = "linux"))]
 fn server_state_with_app_pid(app_pid: libc::pid_t) -> (Arc<ServerState>, tempfile::TempDir) {
     let temp = tempfile::tempdir().unwrap();
     let cfg = Arc::new(RuntimeConfig::from_parts(
@@ -43,7 +43,7 @@ fn server_state_with_app_pid(app_pid: libc::pid_t) -> (Arc<ServerState>, tempfil
     (state, temp)
 }
 
-#[cfg(target_os = "macos")]
+#[cfg(any(target_os = "macos", target_os = "linux"))]
 #[test]
 fn boss_only_admits_app_descendant_when_boss_pid_unregistered() {
     // Repro for the production bug: macOS app hadn't registered the
@@ -62,7 +62,7 @@ fn boss_only_admits_app_descendant_when_boss_pid_unregistered() {
     );
 }
 
-#[cfg(target_os = "macos")]
+#[cfg(any(target_os = "macos", target_os = "linux"))]
 #[test]
 fn app_or_boss_admits_worker_descendant() {
     // Regression for `bossctl agents stop` rejecting calls made
@@ -88,7 +88,7 @@ fn app_or_boss_admits_worker_descendant() {
 /// Spawn `/usr/bin/true`, wait for it to exit, and return its
 /// (now-reaped, definitely-dead) pid. Used to exercise the
 /// dead-old-app reattach branch without guessing an unused pid.
-#[cfg(target_os = "macos")]
+#[cfg(any(target_os = "macos", target_os = "linux"))]
 fn reaped_child_pid() -> libc::pid_t {
     let mut child = std::process::Command::new("/usr/bin/true")
         .spawn()
@@ -98,7 +98,7 @@ fn reaped_child_pid() -> libc::pid_t {
     pid
 }
 
-#[cfg(target_os = "macos")]
+#[cfg(any(target_os = "macos", target_os = "linux"))]
 #[test]
 fn pid_is_alive_true_for_self_false_for_reaped_child() {
     let self_pid = std::process::id() as libc::pid_t;
@@ -142,7 +142,7 @@ fn register_trust_accepts_matching_pid_and_rejects_unknown_live_pid() {
     assert!(!register_app_session_trust_ok(Some(self_pid), None, engine_pid));
 }
 
-#[cfg(target_os = "macos")]
+#[cfg(any(target_os = "macos", target_os = "linux"))]
 #[test]
 fn register_trust_accepts_relaunched_app_when_old_app_pid_is_dead() {
     // The core reattach repro: the engine survived an app restart,
@@ -161,7 +161,7 @@ fn register_trust_accepts_relaunched_app_when_old_app_pid_is_dead() {
     );
 }
 
-#[cfg(target_os = "macos")]
+#[cfg(any(target_os = "macos", target_os = "linux"))]
 #[test]
 fn set_app_pid_repins_trust_root() {
     // After a successful reattach the engine re-pins app_pid so RPC
@@ -178,7 +178,7 @@ fn set_app_pid_repins_trust_root() {
     assert!(server_state.authorize_rpc(RpcTier::BossOnly, Some(self_pid)));
 }
 
-#[cfg(target_os = "macos")]
+#[cfg(any(target_os = "macos", target_os = "linux"))]
 #[test]
 fn boss_only_rejects_worker_descendant_when_boss_pid_unregistered() {
     // Even with the boss_pid-missing fallback, anything descending
@@ -198,7 +198,7 @@ fn boss_only_rejects_worker_descendant_when_boss_pid_unregistered() {
     );
 }
 
-#[cfg(target_os = "macos")]
+#[cfg(any(target_os = "macos", target_os = "linux"))]
 #[test]
 fn boss_only_uses_boss_pid_when_registered() {
     let self_pid = std::process::id() as libc::pid_t;
@@ -213,7 +213,7 @@ fn boss_only_uses_boss_pid_when_registered() {
     );
 }
 
-#[cfg(target_os = "macos")]
+#[cfg(any(target_os = "macos", target_os = "linux"))]
 #[test]
 fn user_tier_admits_caller_outside_app_and_boss_subtrees() {
     // `bossctl workspace summary` is User-tier (read-only proxy of
@@ -238,7 +238,7 @@ fn user_tier_admits_caller_outside_app_and_boss_subtrees() {
     );
 }
 
-#[cfg(target_os = "macos")]
+#[cfg(any(target_os = "macos", target_os = "linux"))]
 #[test]
 fn app_or_boss_admits_caller_outside_subtrees_when_not_a_worker() {
     // Repro for the work item: `bossctl agents transcript` (and its
@@ -262,7 +262,7 @@ fn app_or_boss_admits_caller_outside_subtrees_when_not_a_worker() {
     );
 }
 
-#[cfg(target_os = "macos")]
+#[cfg(any(target_os = "macos", target_os = "linux"))]
 #[test]
 fn app_or_boss_rejects_worker_descendant_outside_app_subtree() {
     // Defense-in-depth for the AppOrBoss fallback: a caller that
@@ -108,6 +108,25 @@ fn worker_tier_authorization_is_not_permissive_without_trust_roots() {
     assert!(server_state.authorize_rpc(RpcTier::Worker, Some(self_pid())));
 }
 
+#[test]
+fn remote_declaration_relabels_only_an_unclassified_peer() {
+    let (server_state, _dir) = test_server_state();
+    let class = server_state.classify_peer(Some(self_pid())).declare_remote("ssh");
+    assert_eq!(
+        class,
+        PeerClass::Remote {
+            transport: "ssh".to_owned()
+        }
+    );
+    assert!(!class.is_worker());
+    assert_eq!(class.worker_run_id(), None);
+
+    // A worker running `boss stdio-proxy` itself must not shed its tier.
+    server_state.worker_registry.register(self_pid(), "exec_abc".to_owned());
+    let class = server_state.classify_peer(Some(self_pid())).declare_remote("ssh");
+    assert_eq!(class.worker_run_id(), Some("exec_abc"));
+}
+
 // ── The gate ─────────────────────────────────────────────────────────────────
 
 #[test]
@@ -167,6 +186,37 @@ fn flag_off_leaves_workers_at_the_historical_user_tier() {
     );
 }
 
+#[test]
+fn remote_peer_is_refused_registration_and_boss_only_verbs() {
+    // Enforced regardless of `worker_rpc_tier`: remote connections have no
+    // historical behaviour to roll back to.
+    let (server_state, _dir) = test_server_state();
+    let class = server_state.classify_peer(Some(self_pid())).declare_remote("ssh");
+
+    for request in [
+        FrontendRequest::RegisterAppSession,
+        FrontendRequest::UpdateWorkerShellPid {
+            run_id: "exec_abc".to_owned(),
+            shell_pid: 4242,
+        },
+        FrontendRequest::ReapRun {
+            run_id: "exec_abc".to_owned(),
+        },
+        FrontendRequest::RetirePane { slot_id: 1 },
+    ] {
+        let denial = server_state
+            .worker_tier_denial(&class, &request)
+            .unwrap_or_else(|| panic!("{request:?} must be refused to a remote peer"));
+        assert_eq!(denial.reason, WorkerTierDenialReason::LocalOnly);
+        assert!(denial.message.contains("remote connection"), "{}", denial.message);
+    }
+
+    // Everything a plain terminal may call stays open, including what a
+    // worker is refused.
+    assert!(server_state.worker_tier_denial(&class, &allowed_request()).is_none());
+    assert!(server_state.worker_tier_denial(&class, &denied_request()).is_none());
+}
+
 // ── End to end over a real connection ────────────────────────────────────────
 
 /// Drive one request through a real `handle_frontend_connection` as `peer_pid`
@@ -236,6 +286,16 @@ async fn denied_verb_never_reaches_its_handler() {
     );
 }
 
+#[tokio::test]
+async fn remote_declaration_is_answered_with_the_resulting_class() {
+    let (server_state, _dir) = test_server_state();
+    let request = r#"{"request_id":"req-1","payload":{"type":"declare_remote_peer","transport":"ssh"}}"#;
+    let parsed = round_trip(server_state, Some(self_pid()), request).await;
+    assert_eq!(parsed["request_id"], "req-1");
+    assert_eq!(parsed["payload"]["type"], "remote_peer_declared");
+    assert_eq!(parsed["payload"]["peer_class"], "remote");
+}
+
 #[tokio::test]
 async fn allowed_verb_from_a_worker_still_executes() {
     let (server_state, _dir) = enforcing_state("exec_abc");
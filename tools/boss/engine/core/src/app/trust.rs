@@ -89,7 +89,7 @@ pub enum RpcTier {
 /// Resolved once per connection rather than per request: the `boss` CLI
 /// opens a fresh connection per invocation and the macOS app holds one for
 /// its lifetime, so a per-request ancestry walk would buy nothing and cost a
-/// `proc_pidinfo` chain on every app request.
+/// parent-pid chain on every app request.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PeerClass {
     /// The peer descends from a registered worker pane shell. `run_id` is
@@ -110,6 +110,15 @@ pub enum PeerClass {
     /// peer *is* classified as a worker but resolution then fails — that
     /// path refuses rather than falling back (see `app::proposals`).
     Other,
+    /// A connection relayed from another machine by `boss stdio-proxy`
+    /// (`transport` is how it arrived, e.g. `ssh`). Reached only from
+    /// [`PeerClass::Other`] via [`PeerClass::declare_remote`]: the socket
+    /// peer is the proxy process, so ancestry has already been checked and
+    /// found no worker. Admitted to the same tiers as a plain terminal on
+    /// the engine host — the proxy is one — except for the verbs
+    /// [`remote_scope_denial`] keeps local, and, like every non-worker,
+    /// never to the worker tier.
+    Remote { transport: String },
 }
 
 impl PeerClass {
@@ -117,13 +126,35 @@ impl PeerClass {
     pub fn worker_run_id(&self) -> Option<&str> {
         match self {
             PeerClass::Worker { run_id } => Some(run_id),
-            PeerClass::Other => None,
+            PeerClass::Other | PeerClass::Remote { .. } => None,
         }
     }
 
     pub fn is_worker(&self) -> bool {
         matches!(self, PeerClass::Worker { .. })
     }
+
+    /// Apply a `DeclareRemotePeer` declaration. The declaration is the
+    /// caller's word, so it can only relabel an unclassified connection:
+    /// a worker stays a worker, which is what keeps a worker from shedding
+    /// its tier by running the proxy itself.
+    pub fn declare_remote(self, transport: &str) -> PeerClass {
+        match self {
+            PeerClass::Other | PeerClass::Remote { .. } => PeerClass::Remote {
+                transport: transport.to_owned(),
+            },
+            worker @ PeerClass::Worker { .. } => worker,
+        }
+    }
+
+    /// Wire label, as reported in `RemotePeerDeclared`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PeerClass::Worker { .. } => "worker",
+            PeerClass::Other => "other",
+            PeerClass::Remote { .. } => "remote",
+        }
+    }
 }
 
 impl ServerState {
@@ -296,11 +327,18 @@ impl ServerState {
     ///    the verb policy; the app, the Boss pane, and plain terminals are
     ///    untouched, which is the "a human/coordinator shell not descended
     ///    from a worker pid is unaffected" property.
+    ///
+    /// A remote peer is held to [`remote_scope_denial`] instead, ahead of
+    /// the flag: remote connections are new, so there is no historical
+    /// behaviour for a rollback to restore.
     pub(super) fn worker_tier_denial(
         &self,
         peer_class: &PeerClass,
         request: &FrontendRequest,
     ) -> Option<WorkerTierDenial> {
+        if let PeerClass::Remote { .. } = peer_class {
+            return remote_scope_denial(request);
+        }
         if !peer_class.is_worker() {
             return None;
         }
@@ -356,3 +394,243 @@ impl ServerState {
         ))
     }
 }
+
+/// The remote scope: what a connection relayed by `boss stdio-proxy` may not
+/// call. `authorize_rpc` cannot tell such a connection apart, because its
+/// socket peer is the local proxy process, so these are refused here rather
+/// than by tier.
+///
+/// - Trust-root registration and the app's session reports. They repoint
+///   the engine's notion of which local process is the app, the Boss
+///   session, or a worker pane — something only a process on this host can
+///   truthfully report.
+/// - The `BossOnly` break-glass verbs (`ReapRun`, `RetirePane`). The tier
+///   exists to keep them to the Boss pane, and a remote caller is by
+///   definition not in it; the proxy only passes the tier's no-Boss-pid
+///   fallback by accident of where it was spawned.
+///
+/// Everything else stays open, as it is to a plain terminal on the host.
+pub(super) fn remote_scope_denial(request: &FrontendRequest) -> Option<WorkerTierDenial> {
+    let deny = || Some(WorkerTierDenial::remote(variant_name(request)));
+    // Exhaustive on purpose, like `worker_verb_decision`: a new verb must
+    // be placed in one of these arms before it is reachable remotely.
+    match request {
+        // ── Denied: trust-root registration and the app's session reports ──
+        FrontendRequest::EngineResponse { .. }
+        | FrontendRequest::RegisterAppSession
+        | FrontendRequest::RegisterCapabilities { .. }
+        | FrontendRequest::ReportSelectedProduct { .. }
+        | FrontendRequest::ReportWorkerSpawnFailed { .. }
+        | FrontendRequest::SpawnCapabilityRestored
+        | FrontendRequest::UpdateWorkerShellPid { .. }
+        | FrontendRequest::WorkerPaneDied { .. } => deny(),
+
+        // ── Denied: `BossOnly` break-glass ──
+        FrontendRequest::ReapRun { .. } | FrontendRequest::RetirePane { .. } => deny(),
+
+        // ── Allowed: what a plain terminal on the host may call ──
+        FrontendRequest::AbandonCiRemediation { .. }
+        | FrontendRequest::AbandonConflictResolution { .. }
+        | FrontendRequest::AcceptDeferredScopeAttention { .. }
+        | FrontendRequest::ActionAttentionGroup { .. }
+        | FrontendRequest::AddDependency { .. }
+        | FrontendRequest::AddHost { .. }
+        | FrontendRequest::AddHostTag { .. }
+        | FrontendRequest::AnswerAttention { .. }
+        | FrontendRequest::AuditProductEffort { .. }
+        | FrontendRequest::CancelExecution { .. }
+        | FrontendRequest::ClassifyCiRemediation { .. }
+        | FrontendRequest::CommentsBannerState { .. }
+        | FrontendRequest::CommentsCreate { .. }
+        | FrontendRequest::CommentsDismiss { .. }
+        | FrontendRequest::CommentsGet { .. }
+        | FrontendRequest::CommentsList { .. }
+        | FrontendRequest::CommentsPostAnswer { .. }
+        | FrontendRequest::CommentsPostFollowup { .. }
+        | FrontendRequest::CommentsResolve { .. }
+        | FrontendRequest::CommentsReviseDoc { .. }
+        | FrontendRequest::CommentsSetIntent { .. }
+        | FrontendRequest::CommentsSetStatus { .. }
+        | FrontendRequest::CommentsUpdateAnchor { .. }
+        | FrontendRequest::CreateAttention { .. }
+        | FrontendRequest::CreateAttentionItem { .. }
+        | FrontendRequest::CreateAutomation { .. }
+        | FrontendRequest::CreateAutomationTask { .. }
+        | FrontendRequest::CreateChore { .. }
+        | FrontendRequest::CreateDecision { .. }
+        | FrontendRequest::CreateExecution { .. }
+        | FrontendRequest::CreateInvestigation { .. }
+        | FrontendRequest::CreateManyChores { .. }
+        | FrontendRequest::CreateManyTasks { .. }
+        | FrontendRequest::CreateProduct { .. }
+        | FrontendRequest::CreateProject { .. }
+        | FrontendRequest::CreateRevision { .. }
+        | FrontendRequest::CreateRun { .. }
+        | FrontendRequest::CreateTask { .. }
+        | FrontendRequest::CreateTaskFromDeferredScopeAttention { .. }
+        | FrontendRequest::DebugLiveStatusPipeline
+        | FrontendRequest::DeclareRemotePeer { .. }
+        | FrontendRequest::DeleteAutomation { .. }
+        | FrontendRequest::DeleteSpendBudget { .. }
+        | FrontendRequest::DeleteWorkItem { .. }
+        | FrontendRequest::DisableAutomation { .. }
+        | FrontendRequest::DismissAttention { .. }
+        | FrontendRequest::EnableAutomation { .. }
+        | FrontendRequest::EvaluateDispatchAdmission { .. }
+        | FrontendRequest::EvaluateEditorialRules { .. }
+        | FrontendRequest::ExecutionTranscript { .. }
+        | FrontendRequest::ExplainDispatch { .. }
+        | FrontendRequest::FindWorkItemsByPr { .. }
+        | FrontendRequest::FocusWorkerPane { .. }
+        | FrontendRequest::GetAttentionGroup { .. }
+        | FrontendRequest::GetAttentionItem { .. }
+        | FrontendRequest::GetAutomation { .. }
+        | FrontendRequest::GetAutomationOpenTaskCount { .. }
+        | FrontendRequest::GetAutomationState
+        | FrontendRequest::GetCiBudget { .. }
+        | FrontendRequest::GetCiRemediation { .. }
+        | FrontendRequest::GetConflictHotspots { .. }
+        | FrontendRequest::GetConflictResolution { .. }
+        | FrontendRequest::GetCostWindowReport { .. }
+        | FrontendRequest::GetDecision { .. }
+        | FrontendRequest::GetDispatchConcurrency
+        | FrontendRequest::GetDispatchState
+        | FrontendRequest::GetDriverQuotaUsage { .. }
+        | FrontendRequest::GetDriverTrafficSplit
+        | FrontendRequest::GetEngineHealth
+        | FrontendRequest::GetEngineVersion
+        | FrontendRequest::GetExecution { .. }
+        | FrontendRequest::GetHost { .. }
+        | FrontendRequest::GetPrBody { .. }
+        | FrontendRequest::GetProductDesignDoc { .. }
+        | FrontendRequest::GetPrStatus { .. }
+        | FrontendRequest::GetRun { .. }
+        | FrontendRequest::GetSelectedProduct
+        | FrontendRequest::GetSettings
+        | FrontendRequest::GetTaskRuntime { .. }
+        | FrontendRequest::GetTopCostConsumers { .. }
+        | FrontendRequest::GetWorkerContext { .. }
+        | FrontendRequest::GetWorkItem { .. }
+        | FrontendRequest::GetWorkItemByShortId { .. }
+        | FrontendRequest::GetWorkItemCostReport { .. }
+        | FrontendRequest::GetWorkTree { .. }
+        | FrontendRequest::GitHubAuthCancel
+        | FrontendRequest::GitHubAuthDisconnect
+        | FrontendRequest::GitHubAuthStart
+        | FrontendRequest::GitHubAuthStatus
+        | FrontendRequest::HoldRun { .. }
+        | FrontendRequest::InterruptWorkerPane { .. }
+        | FrontendRequest::KickPrReconcilers
+        | FrontendRequest::LinkWorkItemExternalRef { .. }
+        | FrontendRequest::ListAnswerAgentRuns { .. }
+        | FrontendRequest::ListAttachments { .. }
+        | FrontendRequest::ListAttachmentsForWorkItem { .. }
+        | FrontendRequest::ListAttentionGroups { .. }
+        | FrontendRequest::ListAttentionItems { .. }
+        | FrontendRequest::ListAttentionItemsForWorkItem { .. }
+        | FrontendRequest::ListAttentionMerges { .. }
+        | FrontendRequest::ListAutomationDedupSuppressions { .. }
+        | FrontendRequest::ListAutomationRuns { .. }
+        | FrontendRequest::ListAutomations { .. }
+        | FrontendRequest::ListAutomationTasks { .. }
+        | FrontendRequest::ListChores { .. }
+        | FrontendRequest::ListCiRemediations { .. }
+        | FrontendRequest::ListConflictResolutions { .. }
+        | FrontendRequest::ListDecisions { .. }
+        | FrontendRequest::ListDeferredScopeAttentions { .. }
+        | FrontendRequest::ListDependencies { .. }
+        | FrontendRequest::ListDependenciesDetailed { .. }
+        | FrontendRequest::ListEditorialActions { .. }
+        | FrontendRequest::ListEngineAttempts { .. }
+        | FrontendRequest::ListExecutions { .. }
+        | FrontendRequest::ListFeatureFlags
+        | FrontendRequest::ListHostedPaneStatuses
+        | FrontendRequest::ListHosts
+        | FrontendRequest::ListLiveStatusDisabledSlots
+        | FrontendRequest::ListPlannerRuns { .. }
+        | FrontendRequest::ListProductDesignDocs { .. }
+        | FrontendRequest::ListProductDispatchShares
+        | FrontendRequest::ListProductDispatchCalendars
+        | FrontendRequest::ListProducts
+        | FrontendRequest::ListProjects { .. }
+        | FrontendRequest::ListProposals { .. }
+        | FrontendRequest::ListRevisions { .. }
+        | FrontendRequest::ListRuns { .. }
+        | FrontendRequest::ListSpendBudgets { .. }
+        | FrontendRequest::ListTasks { .. }
+        | FrontendRequest::ListWorkerLiveStates
+        | FrontendRequest::MarkCiRemediationFailed { .. }
+        | FrontendRequest::MarkCiRemediationNoop { .. }
+        | FrontendRequest::MarkCiRemediationRetriggered { .. }
+        | FrontendRequest::MarkCiRemediationSucceededViaRebase { .. }
+        | FrontendRequest::MarkConflictResolutionFailed { .. }
+        | FrontendRequest::MergeWhenReady { .. }
+        | FrontendRequest::MetricsListLive
+        | FrontendRequest::MetricsReset { .. }
+        | FrontendRequest::MetricsShowLive { .. }
+        | FrontendRequest::MoveWorkItemOnBoard { .. }
+        | FrontendRequest::OpenDocument { .. }
+        | FrontendRequest::OpenLiveWorkspaceTerminal { .. }
+        | FrontendRequest::OpenReviewTerminal { .. }
+        | FrontendRequest::PlanProject { .. }
+        | FrontendRequest::PostAutomationWebhook { .. }
+        | FrontendRequest::ProbeRun { .. }
+        | FrontendRequest::ProbeStatus { .. }
+        | FrontendRequest::RecordEffortEscalation { .. }
+        | FrontendRequest::RecordProducerSideConflict { .. }
+        | FrontendRequest::RecreateCoordinator { .. }
+        | FrontendRequest::ReleaseHoldRun { .. }
+        | FrontendRequest::ReleaseProject { .. }
+        | FrontendRequest::ReleaseReviewTerminal { .. }
+        | FrontendRequest::RemoveDependency { .. }
+        | FrontendRequest::RemoveHost { .. }
+        | FrontendRequest::RemoveHostTag { .. }
+        | FrontendRequest::ReorderProjectTasks { .. }
+        | FrontendRequest::RequestExecution { .. }
+        | FrontendRequest::ResolveProjectDesignDoc { .. }
+        | FrontendRequest::RestoreWorkItem { .. }
+        | FrontendRequest::RetryCiRemediation { .. }
+        | FrontendRequest::RetryConflictResolution { .. }
+        | FrontendRequest::RevealWorkItem { .. }
+        | FrontendRequest::RevokeDecision { .. }
+        | FrontendRequest::RunAutomation { .. }
+        | FrontendRequest::SendInputToWorker { .. }
+        | FrontendRequest::SetAutomationPaused { .. }
+        | FrontendRequest::SetCiBudget { .. }
+        | FrontendRequest::SetDispatchConcurrency { .. }
+        | FrontendRequest::SetDispatchPaused { .. }
+        | FrontendRequest::SetDriverTrafficSplit { .. }
+        | FrontendRequest::SetFeatureFlag { .. }
+        | FrontendRequest::SetHostEnabled { .. }
+        | FrontendRequest::SetLiveStatusEnabled { .. }
+        | FrontendRequest::SetProductDefaultDriver { .. }
+        | FrontendRequest::SetProductDefaultModel { .. }
+        | FrontendRequest::SetProductEditorialRules { .. }
+        | FrontendRequest::SetProductDispatchShare { .. }
+        | FrontendRequest::SetProductDispatchCalendar { .. }
+        | FrontendRequest::SetProductExternalTracker { .. }
+        | FrontendRequest::SetProductMergeMechanism { .. }
+        | FrontendRequest::SetProjectDesignDoc { .. }
+        | FrontendRequest::SetSetting { .. }
+        | FrontendRequest::SetSpendBudget { .. }
+        | FrontendRequest::SetTaskDocPointer { .. }
+        | FrontendRequest::Shutdown { .. }
+        | FrontendRequest::StopRun { .. }
+        | FrontendRequest::SubmitAttachment { .. }
+        | FrontendRequest::SubmitProposal { .. }
+        | FrontendRequest::Subscribe { .. }
+        | FrontendRequest::SupersedeDecision { .. }
+        | FrontendRequest::SyncProductExternalTracker { .. }
+        | FrontendRequest::TailRunTranscript { .. }
+        | FrontendRequest::TriggerPrReview { .. }
+        | FrontendRequest::TrunkSetToken { .. }
+        | FrontendRequest::TrunkStatus
+        | FrontendRequest::UnlinkWorkItemExternalRef { .. }
+        | FrontendRequest::UnpopulateProject { .. }
+        | FrontendRequest::Unsubscribe { .. }
+        | FrontendRequest::UpdateAutomation { .. }
+        | FrontendRequest::UpdateWorkItem { .. }
+        | FrontendRequest::WorkerPoolSummary
+        | FrontendRequest::WorkspacePoolSummary => None,
+    }
+}
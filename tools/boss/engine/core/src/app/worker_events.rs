@@ -22,6 +22,18 @@ impl ServerState {
     }
 }
 
+/// Stamp a driver-originated signal for `run_id` and, when it is the run's
+/// first, feed its spawn-ack time into `dispatch.spawn_ack_seconds`.
+fn record_driver_signal(server_state: &ServerState, run_id: &str, kind: DriverSignalKind) {
+    if let Some(ack_secs) = server_state
+        .live_worker_states
+        .record_driver_signal_timed(run_id, kind)
+        .and_then(|recorded| recorded.spawn_ack_secs)
+    {
+        crate::dispatch_metrics::record_spawn_ack_secs(&server_state.metrics, ack_secs);
+    }
+}
+
 /// Update the per-slot LiveWorkerState for the run this hook event
 /// belongs to and push the new snapshot on the
 /// `worker.live_states` topic if anything changed. Hook events that
@@ -396,9 +408,7 @@ pub(super) async fn dispatch_live_worker_state(
     // accepted a slot and a foreground pid appeared — both true of a pane
     // hosting nothing but an idle login shell. See
     // `LiveWorkerStateRegistry::unverified_driver_starts`.
-    server_state
-        .live_worker_states
-        .record_driver_signal(run_id, DriverSignalKind::HookEvent);
+    record_driver_signal(server_state, run_id, DriverSignalKind::HookEvent);
     // Resolve any outstanding pane-injection delivery waiter for this
     // run. A `UserPromptSubmit` hook is the CLI's own confirmation
     // that it enqueued *something* as the next prompt; when a probe
@@ -463,9 +473,7 @@ pub(super) async fn dispatch_live_worker_state(
         // recorded anyway so the contract holds at every site that
         // learns a transcript path rather than depending on the two
         // staying adjacent.
-        server_state
-            .live_worker_states
-            .record_driver_signal(run_id, DriverSignalKind::TranscriptPath);
+        record_driver_signal(server_state, run_id, DriverSignalKind::TranscriptPath);
         // `run_id` here is the `_boss_run_id` from the hook payload,
         // which carries the **execution_id** (`exec_*`) — not a
         // `work_runs.id` (`run_*`). The setter joins on
@@ -626,9 +634,7 @@ pub(super) async fn dispatch_live_worker_state(
     // judged as never having started a driver. Repeating the call here —
     // idempotent and first-write-wins — makes the record land for local and
     // remote runs alike, whichever side of registration the hook fell on.
-    server_state
-        .live_worker_states
-        .record_driver_signal(run_id, DriverSignalKind::HookEvent);
+    record_driver_signal(server_state, run_id, DriverSignalKind::HookEvent);
     let prior_activity = server_state.live_worker_states.get(slot_id).map(|s| s.activity);
     let changed = server_state.live_worker_states.apply_event(slot_id, &incoming.event);
     if changed {
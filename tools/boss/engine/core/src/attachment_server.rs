@@ -3,7 +3,9 @@
 //!
 //! The surface itself (routing, rendering, the loopback and `Host`-header
 //! gates) lives in `boss_engine_attachments::http`, which knows nothing about
-//! `WorkDb`. This module is the one-directional edge between them.
+//! `WorkDb`. This module is the one-directional edge between them. The
+//! opt-in web dashboard rides the same listener; its source is
+//! [`crate::dashboard::EngineDashboardSource`].
 //!
 //! ## Bind failure is not a boot failure
 //!
@@ -18,6 +20,7 @@
 
 use std::sync::{Arc, OnceLock};
 
+use boss_engine_attachments::DashboardSource;
 use boss_engine_attachments::http::{self, EvidenceCatalog, WorkItemLabel};
 use boss_engine_attachments::store::AttachmentStore;
 use boss_protocol::WorkAttachment;
@@ -83,11 +86,13 @@ impl EvidenceCatalog for WorkDbEvidenceCatalog {
 /// On success `evidence_port` is set to the port actually bound, which is
 /// what unblocks URL minting (see `ServerState::evidence_base_url`). Returns
 /// `None` — after logging why — when the surface is disabled or the bind
-/// fails; the caller carries on regardless.
+/// fails; the caller carries on regardless. `dashboard` is `Some` when
+/// the dashboard routes are enabled.
 pub async fn spawn(
     work_db: Arc<WorkDb>,
     store: AttachmentStore,
     evidence_port: Arc<OnceLock<u16>>,
+    dashboard: Option<Arc<dyn DashboardSource>>,
 ) -> Option<tokio::task::JoinHandle<()>> {
     let Some(port) = http::configured_port() else {
         tracing::info!(
@@ -124,9 +129,15 @@ pub async fn spawn(
         url = %http::base_url(bound),
         "attachment evidence server: listening on loopback",
     );
+    if dashboard.is_some() {
+        tracing::info!(
+            url = %format!("{}{}", http::base_url(bound), boss_engine_attachments::dashboard::DASHBOARD_PATH),
+            "web dashboard: enabled",
+        );
+    }
 
     let catalog: Arc<dyn EvidenceCatalog> = Arc::new(WorkDbEvidenceCatalog::new(work_db));
-    Some(tokio::spawn(http::serve(listener, catalog, store)))
+    Some(tokio::spawn(http::serve(listener, catalog, store, dashboard)))
 }
 
 #[cfg(test)]
@@ -229,7 +240,7 @@ mod tests {
         let listener = http::bind(0).await.expect("loopback bind");
         let port = listener.local_addr().unwrap().port();
         let catalog: Arc<dyn EvidenceCatalog> = Arc::new(WorkDbEvidenceCatalog::new(db));
-        tokio::spawn(http::serve(listener, catalog, store));
+        tokio::spawn(http::serve(listener, catalog, store, None));
 
         async fn fetch(port: u16, path: &str) -> (String, Vec<u8>) {
             let mut stream = tokio::net::TcpStream::connect(("127.0.0.1", port)).await.unwrap();
@@ -286,6 +286,19 @@ pub const ATTENTION_LIFECYCLES: &[AttentionLifecycle] = &[
         "Same product-scoped fetch-success path as `external_tracker_auth_failed`; a run of an \
          unrelated work item is not evidence the tracker stopped erroring.",
     ),
+    entry(
+        crate::spend_budget::SPEND_BUDGET_SOFT_LIMIT_ATTENTION_KIND,
+        ClearedBy::ProducerReconciles,
+        "Scoped to the budgeted product/project, not to a run. The budget evaluator resolves it when \
+         no soft limit on that scope is still crossed — the period rolled over or the budget was \
+         raised or deleted.",
+    ),
+    entry(
+        crate::spend_budget::SPEND_BUDGET_HARD_LIMIT_ATTENTION_KIND,
+        ClearedBy::ProducerReconciles,
+        "Mirrors the dispatch hold itself: the budget evaluator resolves it on the same evaluation \
+         that lifts the hold, so the item is open exactly while dispatch in scope is held.",
+    ),
     // ── Only a human can lower these ────────────────────────────────────
     entry(
         crate::app::probes::PROBE_UNDELIVERED_ATTENTION_KIND,
@@ -586,6 +599,8 @@ mod tests {
             crate::merge_parent_deletion::SIGNOFF_ATTENTION_KIND,
             crate::merge_mechanism::PUSH_RESTRICTION_ATTENTION_KIND,
             crate::envelope_watch::ENVELOPE_OVERRUN_ATTENTION_KIND,
+            crate::spend_budget::SPEND_BUDGET_SOFT_LIMIT_ATTENTION_KIND,
+            crate::spend_budget::SPEND_BUDGET_HARD_LIMIT_ATTENTION_KIND,
             crate::codex_unobserved_command::UNOBSERVED_COMMAND_ATTENTION_KIND,
             crate::codex_unobserved_command::UNOBSERVED_COMMAND_OVERFLOW_ATTENTION_KIND,
             crate::husk_pane_sweep::HUSK_BREAKER_ATTENTION_KIND,
@@ -37,7 +37,7 @@ use boss_protocol::{Attention, AttentionGroup, CreateAttentionInput};
 use boss_transcript_markdown::TranscriptEventKind;
 use serde::Deserialize;
 
-use crate::claude_client::{self, CallConfig, Message, MessagesRequest};
+use crate::claude_client::{CallConfig, Message, MessagesRequest};
 use crate::design_detector;
 use crate::driver::AgentDriver;
 use crate::structured_output::StructuredOutputKind;
@@ -830,9 +830,9 @@ pub async fn extract_followups_backstop(
         .max_tokens(BACKSTOP_MAX_TOKENS)
         .messages(vec![Message::user(prompt)])
         .build();
-    let config = CallConfig::new(BACKSTOP_TIMEOUT).with_endpoint(call.endpoint.clone());
+    let config = CallConfig::new(BACKSTOP_TIMEOUT);
 
-    let response = match claude_client::send_messages(&call.api_key, &request, &config).await {
+    let response = match call.send_messages(&request, &config).await {
         Ok(response) => response,
         Err(err) => {
             tracing::warn!(
@@ -0,0 +1,648 @@
+//! Event-triggered automations: the non-cron half of `AutomationTrigger`.
+//!
+//! [`crate::automation_scheduler`] owns `schedule` automations. Everything
+//! else — `pr_merged`, `ci_red`, `work_item_status`, `upstream_label`,
+//! `webhook` — is fired from here, off the same [`boss_event_bus`] topics the
+//! rest of the engine already publishes:
+//!
+//! | Trigger            | Event                                  | Published by                              |
+//! |--------------------|----------------------------------------|-------------------------------------------|
+//! | `pr_merged`        | [`Event::PrMerged`]                    | `WorkDb::mark_chore_pr_merged`            |
+//! | `ci_red`           | [`Event::DefaultBranchCiRed`]          | [`crate::default_branch_ci`]              |
+//! | `work_item_status` | [`Event::WorkItemStatusChanged`]       | every status-transition write path        |
+//! | `upstream_label`   | [`Event::UpstreamItemImported`]        | the external-tracker reconciler's import  |
+//! | `webhook`          | [`Event::AutomationWebhook`]           | `PostAutomationWebhook` on the socket     |
+//!
+//! ## A burst of events produces one run
+//!
+//! Three layers, cheapest first:
+//!
+//! 1. **Mailbox coalescing.** The subscription's mailbox keeps only the
+//!    newest pending event per `(kind, coalesce_key)`, so a flapping source
+//!    never queues more than one event per entity.
+//! 2. **Debounce.** An automation that recorded a run less than
+//!    [`AUTOMATION_EVENT_DEBOUNCE_SECS`] ago folds the event into that run
+//!    instead of firing again (counted as `coalesced`, no row written) — ten
+//!    PRs merging in a minute is one triage pass, not ten.
+//! 3. **Produced-task dedup.** A triage worker that does fire still files its
+//!    task through `WorkDb::create_automation_task`, so the
+//!    `automation-dedup` fingerprint gate and the open-task limit suppress a
+//!    finding the automation already has open, exactly as for cron runs.
+//!
+//! ## Run history
+//!
+//! Each fire records an `automation_runs` row keyed on `scheduled_for = now`
+//! with `trigger_event` set to a one-line description of the event, so
+//! `boss automation runs` shows *why* the run happened. `next_due_at` is
+//! never touched — event automations have no schedule.
+//!
+//! Unlike a cron occurrence, an event is a moment that has already passed:
+//! a transient dispatch failure is recorded `failed_gave_up` rather than
+//! held for retry, and events that arrive while automations are globally
+//! paused are dropped rather than queued. Delivery is best-effort in the same
+//! sense as every bus consumer — an event dropped under mailbox pressure is
+//! simply not seen.
+//!
+//! This loop has no periodic sweep to fall back on, so it is the bus's
+//! durable subscriber ([`DURABLE_SUBSCRIBER_NAME`]): when the engine runs the
+//! journaled bus (`BOSS_ENABLE_DURABLE_EVENT_BUS`) it checkpoints after every
+//! event, and events published but not yet handled when the engine went down
+//! are replayed on the next boot. A replayed event that had already fired is
+//! absorbed by the debounce unless the engine stayed down longer than
+//! [`AUTOMATION_EVENT_DEBOUNCE_SECS`].
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use boss_event_bus::{Event, Subscription};
+use boss_protocol::{
+    AUTOMATION_OUTCOME_FAILED_GAVE_UP, AUTOMATION_OUTCOME_FAILED_WILL_RETRY, AUTOMATION_OUTCOME_SUPPRESSED_AT_LIMIT,
+    Automation, AutomationTrigger,
+};
+
+use crate::automation_scheduler::{TriageDispatch, TriageDispatcher};
+use crate::stacked_pr_structuring::PrChangedFilesFetcher;
+use crate::work::{AutomationFireRecord, WorkDb};
+
+/// How long after a run an event-triggered automation ignores further
+/// matching events. Long enough to absorb a merge train or a status storm,
+/// short enough that a second, unrelated incident later in the hour fires.
+pub const AUTOMATION_EVENT_DEBOUNCE_SECS: i64 = 5 * 60;
+
+/// Cursor name this loop subscribes under on a durable bus
+/// (`<state_root>/event-bus/cursors/automation_events.cursor`).
+pub const DURABLE_SUBSCRIBER_NAME: &str = "automation_events";
+
+/// Webhook payloads are opaque caller text; keep only this many characters
+/// of one in the recorded `trigger_event`.
+const WEBHOOK_PAYLOAD_RECORD_CHARS: usize = 200;
+
+/// Per-event counters, for logging and tests.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct AutomationEventPass {
+    /// Matching automations whose triage was dispatched.
+    pub fired: usize,
+    /// Matching automations inside their debounce window; the event was
+    /// folded into the previous run.
+    pub coalesced: usize,
+    /// Matching automations already at their open-task limit.
+    pub suppressed: usize,
+    /// Matching automations whose dispatch was operator-gated
+    /// ([`TriageDispatch::Held`]); nothing recorded.
+    pub held: usize,
+    /// Matching automations whose dispatch failed (recorded `failed_gave_up`).
+    pub failed: usize,
+}
+
+/// Production [`PrChangedFilesFetcher`] for `pr_merged` path filters —
+/// `gh pr view --json files`, attributed to the automation-events caller.
+pub struct GhAutomationChangedFiles;
+
+#[async_trait]
+impl PrChangedFilesFetcher for GhAutomationChangedFiles {
+    async fn changed_files(&self, pr_url: &str) -> Result<Vec<String>> {
+        boss_gh_telemetry::scope(
+            boss_gh_telemetry::callers::AUTOMATION_EVENTS,
+            boss_github::pr_files::fetch_pr_changed_files(pr_url),
+        )
+        .await
+    }
+}
+
+/// Spawn the event-trigger loop over `events`, a subscription to the five
+/// trigger topics (see the module table), taken with
+/// `EventBus::subscribe_durable(DURABLE_SUBSCRIBER_NAME, ..)` so a durable
+/// bus can resume it. Checkpoints after every event, handled or dropped.
+/// Exits when the bus closes.
+///
+/// `is_paused` is the scheduler's `ExecutionCoordinator::is_automation_paused`
+/// flag; while it is set events are dropped before any DB work.
+pub fn spawn_loop(
+    work_db: Arc<WorkDb>,
+    dispatcher: Arc<dyn TriageDispatcher>,
+    mut events: Subscription,
+    is_paused: Arc<dyn Fn() -> bool + Send + Sync>,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let fetcher = GhAutomationChangedFiles;
+        while let Some(event) = events.recv().await {
+            if is_paused() {
+                tracing::debug!(
+                    topic = event.kind().topic_name(),
+                    "automation events: globally paused; dropping event",
+                );
+                events.checkpoint();
+                continue;
+            }
+            let now = boss_engine_utils::epoch_time::now_epoch_secs();
+            match handle_event(work_db.as_ref(), now, dispatcher.as_ref(), &fetcher, &event).await {
+                Ok(pass) if pass != AutomationEventPass::default() => tracing::info!(
+                    topic = event.kind().topic_name(),
+                    fired = pass.fired,
+                    coalesced = pass.coalesced,
+                    suppressed = pass.suppressed,
+                    held = pass.held,
+                    failed = pass.failed,
+                    "automation events: event handled",
+                ),
+                Ok(_) => {}
+                Err(err) => tracing::warn!(
+                    topic = event.kind().topic_name(),
+                    ?err,
+                    "automation events: failed to handle event",
+                ),
+            }
+            events.checkpoint();
+        }
+    })
+}
+
+/// Fire every enabled automation `event` triggers. Pure of wall-clock reads
+/// (`now` is UTC epoch seconds) so the debounce is deterministically
+/// testable. Events that no trigger kind listens on are a no-op.
+pub async fn handle_event(
+    work_db: &WorkDb,
+    now: i64,
+    dispatcher: &dyn TriageDispatcher,
+    fetcher: &dyn PrChangedFilesFetcher,
+    event: &Event,
+) -> Result<AutomationEventPass> {
+    let mut pass = AutomationEventPass::default();
+    let Some(routed) = route(work_db, event)? else {
+        return Ok(pass);
+    };
+    let candidates = work_db.list_enabled_automations_by_trigger_kind(&routed.product_id, routed.trigger_kind)?;
+    // Fetched at most once per event, and only if some `pr_merged`
+    // automation actually filters on paths.
+    let mut changed_files: Option<Vec<String>> = None;
+
+    for automation in candidates {
+        // An automation never re-triggers on its own output: a
+        // `work_item_status -> done` automation whose produced task reaches
+        // `done` would otherwise fire itself forever.
+        if let Some(item) = routed.source_work_item
+            && work_db.source_automation_id_for_work_item(item)?.as_deref() == Some(automation.id.as_str())
+        {
+            continue;
+        }
+        if !trigger_matches(&automation, event, fetcher, &mut changed_files).await {
+            continue;
+        }
+        if let Err(err) = fire_one(work_db, now, dispatcher, &automation, &routed.description, &mut pass).await {
+            tracing::warn!(
+                automation_id = %automation.id,
+                ?err,
+                "automation events: error firing automation; skipping",
+            );
+        }
+    }
+    Ok(pass)
+}
+
+/// Where an event goes: the product whose automations may fire, the trigger
+/// kind that listens on it, and the `trigger_event` text recorded on a run.
+struct RoutedEvent<'a> {
+    product_id: String,
+    trigger_kind: &'static str,
+    description: String,
+    /// The work item the event is about, when it has one — used to keep an
+    /// automation from firing on its own produced tasks.
+    source_work_item: Option<&'a str>,
+}
+
+fn route<'a>(work_db: &WorkDb, event: &'a Event) -> Result<Option<RoutedEvent<'a>>> {
+    let routed = match event {
+        Event::PrMerged { pr_url, task_id } => {
+            let Some(product_id) = work_db.product_id_for_work_item(task_id)? else {
+                return Ok(None);
+            };
+            RoutedEvent {
+                product_id,
+                trigger_kind: "pr_merged",
+                description: format!("pr_merged {pr_url}"),
+                source_work_item: Some(task_id),
+            }
+        }
+        Event::WorkItemStatusChanged { work_item_id, status } => {
+            let Some(product_id) = work_db.product_id_for_work_item(work_item_id)? else {
+                return Ok(None);
+            };
+            RoutedEvent {
+                product_id,
+                trigger_kind: "work_item_status",
+                description: format!("work_item_status {work_item_id} -> {status}"),
+                source_work_item: Some(work_item_id),
+            }
+        }
+        Event::DefaultBranchCiRed { product_id, head_sha } => RoutedEvent {
+            product_id: product_id.clone(),
+            trigger_kind: "ci_red",
+            description: format!("default_branch_ci_red {head_sha}"),
+            source_work_item: None,
+        },
+        Event::UpstreamItemImported {
+            product_id,
+            work_item_id,
+            upstream_url,
+            ..
+        } => RoutedEvent {
+            product_id: product_id.clone(),
+            trigger_kind: "upstream_label",
+            description: format!("upstream_item_imported {upstream_url} as {work_item_id}"),
+            source_work_item: None,
+        },
+        Event::AutomationWebhook {
+            product_id,
+            name,
+            payload,
+        } => {
+            let description = match payload.as_deref().filter(|p| !p.is_empty()) {
+                Some(payload) => {
+                    let truncated: String = payload.chars().take(WEBHOOK_PAYLOAD_RECORD_CHARS).collect();
+                    format!("webhook {name}: {truncated}")
+                }
+                None => format!("webhook {name}"),
+            };
+            RoutedEvent {
+                product_id: product_id.clone(),
+                trigger_kind: "webhook",
+                description,
+                source_work_item: None,
+            }
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(routed))
+}
+
+/// Whether `automation`'s trigger accepts `event`. The trigger kind already
+/// matched in [`route`]; this checks the per-trigger filter.
+async fn trigger_matches(
+    automation: &Automation,
+    event: &Event,
+    fetcher: &dyn PrChangedFilesFetcher,
+    changed_files: &mut Option<Vec<String>>,
+) -> bool {
+    match (&automation.trigger, event) {
+        (AutomationTrigger::PrMerged { paths }, Event::PrMerged { pr_url, .. }) => {
+            if paths.is_empty() {
+                return true;
+            }
+            let matcher = match build_path_matcher(paths) {
+                Ok(matcher) => matcher,
+                Err(err) => {
+                    tracing::warn!(automation_id = %automation.id, %err, "invalid pr_merged path glob");
+                    return false;
+                }
+            };
+            if changed_files.is_none() {
+                match fetcher.changed_files(pr_url).await {
+                    Ok(files) => *changed_files = Some(files),
+                    Err(err) => {
+                        tracing::warn!(
+                            automation_id = %automation.id,
+                            %pr_url,
+                            ?err,
+                            "could not list merged PR's changed files; not firing",
+                        );
+                        return false;
+                    }
+                }
+            }
+            changed_files
+                .as_deref()
+                .is_some_and(|files| files.iter().any(|f| matcher.is_match(f)))
+        }
+        (AutomationTrigger::CiRed, Event::DefaultBranchCiRed { .. }) => true,
+        (AutomationTrigger::WorkItemStatus { status }, Event::WorkItemStatusChanged { status: actual, .. }) => {
+            status == actual
+        }
+        (AutomationTrigger::UpstreamLabel { label }, Event::UpstreamItemImported { labels, .. }) => {
+            labels.iter().any(|l| l.eq_ignore_ascii_case(label))
+        }
+        (AutomationTrigger::Webhook { name }, Event::AutomationWebhook { name: posted, .. }) => name == posted,
+        _ => false,
+    }
+}
+
+/// Compile a `pr_merged` trigger's path globs into one matcher.
+pub fn build_path_matcher(paths: &[String]) -> Result<globset::GlobSet, globset::Error> {
+    let mut builder = globset::GlobSetBuilder::new();
+    for path in paths {
+        builder.add(globset::Glob::new(path)?);
+    }
+    builder.build()
+}
+
+/// Debounce, gate, dispatch and record one matching automation.
+async fn fire_one(
+    work_db: &WorkDb,
+    now: i64,
+    dispatcher: &dyn TriageDispatcher,
+    automation: &Automation,
+    trigger_event: &str,
+    pass: &mut AutomationEventPass,
+) -> Result<()> {
+    if let Some(last) = work_db.latest_automation_run_scheduled_for(&automation.id)?
+        && now - last < AUTOMATION_EVENT_DEBOUNCE_SECS
+    {
+        tracing::debug!(
+            automation_id = %automation.id,
+            %trigger_event,
+            last_run = last,
+            "automation events: inside debounce window; folding event into previous run",
+        );
+        pass.coalesced += 1;
+        return Ok(());
+    }
+
+    let open = work_db.count_open_tasks_for_automation(&automation.id)?;
+    if open >= automation.open_task_limit {
+        work_db.record_automation_run_and_advance(
+            AutomationFireRecord::builder()
+                .automation_id(automation.id.clone())
+                .scheduled_for(now)
+                .started_at(now)
+                .outcome(AUTOMATION_OUTCOME_SUPPRESSED_AT_LIMIT)
+                .finished_at(now)
+                .detail(format!(
+                    "open-task count {open} at limit {}",
+                    automation.open_task_limit
+                ))
+                .trigger_event(trigger_event)
+                .build(),
+        )?;
+        pass.suppressed += 1;
+        return Ok(());
+    }
+
+    match dispatcher.dispatch_triage(automation, now).await {
+        TriageDispatch::Dispatched { execution_id } => {
+            // Same pessimistic default as a cron fire; the triage outcome
+            // detector finalises the row when the worker stops.
+            work_db.record_automation_run_and_advance(
+                AutomationFireRecord::builder()
+                    .automation_id(automation.id.clone())
+                    .scheduled_for(now)
+                    .started_at(now)
+                    .outcome(AUTOMATION_OUTCOME_FAILED_WILL_RETRY)
+                    .detail("dispatched; awaiting triage worker decision (Stop not yet received)")
+                    .triage_execution_id(execution_id)
+                    .trigger_event(trigger_event)
+                    .build(),
+            )?;
+            pass.fired += 1;
+        }
+        TriageDispatch::TransientFailure { detail } => {
+            work_db.record_automation_run_and_advance(
+                AutomationFireRecord::builder()
+                    .automation_id(automation.id.clone())
+                    .scheduled_for(now)
+                    .started_at(now)
+                    .outcome(AUTOMATION_OUTCOME_FAILED_GAVE_UP)
+                    .finished_at(now)
+                    .detail(format!("gave up: event-triggered runs are not retried; {detail}"))
+                    .trigger_event(trigger_event)
+                    .build(),
+            )?;
+            pass.failed += 1;
+        }
+        TriageDispatch::Held { reason } => {
+            tracing::debug!(
+                automation_id = %automation.id,
+                %reason,
+                "automation events: dispatch gated; dropping event without recording a run",
+            );
+            pass.held += 1;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::test_support::*;
+    use boss_protocol::CreateAutomationInput;
+
+    /// Always dispatches, recording each call.
+    #[derive(Default)]
+    struct CountingDispatcher {
+        calls: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl TriageDispatcher for CountingDispatcher {
+        async fn dispatch_triage(&self, automation: &Automation, _scheduled_for: i64) -> TriageDispatch {
+            self.calls.lock().unwrap().push(automation.id.clone());
+            TriageDispatch::Dispatched {
+                execution_id: format!("exec_{}", self.calls.lock().unwrap().len()),
+            }
+        }
+    }
+
+    /// Returns a fixed file list for every PR.
+    struct FixedFiles(Vec<&'static str>);
+
+    #[async_trait]
+    impl PrChangedFilesFetcher for FixedFiles {
+        async fn changed_files(&self, _pr_url: &str) -> Result<Vec<String>> {
+            Ok(self.0.iter().map(|f| (*f).to_owned()).collect())
+        }
+    }
+
+    fn create_event_automation(db: &WorkDb, product_id: &str, trigger: AutomationTrigger) -> Automation {
+        db.create_automation(
+            CreateAutomationInput::builder()
+                .product_id(product_id.to_owned())
+                .name(trigger.kind())
+                .trigger(trigger)
+                .standing_instruction("look into it")
+                .build(),
+        )
+        .unwrap()
+    }
+
+    fn webhook(product_id: &str, name: &str) -> Event {
+        Event::AutomationWebhook {
+            product_id: product_id.to_owned(),
+            name: name.to_owned(),
+            payload: Some("deploy 42 failed".to_owned()),
+        }
+    }
+
+    #[tokio::test]
+    async fn a_burst_of_matching_events_produces_one_run_with_the_trigger_recorded() {
+        let (_d, db) = open_db();
+        let product = create_product(&db);
+        let automation = create_event_automation(
+            &db,
+            &product,
+            AutomationTrigger::Webhook {
+                name: "deploy".to_owned(),
+            },
+        );
+        let dispatcher = CountingDispatcher::default();
+        let files = FixedFiles(Vec::new());
+
+        let now = 1_800_000_000;
+        let first = handle_event(&db, now, &dispatcher, &files, &webhook(&product, "deploy"))
+            .await
+            .unwrap();
+        assert_eq!(first.fired, 1);
+        for offset in 1..5 {
+            let pass = handle_event(&db, now + offset, &dispatcher, &files, &webhook(&product, "deploy"))
+                .await
+                .unwrap();
+            assert_eq!(pass.coalesced, 1, "{pass:?}");
+        }
+        assert_eq!(dispatcher.calls.lock().unwrap().len(), 1);
+
+        let runs = db.list_automation_runs(&automation.id).unwrap();
+        assert_eq!(runs.len(), 1);
+        assert_eq!(
+            runs[0].trigger_event.as_deref(),
+            Some("webhook deploy: deploy 42 failed")
+        );
+        assert_eq!(runs[0].triage_execution_id.as_deref(), Some("exec_1"));
+
+        // Past the debounce window the next event fires again.
+        let later = handle_event(
+            &db,
+            now + AUTOMATION_EVENT_DEBOUNCE_SECS,
+            &dispatcher,
+            &files,
+            &webhook(&product, "deploy"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(later.fired, 1);
+    }
+
+    #[tokio::test]
+    async fn only_matching_triggers_fire() {
+        let (_d, db) = open_db();
+        let product = create_product(&db);
+        let chore = create_test_chore_manual(&db, product.as_str(), "some chore");
+        let docs = create_event_automation(
+            &db,
+            &product,
+            AutomationTrigger::PrMerged {
+                paths: vec!["docs/**".to_owned()],
+            },
+        );
+        let engine = create_event_automation(
+            &db,
+            &product,
+            AutomationTrigger::PrMerged {
+                paths: vec!["engine/**/*.rs".to_owned()],
+            },
+        );
+        let blocked = create_event_automation(
+            &db,
+            &product,
+            AutomationTrigger::WorkItemStatus {
+                status: "blocked".to_owned(),
+            },
+        );
+        let dispatcher = CountingDispatcher::default();
+        let files = FixedFiles(vec!["engine/core/src/lib.rs"]);
+
+        let merged = Event::PrMerged {
+            pr_url: "https://github.com/o/r/pull/7".to_owned(),
+            task_id: chore.id.clone(),
+        };
+        let pass = handle_event(&db, 1_800_000_000, &dispatcher, &files, &merged)
+            .await
+            .unwrap();
+        assert_eq!(pass.fired, 1);
+        assert_eq!(*dispatcher.calls.lock().unwrap(), vec![engine.id.clone()]);
+        assert!(db.list_automation_runs(&docs.id).unwrap().is_empty());
+
+        let done = Event::WorkItemStatusChanged {
+            work_item_id: chore.id.clone(),
+            status: "done".to_owned(),
+        };
+        let pass = handle_event(&db, 1_800_000_000, &dispatcher, &files, &done)
+            .await
+            .unwrap();
+        assert_eq!(pass, AutomationEventPass::default());
+
+        let now_blocked = Event::WorkItemStatusChanged {
+            work_item_id: chore.id.clone(),
+            status: "blocked".to_owned(),
+        };
+        handle_event(&db, 1_800_000_000, &dispatcher, &files, &now_blocked)
+            .await
+            .unwrap();
+        let runs = db.list_automation_runs(&blocked.id).unwrap();
+        assert_eq!(
+            runs[0].trigger_event.as_deref(),
+            Some(format!("work_item_status {} -> blocked", chore.id).as_str())
+        );
+    }
+
+    #[tokio::test]
+    async fn an_automation_does_not_fire_on_its_own_produced_task() {
+        let (_d, db) = open_db();
+        let product = create_product(&db);
+        let automation = create_event_automation(
+            &db,
+            &product,
+            AutomationTrigger::WorkItemStatus {
+                status: "done".to_owned(),
+            },
+        );
+        let produced = create_test_chore_manual(&db, product.as_str(), "produced");
+        db.stamp_task_source_automation_for_test(&produced.id, &automation.id, "done")
+            .unwrap();
+        let dispatcher = CountingDispatcher::default();
+
+        let event = Event::WorkItemStatusChanged {
+            work_item_id: produced.id.clone(),
+            status: "done".to_owned(),
+        };
+        let pass = handle_event(&db, 1_800_000_000, &dispatcher, &FixedFiles(Vec::new()), &event)
+            .await
+            .unwrap();
+        assert_eq!(pass, AutomationEventPass::default());
+    }
+
+    #[tokio::test]
+    async fn at_the_open_task_limit_the_event_is_recorded_as_suppressed() {
+        let (_d, db) = open_db();
+        let product = create_product(&db);
+        let automation = create_event_automation(
+            &db,
+            &product,
+            AutomationTrigger::UpstreamLabel {
+                label: "bug".to_owned(),
+            },
+        );
+        let open = create_test_chore_manual(&db, product.as_str(), "already open");
+        db.stamp_task_source_automation_for_test(&open.id, &automation.id, "todo")
+            .unwrap();
+        let dispatcher = CountingDispatcher::default();
+
+        let imported = Event::UpstreamItemImported {
+            product_id: product.clone(),
+            work_item_id: "chr_x".to_owned(),
+            upstream_url: "https://github.com/o/r/issues/3".to_owned(),
+            labels: vec!["Bug".to_owned()],
+        };
+        let pass = handle_event(&db, 1_800_000_000, &dispatcher, &FixedFiles(Vec::new()), &imported)
+            .await
+            .unwrap();
+        assert_eq!(pass.suppressed, 1);
+        assert!(dispatcher.calls.lock().unwrap().is_empty());
+        let runs = db.list_automation_runs(&automation.id).unwrap();
+        assert_eq!(runs[0].outcome, AUTOMATION_OUTCOME_SUPPRESSED_AT_LIMIT);
+        assert_eq!(
+            runs[0].trigger_event.as_deref(),
+            Some("upstream_item_imported https://github.com/o/r/issues/3 as chr_x")
+        );
+    }
+}
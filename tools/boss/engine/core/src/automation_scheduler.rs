@@ -507,7 +507,11 @@ async fn evaluate_one(
     automation: &Automation,
     pass: &mut AutomationSchedulerPass,
 ) -> anyhow::Result<()> {
-    let AutomationTrigger::Schedule { cron, timezone } = &automation.trigger;
+    // `list_due_automations` only returns `schedule` automations; the
+    // event-triggered kinds are fired by `crate::automation_events` instead.
+    let AutomationTrigger::Schedule { cron, timezone } = &automation.trigger else {
+        return Ok(());
+    };
 
     let schedule = match parse_cron(cron) {
         Ok(schedule) => schedule,
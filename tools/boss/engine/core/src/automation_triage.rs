@@ -110,6 +110,9 @@ pub struct RecentlyMergedAutomationTask {
 pub struct TriageContext {
     pub in_flight: Vec<InFlightAutomationTask>,
     pub recently_merged: Vec<RecentlyMergedAutomationTask>,
+    /// For an event-triggered run, the `automation_runs.trigger_event` it
+    /// was fired for. `None` for cron and manual runs.
+    pub trigger_event: Option<String>,
 }
 
 /// `T<short_id>` when available, else the full task id — the reference form
@@ -151,10 +154,30 @@ impl TriageContext {
         Self {
             in_flight,
             recently_merged,
+            trigger_event: None,
         }
     }
 }
 
+/// Render the "What triggered this run" block for an event-triggered run,
+/// or the empty string for a cron or manual one.
+fn render_trigger_block(context: &TriageContext) -> String {
+    match context.trigger_event.as_deref() {
+        Some(event) => format!(
+            "## What triggered this run
+
+This automation fires on engine events, not a schedule. This run was triggered by:
+
+```\n{event}\n```
+
+Start from that event: apply the standing instruction to what it names.
+
+"
+        ),
+        None => String::new(),
+    }
+}
+
 /// Render the "Recently filed / in-flight automation work" block, or an
 /// empty string when `context` has nothing to report (the common case —
 /// most triage runs have no overlapping sibling activity).
@@ -285,6 +308,7 @@ pub fn render_triage_preamble(
 \"{product_name}\". Your session cwd is already a fresh checkout of this product's \
 repository.\n\n\
 Standing instruction:\n\n> {instruction}\n\n\
+{trigger_block}\
 Decide whether a **single, concrete, actionable** task can be derived from this \
 instruction **right now** in this repository. Investigate the repo with a few \
 targeted, read-only checks (keep it lightweight — see below) to make that call. \
@@ -368,6 +392,7 @@ is NOT a skip.\n",
         a_id = a_id,
         product_name = product_name,
         instruction = automation.standing_instruction.trim(),
+        trigger_block = render_trigger_block(context),
         already_tracked = render_already_tracked_section(siblings),
         context_block = context_block,
         create_cmd = create_cmd,
@@ -923,6 +948,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn preamble_names_the_triggering_event_only_for_event_runs() {
+        let automation = Automation::builder()
+            .id("auto_ev")
+            .short_id(3i64)
+            .product_id("prod_1")
+            .name("docs drift")
+            .trigger(boss_protocol::AutomationTrigger::PrMerged {
+                paths: vec!["docs/**".to_owned()],
+            })
+            .standing_instruction("check the docs still match the code")
+            .created_at("2026-01-01")
+            .updated_at("2026-01-01")
+            .build();
+        let plain = render_triage_preamble(&automation, "My Product", &[], &TriageContext::default(), ARTIFACT_PATH);
+        assert!(!plain.contains("What triggered this run"));
+
+        let context = TriageContext {
+            trigger_event: Some("pr_merged https://github.com/o/r/pull/9".to_owned()),
+            ..TriageContext::default()
+        };
+        let preamble = render_triage_preamble(&automation, "My Product", &[], &context, ARTIFACT_PATH);
+        assert!(preamble.contains("## What triggered this run"));
+        assert!(preamble.contains("pr_merged https://github.com/o/r/pull/9"));
+    }
+
     #[test]
     fn preamble_forbids_sub_agents_and_deferral() {
         let automation = Automation::builder()
@@ -977,9 +977,9 @@ pub(crate) mod ci_log_reader_hooks {
     }
 }
 
-/// Best-effort: fetch the tail of the first failing job's log and store it
-/// on the `ci_remediations` row so the worker revision directive can embed
-/// a concrete excerpt. Skips silently when no job id is available or the
+/// Best-effort: fetch the failing step of the first failing job's log
+/// (trimmed to its last 100 lines) and store it on the `ci_remediations`
+/// row so the worker revision directive can embed a concrete excerpt. Skips silently when no job id is available or the
 /// log fetch fails — the worker can always fetch the full log manually.
 ///
 /// `make_reader` is the injectable factory (production:
@@ -1005,7 +1005,7 @@ async fn fetch_and_store_log_excerpt(
     // internally and falls back to a reader that errors cleanly if either
     // is unparseable.
     let reader = make_reader(first_with_job.provider, &first_with_job.target_url);
-    match reader.read_log_tail(job_id, 100).await {
+    match reader.read_failing_step(job_id, 100).await {
         Ok(log) if !log.is_empty() => {
             if let Err(err) = work_db.set_ci_remediation_log_excerpt(&attempt.id, &log) {
                 tracing::debug!(
@@ -2515,6 +2515,8 @@ fn provider_str(p: crate::merge_poller::CiProvider) -> &'static str {
     match p {
         Buildkite => "buildkite",
         GithubActions => "github_actions",
+        GitlabCi => "gitlab_ci",
+        Jenkins => "jenkins",
         Other => "other",
     }
 }
@@ -1354,6 +1354,11 @@ pub struct WorkerCompletionHandler {
     /// Defaults to [`NoopMergeProbe`]; production wires in the shared
     /// [`CommandMergeProbe`] via [`Self::with_merge_probe`].
     merge_probe: Arc<dyn MergeProbe>,
+    /// Posts finalised review passes to their PR as GitHub reviews when
+    /// the `pr_review_publish` flag is on (see [`crate::pr_review_publish`]).
+    /// Defaults to a publisher over [`boss_github::gh_runner::CommandGhRunner`];
+    /// tests inject a fake runner via [`Self::with_pr_review_publisher`].
+    pr_review_publisher: Arc<crate::pr_review_publish::PrReviewPublisher>,
     /// Base backoff between the conflict stop gate's `mergeable=UNKNOWN`
     /// re-probes (see [`crate::conflict_stop_gate`]). Defaults to
     /// [`conflict_stop_gate::DEFAULT_UNKNOWN_RETRY_BACKOFF`]; tests set it
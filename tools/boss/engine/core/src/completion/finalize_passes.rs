@@ -900,6 +900,24 @@ impl WorkerCompletionHandler {
         let product_id = completion.work_item.product_id().to_string();
         let work_item_id = work_item_id(&completion.work_item);
 
+        // Opt-in: mirror the pass onto the PR as a GitHub review. Runs in
+        // the background and never affects the outcome below; a duplicate
+        // head review was already published (or skipped) by its first pass.
+        if !duplicate_head_review
+            && let Some(result) = review_result.as_ref()
+            && self
+                .feature_flags
+                .is_enabled(crate::pr_review_publish::PR_REVIEW_PUBLISH_FLAG)
+        {
+            crate::pr_review_publish::spawn_publish(
+                self.pr_review_publisher.clone(),
+                self.work_db.clone(),
+                product_id.clone(),
+                pr_url.clone(),
+                result.clone(),
+            );
+        }
+
         // incident-002 postmortem gate: the deletion tripwire fired, so the task is now
         // held in `blocked: deletion_signoff`. File the operator sign-off
         // surface enumerating the removed merged-parent surfaces and stop — no
@@ -936,7 +954,8 @@ impl WorkerCompletionHandler {
         // If the severity gate passed, create a revision on the
         // producing task with the rendered findings as revision instructions.
         // The revision is dispatched on the general worker pool (autostart = true,
-        // the default). Nothing is posted to GitHub — feedback stays inside Boss.
+        // the default). The revision itself stays inside Boss; only the opt-in
+        // publishing step above posts anything to GitHub.
         if revision_warranted {
             // `review_result` is Some when `revision_warranted` is true.
             let result = review_result.expect("revision_warranted implies Some(ReviewResult)");
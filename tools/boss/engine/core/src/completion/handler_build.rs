@@ -37,6 +37,7 @@ impl WorkerCompletionHandler {
             branch_verifier: Arc::new(CommandBranchVerifier::new()),
             metrics: local_metrics,
             merge_probe: Arc::new(NoopMergeProbe),
+            pr_review_publisher: Arc::new(crate::pr_review_publish::PrReviewPublisher::default()),
             conflict_unknown_backoff: conflict_stop_gate::DEFAULT_UNKNOWN_RETRY_BACKOFF,
             nudge_breaker: Arc::new(NudgeBreaker::new()),
             max_unproductive_nudges: DEFAULT_MAX_UNPRODUCTIVE_NUDGES,
@@ -296,6 +297,13 @@ impl WorkerCompletionHandler {
         self
     }
 
+    /// Replace the publisher used to post review passes to GitHub. Only
+    /// consulted when the `pr_review_publish` flag is on.
+    pub fn with_pr_review_publisher(mut self, publisher: Arc<crate::pr_review_publish::PrReviewPublisher>) -> Self {
+        self.pr_review_publisher = publisher;
+        self
+    }
+
     /// Shrink the conflict stop gate's `mergeable=UNKNOWN` re-probe
     /// backoff. Tests pass [`std::time::Duration::ZERO`] so exercising the
     /// indeterminate path costs no wall clock; production keeps the
@@ -2,7 +2,7 @@ use std::ffi::OsString;
 use std::path::PathBuf;
 use std::sync::{Arc, OnceLock};
 
-use anyhow::{Context, Result, bail};
+use anyhow::{Context, Result, anyhow, bail};
 
 use boss_engine_utility_model::{self as utility_model, UtilityModel};
 
@@ -98,6 +98,24 @@ pub const DEFAULT_ENABLE_SPAWN_CAPABILITY_BREAKER: bool = true;
 /// heartbeat.
 pub const DEFAULT_ENABLE_DISPATCH_READY_BUS: bool = false;
 
+/// Default value for [`WorkConfig::enable_durable_event_bus`]. **OFF** by
+/// default. When on, the engine's event bus journals every published event
+/// under `<state_root>/event-bus/` (day-rotated, pruned after a week) and
+/// durable subscribers — today the event-triggered automation loop —
+/// resume from a persisted cursor after a restart instead of losing the
+/// transitions published while the engine was down or crashing. The
+/// journal is also what `bossctl events replay` reads. Turning it off
+/// falls back to the in-memory bus; existing journal files are left alone.
+pub const DEFAULT_ENABLE_DURABLE_EVENT_BUS: bool = false;
+
+/// Env var naming where the OpenMetrics exporter listens — see
+/// [`WorkConfig::metrics_exporter_endpoint`]. Unset (the default) means no
+/// exporter: the registry is still flushed to `state.db` every 30s, but
+/// nothing outside the engine can scrape it live. Accepts `host:port`
+/// (`127.0.0.1:9464` for a Prometheus on the same machine) or
+/// `unix:<path>`.
+pub const METRICS_EXPORTER_ADDR_ENV: &str = "BOSS_METRICS_EXPORTER_ADDR";
+
 /// Default value for [`WorkConfig::coordinator_model`]. The Boss coordinator
 /// session (the macOS app's single always-on Claude Code pane) launches on
 /// this model unless overridden. Top-tier models are opt-in only: the
@@ -214,6 +232,16 @@ pub struct WorkConfig {
     /// `BOSS_ENABLE_DISPATCH_READY_BUS`.
     #[builder(default = DEFAULT_ENABLE_DISPATCH_READY_BUS)]
     pub enable_dispatch_ready_bus: bool,
+    /// Whether the event bus runs in durable (journaled, replayable) mode.
+    /// OFF by default — see [`DEFAULT_ENABLE_DURABLE_EVENT_BUS`].
+    /// Configured via `BOSS_ENABLE_DURABLE_EVENT_BUS`.
+    #[builder(default = DEFAULT_ENABLE_DURABLE_EVENT_BUS)]
+    pub enable_durable_event_bus: bool,
+    /// Where the OpenMetrics scrape endpoint listens, or `None` (the
+    /// default) for no exporter. Configured via
+    /// [`METRICS_EXPORTER_ADDR_ENV`]; an unparseable value fails config
+    /// load rather than silently leaving dashboards empty.
+    pub metrics_exporter_endpoint: Option<crate::metrics::exporter::MetricsEndpoint>,
     /// Model slug the Boss coordinator session launches with, pushed to the
     /// macOS app as `EnginePoolConfig.coordinator_model` on every
     /// `RegisterAppSession`. Configured via `BOSS_COORDINATOR_MODEL`;
@@ -267,6 +295,15 @@ impl WorkConfig {
             .unwrap_or(DEFAULT_ENABLE_SPAWN_CAPABILITY_BREAKER);
         let enable_dispatch_ready_bus =
             lookup_bool(&lookup, "BOSS_ENABLE_DISPATCH_READY_BUS")?.unwrap_or(DEFAULT_ENABLE_DISPATCH_READY_BUS);
+        let enable_durable_event_bus =
+            lookup_bool(&lookup, "BOSS_ENABLE_DURABLE_EVENT_BUS")?.unwrap_or(DEFAULT_ENABLE_DURABLE_EVENT_BUS);
+        let metrics_exporter_endpoint = lookup_string(&lookup, METRICS_EXPORTER_ADDR_ENV)
+            .filter(|raw| !raw.trim().is_empty())
+            .map(|raw| {
+                crate::metrics::exporter::MetricsEndpoint::parse(&raw)
+                    .map_err(|err| anyhow!("could not parse {METRICS_EXPORTER_ADDR_ENV}: {err}"))
+            })
+            .transpose()?;
         let coordinator_model = lookup_string(&lookup, "BOSS_COORDINATOR_MODEL")
             .map(|s| s.trim().to_owned())
             .filter(|s| !s.is_empty())
@@ -285,6 +322,8 @@ impl WorkConfig {
             .merge_order_stagger_secs(merge_order_stagger_secs)
             .enable_spawn_capability_breaker(enable_spawn_capability_breaker)
             .enable_dispatch_ready_bus(enable_dispatch_ready_bus)
+            .enable_durable_event_bus(enable_durable_event_bus)
+            .maybe_metrics_exporter_endpoint(metrics_exporter_endpoint)
             .coordinator_model(coordinator_model)
             .build())
     }
@@ -552,10 +591,11 @@ fn default_db_path() -> Result<PathBuf> {
 #[cfg(test)]
 mod tests {
     use super::{
-        DEFAULT_COORDINATOR_MODEL, DEFAULT_ENABLE_DISPATCH_READY_BUS, DEFAULT_ENABLE_REVISION_TRIGGERED_REVIEWS,
-        DEFAULT_ENABLE_SPAWN_CAPABILITY_BREAKER, DEFAULT_MAX_EMBED_DIFF_LINES, DEFAULT_MAX_REVIEW_CYCLES,
-        DEFAULT_MERGE_ORDER_STAGGER_SECS, DEFAULT_MIN_REVIEW_CHANGED_LINES, DEFAULT_REVIEW_POOL_SIZE,
-        MAX_AUTOMATION_POOL_SIZE, MAX_MERGE_ORDER_STAGGER_SECS, MAX_WORKER_POOL_SIZE, WorkConfig,
+        DEFAULT_COORDINATOR_MODEL, DEFAULT_ENABLE_DISPATCH_READY_BUS, DEFAULT_ENABLE_DURABLE_EVENT_BUS,
+        DEFAULT_ENABLE_REVISION_TRIGGERED_REVIEWS, DEFAULT_ENABLE_SPAWN_CAPABILITY_BREAKER,
+        DEFAULT_MAX_EMBED_DIFF_LINES, DEFAULT_MAX_REVIEW_CYCLES, DEFAULT_MERGE_ORDER_STAGGER_SECS,
+        DEFAULT_MIN_REVIEW_CHANGED_LINES, DEFAULT_REVIEW_POOL_SIZE, MAX_AUTOMATION_POOL_SIZE,
+        MAX_MERGE_ORDER_STAGGER_SECS, MAX_WORKER_POOL_SIZE, METRICS_EXPORTER_ADDR_ENV, WorkConfig,
     };
     use std::ffi::OsString;
 
@@ -848,6 +888,62 @@ mod tests {
         assert!(!config.enable_dispatch_ready_bus);
     }
 
+    #[test]
+    fn enable_durable_event_bus_defaults_off_and_reads_env() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let db_path_str = tempdir.path().join("state.db");
+
+        let config = WorkConfig::load_from(|k| match k {
+            "BOSS_DB_PATH" => Some(OsString::from(&db_path_str)),
+            _ => None,
+        })
+        .expect("config loads");
+        assert_eq!(config.enable_durable_event_bus, DEFAULT_ENABLE_DURABLE_EVENT_BUS);
+        assert!(!config.enable_durable_event_bus, "durable event bus defaults off");
+
+        let config = WorkConfig::load_from(|k| match k {
+            "BOSS_ENABLE_DURABLE_EVENT_BUS" => Some(OsString::from("true")),
+            "BOSS_DB_PATH" => Some(OsString::from(&db_path_str)),
+            _ => None,
+        })
+        .expect("config loads");
+        assert!(config.enable_durable_event_bus);
+    }
+
+    #[test]
+    fn metrics_exporter_endpoint_defaults_off_and_reads_env() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let db_path_str = tempdir.path().join("state.db");
+
+        let config = WorkConfig::load_from(|k| match k {
+            "BOSS_DB_PATH" => Some(OsString::from(&db_path_str)),
+            _ => None,
+        })
+        .expect("config loads");
+        assert_eq!(config.metrics_exporter_endpoint, None, "no exporter unless asked for");
+
+        let config = WorkConfig::load_from(|k| match k {
+            METRICS_EXPORTER_ADDR_ENV => Some(OsString::from("127.0.0.1:9464")),
+            "BOSS_DB_PATH" => Some(OsString::from(&db_path_str)),
+            _ => None,
+        })
+        .expect("config loads");
+        assert_eq!(
+            config.metrics_exporter_endpoint,
+            Some(crate::metrics::exporter::MetricsEndpoint::Tcp(
+                "127.0.0.1:9464".parse().unwrap()
+            ))
+        );
+
+        let err = WorkConfig::load_from(|k| match k {
+            METRICS_EXPORTER_ADDR_ENV => Some(OsString::from("prometheus")),
+            "BOSS_DB_PATH" => Some(OsString::from(&db_path_str)),
+            _ => None,
+        })
+        .unwrap_err();
+        assert!(err.to_string().contains(METRICS_EXPORTER_ADDR_ENV), "{err}");
+    }
+
     #[test]
     fn enable_revision_triggered_reviews_rejects_unparseable_value() {
         let tempdir = tempfile::tempdir().unwrap();
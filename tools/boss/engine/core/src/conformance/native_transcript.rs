@@ -28,7 +28,8 @@ const BLOCKED_MARKER: &str = "[blocked] reason=\"native-dialect fixture needs a
 /// on that slug rather than silently skipping it.
 fn native_blocked_marker_jsonl(slug: &str) -> Option<String> {
     match slug {
-        "claude" => Some(format!(
+        // The scripted agent writes Claude-shaped transcript lines.
+        "claude" | "scripted" => Some(format!(
             concat!(
                 r#"{{"type":"user","message":{{"content":[{{"type":"text","text":"do the work"}}]}}}}"#,
                 "\n",
@@ -63,6 +64,18 @@ fn native_blocked_marker_jsonl(slug: &str) -> Option<String> {
             ),
             marker = serde_json::to_string(BLOCKED_MARKER).unwrap(),
         )),
+        // Gemini records one chat message per line under
+        // `$GEMINI_CLI_HOME/.gemini/tmp/<project>/chats/` (the path stamped on
+        // every hook payload as `transcript_path`); `gemini` is the model turn.
+        "gemini" => Some(format!(
+            concat!(
+                r#"{{"id":"m-1","timestamp":"2026-10-01T00:00:00Z","type":"user","content":"do the work"}}"#,
+                "\n",
+                r#"{{"id":"m-2","timestamp":"2026-10-01T00:00:01Z","type":"gemini","content":{marker}}}"#,
+                "\n",
+            ),
+            marker = serde_json::to_string(BLOCKED_MARKER).unwrap(),
+        )),
         _ => None,
     }
 }
@@ -0,0 +1,1314 @@
+//! Container host adapter: run each worker in a rootless container on the
+//! engine's own machine.
+//!
+//! A third [`HostAdapter`] alongside the local pane spawner and the SSH
+//! adapter, for work an operator does not want running directly in their
+//! user session — third-party repos, exploratory chores, anything tagged
+//! risky. A container host is a `hosts` row with a `container_runtime`
+//! (`podman`, `docker`, or `bubblewrap`) instead of an `ssh_target`.
+//!
+//! Workspace lifecycle is the local cube's: the adapter delegates lease /
+//! release / heartbeat / change creation to the local adapter, so the
+//! leased workspace is an ordinary local cube workspace. Only the worker
+//! process moves: it is launched detached inside the container with the
+//! workspace bind-mounted read-write at its own path and nothing else of
+//! the operator's home visible.
+//!
+//! ## What the worker sees
+//!
+//! - the leased workspace (read-write), at the same absolute path;
+//! - a per-host home (`<state root>/containers/homes/<host>`, read-write)
+//!   that the operator seeds with driver credentials — never `~`;
+//! - a per-run directory (read-only) holding the worker's `--settings`
+//!   file and a hard link to the engine's events socket, so `boss-event`
+//!   hooks reach the engine exactly as a local worker's do;
+//! - the engine's `boss-event` shim (read-only), when it resolves.
+//!
+//! The events socket is hard-linked rather than bind-mounted as a file: a
+//! file bind pins the inode, which goes stale the moment the engine
+//! restarts and re-binds its socket. Linking into a mounted *directory*
+//! lets [`ContainerHostAdapter::reattach_events_forward`] swap the link
+//! after a restart and have running containers see the new socket.
+//!
+//! ## Policy
+//!
+//! Network policy and resource limits ride the same
+//! `work_capability_requirements` rows host selection reads, so a product
+//! opts into isolation with `bossctl hosts require product <id> --tag …`:
+//!
+//! - `isolation=container` routes its work to container hosts only;
+//! - `container-network=none|private|host` picks the network policy (the
+//!   most restrictive wins when several subjects set one);
+//! - `container-cpus=` / `container-memory=` / `container-pids=` set
+//!   per-run limits ([`ContainerLimits`]).
+//!
+//! Each container host advertises what it can enforce through the
+//! capability vocabulary in [`crate::host_capability_probe`], so
+//! [`crate::host_scheduling::select_host`] only routes work to a host that
+//! can honor its policy.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, Result, bail};
+use async_trait::async_trait;
+use tokio::process::Command;
+use tokio::time::timeout;
+
+use crate::config::RuntimeConfig;
+use crate::coordinator::{CubeChangeHandle, CubeRepoHandle, CubeRepoSummary, CubeWorkspaceLease, CubeWorkspaceStatus};
+use crate::host_adapter::{
+    HostAdapter, reject_host_local_remote_spawn_plan, reject_unobservable_remote_driver, remote_driver_config_paths,
+    remote_worker_log_path,
+};
+use crate::host_capability_probe::{
+    CONTAINER_LIMITS_CAPABILITY, ISOLATION_CONTAINER_CAPABILITY, RemoteRunner, container_network_capability,
+    container_runtime_capability, discover_remote_capabilities,
+};
+use crate::host_provisioning::RemoteProvisionOutcome;
+use crate::host_scheduling::ContainerLimits;
+use crate::runner::{
+    ComposedWorkerSpawn, RunOutcome, RunWaitState, WorkerSpawnOpts, compose_worker_spawn, work_item_name,
+    work_item_task_kind,
+};
+use crate::ssh_spawn::WORKER_EXIT_STATUS_PREFIX;
+use crate::ssh_transport::{SshOutput, shell_quote};
+use crate::work::{WorkDb, WorkExecution, WorkItem};
+use crate::worker_setup::{WorkerSetupInput, render_remote_settings_json};
+
+/// Wall-clock bound on a single container CLI call (`run --detach`,
+/// `inspect`, a probe). Image pulls are the slow case; an image that is
+/// not already present should be pulled ahead of time (`hosts probe`
+/// reports it missing) rather than on the dispatch path.
+const CONTAINER_CLI_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Requirement-tag prefix selecting a container network policy.
+const CONTAINER_NETWORK_PREFIX: &str = "container-network=";
+
+/// File name of the events-socket hard link inside a run directory.
+const RUN_EVENTS_SOCKET: &str = "events.sock";
+
+/// Fallback hook shim name when the engine's own `boss-event` does not
+/// resolve; the image is then expected to carry it on `PATH`.
+const IMAGE_BOSS_EVENT_BIN: &str = "boss-event";
+
+// ── Runtime + policy vocabulary ──────────────────────────────────────────────
+
+/// The container runtimes a container host can be registered with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerRuntime {
+    Podman,
+    Docker,
+    /// `bwrap`: an unprivileged namespace sandbox over the engine host's own
+    /// (read-only) root filesystem. No image, and no cgroup limits.
+    Bubblewrap,
+}
+
+impl ContainerRuntime {
+    pub fn parse(raw: &str) -> Result<Self> {
+        match raw {
+            "podman" => Ok(Self::Podman),
+            "docker" => Ok(Self::Docker),
+            "bubblewrap" | "bwrap" => Ok(Self::Bubblewrap),
+            other => bail!("unknown container runtime '{other}'; expected podman, docker, or bubblewrap"),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Podman => "podman",
+            Self::Docker => "docker",
+            Self::Bubblewrap => "bubblewrap",
+        }
+    }
+
+    /// Executable invoked for this runtime.
+    pub fn binary(self) -> &'static str {
+        match self {
+            Self::Podman => "podman",
+            Self::Docker => "docker",
+            Self::Bubblewrap => "bwrap",
+        }
+    }
+
+    /// Whether workers run in an OCI image (and so the host needs one).
+    pub fn needs_image(self) -> bool {
+        !matches!(self, Self::Bubblewrap)
+    }
+
+    /// Whether the runtime can enforce [`ContainerLimits`]. `bwrap` has no
+    /// cgroup integration, so it never advertises
+    /// [`CONTAINER_LIMITS_CAPABILITY`] and work with limits never lands on it.
+    pub fn supports_limits(self) -> bool {
+        !matches!(self, Self::Bubblewrap)
+    }
+
+    /// Network policies the runtime can enforce.
+    pub fn network_policies(self) -> &'static [ContainerNetwork] {
+        match self {
+            Self::Podman | Self::Docker => &[
+                ContainerNetwork::None,
+                ContainerNetwork::Private,
+                ContainerNetwork::Host,
+            ],
+            Self::Bubblewrap => &[ContainerNetwork::None, ContainerNetwork::Host],
+        }
+    }
+
+    /// Policy used when no requirement names one. Workers need to reach
+    /// their model provider and GitHub, so the default keeps egress open;
+    /// products that must not have it require `container-network=none`.
+    pub fn default_network(self) -> ContainerNetwork {
+        match self {
+            Self::Podman | Self::Docker => ContainerNetwork::Private,
+            Self::Bubblewrap => ContainerNetwork::Host,
+        }
+    }
+
+    /// Auto capabilities every host on this runtime advertises, independent
+    /// of what the in-container probe discovers.
+    pub fn capabilities(self) -> Vec<String> {
+        let mut caps = vec![
+            ISOLATION_CONTAINER_CAPABILITY.to_owned(),
+            container_runtime_capability(self.as_str()),
+        ];
+        caps.extend(
+            self.network_policies()
+                .iter()
+                .map(|policy| container_network_capability(policy.as_str())),
+        );
+        if self.supports_limits() {
+            caps.push(CONTAINER_LIMITS_CAPABILITY.to_owned());
+        }
+        caps
+    }
+}
+
+/// Network policy for a containerized worker, ordered most to least
+/// restrictive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ContainerNetwork {
+    /// No network namespace egress at all.
+    None,
+    /// A private network namespace with NAT'd egress (the OCI default).
+    Private,
+    /// The engine host's own network namespace.
+    Host,
+}
+
+impl ContainerNetwork {
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw {
+            "none" => Some(Self::None),
+            "private" => Some(Self::Private),
+            "host" => Some(Self::Host),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Private => "private",
+            Self::Host => "host",
+        }
+    }
+
+    /// The most restrictive `container-network=` policy in `required`, if
+    /// any. Unknown spellings are skipped here; no host advertises them, so
+    /// host selection has already refused the work.
+    pub fn from_requirements(required: &BTreeSet<String>) -> Option<Self> {
+        required
+            .iter()
+            .filter_map(|tag| tag.strip_prefix(CONTAINER_NETWORK_PREFIX))
+            .filter_map(Self::parse)
+            .min()
+    }
+}
+
+// ── Container CLI seam ───────────────────────────────────────────────────────
+
+/// The container runtime's command-line surface. Production shells out via
+/// [`CommandContainerCli`]; tests substitute a fake that records argv.
+#[async_trait]
+pub trait ContainerCli: Send + Sync {
+    /// Run `program args…` to completion and capture its output.
+    async fn run(&self, program: &str, args: &[String]) -> Result<SshOutput>;
+
+    /// Launch `program args…` detached (its own session, stdio closed) and
+    /// return its pid. Used for `bwrap`, which has no daemon to hand the
+    /// worker to.
+    async fn spawn_detached(&self, program: &str, args: &[String]) -> Result<u32>;
+}
+
+/// [`ContainerCli`] over real subprocesses.
+#[derive(Debug, Default)]
+pub struct CommandContainerCli;
+
+#[async_trait]
+impl ContainerCli for CommandContainerCli {
+    async fn run(&self, program: &str, args: &[String]) -> Result<SshOutput> {
+        let mut cmd = Command::new(program);
+        cmd.args(args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+        let output = timeout(CONTAINER_CLI_TIMEOUT, cmd.output())
+            .await
+            .with_context(|| format!("{program} timed out after {}s", CONTAINER_CLI_TIMEOUT.as_secs()))?
+            .with_context(|| format!("running {program}"))?;
+        Ok(SshOutput {
+            status: output.status.code().unwrap_or(-1),
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        })
+    }
+
+    async fn spawn_detached(&self, program: &str, args: &[String]) -> Result<u32> {
+        // No `kill_on_drop`: the worker must outlive this call (and an
+        // engine restart). Dropping the handle leaves tokio to reap it, so
+        // a dead worker never lingers as a zombie that `kill -0` would
+        // still report alive.
+        let child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .process_group(0)
+            .spawn()
+            .with_context(|| format!("spawning {program}"))?;
+        child
+            .id()
+            .with_context(|| format!("{program} exited before its pid could be read"))
+    }
+}
+
+// ── Launch argv ──────────────────────────────────────────────────────────────
+
+/// Everything [`container_run_args`] needs to render one worker launch.
+#[derive(Debug, Clone, bon::Builder)]
+#[builder(on(String, into))]
+pub struct ContainerLaunch {
+    pub run_id: String,
+    /// Leased cube workspace, mounted read-write at the same path.
+    pub workspace: PathBuf,
+    /// Per-host home, mounted read-write and exported as `HOME`.
+    pub home: PathBuf,
+    /// Per-run directory (settings + events-socket link), mounted read-only.
+    pub run_dir: PathBuf,
+    /// The engine's `boss-event` shim, mounted read-only when present.
+    pub boss_event_binary: Option<PathBuf>,
+    pub network: ContainerNetwork,
+    #[builder(default)]
+    pub limits: ContainerLimits,
+    /// Environment for the worker, beyond `HOME`.
+    #[builder(default)]
+    pub env: Vec<(String, String)>,
+    /// Shell script the container runs (see [`worker_script`]).
+    pub script: String,
+}
+
+/// Container name for a run. OCI names allow `[a-zA-Z0-9_.-]`.
+pub fn container_name(run_id: &str) -> String {
+    let sanitized: String = run_id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' || c == '.' || c == '-' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    format!("boss-{sanitized}")
+}
+
+/// Render the argv (after the runtime binary) that launches `launch`.
+///
+/// Pure so the exact isolation posture — which paths are visible, with
+/// what access, under which network and limits — is testable without a
+/// container runtime. Refuses a policy the runtime cannot enforce rather
+/// than silently running the worker with less isolation than required.
+pub fn container_run_args(
+    runtime: ContainerRuntime,
+    image: Option<&str>,
+    launch: &ContainerLaunch,
+) -> Result<Vec<String>> {
+    if !runtime.network_policies().contains(&launch.network) {
+        bail!(
+            "container runtime {} cannot enforce network policy '{}'",
+            runtime.as_str(),
+            launch.network.as_str()
+        );
+    }
+    if !launch.limits.is_empty() && !runtime.supports_limits() {
+        bail!("container runtime {} cannot enforce resource limits", runtime.as_str());
+    }
+    let workspace = launch.workspace.display().to_string();
+    let home = launch.home.display().to_string();
+    let run_dir = launch.run_dir.display().to_string();
+    let mut env = vec![("HOME".to_owned(), home.clone())];
+    env.extend(launch.env.iter().cloned());
+
+    let mut args: Vec<String> = Vec::new();
+    match runtime {
+        ContainerRuntime::Podman | ContainerRuntime::Docker => {
+            let image = image.with_context(|| format!("container runtime {} requires an image", runtime.as_str()))?;
+            args.extend(["run", "--detach", "--rm", "--name"].map(str::to_owned));
+            args.push(container_name(&launch.run_id));
+            args.push(format!("--label=boss.run_id={}", launch.run_id));
+            // Rootless: files the worker writes into the workspace must stay
+            // owned by the operator, not by a container-root uid.
+            if runtime == ContainerRuntime::Podman {
+                args.push("--userns=keep-id".to_owned());
+            } else {
+                // SAFETY: getuid/getgid cannot fail and touch no memory.
+                let (uid, gid) = unsafe { (libc::getuid(), libc::getgid()) };
+                args.push(format!("--user={uid}:{gid}"));
+            }
+            args.extend(["--security-opt=no-new-privileges", "--cap-drop=ALL"].map(str::to_owned));
+            let network = match launch.network {
+                ContainerNetwork::None => "none",
+                ContainerNetwork::Private if runtime == ContainerRuntime::Podman => "private",
+                ContainerNetwork::Private => "bridge",
+                ContainerNetwork::Host => "host",
+            };
+            args.push(format!("--network={network}"));
+            if let Some(cpus) = launch.limits.cpus {
+                args.push(format!("--cpus={cpus}"));
+            }
+            if let Some(bytes) = launch.limits.memory_bytes {
+                args.push(format!("--memory={bytes}b"));
+            }
+            if let Some(pids) = launch.limits.pids {
+                args.push(format!("--pids-limit={pids}"));
+            }
+            args.push(format!("--volume={workspace}:{workspace}:rw"));
+            args.push(format!("--volume={home}:{home}:rw"));
+            args.push(format!("--volume={run_dir}:{run_dir}:ro"));
+            if let Some(shim) = &launch.boss_event_binary {
+                let shim = shim.display();
+                args.push(format!("--volume={shim}:{shim}:ro"));
+            }
+            args.push(format!("--workdir={workspace}"));
+            for (key, value) in &env {
+                args.push(format!("--env={key}={value}"));
+            }
+            args.extend([image, "sh", "-c", launch.script.as_str()].map(str::to_owned));
+        }
+        ContainerRuntime::Bubblewrap => {
+            args.extend(["--new-session", "--unshare-all"].map(str::to_owned));
+            if launch.network == ContainerNetwork::Host {
+                args.push("--share-net".to_owned());
+            }
+            // `--clearenv` before `--setenv` so nothing from the engine's
+            // own environment (tokens, sockets) leaks into the worker.
+            args.extend(
+                [
+                    "--clearenv",
+                    "--ro-bind",
+                    "/",
+                    "/",
+                    "--dev",
+                    "/dev",
+                    "--proc",
+                    "/proc",
+                    "--tmpfs",
+                    "/tmp",
+                ]
+                .map(str::to_owned),
+            );
+            // Shadow the operator's real home before binding the per-host one
+            // (which may live beneath it).
+            if let Some(real_home) = std::env::var_os("HOME") {
+                args.extend(["--tmpfs".to_owned(), PathBuf::from(real_home).display().to_string()]);
+            }
+            for (flag, path) in [("--bind", &home), ("--bind", &workspace), ("--ro-bind", &run_dir)] {
+                args.extend([flag.to_owned(), path.clone(), path.clone()]);
+            }
+            if let Some(shim) = &launch.boss_event_binary {
+                let shim = shim.display().to_string();
+                args.extend(["--ro-bind".to_owned(), shim.clone(), shim]);
+            }
+            args.extend(["--chdir".to_owned(), workspace.clone()]);
+            args.extend(["--setenv".to_owned(), "PATH".to_owned(), sandbox_path()]);
+            for (key, value) in &env {
+                args.extend(["--setenv".to_owned(), key.clone(), value.clone()]);
+            }
+            args.extend(["sh", "-c", launch.script.as_str()].map(str::to_owned));
+        }
+    }
+    Ok(args)
+}
+
+/// `PATH` inside a `bwrap` sandbox: the engine's own, which resolves the
+/// same binaries since the root filesystem is the engine host's.
+fn sandbox_path() -> String {
+    std::env::var("PATH").unwrap_or_else(|_| "/usr/local/bin:/usr/bin:/bin".to_owned())
+}
+
+/// The in-container script: tee everything into `<workspace>/.boss/worker.log`
+/// (the file [`HostAdapter::read_worker_log_tail`] reads back), run the
+/// driver's command under its env directives, and append the same exit
+/// status line the remote wrapper writes so the shared log parsers apply.
+pub fn worker_script(driver_env: &str, driver_command: &str) -> String {
+    format!(
+        "mkdir -p .boss && exec >>.boss/worker.log 2>&1\n\
+         printf 'boss-container-run: starting run %s\\n' \"$BOSS_RUN_ID\"\n\
+         ( {driver_env}{driver_command} )\n\
+         printf '{WORKER_EXIT_STATUS_PREFIX}%s\\n' \"$?\"\n"
+    )
+}
+
+/// Point `run_dir/events.sock` at the engine's live events socket. Replaces
+/// any stale link left by a previous engine process.
+fn link_events_socket(engine_socket: &Path, run_dir: &Path) -> Result<PathBuf> {
+    let link = run_dir.join(RUN_EVENTS_SOCKET);
+    match std::fs::remove_file(&link) {
+        Ok(()) => {}
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err).with_context(|| format!("removing stale {}", link.display())),
+    }
+    std::fs::hard_link(engine_socket, &link).with_context(|| {
+        format!(
+            "linking events socket {} into {} (the container state dir must share a filesystem with it)",
+            engine_socket.display(),
+            run_dir.display()
+        )
+    })?;
+    Ok(link)
+}
+
+/// Engine-owned root for container-host state: per-host homes and per-run
+/// directories. `None` when no state root resolves.
+pub fn default_container_state_dir() -> Option<PathBuf> {
+    boss_log_files::default_state_root().map(|root| root.join("containers"))
+}
+
+// ── ContainerHostAdapter ─────────────────────────────────────────────────────
+
+/// [`HostAdapter`] for a container host. See the module docs.
+#[derive(bon::Builder)]
+#[builder(on(String, into))]
+pub struct ContainerHostAdapter {
+    host_id: String,
+    runtime: ContainerRuntime,
+    /// OCI image for `podman` / `docker`; `None` for `bubblewrap`.
+    image: Option<String>,
+    /// The engine's local adapter: the workspace is a local cube lease.
+    local: Arc<dyn HostAdapter>,
+    cli: Arc<dyn ContainerCli>,
+    work_db: Arc<WorkDb>,
+    cfg: Arc<RuntimeConfig>,
+    /// Mirrors `workers.non_opus_permission_mode`, as on the SSH path.
+    non_opus_auto_mode: bool,
+    /// The engine's bound events socket, hard-linked into each run dir.
+    events_socket_path: PathBuf,
+    /// Root for `homes/<host>` and `runs/<run>`
+    /// ([`default_container_state_dir`] in production).
+    state_dir: PathBuf,
+    /// The engine's `boss-event` shim, if it resolves. `None` falls back to
+    /// a `boss-event` on the image's `PATH`.
+    boss_event_binary: Option<PathBuf>,
+}
+
+impl ContainerHostAdapter {
+    fn run_dir(&self, run_id: &str) -> PathBuf {
+        self.state_dir.join("runs").join(run_id)
+    }
+
+    fn home_dir(&self) -> PathBuf {
+        self.state_dir.join("homes").join(&self.host_id)
+    }
+
+    /// Launch the rendered argv and return the worker's host-side pid.
+    async fn launch(&self, args: &[String], run_id: &str, workspace: &str) -> Result<u32> {
+        let binary = self.runtime.binary();
+        if !self.runtime.needs_image() {
+            return self.cli.spawn_detached(binary, args).await;
+        }
+        let out = self.cli.run(binary, args).await?;
+        if !out.success() {
+            bail!("{binary} run exited {}: {}", out.status, out.stderr.trim());
+        }
+        let name = container_name(run_id);
+        let inspect = [
+            "inspect".to_owned(),
+            "--format".to_owned(),
+            "{{.State.Pid}}".to_owned(),
+            name.clone(),
+        ];
+        let out = self.cli.run(binary, &inspect).await?;
+        let pid = out.stdout.trim().parse::<u32>().ok().filter(|pid| *pid > 0);
+        match (out.success(), pid) {
+            (true, Some(pid)) => Ok(pid),
+            // A zero pid means the container already exited — the worker
+            // died on startup, and `--rm` has (or is about to have) removed it.
+            _ => bail!(
+                "container {name} is not running after launch (inspect: {} {}); see {}",
+                out.stdout.trim(),
+                out.stderr.trim(),
+                remote_worker_log_path(workspace),
+            ),
+        }
+    }
+}
+
+#[async_trait]
+impl HostAdapter for ContainerHostAdapter {
+    fn host_id(&self) -> &str {
+        &self.host_id
+    }
+
+    async fn ensure_repo(&self, origin: &str) -> Result<CubeRepoHandle> {
+        self.local.ensure_repo(origin).await
+    }
+
+    async fn lease_workspace(
+        &self,
+        repo_id: &str,
+        task: &str,
+        prefer_workspace_id: Option<&str>,
+        allow_dirty: bool,
+        exclude_workspace_ids: &[&str],
+    ) -> Result<CubeWorkspaceLease> {
+        self.local
+            .lease_workspace(repo_id, task, prefer_workspace_id, allow_dirty, exclude_workspace_ids)
+            .await
+    }
+
+    async fn release_workspace(&self, lease_id: &str) -> Result<()> {
+        self.local.release_workspace(lease_id).await
+    }
+
+    async fn heartbeat_lease(&self, lease_id: &str, ttl_seconds: Option<u64>) -> Result<()> {
+        self.local.heartbeat_lease(lease_id, ttl_seconds).await
+    }
+
+    async fn force_release_lease(&self, lease_id: &str, reason: Option<&str>) -> Result<()> {
+        self.local.force_release_lease(lease_id, reason).await
+    }
+
+    async fn create_change(&self, workspace_path: &Path, title: &str) -> Result<CubeChangeHandle> {
+        self.local.create_change(workspace_path, title).await
+    }
+
+    async fn goto_workspace(&self, workspace_path: &Path, pr: u64) -> Result<()> {
+        self.local.goto_workspace(workspace_path, pr).await
+    }
+
+    async fn workspace_status(&self, workspace_path: &Path) -> Result<CubeWorkspaceStatus> {
+        self.local.workspace_status(workspace_path).await
+    }
+
+    async fn list_workspaces(&self) -> Result<Vec<CubeWorkspaceStatus>> {
+        self.local.list_workspaces().await
+    }
+
+    async fn list_repos(&self) -> Result<Vec<CubeRepoSummary>> {
+        self.local.list_repos().await
+    }
+
+    fn command_repr(&self, args: &[&str]) -> Option<(String, String)> {
+        self.local.command_repr(args)
+    }
+
+    async fn spawn_worker(
+        &self,
+        worker_id: &str,
+        execution: &WorkExecution,
+        work_item: &WorkItem,
+        workspace_path: &Path,
+        cube_change_id: Option<&str>,
+    ) -> Result<RunOutcome> {
+        let host = self.host_id.clone();
+        let lease_id = execution
+            .cube_lease_id
+            .clone()
+            .context("execution missing cube_lease_id; coordinator must lease before container spawn")?;
+        let run_id = execution.id.clone();
+
+        // 1. Same prompt + spawn config as every other host. The workspace
+        //    is local, so the shared path's filesystem probes (Bazel gate)
+        //    already see the right tree. Proposal seams stay off for the
+        //    same reason as on the SSH path: no feature-flag store here.
+        let ComposedWorkerSpawn {
+            prompt_text,
+            spawn_config,
+        } = compose_worker_spawn(
+            &self.work_db,
+            worker_id,
+            execution,
+            work_item,
+            workspace_path,
+            cube_change_id,
+            WorkerSpawnOpts {
+                editorial_enabled: false,
+                max_embed_diff_lines: self.cfg.work.max_review_embed_diff_lines,
+                worker_signal_proposals_seam_enabled: false,
+                deferred_scope_proposals_seam_enabled: false,
+                followup_proposals_seam_enabled: false,
+            },
+        )
+        .await?;
+
+        // 2. Policy: the same requirement rows host selection filtered on.
+        let mut required = self
+            .work_db
+            .required_capabilities_for_work_item(work_item)
+            .context("container spawn: capability requirements")?;
+        let limits = ContainerLimits::take_from(&mut required);
+        let network = ContainerNetwork::from_requirements(&required).unwrap_or_else(|| self.runtime.default_network());
+
+        // 3. Per-run dir: settings + the events-socket link.
+        let run_dir = self.run_dir(&run_id);
+        std::fs::create_dir_all(&run_dir).with_context(|| format!("creating {}", run_dir.display()))?;
+        let home = self.home_dir();
+        std::fs::create_dir_all(&home).with_context(|| format!("creating {}", home.display()))?;
+        let events_socket = link_events_socket(&self.events_socket_path, &run_dir)?;
+
+        // 4. Worker settings: hooks point at the linked socket and the
+        //    mounted shim. Rendered without the engine-data-dir sandbox —
+        //    the container itself is the sandbox, and the engine's data dir
+        //    is not mounted.
+        let settings_input = WorkerSetupInput {
+            run_id: run_id.clone(),
+            lease_id: lease_id.clone(),
+            workspace_path: workspace_path.to_path_buf(),
+            events_socket_path: events_socket.clone(),
+            boss_event_path: self
+                .boss_event_binary
+                .clone()
+                .unwrap_or_else(|| PathBuf::from(IMAGE_BOSS_EVENT_BIN)),
+            draft_pr_mode: false,
+            execution_kind: execution.kind.as_str().to_owned(),
+            task_kind: work_item_task_kind(work_item).map(str::to_owned),
+            worker_kind: crate::worker_setup::worker_kind_for_execution(&execution.kind),
+        };
+        let driver = crate::driver::DriverRegistry::default()
+            .require(&spawn_config.driver)
+            .map_err(|err| anyhow::anyhow!("container spawn: {err}"))?;
+        // The same two gates as the SSH path: the container cannot see the
+        // coordinator's per-run homes, and a worker whose only progress
+        // channel is a byte stream would be invisible from inside it.
+        reject_host_local_remote_spawn_plan(driver.as_ref(), &spawn_config.driver, &host)?;
+        reject_unobservable_remote_driver(driver.as_ref(), &spawn_config.driver, &settings_input, &host)?;
+        let settings_json = render_remote_settings_json(&settings_input, driver.as_ref());
+        let settings_path = run_dir.join("settings.json");
+        std::fs::write(&settings_path, settings_json)
+            .with_context(|| format!("writing {}", settings_path.display()))?;
+
+        let workspace = workspace_path.display().to_string();
+        let (prompt_dir, prompt_path, gitignore_path) = remote_driver_config_paths(&workspace, driver.descriptor());
+        std::fs::create_dir_all(&prompt_dir).with_context(|| format!("creating {prompt_dir}"))?;
+        std::fs::write(&prompt_path, &prompt_text).with_context(|| format!("writing {prompt_path}"))?;
+        std::fs::write(&gitignore_path, driver.config_dir_gitignore())
+            .with_context(|| format!("writing {gitignore_path}"))?;
+
+        // 5. Render + launch.
+        let worker_kind = settings_input.worker_kind;
+        let plan = driver.spawn_invocation(crate::driver::SpawnRequest {
+            model: &spawn_config.model,
+            effort: spawn_config.effort_value,
+            settings_path: Some(settings_path.as_path()),
+            non_opus_auto_mode: self.non_opus_auto_mode,
+            permission_mode_override: worker_kind.forced_permission_mode(),
+            run_id: Some(&run_id),
+        });
+        let driver_env = plan
+            .env
+            .iter()
+            .map(crate::runner::pane_spawn::render_env_directive)
+            .collect::<String>();
+        let launch = ContainerLaunch::builder()
+            .run_id(run_id.clone())
+            .workspace(workspace_path.to_path_buf())
+            .home(home)
+            .run_dir(run_dir)
+            .maybe_boss_event_binary(self.boss_event_binary.clone())
+            .network(network)
+            .limits(limits.clone())
+            .env(vec![
+                ("BOSS_RUN_ID".to_owned(), run_id.clone()),
+                ("BOSS_LEASE_ID".to_owned(), lease_id),
+                ("BOSS_WORKSPACE".to_owned(), workspace.clone()),
+                ("BOSS_EVENTS_SOCKET".to_owned(), events_socket.display().to_string()),
+            ])
+            .script(worker_script(&driver_env, &plan.command))
+            .build();
+        let args = container_run_args(self.runtime, self.image.as_deref(), &launch)?;
+        let pid = self
+            .launch(&args, &run_id, &workspace)
+            .await
+            .with_context(|| format!("launching container worker on host {host}"))?;
+
+        match self.work_db.set_run_remote_pid_for_execution(&run_id, i64::from(pid)) {
+            Ok(true) => {}
+            Ok(false) => tracing::warn!(
+                host_id = %host,
+                run_id = %run_id,
+                pid,
+                "container spawn: no work_runs row to stamp remote_pid onto yet",
+            ),
+            Err(err) => tracing::warn!(
+                host_id = %host,
+                run_id = %run_id,
+                pid,
+                ?err,
+                "container spawn: failed to persist remote_pid",
+            ),
+        }
+
+        tracing::info!(
+            host_id = %host,
+            run_id = %run_id,
+            pid,
+            runtime = self.runtime.as_str(),
+            network = network.as_str(),
+            limits = ?limits,
+            driver = %spawn_config.driver,
+            model = %spawn_config.model,
+            "container worker launched; awaiting Stop over the linked events socket",
+        );
+
+        Ok(RunOutcome {
+            wait_state: RunWaitState::WorkerPaneAlive,
+            result_summary: Some(format!(
+                "Launched container worker '{}' on host {host} ({} pid {pid}, network {}).",
+                work_item_name(work_item),
+                self.runtime.as_str(),
+                network.as_str(),
+            )),
+            attention: None,
+            slot_id: None,
+            spawn_config: Some(spawn_config),
+        })
+    }
+
+    /// Re-link the run's events socket to the restarted engine's socket.
+    /// The run dir is a mounted directory, so the running container sees
+    /// the new link without being touched.
+    async fn reattach_events_forward(&self, run_id: &str, engine_events_socket: &str) -> Result<bool> {
+        let run_dir = self.run_dir(run_id);
+        if !run_dir.is_dir() {
+            bail!("no container run dir for {run_id} at {}", run_dir.display());
+        }
+        link_events_socket(Path::new(engine_events_socket), &run_dir)?;
+        Ok(true)
+    }
+
+    /// The pid is a host-side pid (the container's init, or `bwrap`), so a
+    /// local `kill -0` answers definitively.
+    async fn probe_remote_worker_alive(&self, remote_pid: i64) -> Result<Option<bool>> {
+        let Ok(pid) = libc::pid_t::try_from(remote_pid) else {
+            return Ok(None);
+        };
+        Ok(Some(crate::app::process_is_alive(pid)))
+    }
+
+    async fn read_worker_log_tail(&self, workspace_path: &str, max_bytes: u64) -> Result<Option<String>> {
+        let path = remote_worker_log_path(workspace_path);
+        let bytes = match std::fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Some(String::new())),
+            Err(err) => return Err(err).with_context(|| format!("reading {path}")),
+        };
+        let start = bytes
+            .len()
+            .saturating_sub(usize::try_from(max_bytes).unwrap_or(usize::MAX));
+        Ok(Some(String::from_utf8_lossy(&bytes[start..]).into_owned()))
+    }
+}
+
+// ── Provisioning ─────────────────────────────────────────────────────────────
+
+/// [`RemoteRunner`] that answers capability probes from inside a throwaway
+/// container, so `driver=` / `gh-authed=` describe the image a worker will
+/// actually run in rather than the engine host.
+pub struct ContainerProbeRunner<'a> {
+    pub host_id: &'a str,
+    pub runtime: ContainerRuntime,
+    pub image: Option<&'a str>,
+    pub cli: &'a dyn ContainerCli,
+}
+
+#[async_trait]
+impl RemoteRunner for ContainerProbeRunner<'_> {
+    fn host_id(&self) -> &str {
+        self.host_id
+    }
+
+    async fn run(&self, argv: &[&str]) -> Result<SshOutput> {
+        let script = argv.iter().map(|arg| shell_quote(arg)).collect::<Vec<_>>().join(" ");
+        let mut args: Vec<String> = match self.runtime {
+            ContainerRuntime::Podman | ContainerRuntime::Docker => {
+                let image = self
+                    .image
+                    .with_context(|| format!("container host {} has no image", self.host_id))?;
+                vec!["run".to_owned(), "--rm".to_owned(), image.to_owned()]
+            }
+            ContainerRuntime::Bubblewrap => [
+                "--unshare-all",
+                "--share-net",
+                "--ro-bind",
+                "/",
+                "/",
+                "--dev",
+                "/dev",
+                "--proc",
+                "/proc",
+            ]
+            .map(str::to_owned)
+            .to_vec(),
+        };
+        args.extend(["sh".to_owned(), "-c".to_owned(), script]);
+        self.cli.run(self.runtime.binary(), &args).await
+    }
+}
+
+/// Provision a container host: the runtime answers, the image is present,
+/// and the in-container probe succeeds. Mirrors
+/// [`crate::host_provisioning::provision_remote_host`]'s contract — a host
+/// that fails here must not be left enabled — and returns the discovered
+/// capabilities plus the runtime's own isolation tags.
+pub async fn provision_container_host(
+    host_id: &str,
+    runtime: ContainerRuntime,
+    image: Option<&str>,
+    cli: &dyn ContainerCli,
+) -> RemoteProvisionOutcome {
+    match cli.run(runtime.binary(), &["--version".to_owned()]).await {
+        Ok(out) if out.success() => {}
+        Ok(out) => {
+            return RemoteProvisionOutcome::Failed {
+                kind: "container_runtime_missing",
+                detail: format!(
+                    "{} --version exited {}: {}",
+                    runtime.binary(),
+                    out.status,
+                    out.stderr.trim()
+                ),
+            };
+        }
+        Err(err) => {
+            return RemoteProvisionOutcome::Failed {
+                kind: "container_runtime_missing",
+                detail: format!("{} is not invocable: {err:#}", runtime.binary()),
+            };
+        }
+    }
+    if let Some(image) = image {
+        let inspect = ["image".to_owned(), "inspect".to_owned(), image.to_owned()];
+        match cli.run(runtime.binary(), &inspect).await {
+            Ok(out) if out.success() => {}
+            Ok(_) => {
+                return RemoteProvisionOutcome::Failed {
+                    kind: "container_image_missing",
+                    detail: format!(
+                        "image {image} is not present; pull it with `{} pull {image}`",
+                        runtime.binary()
+                    ),
+                };
+            }
+            Err(err) => {
+                return RemoteProvisionOutcome::Failed {
+                    kind: "unclassified",
+                    detail: format!("inspecting image {image}: {err:#}"),
+                };
+            }
+        }
+    }
+    let runner = ContainerProbeRunner {
+        host_id,
+        runtime,
+        image,
+        cli,
+    };
+    match discover_remote_capabilities(&runner).await {
+        Ok(mut capabilities) => {
+            capabilities.extend(runtime.capabilities());
+            RemoteProvisionOutcome::Ok { capabilities }
+        }
+        Err(err) => RemoteProvisionOutcome::Failed {
+            kind: "capability_probe_failed",
+            detail: format!("{err:#}"),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    /// Fake container CLI: records every call and answers from a script.
+    #[derive(Default)]
+    struct FakeCli {
+        calls: Mutex<Vec<(String, Vec<String>)>>,
+        inspect_pid: Option<&'static str>,
+        missing_image: bool,
+    }
+
+    impl FakeCli {
+        fn calls(&self) -> Vec<(String, Vec<String>)> {
+            self.calls.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl ContainerCli for FakeCli {
+        async fn run(&self, program: &str, args: &[String]) -> Result<SshOutput> {
+            self.calls.lock().unwrap().push((program.to_owned(), args.to_vec()));
+            let ok = |stdout: &str| SshOutput {
+                status: 0,
+                stdout: stdout.to_owned(),
+                stderr: String::new(),
+            };
+            let script = args.last().map(String::as_str).unwrap_or_default();
+            Ok(match args.first().map(String::as_str) {
+                Some("inspect") => ok(self.inspect_pid.unwrap_or("0")),
+                Some("image") if self.missing_image => SshOutput {
+                    status: 125,
+                    stdout: String::new(),
+                    stderr: "no such image".to_owned(),
+                },
+                _ if script == "'uname' '-s'" => ok("Linux\n"),
+                _ if script == "'uname' '-m'" => ok("aarch64\n"),
+                _ if script == "'command' '-v' 'claude'" => ok("/usr/local/bin/claude\n"),
+                _ if script.starts_with("'command'") || script.starts_with("'gh'") => SshOutput {
+                    status: 1,
+                    stdout: String::new(),
+                    stderr: String::new(),
+                },
+                _ => ok(""),
+            })
+        }
+
+        async fn spawn_detached(&self, program: &str, args: &[String]) -> Result<u32> {
+            self.calls.lock().unwrap().push((program.to_owned(), args.to_vec()));
+            Ok(4242)
+        }
+    }
+
+    fn launch(network: ContainerNetwork, limits: ContainerLimits) -> ContainerLaunch {
+        ContainerLaunch::builder()
+            .run_id("exec_1")
+            .workspace(PathBuf::from("/cube/ws-1"))
+            .home(PathBuf::from("/state/containers/homes/sandbox"))
+            .run_dir(PathBuf::from("/state/containers/runs/exec_1"))
+            .boss_event_binary(PathBuf::from("/opt/boss/bin/boss-event"))
+            .network(network)
+            .limits(limits)
+            .env(vec![("BOSS_RUN_ID".to_owned(), "exec_1".to_owned())])
+            .script("run-worker")
+            .build()
+    }
+
+    #[test]
+    fn podman_argv_mounts_only_the_worker_surface_with_policy_and_limits() {
+        let limits = ContainerLimits {
+            cpus: Some(1.5),
+            memory_bytes: Some(512 << 20),
+            pids: Some(256),
+        };
+        let args = container_run_args(
+            ContainerRuntime::Podman,
+            Some("boss-worker:latest"),
+            &launch(ContainerNetwork::None, limits),
+        )
+        .unwrap();
+        let has = |needle: &str| args.iter().any(|a| a == needle);
+        assert_eq!(&args[..3], ["run", "--detach", "--rm"]);
+        assert!(has("boss-exec_1"));
+        assert!(has("--userns=keep-id"));
+        assert!(has("--cap-drop=ALL"));
+        assert!(has("--network=none"));
+        assert!(has("--cpus=1.5"));
+        assert!(has("--memory=536870912b"));
+        assert!(has("--pids-limit=256"));
+        assert!(has("--volume=/cube/ws-1:/cube/ws-1:rw"));
+        assert!(has(
+            "--volume=/state/containers/homes/sandbox:/state/containers/homes/sandbox:rw"
+        ));
+        assert!(has(
+            "--volume=/state/containers/runs/exec_1:/state/containers/runs/exec_1:ro"
+        ));
+        assert!(has("--volume=/opt/boss/bin/boss-event:/opt/boss/bin/boss-event:ro"));
+        assert!(has("--env=HOME=/state/containers/homes/sandbox"));
+        assert!(has("--env=BOSS_RUN_ID=exec_1"));
+        assert_eq!(
+            &args[args.len() - 4..],
+            ["boss-worker:latest", "sh", "-c", "run-worker"]
+        );
+        // Nothing else from the host is mounted.
+        assert_eq!(args.iter().filter(|a| a.starts_with("--volume=")).count(), 4);
+    }
+
+    #[test]
+    fn docker_maps_private_network_to_bridge_and_runs_as_the_operator() {
+        let args = container_run_args(
+            ContainerRuntime::Docker,
+            Some("img"),
+            &launch(ContainerNetwork::Private, ContainerLimits::default()),
+        )
+        .unwrap();
+        assert!(args.iter().any(|a| a == "--network=bridge"));
+        assert!(args.iter().any(|a| a.starts_with("--user=")));
+        assert!(
+            !args
+                .iter()
+                .any(|a| a.starts_with("--cpus") || a.starts_with("--pids-limit"))
+        );
+    }
+
+    #[test]
+    fn bubblewrap_argv_unshares_network_unless_host_policy() {
+        let none = container_run_args(
+            ContainerRuntime::Bubblewrap,
+            None,
+            &launch(ContainerNetwork::None, ContainerLimits::default()),
+        )
+        .unwrap();
+        assert!(none.iter().any(|a| a == "--unshare-all"));
+        assert!(!none.iter().any(|a| a == "--share-net"));
+        assert!(none.windows(3).any(|w| w == ["--bind", "/cube/ws-1", "/cube/ws-1"]));
+        assert!(none.windows(3).any(|w| w
+            == [
+                "--ro-bind",
+                "/state/containers/runs/exec_1",
+                "/state/containers/runs/exec_1"
+            ]));
+        assert_eq!(&none[none.len() - 3..], ["sh", "-c", "run-worker"]);
+
+        let host = container_run_args(
+            ContainerRuntime::Bubblewrap,
+            None,
+            &launch(ContainerNetwork::Host, ContainerLimits::default()),
+        )
+        .unwrap();
+        assert!(host.iter().any(|a| a == "--share-net"));
+    }
+
+    #[test]
+    fn unenforceable_policy_is_refused_rather_than_weakened() {
+        let err = container_run_args(
+            ContainerRuntime::Bubblewrap,
+            None,
+            &launch(ContainerNetwork::Private, ContainerLimits::default()),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("cannot enforce network policy"), "got: {err}");
+        let err = container_run_args(
+            ContainerRuntime::Bubblewrap,
+            None,
+            &launch(
+                ContainerNetwork::None,
+                ContainerLimits {
+                    pids: Some(10),
+                    ..Default::default()
+                },
+            ),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("resource limits"), "got: {err}");
+    }
+
+    #[test]
+    fn network_policy_from_requirements_picks_most_restrictive() {
+        let required: BTreeSet<String> = ["container-network=host", "container-network=none", "os=linux"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        assert_eq!(
+            ContainerNetwork::from_requirements(&required),
+            Some(ContainerNetwork::None)
+        );
+        assert_eq!(ContainerNetwork::from_requirements(&BTreeSet::new()), None);
+    }
+
+    #[test]
+    fn runtime_capabilities_advertise_what_it_can_enforce() {
+        let podman = ContainerRuntime::Podman.capabilities();
+        assert!(podman.contains(&"isolation=container".to_owned()));
+        assert!(podman.contains(&"container-runtime=podman".to_owned()));
+        assert!(podman.contains(&"container-network=private".to_owned()));
+        assert!(podman.contains(&"container-limits=true".to_owned()));
+        let bwrap = ContainerRuntime::Bubblewrap.capabilities();
+        assert!(bwrap.contains(&"container-network=none".to_owned()));
+        assert!(!bwrap.contains(&"container-network=private".to_owned()));
+        assert!(!bwrap.contains(&"container-limits=true".to_owned()));
+    }
+
+    #[test]
+    fn worker_script_logs_and_records_the_exit_status_line() {
+        let script = worker_script("export A='b'; ", "claude --settings x");
+        assert!(script.starts_with("mkdir -p .boss && exec >>.boss/worker.log 2>&1\n"));
+        assert!(script.contains("( export A='b'; claude --settings x )"));
+        assert!(script.contains(&format!("printf '{WORKER_EXIT_STATUS_PREFIX}%s\\n' \"$?\"")));
+    }
+
+    #[tokio::test]
+    async fn provision_probes_inside_the_image_and_adds_runtime_tags() {
+        let cli = FakeCli::default();
+        let outcome = provision_container_host("sandbox", ContainerRuntime::Podman, Some("img"), &cli).await;
+        let RemoteProvisionOutcome::Ok { capabilities } = outcome else {
+            panic!("expected Ok, got {outcome:?}");
+        };
+        assert!(capabilities.contains(&"os=linux".to_owned()));
+        assert!(capabilities.contains(&"arch=arm64".to_owned()));
+        assert!(capabilities.contains(&"driver=claude".to_owned()));
+        assert!(capabilities.contains(&"gh-authed=false".to_owned()));
+        assert!(capabilities.contains(&"isolation=container".to_owned()));
+        // Every probe ran through `podman run --rm img sh -c …`.
+        let calls = cli.calls();
+        assert_eq!(calls[0].1, ["--version"]);
+        assert!(
+            calls
+                .iter()
+                .skip(2)
+                .all(|(program, args)| program == "podman" && args[..3] == ["run", "--rm", "img"])
+        );
+    }
+
+    #[tokio::test]
+    async fn provision_fails_closed_on_a_missing_image() {
+        let cli = FakeCli {
+            missing_image: true,
+            ..Default::default()
+        };
+        let outcome = provision_container_host("sandbox", ContainerRuntime::Docker, Some("img"), &cli).await;
+        assert!(
+            matches!(
+                outcome,
+                RemoteProvisionOutcome::Failed {
+                    kind: "container_image_missing",
+                    ..
+                }
+            ),
+            "got {outcome:?}"
+        );
+    }
+
+    struct StubLocal;
+
+    crate::stub_host_adapter! { StubLocal {
+        fn host_id(&self) -> &str {
+            "local"
+        }
+    } }
+
+    fn adapter(runtime: ContainerRuntime, cli: Arc<FakeCli>, state_dir: &Path) -> ContainerHostAdapter {
+        let work = crate::config::WorkConfig::builder()
+            .cwd(state_dir.to_path_buf())
+            .db_path(state_dir.join("state.db"))
+            .build();
+        ContainerHostAdapter::builder()
+            .host_id("sandbox")
+            .runtime(runtime)
+            .maybe_image(runtime.needs_image().then(|| "img".to_owned()))
+            .local(Arc::new(StubLocal))
+            .cli(cli)
+            .work_db(Arc::new(WorkDb::open(PathBuf::from(":memory:")).unwrap()))
+            .cfg(Arc::new(crate::config::RuntimeConfig::from_parts(work, None)))
+            .non_opus_auto_mode(false)
+            .events_socket_path(state_dir.join("engine.sock"))
+            .state_dir(state_dir.join("containers"))
+            .build()
+    }
+
+    #[tokio::test]
+    async fn oci_launch_reads_the_worker_pid_back_from_inspect() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let cli = Arc::new(FakeCli {
+            inspect_pid: Some("31337\n"),
+            ..Default::default()
+        });
+        let adapter = adapter(ContainerRuntime::Podman, Arc::clone(&cli), dir.path());
+        let pid = adapter
+            .launch(&["run".to_owned()], "exec_1", "/cube/ws-1")
+            .await
+            .unwrap();
+        assert_eq!(pid, 31337);
+        assert_eq!(
+            cli.calls()[1].1,
+            ["inspect", "--format", "{{.State.Pid}}", "boss-exec_1"]
+        );
+    }
+
+    #[tokio::test]
+    async fn oci_launch_reports_a_container_that_died_on_startup() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let cli = Arc::new(FakeCli::default());
+        let adapter = adapter(ContainerRuntime::Docker, cli, dir.path());
+        let err = adapter
+            .launch(&["run".to_owned()], "exec_1", "/cube/ws-1")
+            .await
+            .unwrap_err();
+        let message = format!("{err:#}");
+        assert!(message.contains("not running after launch"), "got: {message}");
+        assert!(message.contains("/cube/ws-1/.boss/worker.log"), "got: {message}");
+    }
+
+    #[tokio::test]
+    async fn bubblewrap_launch_spawns_detached() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let cli = Arc::new(FakeCli::default());
+        let adapter = adapter(ContainerRuntime::Bubblewrap, Arc::clone(&cli), dir.path());
+        let pid = adapter
+            .launch(&["--unshare-all".to_owned()], "exec_1", "/cube/ws-1")
+            .await
+            .unwrap();
+        assert_eq!(pid, 4242);
+        assert_eq!(cli.calls(), [("bwrap".to_owned(), vec!["--unshare-all".to_owned()])]);
+    }
+
+    #[tokio::test]
+    async fn reattach_relinks_the_run_socket_to_the_new_engine_socket() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let adapter = adapter(ContainerRuntime::Podman, Arc::new(FakeCli::default()), dir.path());
+        let run_dir = adapter.run_dir("exec_1");
+        std::fs::create_dir_all(&run_dir).unwrap();
+        let old = dir.path().join("old.sock");
+        let new = dir.path().join("new.sock");
+        std::fs::write(&old, "old").unwrap();
+        std::fs::write(&new, "new").unwrap();
+        link_events_socket(&old, &run_dir).unwrap();
+
+        assert!(
+            adapter
+                .reattach_events_forward("exec_1", new.to_str().unwrap())
+                .await
+                .unwrap()
+        );
+        assert_eq!(std::fs::read_to_string(run_dir.join(RUN_EVENTS_SOCKET)).unwrap(), "new");
+        assert!(
+            adapter
+                .reattach_events_forward("exec_2", new.to_str().unwrap())
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn worker_log_tail_reads_the_local_log() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let adapter = adapter(ContainerRuntime::Podman, Arc::new(FakeCli::default()), dir.path());
+        let ws = dir.path().join("ws");
+        let workspace = ws.to_str().unwrap();
+        assert_eq!(
+            adapter.read_worker_log_tail(workspace, 8).await.unwrap().as_deref(),
+            Some("")
+        );
+        std::fs::create_dir_all(ws.join(".boss")).unwrap();
+        std::fs::write(ws.join(".boss/worker.log"), "0123456789").unwrap();
+        assert_eq!(
+            adapter.read_worker_log_tail(workspace, 4).await.unwrap().as_deref(),
+            Some("6789")
+        );
+    }
+}
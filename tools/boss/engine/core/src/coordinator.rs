@@ -23,7 +23,7 @@ use crate::dispatch_inflight::InflightDispatches;
 use crate::effort::PoolModelTier;
 use crate::host_adapter::{HostAdapter, HostAdapterProvider, LocalHostAdapter, LocalHostAdapterProvider};
 use crate::host_registry::Host;
-use crate::host_scheduling::{self, ChoreRequirements, HostSlot};
+use crate::host_scheduling::{self, ChoreRequirements, ContainerLimits, HostSlot};
 use crate::metrics::Registry;
 use crate::runner::{ExecutionRunner, RunOutcome, RunWaitState};
 use crate::spawn_flow::StartWorkerError;
@@ -2188,15 +2188,40 @@ pub struct ExecutionCoordinator {
     /// cap doesn't collide with the path under test.
     #[builder(default = AtomicUsize::new(MAX_CONCURRENT_INTERACTIVE_WORKERS))]
     max_concurrent_interactive_workers: AtomicUsize,
+    /// Last [`crate::spend_budget::evaluate`] result and when it was
+    /// taken. The budget gate in `drain_ready_queue` reads it instead of
+    /// re-summing `work_runs` on every kick; it is refreshed once it is
+    /// older than `SPEND_BUDGET_SNAPSHOT_TTL` and dropped outright by
+    /// [`Self::invalidate_spend_budgets`] when a budget is set or deleted.
+    #[builder(default)]
+    spend_budget_snapshot: std::sync::Mutex<Option<(std::time::Instant, Arc<crate::spend_budget::BudgetSnapshot>)>>,
+    /// Last read of the stored per-product dispatch calendars, compiled,
+    /// and when it was taken. Refreshed once it is older than
+    /// `DISPATCH_CALENDAR_SNAPSHOT_TTL` and dropped outright by
+    /// [`Self::invalidate_dispatch_calendars`] when a calendar is set or
+    /// cleared. Each check still evaluates against the current instant.
+    #[builder(skip)]
+    dispatch_calendar_snapshot: std::sync::Mutex<
+        Option<(
+            std::time::Instant,
+            Arc<dispatch_calendar_gate::DispatchCalendarSnapshot>,
+        )>,
+    >,
 }
 
 mod config;
 mod dispatch_admission;
+mod dispatch_calendar_gate;
 mod execution;
+mod fair_share;
+mod host_placement;
 mod run;
 mod scheduler;
+mod spend_budget_gate;
 
 pub use dispatch_admission::{PauseBypassOutcome, pause_bypass_decision};
+pub use host_placement::WorkStealOutcome;
+use host_placement::{HostPick, host_score_details};
 pub use run::PANE_SPAWN_FAILED_ATTENTION_KIND;
 pub use scheduler::ANSWER_AGENT_READY_AGE_ATTENTION_KIND;
 
@@ -2232,16 +2257,6 @@ fn augment_details_with_cube_cli_error(details: &mut serde_json::Value, err: &an
     obj.insert("cube_host".to_owned(), serde_json::json!(cube_err.host));
 }
 
-/// The owning project id for capability-requirement lookup, if any. A
-/// project is its own subject; a product has none.
-fn work_item_project_id(item: &WorkItem) -> Option<String> {
-    match item {
-        WorkItem::Project(p) => Some(p.id.clone()),
-        WorkItem::Task(t) | WorkItem::Chore(t) => t.project_id.clone(),
-        WorkItem::Product(_) => None,
-    }
-}
-
 /// Render a one-line, human-readable summary of why no host was eligible,
 /// for the no-eligible-host pre-start failure / attention item.
 ///
@@ -2265,6 +2280,9 @@ fn summarize_ineligibility(report: &[host_scheduling::Eligibility], required_dri
                 .filter_map(|r| match r {
                     R::Disabled => Some("disabled".to_owned()),
                     R::NoFreeSlots => Some("no free slots".to_owned()),
+                    R::LowDisk { free_bytes } => {
+                        Some(format!("low disk ({} free)", host_scheduling::format_gib(*free_bytes)))
+                    }
                     R::NotSelectedHost => Some("not the requested/pinned host".to_owned()),
                     R::DriverProbeNotRun => {
                         if disabled {
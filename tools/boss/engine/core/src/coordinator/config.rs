@@ -96,6 +96,8 @@ impl ExecutionCoordinator {
             live_worker_states: None,
             refused_workspaces: Mutex::new(HashMap::new()),
             max_concurrent_interactive_workers: AtomicUsize::new(MAX_CONCURRENT_INTERACTIVE_WORKERS),
+            spend_budget_snapshot: Default::default(),
+            dispatch_calendar_snapshot: Default::default(),
         }
     }
 
@@ -14,8 +14,9 @@ use std::collections::HashSet;
 
 use anyhow::bail;
 use boss_protocol::{
-    ADMISSION_BLOCKER_AUTOSTART_DISABLED, ADMISSION_BLOCKER_CHURN_GUARD_PARKED, ADMISSION_BLOCKER_INELIGIBLE_STATUS,
-    ADMISSION_BLOCKER_INTERACTIVE_CONCURRENCY_CAP, ADMISSION_BLOCKER_UNMET_DEPENDENCY, DispatchAdmission,
+    ADMISSION_BLOCKER_AUTOSTART_DISABLED, ADMISSION_BLOCKER_CHURN_GUARD_PARKED, ADMISSION_BLOCKER_DISPATCH_CALENDAR,
+    ADMISSION_BLOCKER_INELIGIBLE_STATUS, ADMISSION_BLOCKER_INTERACTIVE_CONCURRENCY_CAP,
+    ADMISSION_BLOCKER_SPEND_BUDGET_EXHAUSTED, ADMISSION_BLOCKER_UNMET_DEPENDENCY, DispatchAdmission,
     DispatchAdmissionBlocker, DispatchAdmissionEntryPoint, DispatchPauseSnapshot, RequestExecutionInput,
 };
 
@@ -134,8 +135,11 @@ impl ExecutionCoordinator {
     /// why not? Computes the current dispatch-pause snapshot plus every
     /// blocker in [`INFORMATIONAL_ONLY_BLOCKER_CODES`] and the genuinely
     /// enforced ones (ineligible status, unmet dependencies, the
-    /// interactive concurrency cap). No DB writes, no dispatch events —
-    /// this never mutates anything, unlike the request path it mirrors.
+    /// interactive concurrency cap, an exhausted spend budget). No
+    /// dispatch events and no writes to the work item — unlike the request
+    /// path it mirrors. The one exception is a stale spend-budget snapshot
+    /// refreshing here, which files or resolves the budget's own attention
+    /// items exactly as the next drain pass would.
     pub async fn evaluate_dispatch_admission(&self, work_item_id: &str) -> Result<DispatchAdmission> {
         let facts = self.work_db.dispatch_admission_facts(work_item_id)?;
         let mut blockers = Vec::new();
@@ -178,6 +182,27 @@ impl ExecutionCoordinator {
                 });
             }
         }
+        // Unlike the pause, a spend-budget hold is not operator-
+        // overridable: force only ever bypasses the pause.
+        if facts.ineligible_reason.is_none()
+            && let Some(reason) = self.spend_budget_hold_for(&facts.resolved_work_item_id)
+        {
+            blockers.push(DispatchAdmissionBlocker {
+                code: ADMISSION_BLOCKER_SPEND_BUDGET_EXHAUSTED.to_string(),
+                message: reason,
+            });
+        }
+        // Nor is a dispatch-calendar hold. Reviews (the rows the operator
+        // pause exempts) are held only by quiet hours, as in the drain.
+        if facts.ineligible_reason.is_none()
+            && let Some(hold) =
+                self.dispatch_calendar_hold_for(&facts.resolved_work_item_id, facts.exempt_from_operator_pause)
+        {
+            blockers.push(DispatchAdmissionBlocker {
+                code: ADMISSION_BLOCKER_DISPATCH_CALENDAR.to_string(),
+                message: hold.message,
+            });
+        }
         let mut pause = self.dispatch_pause_snapshot();
         if facts.exempt_from_operator_pause && pause.overridable {
             // `drain_ready_queue` only holds `paused && !is_review` — an
@@ -0,0 +1,179 @@
+//! The dispatch-time calendar gate: the cached, compiled per-product
+//! calendars that `drain_ready_queue`, the heartbeat and
+//! `evaluate_dispatch_admission` consult, plus the read-only views behind
+//! `bossctl dispatch calendar`. Part of the `coordinator` module split;
+//! the phase math itself is the pure [`crate::dispatch_calendar`].
+use boss_protocol::{DispatchCalendarState, ProductDispatchCalendar};
+
+use super::*;
+use crate::dispatch_calendar::CompiledCalendar;
+use crate::work::DispatchCalendarSetting;
+
+/// How long one read of the stored calendars is reused. Only the table
+/// read is cached — each check evaluates against the current instant, so
+/// a window still opens and closes on the minute.
+const DISPATCH_CALENDAR_SNAPSHOT_TTL: Duration = Duration::from_secs(30);
+
+/// One product's compiled calendar and the name its messages use.
+#[derive(Debug)]
+pub(super) struct ProductCalendar {
+    product_name: String,
+    calendar: CompiledCalendar,
+}
+
+/// Every stored calendar that compiles, keyed by product id.
+pub(super) type DispatchCalendarSnapshot = HashMap<String, ProductCalendar>;
+
+impl ExecutionCoordinator {
+    /// The calendar state that holds `work_item_id` right now, or `None`
+    /// when its product has no calendar or the calendar lets this row
+    /// start. Reviews are held only by quiet hours (see
+    /// [`boss_protocol::DispatchCalendarPhase::holds`]).
+    ///
+    /// Fails open, like the spend-budget gate: a calendar or product
+    /// lookup that errors logs a warning and lets dispatch proceed.
+    pub(super) fn dispatch_calendar_hold_for(
+        &self,
+        work_item_id: &str,
+        is_review: bool,
+    ) -> Option<DispatchCalendarState> {
+        self.dispatch_calendar_state_for(work_item_id)
+            .filter(|state| state.phase.holds(is_review))
+    }
+
+    /// The calendar state of `work_item_id`'s product right now, or
+    /// `None` when it has no calendar.
+    pub(super) fn dispatch_calendar_state_for(&self, work_item_id: &str) -> Option<DispatchCalendarState> {
+        let snapshot = self.dispatch_calendar_snapshot()?;
+        if snapshot.is_empty() {
+            return None;
+        }
+        let product_id = match self.work_db.dispatch_product_for_work_item(work_item_id) {
+            Ok(product_id) => product_id?,
+            Err(err) => {
+                tracing::warn!(
+                    work_item_id,
+                    ?err,
+                    "dispatch calendar gate: product lookup failed; not holding"
+                );
+                return None;
+            }
+        };
+        let entry = snapshot.get(&product_id)?;
+        Some(
+            entry
+                .calendar
+                .evaluate(&entry.product_name, boss_engine_utils::epoch_time::now_epoch_secs()),
+        )
+    }
+
+    /// Drop the cached calendars so the next drain re-reads them. Called
+    /// after a calendar is set or cleared, so widening a window releases
+    /// held work on the very next kick.
+    pub fn invalidate_dispatch_calendars(&self) {
+        *self
+            .dispatch_calendar_snapshot
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = None;
+    }
+
+    fn dispatch_calendar_snapshot(&self) -> Option<Arc<DispatchCalendarSnapshot>> {
+        let mut cached = self
+            .dispatch_calendar_snapshot
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        if let Some((taken_at, snapshot)) = cached.as_ref()
+            && taken_at.elapsed() < DISPATCH_CALENDAR_SNAPSHOT_TTL
+        {
+            return Some(Arc::clone(snapshot));
+        }
+        let settings = match self.work_db.dispatch_calendar_settings() {
+            Ok(settings) => settings,
+            Err(err) => {
+                tracing::warn!(
+                    ?err,
+                    "dispatch calendar gate: reading calendars failed; not holding dispatch"
+                );
+                return None;
+            }
+        };
+        let mut snapshot = DispatchCalendarSnapshot::new();
+        for (product_id, setting) in settings {
+            let calendar = match CompiledCalendar::compile(&setting) {
+                Ok(calendar) => calendar,
+                Err(err) => {
+                    tracing::warn!(
+                        product_id = %product_id,
+                        %err,
+                        "dispatch calendar gate: stored calendar does not parse; not holding its product"
+                    );
+                    continue;
+                }
+            };
+            let product_name = self
+                .work_db
+                .get_product(&product_id)
+                .ok()
+                .flatten()
+                .map_or_else(|| product_id.clone(), |product| product.name);
+            snapshot.insert(product_id, ProductCalendar { product_name, calendar });
+        }
+        let snapshot = Arc::new(snapshot);
+        *cached = Some((std::time::Instant::now(), Arc::clone(&snapshot)));
+        Some(snapshot)
+    }
+
+    /// Every product with a stored calendar, with its phase right now,
+    /// ordered by product name.
+    pub fn product_dispatch_calendars(&self) -> Result<Vec<ProductDispatchCalendar>> {
+        let mut settings = self.work_db.dispatch_calendar_settings()?;
+        let now = boss_engine_utils::epoch_time::now_epoch_secs();
+        self.work_db
+            .list_products()?
+            .into_iter()
+            .filter_map(|product| {
+                let setting = settings.remove(&product.id)?;
+                Some(calendar_for(&product.id, &product.name, setting, now))
+            })
+            .collect()
+    }
+
+    /// `product_id`'s calendar with its phase right now, or `None` when it
+    /// has none.
+    pub fn product_dispatch_calendar(&self, product_id: &str) -> Result<Option<ProductDispatchCalendar>> {
+        let product = self
+            .work_db
+            .get_product(product_id)?
+            .ok_or_else(|| anyhow!("product not found: {product_id}"))?;
+        let Some(setting) = self.work_db.dispatch_calendar_settings()?.remove(&product.id) else {
+            return Ok(None);
+        };
+        calendar_for(
+            &product.id,
+            &product.name,
+            setting,
+            boss_engine_utils::epoch_time::now_epoch_secs(),
+        )
+        .map(Some)
+    }
+}
+
+fn calendar_for(
+    product_id: &str,
+    product_name: &str,
+    setting: DispatchCalendarSetting,
+    now_epoch_s: i64,
+) -> Result<ProductDispatchCalendar> {
+    let state = CompiledCalendar::compile(&setting)
+        .map_err(|err| anyhow!("product {product_name}: stored dispatch calendar does not parse: {err}"))?
+        .evaluate(product_name, now_epoch_s);
+    Ok(ProductDispatchCalendar::builder()
+        .product_id(product_id)
+        .product_name(product_name)
+        .timezone(setting.timezone)
+        .windows(setting.windows)
+        .quiet_hours(setting.quiet_hours)
+        .drain_before_close_minutes(setting.drain_before_close_minutes)
+        .state(state)
+        .build())
+}
@@ -162,29 +162,30 @@ impl ExecutionCoordinator {
     /// `driver=<slug>` are candidates. There is no silent fall-back to a
     /// different driver or to `local`.
     ///
-    /// Returns the selected [`Host`] or an error describing why nothing was
-    /// eligible (consumed by the caller as a recoverable pre-start
-    /// failure).
+    /// Each candidate also carries its latest load sample, its recent mean
+    /// run duration, and the remaining provider quota of the required
+    /// driver there, which [`crate::host_scheduling::select_host`] folds
+    /// into the ranking score. Missing figures score neutral.
+    ///
+    /// Returns the selected [`Host`] with the per-host report, or an error
+    /// describing why nothing was eligible (consumed by the caller as a
+    /// recoverable pre-start failure).
     fn pick_host(
         &self,
         work_item: &WorkItem,
         pinned_host_id: Option<String>,
         requested_host_id: Option<String>,
         required_driver: Option<String>,
-    ) -> Result<Host> {
+    ) -> Result<HostPick> {
         // Capability requirements union over the chore + its product +
-        // its project. Empty today (no writer yet), which leaves every
-        // enabled host capability-eligible for non-driver tags.
-        let product_id = work_item.product_id().to_string();
-        let project_id = work_item_project_id(work_item);
-        let mut subject_ids: Vec<&str> = vec![work_item.primary_id(), product_id.as_str()];
-        if let Some(pid) = project_id.as_deref() {
-            subject_ids.push(pid);
-        }
+        // its project. Empty unless an operator recorded requirements
+        // (`bossctl hosts require`), which leaves every enabled host
+        // capability-eligible for non-driver tags.
         let mut required_capabilities = self
             .work_db
-            .required_capabilities_for_subject_ids(&subject_ids)
+            .required_capabilities_for_work_item(work_item)
             .context("host-selection: capability requirements")?;
+        let container_limits = ContainerLimits::take_from(&mut required_capabilities);
 
         // Resolved driver is a hard requirement, when the caller has one to
         // enforce — see [`Self::select_host_for_execution`] for how it is
@@ -198,6 +199,24 @@ impl ExecutionCoordinator {
             .work_db
             .active_runs_per_host()
             .context("host-selection: active runs per host")?;
+        let loads = self
+            .work_db
+            .fresh_host_loads(crate::host_load::LOAD_SAMPLE_MAX_AGE.as_secs() as i64)
+            .context("host-selection: host load samples")?;
+        let mean_run_secs = self
+            .work_db
+            .mean_run_secs_per_host(
+                boss_engine_utils::epoch_time::now_epoch_secs()
+                    - crate::host_load::RUN_DURATION_WINDOW.as_secs() as i64,
+            )
+            .context("host-selection: mean run duration per host")?;
+        let driver_quota = match required_driver.as_deref() {
+            Some(driver) => self
+                .work_db
+                .fresh_driver_quota_per_host(driver, crate::host_load::DRIVER_QUOTA_MAX_AGE.as_secs() as i64)
+                .context("host-selection: driver quota per host")?,
+            None => HashMap::new(),
+        };
 
         let slots: Vec<HostSlot> = hosts
             .iter()
@@ -221,6 +240,9 @@ impl ExecutionCoordinator {
                     // the first run pushes. Free-slots-first is the
                     // design's documented v1 fallback for the first run.
                     had_prior_run_on_branch: false,
+                    load: loads.get(&host.id).cloned(),
+                    mean_run_secs: mean_run_secs.get(&host.id).copied(),
+                    driver_quota_used_percent: driver_quota.get(&host.id).copied(),
                 })
             })
             .collect::<Result<_>>()?;
@@ -229,27 +251,30 @@ impl ExecutionCoordinator {
             required_capabilities,
             pinned_host_id,
             requested_host_id,
+            container_limits,
         };
         let (picked, report) = host_scheduling::select_host(&requirements, &slots);
         match picked {
             Some(host_id) => hosts
                 .into_iter()
                 .find(|h| h.id == host_id)
+                .map(|host| HostPick { host, report })
                 .ok_or_else(|| anyhow!("selected host '{host_id}' is missing from the registry")),
             None => Err(anyhow!(
-                "no eligible host for work item {}: {}",
+                "{} for work item {}: {}",
+                host_scheduling::HOST_PARKED_WAIT_REASON_PREFIX,
                 work_item.primary_id(),
                 summarize_ineligibility(&report, required_driver.as_deref()),
             )),
         }
     }
 
-    fn select_host_for_execution(
+    pub(super) fn select_host_for_execution(
         &self,
         execution: &WorkExecution,
         work_item: &WorkItem,
         worker_id: &str,
-    ) -> Result<Host> {
+    ) -> Result<HostPick> {
         let pinned = self
             .work_db
             .execution_pinned_host(&execution.id)
@@ -773,8 +798,8 @@ impl ExecutionCoordinator {
         // backs off and raises an attention item rather than hot-looping,
         // and a later kick retries once a host comes online / tags change.
         let had_requested_host = self.requested_host_ids.lock().unwrap().contains_key(&execution.id);
-        let selected_host = match self.select_host_for_execution(execution, &work_item, worker_id) {
-            Ok(host) => host,
+        let (selected_host, host_report) = match self.select_host_for_execution(execution, &work_item, worker_id) {
+            Ok(pick) => (pick.host, pick.report),
             Err(err) => {
                 if had_requested_host {
                     match self.work_db.cancel_execution_with(
@@ -821,6 +846,19 @@ impl ExecutionCoordinator {
                 if had_requested_host {
                     return Err(err);
                 }
+                // Mark the backoff as host-parked so the host-load sweep
+                // can re-place it as soon as a host frees up rather than
+                // when the backoff expires (see `crate::host_load`).
+                if let Err(reason_err) = self
+                    .work_db
+                    .set_dispatch_wait_reason(&execution.id, &format!("{err:#}"))
+                {
+                    tracing::warn!(
+                        execution_id = %execution.id,
+                        error = %format!("{reason_err:#}"),
+                        "host-selection: failed to record no-eligible-host wait reason",
+                    );
+                }
                 self.record_start_failure(
                     Arc::clone(self),
                     execution,
@@ -873,7 +911,10 @@ impl ExecutionCoordinator {
                 DispatchEvent::new(Stage::HostSelected, DispatchOutcome::Ok, &execution.id)
                     .with_work_item(&execution.work_item_id)
                     .with_worker(worker_id)
-                    .with_details(serde_json::json!({ "host_id": selected_host.id.clone() })),
+                    .with_details(serde_json::json!({
+                        "host_id": selected_host.id.clone(),
+                        "scores": host_score_details(&host_report),
+                    })),
             )
             .await;
         tracing::info!(
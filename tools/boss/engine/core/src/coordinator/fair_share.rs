@@ -0,0 +1,265 @@
+//! Fair-share dispatch across products: the per-pass ordering and
+//! concurrency-ceiling state `drain_ready_queue` walks, plus the
+//! read-only views behind `bossctl dispatch share` and `bossctl dispatch
+//! explain`. Part of the `coordinator` module split; the ordering policy
+//! itself is the pure [`crate::dispatch_fair_share`].
+use boss_protocol::{DispatchExplanation, ProductDispatchShare};
+
+use super::*;
+use crate::dispatch_fair_share::{QueueEntry, ceiling_reached, effective_priority, fair_share_order};
+use crate::work::{DispatchClass, DispatchShareSetting, ReadyExecutionShareKey};
+
+/// One drain pass's view of every product's load against its ceiling.
+/// Built once per pass by [`ExecutionCoordinator::fair_share_pass`] and
+/// updated as rows are admitted, so a ceiling holds mid-pass rather than
+/// only from the next pass on.
+#[derive(Debug, Default)]
+pub(super) struct FairSharePass {
+    settings: HashMap<String, DispatchShareSetting>,
+    product_of: HashMap<String, String>,
+    load: HashMap<String, u32>,
+}
+
+/// A row held because its product is at its concurrency ceiling.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct CeilingHold {
+    pub product_id: String,
+    pub load: u32,
+    pub max: u32,
+}
+
+impl FairSharePass {
+    /// Count `execution_id` against its product, or refuse it when the
+    /// product is already at its ceiling. A row admitted here may still
+    /// be held by a later gate in the same pass; it keeps counting until
+    /// the pass ends, which can only make the ceiling hold early, never
+    /// let a product past it.
+    pub(super) fn admit(&mut self, execution_id: &str) -> Result<(), CeilingHold> {
+        let Some(product_id) = self.product_of.get(execution_id) else {
+            return Ok(());
+        };
+        let setting = self.settings.get(product_id).copied().unwrap_or_default();
+        let load = self.load.entry(product_id.clone()).or_default();
+        if ceiling_reached(setting, *load) {
+            return Err(CeilingHold {
+                product_id: product_id.clone(),
+                load: *load,
+                max: setting.max_concurrent.unwrap_or_default(),
+            });
+        }
+        *load += 1;
+        Ok(())
+    }
+}
+
+/// Everything the ordering reads, fetched in one place so the drain pass
+/// and `explain_dispatch` can never order the queue differently.
+struct FairShareInputs {
+    settings: HashMap<String, DispatchShareSetting>,
+    keys: HashMap<String, ReadyExecutionShareKey>,
+    live: HashMap<String, u32>,
+}
+
+impl ExecutionCoordinator {
+    fn fair_share_inputs(&self) -> Result<FairShareInputs> {
+        Ok(FairShareInputs {
+            settings: self.work_db.dispatch_share_settings()?,
+            keys: self.work_db.ready_execution_share_keys()?,
+            live: self.work_db.live_execution_counts_by_product()?,
+        })
+    }
+
+    /// Reorder this pass's candidate rows into fair-share order (see
+    /// [`crate::dispatch_fair_share`]) and build the ceiling state the
+    /// drain loop admits rows against. `inflight` are `ready` rows a
+    /// previous pass already handed off: not candidates, but workers for
+    /// their product all the same.
+    ///
+    /// Fails open: if the inputs cannot be read the rows keep their
+    /// ready-queue order and no ceiling applies this pass.
+    pub(super) fn fair_share_pass(
+        &self,
+        executions: Vec<WorkExecution>,
+        inflight: &[WorkExecution],
+    ) -> (Vec<WorkExecution>, FairSharePass) {
+        let inputs = match self.fair_share_inputs() {
+            Ok(inputs) => inputs,
+            Err(err) => {
+                tracing::warn!(
+                    ?err,
+                    "fair-share: reading dispatch shares failed; keeping ready-queue order"
+                );
+                return (executions, FairSharePass::default());
+            }
+        };
+        let mut load = inputs.live.clone();
+        for execution in inflight {
+            if let Some(product_id) = inputs.keys.get(&execution.id).and_then(|key| key.product_id.as_ref()) {
+                *load.entry(product_id.clone()).or_default() += 1;
+            }
+        }
+        let ordered = order_executions(executions, &inputs, &load);
+        let product_of = inputs
+            .keys
+            .into_iter()
+            .filter_map(|(execution_id, key)| key.product_id.map(|product_id| (execution_id, product_id)))
+            .collect();
+        (
+            ordered,
+            FairSharePass {
+                settings: inputs.settings,
+                product_of,
+                load,
+            },
+        )
+    }
+
+    /// Operator-facing wait reason for a [`CeilingHold`].
+    pub(super) fn ceiling_hold_reason(&self, hold: &CeilingHold) -> String {
+        let name = self
+            .work_db
+            .get_product(&hold.product_id)
+            .ok()
+            .flatten()
+            .map_or_else(|| hold.product_id.clone(), |product| product.name);
+        format!(
+            "Held by the {name} concurrency ceiling ({}/{} workers live) — dispatches as its workers finish",
+            hold.load, hold.max
+        )
+    }
+
+    /// Every product with a stored share or live/ready work, with its
+    /// settings and current counts, ordered by name.
+    pub fn product_dispatch_shares(&self) -> Result<Vec<ProductDispatchShare>> {
+        let inputs = self.fair_share_inputs()?;
+        let ready = ready_counts(&inputs.keys);
+        Ok(self
+            .work_db
+            .list_products()?
+            .into_iter()
+            .filter(|product| {
+                inputs.settings.contains_key(&product.id)
+                    || inputs.live.contains_key(&product.id)
+                    || ready.contains_key(&product.id)
+            })
+            .map(|product| share_for(&product.id, &product.name, &inputs, &ready))
+            .collect())
+    }
+
+    /// One product's share, whether or not it has anything stored.
+    pub fn product_dispatch_share(&self, product_id: &str) -> Result<ProductDispatchShare> {
+        let product = self
+            .work_db
+            .get_product(product_id)?
+            .ok_or_else(|| anyhow!("product not found: {product_id}"))?;
+        let inputs = self.fair_share_inputs()?;
+        let ready = ready_counts(&inputs.keys);
+        Ok(share_for(&product.id, &product.name, &inputs, &ready))
+    }
+
+    /// Read-only: why `work_item_id` is or is not dispatching. Combines
+    /// [`Self::evaluate_dispatch_admission`] with the execution's recorded
+    /// wait reason, where it sits in the order the next drain pass would
+    /// walk, and its product's dispatch calendar.
+    pub async fn explain_dispatch(&self, work_item_id: &str) -> Result<DispatchExplanation> {
+        let admission = self.evaluate_dispatch_admission(work_item_id).await?;
+        let resolved_id = admission.work_item_id.clone();
+        let execution = self.work_db.latest_execution_for_work_item(&resolved_id)?;
+        let inputs = self.fair_share_inputs()?;
+        let product_share = match self.work_db.spend_budget_scopes_for_work_item(&resolved_id)? {
+            Some((product_id, _)) => Some(self.product_dispatch_share(&product_id)?),
+            None => None,
+        };
+        let now = boss_engine_utils::epoch_time::now_epoch_secs();
+
+        let ready_rows = self.work_db.list_ready_executions()?;
+        let queue_len = u32::try_from(ready_rows.len()).unwrap_or(u32::MAX);
+        let ordered = order_executions(ready_rows, &inputs, &inputs.live);
+        let queue_position = execution.as_ref().and_then(|execution| {
+            ordered
+                .iter()
+                .position(|row| row.id == execution.id)
+                .and_then(|index| u32::try_from(index + 1).ok())
+        });
+        let dispatch_class = execution.as_ref().map(|execution| {
+            inputs
+                .keys
+                .get(&execution.id)
+                .map(|key| DispatchClass::from_ordinal(key.class_ordinal))
+                .or_else(|| self.work_db.classify_work_item_for_dispatch(&resolved_id).ok())
+                .unwrap_or(DispatchClass::OtherWork)
+                .label()
+                .to_owned()
+        });
+        Ok(DispatchExplanation::builder()
+            .admission(admission)
+            .maybe_execution_id(execution.as_ref().map(|e| e.id.clone()))
+            .maybe_execution_status(execution.as_ref().map(|e| e.status.as_str().to_owned()))
+            .maybe_wait_reason(execution.as_ref().and_then(|e| e.dispatch_wait_reason.clone()))
+            .maybe_wait_since(execution.as_ref().and_then(|e| e.dispatch_wait_since.clone()))
+            .maybe_dispatch_class(dispatch_class)
+            .maybe_base_priority(execution.as_ref().map(|e| e.priority))
+            .maybe_effective_priority(
+                execution
+                    .as_ref()
+                    .map(|e| effective_priority(e.priority, e.created_epoch(), now)),
+            )
+            .maybe_queue_position(queue_position)
+            .queue_len(queue_len)
+            .maybe_product_share(product_share)
+            .maybe_calendar(self.dispatch_calendar_state_for(&resolved_id))
+            .build())
+    }
+}
+
+fn order_executions(
+    executions: Vec<WorkExecution>,
+    inputs: &FairShareInputs,
+    load: &HashMap<String, u32>,
+) -> Vec<WorkExecution> {
+    let entries: Vec<QueueEntry<'_>> = executions
+        .iter()
+        .map(|execution| {
+            let key = inputs.keys.get(&execution.id);
+            QueueEntry {
+                class_ordinal: key.map_or(DispatchClass::OtherWork.as_ordinal(), |key| key.class_ordinal),
+                product_id: key.and_then(|key| key.product_id.as_deref()),
+                priority: execution.priority,
+                created_epoch_s: execution.created_epoch(),
+            }
+        })
+        .collect();
+    let order = fair_share_order(
+        &entries,
+        load,
+        &inputs.settings,
+        boss_engine_utils::epoch_time::now_epoch_secs(),
+    );
+    let mut slots: Vec<Option<WorkExecution>> = executions.into_iter().map(Some).collect();
+    order.into_iter().filter_map(|index| slots[index].take()).collect()
+}
+
+fn ready_counts(keys: &HashMap<String, ReadyExecutionShareKey>) -> HashMap<String, u32> {
+    let mut counts: HashMap<String, u32> = HashMap::new();
+    for product_id in keys.values().filter_map(|key| key.product_id.as_ref()) {
+        *counts.entry(product_id.clone()).or_default() += 1;
+    }
+    counts
+}
+
+fn share_for(
+    product_id: &str,
+    product_name: &str,
+    inputs: &FairShareInputs,
+    ready: &HashMap<String, u32>,
+) -> ProductDispatchShare {
+    let setting = inputs.settings.get(product_id).copied().unwrap_or_default();
+    ProductDispatchShare::builder()
+        .product_id(product_id)
+        .product_name(product_name)
+        .weight(setting.weight)
+        .maybe_max_concurrent(setting.max_concurrent)
+        .live_workers(inputs.live.get(product_id).copied().unwrap_or(0))
+        .ready_count(ready.get(product_id).copied().unwrap_or(0))
+        .build()
+}
@@ -0,0 +1,111 @@
+//! Host-placement results and the work-stealing pass built on them.
+//!
+//! Placement happens at dispatch time, so the only "queued but
+//! unstarted" work that can be moved to a better host is work that found
+//! no host at all: a `ready` execution backing off after a
+//! `no_eligible_host` pre-start failure. [`ExecutionCoordinator::steal_parked_executions`]
+//! dry-runs placement for each one and, when a host would now take it,
+//! ends the backoff so the next scheduler pass dispatches it.
+
+use super::*;
+use crate::host_scheduling::Eligibility;
+
+/// A successful [`ExecutionCoordinator::pick_host`]: the chosen host plus
+/// the per-host report (eligibility and score) it was chosen from.
+pub(super) struct HostPick {
+    pub host: Host,
+    pub report: Vec<Eligibility>,
+}
+
+/// Per-host scores for a `host_selected` dispatch event, so `bossctl
+/// dispatch tail` shows why the chosen host beat the others.
+pub(super) fn host_score_details(report: &[Eligibility]) -> serde_json::Value {
+    report
+        .iter()
+        .map(|h| {
+            (
+                h.host_id.clone(),
+                serde_json::json!({
+                    "eligible": h.eligible,
+                    "score": h.score.summary(),
+                }),
+            )
+        })
+        .collect::<serde_json::Map<_, _>>()
+        .into()
+}
+
+/// Counts from one [`ExecutionCoordinator::steal_parked_executions`] pass.
+#[derive(Debug, Default)]
+pub struct WorkStealOutcome {
+    /// Host-parked executions examined.
+    pub parked: usize,
+    /// Executions released early because a host would now take them.
+    pub released: usize,
+}
+
+impl ExecutionCoordinator {
+    /// Re-place executions parked by a `no_eligible_host` backoff. For each
+    /// one a dry-run [`Self::select_host_for_execution`] decides whether a
+    /// host would take it now; if so its backoff ends, a
+    /// [`Stage::HostWorkSteal`] event records the host the dry run picked,
+    /// and the scheduler is kicked. Placement itself is not reserved —
+    /// dispatch re-runs selection and may pick differently.
+    ///
+    /// Each release is checked against a host's free slots only through
+    /// that dry run, so several parked executions may be released for one
+    /// free slot; the losers simply fail selection again and re-park.
+    pub async fn steal_parked_executions(self: &Arc<Self>) -> WorkStealOutcome {
+        let mut outcome = WorkStealOutcome::default();
+        let parked = match self.work_db.list_host_parked_executions() {
+            Ok(rows) => rows,
+            Err(err) => {
+                tracing::warn!(error = %format!("{err:#}"), "work steal: failed to list host-parked executions");
+                return outcome;
+            }
+        };
+        outcome.parked = parked.len();
+        for execution in &parked {
+            let pick = self
+                .resolve_execution_work_item(execution)
+                .and_then(|work_item| self.select_host_for_execution(execution, &work_item, ""));
+            let pick = match pick {
+                Ok(pick) => pick,
+                // Still nowhere to go; the backoff stands.
+                Err(_) => continue,
+            };
+            match self.work_db.release_host_parked_execution(&execution.id) {
+                Ok(true) => {}
+                Ok(false) => continue,
+                Err(err) => {
+                    tracing::warn!(
+                        execution_id = %execution.id,
+                        error = %format!("{err:#}"),
+                        "work steal: failed to release host-parked execution",
+                    );
+                    continue;
+                }
+            }
+            outcome.released += 1;
+            let score = pick
+                .report
+                .iter()
+                .find(|h| h.host_id == pick.host.id)
+                .map(|h| h.score.summary());
+            self.dispatch_events
+                .emit(
+                    DispatchEvent::new(Stage::HostWorkSteal, DispatchOutcome::Ok, &execution.id)
+                        .with_work_item(&execution.work_item_id)
+                        .with_details(serde_json::json!({
+                            "host_id": pick.host.id,
+                            "score": score,
+                        })),
+                )
+                .await;
+        }
+        if outcome.released > 0 {
+            self.kick();
+        }
+        outcome
+    }
+}
@@ -238,6 +238,16 @@ impl ExecutionCoordinator {
                 ) {
                     return None;
                 }
+                // Likewise a row its product's dispatch calendar is holding
+                // (outside a window, draining, quiet hours): it is waiting
+                // on the clock, not on a wakeup the heartbeat could have
+                // missed.
+                if self
+                    .dispatch_calendar_hold_for(&exec.work_item_id, self.execution_targets_review_pool(exec))
+                    .is_some()
+                {
+                    return None;
+                }
                 Some((exec.id.clone(), age_ms))
             })
             .collect()
@@ -936,10 +946,16 @@ impl ExecutionCoordinator {
         // keeps `pool_ready_counts` honest — an in-flight row is not a
         // candidate this pass, so it must not inflate the "beaten N
         // candidates" the surviving rows report.
-        let executions: Vec<WorkExecution> = executions
+        let (inflight, executions): (Vec<WorkExecution>, Vec<WorkExecution>) = executions
             .into_iter()
-            .filter(|e| !self.inflight_dispatches.is_work_item_dispatching(&e.work_item_id))
-            .collect();
+            .partition(|e| self.inflight_dispatches.is_work_item_dispatching(&e.work_item_id));
+
+        // Fair-share order across products (see `crate::dispatch_fair_share`):
+        // class stays the primary key, then each product gets its weighted
+        // share of the rows walked first, and aged priority orders rows
+        // within a product. `fair_share` also carries the per-product
+        // ceilings the loop below admits rows against.
+        let (executions, mut fair_share) = self.fair_share_pass(executions, &inflight);
 
         if executions.is_empty() {
             return DrainOutcome::QueueEmpty;
@@ -997,9 +1013,9 @@ impl ExecutionCoordinator {
         // Per-pool candidate counts for this drain pass, computed up front
         // so the `request_recorded` event below can report "how many other
         // eligible rows this execution's dispatch class beat" without a
-        // second query per row. `executions` is already sorted by
-        // `(DispatchClass, priority, created_at, id)` (see
-        // `WorkDb::list_ready_executions`), so within a pool the row order
+        // second query per row. `executions` is already in fair-share order
+        // (dispatch class first — see `WorkDb::list_ready_executions` and
+        // `crate::dispatch_fair_share`), so within a pool the row order
         // below IS the priority order — a class=1 row that appears first is
         // winning against every other row counted for its pool here.
         let pool_ready_counts: HashMap<&'static str, usize> = {
@@ -1077,6 +1093,66 @@ impl ExecutionCoordinator {
                 continue;
             }
 
+            // Dispatch-calendar hold (`bossctl dispatch calendar`): outside
+            // the product's windows, while it drains before a close, or in
+            // its quiet hours, the row stays `ready` until the calendar
+            // opens — the 15 s heartbeat picks that up. Reviews are held
+            // only by quiet hours (see `DispatchCalendarPhase::holds`). Not
+            // bypassable: a forced dispatch overrides the operator pause,
+            // never a calendar.
+            if let Some(hold) = self.dispatch_calendar_hold_for(&execution.work_item_id, is_review) {
+                tracing::info!(
+                    execution_id = %execution.id,
+                    work_item_id = %execution.work_item_id,
+                    pool = pool_label,
+                    phase = hold.phase.as_str(),
+                    "spawn_attempt status=ready -> held reason=dispatch_calendar"
+                );
+                self.dispatch_events
+                    .emit(
+                        DispatchEvent::new(Stage::WorkerClaimed, DispatchOutcome::Skipped, &execution.id)
+                            .with_work_item(&execution.work_item_id)
+                            .with_details(serde_json::json!({
+                                "reason": "dispatch_calendar",
+                                "pool": pool_label,
+                                "execution_kind": execution.kind.as_str(),
+                                "phase": hold.phase.as_str(),
+                                "until_epoch_s": hold.until_epoch_s,
+                            })),
+                    )
+                    .await;
+                self.record_dispatch_wait_reason(&execution.id, &hold.message);
+                continue;
+            }
+
+            // Spend-budget hold: a product or project at or past a hard
+            // limit keeps its rows `ready` until the period rolls over or
+            // the budget is raised (see `crate::spend_budget`). Reviews are
+            // held too — a review spends tokens like any other worker.
+            // Checked before any pool claim so a held row never occupies a
+            // slot another scope could use.
+            if let Some(reason) = self.spend_budget_hold_for(&execution.work_item_id) {
+                tracing::info!(
+                    execution_id = %execution.id,
+                    work_item_id = %execution.work_item_id,
+                    pool = pool_label,
+                    "spawn_attempt status=ready -> held reason=spend_budget_exhausted"
+                );
+                self.dispatch_events
+                    .emit(
+                        DispatchEvent::new(Stage::WorkerClaimed, DispatchOutcome::Skipped, &execution.id)
+                            .with_work_item(&execution.work_item_id)
+                            .with_details(serde_json::json!({
+                                "reason": "spend_budget_exhausted",
+                                "pool": pool_label,
+                                "execution_kind": execution.kind.as_str(),
+                            })),
+                    )
+                    .await;
+                self.record_dispatch_wait_reason(&execution.id, &reason);
+                continue;
+            }
+
             // Skip executions for pools we already know are full.
             // They remain `ready` and will be retried on the next kick.
             //
@@ -1096,6 +1172,38 @@ impl ExecutionCoordinator {
                 continue;
             }
 
+            // Per-product concurrency ceiling (`bossctl dispatch share
+            // --max-concurrent`). Reviews are exempt, as they are from an
+            // operator pause: a review is the lifecycle of a change already
+            // in flight, and holding it would only stall that change.
+            if !is_review && let Err(hold) = fair_share.admit(&execution.id) {
+                tracing::info!(
+                    execution_id = %execution.id,
+                    work_item_id = %execution.work_item_id,
+                    pool = pool_label,
+                    product_id = %hold.product_id,
+                    live = hold.load,
+                    max = hold.max,
+                    "spawn_attempt status=ready -> held reason=product_concurrency_ceiling"
+                );
+                self.dispatch_events
+                    .emit(
+                        DispatchEvent::new(Stage::WorkerClaimed, DispatchOutcome::Skipped, &execution.id)
+                            .with_work_item(&execution.work_item_id)
+                            .with_details(serde_json::json!({
+                                "reason": "product_concurrency_ceiling",
+                                "pool": pool_label,
+                                "execution_kind": execution.kind.as_str(),
+                                "product_id": hold.product_id,
+                                "live_workers": hold.load,
+                                "cap": hold.max,
+                            })),
+                    )
+                    .await;
+                self.record_dispatch_wait_reason(&execution.id, &self.ceiling_hold_reason(&hold));
+                continue;
+            }
+
             // Interactive-pool concurrency cap (operator-settable — see
             // `bossctl dispatch concurrency`, default [`MAX_CONCURRENT_INTERACTIVE_WORKERS`]):
             // main-pool rows are held once the interactive pool's live
@@ -2012,6 +2120,12 @@ impl ExecutionCoordinator {
             )
             .await;
         crate::dispatch_metrics::record_dispatch_completed(&self.metrics);
+        if let Some(created_secs) = execution.created_epoch() {
+            crate::dispatch_metrics::record_dispatch_latency_secs(
+                &self.metrics,
+                boss_engine_utils::epoch_time::now_epoch_secs() - created_secs,
+            );
+        }
         // Clear any `dispatch_stage_stalled` attention item this execution
         // may have accumulated while it sat stuck pre-dispatch — it just
         // claimed a slot, so whatever was blocking it is resolved. Mirrors
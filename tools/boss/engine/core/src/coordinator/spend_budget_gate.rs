@@ -0,0 +1,68 @@
+//! The dispatch-time spend-budget gate: the cached
+//! [`crate::spend_budget::BudgetSnapshot`] that `drain_ready_queue` and
+//! `evaluate_dispatch_admission` consult before a worker is spawned. Part
+//! of the `coordinator` module split; see [`crate::spend_budget`] for how
+//! budgets are measured.
+use super::*;
+
+/// How long one budget evaluation is reused. A drain pass runs on every
+/// kick, and summing a period's `work_runs` per budget on each of them
+/// would be wasted work — spend only moves when a run records tokens, and
+/// a hold that lifts up to this long late is invisible next to a day.
+const SPEND_BUDGET_SNAPSHOT_TTL: Duration = Duration::from_secs(30);
+
+impl ExecutionCoordinator {
+    /// Why dispatch for `work_item_id` must wait on a spend budget, or
+    /// `None` when no hard limit covers its product or project. Work items
+    /// outside the task table (answer-agent comments) are never held.
+    ///
+    /// Fails open: a budget evaluation or scope lookup that errors logs a
+    /// warning and lets dispatch proceed, so a broken budget table can
+    /// never wedge the queue.
+    pub(super) fn spend_budget_hold_for(&self, work_item_id: &str) -> Option<String> {
+        let snapshot = self.spend_budget_snapshot()?;
+        if snapshot.statuses.is_empty() {
+            return None;
+        }
+        match self.work_db.spend_budget_scopes_for_work_item(work_item_id) {
+            Ok(Some((product_id, project_id))) => snapshot.hold_for(&product_id, project_id.as_deref()),
+            Ok(None) => None,
+            Err(err) => {
+                tracing::warn!(
+                    work_item_id,
+                    ?err,
+                    "spend budget gate: scope lookup failed; not holding"
+                );
+                None
+            }
+        }
+    }
+
+    /// Drop the cached budget evaluation so the next drain re-reads it.
+    /// Called after a budget is set or deleted, so raising a hard limit
+    /// releases held work on the very next kick.
+    pub fn invalidate_spend_budgets(&self) {
+        *self.spend_budget_snapshot.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+
+    fn spend_budget_snapshot(&self) -> Option<Arc<crate::spend_budget::BudgetSnapshot>> {
+        let mut cached = self.spend_budget_snapshot.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some((taken_at, snapshot)) = cached.as_ref()
+            && taken_at.elapsed() < SPEND_BUDGET_SNAPSHOT_TTL
+        {
+            return Some(Arc::clone(snapshot));
+        }
+        let now = boss_engine_utils::epoch_time::now_epoch_secs();
+        match crate::spend_budget::evaluate(&self.work_db, now) {
+            Ok(snapshot) => {
+                let snapshot = Arc::new(snapshot);
+                *cached = Some((std::time::Instant::now(), Arc::clone(&snapshot)));
+                Some(snapshot)
+            }
+            Err(err) => {
+                tracing::warn!(?err, "spend budget gate: evaluation failed; not holding dispatch");
+                None
+            }
+        }
+    }
+}
@@ -778,6 +778,61 @@ async fn pin_to_disabled_host_yields_no_eligible_host() {
     );
 }
 
+/// Work stealing: a `no_eligible_host` backoff is marked host-parked, and
+/// the steal pass ends it early once the pinned host comes back — but not
+/// while it is still down.
+#[tokio::test]
+async fn host_parked_execution_is_released_when_its_host_frees_up() {
+    let dir = tempdir().unwrap();
+    let db = Arc::new(WorkDb::open(dir.path().join("boss.db")).unwrap());
+    db.add_host("zakalwe", "user@zakalwe", 2, &[]).unwrap();
+    db.set_host_enabled("zakalwe", false).unwrap();
+
+    let product = create_test_product(&db);
+    let chore = create_test_chore(&db, product.id.clone(), "Parked on a disabled host");
+    db.reconcile_product_executions(&product.id).unwrap();
+    let execution = db.list_executions(Some(&chore.id)).unwrap().pop().unwrap();
+    db.set_execution_pinned_host(&execution.id, Some("zakalwe")).unwrap();
+
+    let cube = Arc::new(FakeCubeClient::default());
+    let runner = Arc::new(FakeExecutionRunner {
+        pending: true,
+        ..FakeExecutionRunner::default()
+    });
+    let coordinator = Arc::new(
+        ExecutionCoordinator::new(db.clone(), WorkerPool::new(1), cube.clone(), runner.clone())
+            .with_pre_start_retry_delays(vec![Duration::from_secs(600)]),
+    );
+    let worker_id = coordinator
+        .worker_pool()
+        .claim_worker(&execution.id, None)
+        .await
+        .expect("worker available");
+    let result = coordinator
+        .schedule_execution(&execution, &worker_id, DispatchAdmission::Queued)
+        .await;
+    assert!(result.is_err(), "no eligible host must fail the dispatch");
+
+    let parked = db.get_execution(&execution.id).unwrap();
+    assert!(parked.dispatch_not_before.is_some(), "the failure backs off");
+    assert!(
+        parked
+            .dispatch_wait_reason
+            .as_deref()
+            .is_some_and(|r| r.starts_with(crate::host_scheduling::HOST_PARKED_WAIT_REASON_PREFIX)),
+        "wait reason marks the backoff as host-parked: {:?}",
+        parked.dispatch_wait_reason,
+    );
+
+    let outcome = coordinator.steal_parked_executions().await;
+    assert_eq!((outcome.parked, outcome.released), (1, 0), "host still disabled");
+
+    db.set_host_enabled("zakalwe", true).unwrap();
+    let outcome = coordinator.steal_parked_executions().await;
+    assert_eq!((outcome.parked, outcome.released), (1, 1));
+    assert!(db.get_execution(&execution.id).unwrap().dispatch_not_before.is_none());
+}
+
 #[tokio::test]
 async fn host_selection_fails_closed_when_enabled_local_has_no_driver_capability() {
     let dir = tempdir().unwrap();
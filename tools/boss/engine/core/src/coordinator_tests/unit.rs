@@ -15,6 +15,7 @@ fn ineligible_host(id: &str, reasons: Vec<IneligibilityReason>) -> Eligibility {
         reasons,
         free_slots: 0,
         had_prior_run_on_branch: false,
+        score: Default::default(),
     }
 }
 
@@ -1,20 +1,33 @@
-//! Human-maintained USD-per-million-token pricing, used **only** for the
-//! optional `boss cost ... --usd` estimate on top of the token totals
-//! [`crate::cost_report`] computes.
+//! USD-per-million-token pricing, used for the optional `boss cost ...
+//! --usd` estimate on top of the token totals [`crate::cost_report`]
+//! computes, and for USD spend budgets ([`crate::spend_budget`]).
 //!
-//! This table is not billing truth. It is a best-effort estimate an
-//! operator edits directly in this file when list prices change — every
-//! figure derived from it is labelled "estimated" wherever it reaches a
-//! human (see `boss cost`'s CLI output). If a model string encountered in
-//! `work_runs.model` matches no entry here (an unknown model, or the
-//! literal `<synthetic>` string some rows carry with no attributable
-//! driver), its tokens are excluded from the estimate and the caller is
-//! told the estimate is partial rather than silently treating it as free.
+//! This table is not billing truth. It is a best-effort estimate: the
+//! built-in [`MODEL_PRICING`] entries are edited in this file when list
+//! prices change, and an operator can override or extend them without a
+//! rebuild by writing `<state_root>/pricing.toml` (see
+//! [`load_overrides`]), read once at engine startup. Every figure derived
+//! from either is labelled "estimated" wherever it reaches a human (see
+//! `boss cost`'s CLI output). If a model string encountered in
+//! `work_runs.model` matches no entry (an unknown model, or the literal
+//! `<synthetic>` string some rows carry with no attributable driver), its
+//! tokens are excluded from the estimate and the caller is told the
+//! estimate is partial rather than silently treating it as free.
 //!
 //! Matching is substring-based against the lowercased model string
 //! (`"claude-sonnet-5-20260101"` matches the `"sonnet"` entry) so a new
 //! dated model snapshot needs no code change as long as it keeps the
-//! family name.
+//! family name. Override entries are checked, in file order, before the
+//! built-in ones.
+
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+/// File name of the operator pricing override, under the state root.
+pub const PRICING_OVERRIDES_FILE: &str = "pricing.toml";
 
 /// USD list price per one million tokens, by token category.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -26,8 +39,8 @@ pub struct ModelPriceUsdPerMillion {
 }
 
 /// `(family substring, price)` pairs, checked in order against the
-/// lowercased model string. Update this table when list prices change —
-/// it is the single place a human needs to edit.
+/// lowercased model string. Update this table when list prices change;
+/// `pricing.toml` entries take precedence over it.
 pub const MODEL_PRICING: &[(&str, ModelPriceUsdPerMillion)] = &[
     (
         "opus",
@@ -58,15 +71,111 @@ pub const MODEL_PRICING: &[(&str, ModelPriceUsdPerMillion)] = &[
     ),
 ];
 
-/// The priced family whose substring matches `model`, if any. `None` for
-/// an unrecognised model (including the literal `<synthetic>` marker) —
-/// callers must treat that as "no price available", never as zero cost.
-pub fn price_for_model(model: &str) -> Option<ModelPriceUsdPerMillion> {
+/// Entries loaded from `pricing.toml`, checked before [`MODEL_PRICING`].
+static OVERRIDES: RwLock<Vec<(String, ModelPriceUsdPerMillion)>> = RwLock::new(Vec::new());
+
+/// One `[[model]]` table in `pricing.toml`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct OverrideEntry {
+    /// Family substring, matched like a [`MODEL_PRICING`] key.
+    #[serde(rename = "match")]
+    needle: String,
+    input: f64,
+    output: f64,
+    cache_write: f64,
+    cache_read: f64,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct OverridesFile {
+    #[serde(default)]
+    model: Vec<OverrideEntry>,
+}
+
+pub fn default_overrides_path(state_root: &Path) -> PathBuf {
+    state_root.join(PRICING_OVERRIDES_FILE)
+}
+
+/// Replace the override entries with the contents of `path`:
+///
+/// ```toml
+/// [[model]]
+/// match = "sonnet"
+/// input = 3.0
+/// output = 15.0
+/// cache_write = 3.75
+/// cache_read = 0.30
+/// ```
+///
+/// A missing file clears the overrides. A file that does not parse is an
+/// error and leaves the previous overrides in place. Returns how many
+/// entries were loaded.
+pub fn load_overrides(path: &Path) -> Result<usize> {
+    let entries = match std::fs::read_to_string(path) {
+        Ok(text) => parse_overrides(&text).with_context(|| format!("parsing {}", path.display()))?,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+        Err(err) => return Err(err).with_context(|| format!("reading {}", path.display())),
+    };
+    let count = entries.len();
+    *OVERRIDES.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = entries;
+    Ok(count)
+}
+
+fn parse_overrides(text: &str) -> Result<Vec<(String, ModelPriceUsdPerMillion)>> {
+    let file: OverridesFile = toml::from_str(text)?;
+    file.model
+        .into_iter()
+        .map(|entry| {
+            let needle = entry.needle.trim().to_ascii_lowercase();
+            anyhow::ensure!(!needle.is_empty(), "a [[model]] entry has an empty `match`");
+            let price = ModelPriceUsdPerMillion {
+                input: entry.input,
+                output: entry.output,
+                cache_write: entry.cache_write,
+                cache_read: entry.cache_read,
+            };
+            for (name, rate) in [
+                ("input", price.input),
+                ("output", price.output),
+                ("cache_write", price.cache_write),
+                ("cache_read", price.cache_read),
+            ] {
+                anyhow::ensure!(
+                    rate.is_finite() && rate >= 0.0,
+                    "[[model]] `{needle}`: {name} must be a non-negative number, got {rate}"
+                );
+            }
+            Ok((needle, price))
+        })
+        .collect()
+}
+
+fn lookup<'a>(
+    entries: impl IntoIterator<Item = (&'a str, ModelPriceUsdPerMillion)>,
+    model: &str,
+) -> Option<ModelPriceUsdPerMillion> {
     let lower = model.to_ascii_lowercase();
-    MODEL_PRICING
-        .iter()
+    entries
+        .into_iter()
         .find(|(needle, _)| lower.contains(needle))
-        .map(|(_, price)| *price)
+        .map(|(_, price)| price)
+}
+
+/// The priced family whose substring matches `model`, if any — the
+/// `pricing.toml` overrides first, then [`MODEL_PRICING`]. `None` for an
+/// unrecognised model (including the literal `<synthetic>` marker) —
+/// callers must treat that as "no price available", never as zero cost.
+pub fn price_for_model(model: &str) -> Option<ModelPriceUsdPerMillion> {
+    let overrides = OVERRIDES.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+    lookup(
+        overrides
+            .iter()
+            .map(|(needle, price)| (needle.as_str(), *price))
+            .chain(MODEL_PRICING.iter().map(|(needle, price)| (*needle, *price))),
+        model,
+    )
 }
 
 /// Estimated USD for one run's recorded token counts under `price`.
@@ -118,6 +227,53 @@ mod tests {
         assert!((usd - (3.0 + 15.0 + 3.75 + 0.30)).abs() < 1e-9, "usd={usd}");
     }
 
+    #[test]
+    fn override_entries_parse_and_take_precedence_over_the_builtin_table() {
+        let overrides = parse_overrides(
+            r#"
+            [[model]]
+            match = "Sonnet"
+            input = 1.0
+            output = 2.0
+            cache_write = 0.5
+            cache_read = 0.1
+
+            [[model]]
+            match = "gpt-5"
+            input = 1.25
+            output = 10.0
+            cache_write = 0.0
+            cache_read = 0.125
+            "#,
+        )
+        .unwrap();
+        assert_eq!(overrides.len(), 2);
+        let table = || {
+            overrides
+                .iter()
+                .map(|(needle, price)| (needle.as_str(), *price))
+                .chain(MODEL_PRICING.iter().map(|(needle, price)| (*needle, *price)))
+        };
+        assert_eq!(lookup(table(), "claude-sonnet-5").unwrap().input, 1.0);
+        assert_eq!(lookup(table(), "gpt-5-codex").unwrap().output, 10.0);
+        assert_eq!(lookup(table(), "claude-opus-5").unwrap().input, 15.0);
+    }
+
+    #[test]
+    fn override_file_rejects_typos_and_negative_rates() {
+        assert!(
+            parse_overrides("[[model]]\nmatch = \"opus\"\ninput = 1.0\noutput = 1.0\ncache_write = 1.0\n").is_err()
+        );
+        assert!(
+            parse_overrides(
+                "[[model]]\nmatch = \"opus\"\ninput = -1.0\noutput = 1.0\ncache_write = 1.0\ncache_read = 1.0\n"
+            )
+            .is_err()
+        );
+        assert!(parse_overrides("[[models]]\nmatch = \"opus\"\n").is_err());
+        assert!(parse_overrides("").unwrap().is_empty());
+    }
+
     #[test]
     fn zero_tokens_estimate_to_zero() {
         let price = price_for_model("sonnet").expect("sonnet priced");
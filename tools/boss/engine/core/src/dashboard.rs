@@ -0,0 +1,343 @@
+//! Engine side of the read-only web dashboard: the [`DashboardSource`] the
+//! loopback server in `boss_engine_attachments::dashboard` serves from.
+//!
+//! The snapshot is assembled from the same reads the app's own requests
+//! use — `get_work_tree` per product, the live-worker registry, attention
+//! groups, and the cost-report run rows — so the page cannot show a state
+//! the app would not. Board placement goes through
+//! [`BoardRow::position`], the engine's authority for which column a row
+//! renders in.
+//!
+//! Pushes come from `app::dashboard_feed`, which subscribes to the topic
+//! broker like any other session and forwards into the broadcast channel
+//! handed to [`EngineDashboardSource::new`].
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use boss_engine_attachments::dashboard::{
+    DashboardAttentionGroup, DashboardCard, DashboardColumn, DashboardExecution, DashboardProduct, DashboardPush,
+    DashboardSnapshot, DashboardSource,
+};
+use boss_engine_board_gesture::BoardRow;
+use boss_protocol::{BoardColumn, LiveWorkerState, Task, TaskStatus};
+use tokio::sync::broadcast;
+
+use crate::coordinator::ExecutionCoordinator;
+use crate::cost_pricing;
+use crate::live_worker_state::LiveWorkerStateRegistry;
+use crate::transcript_markdown::{RenderOpts, render_text};
+use crate::work::WorkDb;
+
+/// Board columns left to right, with the titles the app uses.
+const COLUMNS: [(BoardColumn, &str); 4] = [
+    (BoardColumn::Backlog, "Todo"),
+    (BoardColumn::Doing, "Doing"),
+    (BoardColumn::Review, "In review"),
+    (BoardColumn::Done, "Done"),
+];
+
+/// Done cards kept per product, newest first. The column only grows; the
+/// page is for what is happening now.
+const DONE_CARDS: usize = 20;
+
+/// How far back the executions table looks.
+const EXECUTION_WINDOW_S: i64 = 24 * 60 * 60;
+
+/// Rows in the executions table.
+const EXECUTION_ROWS: usize = 30;
+
+pub struct EngineDashboardSource {
+    work_db: Arc<WorkDb>,
+    live_worker_states: Arc<LiveWorkerStateRegistry>,
+    coordinator: Arc<ExecutionCoordinator>,
+    pushes: broadcast::Sender<DashboardPush>,
+}
+
+impl EngineDashboardSource {
+    pub fn new(
+        work_db: Arc<WorkDb>,
+        live_worker_states: Arc<LiveWorkerStateRegistry>,
+        coordinator: Arc<ExecutionCoordinator>,
+        pushes: broadcast::Sender<DashboardPush>,
+    ) -> Self {
+        Self {
+            work_db,
+            live_worker_states,
+            coordinator,
+            pushes,
+        }
+    }
+}
+
+impl DashboardSource for EngineDashboardSource {
+    fn snapshot(&self) -> DashboardSnapshot {
+        build_snapshot(
+            &self.work_db,
+            self.live_worker_states.snapshot(),
+            self.coordinator.is_dispatch_paused(),
+            boss_engine_utils::epoch_time::now_epoch_secs(),
+        )
+    }
+
+    fn transcript(&self, execution_id: &str) -> Option<String> {
+        let path = match self.work_db.transcript_path_for_execution(execution_id) {
+            Ok(path) => path?,
+            Err(err) => {
+                tracing::warn!(execution_id, ?err, "dashboard: transcript path lookup failed");
+                return None;
+            }
+        };
+        // Remote-host runs record a path on the worker host; those read as
+        // absent here rather than as an error.
+        let content = std::fs::read_to_string(&path).ok()?;
+        let events = crate::driver_transcript::parse_execution_transcript(&self.work_db, execution_id, &content);
+        Some(render_text(&events, &RenderOpts::default()))
+    }
+
+    fn subscribe(&self) -> broadcast::Receiver<DashboardPush> {
+        self.pushes.subscribe()
+    }
+}
+
+/// Assemble a [`DashboardSnapshot`]. A read that fails is logged and its
+/// section left empty: a partial page is more use than an error page.
+pub fn build_snapshot(
+    work_db: &WorkDb,
+    workers: Vec<LiveWorkerState>,
+    dispatch_paused: bool,
+    now_epoch_s: i64,
+) -> DashboardSnapshot {
+    let products = match work_db.list_products() {
+        Ok(products) => products,
+        Err(err) => {
+            tracing::warn!(?err, "dashboard: listing products failed");
+            Vec::new()
+        }
+    };
+
+    let mut snapshot = DashboardSnapshot {
+        generated_at_epoch_s: now_epoch_s,
+        dispatch_paused,
+        ..Default::default()
+    };
+    for product in products.into_iter().filter(|product| product.status != "archived") {
+        match work_db.get_work_tree(&product.id) {
+            Ok(tree) => {
+                let columns = board_columns(tree.tasks.into_iter().chain(tree.chores), &workers);
+                snapshot.merge_queue.extend(
+                    columns
+                        .iter()
+                        .flat_map(|column| &column.cards)
+                        .filter(|card| card.merge_queue_state.is_some())
+                        .cloned(),
+                );
+                snapshot.products.push(DashboardProduct {
+                    id: product.id.clone(),
+                    name: product.name.clone(),
+                    columns,
+                });
+            }
+            Err(err) => tracing::warn!(product_id = %product.id, ?err, "dashboard: work tree read failed"),
+        }
+
+        let groups = match work_db.list_attention_groups(&product.id, None, None, None, None) {
+            Ok(groups) => groups,
+            Err(err) => {
+                tracing::warn!(product_id = %product.id, ?err, "dashboard: attention read failed");
+                Vec::new()
+            }
+        };
+        for group in groups {
+            let members = work_db.list_attentions_for_group(&group.id).unwrap_or_default();
+            snapshot.attentions.push(DashboardAttentionGroup {
+                product_name: product.name.clone(),
+                group,
+                members,
+            });
+        }
+    }
+
+    snapshot.executions = recent_executions(work_db, now_epoch_s);
+    snapshot.workers = workers;
+    snapshot
+}
+
+/// Bucket board rows into [`COLUMNS`], leaving off what the app leaves off:
+/// deleted, archived and automation-produced rows.
+fn board_columns(rows: impl Iterator<Item = Task>, workers: &[LiveWorkerState]) -> Vec<DashboardColumn> {
+    let mut buckets: [Vec<Task>; 4] = Default::default();
+    for task in rows {
+        if task.deleted_at.is_some() || task.source_automation_id.is_some() || task.status == TaskStatus::Archived {
+            continue;
+        }
+        let position = BoardRow {
+            status: task.status.clone(),
+            autostart: task.autostart,
+            blocked_reason: task.blocked_reason.clone(),
+            merge_queue_state: task.merge_queue_state.clone(),
+        }
+        .position();
+        let index = COLUMNS
+            .iter()
+            .position(|(column, _)| *column == position.column)
+            .unwrap_or(0);
+        buckets[index].push(task);
+    }
+    buckets[3].sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+    buckets[3].truncate(DONE_CARDS);
+
+    COLUMNS
+        .iter()
+        .zip(buckets)
+        .map(|((_, title), tasks)| DashboardColumn {
+            title: (*title).to_owned(),
+            cards: tasks.into_iter().map(|task| card(task, workers)).collect(),
+        })
+        .collect()
+}
+
+fn card(task: Task, workers: &[LiveWorkerState]) -> DashboardCard {
+    let worker = workers
+        .iter()
+        .find(|state| state.work_item_id.as_deref() == Some(task.id.as_str()) && !state.activity.is_terminal())
+        .map(|state| state.name.clone());
+    DashboardCard {
+        label: match task.short_id {
+            Some(n) => format!("T{n} {}", task.name),
+            None => task.name.clone(),
+        },
+        status: task.status.as_str().to_owned(),
+        id: task.id,
+        blocked_reason: task.blocked_reason,
+        merge_queue_state: task.merge_queue_state,
+        pr_url: task.pr_url,
+        worker,
+    }
+}
+
+/// Executions with a run in the last [`EXECUTION_WINDOW_S`], newest first.
+/// Token totals fold only measured runs, per the cost report's
+/// NULL-is-unmeasured contract; an execution with no measured run shows
+/// `None`, not zero.
+fn recent_executions(work_db: &WorkDb, now_epoch_s: i64) -> Vec<DashboardExecution> {
+    let records = match work_db.cost_records_for_window(now_epoch_s - EXECUTION_WINDOW_S, now_epoch_s + 1) {
+        Ok(records) => records,
+        Err(err) => {
+            tracing::warn!(?err, "dashboard: cost records read failed");
+            return Vec::new();
+        }
+    };
+
+    let mut by_execution: HashMap<String, DashboardExecution> = HashMap::new();
+    for record in records {
+        let entry = by_execution
+            .entry(record.execution_id.clone())
+            .or_insert_with(|| DashboardExecution {
+                execution_id: record.execution_id.clone(),
+                work_item_label: match record.work_item_short_id {
+                    Some(n) => format!("T{n} {}", record.work_item_name),
+                    None => record.work_item_name.clone(),
+                },
+                kind: record.execution_kind.clone(),
+                status: record.execution_status.clone(),
+                model: None,
+                created_at_epoch_s: record.created_at_epoch_s,
+                total_tokens: None,
+                estimated_usd: None,
+            });
+        entry.created_at_epoch_s = entry.created_at_epoch_s.max(record.created_at_epoch_s);
+        if record.model.is_some() {
+            entry.model = record.model.clone();
+        }
+        let Some(input) = record.input_tokens else {
+            continue;
+        };
+        let output = record.output_tokens.unwrap_or(0);
+        let cache_creation = record.cache_creation_tokens.unwrap_or(0);
+        let cache_read = record.cache_read_tokens.unwrap_or(0);
+        *entry.total_tokens.get_or_insert(0) += input + output + cache_creation + cache_read;
+        if let Some(price) = record.model.as_deref().and_then(cost_pricing::price_for_model) {
+            *entry.estimated_usd.get_or_insert(0.0) +=
+                cost_pricing::estimate_usd(price, input, output, cache_creation, cache_read);
+        }
+    }
+
+    let mut executions: Vec<DashboardExecution> = by_execution.into_values().collect();
+    executions.sort_by(|a, b| {
+        b.created_at_epoch_s
+            .cmp(&a.created_at_epoch_s)
+            .then_with(|| a.execution_id.cmp(&b.execution_id))
+    });
+    executions.truncate(EXECUTION_ROWS);
+    executions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::*;
+    use boss_protocol::WorkItemBinding;
+
+    fn column<'a>(snapshot: &'a DashboardSnapshot, title: &str) -> &'a DashboardColumn {
+        snapshot.products[0]
+            .columns
+            .iter()
+            .find(|column| column.title == title)
+            .unwrap()
+    }
+
+    #[test]
+    fn snapshot_places_rows_by_board_position_and_names_their_worker() {
+        let (_dir, db) = open_db();
+        let product = create_test_product(&db);
+        let queued = create_test_chore(&db, &product.id, "Queued chore");
+        let running = create_active_chore(&db, &product.id, "Running chore");
+
+        let mut worker = LiveWorkerState::new_spawning(
+            1,
+            "run_1",
+            "claude-sonnet",
+            0,
+            Some(WorkItemBinding {
+                work_item_id: running.clone(),
+                work_item_name: "Running chore".to_owned(),
+                execution_id: "exec_1".to_owned(),
+            }),
+        );
+        worker.name = "ada".to_owned();
+
+        let snapshot = build_snapshot(&db, vec![worker], true, 1_700_000_000);
+        assert!(snapshot.dispatch_paused);
+        assert_eq!(snapshot.products.len(), 1);
+        assert_eq!(snapshot.workers.len(), 1);
+
+        // An autostart `todo` row renders in Doing, like the app's board.
+        let doing = column(&snapshot, "Doing");
+        let ids: Vec<&str> = doing.cards.iter().map(|card| card.id.as_str()).collect();
+        assert!(ids.contains(&queued.id.as_str()), "{ids:?}");
+        assert!(ids.contains(&running.as_str()), "{ids:?}");
+        let running_card = doing.cards.iter().find(|card| card.id == running).unwrap();
+        assert_eq!(running_card.worker.as_deref(), Some("ada"));
+        assert_eq!(running_card.status, "active");
+        assert!(snapshot.merge_queue.is_empty());
+        assert!(snapshot.executions.is_empty());
+    }
+
+    #[test]
+    fn archived_products_are_left_off() {
+        let (_dir, db) = open_db();
+        let product = create_test_product(&db);
+        db.update_work_item(
+            &product.id,
+            crate::work::WorkItemPatch {
+                status: Some("archived".to_owned()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let snapshot = build_snapshot(&db, Vec::new(), false, 1_700_000_000);
+        assert!(snapshot.products.is_empty());
+    }
+}
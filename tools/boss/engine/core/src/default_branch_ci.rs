@@ -0,0 +1,200 @@
+//! Default-branch CI watch: the producer behind `ci_red` automations.
+//!
+//! Nothing else in the engine looks at the default branch's CI — the merge
+//! poller and `ci_watch` only follow PRs Boss opened. This loop polls, every
+//! [`DEFAULT_BRANCH_CI_POLL_SECS`], only the products that have an enabled
+//! `ci_red` automation: resolve the product repo's default branch and head
+//! commit, and ask whether any required check on it is failing. A transition
+//! from green to red publishes one [`Event::DefaultBranchCiRed`] for
+//! [`crate::automation_events`] to act on.
+//!
+//! The last observed state lives in memory. The first observation of a
+//! product after boot is a baseline and never fires — a branch that was
+//! already red before the engine started is not news — and red → red (a
+//! fix-forward that is still failing) does not fire again either. A probe
+//! error leaves the previous state untouched.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use boss_event_bus::{Event, EventBus};
+
+use crate::work::WorkDb;
+
+/// Poll interval. Coarse on purpose: each product costs three `gh` calls
+/// per poll, and a red default branch is rarely minutes-urgent.
+pub const DEFAULT_BRANCH_CI_POLL_SECS: u64 = 5 * 60;
+
+/// One observation of a repo's default branch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DefaultBranchCiState {
+    pub head_sha: String,
+    /// Whether any required check on `head_sha` is failing.
+    pub red: bool,
+}
+
+/// Reads the CI state of a repo's default branch. Production uses
+/// [`GhDefaultBranchCi`]; tests script it.
+#[async_trait]
+pub trait DefaultBranchCiProbe: Send + Sync {
+    /// `None` when the repo is not a GitHub repo or any lookup failed.
+    async fn probe(&self, repo_remote_url: &str) -> Option<DefaultBranchCiState>;
+}
+
+/// `gh`-backed [`DefaultBranchCiProbe`].
+pub struct GhDefaultBranchCi;
+
+#[async_trait]
+impl DefaultBranchCiProbe for GhDefaultBranchCi {
+    async fn probe(&self, repo_remote_url: &str) -> Option<DefaultBranchCiState> {
+        let (owner, repo) = git_utils::repo_slug::parse_github_owner_repo(repo_remote_url).ok()?;
+        boss_gh_telemetry::scope(boss_gh_telemetry::callers::AUTOMATION_EVENTS, async {
+            let branch = boss_github::trees::fetch_default_branch(owner, repo).await.ok()?;
+            let head_sha = boss_github::trees::fetch_head_sha(owner, repo, &branch).await.ok()?;
+            let failing =
+                boss_github::check_runs::fetch_failing_checks_for_commit(&format!("{owner}/{repo}"), &head_sha).await;
+            Some(DefaultBranchCiState {
+                head_sha,
+                red: !failing.is_empty(),
+            })
+        })
+        .await
+    }
+}
+
+/// Spawn the poll loop. The first poll runs one interval after boot, not
+/// immediately, so a restart does not add three `gh` calls per product to
+/// the boot burst.
+pub fn spawn_loop(
+    work_db: Arc<WorkDb>,
+    event_bus: Arc<EventBus>,
+    probe: Arc<dyn DefaultBranchCiProbe>,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut last: HashMap<String, DefaultBranchCiState> = HashMap::new();
+        loop {
+            tokio::time::sleep(Duration::from_secs(DEFAULT_BRANCH_CI_POLL_SECS)).await;
+            for event in poll_once(work_db.as_ref(), probe.as_ref(), &mut last).await {
+                event_bus.publish(event);
+            }
+        }
+    })
+}
+
+/// One poll across every listening product. Returns the
+/// [`Event::DefaultBranchCiRed`] events to publish and updates `last` in
+/// place. Products that stopped listening are forgotten, so re-enabling a
+/// `ci_red` automation starts from a fresh baseline.
+pub async fn poll_once(
+    work_db: &WorkDb,
+    probe: &dyn DefaultBranchCiProbe,
+    last: &mut HashMap<String, DefaultBranchCiState>,
+) -> Vec<Event> {
+    let product_ids = match work_db.list_product_ids_with_enabled_automation_kind("ci_red") {
+        Ok(ids) => ids,
+        Err(err) => {
+            tracing::warn!(?err, "default-branch CI watch: failed to list products; skipping poll");
+            return Vec::new();
+        }
+    };
+    last.retain(|product_id, _| product_ids.contains(product_id));
+
+    let mut events = Vec::new();
+    for product_id in product_ids {
+        let repo = match work_db.get_product(&product_id) {
+            Ok(Some(product)) => product.repo_remote_url,
+            Ok(None) => None,
+            Err(err) => {
+                tracing::warn!(%product_id, ?err, "default-branch CI watch: failed to load product");
+                continue;
+            }
+        };
+        let Some(repo) = repo else {
+            continue;
+        };
+        let Some(observed) = probe.probe(&repo).await else {
+            tracing::debug!(%product_id, %repo, "default-branch CI watch: probe failed; keeping previous state");
+            continue;
+        };
+        let went_red = observed.red && last.get(&product_id).is_some_and(|previous| !previous.red);
+        if went_red {
+            tracing::info!(
+                %product_id,
+                head_sha = %observed.head_sha,
+                "default-branch CI watch: default branch went red",
+            );
+            events.push(Event::DefaultBranchCiRed {
+                product_id: product_id.clone(),
+                head_sha: observed.head_sha.clone(),
+            });
+        }
+        last.insert(product_id, observed);
+    }
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::test_support::*;
+    use boss_protocol::{AutomationTrigger, CreateAutomationInput};
+
+    /// Replays a scripted sequence of observations, one per probe call.
+    struct ScriptedProbe(Mutex<Vec<Option<DefaultBranchCiState>>>);
+
+    #[async_trait]
+    impl DefaultBranchCiProbe for ScriptedProbe {
+        async fn probe(&self, _repo_remote_url: &str) -> Option<DefaultBranchCiState> {
+            self.0.lock().unwrap().remove(0)
+        }
+    }
+
+    fn state(head_sha: &str, red: bool) -> Option<DefaultBranchCiState> {
+        Some(DefaultBranchCiState {
+            head_sha: head_sha.to_owned(),
+            red,
+        })
+    }
+
+    #[tokio::test]
+    async fn fires_once_on_a_green_to_red_transition() {
+        let (_d, db) = open_db();
+        let product = create_test_product_with_repo(&db, "p", Some("https://github.com/o/r"));
+        db.create_automation(
+            CreateAutomationInput::builder()
+                .product_id(product.id.clone())
+                .name("ci")
+                .trigger(AutomationTrigger::CiRed)
+                .standing_instruction("find the breakage")
+                .build(),
+        )
+        .unwrap();
+        // Baseline red (ignored), green, red, still red at a new sha, probe
+        // failure, green.
+        let probe = ScriptedProbe(Mutex::new(vec![
+            state("a", true),
+            state("b", false),
+            state("c", true),
+            state("d", true),
+            None,
+            state("e", false),
+        ]));
+        let mut last = HashMap::new();
+
+        let mut fired = Vec::new();
+        for _ in 0..6 {
+            fired.extend(poll_once(&db, &probe, &mut last).await);
+        }
+        assert_eq!(
+            fired,
+            vec![Event::DefaultBranchCiRed {
+                product_id: product.id.clone(),
+                head_sha: "c".to_owned(),
+            }]
+        );
+    }
+}
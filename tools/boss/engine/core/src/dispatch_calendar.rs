@@ -0,0 +1,204 @@
+//! Pure dispatch-calendar evaluation. No I/O — the coordinator compiles
+//! each stored [`DispatchCalendarSetting`] once per snapshot and asks
+//! [`CompiledCalendar::evaluate`] where "now" falls.
+//!
+//! # Phases
+//!
+//! Quiet hours win: inside them the phase is
+//! [`DispatchCalendarPhase::QuietHours`] whatever the windows say. Outside
+//! them, a calendar with windows is [`DispatchCalendarPhase::Closed`]
+//! unless one covers now. Otherwise the product is open until the
+//! earlier of its window closing and its next quiet hours beginning, and
+//! within `drain_before_close_minutes` of that cut-off it is
+//! [`DispatchCalendarPhase::Draining`] — open to work already running,
+//! closed to anything new.
+//!
+//! [`DispatchCalendarPhase::holds`] decides which rows a phase holds;
+//! this module only decides the phase.
+
+use boss_protocol::{DispatchCalendarPhase, DispatchCalendarState};
+use chrono::DateTime;
+
+use crate::automation_schedule::window::{WeeklyWindow, parse_weekly_window, window_position};
+use crate::automation_schedule::{ScheduleParseError, Tz, parse_timezone};
+use crate::work::DispatchCalendarSetting;
+
+/// A stored calendar with its timezone and windows parsed.
+#[derive(Debug, Clone)]
+pub(crate) struct CompiledCalendar {
+    tz: Tz,
+    windows: Vec<WeeklyWindow>,
+    quiet_hours: Vec<WeeklyWindow>,
+    drain_secs: i64,
+}
+
+impl CompiledCalendar {
+    /// Parse `setting`. Also the validation `SetProductDispatchCalendar`
+    /// runs before storing one, so a stored calendar always compiles.
+    pub(crate) fn compile(setting: &DispatchCalendarSetting) -> Result<Self, ScheduleParseError> {
+        let parse_all = |windows: &[boss_protocol::DispatchWindow]| {
+            windows
+                .iter()
+                .map(|window| parse_weekly_window(&window.days, &window.start, &window.end))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        Ok(Self {
+            tz: parse_timezone(&setting.timezone)?,
+            windows: parse_all(&setting.windows)?,
+            quiet_hours: parse_all(&setting.quiet_hours)?,
+            drain_secs: i64::from(setting.drain_before_close_minutes) * 60,
+        })
+    }
+
+    /// The calendar's phase at `now_epoch_s`, with an operator-facing
+    /// message naming `product_name`.
+    pub(crate) fn evaluate(&self, product_name: &str, now_epoch_s: i64) -> DispatchCalendarState {
+        let quiet = window_position(&self.quiet_hours, self.tz, now_epoch_s);
+        if quiet.inside {
+            let message = match quiet.boundary_epoch {
+                Some(end) => format!("{product_name} quiet hours — end {}", self.local(end)),
+                None => format!("{product_name} quiet hours"),
+            };
+            return state(DispatchCalendarPhase::QuietHours, quiet.boundary_epoch, message);
+        }
+
+        let window_close = if self.windows.is_empty() {
+            None
+        } else {
+            let window = window_position(&self.windows, self.tz, now_epoch_s);
+            if !window.inside {
+                let message = match window.boundary_epoch {
+                    Some(open) => format!(
+                        "Outside the {product_name} dispatch window — opens {}",
+                        self.local(open)
+                    ),
+                    None => format!("Outside every {product_name} dispatch window"),
+                };
+                return state(DispatchCalendarPhase::Closed, window.boundary_epoch, message);
+            }
+            window.boundary_epoch
+        };
+
+        // The cut-off is whichever comes first: the window closing or
+        // quiet hours beginning. `quiet.boundary_epoch` is the next quiet
+        // start here, since now is outside quiet hours.
+        let (cutoff, cutoff_label) = match (window_close, quiet.boundary_epoch) {
+            (Some(close), Some(quiet_start)) if quiet_start < close => {
+                (Some(quiet_start), format!("{product_name} quiet hours begin"))
+            }
+            (Some(close), _) => (Some(close), format!("the {product_name} dispatch window closes")),
+            (None, Some(quiet_start)) => (Some(quiet_start), format!("{product_name} quiet hours begin")),
+            (None, None) => (None, String::new()),
+        };
+        match cutoff {
+            Some(cutoff) if self.drain_secs > 0 && cutoff - now_epoch_s <= self.drain_secs => state(
+                DispatchCalendarPhase::Draining,
+                Some(cutoff),
+                format!(
+                    "Draining before {cutoff_label} at {} — no new work starts",
+                    self.local(cutoff)
+                ),
+            ),
+            Some(cutoff) => state(
+                DispatchCalendarPhase::Open,
+                Some(cutoff),
+                format!("Open until {cutoff_label} at {}", self.local(cutoff)),
+            ),
+            None => state(DispatchCalendarPhase::Open, None, "Open".to_owned()),
+        }
+    }
+
+    /// `epoch_s` as a short local wall time (`Mon 08:00 PDT`).
+    fn local(&self, epoch_s: i64) -> String {
+        DateTime::from_timestamp(epoch_s, 0).map_or_else(
+            || format!("@{epoch_s}"),
+            |at| at.with_timezone(&self.tz).format("%a %H:%M %Z").to_string(),
+        )
+    }
+}
+
+fn state(phase: DispatchCalendarPhase, until_epoch_s: Option<i64>, message: String) -> DispatchCalendarState {
+    DispatchCalendarState::builder()
+        .phase(phase)
+        .maybe_until_epoch_s(until_epoch_s)
+        .message(message)
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use boss_protocol::DispatchWindow;
+
+    /// 2026-07-15 (a Wednesday) at `h:m` PDT (UTC-7).
+    fn wed(h: i64, m: i64) -> i64 {
+        1_784_073_600 + (h + 7) * 3600 + m * 60
+    }
+
+    fn calendar(windows: &[&str], quiet: &[&str], drain: u32) -> CompiledCalendar {
+        let parse = |specs: &[&str]| specs.iter().map(|s| DispatchWindow::parse(s).unwrap()).collect();
+        CompiledCalendar::compile(&DispatchCalendarSetting {
+            timezone: "America/Los_Angeles".to_owned(),
+            windows: parse(windows),
+            quiet_hours: parse(quiet),
+            drain_before_close_minutes: drain,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn window_opens_drains_and_closes() {
+        let cal = calendar(&["weekdays 08:00-20:00"], &[], 30);
+        let open = cal.evaluate("Boss", wed(12, 0));
+        assert_eq!(open.phase, DispatchCalendarPhase::Open);
+        assert_eq!(open.until_epoch_s, Some(wed(20, 0)));
+
+        let draining = cal.evaluate("Boss", wed(19, 45));
+        assert_eq!(draining.phase, DispatchCalendarPhase::Draining);
+        assert_eq!(
+            draining.message,
+            "Draining before the Boss dispatch window closes at Wed 20:00 PDT — no new work starts"
+        );
+
+        let closed = cal.evaluate("Boss", wed(21, 0));
+        assert_eq!(closed.phase, DispatchCalendarPhase::Closed);
+        assert_eq!(closed.until_epoch_s, Some(wed(24 + 8, 0)));
+        assert_eq!(closed.message, "Outside the Boss dispatch window — opens Thu 08:00 PDT");
+    }
+
+    #[test]
+    fn quiet_hours_override_windows_and_cut_the_drain_short() {
+        let cal = calendar(&["weekdays 08:00-20:00"], &["daily 12:00-13:00"], 15);
+        assert_eq!(
+            cal.evaluate("Boss", wed(12, 30)).phase,
+            DispatchCalendarPhase::QuietHours
+        );
+
+        let before_quiet = cal.evaluate("Boss", wed(11, 50));
+        assert_eq!(before_quiet.phase, DispatchCalendarPhase::Draining);
+        assert_eq!(before_quiet.until_epoch_s, Some(wed(12, 0)));
+    }
+
+    #[test]
+    fn quiet_hours_alone_leave_the_rest_of_the_day_open() {
+        let cal = calendar(&[], &["daily 22:00-06:00"], 0);
+        let day = cal.evaluate("Boss", wed(14, 0));
+        assert_eq!(day.phase, DispatchCalendarPhase::Open);
+        assert_eq!(day.until_epoch_s, Some(wed(22, 0)));
+        assert_eq!(
+            cal.evaluate("Boss", wed(23, 0)).phase,
+            DispatchCalendarPhase::QuietHours
+        );
+    }
+
+    #[test]
+    fn unknown_timezone_does_not_compile() {
+        let setting = DispatchCalendarSetting {
+            timezone: "Mars/Olympus_Mons".to_owned(),
+            windows: vec![DispatchWindow::parse("daily 08:00-20:00").unwrap()],
+            quiet_hours: Vec::new(),
+            drain_before_close_minutes: 0,
+        };
+        assert!(CompiledCalendar::compile(&setting).is_err());
+    }
+}
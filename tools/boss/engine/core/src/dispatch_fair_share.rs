@@ -0,0 +1,229 @@
+//! Pure fair-share ordering for the ready queue. No I/O — the
+//! coordinator hands in the ready rows' keys, the live worker count per
+//! product and the stored [`DispatchShareSetting`]s, and walks the
+//! returned order in `drain_ready_queue`.
+//!
+//! # The order
+//!
+//! [`crate::work::DispatchClass`] stays the primary key: a merge-conflict
+//! revision of any product still outranks every chore of every product.
+//! Within a class, rows interleave by product. Each slot goes to the
+//! product whose `(live workers + rows already placed this pass) /
+//! weight` is lowest, so a product with fifty ready chores gets its
+//! weighted share of the workers, not all of them, while a product with
+//! one ready chore is offered the very next slot. Ties break on the
+//! candidate rows themselves.
+//!
+//! Within a product, rows order by [`effective_priority`] (highest
+//! first), then age. Aging adds one priority point per
+//! [`PRIORITY_AGING_INTERVAL_SECS`] waited, so a priority-0 row filed
+//! behind a steady stream of priority-5 rows overtakes them after a
+//! bounded wait instead of never.
+//!
+//! Ceilings are not applied here: ordering never drops a row. The drain
+//! loop holds rows whose product has reached its `max_concurrent` (see
+//! [`ceiling_reached`]), so the next product in this order takes the slot.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use crate::work::DispatchShareSetting;
+
+/// How long a `ready` row waits to gain one point of priority.
+pub(crate) const PRIORITY_AGING_INTERVAL_SECS: i64 = 15 * 60;
+
+/// `priority` plus one point per full [`PRIORITY_AGING_INTERVAL_SECS`]
+/// since `created_epoch_s`. A row with no parseable creation time does
+/// not age.
+pub(crate) fn effective_priority(priority: i64, created_epoch_s: Option<i64>, now_epoch_s: i64) -> i64 {
+    let waited = created_epoch_s.map_or(0, |created| (now_epoch_s - created).max(0));
+    priority.saturating_add(waited / PRIORITY_AGING_INTERVAL_SECS)
+}
+
+/// One ready row, as the ordering sees it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct QueueEntry<'a> {
+    pub class_ordinal: i64,
+    /// `None` rows (no owning product) share one bucket at the default
+    /// weight.
+    pub product_id: Option<&'a str>,
+    pub priority: i64,
+    pub created_epoch_s: Option<i64>,
+}
+
+/// `true` once `load` workers of a product with `setting` are live (or
+/// placed this pass) and its ceiling allows no more.
+pub(crate) fn ceiling_reached(setting: DispatchShareSetting, load: u32) -> bool {
+    setting.max_concurrent.is_some_and(|max| load >= max)
+}
+
+/// A product's `(load, weight)`, compared as the fraction `load / weight`.
+type Share = (u64, u64);
+
+/// Orders rows within a product: highest effective priority, then
+/// oldest, then input position.
+type RowKey = (Reverse<i64>, i64, usize);
+
+/// Indices into `entries` in fair-share order. Every index appears
+/// exactly once.
+pub(crate) fn fair_share_order(
+    entries: &[QueueEntry<'_>],
+    live: &HashMap<String, u32>,
+    settings: &HashMap<String, DispatchShareSetting>,
+    now_epoch_s: i64,
+) -> Vec<usize> {
+    // Smallest key dispatches first within a product.
+    let row_key = |index: usize| -> RowKey {
+        let entry = &entries[index];
+        (
+            Reverse(effective_priority(entry.priority, entry.created_epoch_s, now_epoch_s)),
+            entry.created_epoch_s.unwrap_or(i64::MAX),
+            index,
+        )
+    };
+    let weight_of = |product: Option<&str>| {
+        product
+            .and_then(|id| settings.get(id))
+            .copied()
+            .unwrap_or_default()
+            .weight
+            .max(1)
+    };
+
+    let mut classes: BTreeMap<i64, HashMap<Option<&str>, Vec<usize>>> = BTreeMap::new();
+    for (index, entry) in entries.iter().enumerate() {
+        classes
+            .entry(entry.class_ordinal)
+            .or_default()
+            .entry(entry.product_id)
+            .or_default()
+            .push(index);
+    }
+
+    let mut load: HashMap<Option<&str>, u64> = HashMap::new();
+    let mut order = Vec::with_capacity(entries.len());
+    for products in classes.into_values() {
+        let mut queues: Vec<(Option<&str>, VecDeque<usize>)> = products
+            .into_iter()
+            .map(|(product, mut rows)| {
+                rows.sort_by_key(|&index| row_key(index));
+                (product, rows.into())
+            })
+            .collect();
+        loop {
+            let mut best: Option<(usize, Share, RowKey)> = None;
+            for (slot, (product, rows)) in queues.iter().enumerate() {
+                let Some(&head) = rows.front() else {
+                    continue;
+                };
+                let product_load = *load
+                    .entry(*product)
+                    .or_insert_with(|| u64::from(product.and_then(|id| live.get(id)).copied().unwrap_or(0)));
+                let share: Share = (product_load, u64::from(weight_of(*product)));
+                let better = best.as_ref().is_none_or(|(_, best_share, best_key)| {
+                    // share.0 / share.1 vs best.0 / best.1, without division.
+                    (share.0 * best_share.1)
+                        .cmp(&(best_share.0 * share.1))
+                        .then_with(|| row_key(head).cmp(best_key))
+                        .is_lt()
+                });
+                if better {
+                    best = Some((slot, share, row_key(head)));
+                }
+            }
+            let Some((slot, _, _)) = best else {
+                break;
+            };
+            let (product, rows) = &mut queues[slot];
+            order.extend(rows.pop_front());
+            *load.entry(*product).or_default() += 1;
+        }
+    }
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_800_000_000;
+
+    fn entry(product: &str, priority: i64, created_epoch_s: i64) -> QueueEntry<'_> {
+        QueueEntry {
+            class_ordinal: 5,
+            product_id: Some(product),
+            priority,
+            created_epoch_s: Some(created_epoch_s),
+        }
+    }
+
+    fn products_in_order(entries: &[QueueEntry<'_>], order: &[usize]) -> Vec<String> {
+        order
+            .iter()
+            .map(|&index| entries[index].product_id.unwrap_or("-").to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn a_late_single_row_is_not_starved_behind_a_backlog() {
+        let mut entries: Vec<QueueEntry<'_>> = (0..5).map(|n| entry("bulk", 0, NOW - 100 + n)).collect();
+        entries.push(entry("small", 0, NOW));
+        let order = fair_share_order(&entries, &HashMap::new(), &HashMap::new(), NOW);
+        assert_eq!(
+            products_in_order(&entries, &order),
+            ["bulk", "small", "bulk", "bulk", "bulk", "bulk"]
+        );
+    }
+
+    #[test]
+    fn weights_and_live_workers_set_the_share() {
+        let entries: Vec<QueueEntry<'_>> = (0..3)
+            .flat_map(|n| [entry("heavy", 0, NOW - 50 + n), entry("light", 0, NOW - 40 + n)])
+            .collect();
+        let settings = HashMap::from([(
+            "heavy".to_owned(),
+            DispatchShareSetting {
+                weight: 2,
+                max_concurrent: None,
+            },
+        )]);
+        let order = fair_share_order(&entries, &HashMap::new(), &settings, NOW);
+        assert_eq!(
+            products_in_order(&entries, &order),
+            ["heavy", "light", "heavy", "heavy", "light", "light"]
+        );
+
+        // Two live `heavy` workers already cover its double share.
+        let live = HashMap::from([("heavy".to_owned(), 2)]);
+        let order = fair_share_order(&entries, &live, &settings, NOW);
+        assert_eq!(products_in_order(&entries, &order)[0], "light");
+    }
+
+    #[test]
+    fn class_outranks_share_and_aging_outranks_fresh_priority() {
+        let mut entries = vec![
+            entry("a", 5, NOW - 60),
+            entry("a", 0, NOW - 3 * PRIORITY_AGING_INTERVAL_SECS - 60),
+            entry("a", 1, NOW),
+        ];
+        entries.push(QueueEntry {
+            class_ordinal: 1,
+            ..entry("b", 0, NOW)
+        });
+        let order = fair_share_order(&entries, &HashMap::new(), &HashMap::new(), NOW);
+        // The class-1 row first; then priority 5, the aged priority 0
+        // (effective 3), and the fresh priority 1.
+        assert_eq!(order, [3, 0, 1, 2]);
+    }
+
+    #[test]
+    fn ceilings_compare_against_the_load() {
+        let capped = DispatchShareSetting {
+            weight: 1,
+            max_concurrent: Some(2),
+        };
+        assert!(!ceiling_reached(capped, 1));
+        assert!(ceiling_reached(capped, 2));
+        assert!(!ceiling_reached(DispatchShareSetting::default(), 100));
+    }
+}
@@ -1,6 +1,6 @@
 //! Queue-level dispatch telemetry: ready-queue depth per pool, oldest-
-//! ready wait age per pool, dispatch completion counts, and drain-pass
-//! duration.
+//! ready wait age per pool, dispatch completion counts, drain-pass
+//! duration, and the dispatch-latency / spawn-ack histograms.
 //!
 //! Before this module, the only dispatch-adjacent counters were
 //! `cube_workspace_lease.{attempts,success,failure}`
@@ -57,6 +57,19 @@ crate::register_gauge!(
     "Wall-clock duration in milliseconds of the most recently completed drain_ready_queue pass.",
 );
 
+crate::register_histogram!(
+    DISPATCH_LATENCY_SECONDS,
+    "dispatch.latency_seconds",
+    "Seconds from an execution's creation to the drain pass that claimed a worker slot for it.",
+    crate::metrics::WAIT_BUCKETS_SECS,
+);
+crate::register_histogram!(
+    DISPATCH_SPAWN_ACK_SECONDS,
+    "dispatch.spawn_ack_seconds",
+    "Seconds from a worker slot's spawn registration to the first driver-originated signal (hook or transcript path).",
+    crate::metrics::WAIT_BUCKETS_SECS,
+);
+
 /// Register every handle this module declares. Called from
 /// [`crate::metrics_init::init_all`] at engine startup.
 pub fn register_metrics(registry: &Registry) {
@@ -68,6 +81,8 @@ pub fn register_metrics(registry: &Registry) {
     registry.register_gauge(&DISPATCH_QUEUE_OLDEST_WAIT_SECONDS_REVIEW);
     registry.register_counter(&DISPATCH_COMPLETED);
     registry.register_gauge(&DISPATCH_DRAIN_PASS_DURATION_MS);
+    registry.register_histogram(&DISPATCH_LATENCY_SECONDS);
+    registry.register_histogram(&DISPATCH_SPAWN_ACK_SECONDS);
 }
 
 /// Per-pool depth + oldest-ready-age, computed once up front by the
@@ -102,6 +117,17 @@ pub fn record_dispatch_completed(registry: &Registry) {
     DISPATCH_COMPLETED.inc(registry);
 }
 
+/// Record how long a just-claimed execution waited, in seconds, since it
+/// was created. Negative ages (clock skew) count as zero.
+pub fn record_dispatch_latency_secs(registry: &Registry, latency_secs: i64) {
+    DISPATCH_LATENCY_SECONDS.observe(registry, latency_secs.max(0) as u64);
+}
+
+/// Record a run's spawn-to-first-driver-signal time, in seconds.
+pub fn record_spawn_ack_secs(registry: &Registry, ack_secs: i64) {
+    DISPATCH_SPAWN_ACK_SECONDS.observe(registry, ack_secs.max(0) as u64);
+}
+
 /// Record the wall-clock duration of one `drain_ready_queue` pass.
 pub fn record_drain_pass_duration_ms(registry: &Registry, duration_ms: i64) {
     DISPATCH_DRAIN_PASS_DURATION_MS.set(registry, duration_ms);
@@ -151,6 +177,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn latency_histograms_clamp_negative_ages_to_zero() {
+        let registry = Registry::new();
+        register_metrics(&registry);
+
+        record_dispatch_latency_secs(&registry, -5);
+        record_dispatch_latency_secs(&registry, 90);
+        record_spawn_ack_secs(&registry, 3);
+
+        let snaps = registry.histogram_snapshots();
+        let latency = snaps.iter().find(|s| s.name == "dispatch.latency_seconds").unwrap();
+        assert_eq!(latency.count, 2);
+        assert_eq!(latency.sum, 90);
+        assert_eq!(
+            latency.cumulative_counts[0], 1,
+            "the skewed sample lands in the first bucket"
+        );
+        let ack = snaps.iter().find(|s| s.name == "dispatch.spawn_ack_seconds").unwrap();
+        assert_eq!((ack.count, ack.sum), (1, 3));
+    }
+
     #[test]
     fn record_dispatch_completed_increments_counter() {
         let registry = Registry::new();
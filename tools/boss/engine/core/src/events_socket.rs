@@ -1,12 +1,13 @@
 //! Engine events socket — accepts connections from `boss-event` shims
 //! running inside leased worker workspaces, looks up the connecting
-//! peer's pid via `LOCAL_PEERPID`, decodes the JSON hook payload via
+//! peer's pid from the kernel, decodes the JSON hook payload via
 //! [`boss_protocol::normalize_hook_event`], and produces typed
 //! [`IncomingHookEvent`]s annotated with the peer pid and (when the
 //! peer's process tree is registered with [`crate::worker_registry`])
 //! the matching `run_id`.
 //!
-//! Cross-platform: macOS uses `LOCAL_PEERPID`, Linux uses `SO_PEERCRED`.
+//! Cross-platform: macOS uses `LOCAL_PEERPID`, Linux uses `SO_PEERCRED`
+//! (see [`crate::process_probe`]).
 
 use std::io;
 use std::os::fd::AsRawFd;
@@ -22,13 +23,6 @@ use crate::work::WorkDb;
 use tokio::io::AsyncReadExt;
 use tokio::net::{UnixListener, UnixStream};
 
-/// `level` for `getsockopt(SOL_LOCAL, LOCAL_PEERPID)` on macOS.
-#[cfg(target_os = "macos")]
-const SOL_LOCAL: libc::c_int = 0;
-/// `optname` for the LOCAL_PEERPID getsockopt on macOS.
-#[cfg(target_os = "macos")]
-const LOCAL_PEERPID: libc::c_int = 0x002;
-
 /// One hook event after peer-pid lookup, payload extraction, and
 /// normalization.
 ///
@@ -255,61 +249,9 @@ pub(crate) fn path_has_a_live_listener(path: &Path) -> bool {
 }
 
 /// Look up the peer pid of a connected stream socket via
-/// `getsockopt(SOL_LOCAL, LOCAL_PEERPID)` on macOS.
-#[cfg(target_os = "macos")]
-pub fn peer_pid(stream: &UnixStream) -> io::Result<libc::pid_t> {
-    let fd = stream.as_raw_fd();
-    let mut pid: libc::pid_t = 0;
-    let mut len: libc::socklen_t = std::mem::size_of::<libc::pid_t>() as libc::socklen_t;
-    // SAFETY: `fd` is borrowed from the caller's UnixStream and remains
-    // valid for this call; `pid` and `len` are stack-local mutables and
-    // their addresses are passed only to `getsockopt`.
-    let rc = unsafe {
-        libc::getsockopt(
-            fd,
-            SOL_LOCAL,
-            LOCAL_PEERPID,
-            &mut pid as *mut _ as *mut libc::c_void,
-            &mut len,
-        )
-    };
-    if rc != 0 {
-        return Err(io::Error::last_os_error());
-    }
-    Ok(pid)
-}
-
-/// Look up the peer pid of a connected stream socket via
-/// `getsockopt(SO_PEERCRED)` on Linux.
-#[cfg(target_os = "linux")]
+/// [`crate::process_probe::socket_peer_pid`].
 pub fn peer_pid(stream: &UnixStream) -> io::Result<libc::pid_t> {
-    let fd = stream.as_raw_fd();
-    let mut cred = libc::ucred { pid: 0, uid: 0, gid: 0 };
-    let mut len: libc::socklen_t = std::mem::size_of::<libc::ucred>() as libc::socklen_t;
-    // SAFETY: `fd` is borrowed from the caller's UnixStream and remains
-    // valid for this call; `cred` and `len` are stack-local mutables and
-    // their addresses are passed only to `getsockopt`.
-    let rc = unsafe {
-        libc::getsockopt(
-            fd,
-            libc::SOL_SOCKET,
-            libc::SO_PEERCRED,
-            &mut cred as *mut _ as *mut libc::c_void,
-            &mut len,
-        )
-    };
-    if rc != 0 {
-        return Err(io::Error::last_os_error());
-    }
-    Ok(cred.pid)
-}
-
-#[cfg(not(any(target_os = "macos", target_os = "linux")))]
-pub fn peer_pid(_stream: &UnixStream) -> io::Result<libc::pid_t> {
-    Err(io::Error::new(
-        io::ErrorKind::Unsupported,
-        "peer pid lookup is not supported on this platform",
-    ))
+    crate::process_probe::socket_peer_pid(stream.as_raw_fd())
 }
 
 /// Read a connection to EOF and produce a typed IncomingHookEvent.
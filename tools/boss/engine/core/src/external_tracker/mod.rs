@@ -2,8 +2,8 @@
 //! transport layer.
 //!
 //! The tracker vocabulary (`ExternalTracker`, `UpstreamItem`, `TrackerError`,
-//! …) and the GitHub transport (REST client, device-flow OAuth, credential
-//! resolution) live in the lower-level `boss_github_tracker` crate, so that
+//! …) and the tracker transports (GitHub, Linear and Jira clients,
+//! device-flow OAuth, credential resolution) live in the lower-level `boss_github_tracker` crate, so that
 //! touching them no longer rebuilds and retests the whole engine. They are
 //! re-exported here under the long-standing `crate::external_tracker::*`
 //! paths the engine already uses.
@@ -22,7 +22,7 @@ mod github_oauth_org_probe_tests;
 
 pub use boss_github_tracker::{
     CloseReason, ClosedReason, EchoTracker, ExternalTracker, RegistryError, Result, TrackerConfigError, TrackerContext,
-    TrackerCredential, TrackerError, TrackerRegistry, UpstreamItem, UpstreamPrAssociation, UpstreamRef, UpstreamStatus,
-    credentials, github, github_oauth,
+    TrackerCredential, TrackerError, TrackerRegistry, UpstreamComment, UpstreamFieldUpdate, UpstreamItem,
+    UpstreamPrAssociation, UpstreamRef, UpstreamStatus, credentials, github, github_oauth, jira, linear,
 };
 pub use org_state_sink::WorkDbOrgStateSink;
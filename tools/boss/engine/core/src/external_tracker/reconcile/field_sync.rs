@@ -0,0 +1,688 @@
+//! Opt-in two-way sync of selected fields and comments between upstream
+//! items and their Boss mirrors.
+//!
+//! Enabled per product through a `sync` object in the tracker config:
+//!
+//! ```json
+//! "sync": {
+//!   "title": "upstream_wins",
+//!   "description": "last_writer_wins",
+//!   "priority": "boss_wins",
+//!   "assignee": "upstream_wins",
+//!   "comments": true
+//! }
+//! ```
+//!
+//! Each listed field keeps its own baseline (checksums of the value both
+//! sides last agreed on) in `external_tracker_field_state`. A change on one
+//! side only is propagated to the other; a change on both sides is a
+//! conflict, resolved by the field's policy. Boss-side wins are pushed
+//! through [`ExternalTracker::update_fields`] after the Boss-side SQL for
+//! the product has committed, under the same per-tick budget as the other
+//! deferred upstream calls. When `sync` lists `title` or `description`,
+//! this path replaces Behavior 8 for the product.
+//!
+//! Comments are additive rather than conflicting: upstream comments not yet
+//! linked are imported as `WorkComment`s, and Boss comments not yet linked
+//! are posted upstream. Links live in `external_tracker_comment_links` so a
+//! comment is never mirrored twice.
+
+use std::collections::HashSet;
+
+use boss_protocol::{CommentAnchor, CreateCommentInput, Task};
+use tracing::{debug, info, warn};
+
+use crate::external_tracker::{TrackerError, UpstreamFieldUpdate, UpstreamItem, UpstreamRef};
+use crate::work::{ExternalFieldSyncState, field_checksum};
+
+use super::logic::ProductReconcileCtx;
+use super::{
+    COMMENT_SYNC_FAILED, COMMENTS_IMPORTED, COMMENTS_PUSHED, FIELD_CONFLICT, FIELD_PUSH_FAILED, FIELD_PUSHED,
+    FIELD_SYNCED, PassOutcome,
+};
+
+/// Field-state key the comment-sync fingerprints are stored under.
+const COMMENTS_FIELD: &str = "comments";
+
+/// A field the two-way sync can carry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum SyncField {
+    /// Upstream title ↔ `tasks.name`.
+    Title,
+    /// Upstream body ↔ `tasks.description`, minus the `> Imported from` header.
+    Description,
+    /// Normalised upstream priority ↔ `tasks.priority`.
+    Priority,
+    /// First upstream assignee ↔ the Boss-side assignee kept in
+    /// `external_tracker_field_state.boss_value`.
+    Assignee,
+}
+
+impl SyncField {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Title => "title",
+            Self::Description => "description",
+            Self::Priority => "priority",
+            Self::Assignee => "assignee",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "title" => Some(Self::Title),
+            "description" => Some(Self::Description),
+            "priority" => Some(Self::Priority),
+            "assignee" => Some(Self::Assignee),
+            _ => None,
+        }
+    }
+
+    /// Upstream's current value, or `None` when upstream has nothing to say
+    /// about this field (no native priority), in which case it is skipped.
+    fn upstream_value(self, upstream: &UpstreamItem) -> Option<String> {
+        match self {
+            Self::Title => Some(upstream.title.clone()),
+            Self::Description => Some(upstream.body.clone()),
+            Self::Priority => upstream.priority.clone(),
+            Self::Assignee => Some(upstream.assignees.first().cloned().unwrap_or_default()),
+        }
+    }
+
+    /// Boss's current value, in the same shape as [`Self::upstream_value`].
+    fn boss_value(self, task: &Task, upstream: &UpstreamItem, state: Option<&ExternalFieldSyncState>) -> String {
+        match self {
+            Self::Title => task.name.clone(),
+            Self::Description => strip_import_header(&task.description, &upstream.upstream_url).to_owned(),
+            Self::Priority => task.priority.clone(),
+            Self::Assignee => state.and_then(|s| s.boss_value.clone()).unwrap_or_default(),
+        }
+    }
+
+    /// The value to store on the Boss side when upstream's `value` wins.
+    fn boss_stored_value(self, value: &str, upstream: &UpstreamItem) -> String {
+        match self {
+            Self::Description => import_header_description(&upstream.upstream_url, value),
+            _ => value.to_owned(),
+        }
+    }
+
+    /// The upstream write that carries Boss's `value` for this field.
+    fn upstream_update(self, value: &str) -> UpstreamFieldUpdate {
+        let mut update = UpstreamFieldUpdate::default();
+        match self {
+            Self::Title => update.title = Some(value.to_owned()),
+            Self::Description => update.body = Some(value.to_owned()),
+            Self::Priority => update.priority = Some(value.to_owned()),
+            Self::Assignee => {
+                update.assignees = Some(if value.is_empty() {
+                    Vec::new()
+                } else {
+                    vec![value.to_owned()]
+                });
+            }
+        }
+        update
+    }
+}
+
+/// How a field edited on both sides since the last sync is resolved. The
+/// config spellings are `upstream_wins`, `boss_wins` and `last_writer_wins`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FieldSyncPolicy {
+    /// The upstream value is applied to Boss.
+    Upstream,
+    /// The Boss value is pushed upstream.
+    Boss,
+    /// Compare the upstream item's `updated_at` with the Boss edit time;
+    /// upstream wins ties.
+    LastWriter,
+}
+
+impl FieldSyncPolicy {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "upstream_wins" => Some(Self::Upstream),
+            "boss_wins" => Some(Self::Boss),
+            "last_writer_wins" => Some(Self::LastWriter),
+            _ => None,
+        }
+    }
+
+    fn resolve(self, upstream_updated_at: i64, boss_updated_at: i64) -> Side {
+        match self {
+            Self::Upstream => Side::Upstream,
+            Self::Boss => Side::Boss,
+            Self::LastWriter if boss_updated_at > upstream_updated_at => Side::Boss,
+            Self::LastWriter => Side::Upstream,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Upstream,
+    Boss,
+}
+
+/// Parsed `sync` object from a product's tracker config. The default (no
+/// `sync` key) syncs nothing, leaving the reconciler one-way.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct SyncConfig {
+    /// Synced fields with their conflict policy, in [`SyncField`] order.
+    pub(crate) fields: Vec<(SyncField, FieldSyncPolicy)>,
+    pub(crate) comments: bool,
+}
+
+impl SyncConfig {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.fields.is_empty() && !self.comments
+    }
+
+    /// `true` when this path, not Behavior 8, owns title/description drift.
+    pub(crate) fn owns_title_or_description(&self) -> bool {
+        self.fields
+            .iter()
+            .any(|(f, _)| matches!(f, SyncField::Title | SyncField::Description))
+    }
+}
+
+/// Parse and validate the optional `sync` object of a tracker config.
+/// Shared by config validation at bind time and the reconciler, so a config
+/// that validated is always one the reconciler understands.
+pub(crate) fn parse_sync_config(config: &serde_json::Value) -> Result<SyncConfig, String> {
+    let sync = match config.get("sync") {
+        None | Some(serde_json::Value::Null) => return Ok(SyncConfig::default()),
+        Some(serde_json::Value::Object(map)) => map,
+        Some(_) => return Err("'sync' must be an object".to_owned()),
+    };
+    let mut parsed = SyncConfig::default();
+    for (key, value) in sync {
+        if key == COMMENTS_FIELD {
+            parsed.comments = value
+                .as_bool()
+                .ok_or_else(|| "'sync.comments' must be a boolean".to_owned())?;
+            continue;
+        }
+        let field = SyncField::parse(key).ok_or_else(|| {
+            format!("unknown sync field '{key}'; supported: title, description, priority, assignee, comments")
+        })?;
+        let policy = value.as_str().and_then(FieldSyncPolicy::parse).ok_or_else(|| {
+            format!("invalid policy for 'sync.{key}'; supported: upstream_wins, boss_wins, last_writer_wins")
+        })?;
+        parsed.fields.push((field, policy));
+    }
+    parsed.fields.sort_by_key(|(f, _)| *f);
+    Ok(parsed)
+}
+
+/// Carries intent to push one Boss-side field value upstream after all
+/// Boss-side SQL writes are done.
+pub(super) struct FieldPushCandidate {
+    work_item_id: String,
+    upstream_ref: UpstreamRef,
+    field: SyncField,
+    /// Boss's value; becomes the baseline on both sides once the push lands.
+    boss_value: String,
+    /// Upstream's value this tick; recorded as the upstream baseline when
+    /// the tracker cannot write the field at all, so the push isn't retried
+    /// forever.
+    upstream_value: String,
+}
+
+/// Carries intent to run a comment sync for one item after all Boss-side
+/// SQL writes are done.
+pub(super) struct CommentSyncCandidate {
+    work_item_id: String,
+    upstream_ref: UpstreamRef,
+    upstream_url: String,
+    /// `updated_at` of the upstream item this tick; stored as the upstream
+    /// comment fingerprint once the sync completes.
+    upstream_fingerprint: String,
+    /// Task `updated_at`, stamped as the `doc_version` of imported comments.
+    doc_version: String,
+}
+
+/// Deferred upstream calls queued by [`reconcile_fields`].
+#[derive(Default)]
+pub(super) struct FieldSyncCandidates {
+    pub(super) pushes: Vec<FieldPushCandidate>,
+    pub(super) comments: Vec<CommentSyncCandidate>,
+}
+
+/// Strip the `> Imported from {url}` header the importer prepends, so the
+/// Boss description compares against the raw upstream body.
+fn strip_import_header<'a>(description: &'a str, upstream_url: &str) -> &'a str {
+    description
+        .strip_prefix(&format!("> Imported from {upstream_url}\n\n"))
+        .unwrap_or(description)
+}
+
+fn import_header_description(upstream_url: &str, body: &str) -> String {
+    format!("> Imported from {upstream_url}\n\n{body}")
+}
+
+/// Epoch-seconds timestamp as stored by `now_string`, `0` when unset or
+/// unparseable (which makes upstream win a last-writer-wins tie).
+fn epoch_secs(ts: Option<&str>) -> i64 {
+    ts.and_then(|s| s.parse().ok()).unwrap_or(0)
+}
+
+/// Fingerprint of the Boss comment set: the ids in creation order. Any new
+/// Boss comment changes it, which is what triggers a comment sync.
+fn boss_comment_fingerprint(rctx: &ProductReconcileCtx<'_>, work_item_id: &str) -> anyhow::Result<String> {
+    let comments = rctx.work_db.list_comments("work_item", work_item_id, false)?;
+    Ok(comments.iter().map(|c| c.id.as_str()).collect::<Vec<_>>().join("\n"))
+}
+
+/// Seed the baselines of a freshly imported item. A new import is a copy
+/// of upstream, so every synced field adopts upstream's value outright
+/// (title and description already match; priority and assignee don't exist
+/// on the import input and are written here).
+pub(super) fn seed_imported_fields(rctx: &ProductReconcileCtx<'_>, work_item_id: &str, upstream: &UpstreamItem) {
+    for &(field, _) in &rctx.sync.fields {
+        let Some(upstream_value) = field.upstream_value(upstream) else {
+            continue;
+        };
+        let stored = field.boss_stored_value(&upstream_value, upstream);
+        if let Err(e) =
+            rctx.work_db
+                .reconciler_apply_upstream_field(work_item_id, field.as_str(), &stored, &upstream_value)
+        {
+            warn!(work_item_id, field = field.as_str(), error = %e, "seeding two-way sync baseline failed");
+        }
+    }
+}
+
+/// Reconcile every synced field of one bound work item and decide whether
+/// its comments need a sync this tick.
+///
+/// Per field:
+/// - no baseline yet → record one silently (the assignee additionally
+///   adopts upstream's value, since Boss has none of its own yet);
+/// - only upstream changed → write it onto the Boss row;
+/// - only Boss changed → queue an `update_fields` push;
+/// - both changed to the same value → re-baseline;
+/// - both changed differently → conflict, resolved by the field's policy.
+pub(super) async fn reconcile_fields(
+    rctx: &ProductReconcileCtx<'_>,
+    task: &Task,
+    upstream: &UpstreamItem,
+    candidates: &mut FieldSyncCandidates,
+    outcome: &mut PassOutcome,
+) {
+    let &ProductReconcileCtx {
+        work_db,
+        metrics,
+        product_id,
+        publisher,
+        sync,
+        ..
+    } = rctx;
+    let work_item_id = task.id.as_str();
+
+    let states = match work_db.reconciler_get_field_sync_states(work_item_id) {
+        Ok(s) => s,
+        Err(e) => {
+            warn!(work_item_id, error = %e, "reconciler_get_field_sync_states failed; skipping two-way sync");
+            return;
+        }
+    };
+
+    let mut boss_updated = false;
+    let mut final_name = task.name.clone();
+    let mut final_description = task.description.clone();
+    for &(field, policy) in &sync.fields {
+        let Some(upstream_value) = field.upstream_value(upstream) else {
+            continue;
+        };
+        let state = states.get(field.as_str());
+        let boss_value = field.boss_value(task, upstream, state);
+
+        let baseline = state.and_then(|s| s.upstream_checksum.as_deref().zip(s.boss_checksum.as_deref()));
+        let Some((upstream_baseline, boss_baseline)) = baseline else {
+            let recorded = if field == SyncField::Assignee && state.and_then(|s| s.boss_value.as_ref()).is_none() {
+                work_db
+                    .reconciler_apply_upstream_field(work_item_id, field.as_str(), &upstream_value, &upstream_value)
+                    .map(|_| ())
+            } else {
+                work_db.reconciler_record_field_baseline(work_item_id, field.as_str(), &upstream_value, &boss_value)
+            };
+            if let Err(e) = recorded {
+                warn!(work_item_id, field = field.as_str(), error = %e, "recording two-way sync baseline failed");
+            }
+            continue;
+        };
+
+        let upstream_changed = field_checksum(&upstream_value) != upstream_baseline;
+        let boss_changed = field_checksum(&boss_value) != boss_baseline;
+        if !upstream_changed && !boss_changed {
+            continue;
+        }
+        if upstream_value == boss_value {
+            // Both sides landed on the same value independently.
+            if let Err(e) =
+                work_db.reconciler_record_field_baseline(work_item_id, field.as_str(), &upstream_value, &boss_value)
+            {
+                warn!(work_item_id, field = field.as_str(), error = %e, "recording two-way sync baseline failed");
+            }
+            continue;
+        }
+
+        let winner = match (upstream_changed, boss_changed) {
+            (true, false) => Side::Upstream,
+            (false, true) => Side::Boss,
+            _ => {
+                let boss_edited_at = match field {
+                    SyncField::Assignee => epoch_secs(state.and_then(|s| s.boss_updated_at.as_deref())),
+                    _ => epoch_secs(Some(&task.updated_at)),
+                };
+                let winner = policy.resolve(upstream.updated_at, boss_edited_at);
+                FIELD_CONFLICT.inc(metrics);
+                outcome.field_conflicts += 1;
+                warn!(
+                    work_item_id,
+                    canonical_id = %upstream.upstream_ref.canonical_id,
+                    field = field.as_str(),
+                    ?policy,
+                    ?winner,
+                    "two-way sync: field edited on both sides; resolved by policy"
+                );
+                winner
+            }
+        };
+
+        match winner {
+            Side::Upstream => {
+                let stored = field.boss_stored_value(&upstream_value, upstream);
+                match work_db.reconciler_apply_upstream_field(work_item_id, field.as_str(), &stored, &upstream_value) {
+                    Ok(true) => {
+                        FIELD_SYNCED.inc(metrics);
+                        outcome.fields_synced += 1;
+                        boss_updated = true;
+                        match field {
+                            SyncField::Title => final_name = stored,
+                            SyncField::Description => final_description = stored,
+                            _ => {}
+                        }
+                        info!(
+                            work_item_id,
+                            canonical_id = %upstream.upstream_ref.canonical_id,
+                            field = field.as_str(),
+                            "two-way sync: upstream edit applied to boss row"
+                        );
+                    }
+                    Ok(false) => {}
+                    Err(e) => {
+                        warn!(work_item_id, field = field.as_str(), error = %e, "reconciler_apply_upstream_field failed");
+                    }
+                }
+            }
+            Side::Boss => candidates.pushes.push(FieldPushCandidate {
+                work_item_id: work_item_id.to_owned(),
+                upstream_ref: upstream.upstream_ref.clone(),
+                field,
+                boss_value,
+                upstream_value,
+            }),
+        }
+    }
+
+    // Keep Behavior 8's combined baseline current while this path owns
+    // title/description, so dropping them from `sync` later doesn't replay
+    // every past edit as a Behavior 8 conflict.
+    if sync.owns_title_or_description()
+        && let Err(e) = work_db.reconciler_set_content_checksums_baseline(
+            work_item_id,
+            &upstream.title,
+            &upstream.body,
+            &final_name,
+            &final_description,
+        )
+    {
+        warn!(work_item_id, error = %e, "reconciler_set_content_checksums_baseline failed");
+    }
+
+    if boss_updated {
+        publisher
+            .publish_work_item_invalidated(product_id, work_item_id, "chore_updated")
+            .await;
+    }
+
+    if sync.comments {
+        queue_comment_sync(rctx, task, upstream, states.get(COMMENTS_FIELD), candidates);
+    }
+}
+
+/// Queue a comment sync when either side's comment fingerprint moved since
+/// the last one (or there has never been one). The upstream fingerprint is
+/// the item's `updated_at`, which every tracker bumps on a new comment, so
+/// unchanged items cost no extra API call.
+fn queue_comment_sync(
+    rctx: &ProductReconcileCtx<'_>,
+    task: &Task,
+    upstream: &UpstreamItem,
+    state: Option<&ExternalFieldSyncState>,
+    candidates: &mut FieldSyncCandidates,
+) {
+    let boss_fingerprint = match boss_comment_fingerprint(rctx, &task.id) {
+        Ok(f) => f,
+        Err(e) => {
+            warn!(work_item_id = %task.id, error = %e, "listing boss comments failed; skipping comment sync");
+            return;
+        }
+    };
+    let upstream_fingerprint = upstream.updated_at.to_string();
+    let up_to_date = state.is_some_and(|s| {
+        s.upstream_checksum.as_deref() == Some(field_checksum(&upstream_fingerprint).as_str())
+            && s.boss_checksum.as_deref() == Some(field_checksum(&boss_fingerprint).as_str())
+    });
+    if !up_to_date {
+        candidates.comments.push(CommentSyncCandidate {
+            work_item_id: task.id.clone(),
+            upstream_ref: upstream.upstream_ref.clone(),
+            upstream_url: upstream.upstream_url.clone(),
+            upstream_fingerprint,
+            doc_version: task.updated_at.clone(),
+        });
+    }
+}
+
+/// Issue the deferred field pushes and comment syncs queued during
+/// reconcile. Each is capped at 20 per tick to match the other budgets;
+/// anything over budget is still pending next tick because its baseline
+/// hasn't moved.
+pub(super) async fn drain_candidates(
+    rctx: &ProductReconcileCtx<'_>,
+    candidates: FieldSyncCandidates,
+    outcome: &mut PassOutcome,
+) {
+    const FIELD_PUSH_BUDGET: usize = 20;
+    for push in candidates.pushes.into_iter().take(FIELD_PUSH_BUDGET) {
+        push_field(rctx, push, outcome).await;
+    }
+
+    const COMMENT_SYNC_BUDGET: usize = 20;
+    for candidate in candidates.comments.into_iter().take(COMMENT_SYNC_BUDGET) {
+        sync_comments(rctx, candidate, outcome).await;
+    }
+}
+
+async fn push_field(rctx: &ProductReconcileCtx<'_>, push: FieldPushCandidate, outcome: &mut PassOutcome) {
+    let &ProductReconcileCtx {
+        work_db,
+        tracker,
+        ctx,
+        metrics,
+        ..
+    } = rctx;
+    let field = push.field.as_str();
+    let update = push.field.upstream_update(&push.boss_value);
+    // The value upstream holds after this attempt: Boss's on success,
+    // unchanged when the tracker can't write the field.
+    let upstream_after = match tracker.update_fields(ctx, &push.upstream_ref, &update).await {
+        Ok(()) => {
+            FIELD_PUSHED.inc(metrics);
+            outcome.fields_pushed += 1;
+            info!(
+                work_item_id = %push.work_item_id,
+                canonical_id = %push.upstream_ref.canonical_id,
+                field,
+                "two-way sync: boss edit pushed upstream"
+            );
+            &push.boss_value
+        }
+        Err(TrackerError::Unsupported(msg)) => {
+            warn!(
+                work_item_id = %push.work_item_id,
+                canonical_id = %push.upstream_ref.canonical_id,
+                field,
+                %msg,
+                "two-way sync: tracker cannot write this field; keeping the boss value local"
+            );
+            &push.upstream_value
+        }
+        Err(e) => {
+            FIELD_PUSH_FAILED.inc(metrics);
+            outcome.field_push_failed += 1;
+            warn!(
+                work_item_id = %push.work_item_id,
+                canonical_id = %push.upstream_ref.canonical_id,
+                field,
+                error = %e,
+                "two-way sync: update_fields failed; will retry next tick"
+            );
+            return;
+        }
+    };
+    if let Err(e) =
+        work_db.reconciler_record_field_baseline(&push.work_item_id, field, upstream_after, &push.boss_value)
+    {
+        warn!(work_item_id = %push.work_item_id, field, error = %e, "recording two-way sync baseline failed");
+    }
+}
+
+/// Mirror comments both ways for one item: import unlinked upstream
+/// comments, then post unlinked Boss comments. The fingerprint baseline is
+/// only recorded once both directions succeeded, so a partial failure is
+/// retried on the next tick; links make the retry idempotent.
+async fn sync_comments(rctx: &ProductReconcileCtx<'_>, candidate: CommentSyncCandidate, outcome: &mut PassOutcome) {
+    let &ProductReconcileCtx {
+        work_db,
+        tracker,
+        ctx,
+        metrics,
+        ..
+    } = rctx;
+    let work_item_id = candidate.work_item_id.as_str();
+
+    let upstream_comments = match tracker.fetch_comments(ctx, &candidate.upstream_ref).await {
+        Ok(c) => c,
+        Err(TrackerError::Unsupported(msg)) => {
+            debug!(work_item_id, %msg, "tracker has no comment API; recording comment baseline");
+            record_comment_baseline(rctx, &candidate);
+            return;
+        }
+        Err(e) => {
+            COMMENT_SYNC_FAILED.inc(metrics);
+            outcome.comment_sync_failed += 1;
+            warn!(work_item_id, error = %e, "fetch_comments failed; will retry next tick");
+            return;
+        }
+    };
+    let links = match work_db.reconciler_list_comment_links(work_item_id) {
+        Ok(l) => l,
+        Err(e) => {
+            warn!(work_item_id, error = %e, "reconciler_list_comment_links failed");
+            return;
+        }
+    };
+    let linked_upstream: HashSet<&str> = links.iter().map(|l| l.upstream_comment_id.as_str()).collect();
+    let mut linked_boss: HashSet<String> = links.iter().map(|l| l.work_comment_id.clone()).collect();
+
+    let mut complete = true;
+    for comment in &upstream_comments {
+        if linked_upstream.contains(comment.id.as_str()) || comment.body.trim().is_empty() {
+            continue;
+        }
+        let input = CreateCommentInput {
+            artifact_id: work_item_id.to_owned(),
+            anchor: CommentAnchor {
+                exact: "Imported from".to_owned(),
+                prefix: String::new(),
+                suffix: format!(" {}", candidate.upstream_url),
+            },
+            artifact_kind: "work_item".to_owned(),
+            author: format!("{}:{}", candidate.upstream_ref.kind, comment.author),
+            body: comment.body.clone(),
+            doc_version: candidate.doc_version.clone(),
+            plain_text_projection_version: 0,
+        };
+        match work_db.reconciler_import_upstream_comment(work_item_id, &comment.id, input) {
+            Ok(Some(imported)) => {
+                COMMENTS_IMPORTED.inc(metrics);
+                outcome.comments_imported += 1;
+                linked_boss.insert(imported.id);
+            }
+            Ok(None) => {}
+            Err(e) => {
+                complete = false;
+                warn!(work_item_id, upstream_comment_id = %comment.id, error = %e, "importing upstream comment failed");
+            }
+        }
+    }
+
+    let boss_comments = match work_db.list_comments("work_item", work_item_id, false) {
+        Ok(c) => c,
+        Err(e) => {
+            warn!(work_item_id, error = %e, "listing boss comments failed");
+            return;
+        }
+    };
+    for comment in &boss_comments {
+        if linked_boss.contains(&comment.id) {
+            continue;
+        }
+        let body = format!("> {}\n\n{}", comment.anchor.exact, comment.body);
+        match tracker.post_comment(ctx, &candidate.upstream_ref, &body).await {
+            Ok(posted) => {
+                COMMENTS_PUSHED.inc(metrics);
+                outcome.comments_pushed += 1;
+                if let Err(e) = work_db.reconciler_link_pushed_comment(work_item_id, &posted.id, &comment.id) {
+                    complete = false;
+                    warn!(work_item_id, work_comment_id = %comment.id, error = %e, "linking pushed comment failed");
+                }
+            }
+            Err(e) => {
+                complete = false;
+                COMMENT_SYNC_FAILED.inc(metrics);
+                outcome.comment_sync_failed += 1;
+                warn!(
+                    work_item_id,
+                    work_comment_id = %comment.id,
+                    error = %e,
+                    "post_comment failed; will retry next tick"
+                );
+            }
+        }
+    }
+
+    if complete {
+        record_comment_baseline(rctx, &candidate);
+    }
+}
+
+fn record_comment_baseline(rctx: &ProductReconcileCtx<'_>, candidate: &CommentSyncCandidate) {
+    let work_item_id = candidate.work_item_id.as_str();
+    let recorded = boss_comment_fingerprint(rctx, work_item_id).and_then(|boss| {
+        rctx.work_db.reconciler_record_field_baseline(
+            work_item_id,
+            COMMENTS_FIELD,
+            &candidate.upstream_fingerprint,
+            &boss,
+        )
+    });
+    if let Err(e) = recorded {
+        warn!(work_item_id, error = %e, "recording comment sync baseline failed");
+    }
+}
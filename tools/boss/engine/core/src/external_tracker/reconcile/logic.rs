@@ -24,6 +24,7 @@ use crate::external_tracker::{
 use crate::metrics::Registry;
 use crate::work::{TaskStatus, WorkDb, content_checksum};
 
+use super::field_sync::{self, FieldSyncCandidates, SyncConfig, parse_sync_config};
 use super::{
     CLOSED, FETCH_FAILED, FETCH_SUCCEEDED, IMPORTED, IN_PROGRESS_SET_FAILED, IN_PROGRESS_SET_SUCCEEDED, PR_ATTACHED,
     PR_MERGE_CLOSE_FAILED, PR_MERGE_CLOSE_SUCCEEDED, PassOutcome, REVERSE_CLOSE_FAILED, REVERSE_CLOSE_SUCCEEDED,
@@ -68,25 +69,29 @@ struct LabelCandidate {
 /// these keeps the helper signatures under clippy's argument-count
 /// threshold and avoids repeating the same handle list at every call site.
 #[derive(bon::Builder)]
-struct ProductReconcileCtx<'a> {
-    work_db: &'a WorkDb,
-    tracker: &'a dyn ExternalTracker,
-    ctx: &'a TrackerContext,
-    product_id: &'a str,
-    reverse_close: bool,
-    in_progress_column: &'a str,
-    metrics: &'a Registry,
-    publisher: &'a dyn WorkInvalidationPublisher,
+pub(super) struct ProductReconcileCtx<'a> {
+    pub(super) work_db: &'a WorkDb,
+    pub(super) tracker: &'a dyn ExternalTracker,
+    pub(super) ctx: &'a TrackerContext,
+    pub(super) product_id: &'a str,
+    pub(super) reverse_close: bool,
+    pub(super) in_progress_column: &'a str,
+    pub(super) metrics: &'a Registry,
+    pub(super) publisher: &'a dyn WorkInvalidationPublisher,
+    /// Two-way field/comment sync settings (see [`super::field_sync`]).
+    pub(super) sync: &'a SyncConfig,
 }
 
 /// Mutable accumulators for the deferred upstream API calls a reconcile pass
-/// queues (close, in-progress move, label-add). Populated per item, then
-/// drained after the Boss-side SQL for the whole product has committed.
+/// queues (close, in-progress move, label-add, two-way sync pushes).
+/// Populated per item, then drained after the Boss-side SQL for the whole
+/// product has committed.
 #[derive(Default)]
 struct ReconcileCandidates {
     close: Vec<CloseCandidate>,
     in_progress: Vec<InProgressCandidate>,
     label: Vec<LabelCandidate>,
+    field_sync: FieldSyncCandidates,
 }
 
 pub(super) async fn process_product(
@@ -103,6 +108,12 @@ pub(super) async fn process_product(
         .as_str()
         .unwrap_or("In Progress")
         .to_owned();
+    // Validated at bind time; a config that somehow fails to parse here
+    // just leaves the product one-way rather than skipping it.
+    let sync = parse_sync_config(&ctx.config).unwrap_or_else(|e| {
+        warn!(product_id, error = %e, "invalid external tracker sync config; two-way sync disabled");
+        SyncConfig::default()
+    });
 
     // ── 1. Fetch upstream items ───────────────────────────────────────────────
     let upstream_items = match tracker.fetch_items(ctx).await {
@@ -216,6 +227,7 @@ pub(super) async fn process_product(
         in_progress_column: &in_progress_column,
         metrics,
         publisher,
+        sync: &sync,
     };
     let mut candidates = ReconcileCandidates::default();
 
@@ -478,6 +490,9 @@ pub(super) async fn process_product(
             }
         }
     }
+
+    // ── 8. Push boss-side field edits and sync comments (two-way sync) ───────
+    field_sync::drain_candidates(&rctx, candidates.field_sync, outcome).await;
 }
 
 // ── Per-item helpers ──────────────────────────────────────────────────────────
@@ -518,6 +533,7 @@ async fn reconcile_existing(
         close: close_candidates,
         in_progress: in_progress_candidates,
         label: label_candidates,
+        field_sync: field_sync_candidates,
     } = candidates;
 
     let work_item_id = &task.id;
@@ -660,86 +676,97 @@ async fn reconcile_existing(
     //   • Both sides changed → warn and emit a metric; operator must reconcile.
     //   • No baseline (pre-migration import) → establish baseline silently.
     //   • Only boss changed → operator edit; leave it alone.
-    match work_db.reconciler_get_content_checksums(work_item_id) {
-        Err(e) => {
-            warn!(work_item_id, error = %e, "reconciler_get_content_checksums failed (Behavior 8)");
-        }
-        Ok(None) => {
-            // Pre-migration item: no baseline yet. Record checksums of the
-            // current upstream and boss content without auto-syncing (we can't
-            // tell if the boss side has been edited since import).
-            if let Err(e) = work_db.reconciler_set_content_checksums_baseline(
-                work_item_id,
-                &upstream.title,
-                &upstream.body,
-                &task.name,
-                &task.description,
-            ) {
-                warn!(
-                    work_item_id,
-                    error = %e,
-                    "reconciler_set_content_checksums_baseline failed (Behavior 8)"
-                );
+    //
+    // Skipped when the product's two-way `sync` config covers title or
+    // description: the per-field path below owns that drift instead.
+    if !rctx.sync.owns_title_or_description() {
+        match work_db.reconciler_get_content_checksums(work_item_id) {
+            Err(e) => {
+                warn!(work_item_id, error = %e, "reconciler_get_content_checksums failed (Behavior 8)");
             }
-        }
-        Ok(Some((stored_upstream_checksum, stored_boss_checksum))) => {
-            let current_upstream_checksum = content_checksum(&upstream.title, &upstream.body);
-            let upstream_changed = current_upstream_checksum != stored_upstream_checksum;
-
-            if upstream_changed {
-                // Check whether the boss side has diverged from the last-synced baseline.
-                let current_boss_checksum = content_checksum(&task.name, &task.description);
-                let boss_changed = current_boss_checksum != stored_boss_checksum;
-
-                if !boss_changed {
-                    // Only the upstream changed → auto-sync name and description.
-                    let new_name = upstream.title.clone();
-                    let new_desc = format!("> Imported from {}\n\n{}", upstream.upstream_url, upstream.body);
-                    match work_db.reconciler_update_name_and_description(
-                        work_item_id,
-                        &new_name,
-                        &new_desc,
-                        &upstream.title,
-                        &upstream.body,
-                    ) {
-                        Ok(true) => {
-                            TITLE_BODY_SYNCED.inc(metrics);
-                            outcome.title_body_synced += 1;
-                            info!(
-                                work_item_id,
-                                canonical_id = %upstream.upstream_ref.canonical_id,
-                                "Behavior 8: upstream title/body changed → boss row auto-synced"
-                            );
-                            publisher
-                                .publish_work_item_invalidated(product_id, work_item_id, "chore_updated")
-                                .await;
-                        }
-                        Ok(false) => {}
-                        Err(e) => {
-                            warn!(
-                                work_item_id,
-                                error = %e,
-                                "reconciler_update_name_and_description failed (Behavior 8)"
-                            );
-                        }
-                    }
-                } else {
-                    // Both sides changed → flag for operator attention, preserve boss edits.
-                    TITLE_BODY_CONFLICT.inc(metrics);
-                    outcome.title_body_conflict += 1;
+            Ok(None) => {
+                // Pre-migration item: no baseline yet. Record checksums of the
+                // current upstream and boss content without auto-syncing (we can't
+                // tell if the boss side has been edited since import).
+                if let Err(e) = work_db.reconciler_set_content_checksums_baseline(
+                    work_item_id,
+                    &upstream.title,
+                    &upstream.body,
+                    &task.name,
+                    &task.description,
+                ) {
                     warn!(
                         work_item_id,
-                        canonical_id = %upstream.upstream_ref.canonical_id,
-                        upstream_title = %upstream.title,
-                        boss_name = %task.name,
-                        "Behavior 8: upstream title/body drift detected but boss side was also \
-                         edited — skipping auto-sync; operator must reconcile manually"
+                        error = %e,
+                        "reconciler_set_content_checksums_baseline failed (Behavior 8)"
                     );
                 }
             }
+            Ok(Some((stored_upstream_checksum, stored_boss_checksum))) => {
+                let current_upstream_checksum = content_checksum(&upstream.title, &upstream.body);
+                let upstream_changed = current_upstream_checksum != stored_upstream_checksum;
+
+                if upstream_changed {
+                    // Check whether the boss side has diverged from the last-synced baseline.
+                    let current_boss_checksum = content_checksum(&task.name, &task.description);
+                    let boss_changed = current_boss_checksum != stored_boss_checksum;
+
+                    if !boss_changed {
+                        // Only the upstream changed → auto-sync name and description.
+                        let new_name = upstream.title.clone();
+                        let new_desc = format!("> Imported from {}\n\n{}", upstream.upstream_url, upstream.body);
+                        match work_db.reconciler_update_name_and_description(
+                            work_item_id,
+                            &new_name,
+                            &new_desc,
+                            &upstream.title,
+                            &upstream.body,
+                        ) {
+                            Ok(true) => {
+                                TITLE_BODY_SYNCED.inc(metrics);
+                                outcome.title_body_synced += 1;
+                                info!(
+                                    work_item_id,
+                                    canonical_id = %upstream.upstream_ref.canonical_id,
+                                    "Behavior 8: upstream title/body changed → boss row auto-synced"
+                                );
+                                publisher
+                                    .publish_work_item_invalidated(product_id, work_item_id, "chore_updated")
+                                    .await;
+                            }
+                            Ok(false) => {}
+                            Err(e) => {
+                                warn!(
+                                    work_item_id,
+                                    error = %e,
+                                    "reconciler_update_name_and_description failed (Behavior 8)"
+                                );
+                            }
+                        }
+                    } else {
+                        // Both sides changed → flag for operator attention, preserve boss edits.
+                        TITLE_BODY_CONFLICT.inc(metrics);
+                        outcome.title_body_conflict += 1;
+                        warn!(
+                            work_item_id,
+                            canonical_id = %upstream.upstream_ref.canonical_id,
+                            upstream_title = %upstream.title,
+                            boss_name = %task.name,
+                            "Behavior 8: upstream title/body drift detected but boss side was also \
+                             edited — skipping auto-sync; operator must reconcile manually"
+                        );
+                    }
+                }
+            }
         }
     }
 
+    // Two-way sync of the fields and comments listed in the product's
+    // `sync` config (opt-in; see `field_sync`).
+    if !rctx.sync.is_empty() {
+        field_sync::reconcile_fields(rctx, task, upstream, field_sync_candidates, outcome).await;
+    }
+
     // Bump synced_at every successful reconcile.
     if let Err(e) = work_db.touch_external_ref_synced_at(work_item_id) {
         warn!(work_item_id, error = %e, "touch_external_ref_synced_at failed");
@@ -814,9 +841,21 @@ async fn import_new(rctx: &ProductReconcileCtx<'_>, upstream: &UpstreamItem, out
         warn!(work_item_id = %chore.id, error = %e, "reconciler_attach_pr_url failed after import");
     }
 
+    // Two-way sync: the new row starts out agreeing with upstream on every
+    // synced field.
+    field_sync::seed_imported_fields(rctx, &chore.id, upstream);
+
     publisher
         .publish_work_item_invalidated(product_id, &chore.id, "chore_created")
         .await;
+    // For `upstream_label` automations. Published after the import committed,
+    // so an automation that fires on it always finds the chore.
+    work_db.event_bus().publish(boss_event_bus::Event::UpstreamItemImported {
+        product_id: product_id.to_owned(),
+        work_item_id: chore.id.clone(),
+        upstream_url: upstream.upstream_url.clone(),
+        labels: upstream.labels.clone(),
+    });
 
     IMPORTED.inc(metrics);
     outcome.items_imported += 1;
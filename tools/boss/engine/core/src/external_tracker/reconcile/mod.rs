@@ -22,8 +22,11 @@ use super::{TrackerContext, TrackerCredential, TrackerRegistry};
 use crate::metrics::Registry;
 use crate::work::WorkDb;
 
+mod field_sync;
 mod logic;
 
+pub(crate) use field_sync::parse_sync_config;
+
 // ── Work-invalidation publisher ───────────────────────────────────────────────
 
 /// Sink for work-invalidation broadcasts emitted by the reconciler.
@@ -139,6 +142,41 @@ crate::register_counter!(
     "external_tracker.title_body_conflict",
     "Upstream title/body drift skipped because the Boss side was also edited since import — operator must reconcile (Behavior 8).",
 );
+crate::register_counter!(
+    FIELD_SYNCED,
+    "external_tracker.field_synced",
+    "Synced fields whose upstream edit was applied to the Boss work item (two-way sync).",
+);
+crate::register_counter!(
+    FIELD_PUSHED,
+    "external_tracker.field_pushed",
+    "Synced fields whose Boss edit was pushed upstream via update_fields (two-way sync).",
+);
+crate::register_counter!(
+    FIELD_PUSH_FAILED,
+    "external_tracker.field_push_failed",
+    "update_fields calls that failed; the push is retried next tick (two-way sync).",
+);
+crate::register_counter!(
+    FIELD_CONFLICT,
+    "external_tracker.field_conflict",
+    "Synced fields edited on both sides since the last sync, resolved by the field's conflict policy (two-way sync).",
+);
+crate::register_counter!(
+    COMMENTS_IMPORTED,
+    "external_tracker.comments_imported",
+    "Upstream comments imported as Boss work-item comments (two-way sync).",
+);
+crate::register_counter!(
+    COMMENTS_PUSHED,
+    "external_tracker.comments_pushed",
+    "Boss work-item comments posted to the upstream item (two-way sync).",
+);
+crate::register_counter!(
+    COMMENT_SYNC_FAILED,
+    "external_tracker.comment_sync_failed",
+    "fetch_comments/post_comment calls that failed; the comment sync is retried next tick (two-way sync).",
+);
 
 /// Label that the reconciler attaches to upstream items it has imported,
 /// so users browsing the upstream tracker can see which issues Boss mirrors.
@@ -165,6 +203,13 @@ pub fn register_metrics(registry: &Registry) {
     registry.register_counter(&TRACKED_LABEL_ATTACH_FAILED);
     registry.register_counter(&TITLE_BODY_SYNCED);
     registry.register_counter(&TITLE_BODY_CONFLICT);
+    registry.register_counter(&FIELD_SYNCED);
+    registry.register_counter(&FIELD_PUSHED);
+    registry.register_counter(&FIELD_PUSH_FAILED);
+    registry.register_counter(&FIELD_CONFLICT);
+    registry.register_counter(&COMMENTS_IMPORTED);
+    registry.register_counter(&COMMENTS_PUSHED);
+    registry.register_counter(&COMMENT_SYNC_FAILED);
 }
 
 // ── Outcome ───────────────────────────────────────────────────────────────────
@@ -200,6 +245,20 @@ pub struct PassOutcome {
     pub title_body_synced: usize,
     /// Behavior 8: items where upstream drift was skipped because boss side was also edited.
     pub title_body_conflict: usize,
+    /// Two-way sync: fields whose upstream edit was applied to the boss row.
+    pub fields_synced: usize,
+    /// Two-way sync: fields whose boss edit was pushed upstream.
+    pub fields_pushed: usize,
+    /// Two-way sync: update_fields calls that failed.
+    pub field_push_failed: usize,
+    /// Two-way sync: fields edited on both sides, resolved by policy.
+    pub field_conflicts: usize,
+    /// Two-way sync: upstream comments imported into Boss.
+    pub comments_imported: usize,
+    /// Two-way sync: boss comments posted upstream.
+    pub comments_pushed: usize,
+    /// Two-way sync: fetch_comments/post_comment calls that failed.
+    pub comment_sync_failed: usize,
 }
 
 // ── Public entry point ────────────────────────────────────────────────────────
@@ -321,6 +380,13 @@ pub fn spawn_loop(
                 || outcome.tracked_label_attach_failed > 0
                 || outcome.title_body_synced > 0
                 || outcome.title_body_conflict > 0
+                || outcome.fields_synced > 0
+                || outcome.fields_pushed > 0
+                || outcome.field_push_failed > 0
+                || outcome.field_conflicts > 0
+                || outcome.comments_imported > 0
+                || outcome.comments_pushed > 0
+                || outcome.comment_sync_failed > 0
             {
                 tracing::info!(
                     products_processed = outcome.products_processed,
@@ -337,6 +403,13 @@ pub fn spawn_loop(
                     tracked_label_attach_failed = outcome.tracked_label_attach_failed,
                     title_body_synced = outcome.title_body_synced,
                     title_body_conflict = outcome.title_body_conflict,
+                    fields_synced = outcome.fields_synced,
+                    fields_pushed = outcome.fields_pushed,
+                    field_push_failed = outcome.field_push_failed,
+                    field_conflicts = outcome.field_conflicts,
+                    comments_imported = outcome.comments_imported,
+                    comments_pushed = outcome.comments_pushed,
+                    comment_sync_failed = outcome.comment_sync_failed,
                     "external tracker reconciler: pass complete",
                 );
             }
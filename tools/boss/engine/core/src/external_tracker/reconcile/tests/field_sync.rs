@@ -0,0 +1,262 @@
+//! Two-way field and comment sync (`sync` tracker config) tests.
+
+use boss_protocol::{CommentAnchor, CreateCommentInput, WorkItemPatch};
+
+use super::*;
+
+fn setup_product_with_sync(db: &WorkDb, sync: serde_json::Value) -> boss_protocol::Product {
+    let product = create_test_product_named(db, "Sync Product");
+    db.set_product_external_tracker(
+        &product.id,
+        Some("spy"),
+        Some(&json!({ "kind": "spy", "sync": sync })),
+        false,
+    )
+    .expect("set external tracker with sync");
+    product
+}
+
+/// Run one pass against `tracker`, handing it back so its call logs can be
+/// inspected.
+async fn tick(db: &WorkDb, tracker: Arc<SpyTracker>) -> (PassOutcome, Arc<SpyTracker>) {
+    let registry = spy_registry(Arc::clone(&tracker));
+    let metrics = Registry::new();
+    register_metrics(&metrics);
+    let outcome = run_one_pass(db, &registry, &metrics, &noop_pub(), &ambient_resolver()).await;
+    (outcome, tracker)
+}
+
+fn rename(db: &WorkDb, id: &str, name: &str) {
+    db.update_task(
+        id,
+        WorkItemPatch {
+            name: Some(name.to_owned()),
+            ..Default::default()
+        },
+        "human",
+    )
+    .expect("update_task");
+}
+
+#[tokio::test]
+async fn import_adopts_upstream_priority_and_assignee() {
+    let db = in_memory_db();
+    setup_product_with_sync(&db, json!({ "priority": "upstream_wins", "assignee": "upstream_wins" }));
+    let item = UpstreamItem {
+        priority: Some("high".to_owned()),
+        assignees: vec!["octocat".to_owned()],
+        ..open_item(1, "Prioritised")
+    };
+
+    let (outcome, _) = tick(&db, SpyTracker::new(vec![item.clone()])).await;
+    assert_eq!(outcome.items_imported, 1);
+
+    let task = db.find_by_external_ref("spy", "spy#1").unwrap().unwrap();
+    assert_eq!(task.priority, "high");
+    let states = db.reconciler_get_field_sync_states(&task.id).unwrap();
+    assert_eq!(states["assignee"].boss_value.as_deref(), Some("octocat"));
+
+    // A second tick with nothing changed is a no-op.
+    let (outcome, tracker) = tick(&db, SpyTracker::new(vec![item])).await;
+    assert_eq!(outcome.fields_synced, 0);
+    assert!(tracker.update_fields_calls().is_empty());
+}
+
+/// With title under two-way sync, an upstream-only edit goes through the
+/// per-field path and Behavior 8 stays out of it.
+#[tokio::test]
+async fn upstream_title_edit_applied_per_field() {
+    let db = in_memory_db();
+    setup_product_with_sync(&db, json!({ "title": "upstream_wins" }));
+    tick(&db, SpyTracker::new(vec![open_item(2, "Original")])).await;
+
+    let (outcome, _) = tick(&db, SpyTracker::new(vec![open_item(2, "Renamed upstream")])).await;
+
+    assert_eq!(outcome.fields_synced, 1);
+    assert_eq!(outcome.title_body_synced, 0, "Behavior 8 must not also fire");
+    let task = db.find_by_external_ref("spy", "spy#2").unwrap().unwrap();
+    assert_eq!(task.name, "Renamed upstream");
+}
+
+/// A Boss-only edit is pushed upstream once; when upstream reflects it on
+/// the next fetch nothing further happens.
+#[tokio::test]
+async fn boss_title_edit_pushed_upstream_once() {
+    let db = in_memory_db();
+    setup_product_with_sync(&db, json!({ "title": "upstream_wins" }));
+    tick(&db, SpyTracker::new(vec![open_item(3, "Original")])).await;
+    let task = db.find_by_external_ref("spy", "spy#3").unwrap().unwrap();
+    rename(&db, &task.id, "Operator title");
+
+    let (outcome, tracker) = tick(&db, SpyTracker::new(vec![open_item(3, "Original")])).await;
+    assert_eq!(outcome.fields_pushed, 1);
+    let calls = tracker.update_fields_calls();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].0, "spy#3");
+    assert_eq!(
+        calls[0].1,
+        UpstreamFieldUpdate {
+            title: Some("Operator title".to_owned()),
+            ..Default::default()
+        }
+    );
+
+    let (outcome, tracker) = tick(&db, SpyTracker::new(vec![open_item(3, "Operator title")])).await;
+    assert_eq!(
+        outcome.fields_pushed + outcome.fields_synced + outcome.field_conflicts,
+        0
+    );
+    assert!(tracker.update_fields_calls().is_empty());
+}
+
+/// Both sides edited: the field's policy decides, and the conflict is
+/// counted either way.
+#[tokio::test]
+async fn conflict_resolved_by_policy() {
+    for (policy, upstream_updated_at, expect_push) in [
+        ("upstream_wins", 0, false),
+        ("boss_wins", 0, true),
+        // Upstream edited long after the Boss edit → upstream wins.
+        ("last_writer_wins", 4_000_000_000, false),
+        // Upstream timestamp predates the Boss edit → Boss wins.
+        ("last_writer_wins", 1, true),
+    ] {
+        let db = in_memory_db();
+        setup_product_with_sync(&db, json!({ "title": policy }));
+        tick(&db, SpyTracker::new(vec![open_item(4, "Original")])).await;
+        let task = db.find_by_external_ref("spy", "spy#4").unwrap().unwrap();
+        rename(&db, &task.id, "Boss title");
+
+        let item = UpstreamItem {
+            updated_at: upstream_updated_at,
+            ..open_item(4, "Upstream title")
+        };
+        let (outcome, tracker) = tick(&db, SpyTracker::new(vec![item])).await;
+
+        assert_eq!(outcome.field_conflicts, 1, "{policy}");
+        let task = db.find_by_external_ref("spy", "spy#4").unwrap().unwrap();
+        if expect_push {
+            assert_eq!(tracker.update_fields_calls().len(), 1, "{policy}");
+            assert_eq!(task.name, "Boss title", "{policy}");
+        } else {
+            assert!(tracker.update_fields_calls().is_empty(), "{policy}");
+            assert_eq!(task.name, "Upstream title", "{policy}");
+        }
+    }
+}
+
+/// A tracker that can't write the field re-baselines instead of retrying
+/// every tick; a transient failure is retried.
+#[tokio::test]
+async fn push_unsupported_is_not_retried_but_transient_is() {
+    let db = in_memory_db();
+    setup_product_with_sync(&db, json!({ "priority": "upstream_wins" }));
+    let item = UpstreamItem {
+        priority: Some("low".to_owned()),
+        ..open_item(5, "Prio")
+    };
+    tick(&db, SpyTracker::new(vec![item.clone()])).await;
+    let task = db.find_by_external_ref("spy", "spy#5").unwrap().unwrap();
+    db.update_task(
+        &task.id,
+        WorkItemPatch {
+            priority: Some("high".to_owned()),
+            ..Default::default()
+        },
+        "human",
+    )
+    .unwrap();
+
+    let tracker = SpyTracker::new(vec![item.clone()]);
+    tracker.push_update_fields_error(TrackerError::Transient("503".to_owned()));
+    let (outcome, _) = tick(&db, tracker).await;
+    assert_eq!(outcome.field_push_failed, 1);
+
+    let tracker = SpyTracker::new(vec![item.clone()]);
+    tracker.push_update_fields_error(TrackerError::Unsupported("no priority".to_owned()));
+    let (outcome, tracker) = tick(&db, tracker).await;
+    assert_eq!(tracker.update_fields_calls().len(), 1, "transient failure is retried");
+    assert_eq!(outcome.field_push_failed, 0);
+    assert_eq!(outcome.fields_pushed, 0);
+
+    let (_, tracker) = tick(&db, SpyTracker::new(vec![item])).await;
+    assert!(
+        tracker.update_fields_calls().is_empty(),
+        "unsupported push is not retried"
+    );
+    let task = db.find_by_external_ref("spy", "spy#5").unwrap().unwrap();
+    assert_eq!(task.priority, "high", "boss value is kept locally");
+}
+
+/// Upstream comments are imported once and Boss comments are posted once,
+/// with no mirroring of a mirrored comment.
+#[tokio::test]
+async fn comments_mirrored_both_ways_once() {
+    let db = in_memory_db();
+    setup_product_with_sync(&db, json!({ "comments": true }));
+    tick(&db, SpyTracker::new(vec![open_item(6, "Discussed")])).await;
+    let task = db.find_by_external_ref("spy", "spy#6").unwrap().unwrap();
+
+    db.create_comment(CreateCommentInput {
+        artifact_id: task.id.clone(),
+        anchor: CommentAnchor {
+            exact: "Body of issue 6".to_owned(),
+            prefix: String::new(),
+            suffix: String::new(),
+        },
+        artifact_kind: "work_item".to_owned(),
+        author: "human".to_owned(),
+        body: "Can we split this?".to_owned(),
+        doc_version: "v1".to_owned(),
+        plain_text_projection_version: 0,
+    })
+    .unwrap();
+
+    let item = UpstreamItem {
+        updated_at: 10,
+        ..open_item(6, "Discussed")
+    };
+    let tracker = SpyTracker::new(vec![item.clone()]);
+    tracker.with_upstream_comments(vec![UpstreamComment {
+        id: "u1".to_owned(),
+        author: "pm".to_owned(),
+        body: "Please prioritise".to_owned(),
+        created_at: 5,
+    }]);
+    let (outcome, tracker) = tick(&db, tracker).await;
+
+    assert_eq!(outcome.comments_imported, 1);
+    assert_eq!(outcome.comments_pushed, 1);
+    assert_eq!(
+        tracker.posted_comments(),
+        vec![("spy#6".to_owned(), "> Body of issue 6\n\nCan we split this?".to_owned())]
+    );
+    let comments = db.list_comments("work_item", &task.id, false).unwrap();
+    let imported = comments
+        .iter()
+        .find(|c| c.body == "Please prioritise")
+        .expect("upstream comment imported");
+    assert_eq!(imported.author, "spy:pm");
+
+    // Same thread again (the posted comment now shows up upstream too).
+    let tracker = SpyTracker::new(vec![UpstreamItem { updated_at: 11, ..item }]);
+    tracker.with_upstream_comments(vec![
+        UpstreamComment {
+            id: "u1".to_owned(),
+            author: "pm".to_owned(),
+            body: "Please prioritise".to_owned(),
+            created_at: 5,
+        },
+        UpstreamComment {
+            id: "posted-1".to_owned(),
+            author: "boss-bot".to_owned(),
+            body: "> Body of issue 6\n\nCan we split this?".to_owned(),
+            created_at: 6,
+        },
+    ]);
+    let (outcome, tracker) = tick(&db, tracker).await;
+    assert_eq!(outcome.comments_imported, 0);
+    assert_eq!(outcome.comments_pushed, 0);
+    assert!(tracker.posted_comments().is_empty());
+    assert_eq!(db.list_comments("work_item", &task.id, false).unwrap().len(), 2);
+}
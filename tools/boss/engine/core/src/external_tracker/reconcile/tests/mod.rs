@@ -16,8 +16,8 @@ use serde_json::json;
 
 use super::*;
 use crate::external_tracker::{
-    CloseReason, ExternalTracker, TrackerConfigError, TrackerContext, TrackerError, TrackerRegistry, UpstreamItem,
-    UpstreamPrAssociation, UpstreamRef, UpstreamStatus,
+    CloseReason, ExternalTracker, TrackerConfigError, TrackerContext, TrackerError, TrackerRegistry, UpstreamComment,
+    UpstreamFieldUpdate, UpstreamItem, UpstreamPrAssociation, UpstreamRef, UpstreamStatus,
 };
 use crate::metrics::Registry;
 use crate::test_support::*;
@@ -105,6 +105,14 @@ impl<Rec: Clone> SpyChannel<Rec> {
     }
 }
 
+/// Upstream comment thread served by [`SpyTracker::fetch_comments`], plus
+/// a log of `(canonical_id, body)` for every `post_comment`.
+#[derive(Default)]
+struct SpyComments {
+    upstream: Mutex<Vec<UpstreamComment>>,
+    posted: Mutex<Vec<(String, String)>>,
+}
+
 /// Test double: records `close_issue` and `set_project_status` calls and
 /// returns pre-configured responses.  `fetch_items` returns the item list
 /// unless a fetch error has been queued via `push_fetch_error`.
@@ -114,6 +122,8 @@ struct SpyTracker {
     close: SpyChannel<String>,
     set_project_status: SpyChannel<String>,
     add_label: SpyChannel<(String, String)>,
+    update_fields: SpyChannel<(String, UpstreamFieldUpdate)>,
+    comments: SpyComments,
 }
 
 impl SpyTracker {
@@ -124,6 +134,8 @@ impl SpyTracker {
             close: SpyChannel::new(),
             set_project_status: SpyChannel::new(),
             add_label: SpyChannel::new(),
+            update_fields: SpyChannel::new(),
+            comments: SpyComments::default(),
         })
     }
 
@@ -197,6 +209,24 @@ impl SpyTracker {
     fn add_label_calls(&self) -> Vec<(String, String)> {
         self.add_label.calls()
     }
+
+    fn push_update_fields_error(self: &Arc<Self>, err: TrackerError) -> &Arc<Self> {
+        self.update_fields.queue(Err(err));
+        self
+    }
+
+    fn update_fields_calls(&self) -> Vec<(String, UpstreamFieldUpdate)> {
+        self.update_fields.calls()
+    }
+
+    fn with_upstream_comments(self: &Arc<Self>, comments: Vec<UpstreamComment>) -> &Arc<Self> {
+        *self.comments.upstream.lock().unwrap() = comments;
+        self
+    }
+
+    fn posted_comments(&self) -> Vec<(String, String)> {
+        self.comments.posted.lock().unwrap().clone()
+    }
 }
 
 #[async_trait]
@@ -249,6 +279,39 @@ impl ExternalTracker for SpyTracker {
     ) -> crate::external_tracker::Result<()> {
         self.add_label.record((ref_.canonical_id.clone(), label.to_owned()))
     }
+
+    async fn fetch_comments(
+        &self,
+        _ctx: &TrackerContext,
+        _ref_: &UpstreamRef,
+    ) -> crate::external_tracker::Result<Vec<UpstreamComment>> {
+        Ok(self.comments.upstream.lock().unwrap().clone())
+    }
+
+    async fn post_comment(
+        &self,
+        _ctx: &TrackerContext,
+        ref_: &UpstreamRef,
+        body: &str,
+    ) -> crate::external_tracker::Result<UpstreamComment> {
+        let mut posted = self.comments.posted.lock().unwrap();
+        posted.push((ref_.canonical_id.clone(), body.to_owned()));
+        Ok(UpstreamComment {
+            id: format!("posted-{}", posted.len()),
+            author: "boss-bot".to_owned(),
+            body: body.to_owned(),
+            created_at: 0,
+        })
+    }
+
+    async fn update_fields(
+        &self,
+        _ctx: &TrackerContext,
+        ref_: &UpstreamRef,
+        update: &UpstreamFieldUpdate,
+    ) -> crate::external_tracker::Result<()> {
+        self.update_fields.record((ref_.canonical_id.clone(), update.clone()))
+    }
 }
 
 // ── Helpers ───────────────────────────────────────────────────────────────
@@ -302,6 +365,7 @@ fn open_item(id: u64, title: &str) -> UpstreamItem {
         pr_associations: vec![],
         updated_at: 0,
         project_status: None,
+        priority: None,
     }
 }
 
@@ -347,6 +411,7 @@ fn setup_product_with_reverse_close(db: &WorkDb) -> boss_protocol::Product {
 }
 
 mod attention;
+mod field_sync;
 mod imports;
 mod pass_runner;
 mod pick_best_pr;
@@ -17,6 +17,11 @@
 //! PR-URL transition over the forwarded socket. Coordinator host-selection
 //! / routing (PR 3) and live-status + transcript readback (PR 4) build on
 //! top. The trait stays stable across local and remote.
+//!
+//! A third implementation, [`crate::container_host::ContainerHostAdapter`],
+//! runs workers in a rootless container on the engine's own machine over a
+//! local cube lease; [`SshHostAdapterProvider`] builds it for any host row
+//! that carries a `container_runtime`.
 
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
@@ -28,6 +33,9 @@ use async_trait::async_trait;
 use tokio::sync::Mutex;
 
 use crate::config::RuntimeConfig;
+use crate::container_host::{
+    CommandContainerCli, ContainerCli, ContainerHostAdapter, ContainerRuntime, default_container_state_dir,
+};
 use crate::coordinator::{
     CubeChangeHandle, CubeClient, CubeRepoHandle, CubeRepoSummary, CubeWorkspaceLease, CubeWorkspaceStatus,
 };
@@ -60,7 +68,7 @@ const REMOTE_BOSS_EVENT_BIN: &str = "boss-event";
 /// Paths for engine-owned files in a remote driver's workspace config
 /// directory. Keeping the prompt and ignore file together prevents the
 /// prompt from appearing in the worker's change.
-fn remote_driver_config_paths(
+pub(crate) fn remote_driver_config_paths(
     workspace: &str,
     descriptor: &crate::driver::DriverDescriptor,
 ) -> (String, String, String) {
@@ -76,7 +84,7 @@ fn remote_driver_config_paths(
 ///
 /// `host_id = "local"` is the special case for the coordinator's own
 /// machine. Every other id corresponds to a registered SSH-reachable
-/// remote (Phase 3+).
+/// remote (Phase 3+) or a local container host.
 #[async_trait]
 pub trait HostAdapter: Send + Sync {
     /// Stable host identifier (e.g. `"local"`, `"zakalwe"`).
@@ -1127,8 +1135,9 @@ impl HostAdapterProvider for LocalHostAdapterProvider {
     }
 }
 
-/// Production provider: returns the local adapter for `host_id = "local"`
-/// and builds (and caches) an [`SshHostAdapter`] for every other host.
+/// Production provider: returns the local adapter for `host_id = "local"`,
+/// builds (and caches) a [`ContainerHostAdapter`] for a container host, and
+/// an [`SshHostAdapter`] for every other host.
 ///
 /// Each remote host gets one `ControlMaster` connection, opened on first
 /// use and reused for the engine's lifetime — matching the SSH-transport
@@ -1152,6 +1161,14 @@ pub struct SshHostAdapterProvider {
     events_socket_path: PathBuf,
     /// Engine-owned directory holding the per-host `ControlMaster` sockets.
     control_socket_dir: PathBuf,
+    /// Runtime CLI behind every [`ContainerHostAdapter`].
+    #[builder(default = Arc::new(CommandContainerCli))]
+    container_cli: Arc<dyn ContainerCli>,
+    /// Root for container-host homes and per-run dirs; `None` (no state
+    /// root) makes container hosts unbuildable rather than guessing a path.
+    /// [`Self::new`] uses [`default_container_state_dir`]; builder callers
+    /// pass it explicitly.
+    container_state_dir: Option<PathBuf>,
     /// Lazily-built remote adapters, one per host id.
     #[builder(default = Mutex::new(HashMap::new()))]
     cache: Mutex<HashMap<String, Arc<dyn HostAdapter>>>,
@@ -1173,9 +1190,34 @@ impl SshHostAdapterProvider {
             non_opus_auto_mode,
             events_socket_path,
             control_socket_dir,
+            container_cli: Arc::new(CommandContainerCli),
+            container_state_dir: default_container_state_dir(),
             cache: Mutex::new(HashMap::new()),
         }
     }
+
+    fn container_adapter(&self, host: &Host, runtime: &str) -> Result<Arc<dyn HostAdapter>> {
+        let runtime = ContainerRuntime::parse(runtime)?;
+        let state_dir = self
+            .container_state_dir
+            .clone()
+            .context("no engine state root; cannot place container-host state")?;
+        Ok(Arc::new(
+            ContainerHostAdapter::builder()
+                .host_id(host.id.clone())
+                .runtime(runtime)
+                .maybe_image(host.container_image.clone())
+                .local(Arc::clone(&self.local))
+                .cli(Arc::clone(&self.container_cli))
+                .work_db(Arc::clone(&self.work_db))
+                .cfg(Arc::clone(&self.cfg))
+                .non_opus_auto_mode(self.non_opus_auto_mode)
+                .events_socket_path(self.events_socket_path.clone())
+                .state_dir(state_dir)
+                .maybe_boss_event_binary(crate::runner::pane_spawn::default_boss_event_binary())
+                .build(),
+        ))
+    }
 }
 
 #[async_trait]
@@ -1190,6 +1232,14 @@ impl HostAdapterProvider for SshHostAdapterProvider {
             return Ok(Arc::clone(adapter));
         }
 
+        if let Some(runtime) = host.container_runtime.as_deref() {
+            let adapter = self
+                .container_adapter(host, runtime)
+                .with_context(|| format!("building container adapter for host '{}'", host.id))?;
+            cache.insert(host.id.clone(), Arc::clone(&adapter));
+            return Ok(adapter);
+        }
+
         let ssh_target = host
             .ssh_target
             .as_deref()
@@ -1221,7 +1271,7 @@ pub const REASON_HOST_DRIVER_UNSUPPORTED: &str = "host_driver_unsupported";
 /// the coordinator. Remote provisioning for those drivers is not implemented;
 /// descriptor opt-in keeps an added driver from silently inheriting unsafe
 /// remote eligibility.
-fn reject_host_local_remote_spawn_plan(
+pub(crate) fn reject_host_local_remote_spawn_plan(
     driver: &dyn crate::driver::AgentDriver,
     driver_slug: &str,
     host: &str,
@@ -1266,7 +1316,7 @@ async fn remote_home_dir(transport: &SshTransport) -> Result<String> {
 /// Checked on the driver's declared capability rather than its slug, so a
 /// future driver that DOES wire hooks into the settings file passes without
 /// this gate needing to learn its name.
-fn reject_unobservable_remote_driver(
+pub(crate) fn reject_unobservable_remote_driver(
     driver: &dyn crate::driver::AgentDriver,
     driver_slug: &str,
     settings_input: &WorkerSetupInput,
@@ -1338,6 +1388,10 @@ mod tests {
                 Arc::new(crate::driver::GrokDriver::default()) as Arc<dyn crate::driver::AgentDriver>,
                 "grok",
             ),
+            (
+                Arc::new(crate::driver::GeminiDriver::default()) as Arc<dyn crate::driver::AgentDriver>,
+                "gemini",
+            ),
         ] {
             // Guard the premise: if a driver ever starts wiring hooks into
             // the settings file, this test should stop asserting a refusal
@@ -1367,6 +1421,10 @@ mod tests {
                 Arc::new(crate::driver::GrokDriver::default()) as Arc<dyn crate::driver::AgentDriver>,
                 "grok",
             ),
+            (
+                Arc::new(crate::driver::GeminiDriver::default()) as Arc<dyn crate::driver::AgentDriver>,
+                "gemini",
+            ),
         ] {
             let err = reject_host_local_remote_spawn_plan(driver.as_ref(), slug, "host-1")
                 .expect_err("host-local plans must not be rendered on the engine for remote use");
@@ -28,6 +28,15 @@
 //! present. A host that has been probed always also carries
 //! [`DRIVERS_PROBED_CAPABILITY`] so "never probed for drivers" is
 //! distinguishable from "probed and found none".
+//!
+//! ## Container isolation
+//!
+//! A container host ([`crate::container_host`]) additionally advertises
+//! [`ISOLATION_CONTAINER_CAPABILITY`], its runtime
+//! (`container-runtime=<name>`), one `container-network=<policy>` tag per
+//! network policy it can enforce, and [`CONTAINER_LIMITS_CAPABILITY`] when
+//! it can enforce CPU / memory / pids limits. Requiring any of these on a
+//! product routes its work to isolated hosts only.
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
@@ -100,6 +109,29 @@ pub fn driver_capability(slug: &str) -> String {
 /// can name the distinction.
 pub const DRIVERS_PROBED_CAPABILITY: &str = "drivers-probed=true";
 
+/// Marker every container host carries: the worker runs inside a rootless
+/// container / sandbox rather than directly on a host's user session.
+/// Require it on a product to route risky work only to isolated hosts.
+pub const ISOLATION_CONTAINER_CAPABILITY: &str = "isolation=container";
+
+/// Marker for a container host that can enforce per-run CPU / memory /
+/// pids limits. Host selection requires it whenever the work carries any
+/// `container-cpus=` / `container-memory=` / `container-pids=` requirement
+/// (see [`crate::host_scheduling::ContainerLimits`]).
+pub const CONTAINER_LIMITS_CAPABILITY: &str = "container-limits=true";
+
+/// Capability key naming a container host's runtime.
+pub fn container_runtime_capability(runtime: &str) -> String {
+    format!("container-runtime={runtime}")
+}
+
+/// Capability key for a network policy a container host can enforce. A
+/// product requiring `container-network=none` only lands on hosts that can
+/// actually cut the worker off from the network.
+pub fn container_network_capability(policy: &str) -> String {
+    format!("container-network={policy}")
+}
+
 /// `(slug, binary)` pairs the driver probe checks. Source of truth is the
 /// engine's registered drivers — the candidate set is whatever this binary
 /// can launch, not a hand-maintained list that drifts when a driver is
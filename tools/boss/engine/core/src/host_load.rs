@@ -0,0 +1,521 @@
+//! Periodic host-load sampler and work-stealing trigger.
+//!
+//! [`crate::host_scheduling::select_host`] ranks eligible hosts by a score
+//! that blends free slots with live load, historical run duration, and the
+//! driver's remaining provider quota. This sweep keeps the inputs current:
+//!
+//! 1. **Load.** Every [`DEFAULT_INTERVAL`] each enabled host reports CPU
+//!    count, 1-minute load average, available memory, and free disk under
+//!    `$HOME` via [`LOAD_PROBE_SCRIPT`]. SSH hosts run it over their
+//!    existing ControlMaster; `local` and container hosts share the engine's
+//!    own machine, so one local sample stands for all of them. A failed
+//!    sample clears the host's row — a stale figure is worse than none —
+//!    and never touches the dispatch-health counter: a load probe is not a
+//!    dispatch.
+//! 2. **Quota.** Hosts on the engine's machine take the shared
+//!    [`boss_engine_driver_quota::QuotaCache`] readings. An SSH host with
+//!    `driver=claude` runs `claude -p /usage` under its own login at most
+//!    once per quota-cache TTL; other drivers' quota is only read locally.
+//! 3. **Work stealing.** [`ExecutionCoordinator::steal_parked_executions`]
+//!    re-places executions parked by a `no_eligible_host` backoff as soon as
+//!    a host would take them.
+//!
+//! Run duration needs no sampling: it is read straight from `work_runs` at
+//! selection time ([`WorkDb::mean_run_secs_per_host`]).
+//!
+//! With only the `local` host registered there is nothing to rank, so the
+//! sampling steps are skipped and single-machine installs keep their
+//! previous placement behaviour exactly.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result, bail};
+use boss_engine_driver_quota::QuotaCache;
+use boss_protocol::{DRIVER_SLUG_CLAUDE, DriverQuotaOutcome};
+
+use crate::coordinator::{ExecutionCoordinator, WorkStealOutcome};
+use crate::host_capability_probe::{RemoteRunner, driver_capability};
+use crate::host_registry::{Host, HostLoad};
+use crate::ssh_transport::SshTransport;
+use crate::work::WorkDb;
+
+/// How often the sweep runs. Matches the other host reconcilers.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Age past which a load sample no longer informs placement — three missed
+/// passes.
+pub const LOAD_SAMPLE_MAX_AGE: Duration = Duration::from_secs(180);
+
+/// Age past which a per-host quota reading no longer informs placement.
+/// Twice the quota cache TTL, so one slow refresh does not blank the figure.
+pub const DRIVER_QUOTA_MAX_AGE: Duration = Duration::from_secs(2 * boss_engine_driver_quota::DEFAULT_TTL.as_secs());
+
+/// Window of completed runs the per-host mean duration is taken over.
+pub const RUN_DURATION_WINDOW: Duration = Duration::from_secs(14 * 24 * 60 * 60);
+
+/// Wall-clock bound on the local load probe.
+const LOCAL_PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// POSIX `sh` script that prints one `key=value` line per figure it could
+/// read, on Linux (`/proc`) and macOS (`sysctl` / `vm_stat`). A figure it
+/// cannot read is printed empty or not at all; [`parse_load_probe`] treats
+/// both as unknown.
+pub const LOAD_PROBE_SCRIPT: &str = r#"echo "cpus=$(getconf _NPROCESSORS_ONLN 2>/dev/null || sysctl -n hw.ncpu 2>/dev/null)"
+if [ -r /proc/loadavg ]; then
+  echo "load1=$(cut -d' ' -f1 /proc/loadavg)"
+else
+  echo "load1=$(sysctl -n vm.loadavg 2>/dev/null | tr -d '{}' | awk '{print $1}')"
+fi
+if [ -r /proc/meminfo ]; then
+  echo "mem_available_kib=$(awk '/^MemAvailable:/ {print $2}' /proc/meminfo)"
+else
+  vm_stat 2>/dev/null | awk -v ps="$(sysctl -n hw.pagesize 2>/dev/null || echo 4096)" \
+    '/Pages (free|inactive|speculative):/ {gsub("\\.", "", $NF); n += $NF} END {if (n) print "mem_available_kib=" int(n * ps / 1024)}'
+fi
+echo "disk_free_kib=$(df -Pk "$HOME" 2>/dev/null | awk 'NR == 2 {print $4}')""#;
+
+/// Remote counterpart of the local `claude` quota probe: same arguments,
+/// same `/` working directory, same stripped API key (see
+/// [`boss_engine_driver_quota::probes::claude::CLAUDE_UNSET_ENV_VARS`]).
+fn remote_claude_usage_script() -> String {
+    let unset: Vec<String> = boss_engine_driver_quota::probes::claude::CLAUDE_UNSET_ENV_VARS
+        .iter()
+        .map(|key| format!("-u {key}"))
+        .collect();
+    format!(
+        "cd / && exec env {} claude -p /usage --output-format json",
+        unset.join(" ")
+    )
+}
+
+/// Parse [`LOAD_PROBE_SCRIPT`] output. Unknown keys and unparseable values
+/// are ignored rather than failing the whole sample.
+pub fn parse_load_probe(stdout: &str) -> HostLoad {
+    let mut load = HostLoad::default();
+    for line in stdout.lines() {
+        let Some((key, value)) = line.trim().split_once('=') else {
+            continue;
+        };
+        let value = value.trim();
+        match key {
+            "cpus" => load.cpu_count = value.parse::<u32>().ok().filter(|n| *n > 0),
+            "load1" => load.load_avg_1m = value.parse::<f64>().ok().filter(|l| l.is_finite() && *l >= 0.0),
+            "mem_available_kib" => load.mem_available_bytes = value.parse::<u64>().ok().map(|kib| kib * 1024),
+            "disk_free_kib" => load.disk_free_bytes = value.parse::<u64>().ok().map(|kib| kib * 1024),
+            _ => {}
+        }
+    }
+    load
+}
+
+/// Run [`LOAD_PROBE_SCRIPT`] on a remote host.
+pub async fn sample_remote_load(transport: &impl RemoteRunner) -> Result<HostLoad> {
+    let out = transport
+        .run(&["sh", "-c", LOAD_PROBE_SCRIPT])
+        .await
+        .with_context(|| format!("sampling load on host {}", transport.host_id()))?;
+    if !out.success() {
+        bail!(
+            "load probe on host {} exited {}: {}",
+            transport.host_id(),
+            out.status,
+            out.stderr.lines().next().unwrap_or("no stderr").trim()
+        );
+    }
+    let load = parse_load_probe(&out.stdout);
+    if load.is_empty() {
+        bail!("load probe on host {} reported no figures", transport.host_id());
+    }
+    Ok(load)
+}
+
+/// Run [`LOAD_PROBE_SCRIPT`] on the engine's own machine.
+pub async fn sample_local_load() -> Result<HostLoad> {
+    let mut command = tokio::process::Command::new("sh");
+    command
+        .arg("-c")
+        .arg(LOAD_PROBE_SCRIPT)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    let out = tokio::time::timeout(LOCAL_PROBE_TIMEOUT, command.output())
+        .await
+        .context("local load probe timed out")?
+        .context("running local load probe")?;
+    if !out.status.success() {
+        bail!("local load probe exited {}", out.status);
+    }
+    let load = parse_load_probe(&String::from_utf8_lossy(&out.stdout));
+    if load.is_empty() {
+        bail!("local load probe reported no figures");
+    }
+    Ok(load)
+}
+
+/// Read the `claude` subscription quota on a remote host under that host's
+/// own login. `None` when the probe failed in any way; the reason is logged.
+pub async fn probe_remote_claude_quota(transport: &impl RemoteRunner) -> Option<f64> {
+    let script = remote_claude_usage_script();
+    let out = match transport.run(&["sh", "-c", &script]).await {
+        Ok(out) => out,
+        Err(err) => {
+            tracing::debug!(
+                host_id = transport.host_id(),
+                error = %format!("{err:#}"),
+                "host load: remote claude quota probe failed to run",
+            );
+            return None;
+        }
+    };
+    if !out.success() {
+        tracing::debug!(
+            host_id = transport.host_id(),
+            status = out.status,
+            "host load: remote claude quota probe exited non-zero",
+        );
+        return None;
+    }
+    let now = boss_engine_utils::epoch_time::now_epoch_secs();
+    match boss_engine_driver_quota::parse::parse_claude_usage_json(&out.stdout, now) {
+        DriverQuotaOutcome::Reading(reading) => Some(reading.used_percent),
+        DriverQuotaOutcome::Unavailable { kind, reason } => {
+            tracing::debug!(
+                host_id = transport.host_id(),
+                kind = kind.as_str(),
+                %reason,
+                "host load: remote claude quota unavailable",
+            );
+            None
+        }
+    }
+}
+
+/// Counts from one pass; logged at `info` when anything changed.
+#[derive(Debug, Default)]
+pub struct HostLoadOutcome {
+    /// Hosts whose load sample was recorded.
+    pub sampled: usize,
+    /// Hosts whose load probe failed; their sample was cleared.
+    pub sample_failed: usize,
+    /// Remote quota probes run this pass.
+    pub quota_probed: usize,
+    pub steal: WorkStealOutcome,
+}
+
+impl crate::sweep_loop::SweepOutcome for HostLoadOutcome {
+    fn has_activity(&self) -> bool {
+        self.sample_failed > 0 || self.steal.released > 0
+    }
+
+    fn log(&self) {
+        tracing::info!(
+            sampled = self.sampled,
+            sample_failed = self.sample_failed,
+            quota_probed = self.quota_probed,
+            parked = self.steal.parked,
+            released = self.steal.released,
+            "host load: pass complete",
+        );
+    }
+}
+
+/// The sweep's collaborators plus the one piece of state it keeps between
+/// passes: when each SSH host's quota was last probed.
+pub struct HostLoadSweep {
+    work_db: Arc<WorkDb>,
+    coordinator: Arc<ExecutionCoordinator>,
+    quota_cache: Arc<QuotaCache>,
+    /// `None` when no ControlMaster directory could be resolved; SSH hosts
+    /// are then left unsampled (and score neutral) rather than probed over
+    /// a fresh connection each pass.
+    control_socket_dir: Option<PathBuf>,
+    remote_quota_probed_at: Mutex<HashMap<String, Instant>>,
+}
+
+impl HostLoadSweep {
+    pub fn new(
+        work_db: Arc<WorkDb>,
+        coordinator: Arc<ExecutionCoordinator>,
+        quota_cache: Arc<QuotaCache>,
+        control_socket_dir: Option<PathBuf>,
+    ) -> Self {
+        Self {
+            work_db,
+            coordinator,
+            quota_cache,
+            control_socket_dir,
+            remote_quota_probed_at: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Run one pass: sample every enabled host, refresh per-host quota,
+    /// then re-place host-parked executions.
+    pub async fn run_one_pass(&self) -> HostLoadOutcome {
+        let mut outcome = HostLoadOutcome::default();
+        match self.work_db.list_hosts() {
+            Ok(hosts) if hosts.iter().any(|h| h.id != "local") => {
+                let enabled: Vec<Host> = hosts.into_iter().filter(|h| h.enabled).collect();
+                self.sample_machine_hosts(&enabled, &mut outcome).await;
+                for host in enabled.iter().filter(|h| h.ssh_target.is_some()) {
+                    self.sample_ssh_host(host, &mut outcome).await;
+                }
+            }
+            Ok(_) => {}
+            Err(err) => {
+                tracing::warn!(error = %format!("{err:#}"), "host load: failed to list hosts; skipping sampling");
+            }
+        }
+        outcome.steal = self.coordinator.steal_parked_executions().await;
+        outcome
+    }
+
+    /// `local` and container hosts: one sample of the engine's machine and
+    /// the shared quota cache's readings, recorded against each.
+    async fn sample_machine_hosts(&self, hosts: &[Host], outcome: &mut HostLoadOutcome) {
+        let machine_hosts: Vec<&Host> = hosts.iter().filter(|h| h.ssh_target.is_none()).collect();
+        if machine_hosts.is_empty() {
+            return;
+        }
+        let load = sample_local_load().await;
+        if let Err(err) = &load {
+            tracing::debug!(error = %format!("{err:#}"), "host load: local load probe failed");
+        }
+        let quota = self.quota_cache.snapshot(false).await;
+        for host in machine_hosts {
+            self.record_load(&host.id, load.as_ref().ok(), outcome);
+            for entry in &quota.entries {
+                let used = match &entry.outcome {
+                    DriverQuotaOutcome::Reading(reading) => Some(reading.used_percent),
+                    DriverQuotaOutcome::Unavailable { .. } => None,
+                };
+                if let Err(err) =
+                    self.work_db
+                        .record_host_driver_quota(&host.id, &entry.driver, used, entry.observed_at_epoch_s)
+                {
+                    tracing::warn!(
+                        host_id = %host.id,
+                        driver = %entry.driver,
+                        error = %format!("{err:#}"),
+                        "host load: failed to record driver quota",
+                    );
+                }
+            }
+        }
+    }
+
+    async fn sample_ssh_host(&self, host: &Host, outcome: &mut HostLoadOutcome) {
+        let (Some(target), Some(dir)) = (host.ssh_target.as_deref(), self.control_socket_dir.as_deref()) else {
+            return;
+        };
+        let transport = SshTransport::new(&host.id, target, dir);
+        if let Err(err) = transport.open_control_master().await {
+            tracing::debug!(
+                host_id = %host.id,
+                error = %format!("{err:#}"),
+                "host load: could not open ControlMaster; clearing load sample",
+            );
+            self.record_load(&host.id, None, outcome);
+            return;
+        }
+        let load = sample_remote_load(&transport).await;
+        if let Err(err) = &load {
+            tracing::debug!(host_id = %host.id, error = %format!("{err:#}"), "host load: remote load probe failed");
+        }
+        self.record_load(&host.id, load.as_ref().ok(), outcome);
+
+        if !self.remote_quota_due(host) {
+            return;
+        }
+        outcome.quota_probed += 1;
+        let used = probe_remote_claude_quota(&transport).await;
+        let now = boss_engine_utils::epoch_time::now_epoch_secs();
+        if let Err(err) = self
+            .work_db
+            .record_host_driver_quota(&host.id, DRIVER_SLUG_CLAUDE, used, now)
+        {
+            tracing::warn!(
+                host_id = %host.id,
+                error = %format!("{err:#}"),
+                "host load: failed to record remote claude quota",
+            );
+        }
+    }
+
+    /// Whether `host` runs `claude` and its quota was last probed more than
+    /// a quota-cache TTL ago. Stamps the attempt when it returns `true`, so
+    /// a failing probe is retried on the same cadence as a working one.
+    fn remote_quota_due(&self, host: &Host) -> bool {
+        let has_claude = match self.work_db.list_host_capabilities(&host.id) {
+            Ok(caps) => {
+                let wanted = driver_capability(DRIVER_SLUG_CLAUDE);
+                caps.iter().any(|c| c.capability == wanted)
+            }
+            Err(_) => false,
+        };
+        if !has_claude {
+            return false;
+        }
+        let mut probed_at = self.remote_quota_probed_at.lock().unwrap();
+        let due = probed_at
+            .get(&host.id)
+            .is_none_or(|at| at.elapsed() >= boss_engine_driver_quota::DEFAULT_TTL);
+        if due {
+            probed_at.insert(host.id.clone(), Instant::now());
+        }
+        due
+    }
+
+    fn record_load(&self, host_id: &str, load: Option<&HostLoad>, outcome: &mut HostLoadOutcome) {
+        let result = match load {
+            Some(load) => {
+                outcome.sampled += 1;
+                self.work_db.record_host_load(host_id, load)
+            }
+            None => {
+                outcome.sample_failed += 1;
+                self.work_db.clear_host_load(host_id)
+            }
+        };
+        if let Err(err) = result {
+            tracing::warn!(host_id, error = %format!("{err:#}"), "host load: failed to record load sample");
+        }
+    }
+}
+
+/// Spawn a tokio task that runs [`HostLoadSweep::run_one_pass`] forever at
+/// `interval`. Fires immediately on spawn so placement has figures to work
+/// with from the first dispatch after boot.
+pub fn spawn_loop(sweep: HostLoadSweep, interval: Duration) -> tokio::task::JoinHandle<()> {
+    let sweep = Arc::new(sweep);
+    crate::sweep_loop::spawn_sweep_loop(interval, move || {
+        let sweep = Arc::clone(&sweep);
+        async move { sweep.run_one_pass().await }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use boss_protocol::RequestExecutionInput;
+
+    use super::*;
+    use crate::ssh_transport::SshOutput;
+    use crate::test_support::{create_product, create_test_chore, open_db};
+
+    /// Answers every command with one canned output.
+    struct CannedRunner(SshOutput);
+
+    #[async_trait]
+    impl RemoteRunner for CannedRunner {
+        fn host_id(&self) -> &str {
+            "zakalwe"
+        }
+
+        async fn run(&self, _argv: &[&str]) -> Result<SshOutput> {
+            Ok(self.0.clone())
+        }
+    }
+
+    fn canned(status: i32, stdout: &str) -> CannedRunner {
+        CannedRunner(SshOutput {
+            status,
+            stdout: stdout.to_owned(),
+            stderr: String::new(),
+        })
+    }
+
+    #[test]
+    fn parses_linux_probe_output() {
+        let load = parse_load_probe("cpus=16\nload1=3.42\nmem_available_kib=8388608\ndisk_free_kib=104857600\n");
+        assert_eq!(
+            load,
+            HostLoad {
+                cpu_count: Some(16),
+                load_avg_1m: Some(3.42),
+                mem_available_bytes: Some(8 << 30),
+                disk_free_bytes: Some(100 << 30),
+            }
+        );
+    }
+
+    #[test]
+    fn empty_and_garbled_figures_are_unknown() {
+        let load = parse_load_probe("cpus=0\nload1=\nmem_available_kib=lots\nnoise\ndisk_free_kib=2048\n");
+        assert_eq!(load.cpu_count, None);
+        assert_eq!(load.load_avg_1m, None);
+        assert_eq!(load.mem_available_bytes, None);
+        assert_eq!(load.disk_free_bytes, Some(2 << 20));
+        assert!(parse_load_probe("").is_empty());
+    }
+
+    #[tokio::test]
+    async fn remote_sample_fails_on_non_zero_exit_or_no_figures() {
+        assert!(sample_remote_load(&canned(255, "")).await.is_err());
+        assert!(sample_remote_load(&canned(0, "cpus=\n")).await.is_err());
+        let load = sample_remote_load(&canned(0, "cpus=4\nload1=0.5\n")).await.unwrap();
+        assert_eq!(load.cpu_count, Some(4));
+    }
+
+    #[tokio::test]
+    async fn remote_claude_quota_reads_the_weekly_figure() {
+        let stdout = r#"{"is_error":false,"result":"Current week (all models): 42% used · resets Aug 25 at 7pm (America/Chicago)"}"#;
+        assert_eq!(probe_remote_claude_quota(&canned(0, stdout)).await, Some(42.0));
+        assert_eq!(probe_remote_claude_quota(&canned(1, stdout)).await, None);
+        assert_eq!(probe_remote_claude_quota(&canned(0, "not json")).await, None);
+    }
+
+    #[test]
+    fn remote_claude_usage_script_strips_the_api_key() {
+        assert_eq!(
+            remote_claude_usage_script(),
+            "cd / && exec env -u ANTHROPIC_API_KEY claude -p /usage --output-format json"
+        );
+    }
+
+    /// A `ready` execution with `reason` as its wait reason, backing off
+    /// for another five minutes.
+    fn backing_off_execution(db: &WorkDb, reason: &str) -> String {
+        let product = create_product(db);
+        let wi = create_test_chore(db, &product, "parked").id;
+        let execution_id = db
+            .request_execution(RequestExecutionInput::builder().work_item_id(&wi).build())
+            .unwrap()
+            .id;
+        db.set_dispatch_wait_reason(&execution_id, reason).unwrap();
+        let later = boss_engine_utils::epoch_time::now_epoch_secs() + 300;
+        db.connect()
+            .unwrap()
+            .execute(
+                "UPDATE work_executions SET dispatch_not_before = ?2 WHERE id = ?1",
+                rusqlite::params![execution_id, later.to_string()],
+            )
+            .unwrap();
+        execution_id
+    }
+
+    #[test]
+    fn host_parked_execution_is_listed_and_released_once() {
+        let (_dir, db) = open_db();
+        let execution_id = backing_off_execution(&db, "no eligible host for work item x: zakalwe: no free slots");
+        let parked = db.list_host_parked_executions().unwrap();
+        assert_eq!(parked.len(), 1);
+        assert_eq!(parked[0].id, execution_id);
+
+        assert!(db.release_host_parked_execution(&execution_id).unwrap());
+        assert!(!db.release_host_parked_execution(&execution_id).unwrap());
+        assert!(db.list_host_parked_executions().unwrap().is_empty());
+    }
+
+    #[test]
+    fn backoff_for_another_reason_is_not_stolen() {
+        let (_dir, db) = open_db();
+        let execution_id = backing_off_execution(&db, "cube repo ensure failed");
+        assert!(db.list_host_parked_executions().unwrap().is_empty());
+        assert!(!db.release_host_parked_execution(&execution_id).unwrap());
+    }
+}
@@ -430,6 +430,9 @@ mod tests {
                     capabilities: BTreeSet::new(),
                     active_runs,
                     had_prior_run_on_branch: false,
+                    load: None,
+                    mean_run_secs: None,
+                    driver_quota_used_percent: None,
                 }
             })
             .collect()
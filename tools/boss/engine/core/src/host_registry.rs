@@ -13,7 +13,7 @@ use anyhow::{Context, Result, bail};
 use boss_event_bus::Event;
 use rusqlite::{Connection, OptionalExtension, params, params_from_iter};
 
-use crate::work::WorkDb;
+use crate::work::{WorkDb, WorkItem};
 
 // ── Public types ─────────────────────────────────────────────────────────────
 
@@ -21,6 +21,15 @@ use crate::work::WorkDb;
 pub struct Host {
     pub id: String,
     pub ssh_target: Option<String>,
+    /// Container runtime (`podman`, `docker`, `bubblewrap`) of a host whose
+    /// workers run isolated on the engine's own machine instead of over
+    /// SSH. `None` for `local` and every SSH host. See
+    /// [`crate::container_host`].
+    pub container_runtime: Option<String>,
+    /// Image a `podman` / `docker` container host runs workers in. `None`
+    /// for `bubblewrap`, which sandboxes the engine host's own root
+    /// filesystem, and for non-container hosts.
+    pub container_image: Option<String>,
     pub pool_size: i64,
     pub enabled: bool,
     /// Epoch-seconds string of the most recent contact *attempt* with
@@ -63,6 +72,36 @@ pub struct HostCapability {
     pub source: String,
 }
 
+/// Live load figures for one host, as last sampled by
+/// [`crate::host_load`]. Every figure is optional: a probe that could read
+/// the load average but not `MemAvailable` still contributes what it has,
+/// and [`crate::host_scheduling`] scores a missing figure as neutral rather
+/// than as idle or as saturated.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HostLoad {
+    /// Online CPUs; the load average is judged per CPU.
+    pub cpu_count: Option<u32>,
+    /// One-minute load average.
+    pub load_avg_1m: Option<f64>,
+    /// Memory the kernel reports as available to a new process
+    /// (`MemAvailable` on Linux, free + inactive pages on macOS).
+    pub mem_available_bytes: Option<u64>,
+    /// Free bytes on the filesystem holding the host's home directory,
+    /// where cube keeps its workspaces.
+    pub disk_free_bytes: Option<u64>,
+}
+
+impl HostLoad {
+    /// Whether the probe produced no figure at all.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+/// Fewest completed runs a host needs before its mean run duration feeds
+/// placement. Below this one unusually long chore would brand a host slow.
+pub const MIN_RUN_DURATION_SAMPLES: i64 = 3;
+
 // ── Migration helpers (called from WorkDb::init) ──────────────────────────────
 
 pub(crate) fn migrate_host_registry_tables(conn: &Connection) -> Result<()> {
@@ -107,6 +146,45 @@ pub(crate) fn migrate_hosts_health_columns(conn: &Connection) -> Result<()> {
     Ok(())
 }
 
+/// Add the container-host columns. Existing rows (local + SSH hosts) keep
+/// `NULL` in both, which is exactly "not a container host".
+pub(crate) fn migrate_hosts_container_columns(conn: &Connection) -> Result<()> {
+    let cols = pragma_columns(conn, "hosts")?;
+    if !cols.contains(&"container_runtime".to_owned()) {
+        conn.execute("ALTER TABLE hosts ADD COLUMN container_runtime TEXT", [])?;
+    }
+    if !cols.contains(&"container_image".to_owned()) {
+        conn.execute("ALTER TABLE hosts ADD COLUMN container_image TEXT", [])?;
+    }
+    Ok(())
+}
+
+/// Tables behind load-aware placement ([`crate::host_load`]): the latest
+/// load sample per host, and the latest provider quota reading per
+/// `(host, driver)`. Both keep only the newest observation, and a failed
+/// probe deletes its row rather than leaving an old figure to pass as
+/// current.
+pub(crate) fn migrate_host_load_tables(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS host_load_samples (
+             host_id             TEXT PRIMARY KEY REFERENCES hosts(id) ON DELETE CASCADE,
+             cpu_count           INTEGER,
+             load_avg_1m         REAL,
+             mem_available_bytes INTEGER,
+             disk_free_bytes     INTEGER,
+             sampled_at          TEXT NOT NULL
+         );
+         CREATE TABLE IF NOT EXISTS host_driver_quota (
+             host_id      TEXT NOT NULL REFERENCES hosts(id) ON DELETE CASCADE,
+             driver       TEXT NOT NULL,
+             used_percent REAL NOT NULL,
+             observed_at  TEXT NOT NULL,
+             PRIMARY KEY (host_id, driver)
+         );",
+    )?;
+    Ok(())
+}
+
 pub(crate) fn migrate_work_executions_host_columns(conn: &Connection) -> Result<()> {
     let cols = pragma_columns(conn, "work_executions")?;
     if !cols.contains(&"pinned_host_id".to_owned()) {
@@ -297,7 +375,8 @@ impl WorkDb {
     pub fn list_hosts(&self) -> Result<Vec<Host>> {
         let conn = self.connect()?;
         let mut stmt = conn.prepare(
-            "SELECT id, ssh_target, pool_size, enabled, last_seen_at, last_error_text, consecutive_failures, created_at
+            "SELECT id, ssh_target, pool_size, enabled, last_seen_at, last_error_text, consecutive_failures, created_at,
+                    container_runtime, container_image
              FROM hosts
              ORDER BY created_at ASC, id ASC",
         )?;
@@ -308,7 +387,8 @@ impl WorkDb {
     pub fn get_host(&self, id: &str) -> Result<Option<Host>> {
         let conn = self.connect()?;
         conn.query_row(
-            "SELECT id, ssh_target, pool_size, enabled, last_seen_at, last_error_text, consecutive_failures, created_at
+            "SELECT id, ssh_target, pool_size, enabled, last_seen_at, last_error_text, consecutive_failures, created_at,
+                    container_runtime, container_image
              FROM hosts WHERE id = ?1",
             params![id],
             map_host,
@@ -320,6 +400,35 @@ impl WorkDb {
     /// Insert a new non-local host. Returns an error if the id is `"local"` or
     /// already exists. User-provided `tags` are stored with `source = 'user'`.
     pub fn add_host(&self, id: &str, ssh_target: &str, pool_size: i64, tags: &[String]) -> Result<Host> {
+        self.insert_host(id, Some(ssh_target), None, pool_size, tags)
+    }
+
+    /// Insert a new container host: workers run isolated on the engine's
+    /// own machine under `runtime` (`podman`, `docker`, `bubblewrap`), in
+    /// `image` for the OCI runtimes. Same id / tag rules as [`Self::add_host`].
+    pub fn add_container_host(
+        &self,
+        id: &str,
+        runtime: &str,
+        image: Option<&str>,
+        pool_size: i64,
+        tags: &[String],
+    ) -> Result<Host> {
+        let runtime = crate::container_host::ContainerRuntime::parse(runtime)?;
+        if runtime.needs_image() && image.is_none() {
+            bail!("container runtime '{}' requires an image", runtime.as_str());
+        }
+        self.insert_host(id, None, Some((runtime.as_str(), image)), pool_size, tags)
+    }
+
+    fn insert_host(
+        &self,
+        id: &str,
+        ssh_target: Option<&str>,
+        container: Option<(&str, Option<&str>)>,
+        pool_size: i64,
+        tags: &[String],
+    ) -> Result<Host> {
         if id == "local" {
             bail!("'local' is a reserved host id; use `bossctl hosts list` to see it");
         }
@@ -338,10 +447,18 @@ impl WorkDb {
         }
 
         let now = now_epoch_string();
+        let (container_runtime, container_image) = container.unzip();
         tx.execute(
-            "INSERT INTO hosts (id, ssh_target, pool_size, enabled, created_at)
-             VALUES (?1, ?2, ?3, 1, ?4)",
-            params![id, ssh_target, pool_size, now],
+            "INSERT INTO hosts (id, ssh_target, pool_size, enabled, created_at, container_runtime, container_image)
+             VALUES (?1, ?2, ?3, 1, ?4, ?5, ?6)",
+            params![
+                id,
+                ssh_target,
+                pool_size,
+                now,
+                container_runtime,
+                container_image.flatten()
+            ],
         )?;
 
         for tag in tags {
@@ -354,7 +471,8 @@ impl WorkDb {
 
         let host = tx
             .query_row(
-                "SELECT id, ssh_target, pool_size, enabled, last_seen_at, last_error_text, consecutive_failures, created_at
+                "SELECT id, ssh_target, pool_size, enabled, last_seen_at, last_error_text, consecutive_failures, created_at,
+                    container_runtime, container_image
                  FROM hosts WHERE id = ?1",
                 params![id],
                 map_host,
@@ -628,6 +746,130 @@ impl WorkDb {
         Ok(out)
     }
 
+    /// Replace `host_id`'s load sample with `load`, stamped now.
+    pub fn record_host_load(&self, host_id: &str, load: &HostLoad) -> Result<()> {
+        let conn = self.connect()?;
+        conn.execute(
+            "INSERT INTO host_load_samples
+                 (host_id, cpu_count, load_avg_1m, mem_available_bytes, disk_free_bytes, sampled_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+             ON CONFLICT(host_id) DO UPDATE SET
+                 cpu_count = excluded.cpu_count,
+                 load_avg_1m = excluded.load_avg_1m,
+                 mem_available_bytes = excluded.mem_available_bytes,
+                 disk_free_bytes = excluded.disk_free_bytes,
+                 sampled_at = excluded.sampled_at",
+            params![
+                host_id,
+                load.cpu_count,
+                load.load_avg_1m,
+                load.mem_available_bytes.map(|b| b as i64),
+                load.disk_free_bytes.map(|b| b as i64),
+                now_epoch_string(),
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Drop `host_id`'s load sample — its probe failed, so the last figure
+    /// no longer describes the host.
+    pub fn clear_host_load(&self, host_id: &str) -> Result<()> {
+        let conn = self.connect()?;
+        conn.execute("DELETE FROM host_load_samples WHERE host_id = ?1", params![host_id])?;
+        Ok(())
+    }
+
+    /// Load samples no older than `max_age_secs`, keyed by host. A host
+    /// whose sample has aged out is simply absent and scores as unknown.
+    pub fn fresh_host_loads(&self, max_age_secs: i64) -> Result<HashMap<String, HostLoad>> {
+        let conn = self.connect()?;
+        let cutoff = boss_engine_utils::epoch_time::now_epoch_secs() - max_age_secs;
+        let mut stmt = conn.prepare(
+            "SELECT host_id, cpu_count, load_avg_1m, mem_available_bytes, disk_free_bytes
+             FROM host_load_samples
+             WHERE CAST(sampled_at AS INTEGER) >= ?1",
+        )?;
+        let rows = stmt.query_map(params![cutoff], |r| {
+            Ok((
+                r.get::<_, String>(0)?,
+                HostLoad {
+                    cpu_count: r.get(1)?,
+                    load_avg_1m: r.get(2)?,
+                    mem_available_bytes: r.get::<_, Option<i64>>(3)?.map(|b| b.max(0) as u64),
+                    disk_free_bytes: r.get::<_, Option<i64>>(4)?.map(|b| b.max(0) as u64),
+                },
+            ))
+        })?;
+        Ok(collect_rows(rows)?.into_iter().collect())
+    }
+
+    /// Record the provider quota `driver` reported on `host_id` as of
+    /// `observed_at_epoch_s`, or delete the reading when the probe failed
+    /// (`None`). The observation time comes from the probe, not the clock,
+    /// so a cached reading keeps its real age.
+    pub fn record_host_driver_quota(
+        &self,
+        host_id: &str,
+        driver: &str,
+        used_percent: Option<f64>,
+        observed_at_epoch_s: i64,
+    ) -> Result<()> {
+        let conn = self.connect()?;
+        match used_percent {
+            Some(used) => conn.execute(
+                "INSERT INTO host_driver_quota (host_id, driver, used_percent, observed_at)
+                 VALUES (?1, ?2, ?3, ?4)
+                 ON CONFLICT(host_id, driver) DO UPDATE SET
+                     used_percent = excluded.used_percent,
+                     observed_at = excluded.observed_at",
+                params![host_id, driver, used, observed_at_epoch_s.to_string()],
+            )?,
+            None => conn.execute(
+                "DELETE FROM host_driver_quota WHERE host_id = ?1 AND driver = ?2",
+                params![host_id, driver],
+            )?,
+        };
+        Ok(())
+    }
+
+    /// `driver`'s quota used-percent per host, for readings no older than
+    /// `max_age_secs`.
+    pub fn fresh_driver_quota_per_host(&self, driver: &str, max_age_secs: i64) -> Result<HashMap<String, f64>> {
+        let conn = self.connect()?;
+        let cutoff = boss_engine_utils::epoch_time::now_epoch_secs() - max_age_secs;
+        let mut stmt = conn.prepare(
+            "SELECT host_id, used_percent FROM host_driver_quota
+             WHERE driver = ?1 AND CAST(observed_at AS INTEGER) >= ?2",
+        )?;
+        let rows = stmt.query_map(params![driver, cutoff], |r| {
+            Ok((r.get::<_, String>(0)?, r.get::<_, f64>(1)?))
+        })?;
+        Ok(collect_rows(rows)?.into_iter().collect())
+    }
+
+    /// Mean wall-clock duration, in seconds, of the runs that completed on
+    /// each host since `since_epoch_s`. Hosts with fewer than
+    /// [`MIN_RUN_DURATION_SAMPLES`] such runs are omitted. Only `completed`
+    /// runs count: a run that failed or was reaped says nothing about how
+    /// fast the host gets work done.
+    pub fn mean_run_secs_per_host(&self, since_epoch_s: i64) -> Result<HashMap<String, f64>> {
+        let conn = self.connect()?;
+        let mut stmt = conn.prepare(
+            "SELECT host_id, AVG(CAST(finished_at AS INTEGER) - CAST(started_at AS INTEGER))
+             FROM work_runs
+             WHERE status = 'completed'
+               AND started_at IS NOT NULL
+               AND finished_at IS NOT NULL
+               AND CAST(finished_at AS INTEGER) >= ?1
+             GROUP BY host_id
+             HAVING COUNT(*) >= ?2",
+        )?;
+        let rows = stmt.query_map(params![since_epoch_s, MIN_RUN_DURATION_SAMPLES], |r| {
+            Ok((r.get::<_, String>(0)?, r.get::<_, f64>(1)?))
+        })?;
+        Ok(collect_rows(rows)?.into_iter().collect())
+    }
+
     /// Union of required capabilities recorded against any of the given
     /// subject ids (a chore plus its product / project). Subject ids are
     /// globally unique across kinds, so the lookup matches on id alone.
@@ -651,8 +893,63 @@ impl WorkDb {
         }
         Ok(out)
     }
+
+    /// [`Self::required_capabilities_for_subject_ids`] over `item` plus its
+    /// product and project — the union the scheduler filters hosts on and
+    /// the container adapter derives network policy / resource limits from.
+    pub fn required_capabilities_for_work_item(&self, item: &WorkItem) -> Result<BTreeSet<String>> {
+        let product_id = item.product_id().to_string();
+        let project_id = match item {
+            WorkItem::Project(p) => Some(p.id.clone()),
+            WorkItem::Task(t) | WorkItem::Chore(t) => t.project_id.clone(),
+            WorkItem::Product(_) => None,
+        };
+        let mut subject_ids: Vec<&str> = vec![item.primary_id(), product_id.as_str()];
+        if let Some(pid) = project_id.as_deref() {
+            subject_ids.push(pid);
+        }
+        self.required_capabilities_for_subject_ids(&subject_ids)
+    }
+
+    /// Record that work under `subject_id` (a product, project or chore)
+    /// requires `capability` on whatever host runs it — e.g. a product
+    /// that must only run on `isolation=container` hosts, or a
+    /// `container-network=none` policy. Idempotent.
+    pub fn add_capability_requirement(&self, subject_kind: &str, subject_id: &str, capability: &str) -> Result<()> {
+        if !CAPABILITY_REQUIREMENT_SUBJECT_KINDS.contains(&subject_kind) {
+            bail!(
+                "unknown requirement subject kind '{}'; expected one of {}",
+                subject_kind,
+                CAPABILITY_REQUIREMENT_SUBJECT_KINDS.join(", ")
+            );
+        }
+        let conn = self.connect()?;
+        conn.execute(
+            "INSERT OR IGNORE INTO work_capability_requirements (subject_kind, subject_id, capability)
+             VALUES (?1, ?2, ?3)",
+            params![subject_kind, subject_id, capability],
+        )?;
+        Ok(())
+    }
+
+    /// Drop a requirement recorded by [`Self::add_capability_requirement`].
+    /// Errors when no such row exists so a typo doesn't silently no-op.
+    pub fn remove_capability_requirement(&self, subject_id: &str, capability: &str) -> Result<()> {
+        let conn = self.connect()?;
+        let n = conn.execute(
+            "DELETE FROM work_capability_requirements WHERE subject_id = ?1 AND capability = ?2",
+            params![subject_id, capability],
+        )?;
+        if n == 0 {
+            bail!("requirement '{}' not found on '{}'", capability, subject_id);
+        }
+        Ok(())
+    }
 }
 
+/// Subject kinds a `work_capability_requirements` row may be keyed on.
+pub const CAPABILITY_REQUIREMENT_SUBJECT_KINDS: &[&str] = &["product", "project", "chore"];
+
 // ── Row mappers ───────────────────────────────────────────────────────────────
 
 fn map_host(row: &rusqlite::Row<'_>) -> rusqlite::Result<Host> {
@@ -665,6 +962,8 @@ fn map_host(row: &rusqlite::Row<'_>) -> rusqlite::Result<Host> {
         last_error_text: row.get(5)?,
         consecutive_failures: row.get(6)?,
         created_at: row.get(7)?,
+        container_runtime: row.get(8)?,
+        container_image: row.get(9)?,
     })
 }
 
@@ -695,7 +994,7 @@ fn pragma_columns(conn: &Connection, table: &str) -> Result<Vec<String>> {
 // Assertions go through the public methods (returned values, `Host` /
 // `HostCapability` fields, error outcomes, post-state read back). Raw
 // connections are used only to plant fixture rows in tables that have no
-// public insert path on `WorkDb` (`work_executions`, `work_runs`,
+// convenient insert path on `WorkDb` (`work_executions`, `work_runs`,
 // `work_capability_requirements`) and to control `created_at` for the
 // ordering test.
 #[cfg(test)]
@@ -798,6 +1097,31 @@ mod tests {
         assert!(names.contains("xcode=15"));
     }
 
+    #[test]
+    fn add_container_host_persists_runtime_and_image() {
+        let db = open_db();
+        let host = db
+            .add_container_host("sandbox", "podman", Some("boss-worker:latest"), 3, &[])
+            .unwrap();
+        assert_eq!(host.ssh_target, None);
+        assert_eq!(host.container_runtime.as_deref(), Some("podman"));
+        assert_eq!(host.container_image.as_deref(), Some("boss-worker:latest"));
+        assert_eq!(host.pool_size, 3);
+
+        let bwrap = db.add_container_host("bwrap", "bubblewrap", None, 1, &[]).unwrap();
+        assert_eq!(bwrap.container_runtime.as_deref(), Some("bubblewrap"));
+        assert_eq!(bwrap.container_image, None);
+    }
+
+    #[test]
+    fn add_container_host_rejects_unknown_runtime_and_missing_image() {
+        let db = open_db();
+        let err = db.add_container_host("c", "lxc", None, 1, &[]).unwrap_err();
+        assert!(err.to_string().contains("unknown container runtime"), "got: {err}");
+        let err = db.add_container_host("c", "docker", None, 1, &[]).unwrap_err();
+        assert!(err.to_string().contains("requires an image"), "got: {err}");
+    }
+
     // ── remove_host ───────────────────────────────────────────────────────
 
     #[test]
@@ -1374,6 +1698,100 @@ mod tests {
         assert_eq!(counts.get("local").copied(), Some(1));
     }
 
+    #[test]
+    fn mean_run_secs_per_host_averages_completed_runs_with_enough_samples() {
+        let db = open_db();
+        insert_execution(&db, "exec-1");
+        // Scoped so the connection is released before the query below
+        // needs one.
+        {
+            let conn = db.connect().unwrap();
+            let plant = |id: &str, host: &str, status: &str, started: i64, finished: i64| {
+                conn.execute(
+                    "INSERT INTO work_runs
+                         (id, execution_id, agent_id, status, created_at, started_at, finished_at, host_id)
+                     VALUES (?1, 'exec-1', 'agent-1', ?2, '100', ?3, ?4, ?5)",
+                    params![id, status, started.to_string(), finished.to_string(), host],
+                )
+                .unwrap();
+            };
+            plant("z1", "zakalwe", "completed", 1_000, 1_600);
+            plant("z2", "zakalwe", "completed", 2_000, 2_400);
+            plant("z3", "zakalwe", "completed", 3_000, 3_200);
+            // Failed runs say nothing about speed.
+            plant("z4", "zakalwe", "failed", 4_000, 9_000);
+            // Too few samples to count.
+            plant("l1", "local", "completed", 1_000, 1_100);
+            plant("l2", "local", "completed", 2_000, 2_100);
+            // Outside the window.
+            plant("a1", "anaplian", "completed", 10, 20);
+            plant("a2", "anaplian", "completed", 10, 20);
+            plant("a3", "anaplian", "completed", 10, 20);
+        }
+
+        let means = db.mean_run_secs_per_host(500).unwrap();
+        assert_eq!(means.len(), 1, "{means:?}");
+        assert_eq!(means.get("zakalwe").copied(), Some(400.0));
+    }
+
+    #[test]
+    fn host_load_round_trips_and_clears() {
+        let db = open_db();
+        let load = HostLoad {
+            cpu_count: Some(8),
+            load_avg_1m: Some(2.5),
+            mem_available_bytes: Some(12 << 30),
+            disk_free_bytes: None,
+        };
+        db.record_host_load("local", &load).unwrap();
+        assert_eq!(db.fresh_host_loads(60).unwrap().get("local"), Some(&load));
+
+        db.clear_host_load("local").unwrap();
+        assert!(db.fresh_host_loads(60).unwrap().is_empty());
+    }
+
+    #[test]
+    fn host_load_older_than_max_age_is_not_fresh() {
+        let db = open_db();
+        db.record_host_load("local", &HostLoad::default()).unwrap();
+        db.connect()
+            .unwrap()
+            .execute("UPDATE host_load_samples SET sampled_at = '100'", [])
+            .unwrap();
+        assert!(db.fresh_host_loads(60).unwrap().is_empty());
+    }
+
+    #[test]
+    fn driver_quota_reading_is_replaced_and_deleted_on_failure() {
+        let db = open_db();
+        db.add_host("zakalwe", "user@z", 2, &[]).unwrap();
+        let now = boss_engine_utils::epoch_time::now_epoch_secs();
+        db.record_host_driver_quota("zakalwe", "claude", Some(40.0), now)
+            .unwrap();
+        db.record_host_driver_quota("zakalwe", "claude", Some(55.0), now)
+            .unwrap();
+        db.record_host_driver_quota("local", "claude", Some(90.0), now - 600)
+            .unwrap();
+        let readings = db.fresh_driver_quota_per_host("claude", 60).unwrap();
+        assert_eq!(readings.get("zakalwe").copied(), Some(55.0));
+        assert!(!readings.contains_key("local"), "a reading observed long ago is stale");
+        assert_eq!(
+            db.fresh_driver_quota_per_host("claude", 900)
+                .unwrap()
+                .get("local")
+                .copied(),
+            Some(90.0)
+        );
+
+        db.record_host_driver_quota("zakalwe", "claude", None, now).unwrap();
+        assert!(
+            !db.fresh_driver_quota_per_host("claude", 60)
+                .unwrap()
+                .contains_key("zakalwe")
+        );
+        assert!(db.fresh_driver_quota_per_host("codex", 60).unwrap().is_empty());
+    }
+
     #[test]
     fn run_host_returns_attributed_host() {
         let db = open_db();
@@ -1413,6 +1831,36 @@ mod tests {
 
     // ── ensure_local_host / refresh_local_host_auto_capabilities ─────────────
 
+    #[test]
+    fn capability_requirement_round_trips_through_subject_lookup() {
+        let db = open_db();
+        db.add_capability_requirement("product", "prod-1", "isolation=container")
+            .unwrap();
+        // Idempotent re-add.
+        db.add_capability_requirement("product", "prod-1", "isolation=container")
+            .unwrap();
+        db.add_capability_requirement("project", "proj-1", "container-network=none")
+            .unwrap();
+
+        let caps = db.required_capabilities_for_subject_ids(&["prod-1", "proj-1"]).unwrap();
+        assert_eq!(
+            caps,
+            BTreeSet::from(["container-network=none".to_owned(), "isolation=container".to_owned()])
+        );
+
+        db.remove_capability_requirement("prod-1", "isolation=container")
+            .unwrap();
+        let err = db
+            .remove_capability_requirement("prod-1", "isolation=container")
+            .unwrap_err();
+        assert!(err.to_string().contains("not found"), "got: {err}");
+        let err = db.add_capability_requirement("host", "x", "os=linux").unwrap_err();
+        assert!(
+            err.to_string().contains("unknown requirement subject kind"),
+            "got: {err}"
+        );
+    }
+
     #[test]
     fn ensure_local_host_is_idempotent() {
         let db = open_db();
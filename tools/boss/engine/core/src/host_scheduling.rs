@@ -4,12 +4,24 @@
 //!    don't satisfy the chore's `work_capability_requirements`. A
 //!    chore inherits product/project requirements unless it overrides
 //!    them (the engine union'd those in before calling this module).
+//!    Container resource-limit tags are not capabilities; they are lifted
+//!    out into [`ContainerLimits`] first and instead require a host that
+//!    can enforce limits.
 //! 2. **Ranking** — among the candidates that survive the filter,
 //!    prefer:
 //!    1. the host with branch affinity (a prior run for this
 //!       execution's PR branch landed on it),
-//!    2. then the host with the most free slots,
-//!    3. then lexicographic host id (for deterministic tests).
+//!    2. then the highest [`HostScore`] — a weighted blend of free slots,
+//!       live CPU and memory headroom (sampled by [`crate::host_load`]),
+//!       historical run duration on the host, and the remaining provider
+//!       quota of the execution's driver there,
+//!    3. then the host with the most free slots,
+//!    4. then lexicographic host id (for deterministic tests).
+//!
+//! The score only ranks; it never excludes. The one load figure that does
+//! exclude is disk: a host below [`MIN_DISK_FREE_BYTES`] cannot hold
+//! another workspace, so it is ineligible like a full pool. A figure the
+//! probe could not read scores as neutral (`0.5`), never as idle.
 //!
 //! A durable pinned host narrows the candidates to one host and bypasses
 //! capability matching, the documented escape hatch for operator knowledge
@@ -18,8 +30,8 @@
 
 use std::collections::BTreeSet;
 
-use crate::host_capability_probe::DRIVERS_PROBED_CAPABILITY;
-use crate::host_registry::Host;
+use crate::host_capability_probe::{CONTAINER_LIMITS_CAPABILITY, DRIVERS_PROBED_CAPABILITY};
+use crate::host_registry::{Host, HostLoad};
 
 /// Per-host context provided to [`select_host`]. The caller builds
 /// these from the `hosts` table, capability rows, and the live
@@ -36,6 +48,13 @@ pub struct HostSlot {
     /// Whether a previous run of this execution's PR branch landed
     /// here. Used for the branch-affinity tiebreaker.
     pub had_prior_run_on_branch: bool,
+    /// Latest load sample, when one is fresh enough to trust.
+    pub load: Option<HostLoad>,
+    /// Mean duration of recently completed runs on this host.
+    pub mean_run_secs: Option<f64>,
+    /// Provider quota already used (percent) for the execution's driver on
+    /// this host, when a fresh reading exists.
+    pub driver_quota_used_percent: Option<f64>,
 }
 
 impl HostSlot {
@@ -55,6 +74,91 @@ pub struct ChoreRequirements {
     /// A one-dispatch `--host` request. Unlike [`Self::pinned_host_id`], this
     /// is an admission constraint and therefore does not bypass capabilities.
     pub requested_host_id: Option<String>,
+    /// Resource limits lifted out of the requirement tags. Non-empty limits
+    /// require [`CONTAINER_LIMITS_CAPABILITY`] on the picked host.
+    pub container_limits: ContainerLimits,
+}
+
+/// Per-run resource limits for a container host, expressed as requirement
+/// tags (`container-cpus=2`, `container-memory=4g`, `container-pids=512`)
+/// on a product, project or chore. They are not host capabilities — no
+/// host advertises "`container-cpus=2`" — so they are lifted out of the
+/// required set before the string-equality filter runs.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ContainerLimits {
+    /// CPU quota, in (fractional) cores.
+    pub cpus: Option<f64>,
+    /// Memory ceiling in bytes.
+    pub memory_bytes: Option<u64>,
+    /// Maximum number of processes in the container.
+    pub pids: Option<u64>,
+}
+
+pub const CONTAINER_CPUS_PREFIX: &str = "container-cpus=";
+pub const CONTAINER_MEMORY_PREFIX: &str = "container-memory=";
+pub const CONTAINER_PIDS_PREFIX: &str = "container-pids=";
+
+impl ContainerLimits {
+    pub fn is_empty(&self) -> bool {
+        self.cpus.is_none() && self.memory_bytes.is_none() && self.pids.is_none()
+    }
+
+    /// Remove every well-formed limit tag from `required` and return the
+    /// limits they describe. When the product and the chore both set a
+    /// limit, the most restrictive one wins. A malformed tag
+    /// (`container-memory=lots`) is left in place so it surfaces as a
+    /// missing capability instead of being silently ignored.
+    pub fn take_from(required: &mut BTreeSet<String>) -> Self {
+        let mut limits = Self::default();
+        required.retain(|tag| {
+            if let Some(v) = tag.strip_prefix(CONTAINER_CPUS_PREFIX) {
+                match v.parse::<f64>() {
+                    Ok(cpus) if cpus > 0.0 => {
+                        limits.cpus = Some(limits.cpus.map_or(cpus, |c| c.min(cpus)));
+                        false
+                    }
+                    _ => true,
+                }
+            } else if let Some(v) = tag.strip_prefix(CONTAINER_MEMORY_PREFIX) {
+                match parse_memory_bytes(v) {
+                    Some(bytes) => {
+                        limits.memory_bytes = Some(limits.memory_bytes.map_or(bytes, |b| b.min(bytes)));
+                        false
+                    }
+                    None => true,
+                }
+            } else if let Some(v) = tag.strip_prefix(CONTAINER_PIDS_PREFIX) {
+                match v.parse::<u64>() {
+                    Ok(pids) if pids > 0 => {
+                        limits.pids = Some(limits.pids.map_or(pids, |p| p.min(pids)));
+                        false
+                    }
+                    _ => true,
+                }
+            } else {
+                true
+            }
+        });
+        limits
+    }
+}
+
+/// Parse `512m` / `4g` / `1048576` (bytes) into a byte count.
+fn parse_memory_bytes(raw: &str) -> Option<u64> {
+    let raw = raw.trim().to_ascii_lowercase();
+    let (digits, unit) = match raw.find(|c: char| !c.is_ascii_digit()) {
+        Some(i) => raw.split_at(i),
+        None => (raw.as_str(), ""),
+    };
+    let n: u64 = digits.parse().ok()?;
+    let mult: u64 = match unit {
+        "" | "b" => 1,
+        "k" | "kb" => 1 << 10,
+        "m" | "mb" => 1 << 20,
+        "g" | "gb" => 1 << 30,
+        _ => return None,
+    };
+    n.checked_mul(mult).filter(|b| *b > 0)
 }
 
 /// Reasons a host can be ineligible. Surfaced as part of the
@@ -72,6 +176,8 @@ pub enum IneligibilityReason {
     /// requested driver because discovery has not run yet. This is distinct
     /// from a completed probe that found no matching binary.
     DriverProbeNotRun,
+    /// Less than [`MIN_DISK_FREE_BYTES`] free where workspaces live.
+    LowDisk { free_bytes: u64 },
     /// A durable `pinned_host_id` OR a request-scoped `requested_host_id`
     /// is set on the execution and this host is not that host. When both
     /// are set the request-scoped host wins outright (see `selected_pin`
@@ -87,6 +193,140 @@ pub struct Eligibility {
     pub reasons: Vec<IneligibilityReason>,
     pub free_slots: i64,
     pub had_prior_run_on_branch: bool,
+    /// Ranking score, computed for every host so an operator can see why
+    /// an eligible host lost as well as why an ineligible one was skipped.
+    pub score: HostScore,
+}
+
+/// Leading words of the `dispatch_wait_reason` an execution carries while
+/// it backs off because no host was eligible. The host-load sweep finds
+/// work to re-place by this prefix, so the coordinator's error text must
+/// keep starting with it.
+pub const HOST_PARKED_WAIT_REASON_PREFIX: &str = "no eligible host";
+
+/// Free disk below which a host takes no new work. A fresh workspace clone
+/// plus build outputs routinely runs to several GiB.
+pub const MIN_DISK_FREE_BYTES: u64 = 5 << 30;
+
+/// Available memory at which the memory factor saturates; more than this
+/// does not make a host a better pick.
+pub const COMFORTABLE_MEM_AVAILABLE_BYTES: u64 = 8 << 30;
+
+/// Load per CPU at which the CPU factor reaches zero.
+pub const SATURATED_LOAD_PER_CPU: f64 = 1.5;
+
+/// Factor value for a figure the probe could not read.
+pub const NEUTRAL_FACTOR: f64 = 0.5;
+
+const SLOTS_WEIGHT: f64 = 0.30;
+const CPU_WEIGHT: f64 = 0.25;
+const MEMORY_WEIGHT: f64 = 0.15;
+const SPEED_WEIGHT: f64 = 0.15;
+const QUOTA_WEIGHT: f64 = 0.15;
+
+/// One weighted input to a [`HostScore`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreFactor {
+    /// `slots`, `cpu`, `memory`, `speed` or `quota`.
+    pub name: &'static str,
+    pub weight: f64,
+    /// Normalized to `0.0..=1.0`, higher is better.
+    pub value: f64,
+    /// The raw figure behind `value`, for the operator.
+    pub detail: String,
+}
+
+/// Weighted sum of a host's [`ScoreFactor`]s; `total` is in `0.0..=1.0`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct HostScore {
+    pub total: f64,
+    pub factors: Vec<ScoreFactor>,
+}
+
+impl HostScore {
+    /// One-line rendering, e.g. `0.72 (slots 1.00: 3 free; cpu 0.40: …)`.
+    pub fn summary(&self) -> String {
+        let factors: Vec<String> = self
+            .factors
+            .iter()
+            .map(|f| format!("{} {:.2}: {}", f.name, f.value, f.detail))
+            .collect();
+        format!("{:.2} ({})", self.total, factors.join("; "))
+    }
+}
+
+/// Candidate-wide figures each host's score is judged against.
+struct ScoreBaseline {
+    most_free_slots: i64,
+    fastest_mean_run_secs: Option<f64>,
+}
+
+fn factor(name: &'static str, weight: f64, value: Option<f64>, detail: String) -> ScoreFactor {
+    ScoreFactor {
+        name,
+        weight,
+        value: value.map_or(NEUTRAL_FACTOR, |v| v.clamp(0.0, 1.0)),
+        detail,
+    }
+}
+
+fn score_slot(slot: &HostSlot, baseline: &ScoreBaseline) -> HostScore {
+    let free = slot.free_slots();
+    let load = slot.load.as_ref();
+    let slots = factor(
+        "slots",
+        SLOTS_WEIGHT,
+        Some(if baseline.most_free_slots > 0 {
+            free as f64 / baseline.most_free_slots as f64
+        } else {
+            0.0
+        }),
+        format!("{free} free"),
+    );
+    let cpu = match load.and_then(|l| l.load_avg_1m.zip(l.cpu_count.filter(|n| *n > 0))) {
+        Some((avg, cpus)) => factor(
+            "cpu",
+            CPU_WEIGHT,
+            Some(1.0 - avg / f64::from(cpus) / SATURATED_LOAD_PER_CPU),
+            format!("load {avg:.2} on {cpus} cpus"),
+        ),
+        None => factor("cpu", CPU_WEIGHT, None, "no load sample".to_owned()),
+    };
+    let memory = match load.and_then(|l| l.mem_available_bytes) {
+        Some(bytes) => factor(
+            "memory",
+            MEMORY_WEIGHT,
+            Some(bytes as f64 / COMFORTABLE_MEM_AVAILABLE_BYTES as f64),
+            format!("{} available", format_gib(bytes)),
+        ),
+        None => factor("memory", MEMORY_WEIGHT, None, "no memory sample".to_owned()),
+    };
+    let speed = match (slot.mean_run_secs, baseline.fastest_mean_run_secs) {
+        (Some(mean), Some(fastest)) if mean > 0.0 => factor(
+            "speed",
+            SPEED_WEIGHT,
+            Some(fastest / mean),
+            format!("mean run {:.0}m", mean / 60.0),
+        ),
+        _ => factor("speed", SPEED_WEIGHT, None, "too few completed runs".to_owned()),
+    };
+    let quota = match slot.driver_quota_used_percent {
+        Some(used) => factor(
+            "quota",
+            QUOTA_WEIGHT,
+            Some(1.0 - used / 100.0),
+            format!("{used:.0}% of driver quota used"),
+        ),
+        None => factor("quota", QUOTA_WEIGHT, None, "no quota reading".to_owned()),
+    };
+    let factors = vec![slots, cpu, memory, speed, quota];
+    let total = factors.iter().map(|f| f.weight * f.value).sum();
+    HostScore { total, factors }
+}
+
+/// Render a byte count in GiB with one decimal, e.g. `12.5 GiB`.
+pub fn format_gib(bytes: u64) -> String {
+    format!("{:.1} GiB", bytes as f64 / f64::from(1u32 << 30))
 }
 
 /// Compute per-host eligibility and return the picked host id along
@@ -97,7 +337,15 @@ pub struct Eligibility {
 /// `pinned_host_id` semantics: when set, only that host can win.
 pub fn select_host(requirements: &ChoreRequirements, slots: &[HostSlot]) -> (Option<String>, Vec<Eligibility>) {
     let mut report = Vec::with_capacity(slots.len());
-    let mut candidates: Vec<&HostSlot> = Vec::new();
+    let mut candidates: Vec<(&HostSlot, f64)> = Vec::new();
+    let baseline = ScoreBaseline {
+        most_free_slots: slots.iter().map(HostSlot::free_slots).max().unwrap_or(0),
+        fastest_mean_run_secs: slots
+            .iter()
+            .filter_map(|slot| slot.mean_run_secs)
+            .filter(|mean| *mean > 0.0)
+            .min_by(f64::total_cmp),
+    };
 
     for slot in slots {
         let mut reasons = Vec::new();
@@ -107,6 +355,11 @@ pub fn select_host(requirements: &ChoreRequirements, slots: &[HostSlot]) -> (Opt
         if slot.free_slots() <= 0 {
             reasons.push(IneligibilityReason::NoFreeSlots);
         }
+        if let Some(free_bytes) = slot.load.as_ref().and_then(|l| l.disk_free_bytes)
+            && free_bytes < MIN_DISK_FREE_BYTES
+        {
+            reasons.push(IneligibilityReason::LowDisk { free_bytes });
+        }
         let selected_pin = requirements
             .requested_host_id
             .as_ref()
@@ -116,9 +369,12 @@ pub fn select_host(requirements: &ChoreRequirements, slots: &[HostSlot]) -> (Opt
         {
             reasons.push(IneligibilityReason::NotSelectedHost);
         }
+        let limits_required =
+            (!requirements.container_limits.is_empty()).then(|| CONTAINER_LIMITS_CAPABILITY.to_owned());
         let missing: Vec<String> = requirements
             .required_capabilities
             .iter()
+            .chain(limits_required.as_ref())
             .filter(|cap| !slot.capabilities.contains(*cap))
             .cloned()
             .collect();
@@ -139,38 +395,36 @@ pub fn select_host(requirements: &ChoreRequirements, slots: &[HostSlot]) -> (Opt
             }
         }
         let eligible = reasons.is_empty();
+        let score = score_slot(slot, &baseline);
+        if eligible {
+            candidates.push((slot, score.total));
+        }
         report.push(Eligibility {
             host_id: slot.host.id.clone(),
             eligible,
             reasons,
             free_slots: slot.free_slots(),
             had_prior_run_on_branch: slot.had_prior_run_on_branch,
+            score,
         });
-        if eligible {
-            candidates.push(slot);
-        }
     }
 
     // Rank candidates. Branch affinity wins outright; among hosts that
-    // tie on branch affinity, prefer more free slots; ties on free
-    // slots fall through to lexicographic host id (stable, testable).
+    // tie on branch affinity, prefer the higher score, then more free
+    // slots; remaining ties fall through to lexicographic host id
+    // (stable, testable).
     let picked = candidates
         .into_iter()
-        .min_by(|a, b| {
+        .min_by(|(a, a_score), (b, b_score)| {
             // Branch affinity: a host with a prior run sorts *before*
             // one without (so we want false > true under min).
-            let aff = b.had_prior_run_on_branch.cmp(&a.had_prior_run_on_branch);
-            if aff != std::cmp::Ordering::Equal {
-                return aff;
-            }
-            // More free slots wins (higher slots sorts before).
-            let slots = b.free_slots().cmp(&a.free_slots());
-            if slots != std::cmp::Ordering::Equal {
-                return slots;
-            }
-            a.host.id.cmp(&b.host.id)
+            b.had_prior_run_on_branch
+                .cmp(&a.had_prior_run_on_branch)
+                .then_with(|| b_score.total_cmp(a_score))
+                .then_with(|| b.free_slots().cmp(&a.free_slots()))
+                .then_with(|| a.host.id.cmp(&b.host.id))
         })
-        .map(|s| s.host.id.clone());
+        .map(|(s, _)| s.host.id.clone());
 
     (picked, report)
 }
@@ -183,6 +437,8 @@ mod tests {
         Host {
             id: id.to_owned(),
             ssh_target: None,
+            container_runtime: None,
+            container_image: None,
             pool_size: pool,
             enabled,
             last_seen_at: None,
@@ -198,15 +454,29 @@ mod tests {
             capabilities: caps.iter().map(|s| s.to_string()).collect(),
             active_runs: active,
             had_prior_run_on_branch: false,
+            load: None,
+            mean_run_secs: None,
+            driver_quota_used_percent: None,
         }
     }
 
+    fn loaded(mut slot: HostSlot, cpus: u32, load_avg: f64, mem_gib: u64) -> HostSlot {
+        slot.load = Some(HostLoad {
+            cpu_count: Some(cpus),
+            load_avg_1m: Some(load_avg),
+            mem_available_bytes: Some(mem_gib << 30),
+            disk_free_bytes: Some(100 << 30),
+        });
+        slot
+    }
+
     #[test]
     fn picks_only_host_when_capabilities_match() {
         let reqs = ChoreRequirements {
             required_capabilities: ["os=macos".into()].into_iter().collect(),
             pinned_host_id: None,
             requested_host_id: None,
+            container_limits: ContainerLimits::default(),
         };
         let slots = vec![slot("local", 4, 0, &["os=macos", "bazel"])];
         let (picked, report) = select_host(&reqs, &slots);
@@ -221,6 +491,7 @@ mod tests {
             required_capabilities: ["xcode=15".into()].into_iter().collect(),
             pinned_host_id: None,
             requested_host_id: None,
+            container_limits: ContainerLimits::default(),
         };
         let slots = vec![
             slot("local", 4, 0, &["os=macos"]),
@@ -256,28 +527,93 @@ mod tests {
         // `local` has more free slots, but `zakalwe` previously ran a
         // run for this PR's branch — affinity wins.
         let reqs = ChoreRequirements::default();
-        let local = HostSlot {
-            host: make_host("local", 8, true),
-            capabilities: BTreeSet::new(),
-            active_runs: 0,
-            had_prior_run_on_branch: false,
-        };
+        let local = slot("local", 8, 0, &[]);
         let zakalwe = HostSlot {
-            host: make_host("zakalwe", 2, true),
-            capabilities: BTreeSet::new(),
-            active_runs: 0,
             had_prior_run_on_branch: true,
+            ..slot("zakalwe", 2, 0, &[])
         };
         let (picked, _) = select_host(&reqs, &[local, zakalwe]);
         assert_eq!(picked.as_deref(), Some("zakalwe"));
     }
 
+    #[test]
+    fn idle_host_outranks_a_busy_one_with_more_free_slots() {
+        let reqs = ChoreRequirements::default();
+        let busy = loaded(slot("anaplian", 4, 0, &[]), 8, 11.0, 1);
+        let idle = loaded(slot("zakalwe", 2, 0, &[]), 8, 0.5, 16);
+        let (picked, report) = select_host(&reqs, &[busy, idle]);
+        assert_eq!(picked.as_deref(), Some("zakalwe"));
+        let busy_score = &report[0].score;
+        let cpu = busy_score.factors.iter().find(|f| f.name == "cpu").unwrap();
+        assert!(cpu.value < 0.1, "load 11 on 8 cpus is nearly saturated: {cpu:?}");
+        assert!(busy_score.summary().contains("load 11.00 on 8 cpus"));
+        assert!(report[1].score.total > busy_score.total);
+    }
+
+    #[test]
+    fn missing_figures_score_neutral() {
+        let reqs = ChoreRequirements::default();
+        let (_, report) = select_host(&reqs, &[slot("local", 1, 0, &[])]);
+        let score = &report[0].score;
+        assert_eq!(score.factors.len(), 5);
+        for f in score.factors.iter().filter(|f| f.name != "slots") {
+            assert_eq!(f.value, NEUTRAL_FACTOR, "{f:?}");
+        }
+        assert!((score.total - (SLOTS_WEIGHT + 0.5 * (1.0 - SLOTS_WEIGHT))).abs() < 1e-9);
+    }
+
+    #[test]
+    fn faster_host_and_spare_quota_break_a_load_tie() {
+        let reqs = ChoreRequirements::default();
+        let slow = HostSlot {
+            mean_run_secs: Some(3_600.0),
+            driver_quota_used_percent: Some(20.0),
+            ..slot("aardvark", 2, 0, &[])
+        };
+        let fast = HostSlot {
+            mean_run_secs: Some(1_200.0),
+            driver_quota_used_percent: Some(20.0),
+            ..slot("zebra", 2, 0, &[])
+        };
+        let (picked, report) = select_host(&reqs, &[slow.clone(), fast.clone()]);
+        assert_eq!(picked.as_deref(), Some("zebra"));
+        let speed = report[0].score.factors.iter().find(|f| f.name == "speed").unwrap();
+        assert!((speed.value - 1.0 / 3.0).abs() < 1e-9);
+
+        let exhausted = HostSlot {
+            driver_quota_used_percent: Some(100.0),
+            ..fast
+        };
+        let (picked, _) = select_host(&reqs, &[slow, exhausted]);
+        assert_eq!(picked.as_deref(), Some("aardvark"));
+    }
+
+    #[test]
+    fn low_disk_excludes_host_even_when_pinned() {
+        let reqs = ChoreRequirements {
+            pinned_host_id: Some("zakalwe".to_owned()),
+            ..ChoreRequirements::default()
+        };
+        let mut full = slot("zakalwe", 2, 0, &[]);
+        full.load = Some(HostLoad {
+            disk_free_bytes: Some(1 << 30),
+            ..HostLoad::default()
+        });
+        let (picked, report) = select_host(&reqs, &[full]);
+        assert!(picked.is_none());
+        assert_eq!(
+            report[0].reasons,
+            vec![IneligibilityReason::LowDisk { free_bytes: 1 << 30 }]
+        );
+    }
+
     #[test]
     fn pinned_host_wins_even_without_capability_match() {
         let reqs = ChoreRequirements {
             required_capabilities: ["xcode=15".into()].into_iter().collect(),
             pinned_host_id: Some("local".to_owned()),
             requested_host_id: None,
+            container_limits: ContainerLimits::default(),
         };
         let slots = vec![
             slot("local", 4, 0, &["os=macos"]), // pinned, doesn't have xcode
@@ -293,6 +629,7 @@ mod tests {
             required_capabilities: ["xcode=15".into()].into_iter().collect(),
             pinned_host_id: None,
             requested_host_id: Some("local".to_owned()),
+            container_limits: ContainerLimits::default(),
         };
         let slots = vec![slot("local", 4, 0, &["os=macos"]), slot("zakalwe", 4, 0, &["xcode=15"])];
         let (picked, report) = select_host(&reqs, &slots);
@@ -314,6 +651,7 @@ mod tests {
             required_capabilities: BTreeSet::new(),
             pinned_host_id: Some("not-registered".to_owned()),
             requested_host_id: None,
+            container_limits: ContainerLimits::default(),
         };
         let slots = vec![slot("local", 4, 0, &[])];
         let (picked, report) = select_host(&reqs, &slots);
@@ -331,6 +669,7 @@ mod tests {
             required_capabilities: ["os=macos".into(), "xcode=15".into()].into_iter().collect(),
             pinned_host_id: None,
             requested_host_id: None,
+            container_limits: ContainerLimits::default(),
         };
         let slots = vec![slot("linux-host", 4, 0, &["os=linux"])];
         let (picked, report) = select_host(&reqs, &slots);
@@ -372,6 +711,7 @@ mod tests {
             required_capabilities: ["os=macos".into()].into_iter().collect(),
             pinned_host_id: None,
             requested_host_id: None,
+            container_limits: ContainerLimits::default(),
         };
         let (picked, report) = select_host(&one_requirement, &slots);
         assert!(picked.is_none());
@@ -412,6 +752,7 @@ mod tests {
             required_capabilities: ["driver=codex".into()].into_iter().collect(),
             pinned_host_id: None,
             requested_host_id: None,
+            container_limits: ContainerLimits::default(),
         };
         let slots = vec![
             slot("local", 4, 0, &["os=macos", "driver=claude", "drivers-probed=true"]),
@@ -437,6 +778,7 @@ mod tests {
             required_capabilities: ["driver=claude".into()].into_iter().collect(),
             pinned_host_id: None,
             requested_host_id: None,
+            container_limits: ContainerLimits::default(),
         };
         // Pre-change remote host: os/arch/gh only, never re-probed.
         let slots = vec![slot("anaplian", 3, 0, &["os=macos", "arch=arm64", "gh-authed=true"])];
@@ -455,6 +797,7 @@ mod tests {
             required_capabilities: ["driver=claude".into(), "os=linux".into()].into_iter().collect(),
             pinned_host_id: None,
             requested_host_id: None,
+            container_limits: ContainerLimits::default(),
         };
         let slots = vec![slot("anaplian", 3, 0, &[])];
         let (picked, report) = select_host(&reqs, &slots);
@@ -476,6 +819,7 @@ mod tests {
             required_capabilities: ["driver=codex".into()].into_iter().collect(),
             pinned_host_id: None,
             requested_host_id: None,
+            container_limits: ContainerLimits::default(),
         };
         let slots = vec![
             slot("local", 4, 0, &["driver=claude", "drivers-probed=true"]),
@@ -484,4 +828,54 @@ mod tests {
         let (picked, _) = select_host(&reqs, &slots);
         assert!(picked.is_none());
     }
+
+    #[test]
+    fn container_limits_lift_out_of_required_tags_most_restrictive_wins() {
+        let mut required: BTreeSet<String> = [
+            "isolation=container",
+            "container-cpus=4",
+            "container-cpus=1.5",
+            "container-memory=4g",
+            "container-memory=512m",
+            "container-pids=256",
+            "container-memory=lots",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+        let limits = ContainerLimits::take_from(&mut required);
+        assert_eq!(limits.cpus, Some(1.5));
+        assert_eq!(limits.memory_bytes, Some(512 << 20));
+        assert_eq!(limits.pids, Some(256));
+        // The malformed tag stays so it surfaces as a missing capability.
+        assert_eq!(
+            required,
+            BTreeSet::from(["container-memory=lots".to_owned(), "isolation=container".to_owned()])
+        );
+    }
+
+    #[test]
+    fn container_limits_require_a_limit_capable_host() {
+        let reqs = ChoreRequirements {
+            required_capabilities: ["isolation=container".into()].into_iter().collect(),
+            pinned_host_id: None,
+            requested_host_id: None,
+            container_limits: ContainerLimits {
+                pids: Some(128),
+                ..Default::default()
+            },
+        };
+        let slots = vec![
+            slot("bwrap", 4, 0, &["isolation=container"]),
+            slot("podman", 2, 0, &["isolation=container", "container-limits=true"]),
+        ];
+        let (picked, report) = select_host(&reqs, &slots);
+        assert_eq!(picked.as_deref(), Some("podman"));
+        assert_eq!(
+            report[0].reasons,
+            vec![IneligibilityReason::MissingCapabilities(vec![
+                "container-limits=true".into()
+            ])]
+        );
+    }
 }
@@ -12,6 +12,7 @@ pub mod attentions_detector;
 pub mod audit;
 pub mod audit_effort;
 pub use boss_engine_automation_schedule as automation_schedule;
+pub mod automation_events;
 pub mod automation_scheduler;
 pub mod automation_triage;
 pub mod awaiting_input_status;
@@ -32,6 +33,7 @@ pub mod completion;
 pub mod config;
 #[cfg(test)]
 mod conformance;
+pub mod container_host;
 pub use boss_conflict_diagnosis as conflict_diagnosis;
 pub mod conflict_ladder;
 pub mod conflict_remediation;
@@ -47,6 +49,7 @@ pub mod cube_op_budget;
 pub mod database_backup;
 pub mod dead_pane_sweep;
 pub mod dead_pid_sweep;
+pub mod default_branch_ci;
 pub mod deferred_scope;
 pub mod dep_unblock_sweep;
 pub mod design_detector;
@@ -57,7 +60,10 @@ pub mod attachment_server;
 pub mod codex_guard_trace;
 pub mod codex_home_retention_sweep;
 pub mod codex_unobserved_command;
+pub mod dashboard;
+pub(crate) mod dispatch_calendar;
 pub mod dispatch_failure_recovery_sweep;
+pub(crate) mod dispatch_fair_share;
 pub mod dispatch_inflight;
 pub mod dispatch_metrics;
 pub mod driver_teardown;
@@ -76,6 +82,7 @@ pub mod editorial_hook;
 pub use boss_engine_effort as effort;
 pub mod engine_control;
 pub mod envelope_watch;
+pub use boss_event_bus as event_bus;
 pub mod event_publish;
 pub mod events_socket;
 pub mod execution_liveness;
@@ -86,6 +93,7 @@ pub mod github_api_usage;
 pub mod hold_registry;
 pub mod host_adapter;
 pub mod host_capability_probe;
+pub mod host_load;
 pub mod host_provisioning;
 pub mod host_reconcile;
 pub mod host_registry;
@@ -119,7 +127,7 @@ pub use boss_metrics as metrics;
 // `crate::register_counter!` at every engine call site; the macros
 // are `#[macro_export]`ed from `boss_engine_metrics_registry` and
 // re-exported through `boss_metrics`.
-pub use boss_metrics::{register_counter, register_gauge};
+pub use boss_metrics::{register_counter, register_gauge, register_histogram};
 pub mod metrics_init;
 pub mod metrics_store;
 pub mod no_op_signal;
@@ -133,8 +141,10 @@ pub mod populator;
 pub mod postmortem_followups;
 pub use boss_pr_review as pr_review;
 pub use boss_pr_template as pr_template;
+pub mod pr_review_publish;
 pub mod pr_review_recovery;
 pub mod pr_url_capture;
+pub mod process_probe;
 pub mod project_postmortem_sweep;
 pub(crate) mod prompt_fragments;
 pub mod proposal_channel_error;
@@ -155,6 +165,7 @@ pub mod spawn_ack_sweep;
 pub mod spawn_flow;
 pub mod spawn_health;
 pub mod speculative_conflict;
+pub mod spend_budget;
 pub mod ssh_spawn;
 pub use boss_ssh_transport as ssh_transport;
 pub mod stacked_pr_structuring;
@@ -45,7 +45,7 @@ use serde_json::Value;
 
 use boss_engine_live_status_redact as live_status_redact;
 
-use crate::claude_client::{self, CallConfig, ClaudeError, Message, MessagesRequest};
+use crate::claude_client::{CallConfig, ClaudeError, Message, MessagesRequest};
 use crate::utility_model::{UtilityCall, UtilityModel, UtilityTask};
 
 /// Haiku 4.5 is the right shape for a one-sentence cheap summary —
@@ -451,8 +451,8 @@ pub async fn claude_one_sentence(call: &UtilityCall, transcript: &str) -> Result
         .system(system)
         .messages(vec![Message::user(user)])
         .build();
-    let config = CallConfig::new(SUMMARY_TIMEOUT).with_endpoint(call.endpoint.clone());
-    let response = claude_client::send_messages(&call.api_key, &request, &config).await?;
+    let config = CallConfig::new(SUMMARY_TIMEOUT);
+    let response = call.send_messages(&request, &config).await?;
     let cleaned = clean_summary(response.first_text().unwrap_or_default());
     if cleaned.is_empty() {
         return Ok(ClaudeReply::PostFilterDropped);
@@ -704,7 +704,7 @@ mod tests {
         Mock::given(method("POST"))
             .and(path("/v1/messages"))
             .and(header("x-api-key", "test-key"))
-            .and(header("anthropic-version", claude_client::ANTHROPIC_API_VERSION))
+            .and(header("anthropic-version", crate::claude_client::ANTHROPIC_API_VERSION))
             .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
                 "content": [{
                     "type": "text",
@@ -301,6 +301,15 @@ impl DriverSignalKind {
     }
 }
 
+/// Outcome of [`LiveWorkerStateRegistry::record_driver_signal_timed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DriverSignalRecorded {
+    pub slot_id: u8,
+    /// Seconds from spawn registration to this signal. `Some` only on the
+    /// run's first driver signal, and only for an engine-spawned slot.
+    pub spawn_ack_secs: Option<i64>,
+}
+
 /// A slot whose spawn has gone [`DRIVER_START_GRACE_SECS`] without any
 /// driver-originated signal — i.e. Boss has no evidence the driver
 /// binary ever executed. Returned by
@@ -715,22 +724,42 @@ impl LiveWorkerStateRegistry {
     /// Returns the slot id when a live entry matched, `None` otherwise
     /// (a hook for a released or unknown run — a benign no-op).
     pub fn record_driver_signal(&self, run_id: &str, kind: DriverSignalKind) -> Option<u8> {
+        self.record_driver_signal_timed(run_id, kind)
+            .map(|recorded| recorded.slot_id)
+    }
+
+    /// [`Self::record_driver_signal`], additionally reporting the spawn-ack
+    /// time when this call was the run's first driver signal — what the
+    /// hook ingress feeds the `dispatch.spawn_ack_seconds` histogram.
+    pub fn record_driver_signal_timed(&self, run_id: &str, kind: DriverSignalKind) -> Option<DriverSignalRecorded> {
         let mut guard = self.inner.lock().expect("registry mutex poisoned");
         let entry = guard.values_mut().find(|entry| entry.state.run_id == run_id)?;
         let slot_id = entry.state.slot_id;
         if entry.meta.driver_signal_at.is_some() {
             // Already proven; keep the first timestamp.
-            return Some(slot_id);
+            return Some(DriverSignalRecorded {
+                slot_id,
+                spawn_ack_secs: None,
+            });
         }
-        entry.meta.driver_signal_at = Some(boss_engine_utils::epoch_time::now_epoch_secs());
+        let now = boss_engine_utils::epoch_time::now_epoch_secs();
+        entry.meta.driver_signal_at = Some(now);
+        // A re-adopted slot's `spawned_at` is when the engine noticed the
+        // process, not when it exec'd, so it has no ack time to report.
+        let spawn_ack_secs = (entry.meta.driver_start_expectation == DriverStartExpectation::EngineSpawned)
+            .then(|| (now - entry.meta.spawned_at).max(0));
         drop(guard);
         tracing::info!(
             slot_id,
             run_id,
             signal = kind.as_str(),
+            spawn_ack_secs,
             "driver-start verified: first driver-originated signal received for this run",
         );
-        Some(slot_id)
+        Some(DriverSignalRecorded {
+            slot_id,
+            spawn_ack_secs,
+        })
     }
 
     /// Whether a driver-originated signal has been recorded for `slot_id`.
@@ -2534,6 +2563,26 @@ mod tests {
         assert_eq!(reg.driver_signal_at(1), Some(first));
     }
 
+    #[test]
+    fn record_driver_signal_timed_reports_the_ack_time_once() {
+        let reg = LiveWorkerStateRegistry::new();
+        aged_slot_with_live_shell(&reg, 1, "run-a", false);
+
+        let first = reg
+            .record_driver_signal_timed("run-a", DriverSignalKind::HookEvent)
+            .unwrap();
+        assert_eq!(first.slot_id, 1);
+        let ack = first
+            .spawn_ack_secs
+            .expect("first signal on a spawned slot has an ack time");
+        assert!(ack >= DRIVER_START_GRACE_SECS + 60, "ack was {ack}");
+
+        let second = reg
+            .record_driver_signal_timed("run-a", DriverSignalKind::TranscriptPath)
+            .unwrap();
+        assert_eq!(second.spawn_ack_secs, None, "only the first signal is an ack");
+    }
+
     /// Register a re-adopted slot aged past `DRIVER_START_GRACE_SECS` —
     /// the shape re-adoption always produces, because registration stamps
     /// `spawned_at` with the current time for a process that has in fact
@@ -115,6 +115,12 @@ crate::register_counter!(
     "merge_poller.pass_timed_out",
     "Detection passes abandoned mid-flight after exceeding the pass timeout."
 );
+crate::register_histogram!(
+    PASS_DURATION_MS,
+    "merge_poller.pass_duration_ms",
+    "Wall-clock duration of detection passes that finished within their time budget (abandoned passes count in merge_poller.pass_timed_out instead).",
+    crate::metrics::LATENCY_BUCKETS_MS,
+);
 crate::register_counter!(
     ADAPTIVE_BATCHES,
     "merge_poller.adaptive_batches",
@@ -188,6 +194,7 @@ pub fn init(registry: &Registry) {
     registry.register_counter(&TRUNK_EPISODES_ADOPTED);
     registry.register_counter(&PASS_OVERRUN);
     registry.register_counter(&PASS_TIMED_OUT);
+    registry.register_histogram(&PASS_DURATION_MS);
     registry.register_counter(&ADAPTIVE_BATCHES);
     registry.register_counter(&ADAPTIVE_PRS_RECONCILED);
     registry.register_gauge(&ADAPTIVE_TRACKED);
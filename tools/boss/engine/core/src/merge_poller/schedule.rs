@@ -431,6 +431,7 @@ where
     match tokio::time::timeout(budget, pass).await {
         Ok(outcome) => {
             let elapsed = started.elapsed();
+            PASS_DURATION_MS.observe(metrics, elapsed.as_millis() as u64);
             if elapsed > cadence {
                 PASS_OVERRUN.inc_by(metrics, 1);
                 tracing::warn!(
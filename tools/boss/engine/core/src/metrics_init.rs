@@ -7,6 +7,68 @@
 //! moving down into `boss_metrics`.
 
 use boss_metrics::Registry;
+use boss_metrics::exporter::LabelPattern;
+
+/// Label schema for every dynamically-named counter family, handed to the
+/// OpenMetrics exporter (`boss_metrics::exporter`). Dynamic names encode
+/// their labels positionally, so without an entry here a family exports
+/// as one unlabelled series per label combination. Add a line alongside
+/// any new `counter_inc_by_dynamic` family; each `family` must differ
+/// from every registered name.
+pub const LABEL_PATTERNS: &[LabelPattern] = &[
+    LabelPattern {
+        family: "answer_agent.failed_by_kind",
+        pattern: "answer_agent.failed.{error_kind}",
+    },
+    LabelPattern {
+        family: "answer_agent.superseded_by_reason",
+        pattern: "answer_agent.superseded.{reason}",
+    },
+    LabelPattern {
+        family: "answer_agent.queue_wait_by_bucket",
+        pattern: "answer_agent.queue_wait_ms.{bucket}",
+    },
+    LabelPattern {
+        family: "bus_events_dropped",
+        pattern: "bus_events_dropped_total.{topic}",
+    },
+    LabelPattern {
+        family: "completion.mid_turn_reap_by_source",
+        pattern: "completion.mid_turn_reap.{source}.count",
+    },
+    LabelPattern {
+        family: "conflict.classified",
+        pattern: "conflict.{product}.{class}.classified",
+    },
+    LabelPattern {
+        family: "github_api.calls_by_caller",
+        pattern: "github_api.calls.{caller}.{api}",
+    },
+    LabelPattern {
+        family: "github_api.calls_by_verb",
+        pattern: "github_api.verb.{api}.{verb}",
+    },
+    LabelPattern {
+        family: "github_api.points_by_caller",
+        pattern: "github_api.points.{caller}.{api}",
+    },
+    LabelPattern {
+        family: "github_api.errors_by_caller",
+        pattern: "github_api.errors.{caller}.{outcome}",
+    },
+    LabelPattern {
+        family: "merge_poller.adaptive_batch_size",
+        pattern: "merge_poller.adaptive_batch_size.{bucket}",
+    },
+    LabelPattern {
+        family: "speculative_conflict.by_product",
+        pattern: "speculative_conflict.{product}.{outcome}",
+    },
+    LabelPattern {
+        family: "stacked_pr_structuring.offered_by_product",
+        pattern: "stacked_pr_structuring.{product}.offered",
+    },
+];
 
 /// Force registration of every counter / gauge handle the engine
 /// declares.
@@ -72,6 +134,36 @@ pub fn init_all(registry: &Registry) {
 mod tests {
     use super::*;
 
+    #[test]
+    fn label_pattern_families_do_not_shadow_registered_metrics() {
+        let registry = Registry::new();
+        init_all(&registry);
+        let names: Vec<_> = registry
+            .counter_snapshots()
+            .into_iter()
+            .map(|s| s.name)
+            .chain(registry.gauge_snapshots().into_iter().map(|s| s.name))
+            .chain(registry.histogram_snapshots().into_iter().map(|s| s.name))
+            .collect();
+        for pattern in LABEL_PATTERNS {
+            assert!(
+                !names.contains(&pattern.family.to_owned()),
+                "{} is both a label-pattern family and a registered metric",
+                pattern.family
+            );
+        }
+
+        // A labelled family renders as one family, not one per label value.
+        registry.counter_inc_by_dynamic("github_api.calls.merge_poller.graphql", "d", 1);
+        registry.counter_inc_by_dynamic("github_api.calls.ci_watch.rest", "d", 1);
+        let text = boss_metrics::exporter::render(&registry, LABEL_PATTERNS);
+        assert_eq!(
+            text.matches("# TYPE boss_github_api_calls_by_caller counter").count(),
+            1
+        );
+        assert!(text.contains("# TYPE boss_merge_poller_pass_duration_ms histogram"));
+    }
+
     #[test]
     fn init_all_registers_all_declared_counters() {
         let registry = Registry::new();
@@ -284,9 +376,9 @@ mod tests {
         );
         assert_eq!(
             names.len(),
-            100,
+            107,
             "expected 6 answer_agent + 6 pr_url_capture + 4 worker_proposals fallback_hit + 3 cube_workspace_lease + \
-             10 dispatcher + 15 merge_poller + 18 external_tracker + 2 speculative_conflict + \
+             10 dispatcher + 15 merge_poller + 25 external_tracker + 2 speculative_conflict + \
              1 stacked_pr_structuring + 1 dispatch_metrics + 9 trunk_queue_poller + \
              9 worker_proposals submit + 1 worker_proposals channel_error + \
              5 github_api + 2 codex_unobserved_command + 2 codex_guard_trace + \
@@ -40,7 +40,7 @@ use std::time::Duration;
 use anyhow::Result;
 use sha2::{Digest, Sha256};
 
-use crate::claude_client::{self, CallConfig, Message, MessagesRequest, RetryPolicy};
+use crate::claude_client::{CallConfig, Message, MessagesRequest, RetryPolicy};
 use crate::utility_model::{UtilityCall, UtilityModel, UtilityTask};
 use crate::work::{WorkDb, WorkItem};
 use boss_protocol::ExecutionKind;
@@ -75,7 +75,7 @@ const SUMMARY_TIMEOUT: Duration = Duration::from_secs(5);
 /// that a single re-issue absorbs, and this call sits on the
 /// `SpawnWorkerPane` path so every extra attempt adds directly to
 /// spawn latency. Non-transient errors (auth, other 4xx, decode) are
-/// never retried — see [`claude_client::ClaudeError::is_retryable`].
+/// never retried — see [`crate::claude_client::ClaudeError::is_retryable`].
 ///
 /// Wall-clock ceiling:
 /// `SUMMARY_ATTEMPTS * SUMMARY_TIMEOUT + (SUMMARY_ATTEMPTS - 1) * SUMMARY_BACKOFF`
@@ -358,11 +358,13 @@ pub async fn claude_short_summary(call: &UtilityCall, name: &str, description: &
     // Explicit spawn-path policy rather than `CallConfig::new`'s default:
     // pin the attempt count and short backoff so a future default change
     // cannot quietly inflate (or strip) retry on the critical spawn path.
-    let config = CallConfig::new(SUMMARY_TIMEOUT)
-        .with_retry(RetryPolicy::new(SUMMARY_ATTEMPTS, SUMMARY_BACKOFF, SUMMARY_BACKOFF))
-        .with_endpoint(call.endpoint.clone());
+    let config = CallConfig::new(SUMMARY_TIMEOUT).with_retry(RetryPolicy::new(
+        SUMMARY_ATTEMPTS,
+        SUMMARY_BACKOFF,
+        SUMMARY_BACKOFF,
+    ));
 
-    let response = claude_client::send_messages(&call.api_key, &request, &config).await?;
+    let response = call.send_messages(&request, &config).await?;
     let cleaned = clean_summary(response.first_text().unwrap_or_default());
     if cleaned.is_empty() {
         anyhow::bail!("anthropic returned an empty summary");
@@ -720,7 +722,7 @@ mod tests {
         Mock::given(method("POST"))
             .and(path("/v1/messages"))
             .and(header("x-api-key", "test-key"))
-            .and(header("anthropic-version", claude_client::ANTHROPIC_API_VERSION))
+            .and(header("anthropic-version", crate::claude_client::ANTHROPIC_API_VERSION))
             .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
                 "content": [{"type": "text", "text": "fixing the pane titlebar"}],
             })))
@@ -91,7 +91,7 @@ use serde_json::{Value, json};
 
 use boss_protocol::{PlannerInput, PlannerOutput, planner_output_schema};
 
-use crate::claude_client::{self, CallConfig, ClaudeError, MessagesResponse, RetryPolicy};
+use crate::claude_client::{CallConfig, ClaudeError, MessagesResponse, RetryPolicy};
 use crate::utility_model::{UtilityCall, UtilityModel, UtilityTask};
 
 /// The model the Planner runs on. A direct API call needs a concrete model
@@ -282,9 +282,11 @@ enum RetryFeedback {
 /// to split multi-clause or multi-layer tasks — the design doc's breakdown is
 /// authoritative (entry count in, row count out).
 async fn plan_with_call(call: &UtilityCall, input: &PlannerInput) -> PlannerOutcome {
-    let config = CallConfig::new(PLANNER_TIMEOUT)
-        .with_retry(RetryPolicy::new(PLANNER_ATTEMPTS, PLANNER_BACKOFF, PLANNER_BACKOFF))
-        .with_endpoint(call.endpoint.clone());
+    let config = CallConfig::new(PLANNER_TIMEOUT).with_retry(RetryPolicy::new(
+        PLANNER_ATTEMPTS,
+        PLANNER_BACKOFF,
+        PLANNER_BACKOFF,
+    ));
 
     let mut feedback: Option<RetryFeedback> = None;
     for attempt in 1..=PLANNER_VALIDATION_ATTEMPTS {
@@ -292,7 +294,7 @@ async fn plan_with_call(call: &UtilityCall, input: &PlannerInput) -> PlannerOutc
             None => build_request_body(input, &call.model),
             Some(fb) => build_retry_request_body(input, fb, &call.model),
         };
-        match claude_client::send_messages_raw(&call.api_key, &body, &config).await {
+        match call.send_messages_raw(&body, &config).await {
             Ok(response) => match planner_output_from_response(&response) {
                 Ok(output) => {
                     return PlannerOutcome::Success(output);
@@ -1206,9 +1208,11 @@ mod tests {
     fn mock_call(server_uri: &str) -> UtilityCall {
         UtilityCall {
             provider: "anthropic".to_owned(),
+            api: crate::utility_model::UtilityApi::AnthropicMessages,
             endpoint: format!("{server_uri}/v1/messages"),
             model: PLANNER_MODEL.to_owned(),
             api_key: "test-key".to_owned(),
+            timeout: None,
         }
     }
 
@@ -1670,7 +1674,7 @@ mod tests {
         Mock::given(method("POST"))
             .and(path("/v1/messages"))
             .and(header("x-api-key", "test-key"))
-            .and(header("anthropic-version", claude_client::ANTHROPIC_API_VERSION))
+            .and(header("anthropic-version", crate::claude_client::ANTHROPIC_API_VERSION))
             .respond_with(ResponseTemplate::new(200).set_body_json(tool_use_response()))
             .mount(&server)
             .await;
@@ -1687,6 +1691,43 @@ mod tests {
         }
     }
 
+    /// The same plan from an OpenAI-compatible server: the forced tool call
+    /// goes out as a `json_schema` response format and the JSON reply is
+    /// read back as the tool input.
+    #[tokio::test]
+    async fn end_to_end_success_against_a_chat_completions_server() {
+        let server = MockServer::start().await;
+        let input = tool_use_response()["content"][1]["input"].clone();
+        Mock::given(method("POST"))
+            .and(path("/v1/chat/completions"))
+            .and(wiremock::matchers::body_partial_json(json!({
+                "model": "local-planner",
+                "response_format": { "type": "json_schema", "json_schema": { "name": TOOL_NAME } },
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "choices": [{
+                    "message": { "role": "assistant", "content": input.to_string() },
+                    "finish_reason": "stop",
+                }],
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let call = UtilityCall {
+            provider: "local".to_owned(),
+            api: crate::utility_model::UtilityApi::OpenAiChatCompletions,
+            endpoint: format!("{}/v1/chat/completions", server.uri()),
+            model: "local-planner".to_owned(),
+            api_key: String::new(),
+            timeout: None,
+        };
+        match plan_with_call(&call, &sample_input()).await {
+            PlannerOutcome::Success(out) => assert_eq!(out.tasks.len(), 2),
+            other => panic!("expected Success, got {other:?}"),
+        }
+    }
+
     #[tokio::test]
     async fn retries_once_then_succeeds() {
         let server = MockServer::start().await;
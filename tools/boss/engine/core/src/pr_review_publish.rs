@@ -0,0 +1,744 @@
+//! Optional publishing of a completed reviewer pass to its GitHub PR as a
+//! pull-request review. Gated by the `pr_review_publish` feature flag
+//! (default off); the reviewer itself stays read-only — the engine posts,
+//! after the pass has been finalised, using the ambient `gh` auth.
+//!
+//! # What gets posted
+//!
+//! Each [`ReviewFinding`] whose free-form `location` resolves to a line
+//! the PR's diff shows (see [`resolve_anchor`]) becomes an inline comment
+//! on that line. Findings that do not resolve — a file-level finding, a
+//! file without a patch, a location naming nothing in the diff — are
+//! listed in the review body under the pass's summary. Every piece of
+//! text goes through the product's editorial rules first
+//! ([`boss_editorial::evaluate`]): a rewrite is applied, and a finding
+//! whose text the rules block is withheld rather than posted.
+//!
+//! # Idempotence
+//!
+//! Every comment carries a hidden marker with the finding's fingerprint
+//! (file, category and title — stable across head shas), and every review
+//! body a marker with the head sha it reviewed. Publishing therefore:
+//!
+//! - posts nothing when a review for this head sha already exists, so a
+//!   replayed finalize never duplicates a review;
+//! - updates an earlier comment in place when the same finding is
+//!   reported again, instead of posting a second one;
+//! - resolves the thread of an earlier comment whose finding is no longer
+//!   reported, noting the head sha it cleared at;
+//! - refuses to post when the PR head has moved past the reviewed sha,
+//!   since the diff lines would no longer match what was reviewed.
+//!
+//! Everything here is best-effort: a failure is logged and the review
+//! pass's own outcome (revision or not) is unaffected.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, LazyLock};
+
+use boss_editorial::{CompiledRules, EditorialDecision};
+use boss_github::gh_runner::{CommandGhRunner, GhRunner, GhRunnerError};
+use boss_github::pr_files::{PrFilePatch, fetch_pr_file_patches};
+use regex::Regex;
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+
+use crate::pr_review::{ReviewFinding, ReviewResult};
+use crate::work::WorkDb;
+
+/// Feature flag gating the whole publishing step.
+pub(crate) const PR_REVIEW_PUBLISH_FLAG: &str = "pr_review_publish";
+
+const FINDING_MARKER_PREFIX: &str = "<!-- boss-review-finding:";
+const REVIEW_MARKER_PREFIX: &str = "<!-- boss-review-head:";
+const MARKER_SUFFIX: &str = " -->";
+const RESOLVED_NOTE_PREFIX: &str = "_No longer reported as of";
+
+/// How far a stated line number may be from a line the diff shows and
+/// still be moved onto it. Reviewers cite lines from the file they read,
+/// so a line just outside a hunk is almost always about that hunk.
+const LINE_SNAP_DISTANCE: u32 = 3;
+
+const REST_PAGE_SIZE: usize = 100;
+const REST_MAX_PAGES: usize = 10;
+
+/// Words a `location` uses around the thing it names, never the name.
+const LOCATION_STOPWORDS: &[&str] = &[
+    "and", "around", "block", "call", "class", "const", "def", "end", "enum", "file", "fn", "for", "func", "function",
+    "hunk", "impl", "in", "line", "lines", "match", "method", "mod", "near", "of", "the", "top", "trait", "type",
+    "struct", "test", "tests",
+];
+
+/// What one publish attempt did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PublishOutcome {
+    /// A review for this head sha is already on the PR; nothing posted.
+    AlreadyPublished,
+    /// The PR head is no longer the reviewed sha; nothing posted.
+    HeadMoved { reviewed: String, current: String },
+    Published {
+        inline: usize,
+        updated: usize,
+        resolved: usize,
+        in_body: usize,
+        withheld: usize,
+    },
+}
+
+/// Posts review passes through an injectable [`GhRunner`].
+pub struct PrReviewPublisher {
+    gh: Arc<dyn GhRunner>,
+}
+
+impl Default for PrReviewPublisher {
+    fn default() -> Self {
+        Self::new(Arc::new(CommandGhRunner))
+    }
+}
+
+/// A bot comment already on the PR, as read back from the REST listing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ExistingComment {
+    id: u64,
+    fingerprint: String,
+    body: String,
+    /// `None` once GitHub marks the comment outdated (its line is no
+    /// longer in the diff).
+    line: Option<u32>,
+}
+
+/// One inline comment to create.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct NewComment {
+    path: String,
+    line: u32,
+    body: String,
+}
+
+/// Everything one publish will do, computed without I/O.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct PublishPlan {
+    new_comments: Vec<NewComment>,
+    /// `(comment id, new body)`.
+    updates: Vec<(u64, String)>,
+    /// Earlier comments whose finding is still reported, so a resolved
+    /// thread must be reopened.
+    reopen: Vec<u64>,
+    /// `(comment id, body with the resolved note)`.
+    resolve: Vec<(u64, String)>,
+    /// Rendered findings that did not anchor to a diff line.
+    body_findings: Vec<String>,
+    withheld: usize,
+}
+
+impl PrReviewPublisher {
+    pub fn new(gh: Arc<dyn GhRunner>) -> Self {
+        Self { gh }
+    }
+
+    /// Publish `result` to `pr_url`, applying `rules` to every piece of
+    /// text. See the module docs for the idempotence contract.
+    pub async fn publish(
+        &self,
+        pr_url: &str,
+        result: &ReviewResult,
+        rules: &CompiledRules,
+    ) -> Result<PublishOutcome, GhRunnerError> {
+        let (owner, repo, pr_number) = boss_github::pr_url::parse_pr_url_parts(pr_url)
+            .ok_or_else(|| GhRunnerError::transient(format!("not a GitHub PR URL: {pr_url}")))?;
+        let slug = format!("{owner}/{repo}");
+
+        let pull = self
+            .gh
+            .rest_get(&format!("repos/{slug}/pulls/{pr_number}"), None)
+            .await?;
+        let current_head = pull.body["head"]["sha"].as_str().unwrap_or_default().to_owned();
+        if !result.head_sha.is_empty() && !current_head.is_empty() && result.head_sha != current_head {
+            return Ok(PublishOutcome::HeadMoved {
+                reviewed: result.head_sha.clone(),
+                current: current_head,
+            });
+        }
+        let head_sha = if result.head_sha.is_empty() {
+            current_head
+        } else {
+            result.head_sha.clone()
+        };
+
+        let reviews = self
+            .rest_get_all(&format!("repos/{slug}/pulls/{pr_number}/reviews"))
+            .await?;
+        let review_marker = review_marker(&head_sha);
+        let mut any_prior_review = false;
+        for review in &reviews {
+            let body = review["body"].as_str().unwrap_or_default();
+            if body.contains(&review_marker) {
+                return Ok(PublishOutcome::AlreadyPublished);
+            }
+            any_prior_review |= body.contains(REVIEW_MARKER_PREFIX);
+        }
+
+        let patches = fetch_pr_file_patches(self.gh.as_ref(), &slug, pr_number).await?;
+        let comments = self
+            .rest_get_all(&format!("repos/{slug}/pulls/{pr_number}/comments"))
+            .await?;
+        let existing = parse_existing_comments(&comments);
+        let plan = plan_publish(result, &patches, &existing, rules, &head_sha);
+
+        for (id, body) in &plan.updates {
+            self.gh
+                .rest_patch(&format!("repos/{slug}/pulls/comments/{id}"), &[("body", body)], None)
+                .await?;
+        }
+        if !plan.resolve.is_empty() || !plan.reopen.is_empty() {
+            let threads = self.review_threads(owner, repo, pr_number).await?;
+            for (id, body) in &plan.resolve {
+                self.gh
+                    .rest_patch(&format!("repos/{slug}/pulls/comments/{id}"), &[("body", body)], None)
+                    .await?;
+                if let Some((thread_id, false)) = threads.get(id) {
+                    self.set_thread_resolved(thread_id, true).await?;
+                }
+            }
+            for id in &plan.reopen {
+                if let Some((thread_id, true)) = threads.get(id) {
+                    self.set_thread_resolved(thread_id, false).await?;
+                }
+            }
+        }
+
+        // A pass with nothing new to show still posts on the PR's first
+        // review, so a human can see it was reviewed at all.
+        if !plan.new_comments.is_empty() || !plan.body_findings.is_empty() || !any_prior_review {
+            let body = render_review_body(result, &plan.body_findings, rules, &head_sha);
+            let comments: Vec<Value> = plan
+                .new_comments
+                .iter()
+                .map(|c| json!({"path": c.path, "line": c.line, "side": "RIGHT", "body": c.body}))
+                .collect();
+            self.gh
+                .rest_post(
+                    &format!("repos/{slug}/pulls/{pr_number}/reviews"),
+                    &json!({
+                        "commit_id": head_sha,
+                        "event": "COMMENT",
+                        "body": body,
+                        "comments": comments,
+                    }),
+                    None,
+                )
+                .await?;
+        }
+
+        Ok(PublishOutcome::Published {
+            inline: plan.new_comments.len(),
+            updated: plan.updates.len(),
+            resolved: plan.resolve.len(),
+            in_body: plan.body_findings.len(),
+            withheld: plan.withheld,
+        })
+    }
+
+    /// Page through a REST listing until a short page.
+    async fn rest_get_all(&self, path: &str) -> Result<Vec<Value>, GhRunnerError> {
+        let mut items = Vec::new();
+        for page in 1..=REST_MAX_PAGES {
+            let response = self
+                .gh
+                .rest_get(&format!("{path}?per_page={REST_PAGE_SIZE}&page={page}"), None)
+                .await?;
+            let page_items = response.body.as_array().cloned().unwrap_or_default();
+            let last_page = page_items.len() < REST_PAGE_SIZE;
+            items.extend(page_items);
+            if last_page {
+                break;
+            }
+        }
+        Ok(items)
+    }
+
+    /// Review threads keyed by their first comment's REST id, with the
+    /// thread's node id and whether it is resolved. Covers the PR's
+    /// first 100 threads.
+    async fn review_threads(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+    ) -> Result<HashMap<u64, (String, bool)>, GhRunnerError> {
+        const QUERY: &str = "query($owner: String!, $name: String!, $number: Int!) { \
+            repository(owner: $owner, name: $name) { pullRequest(number: $number) { \
+            reviewThreads(first: 100) { nodes { id isResolved comments(first: 1) { nodes { databaseId } } } } } } }";
+        let number = pr_number.to_string();
+        let response = self
+            .gh
+            .graphql(QUERY, &[("owner", owner), ("name", repo), ("number", &number)], None)
+            .await?;
+        Ok(parse_review_threads(&response))
+    }
+
+    async fn set_thread_resolved(&self, thread_id: &str, resolved: bool) -> Result<(), GhRunnerError> {
+        let query = if resolved {
+            "mutation($id: ID!) { resolveReviewThread(input: {threadId: $id}) { thread { id } } }"
+        } else {
+            "mutation($id: ID!) { unresolveReviewThread(input: {threadId: $id}) { thread { id } } }"
+        };
+        self.gh.graphql(query, &[("id", thread_id)], None).await?;
+        Ok(())
+    }
+}
+
+/// Publish `result` in the background when the flag is on: load the
+/// product's editorial rules, post, and log the outcome. Never blocks or
+/// fails the caller.
+pub(crate) fn spawn_publish(
+    publisher: Arc<PrReviewPublisher>,
+    work_db: Arc<WorkDb>,
+    product_id: String,
+    pr_url: String,
+    result: ReviewResult,
+) {
+    tokio::spawn(async move {
+        let rules = product_editorial_rules(&work_db, &product_id);
+        let outcome = boss_gh_telemetry::scope(
+            boss_gh_telemetry::callers::PR_REVIEW_PUBLISH,
+            publisher.publish(&pr_url, &result, &rules),
+        )
+        .await;
+        match outcome {
+            Ok(PublishOutcome::Published {
+                inline,
+                updated,
+                resolved,
+                in_body,
+                withheld,
+            }) => tracing::info!(
+                pr_url = %pr_url,
+                head_sha = %result.head_sha,
+                inline,
+                updated,
+                resolved,
+                in_body,
+                withheld,
+                "pr_review publish: review posted to the PR",
+            ),
+            Ok(PublishOutcome::AlreadyPublished) => tracing::debug!(
+                pr_url = %pr_url,
+                head_sha = %result.head_sha,
+                "pr_review publish: a review for this head sha is already on the PR; skipped",
+            ),
+            Ok(PublishOutcome::HeadMoved { reviewed, current }) => tracing::info!(
+                pr_url = %pr_url,
+                %reviewed,
+                %current,
+                "pr_review publish: PR head moved past the reviewed sha; skipped",
+            ),
+            Err(err) => tracing::warn!(
+                pr_url = %pr_url,
+                http_status = ?err.http_status,
+                error = %err.message,
+                "pr_review publish: posting the review failed",
+            ),
+        }
+    });
+}
+
+/// The product's editorial rules, compiled; the baked-in defaults alone
+/// when the product has none or they fail to compile.
+fn product_editorial_rules(work_db: &WorkDb, product_id: &str) -> CompiledRules {
+    let rules = match work_db.get_product(product_id) {
+        Ok(Some(product)) => product.editorial_rules.unwrap_or_default(),
+        Ok(None) => Default::default(),
+        Err(err) => {
+            tracing::warn!(
+                product_id,
+                ?err,
+                "pr_review publish: product lookup failed; using default rules"
+            );
+            Default::default()
+        }
+    };
+    CompiledRules::compile(rules).unwrap_or_else(|err| {
+        tracing::warn!(product_id, %err, "pr_review publish: editorial rules failed to compile; using defaults");
+        CompiledRules::compile(Default::default()).expect("default editorial rules compile")
+    })
+}
+
+fn plan_publish(
+    result: &ReviewResult,
+    patches: &[PrFilePatch],
+    existing: &[ExistingComment],
+    rules: &CompiledRules,
+    head_sha: &str,
+) -> PublishPlan {
+    let mut plan = PublishPlan::default();
+    let mut reported: HashSet<String> = HashSet::new();
+    let mut superseded: HashSet<u64> = HashSet::new();
+    for finding in &result.findings {
+        let fingerprint = finding_fingerprint(finding);
+        if !reported.insert(fingerprint.clone()) {
+            continue;
+        }
+        let Some(text) = apply_editorial(&render_finding(finding), rules) else {
+            plan.withheld += 1;
+            continue;
+        };
+        let body = format!("{text}\n\n{}", finding_marker(&fingerprint));
+        let live = existing
+            .iter()
+            .find(|comment| comment.fingerprint == fingerprint && comment.line.is_some());
+        if let Some(comment) = live {
+            if comment.body != body {
+                plan.updates.push((comment.id, body));
+            }
+            plan.reopen.push(comment.id);
+            continue;
+        }
+        // An outdated comment for the same finding is superseded by the
+        // new one below, not left open beside it.
+        superseded.extend(
+            existing
+                .iter()
+                .filter(|comment| comment.fingerprint == fingerprint)
+                .map(|comment| comment.id),
+        );
+        match resolve_anchor(finding, patches) {
+            Some((path, line)) => plan.new_comments.push(NewComment { path, line, body }),
+            None => plan.body_findings.push(text),
+        }
+    }
+    for comment in existing {
+        if comment.body.contains(RESOLVED_NOTE_PREFIX) {
+            continue;
+        }
+        if !reported.contains(&comment.fingerprint) || superseded.contains(&comment.id) {
+            let short_sha = &head_sha[..head_sha.len().min(7)];
+            let body = format!("{}\n\n{RESOLVED_NOTE_PREFIX} `{short_sha}`._", comment.body);
+            plan.resolve.push((comment.id, body));
+        }
+    }
+    plan
+}
+
+/// Run `text` through the editorial rules: the rewritten text on a
+/// rewrite, `None` when the rules block it.
+fn apply_editorial(text: &str, rules: &CompiledRules) -> Option<String> {
+    match boss_editorial::evaluate(text, "", rules, None) {
+        EditorialDecision::Allow => Some(text.to_owned()),
+        EditorialDecision::Rewrite { body, .. } => Some(body),
+        EditorialDecision::Block { findings } => {
+            tracing::info!(
+                findings = ?findings.iter().map(|f| f.description.as_str()).collect::<Vec<_>>(),
+                "pr_review publish: editorial rules blocked a review text; withholding it",
+            );
+            None
+        }
+    }
+}
+
+fn render_finding(finding: &ReviewFinding) -> String {
+    format!(
+        "**{}**\n\n{}\n\n<sub>{} · {}</sub>",
+        finding.title.trim(),
+        finding.detail.trim(),
+        finding.severity.as_str(),
+        category_label(finding),
+    )
+}
+
+fn category_label(finding: &ReviewFinding) -> String {
+    serde_json::to_value(&finding.category)
+        .ok()
+        .and_then(|value| value.as_str().map(|label| label.replace('_', " ")))
+        .unwrap_or_default()
+}
+
+fn render_review_body(
+    result: &ReviewResult,
+    body_findings: &[String],
+    rules: &CompiledRules,
+    head_sha: &str,
+) -> String {
+    let mut body = apply_editorial(result.summary.trim(), rules)
+        .unwrap_or_else(|| format!("Automated review: {} finding(s) at this commit.", result.findings.len()));
+    if !body_findings.is_empty() {
+        body.push_str("\n\n### Not tied to a diff line\n");
+        for finding in body_findings {
+            body.push_str("\n---\n\n");
+            body.push_str(finding);
+            body.push('\n');
+        }
+    }
+    body.push_str("\n\n");
+    body.push_str(&review_marker(head_sha));
+    body
+}
+
+/// Stable identity of a finding across re-reviews: file, category and
+/// the title with case and punctuation folded away.
+fn finding_fingerprint(finding: &ReviewFinding) -> String {
+    let title: String = finding
+        .title
+        .chars()
+        .filter(|c| c.is_alphanumeric() || c.is_whitespace())
+        .collect::<String>()
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut hasher = Sha256::new();
+    hasher.update(finding.file.trim_start_matches("./").as_bytes());
+    hasher.update([0]);
+    hasher.update(category_label(finding).as_bytes());
+    hasher.update([0]);
+    hasher.update(title.as_bytes());
+    hasher.finalize().iter().take(8).map(|b| format!("{b:02x}")).collect()
+}
+
+fn finding_marker(fingerprint: &str) -> String {
+    format!("{FINDING_MARKER_PREFIX}{fingerprint}{MARKER_SUFFIX}")
+}
+
+fn review_marker(head_sha: &str) -> String {
+    format!("{REVIEW_MARKER_PREFIX}{head_sha}{MARKER_SUFFIX}")
+}
+
+fn parse_existing_comments(comments: &[Value]) -> Vec<ExistingComment> {
+    comments
+        .iter()
+        .filter_map(|comment| {
+            let body = comment["body"].as_str()?;
+            let start = body.find(FINDING_MARKER_PREFIX)? + FINDING_MARKER_PREFIX.len();
+            let end = body[start..].find(MARKER_SUFFIX)? + start;
+            Some(ExistingComment {
+                id: comment["id"].as_u64()?,
+                fingerprint: body[start..end].to_owned(),
+                body: body.to_owned(),
+                line: comment["line"].as_u64().and_then(|line| u32::try_from(line).ok()),
+            })
+        })
+        .collect()
+}
+
+fn parse_review_threads(response: &Value) -> HashMap<u64, (String, bool)> {
+    response["data"]["repository"]["pullRequest"]["reviewThreads"]["nodes"]
+        .as_array()
+        .map(|threads| {
+            threads
+                .iter()
+                .filter_map(|thread| {
+                    let comment_id = thread["comments"]["nodes"][0]["databaseId"].as_u64()?;
+                    let thread_id = thread["id"].as_str()?.to_owned();
+                    Some((comment_id, (thread_id, thread["isResolved"].as_bool().unwrap_or(false))))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Resolve a finding's free-form `location` to `(path, line)` on the
+/// right-hand side of the PR's diff, or `None` when it should go in the
+/// review body instead. Tried in order: a `@@ ... +c,d @@` hunk header;
+/// a stated line number (`L42`, `line 42`, `:42`, the first commentable
+/// line of a `L40-50` range), moved at most [`LINE_SNAP_DISTANCE`] lines
+/// onto the diff; an identifier from the location, found on an added
+/// line, then on a context line.
+fn resolve_anchor(finding: &ReviewFinding, patches: &[PrFilePatch]) -> Option<(String, u32)> {
+    static HUNK: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"@@\s*-\d+(?:,\d+)?\s+\+(\d+)").expect("valid regex"));
+    static RANGE: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"(?i)\bL?(\d+)\s*(?:-|–|\.\.)\s*L?(\d+)\b").expect("valid regex"));
+    static LINE: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"(?i)(?:\bL|\blines?\s*|:)(\d+)\b").expect("valid regex"));
+    static IDENT: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[A-Za-z_][A-Za-z0-9_]{2,}").expect("valid regex"));
+
+    let location = finding.location.as_deref()?;
+    let file = finding.file.trim_start_matches("./");
+    let patch = patches.iter().find(|patch| patch.path == file)?;
+    let lines = patch.right_side_lines();
+    if lines.is_empty() {
+        return None;
+    }
+    let anchored = |line: u32| Some((patch.path.clone(), line));
+
+    if let Some(start) = HUNK.captures(location).and_then(|c| c[1].parse::<u32>().ok()) {
+        let in_hunk = lines.iter().filter(|l| l.line >= start);
+        if let Some(line) = in_hunk.clone().find(|l| l.added).or_else(|| in_hunk.clone().next()) {
+            return anchored(line.line);
+        }
+    }
+    if let Some(range) = RANGE.captures(location) {
+        let (from, to) = (range[1].parse::<u32>().ok()?, range[2].parse::<u32>().ok()?);
+        let within = |l: &&boss_github::pr_files::DiffLine| l.line >= from && l.line <= to;
+        if let Some(line) = lines
+            .iter()
+            .filter(within)
+            .find(|l| l.added)
+            .or_else(|| lines.iter().find(within))
+        {
+            return anchored(line.line);
+        }
+    }
+    if let Some(stated) = LINE.captures(location).and_then(|c| c[1].parse::<u32>().ok())
+        && let Some(nearest) = lines.iter().min_by_key(|l| l.line.abs_diff(stated))
+        && nearest.line.abs_diff(stated) <= LINE_SNAP_DISTANCE
+    {
+        return anchored(nearest.line);
+    }
+
+    let mut idents: Vec<&str> = IDENT
+        .find_iter(location)
+        .map(|m| m.as_str())
+        .filter(|word| !LOCATION_STOPWORDS.contains(&word.to_ascii_lowercase().as_str()))
+        .collect();
+    idents.sort_by_key(|word| std::cmp::Reverse(word.len()));
+    for ident in idents {
+        if let Some(line) = lines
+            .iter()
+            .find(|l| l.added && l.text.contains(ident))
+            .or_else(|| lines.iter().find(|l| l.text.contains(ident)))
+        {
+            return anchored(line.line);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pr_review::{ReviewFindingCategory, ReviewFindingConfidence, ReviewFindingSeverity};
+
+    const HEAD: &str = "0123456789abcdef";
+
+    fn finding(file: &str, location: Option<&str>, title: &str) -> ReviewFinding {
+        ReviewFinding::builder()
+            .severity(ReviewFindingSeverity::High)
+            .category(ReviewFindingCategory::Correctness)
+            .file(file)
+            .maybe_location(location.map(str::to_owned))
+            .title(title)
+            .detail("Return the error instead of unwrapping it.")
+            .confidence(ReviewFindingConfidence::High)
+            .build()
+    }
+
+    fn result(findings: Vec<ReviewFinding>) -> ReviewResult {
+        ReviewResult::builder()
+            .pr_url("https://github.com/org/repo/pull/7")
+            .head_sha(HEAD)
+            .summary("One correctness problem in the loader.")
+            .revision_warranted(true)
+            .findings(findings)
+            .regression_check(Default::default())
+            .build()
+    }
+
+    fn patches() -> Vec<PrFilePatch> {
+        vec![PrFilePatch {
+            path: "src/loader.rs".to_owned(),
+            patch: Some(
+                "@@ -10,4 +10,5 @@ impl Loader {\n     fn load(&self) -> Config {\n-        read().unwrap()\n+        \
+                 let raw = read_config().unwrap();\n+        parse(raw)\n     }\n \n@@ -80,2 +81,3 @@\n fn helper() {}\n+fn \
+                 extra() {}\n }"
+                    .to_owned(),
+            ),
+        }]
+    }
+
+    fn rules() -> CompiledRules {
+        CompiledRules::compile(Default::default()).unwrap()
+    }
+
+    #[test]
+    fn locations_resolve_by_line_range_hunk_and_identifier() {
+        let patches = patches();
+        let at = |location: &str| resolve_anchor(&finding("src/loader.rs", Some(location), "t"), &patches);
+        assert_eq!(at("fn load, ~L11"), Some(("src/loader.rs".to_owned(), 11)));
+        // Line 9 is outside the hunk but within snapping distance.
+        assert_eq!(at("line 9"), Some(("src/loader.rs".to_owned(), 10)));
+        assert_eq!(at("L81-83"), Some(("src/loader.rs".to_owned(), 82)));
+        assert_eq!(at("@@ -80,2 +81,3 @@"), Some(("src/loader.rs".to_owned(), 82)));
+        assert_eq!(at("call to read_config"), Some(("src/loader.rs".to_owned(), 11)));
+        assert_eq!(at("line 40"), None);
+        assert_eq!(at("fn unrelated_symbol"), None);
+        assert_eq!(
+            resolve_anchor(&finding("src/loader.rs", None, "t"), &patches),
+            None,
+            "a file-level finding goes in the review body"
+        );
+        assert_eq!(
+            resolve_anchor(&finding("src/other.rs", Some("L11"), "t"), &patches),
+            None
+        );
+    }
+
+    #[test]
+    fn first_publish_inlines_anchored_findings_and_lists_the_rest() {
+        let result = result(vec![
+            finding("src/loader.rs", Some("L11"), "Unwrap panics on a missing file"),
+            finding("src/loader.rs", None, "Loader has no tests"),
+            finding("src/loader.rs", Some("L11"), "unwrap panics on a missing file!"),
+        ]);
+        let plan = plan_publish(&result, &patches(), &[], &rules(), HEAD);
+        assert_eq!(plan.new_comments.len(), 1, "a repeated finding is posted once");
+        assert_eq!(plan.new_comments[0].line, 11);
+        assert!(plan.new_comments[0].body.contains(FINDING_MARKER_PREFIX));
+        assert_eq!(plan.body_findings.len(), 1);
+        assert!(plan.updates.is_empty() && plan.resolve.is_empty());
+    }
+
+    #[test]
+    fn re_review_updates_reported_findings_and_resolves_dropped_ones() {
+        let kept = finding("src/loader.rs", Some("L11"), "Unwrap panics on a missing file");
+        let dropped = finding("src/loader.rs", Some("L12"), "Parse error is swallowed");
+        let kept_fingerprint = finding_fingerprint(&kept);
+        let existing = vec![
+            ExistingComment {
+                id: 1,
+                fingerprint: kept_fingerprint.clone(),
+                body: format!("old wording\n\n{}", finding_marker(&kept_fingerprint)),
+                line: Some(11),
+            },
+            ExistingComment {
+                id: 2,
+                fingerprint: finding_fingerprint(&dropped),
+                body: "Parse error is swallowed".to_owned(),
+                line: Some(12),
+            },
+        ];
+        let plan = plan_publish(&result(vec![kept]), &patches(), &existing, &rules(), HEAD);
+        assert!(plan.new_comments.is_empty(), "the kept finding is not posted again");
+        assert_eq!(plan.updates.len(), 1);
+        assert_eq!(plan.updates[0].0, 1);
+        assert_eq!(plan.resolve.len(), 1);
+        assert_eq!(plan.resolve[0].0, 2);
+        assert!(plan.resolve[0].1.contains("`0123456`"));
+
+        // Once noted as resolved, a comment is left alone.
+        let mut resolved = existing.clone();
+        resolved[1].body = plan.resolve[0].1.clone();
+        let again = plan_publish(&result(vec![]), &patches(), &resolved[1..], &rules(), HEAD);
+        assert!(again.resolve.is_empty());
+    }
+
+    #[test]
+    fn editorial_rules_withhold_blocked_findings() {
+        let mut leaky = finding("src/loader.rs", Some("L11"), "Leaks internals");
+        leaky.detail = "The engine should never see this path.".to_owned();
+        let plan = plan_publish(&result(vec![leaky]), &patches(), &[], &rules(), HEAD);
+        assert_eq!(plan.withheld, 1);
+        assert!(plan.new_comments.is_empty() && plan.body_findings.is_empty());
+    }
+
+    #[test]
+    fn existing_comments_parse_only_bot_markers() {
+        let comments = vec![
+            json!({"id": 5, "line": 11, "body": format!("text\n\n{}", finding_marker("abcd"))}),
+            json!({"id": 6, "line": null, "body": format!("text\n\n{}", finding_marker("ef01"))}),
+            json!({"id": 7, "line": 3, "body": "a human comment"}),
+        ];
+        let parsed = parse_existing_comments(&comments);
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].fingerprint, "abcd");
+        assert_eq!(parsed[1].line, None);
+    }
+}
@@ -0,0 +1,253 @@
+//! Platform process probes behind worker classification: a pid's parent
+//! and a connected unix socket's peer pid.
+//!
+//! Hook correlation ([`crate::worker_registry::WorkerRegistry::lookup_with_ancestor_walk`])
+//! and the RPC trust tiers (`app::trust`) both start from the socket peer
+//! pid and walk up the process tree until they meet a registered worker
+//! shell or trust root. Both answers must come from the kernel, not from
+//! anything the peer says about itself.
+//!
+//! | | peer pid | parent pid |
+//! |---|---|---|
+//! | macOS | `getsockopt(SOL_LOCAL, LOCAL_PEERPID)` | `proc_pidinfo(PROC_PIDTBSDINFO)` (`libproc.rs`) |
+//! | Linux | `getsockopt(SOL_SOCKET, SO_PEERCRED)` | `/proc/<pid>/stat`, pinned by a pidfd |
+//!
+//! Other platforms return [`io::ErrorKind::Unsupported`], which every
+//! caller already treats as "not a descendant" — a peer that cannot be
+//! classified is never promoted to a worker run or a trust root.
+
+use std::io;
+use std::os::fd::RawFd;
+
+/// Parent of `pid`, or `None` when `pid` is the root of its process tree
+/// (parent 0: `launchd` / `init` themselves, or a process whose parent is
+/// outside the engine's pid namespace).
+#[cfg(target_os = "macos")]
+pub fn parent_pid(pid: libc::pid_t) -> io::Result<Option<libc::pid_t>> {
+    let ppid = crate::libproc::proc_bsd_info(pid)?.ppid;
+    if ppid == 0 { Ok(None) } else { Ok(Some(ppid)) }
+}
+
+/// Parent of `pid`, or `None` when `pid` is the root of its process tree
+/// (parent 0: `init` itself, or a process whose parent is outside the
+/// engine's pid namespace).
+///
+/// Reads field 4 of `/proc/<pid>/stat`. A pid can exit and be recycled
+/// between any two syscalls, so the read is bracketed by a pidfd: the fd
+/// is opened first and pins the process the pid named at that moment,
+/// and once the stat has been read the pidfd must still name a live
+/// process. If it does not, the stat may belong to a newcomer that reused
+/// the pid, and the probe fails with `ESRCH` instead of handing an
+/// ancestor walk a stranger's parent. Kernels without `pidfd_open` (before
+/// 5.3) fall back to the bare read.
+#[cfg(target_os = "linux")]
+pub fn parent_pid(pid: libc::pid_t) -> io::Result<Option<libc::pid_t>> {
+    if pid <= 0 {
+        return Err(io::Error::from_raw_os_error(libc::ESRCH));
+    }
+    let pidfd = linux::Pidfd::open(pid)?;
+    let stat = std::fs::read_to_string(format!("/proc/{pid}/stat"))?;
+    let ppid = linux::parse_stat_ppid(&stat)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, format!("unparseable /proc/{pid}/stat")))?;
+    if let Some(pidfd) = pidfd
+        && !pidfd.is_alive()?
+    {
+        return Err(io::Error::from_raw_os_error(libc::ESRCH));
+    }
+    if ppid == 0 { Ok(None) } else { Ok(Some(ppid)) }
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
+pub fn parent_pid(_pid: libc::pid_t) -> io::Result<Option<libc::pid_t>> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "parent pid lookup is not supported on this platform",
+    ))
+}
+
+/// `level` for `getsockopt(SOL_LOCAL, LOCAL_PEERPID)` on macOS.
+#[cfg(target_os = "macos")]
+const SOL_LOCAL: libc::c_int = 0;
+/// `optname` for the LOCAL_PEERPID getsockopt on macOS.
+#[cfg(target_os = "macos")]
+const LOCAL_PEERPID: libc::c_int = 0x002;
+
+/// Pid of the process on the other end of the connected unix stream socket
+/// `fd`, via `getsockopt(SOL_LOCAL, LOCAL_PEERPID)`.
+#[cfg(target_os = "macos")]
+pub fn socket_peer_pid(fd: RawFd) -> io::Result<libc::pid_t> {
+    let mut pid: libc::pid_t = 0;
+    let mut len: libc::socklen_t = std::mem::size_of::<libc::pid_t>() as libc::socklen_t;
+    // SAFETY: `fd` is borrowed from the caller's socket and remains valid
+    // for this call; `pid` and `len` are stack-local mutables and their
+    // addresses are passed only to `getsockopt`.
+    let rc = unsafe {
+        libc::getsockopt(
+            fd,
+            SOL_LOCAL,
+            LOCAL_PEERPID,
+            &mut pid as *mut _ as *mut libc::c_void,
+            &mut len,
+        )
+    };
+    if rc != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(pid)
+}
+
+/// Pid of the process on the other end of the connected unix stream socket
+/// `fd`, via `getsockopt(SO_PEERCRED)`. The kernel records the credentials
+/// at `connect()` time, so unlike `LOCAL_PEERPID` the answer survives the
+/// peer closing its end.
+#[cfg(target_os = "linux")]
+pub fn socket_peer_pid(fd: RawFd) -> io::Result<libc::pid_t> {
+    let mut cred = libc::ucred { pid: 0, uid: 0, gid: 0 };
+    let mut len: libc::socklen_t = std::mem::size_of::<libc::ucred>() as libc::socklen_t;
+    // SAFETY: `fd` is borrowed from the caller's socket and remains valid
+    // for this call; `cred` and `len` are stack-local mutables and their
+    // addresses are passed only to `getsockopt`.
+    let rc = unsafe {
+        libc::getsockopt(
+            fd,
+            libc::SOL_SOCKET,
+            libc::SO_PEERCRED,
+            &mut cred as *mut _ as *mut libc::c_void,
+            &mut len,
+        )
+    };
+    if rc != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    // A peer in another pid namespace is reported as pid 0.
+    if cred.pid <= 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "socket peer is outside the engine's pid namespace",
+        ));
+    }
+    Ok(cred.pid)
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
+pub fn socket_peer_pid(_fd: RawFd) -> io::Result<libc::pid_t> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "peer pid lookup is not supported on this platform",
+    ))
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use std::io;
+    use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+
+    /// A pidfd for one process. Closed on drop.
+    pub(super) struct Pidfd(OwnedFd);
+
+    impl Pidfd {
+        /// `pidfd_open(pid, 0)`. `Ok(None)` when the kernel predates pidfds
+        /// (`ENOSYS`) or a seccomp policy refuses the syscall (`EPERM`);
+        /// `ESRCH` when `pid` is already gone.
+        pub(super) fn open(pid: libc::pid_t) -> io::Result<Option<Self>> {
+            // SAFETY: `pidfd_open` takes a pid and flags and returns a new
+            // fd or -1; no memory is passed.
+            let fd = unsafe { libc::syscall(libc::SYS_pidfd_open, pid, 0) };
+            if fd < 0 {
+                let err = io::Error::last_os_error();
+                return match err.raw_os_error() {
+                    Some(libc::ENOSYS) | Some(libc::EPERM) => Ok(None),
+                    _ => Err(err),
+                };
+            }
+            // SAFETY: the syscall succeeded, so `fd` is a fresh descriptor
+            // this process owns and nothing else will close.
+            Ok(Some(Self(unsafe { OwnedFd::from_raw_fd(fd as libc::c_int) })))
+        }
+
+        /// Whether the pinned process has not yet exited. A zombie still
+        /// counts as alive, matching what `/proc/<pid>/stat` showed.
+        pub(super) fn is_alive(&self) -> io::Result<bool> {
+            // SAFETY: signal 0 delivers nothing; the kernel only checks that
+            // the process behind the pidfd still exists.
+            let rc = unsafe {
+                libc::syscall(
+                    libc::SYS_pidfd_send_signal,
+                    self.0.as_raw_fd(),
+                    0,
+                    std::ptr::null::<libc::siginfo_t>(),
+                    0,
+                )
+            };
+            if rc == 0 {
+                return Ok(true);
+            }
+            let err = io::Error::last_os_error();
+            match err.raw_os_error() {
+                Some(libc::ESRCH) => Ok(false),
+                // Alive but owned by another user.
+                Some(libc::EPERM) => Ok(true),
+                _ => Err(err),
+            }
+        }
+    }
+
+    /// Field 4 (`ppid`) of a `/proc/<pid>/stat` line. Field 2 is the command
+    /// name in parentheses and may itself contain spaces and `)`, so the
+    /// fields are counted from the *last* `)`.
+    pub(super) fn parse_stat_ppid(stat: &str) -> Option<libc::pid_t> {
+        let (_, after_comm) = stat.rsplit_once(')')?;
+        let mut fields = after_comm.split_whitespace();
+        let _state = fields.next()?;
+        fields.next()?.parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parent_of_self_is_the_test_runner() {
+        let self_pid = std::process::id() as libc::pid_t;
+        #[cfg(any(target_os = "macos", target_os = "linux"))]
+        {
+            let parent = parent_pid(self_pid).unwrap();
+            assert_eq!(parent, Some(unsafe { libc::getppid() }));
+        }
+        #[cfg(not(any(target_os = "macos", target_os = "linux")))]
+        assert_eq!(parent_pid(self_pid).unwrap_err().kind(), io::ErrorKind::Unsupported);
+    }
+
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
+    #[test]
+    fn parent_of_a_reaped_child_is_an_error() {
+        let mut child = std::process::Command::new("true").spawn().expect("spawn true");
+        let pid = child.id() as libc::pid_t;
+        child.wait().unwrap();
+        assert!(parent_pid(pid).is_err());
+    }
+
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
+    #[test]
+    fn socket_peer_pid_names_the_connecting_process() {
+        use std::os::fd::AsRawFd;
+        let (ours, _theirs) = std::os::unix::net::UnixStream::pair().unwrap();
+        assert_eq!(
+            socket_peer_pid(ours.as_raw_fd()).unwrap(),
+            std::process::id() as libc::pid_t
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn stat_ppid_survives_hostile_command_names() {
+        assert_eq!(linux::parse_stat_ppid("1234 (bash) S 987 1234 1234 0 -1"), Some(987));
+        assert_eq!(
+            linux::parse_stat_ppid("1234 (evil) S 1 (x) S 42 1234) R 555 1 1"),
+            Some(555)
+        );
+        assert_eq!(linux::parse_stat_ppid("1 (init) S 0 1 1"), Some(0));
+        assert_eq!(linux::parse_stat_ppid("garbage"), None);
+    }
+}
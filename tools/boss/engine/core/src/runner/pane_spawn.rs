@@ -304,19 +304,7 @@ impl PaneSpawnRunner {
         if let Some(injected) = self.boss_event_path_override.get() {
             return injected.clone();
         }
-        let engine_path = std::env::current_exe().unwrap_or_default();
-        let workspace = std::env::var_os("BUILD_WORKSPACE_DIRECTORY").map(PathBuf::from);
-        let env_override = std::env::var_os("BOSS_EVENT_BIN").map(PathBuf::from);
-        let boss_bin_dir = std::env::var_os("BOSS_BIN_DIR").map(PathBuf::from);
-        let stable_bin_dir = boss_log_files::default_state_root().map(|root| root.join("bin"));
-        resolve_boss_event_binary(
-            &engine_path,
-            workspace.as_deref(),
-            env_override.as_deref(),
-            boss_bin_dir.as_deref(),
-            stable_bin_dir.as_deref(),
-        )
-        .unwrap_or_else(|| {
+        default_boss_event_binary().unwrap_or_else(|| {
             panic!(
                 "boss-event binary not found: none of BOSS_EVENT_BIN, BOSS_BIN_DIR, \
                  the stable bin dir, runfiles, bazel-bin, or the engine-sibling resolved \
@@ -346,6 +334,25 @@ impl PaneSpawnRunner {
     }
 }
 
+/// [`resolve_boss_event_binary`] over this engine process's own inputs
+/// (current exe, `BUILD_WORKSPACE_DIRECTORY`, `BOSS_EVENT_BIN`,
+/// `BOSS_BIN_DIR`, the stable state-root bin dir). Shared by the pane
+/// spawner and the container host adapter, which bind-mounts the same shim.
+pub(crate) fn default_boss_event_binary() -> Option<PathBuf> {
+    let engine_path = std::env::current_exe().unwrap_or_default();
+    let workspace = std::env::var_os("BUILD_WORKSPACE_DIRECTORY").map(PathBuf::from);
+    let env_override = std::env::var_os("BOSS_EVENT_BIN").map(PathBuf::from);
+    let boss_bin_dir = std::env::var_os("BOSS_BIN_DIR").map(PathBuf::from);
+    let stable_bin_dir = boss_log_files::default_state_root().map(|root| root.join("bin"));
+    resolve_boss_event_binary(
+        &engine_path,
+        workspace.as_deref(),
+        env_override.as_deref(),
+        boss_bin_dir.as_deref(),
+        stable_bin_dir.as_deref(),
+    )
+}
+
 /// Resolve the absolute path of the `boss-event` shim. Thin re-export of
 /// [`boss_engine_worker_bin::resolve_boss_event_binary`] so existing
 /// `crate::runner::resolve_boss_event_binary` call sites (and the
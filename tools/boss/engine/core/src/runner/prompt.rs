@@ -2299,8 +2299,10 @@ fn compose_ci_remediation_fragment(attempt: &CiRemediation) -> String {
         );
         out.push_str(
             "1. Re-run the failing build via the per-provider CLI (`bk build retry <build-id>` \
-             for Buildkite or `gh run rerun <run-id> --failed` for GitHub Actions). The failing \
-             check's `target_url` above carries the right id.\n\
+             for Buildkite or `gh run rerun <run-id> --failed` for GitHub Actions) or API \
+             (`POST /api/v4/projects/<project>/jobs/<job-id>/retry` for GitLab CI, \
+             `POST <job-url>/build` for Jenkins). The failing check's `target_url` above carries \
+             the right id.\n\
              2. Call `boss engine ci mark-retriggered --attempt-id <attempt-id> --new-id <new-build-or-run-id>` \
              so the engine records the new run id and stays out of the budget path. Do NOT call \
              `mark-failed` or push code.\n\
@@ -2416,7 +2418,11 @@ fn compose_ci_remediation_fragment(attempt: &CiRemediation) -> String {
                  (slug and build number are in the check's `target_url` above; job UUIDs come \
                  from `bk build view <N> --pipeline <slug>`)\n\
                  - GitHub Actions: `gh run view --log-failed --job <job-id>` \
-                 (job id from the failing check URL above)\n\n",
+                 (job id from the failing check URL above)\n\
+                 - GitLab CI: `GET /api/v4/projects/<url-encoded project>/jobs/<job-id>/trace` \
+                 with `PRIVATE-TOKEN: $GITLAB_TOKEN`\n\
+                 - Jenkins: `GET <job-url>/<build-number>/consoleText` \
+                 with `--user \"$JENKINS_USER:$JENKINS_API_TOKEN\"`\n\n",
             ));
             out.push_str("Engine-collected log excerpt (from the synthetic merge commit's failing job):\n\n");
             match attempt.log_excerpt.as_deref().map(str::trim) {
@@ -2460,7 +2466,7 @@ fn compose_ci_remediation_fragment(attempt: &CiRemediation) -> String {
                 }
             }
         } else {
-            out.push_str("Engine-collected log excerpt (failing job tail):\n\n");
+            out.push_str("Engine-collected log excerpt (failing step of the failing job):\n\n");
             match attempt.log_excerpt.as_deref().map(str::trim) {
                 Some(tail) if !tail.is_empty() => {
                     out.push_str("```\n");
@@ -2470,8 +2476,11 @@ fn compose_ci_remediation_fragment(attempt: &CiRemediation) -> String {
                 _ => {
                     out.push_str(
                         "_The engine's pre-spawn log fetch did not produce an excerpt for this attempt. \
-                         Use the ready-to-run commands above (`bk job log --pipeline …`) or \
-                         `gh run view --log-failed --job <job-id>` (job id from the failing check URL)._\n\n",
+                         Use the ready-to-run commands above (`bk job log --pipeline …`), \
+                         `gh run view --log-failed --job <job-id>` (job id from the failing check URL), \
+                         GitLab's job trace API (`…/-/jobs/<job-id>` → \
+                         `/api/v4/projects/<project>/jobs/<job-id>/trace`), or Jenkins' \
+                         `<build-url>/consoleText`._\n\n",
                     );
                 }
             }
@@ -2648,8 +2657,10 @@ fn compose_ci_remediation_prompt(
     );
     prompt.push_str(
         "1. Re-run the failing build via the per-provider CLI (`bk build retry <build-id>` \
-         for Buildkite or `gh run rerun <run-id> --failed` for GitHub Actions). The failing \
-         check's `target_url` above carries the right id.\n\
+         for Buildkite or `gh run rerun <run-id> --failed` for GitHub Actions) or API \
+         (`POST /api/v4/projects/<project>/jobs/<job-id>/retry` for GitLab CI, \
+         `POST <job-url>/build` for Jenkins). The failing check's `target_url` above carries \
+         the right id.\n\
          2. Call `boss engine ci mark-retriggered --attempt-id <attempt-id> --new-id <new-build-or-run-id>` \
          so the engine records the new run id and stays out of the budget path. Do NOT call \
          `mark-failed` or push code.\n\
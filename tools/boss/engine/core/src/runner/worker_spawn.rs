@@ -500,7 +500,13 @@ pub(crate) async fn compose_worker_spawn(
                 let merged_tasks = work_db
                     .list_recently_completed_automation_tasks_for_product(&automation.product_id, since_epoch)
                     .unwrap_or_default();
-                let triage_context = crate::automation_triage::TriageContext::from_rows(open_tasks, merged_tasks);
+                let mut triage_context = crate::automation_triage::TriageContext::from_rows(open_tasks, merged_tasks);
+                // Event-triggered runs carry what fired them on their run row.
+                triage_context.trigger_event = work_db
+                    .automation_run_for_triage_execution(&execution.id)
+                    .ok()
+                    .flatten()
+                    .and_then(|run| run.trigger_event);
                 crate::automation_triage::render_triage_preamble(
                     &automation,
                     &product_name,
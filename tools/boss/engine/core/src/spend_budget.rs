@@ -0,0 +1,530 @@
+//! Dispatch-time spend budgets.
+//!
+//! `boss cost` only ever reported spend after the fact, so an automation
+//! loop that kept re-dispatching itself could run all night before anyone
+//! looked. A budget ([`boss_protocol::SpendBudget`]) caps what a product
+//! or project may spend per UTC day, week or month, in tokens or in
+//! estimated USD, and this module is what checks it:
+//!
+//! - [`evaluate`] computes every budget's spend for its current period
+//!   from the same `work_runs` token columns the cost report reads, and
+//!   files or resolves the limit attention items as a side effect.
+//! - [`BudgetSnapshot::hold_for`] answers "may this scope dispatch?" for
+//!   `drain_ready_queue` and the dispatch-admission preview. A scope at
+//!   or past a hard limit is held: its `ready` rows stay `ready` with a
+//!   `dispatch_wait_reason` naming the budget, and dispatch resumes on
+//!   its own once the period rolls over or the budget is raised.
+//!
+//! Each limit is announced once per period on the scoped product or
+//! project — [`SPEND_BUDGET_SOFT_LIMIT_ATTENTION_KIND`] at the soft limit,
+//! [`SPEND_BUDGET_HARD_LIMIT_ATTENTION_KIND`] at the hard one — and the
+//! item is resolved here again when no budget on that scope is still past
+//! the limit. Runs only count once they record their tokens, so spend
+//! from workers still running when a limit is reached lands on top of it;
+//! the gate stops new work, it does not interrupt old.
+//!
+//! USD budgets price through [`crate::cost_pricing`], overrides included.
+//! A model the table does not price contributes nothing and marks the
+//! figure partial, so a USD budget can under-count but never over-count.
+
+use boss_protocol::{SpendBudget, SpendBudgetMetric, SpendBudgetPeriod, SpendBudgetState, SpendBudgetStatus};
+use chrono::{DateTime, Datelike, Days, Months, NaiveDate, NaiveTime};
+
+use crate::cost_pricing;
+use crate::work::{ModelSpend, SpendBudgetLimit, SpendBudgetRow, WorkDb};
+
+/// Filed on a product/project when a budget's soft limit is reached.
+/// Resolved by [`evaluate`] once no budget on the scope is past its soft
+/// limit (the period rolled over, or the limit was raised or removed).
+pub const SPEND_BUDGET_SOFT_LIMIT_ATTENTION_KIND: &str = "spend_budget_soft_limit";
+
+/// Filed on a product/project when a budget's hard limit is reached and
+/// its dispatch is held. Resolved the same way as the soft-limit kind.
+pub const SPEND_BUDGET_HARD_LIMIT_ATTENTION_KIND: &str = "spend_budget_hard_limit";
+
+/// `[start, end)` epoch seconds of the UTC calendar period containing
+/// `now_epoch_s`. Weeks start on Monday.
+pub fn period_bounds(period: SpendBudgetPeriod, now_epoch_s: i64) -> (i64, i64) {
+    let today = DateTime::from_timestamp(now_epoch_s, 0)
+        .unwrap_or_default()
+        .date_naive();
+    let (start, end) = match period {
+        SpendBudgetPeriod::Day => (today, today + Days::new(1)),
+        SpendBudgetPeriod::Week => {
+            let start = today - Days::new(u64::from(today.weekday().num_days_from_monday()));
+            (start, start + Days::new(7))
+        }
+        SpendBudgetPeriod::Month => {
+            let start = today.with_day(1).unwrap_or(today);
+            (start, start + Months::new(1))
+        }
+    };
+    (midnight(start), midnight(end))
+}
+
+fn midnight(date: NaiveDate) -> i64 {
+    date.and_time(NaiveTime::MIN).and_utc().timestamp()
+}
+
+/// Fold per-model sums into the budget's metric. The flag is `true` when
+/// a USD figure left out a model the pricing table does not price.
+pub fn measure(metric: SpendBudgetMetric, spend: &[ModelSpend]) -> (f64, bool) {
+    let mut total = 0.0;
+    let mut partial = false;
+    for row in spend {
+        match metric {
+            SpendBudgetMetric::Tokens => {
+                total +=
+                    (row.input_tokens + row.output_tokens + row.cache_creation_tokens + row.cache_read_tokens) as f64;
+            }
+            SpendBudgetMetric::Usd => match row.model.as_deref().and_then(cost_pricing::price_for_model) {
+                Some(price) => {
+                    total += cost_pricing::estimate_usd(
+                        price,
+                        row.input_tokens,
+                        row.output_tokens,
+                        row.cache_creation_tokens,
+                        row.cache_read_tokens,
+                    );
+                }
+                None => partial = true,
+            },
+        }
+    }
+    (total, partial)
+}
+
+fn limit_reached(limit: Option<f64>, spent: f64) -> bool {
+    limit.is_some_and(|limit| spent >= limit)
+}
+
+pub fn state_for(budget: &SpendBudget, spent: f64) -> SpendBudgetState {
+    if limit_reached(budget.hard_limit, spent) {
+        SpendBudgetState::HardLimitReached
+    } else if limit_reached(budget.soft_limit, spent) {
+        SpendBudgetState::SoftLimitReached
+    } else {
+        SpendBudgetState::UnderLimit
+    }
+}
+
+/// The current period's spend against one budget. Read-only.
+pub fn status_for(work_db: &WorkDb, budget: &SpendBudget, now_epoch_s: i64) -> anyhow::Result<SpendBudgetStatus> {
+    let (start, end) = period_bounds(budget.period, now_epoch_s);
+    let spend = work_db.spend_by_model(&budget.scope_kind, &budget.scope_id, start, end)?;
+    let (spent, spent_partial) = measure(budget.metric, &spend);
+    Ok(SpendBudgetStatus {
+        budget: budget.clone(),
+        period_end_epoch_s: end,
+        period_start_epoch_s: start,
+        spent,
+        spent_partial,
+        state: state_for(budget, spent),
+    })
+}
+
+/// Every budget's status at one instant, as [`evaluate`] found it.
+#[derive(Debug, Clone, Default)]
+pub struct BudgetSnapshot {
+    pub statuses: Vec<SpendBudgetStatus>,
+}
+
+impl BudgetSnapshot {
+    /// Why dispatch for a task in `product_id` (and `project_id`, when it
+    /// has one) must wait, or `None` when no hard limit covers it. A
+    /// budget's id is compared only against the id of its own kind.
+    pub fn hold_for(&self, product_id: &str, project_id: Option<&str>) -> Option<String> {
+        self.statuses
+            .iter()
+            .filter(|status| status.state == SpendBudgetState::HardLimitReached)
+            .find(|status| {
+                let scope = Some(status.budget.scope_id.as_str());
+                match status.budget.scope_kind.as_str() {
+                    "product" => scope == Some(product_id),
+                    "project" => scope == project_id,
+                    _ => false,
+                }
+            })
+            .map(hold_reason)
+    }
+}
+
+fn period_adjective(period: SpendBudgetPeriod) -> &'static str {
+    match period {
+        SpendBudgetPeriod::Day => "daily",
+        SpendBudgetPeriod::Week => "weekly",
+        SpendBudgetPeriod::Month => "monthly",
+    }
+}
+
+/// `12,345 tokens` or `$12.35`.
+pub fn format_amount(metric: SpendBudgetMetric, amount: f64) -> String {
+    match metric {
+        SpendBudgetMetric::Tokens => {
+            let digits = (amount.round() as i64).to_string();
+            let mut grouped = String::new();
+            for (i, ch) in digits.chars().enumerate() {
+                if i > 0 && (digits.len() - i).is_multiple_of(3) && ch != '-' {
+                    grouped.push(',');
+                }
+                grouped.push(ch);
+            }
+            format!("{grouped} tokens")
+        }
+        SpendBudgetMetric::Usd => format!("${amount:.2}"),
+    }
+}
+
+fn format_utc(epoch_s: i64) -> String {
+    DateTime::from_timestamp(epoch_s, 0)
+        .map(|at| at.format("%Y-%m-%d %H:%M UTC").to_string())
+        .unwrap_or_else(|| epoch_s.to_string())
+}
+
+/// The `dispatch_wait_reason` (and admission blocker message) for a scope
+/// held by `status`.
+pub fn hold_reason(status: &SpendBudgetStatus) -> String {
+    let budget = &status.budget;
+    format!(
+        "Held by the {} {} budget for {} ({} of {} spent, estimated) — resumes {} or when the budget is raised",
+        period_adjective(budget.period),
+        budget.metric.as_str(),
+        budget.scope_name,
+        format_amount(budget.metric, status.spent),
+        format_amount(budget.metric, budget.hard_limit.unwrap_or_default()),
+        format_utc(status.period_end_epoch_s),
+    )
+}
+
+fn attention_text(status: &SpendBudgetStatus, limit: SpendBudgetLimit) -> (String, String) {
+    let budget = &status.budget;
+    let (label, amount, consequence) = match limit {
+        SpendBudgetLimit::Soft => (
+            "soft",
+            budget.soft_limit.unwrap_or_default(),
+            "Dispatch continues; this is a warning.",
+        ),
+        SpendBudgetLimit::Hard => (
+            "hard",
+            budget.hard_limit.unwrap_or_default(),
+            "New dispatch for this scope is held. Workers already running are not interrupted.",
+        ),
+    };
+    let title = format!(
+        "{} {} budget {label} limit reached",
+        budget.scope_name,
+        period_adjective(budget.period),
+    );
+    let partial = if status.spent_partial {
+        " Runs on models the pricing table does not price are not counted, so the real figure is higher."
+    } else {
+        ""
+    };
+    let body = format!(
+        "{} of the {label} limit {} spent this period (estimated, {} to {}).{partial}\n\n\
+         {consequence} The budget resets at {}. Inspect it with `boss cost budget list`; raise or \
+         remove it with `boss cost budget set` / `boss cost budget rm {}`.",
+        format_amount(budget.metric, status.spent),
+        format_amount(budget.metric, amount),
+        format_utc(status.period_start_epoch_s),
+        format_utc(status.period_end_epoch_s),
+        format_utc(status.period_end_epoch_s),
+        budget.id,
+    );
+    (title, body)
+}
+
+/// Compute every budget's status and reconcile the limit attention items:
+/// file one the first time a limit is reached in a period, resolve a
+/// scope's item once none of its budgets is past that limit. Attention
+/// failures are logged, not returned — a missed notification must not
+/// stop the gate from holding.
+pub fn evaluate(work_db: &WorkDb, now_epoch_s: i64) -> anyhow::Result<BudgetSnapshot> {
+    let rows = work_db.list_spend_budgets(None)?;
+    let mut statuses = Vec::with_capacity(rows.len());
+    let mut cleared: Vec<(String, String, SpendBudgetLimit)> = Vec::new();
+    for row in &rows {
+        let status = status_for(work_db, &row.budget, now_epoch_s)?;
+        for (limit, reached, notified) in [
+            (
+                SpendBudgetLimit::Soft,
+                limit_reached(row.budget.soft_limit, status.spent),
+                row.soft_notified_period_start,
+            ),
+            (
+                SpendBudgetLimit::Hard,
+                limit_reached(row.budget.hard_limit, status.spent),
+                row.hard_notified_period_start,
+            ),
+        ] {
+            if reached && notified != Some(status.period_start_epoch_s) {
+                notify(work_db, row, &status, limit);
+            } else if !reached && notified.is_some() {
+                if let Err(err) = work_db.set_spend_budget_notified(&row.budget.id, limit, None) {
+                    tracing::warn!(budget_id = %row.budget.id, ?err, "spend budget: clearing notification marker failed");
+                }
+                cleared.push((row.budget.scope_kind.clone(), row.budget.scope_id.clone(), limit));
+            }
+        }
+        statuses.push(status);
+    }
+
+    for (scope_kind, scope_id, limit) in cleared {
+        let still_reached = statuses.iter().any(|status| {
+            status.budget.scope_kind == scope_kind
+                && status.budget.scope_id == scope_id
+                && match limit {
+                    SpendBudgetLimit::Soft => limit_reached(status.budget.soft_limit, status.spent),
+                    SpendBudgetLimit::Hard => limit_reached(status.budget.hard_limit, status.spent),
+                }
+        });
+        if !still_reached && let Err(err) = work_db.resolve_external_tracker_attention(&scope_id, attention_kind(limit))
+        {
+            tracing::warn!(%scope_id, ?err, "spend budget: resolving limit attention failed");
+        }
+    }
+    Ok(BudgetSnapshot { statuses })
+}
+
+/// Resolve the limit attention items a deleted budget may have left open,
+/// unless another budget on the scope is still past the same limit.
+pub fn resolve_after_delete(work_db: &WorkDb, deleted: &SpendBudgetRow, now_epoch_s: i64) {
+    let snapshot = match evaluate(work_db, now_epoch_s) {
+        Ok(snapshot) => snapshot,
+        Err(err) => {
+            tracing::warn!(budget_id = %deleted.budget.id, ?err, "spend budget: re-evaluation after delete failed");
+            return;
+        }
+    };
+    for (limit, notified) in [
+        (SpendBudgetLimit::Soft, deleted.soft_notified_period_start),
+        (SpendBudgetLimit::Hard, deleted.hard_notified_period_start),
+    ] {
+        let still_reached = snapshot.statuses.iter().any(|status| {
+            status.budget.scope_kind == deleted.budget.scope_kind
+                && status.budget.scope_id == deleted.budget.scope_id
+                && match limit {
+                    SpendBudgetLimit::Soft => limit_reached(status.budget.soft_limit, status.spent),
+                    SpendBudgetLimit::Hard => limit_reached(status.budget.hard_limit, status.spent),
+                }
+        });
+        if notified.is_some()
+            && !still_reached
+            && let Err(err) =
+                work_db.resolve_external_tracker_attention(&deleted.budget.scope_id, attention_kind(limit))
+        {
+            tracing::warn!(scope_id = %deleted.budget.scope_id, ?err, "spend budget: resolving limit attention failed");
+        }
+    }
+}
+
+fn attention_kind(limit: SpendBudgetLimit) -> &'static str {
+    match limit {
+        SpendBudgetLimit::Soft => SPEND_BUDGET_SOFT_LIMIT_ATTENTION_KIND,
+        SpendBudgetLimit::Hard => SPEND_BUDGET_HARD_LIMIT_ATTENTION_KIND,
+    }
+}
+
+fn notify(work_db: &WorkDb, row: &SpendBudgetRow, status: &SpendBudgetStatus, limit: SpendBudgetLimit) {
+    let (title, body) = attention_text(status, limit);
+    let filed = work_db
+        .upsert_external_tracker_attention(&row.budget.scope_id, attention_kind(limit), &title, &body)
+        .and_then(|()| work_db.set_spend_budget_notified(&row.budget.id, limit, Some(status.period_start_epoch_s)));
+    match filed {
+        Ok(()) => tracing::warn!(
+            budget_id = %row.budget.id,
+            scope_id = %row.budget.scope_id,
+            spent = status.spent,
+            ?limit,
+            "spend budget limit reached"
+        ),
+        Err(err) => tracing::warn!(budget_id = %row.budget.id, ?err, "spend budget: filing limit attention failed"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_ready_chore_execution, create_test_chore, create_test_product, open_db};
+    use boss_protocol::CreateRunInput;
+    use rusqlite::params;
+
+    // 2026-10-14 (a Wednesday) 15:30:00 UTC.
+    const WEDNESDAY: i64 = 1_791_991_800;
+
+    #[test]
+    fn periods_are_utc_calendar_day_monday_week_and_month() {
+        assert_eq!(
+            period_bounds(SpendBudgetPeriod::Day, WEDNESDAY),
+            (1_791_936_000, 1_792_022_400)
+        );
+        // Monday 2026-10-12 to Monday 2026-10-19.
+        assert_eq!(
+            period_bounds(SpendBudgetPeriod::Week, WEDNESDAY),
+            (1_791_763_200, 1_792_368_000)
+        );
+        // 2026-10-01 to 2026-11-01.
+        assert_eq!(
+            period_bounds(SpendBudgetPeriod::Month, WEDNESDAY),
+            (1_790_812_800, 1_793_491_200)
+        );
+        let (start, end) = period_bounds(SpendBudgetPeriod::Day, 1_791_936_000);
+        assert_eq!(start, 1_791_936_000, "midnight belongs to the day it starts");
+        assert_eq!(end - start, 86_400);
+    }
+
+    #[test]
+    fn usd_measure_marks_unpriced_models_partial() {
+        let spend = [
+            ModelSpend {
+                model: Some("claude-sonnet-5".to_owned()),
+                input_tokens: 1_000_000,
+                output_tokens: 0,
+                cache_creation_tokens: 0,
+                cache_read_tokens: 0,
+            },
+            ModelSpend {
+                model: Some("<synthetic>".to_owned()),
+                input_tokens: 5,
+                output_tokens: 5,
+                cache_creation_tokens: 0,
+                cache_read_tokens: 0,
+            },
+        ];
+        let (tokens, partial) = measure(SpendBudgetMetric::Tokens, &spend);
+        assert_eq!(tokens, 1_000_010.0);
+        assert!(!partial);
+        let (usd, partial) = measure(SpendBudgetMetric::Usd, &spend);
+        let sonnet = cost_pricing::price_for_model("sonnet").unwrap();
+        assert!((usd - sonnet.input).abs() < 1e-9, "usd={usd}");
+        assert!(partial);
+    }
+
+    #[test]
+    fn hard_limit_holds_the_scope_and_files_each_limit_once() {
+        let (_dir, db) = open_db();
+        let product = create_test_product(&db);
+        let chore = create_test_chore(&db, &product.id, "Looping automation");
+        let execution = create_ready_chore_execution(&db, &chore.id);
+        let now = boss_engine_utils::epoch_time::now_epoch_secs();
+        let run_id = db
+            .create_run(
+                CreateRunInput::builder()
+                    .agent_id("worker-1")
+                    .execution_id(&execution.id)
+                    .status("completed")
+                    .build(),
+            )
+            .unwrap()
+            .id;
+        db.connect()
+            .unwrap()
+            .execute(
+                "UPDATE work_runs SET model = 'claude-sonnet-5', input_tokens = 900, output_tokens = 100 WHERE id = ?1",
+                params![run_id],
+            )
+            .unwrap();
+
+        db.upsert_spend_budget(
+            "product",
+            &product.id,
+            SpendBudgetMetric::Tokens,
+            SpendBudgetPeriod::Day,
+            Some(500.0),
+            Some(1_000.0),
+        )
+        .unwrap();
+
+        let snapshot = evaluate(&db, now).unwrap();
+        assert_eq!(snapshot.statuses[0].spent, 1_000.0);
+        assert_eq!(snapshot.statuses[0].state, SpendBudgetState::HardLimitReached);
+        let reason = snapshot.hold_for(&product.id, None).expect("held");
+        assert!(reason.contains("daily tokens budget"), "{reason}");
+        assert!(snapshot.hold_for("prod_other", None).is_none());
+
+        let open_kinds = |db: &WorkDb| -> Vec<String> {
+            let mut kinds: Vec<String> = db
+                .list_attention_items_for_work_item(&product.id)
+                .unwrap()
+                .into_iter()
+                .filter(|item| item.status == "open")
+                .map(|item| item.kind)
+                .collect();
+            kinds.sort();
+            kinds
+        };
+        assert_eq!(
+            open_kinds(&db),
+            vec![
+                SPEND_BUDGET_HARD_LIMIT_ATTENTION_KIND,
+                SPEND_BUDGET_SOFT_LIMIT_ATTENTION_KIND
+            ]
+        );
+        let markers = db.list_spend_budgets(None).unwrap().remove(0);
+        assert!(markers.soft_notified_period_start.is_some());
+        assert!(markers.hard_notified_period_start.is_some());
+
+        // Raising the budget releases the hold and resolves both items.
+        db.upsert_spend_budget(
+            "product",
+            &product.id,
+            SpendBudgetMetric::Tokens,
+            SpendBudgetPeriod::Day,
+            None,
+            Some(5_000.0),
+        )
+        .unwrap();
+        let snapshot = evaluate(&db, now).unwrap();
+        assert_eq!(snapshot.statuses[0].state, SpendBudgetState::UnderLimit);
+        assert!(snapshot.hold_for(&product.id, None).is_none());
+        assert!(open_kinds(&db).is_empty());
+        let markers = db.list_spend_budgets(None).unwrap().remove(0);
+        assert_eq!(markers.soft_notified_period_start, None);
+        assert_eq!(markers.hard_notified_period_start, None);
+    }
+
+    #[test]
+    fn hold_matches_the_scope_kind_as_well_as_the_id() {
+        let status = |scope_kind: &str| SpendBudgetStatus {
+            budget: SpendBudget::builder()
+                .id("budget_1")
+                .scope_id("shared_id")
+                .metric(SpendBudgetMetric::Tokens)
+                .period(SpendBudgetPeriod::Day)
+                .scope_kind(scope_kind)
+                .scope_name("Flunge")
+                .hard_limit(1_000.0)
+                .build(),
+            period_end_epoch_s: WEDNESDAY,
+            period_start_epoch_s: WEDNESDAY - 86_400,
+            spent: 1_000.0,
+            spent_partial: false,
+            state: SpendBudgetState::HardLimitReached,
+        };
+
+        // A project budget whose id collides with a product's id holds
+        // only that project's tasks.
+        let project = BudgetSnapshot {
+            statuses: vec![status("project")],
+        };
+        assert!(project.hold_for("shared_id", None).is_none());
+        assert!(project.hold_for("shared_id", Some("proj_other")).is_none());
+        assert!(project.hold_for("prod_other", Some("shared_id")).is_some());
+
+        let product = BudgetSnapshot {
+            statuses: vec![status("product")],
+        };
+        assert!(product.hold_for("shared_id", None).is_some());
+        assert!(product.hold_for("prod_other", Some("shared_id")).is_none());
+    }
+
+    #[test]
+    fn token_amounts_are_grouped() {
+        assert_eq!(
+            format_amount(SpendBudgetMetric::Tokens, 1_234_567.0),
+            "1,234,567 tokens"
+        );
+        assert_eq!(format_amount(SpendBudgetMetric::Tokens, 999.0), "999 tokens");
+        assert_eq!(format_amount(SpendBudgetMetric::Usd, 12.345), "$12.35");
+    }
+}
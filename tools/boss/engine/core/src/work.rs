@@ -466,8 +466,10 @@ mod dep_helpers;
 mod design_postmortem;
 mod dispatch;
 mod dispatch_admission;
+mod dispatch_calendars;
 mod dispatch_class;
 mod dispatch_helpers;
+mod dispatch_shares;
 mod driver_allocation;
 mod driver_lookup;
 mod editorial;
@@ -476,6 +478,7 @@ mod exec_tail;
 mod execution_launch_config;
 mod execution_retention;
 mod executions_runs;
+mod external_field_sync;
 mod github_api_usage_db;
 mod host_reconcile_queries;
 mod insert_helpers;
@@ -500,6 +503,7 @@ mod revise_doc;
 mod revision_helpers;
 mod run_rows;
 mod schema_init;
+mod spend_budgets;
 mod task_targets;
 #[cfg(test)]
 mod tests;
@@ -525,10 +529,11 @@ pub(crate) use chain_helpers::*;
 pub(crate) use dep_helpers::*;
 pub(crate) use design_postmortem::TriggerTaskSnapshot;
 pub(crate) use dispatch_class::DispatchClass;
+use dispatch_class::dispatch_class_case_sql;
 pub(crate) use dispatch_helpers::*;
 pub(crate) use driver_allocation::*;
 pub(crate) use exec_status_helpers::*;
-pub(crate) use exec_tail::content_checksum;
+pub(crate) use exec_tail::{content_checksum, field_checksum};
 pub(crate) use insert_helpers::*;
 // Private on purpose: only the list-read submodules under `work` build
 // these queries, so it stays visible to `work` and its children only.
@@ -560,6 +565,8 @@ pub use audit_misc::canonicalize_repo_remote_url;
 pub use audit_misc::canonicalize_worker_branch_prefix;
 pub use automations::AutomationFireRecord;
 pub use boothby::{BoothbyActionContext, BoothbyActionGuard};
+pub use dispatch_calendars::DispatchCalendarSetting;
+pub use dispatch_shares::{DispatchShareSetting, ReadyExecutionShareKey};
 pub use exec_tail::ClearedExecutionWorkspace;
 pub use execution_retention::{
     DEFAULT_RETENTION_KEEP_PER_WORK_ITEM, DEFAULT_RETENTION_MAX_AGE_SECS, ExecutionPruneOutcome,
@@ -579,6 +586,8 @@ pub use boss_metrics::MetricsCounterRow;
 pub use boss_metrics::MetricsGaugeRow;
 pub use output_types::AutomationDedupSuppression;
 pub use output_types::AutomationSiblingTask;
+pub use output_types::ExternalCommentLink;
+pub use output_types::ExternalFieldSyncState;
 pub use output_types::HostBoundExecution;
 pub use output_types::IdleAbandonmentCompletion;
 pub use output_types::LatePrCandidate;
@@ -615,4 +624,5 @@ pub use review_verdicts::{
     REVIEW_GATE_OUTCOME_REVISION_CREATION_FAILED, ReviewVerdict, ReviewVerdictInput, is_informative_gate_outcome,
 };
 pub use revision_helpers::normalize_priority;
+pub use spend_budgets::{ModelSpend, SpendBudgetLimit, SpendBudgetRow};
 pub use trunk_merge_intents::{ActiveTrunkMergeIntent, TrunkMergeIntent, TrunkMergeIntentInsertInput};
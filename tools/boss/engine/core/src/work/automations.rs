@@ -25,6 +25,10 @@ pub struct AutomationFireRecord {
     /// `Some(next_occurrence)` advances `automations.next_due_at`; `None`
     /// holds the current occurrence (used for transient-failure retry).
     pub next_due_at: Option<i64>,
+    /// What fired an event-triggered automation (see
+    /// `AutomationRun::trigger_event`). `None` for cron and manual fires; a
+    /// re-record of the same occurrence with `None` keeps the stored value.
+    pub trigger_event: Option<String>,
 }
 
 const AUTOMATION_SELECT: &str = "
@@ -420,7 +424,7 @@ impl WorkDb {
         let mut stmt = conn.prepare(
             "SELECT id, automation_id, scheduled_for, started_at, finished_at,
                     triage_execution_id, outcome, produced_task_id, detail,
-                    first_attempted_at
+                    first_attempted_at, trigger_event
                FROM automation_runs
               WHERE automation_id = ?1
               ORDER BY scheduled_for DESC, started_at DESC
@@ -603,6 +607,70 @@ impl WorkDb {
         Ok((min_next_due, uninitialized_count > 0))
     }
 
+    /// List the enabled automations of `product_id` whose trigger is of
+    /// `trigger_kind` (one of `AutomationTrigger::kind`'s values). Used by the
+    /// event-trigger loop to find the automations an engine event may fire;
+    /// oldest-first, like [`Self::list_due_automations`].
+    pub fn list_enabled_automations_by_trigger_kind(
+        &self,
+        product_id: &str,
+        trigger_kind: &str,
+    ) -> Result<Vec<boss_protocol::Automation>> {
+        let conn = self.connect()?;
+        let sql = format!(
+            "{AUTOMATION_SELECT}
+              WHERE product_id = ?1 AND enabled = 1 AND trigger_kind = ?2
+              ORDER BY created_at ASC, id ASC"
+        );
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt.query_map(params![product_id, trigger_kind], map_automation)?;
+        collect_rows(rows)
+    }
+
+    /// Distinct product ids that have at least one enabled automation of
+    /// `trigger_kind`. Lets a poller that produces an event (the
+    /// default-branch CI watch) skip every product nobody is listening on.
+    pub fn list_product_ids_with_enabled_automation_kind(&self, trigger_kind: &str) -> Result<Vec<String>> {
+        let conn = self.connect()?;
+        let mut stmt = conn.prepare(
+            "SELECT DISTINCT product_id FROM automations
+              WHERE enabled = 1 AND trigger_kind = ?1
+              ORDER BY product_id ASC",
+        )?;
+        let rows = stmt.query_map([trigger_kind], |row| row.get::<_, String>(0))?;
+        collect_rows(rows)
+    }
+
+    /// The newest `scheduled_for` (epoch seconds) recorded for
+    /// `automation_id`, or `None` if it has never run. The event-trigger
+    /// loop debounces a burst of events against it.
+    pub fn latest_automation_run_scheduled_for(&self, automation_id: &str) -> Result<Option<i64>> {
+        let conn = self.connect()?;
+        conn.query_row(
+            "SELECT MAX(CAST(scheduled_for AS INTEGER)) FROM automation_runs WHERE automation_id = ?1",
+            [automation_id],
+            |row| row.get::<_, Option<i64>>(0),
+        )
+        .map_err(Into::into)
+    }
+
+    /// The owning product of a task or project, or `None` when `work_item_id`
+    /// names neither (or only a soft-deleted task). Used to route an engine
+    /// event that carries only a work item id to that product's automations.
+    pub fn product_id_for_work_item(&self, work_item_id: &str) -> Result<Option<String>> {
+        let conn = self.connect()?;
+        conn.query_row(
+            "SELECT product_id FROM tasks WHERE id = ?1 AND deleted_at IS NULL
+             UNION ALL
+             SELECT product_id FROM projects WHERE id = ?1
+             LIMIT 1",
+            [work_item_id],
+            |row| row.get::<_, String>(0),
+        )
+        .optional()
+        .map_err(Into::into)
+    }
+
     /// Fetch the `automation_runs` row for a specific occurrence, if one
     /// exists. The `(automation_id, scheduled_for)` pair is the
     /// at-most-once dedupe key for a fired occurrence.
@@ -615,7 +683,7 @@ impl WorkDb {
         conn.query_row(
             "SELECT id, automation_id, scheduled_for, started_at, finished_at,
                     triage_execution_id, outcome, produced_task_id, detail,
-                    first_attempted_at
+                    first_attempted_at, trigger_event
                FROM automation_runs
               WHERE automation_id = ?1 AND scheduled_for = ?2",
             params![automation_id, scheduled_for_epoch.to_string()],
@@ -728,7 +796,8 @@ impl WorkDb {
                     "UPDATE automation_runs
                         SET started_at = ?2, finished_at = ?3,
                             triage_execution_id = ?4, outcome = ?5,
-                            produced_task_id = ?6, detail = ?7
+                            produced_task_id = ?6, detail = ?7,
+                            trigger_event = COALESCE(?8, trigger_event)
                       WHERE id = ?1",
                     params![
                         id,
@@ -738,6 +807,7 @@ impl WorkDb {
                         record.outcome,
                         record.produced_task_id,
                         record.detail,
+                        record.trigger_event,
                     ],
                 )?;
             }
@@ -747,8 +817,8 @@ impl WorkDb {
                     "INSERT INTO automation_runs
                          (id, automation_id, scheduled_for, started_at, finished_at,
                           triage_execution_id, outcome, produced_task_id, detail,
-                          first_attempted_at)
-                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                          first_attempted_at, trigger_event)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
                     params![
                         run_id,
                         record.automation_id,
@@ -760,6 +830,7 @@ impl WorkDb {
                         record.produced_task_id,
                         record.detail,
                         started_at,
+                        record.trigger_event,
                     ],
                 )?;
             }
@@ -888,7 +959,7 @@ impl WorkDb {
         conn.query_row(
             "SELECT id, automation_id, scheduled_for, started_at, finished_at,
                     triage_execution_id, outcome, produced_task_id, detail,
-                    first_attempted_at
+                    first_attempted_at, trigger_event
                FROM automation_runs
               WHERE triage_execution_id = ?1
               ORDER BY scheduled_for DESC, started_at DESC
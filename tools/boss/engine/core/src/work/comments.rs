@@ -119,39 +119,8 @@ const COMMENT_INSERT_SQL: &str = "INSERT INTO work_comments \
 impl WorkDb {
     /// Create an `active` comment. Returns the inserted row.
     pub fn create_comment(&self, input: CreateCommentInput) -> Result<WorkComment> {
-        if input.body.trim().is_empty() {
-            bail!("comment body may not be empty");
-        }
-        if input.anchor.exact.is_empty() {
-            bail!("comment anchor.exact may not be empty");
-        }
-        if input.artifact_id.trim().is_empty() {
-            bail!("comment artifact_id may not be empty");
-        }
         let conn = self.connect()?;
-        let id = next_id("cmt");
-        let now = now_string();
-        let anchor_json = serde_json::to_string(&input.anchor)?;
-        conn.execute(
-            COMMENT_INSERT_SQL,
-            params![
-                id,
-                input.artifact_kind,
-                input.artifact_id,
-                input.doc_version,
-                anchor_json,
-                input.body,
-                input.author,
-                COMMENT_STATUS_ACTIVE,
-                Option::<String>::None,
-                Option::<String>::None,
-                input.plain_text_projection_version,
-                now,
-                now,
-                Option::<String>::None,
-            ],
-        )?;
-        query_comment(&conn, &id)?.with_context(|| format!("missing comment after insert: {id}"))
+        insert_comment(&conn, &input)
     }
 
     /// List comments for an artifact in document-creation order. Excludes
@@ -968,6 +937,45 @@ impl WorkDb {
 /// Re-extract a 64/exact/64-char anchor around `[start, start+length)` in the
 /// plain text, trimmed to text bounds. Used after a fuzzy resolve so the
 /// stored anchor reflects the current doc and the next load exact-matches.
+/// Validate and insert an `active` comment on `conn`, returning the
+/// inserted row. Shared by [`WorkDb::create_comment`] and callers that must
+/// insert a comment inside a wider transaction (the external-tracker
+/// comment import, which records its link row atomically with the insert).
+pub(super) fn insert_comment(conn: &Connection, input: &CreateCommentInput) -> Result<WorkComment> {
+    if input.body.trim().is_empty() {
+        bail!("comment body may not be empty");
+    }
+    if input.anchor.exact.is_empty() {
+        bail!("comment anchor.exact may not be empty");
+    }
+    if input.artifact_id.trim().is_empty() {
+        bail!("comment artifact_id may not be empty");
+    }
+    let id = next_id("cmt");
+    let now = now_string();
+    let anchor_json = serde_json::to_string(&input.anchor)?;
+    conn.execute(
+        COMMENT_INSERT_SQL,
+        params![
+            id,
+            input.artifact_kind,
+            input.artifact_id,
+            input.doc_version,
+            anchor_json,
+            input.body,
+            input.author,
+            COMMENT_STATUS_ACTIVE,
+            Option::<String>::None,
+            Option::<String>::None,
+            input.plain_text_projection_version,
+            now,
+            now,
+            Option::<String>::None,
+        ],
+    )?;
+    query_comment(conn, &id)?.with_context(|| format!("missing comment after insert: {id}"))
+}
+
 fn extract_anchor(plain_text: &str, start: usize, length: usize) -> CommentAnchor {
     let chars: Vec<char> = plain_text.chars().collect();
     let n = chars.len();
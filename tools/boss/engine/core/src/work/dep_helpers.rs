@@ -732,6 +732,10 @@ pub(crate) fn maybe_engine_unblock_dependent(
 /// check is the safety net — a regressed prereq immediately re-gates
 /// any future dispatch of its dependents — so the cascade can stay
 /// purely additive.
+///
+/// Every status-transition write path calls this, so it is also where the
+/// transition itself is announced: [`Event::WorkItemStatusChanged`] is
+/// staged unconditionally, for `work_item_status` automations.
 pub(crate) fn cascade_dependents_after_prereq_status_change(
     pending: &mut PendingEvents,
     conn: &Connection,
@@ -739,6 +743,10 @@ pub(crate) fn cascade_dependents_after_prereq_status_change(
     new_prereq_status: &str,
     now_epoch: &str,
 ) -> Result<()> {
+    pending.push(Event::WorkItemStatusChanged {
+        work_item_id: prereq_id.to_owned(),
+        status: new_prereq_status.to_owned(),
+    });
     // Fire the cascade when the prereq reaches any status that *might*
     // satisfy at least one class of dependent:
     //   - `done` / `archived` satisfy all dependents (standard rule).
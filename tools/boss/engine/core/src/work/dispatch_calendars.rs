@@ -0,0 +1,155 @@
+//! DB operations behind per-product dispatch calendars: the
+//! `product_dispatch_calendars` settings [`crate::dispatch_calendar`]
+//! evaluates, and the owning-product lookup the dispatch gate keys them by.
+
+use super::*;
+
+use boss_protocol::DispatchWindow;
+
+/// One product's stored dispatch calendar, exactly as it was set. The
+/// timezone and windows were validated on the way in; they are parsed
+/// again by [`crate::dispatch_calendar::CompiledCalendar::compile`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DispatchCalendarSetting {
+    pub timezone: String,
+    pub windows: Vec<DispatchWindow>,
+    pub quiet_hours: Vec<DispatchWindow>,
+    pub drain_before_close_minutes: u32,
+}
+
+impl WorkDb {
+    /// Store `product_id`'s dispatch calendar, replacing any earlier one.
+    /// `None` deletes the row, so the product dispatches around the clock
+    /// again.
+    pub fn set_product_dispatch_calendar(
+        &self,
+        product_id: &str,
+        setting: Option<&DispatchCalendarSetting>,
+    ) -> Result<()> {
+        let conn = self.connect()?;
+        let Some(setting) = setting else {
+            conn.execute(
+                "DELETE FROM product_dispatch_calendars WHERE product_id = ?1",
+                params![product_id],
+            )?;
+            return Ok(());
+        };
+        conn.execute(
+            "INSERT INTO product_dispatch_calendars
+                 (product_id, timezone, windows_json, quiet_hours_json, drain_before_close_minutes, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+             ON CONFLICT(product_id) DO UPDATE SET
+                 timezone = excluded.timezone,
+                 windows_json = excluded.windows_json,
+                 quiet_hours_json = excluded.quiet_hours_json,
+                 drain_before_close_minutes = excluded.drain_before_close_minutes,
+                 updated_at = excluded.updated_at",
+            params![
+                product_id,
+                setting.timezone,
+                serde_json::to_string(&setting.windows)?,
+                serde_json::to_string(&setting.quiet_hours)?,
+                setting.drain_before_close_minutes,
+                now_string(),
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Every stored dispatch calendar, keyed by product id. Products
+    /// without a row have no calendar.
+    pub fn dispatch_calendar_settings(&self) -> Result<HashMap<String, DispatchCalendarSetting>> {
+        let conn = self.connect()?;
+        let mut stmt = conn.prepare(
+            "SELECT product_id, timezone, windows_json, quiet_hours_json, drain_before_close_minutes
+             FROM product_dispatch_calendars",
+        )?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                    row.get::<_, u32>(4)?,
+                ))
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        rows.into_iter()
+            .map(
+                |(product_id, timezone, windows, quiet_hours, drain_before_close_minutes)| {
+                    let setting = DispatchCalendarSetting {
+                        timezone,
+                        windows: serde_json::from_str(&windows)
+                            .with_context(|| format!("product {product_id}: malformed dispatch windows"))?,
+                        quiet_hours: serde_json::from_str(&quiet_hours)
+                            .with_context(|| format!("product {product_id}: malformed quiet hours"))?,
+                        drain_before_close_minutes,
+                    };
+                    Ok((product_id, setting))
+                },
+            )
+            .collect()
+    }
+
+    /// The product whose dispatch calendar governs `work_item_id`: the
+    /// task's product, or the automation's for an `automation_triage`
+    /// execution. `None` for anything else (an answer-agent comment),
+    /// which no calendar covers.
+    pub fn dispatch_product_for_work_item(&self, work_item_id: &str) -> Result<Option<String>> {
+        let conn = self.connect()?;
+        conn.query_row(
+            "SELECT product_id FROM tasks WHERE id = ?1
+             UNION ALL
+             SELECT product_id FROM automations WHERE id = ?1
+             LIMIT 1",
+            [work_item_id],
+            |row| row.get::<_, String>(0),
+        )
+        .optional()
+        .map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_test_chore, create_test_product, open_db, seed_daily_automation};
+
+    #[test]
+    fn calendar_round_trips_and_none_deletes_the_row() {
+        let (_dir, db) = open_db();
+        let product = create_test_product(&db);
+        let setting = DispatchCalendarSetting {
+            timezone: "America/Los_Angeles".to_owned(),
+            windows: vec![DispatchWindow::parse("weekdays 08:00-20:00").unwrap()],
+            quiet_hours: vec![DispatchWindow::parse("daily 12:00-13:00").unwrap()],
+            drain_before_close_minutes: 30,
+        };
+        db.set_product_dispatch_calendar(&product.id, Some(&setting)).unwrap();
+        assert_eq!(
+            db.dispatch_calendar_settings().unwrap().get(&product.id),
+            Some(&setting)
+        );
+
+        db.set_product_dispatch_calendar(&product.id, None).unwrap();
+        assert!(db.dispatch_calendar_settings().unwrap().is_empty());
+    }
+
+    #[test]
+    fn dispatch_product_resolves_tasks_and_automations() {
+        let (_dir, db) = open_db();
+        let product = create_test_product(&db);
+        let chore = create_test_chore(&db, &product.id, "chore");
+        let automation = seed_daily_automation(&db, &product.id);
+        assert_eq!(
+            db.dispatch_product_for_work_item(&chore.id).unwrap(),
+            Some(product.id.clone())
+        );
+        assert_eq!(
+            db.dispatch_product_for_work_item(&automation.id).unwrap(),
+            Some(product.id.clone())
+        );
+        assert_eq!(db.dispatch_product_for_work_item("no-such-item").unwrap(), None);
+    }
+}
@@ -15,7 +15,7 @@ use super::*;
 ///
 /// [`WorkDb::list_ready_executions`] applies this key via an `ORDER BY
 /// CASE …` clause built from the same `CREATED_VIA_*` prefixes this
-/// classifier uses, and [`WorkDb::classify_work_item_for_dispatch`]
+/// classifier uses (see `dispatch_class_case_sql`), and [`WorkDb::classify_work_item_for_dispatch`]
 /// recomputes the class for dispatch-trace logging. Keep all three in
 /// sync when a class changes.
 ///
@@ -76,6 +76,18 @@ impl DispatchClass {
         self as i64
     }
 
+    /// Inverse of [`Self::as_ordinal`]; anything out of range is
+    /// [`Self::OtherWork`], the same fallback the SQL `CASE` uses.
+    pub(crate) fn from_ordinal(ordinal: i64) -> Self {
+        match ordinal {
+            1 => Self::MergeConflictRevision,
+            2 => Self::CiFixRevision,
+            3 => Self::PrReviewRevision,
+            4 => Self::OtherRevision,
+            _ => Self::OtherWork,
+        }
+    }
+
     pub(crate) fn label(self) -> &'static str {
         match self {
             Self::MergeConflictRevision => "merge_conflict_revision",
@@ -87,6 +99,24 @@ impl DispatchClass {
     }
 }
 
+/// The SQL twin of [`DispatchClass::classify`], as an expression over a
+/// `tasks` row aliased `t` (NULL when the execution has no task row).
+/// Evaluates to the class ordinal.
+pub(super) fn dispatch_class_case_sql() -> String {
+    format!(
+        "CASE \
+           WHEN t.kind = 'revision' AND t.created_via GLOB '{merge_conflict}*' THEN 1 \
+           WHEN t.kind = 'revision' AND t.created_via GLOB '{ci_fix}*' THEN 2 \
+           WHEN t.kind = 'revision' AND t.created_via GLOB '{pr_review}*' THEN 3 \
+           WHEN t.kind = 'revision' THEN 4 \
+           ELSE 5 \
+         END",
+        merge_conflict = CREATED_VIA_MERGE_CONFLICT_PREFIX,
+        ci_fix = CREATED_VIA_CI_FIX_PREFIX,
+        pr_review = CREATED_VIA_PR_REVIEW_PREFIX,
+    )
+}
+
 impl WorkDb {
     /// Look up the [`DispatchClass`] for `work_item_id`, for dispatch-trace
     /// logging at pickup time. Mirrors the classification
@@ -0,0 +1,217 @@
+//! DB operations behind fair-share dispatch: the per-product
+//! `product_dispatch_shares` settings and the per-product counts the
+//! ordering in [`crate::dispatch_fair_share`] is computed from.
+
+use super::*;
+
+use boss_protocol::DEFAULT_DISPATCH_SHARE_WEIGHT;
+
+/// One product's stored fair-share settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DispatchShareSetting {
+    pub weight: u32,
+    pub max_concurrent: Option<u32>,
+}
+
+impl Default for DispatchShareSetting {
+    fn default() -> Self {
+        Self {
+            weight: DEFAULT_DISPATCH_SHARE_WEIGHT,
+            max_concurrent: None,
+        }
+    }
+}
+
+/// What the fair-share ordering needs to know about one `ready`
+/// execution beyond the row itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadyExecutionShareKey {
+    /// [`DispatchClass`] ordinal, computed by the same `CASE` the ready
+    /// queue sorts by.
+    pub class_ordinal: i64,
+    /// The owning product: the task's, or the automation's for an
+    /// `automation_triage` execution. `None` for anything else (an
+    /// answer-agent comment), which is scheduled as a product of its own.
+    pub product_id: Option<String>,
+}
+
+/// `COALESCE`d owning product for a `work_executions` row aliased `we`,
+/// given `tasks t` and `automations a` left-joined on its work item.
+const EXECUTION_PRODUCT_SQL: &str = "COALESCE(t.product_id, a.product_id)";
+
+const EXECUTION_PRODUCT_JOINS: &str = "
+    LEFT JOIN tasks t ON t.id = we.work_item_id
+    LEFT JOIN automations a ON a.id = we.work_item_id
+";
+
+impl WorkDb {
+    /// Store `product_id`'s fair-share settings. The default weight with
+    /// no ceiling deletes the row instead, so "reset" leaves nothing
+    /// behind.
+    pub fn set_product_dispatch_share(&self, product_id: &str, setting: DispatchShareSetting) -> Result<()> {
+        let conn = self.connect()?;
+        if setting == DispatchShareSetting::default() {
+            conn.execute(
+                "DELETE FROM product_dispatch_shares WHERE product_id = ?1",
+                params![product_id],
+            )?;
+            return Ok(());
+        }
+        conn.execute(
+            "INSERT INTO product_dispatch_shares (product_id, weight, max_concurrent, updated_at)
+             VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT(product_id) DO UPDATE SET
+                 weight = excluded.weight,
+                 max_concurrent = excluded.max_concurrent,
+                 updated_at = excluded.updated_at",
+            params![product_id, setting.weight, setting.max_concurrent, now_string()],
+        )?;
+        Ok(())
+    }
+
+    /// Every stored fair-share setting, keyed by product id. Products
+    /// without a row use [`DispatchShareSetting::default`].
+    pub fn dispatch_share_settings(&self) -> Result<HashMap<String, DispatchShareSetting>> {
+        let conn = self.connect()?;
+        let mut stmt = conn.prepare("SELECT product_id, weight, max_concurrent FROM product_dispatch_shares")?;
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                DispatchShareSetting {
+                    weight: row.get(1)?,
+                    max_concurrent: row.get(2)?,
+                },
+            ))
+        })?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    /// Class and owning product for every `ready` execution, keyed by
+    /// execution id. Covers exactly the rows [`Self::list_ready_executions`]
+    /// can return, plus ones still held by `dispatch_not_before`.
+    pub fn ready_execution_share_keys(&self) -> Result<HashMap<String, ReadyExecutionShareKey>> {
+        let conn = self.connect()?;
+        let sql = format!(
+            "SELECT we.id, {class_case}, {EXECUTION_PRODUCT_SQL}
+             FROM work_executions we {EXECUTION_PRODUCT_JOINS}
+             WHERE we.status = 'ready'",
+            class_case = dispatch_class_case_sql(),
+        );
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                ReadyExecutionShareKey {
+                    class_ordinal: row.get(1)?,
+                    product_id: row.get(2)?,
+                },
+            ))
+        })?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    /// Live workers (`running` / `waiting_human` executions) per owning
+    /// product. Executions with no owning product are not counted.
+    pub fn live_execution_counts_by_product(&self) -> Result<HashMap<String, u32>> {
+        let conn = self.connect()?;
+        let sql = format!(
+            "SELECT {EXECUTION_PRODUCT_SQL} AS owning_product_id, COUNT(*)
+             FROM work_executions we {EXECUTION_PRODUCT_JOINS}
+             WHERE we.status IN ('running', 'waiting_human') AND owning_product_id IS NOT NULL
+             GROUP BY owning_product_id"
+        );
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt.query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, u32>(1)?)))?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{
+        create_ready_chore_execution, create_test_chore, create_test_product, create_test_product_named, open_db,
+        seed_daily_automation,
+    };
+
+    #[test]
+    fn default_setting_deletes_the_row() {
+        let (_dir, db) = open_db();
+        let product = create_test_product(&db);
+        let setting = DispatchShareSetting {
+            weight: 3,
+            max_concurrent: Some(2),
+        };
+        db.set_product_dispatch_share(&product.id, setting).unwrap();
+        assert_eq!(db.dispatch_share_settings().unwrap().get(&product.id), Some(&setting));
+
+        db.set_product_dispatch_share(&product.id, DispatchShareSetting::default())
+            .unwrap();
+        assert!(db.dispatch_share_settings().unwrap().is_empty());
+    }
+
+    #[test]
+    fn share_keys_and_live_counts_resolve_the_owning_product() {
+        let (_dir, db) = open_db();
+        let flunge = create_test_product_named(&db, "Flunge");
+        let other = create_test_product(&db);
+        let ready = create_ready_chore_execution(&db, create_test_chore(&db, &flunge.id, "ready").id);
+        let running = create_ready_chore_execution(&db, create_test_chore(&db, &other.id, "running").id);
+        db.connect()
+            .unwrap()
+            .execute(
+                "UPDATE work_executions SET status = 'running' WHERE id = ?1",
+                params![running.id],
+            )
+            .unwrap();
+
+        let keys = db.ready_execution_share_keys().unwrap();
+        assert_eq!(
+            keys.get(&ready.id),
+            Some(&ReadyExecutionShareKey {
+                class_ordinal: DispatchClass::OtherWork.as_ordinal(),
+                product_id: Some(flunge.id.clone()),
+            })
+        );
+        assert!(!keys.contains_key(&running.id));
+
+        let live = db.live_execution_counts_by_product().unwrap();
+        assert_eq!(live.get(&other.id), Some(&1));
+        assert_eq!(live.get(&flunge.id), None);
+    }
+
+    #[test]
+    fn live_counts_group_task_and_automation_executions_by_product() {
+        let (_dir, db) = open_db();
+        let flunge = create_test_product_named(&db, "Flunge");
+        let other = create_test_product(&db);
+        let task_running = create_ready_chore_execution(&db, create_test_chore(&db, &flunge.id, "running").id);
+        let task_waiting = create_ready_chore_execution(&db, create_test_chore(&db, &other.id, "waiting").id);
+        let task_ready = create_ready_chore_execution(&db, create_test_chore(&db, &other.id, "ready").id);
+        let triage_running = db
+            .create_automation_triage_execution(
+                &seed_daily_automation(&db, &flunge.id).id,
+                "git@example.com:flunge.git",
+            )
+            .unwrap();
+        for (id, status) in [
+            (&task_running.id, "running"),
+            (&task_waiting.id, "waiting_human"),
+            (&triage_running.id, "running"),
+        ] {
+            db.connect()
+                .unwrap()
+                .execute(
+                    "UPDATE work_executions SET status = ?2 WHERE id = ?1",
+                    params![id, status],
+                )
+                .unwrap();
+        }
+
+        let live = db.live_execution_counts_by_product().unwrap();
+        assert_eq!(live.get(&flunge.id), Some(&2));
+        assert_eq!(live.get(&other.id), Some(&1));
+        assert_eq!(live.len(), 2);
+        assert!(db.ready_execution_share_keys().unwrap().contains_key(&task_ready.id));
+    }
+}
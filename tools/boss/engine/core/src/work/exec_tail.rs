@@ -15,6 +15,13 @@ pub(crate) fn content_checksum(title: &str, body: &str) -> String {
     format!("{:x}", h.finalize())
 }
 
+/// SHA-256 checksum of one synced field value, the per-field counterpart of
+/// [`content_checksum`] used by the two-way sync baselines in
+/// `external_tracker_field_state`. Same encoding, no normalization.
+pub(crate) fn field_checksum(value: &str) -> String {
+    format!("{:x}", Sha256::digest(value.as_bytes()))
+}
+
 /// What [`WorkDb::clear_execution_workspace`] cleared, returned so the
 /// caller can both release the cube lease and (separately) tear down any
 /// driver-owned state that lived outside the workspace.
@@ -635,18 +635,7 @@ impl WorkDb {
     /// `crate::dispatch_spillover` and `DispatchClass`'s docs.
     pub fn list_ready_executions(&self) -> Result<Vec<WorkExecution>> {
         let conn = self.connect()?;
-        let class_case = format!(
-            "CASE \
-               WHEN t.kind = 'revision' AND t.created_via GLOB '{merge_conflict}*' THEN 1 \
-               WHEN t.kind = 'revision' AND t.created_via GLOB '{ci_fix}*' THEN 2 \
-               WHEN t.kind = 'revision' AND t.created_via GLOB '{pr_review}*' THEN 3 \
-               WHEN t.kind = 'revision' THEN 4 \
-               ELSE 5 \
-             END",
-            merge_conflict = CREATED_VIA_MERGE_CONFLICT_PREFIX,
-            ci_fix = CREATED_VIA_CI_FIX_PREFIX,
-            pr_review = CREATED_VIA_PR_REVIEW_PREFIX,
-        );
+        let class_case = dispatch_class_case_sql();
         let sql = format!(
             "SELECT we.id, we.work_item_id, we.kind, we.status, we.repo_remote_url, we.cube_repo_id, we.cube_lease_id, \
                     we.cube_workspace_id, we.workspace_path, we.priority, we.preferred_workspace_id, \
@@ -0,0 +1,242 @@
+//! Storage for the external-tracker reconciler's opt-in two-way sync: the
+//! per-field checksum baselines in `external_tracker_field_state` and the
+//! upstream-comment ↔ `work_comments` links in
+//! `external_tracker_comment_links`.
+//!
+//! The policy (which side wins, when to push) lives in
+//! `external_tracker::reconcile::field_sync`; this module only persists
+//! what that policy decided.
+
+use super::*;
+
+/// `tasks` column backing a synced field, or `None` for fields whose Boss
+/// side lives in `external_tracker_field_state.boss_value`.
+fn task_column_for_field(field: &str) -> Option<&'static str> {
+    match field {
+        "title" => Some("name"),
+        "description" => Some("description"),
+        "priority" => Some("priority"),
+        _ => None,
+    }
+}
+
+impl WorkDb {
+    /// Every stored two-way sync baseline for `work_item_id`, keyed by field
+    /// name. Fields never synced are simply absent.
+    pub fn reconciler_get_field_sync_states(
+        &self,
+        work_item_id: &str,
+    ) -> Result<HashMap<String, ExternalFieldSyncState>> {
+        let conn = self.connect()?;
+        let mut stmt = conn.prepare(
+            "SELECT field, upstream_checksum, boss_checksum, boss_value, boss_updated_at
+             FROM external_tracker_field_state
+             WHERE work_item_id = ?1",
+        )?;
+        let rows = stmt.query_map(params![work_item_id], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                ExternalFieldSyncState {
+                    upstream_checksum: row.get(1)?,
+                    boss_checksum: row.get(2)?,
+                    boss_value: row.get(3)?,
+                    boss_updated_at: row.get(4)?,
+                },
+            ))
+        })?;
+        Ok(rows.collect::<rusqlite::Result<HashMap<_, _>>>()?)
+    }
+
+    /// Record `upstream_value` / `boss_value` as the agreed baseline for one
+    /// field without touching the work item itself. Used for first sight of
+    /// a field, after a successful push to upstream, and when both sides
+    /// converged on their own.
+    pub fn reconciler_record_field_baseline(
+        &self,
+        work_item_id: &str,
+        field: &str,
+        upstream_value: &str,
+        boss_value: &str,
+    ) -> Result<()> {
+        let conn = self.connect()?;
+        upsert_field_baseline(&conn, work_item_id, field, upstream_value, boss_value)
+    }
+
+    /// Write an upstream-originated value onto the Boss side of `field` and
+    /// record the new baseline, in one transaction.
+    ///
+    /// `boss_value` is the value as Boss stores it (for the description,
+    /// with the `> Imported from` header re-added); `synced_value` is the
+    /// value both sides now agree on, which both baseline checksums are
+    /// taken over. `title`, `description` and `priority` write the matching
+    /// `tasks` column and bump `updated_at`; any other field writes
+    /// `boss_value` without stamping `boss_updated_at`, which is reserved
+    /// for operator edits.
+    ///
+    /// Returns `false` when the work item is soft-deleted or missing.
+    pub fn reconciler_apply_upstream_field(
+        &self,
+        work_item_id: &str,
+        field: &str,
+        boss_value: &str,
+        synced_value: &str,
+    ) -> Result<bool> {
+        let mut conn = self.connect()?;
+        let tx = conn.transaction()?;
+        let live: bool = tx
+            .query_row(
+                "SELECT 1 FROM tasks WHERE id = ?1 AND deleted_at IS NULL",
+                params![work_item_id],
+                |_| Ok(true),
+            )
+            .optional()?
+            .unwrap_or(false);
+        if !live {
+            return Ok(false);
+        }
+        match task_column_for_field(field) {
+            Some(column) => {
+                tx.execute(
+                    &format!("UPDATE tasks SET {column} = ?2, updated_at = ?3 WHERE id = ?1"),
+                    params![work_item_id, boss_value, now_string()],
+                )?;
+            }
+            None => {
+                tx.execute(
+                    "INSERT INTO external_tracker_field_state (work_item_id, field, boss_value)
+                     VALUES (?1, ?2, ?3)
+                     ON CONFLICT(work_item_id, field) DO UPDATE SET boss_value = excluded.boss_value",
+                    params![work_item_id, field, boss_value],
+                )?;
+            }
+        }
+        upsert_field_baseline(&tx, work_item_id, field, synced_value, synced_value)?;
+        tx.commit()?;
+        Ok(true)
+    }
+
+    /// Set the Boss-side assignee of an externally-tracked work item. An
+    /// empty string clears it. Stamps `boss_updated_at` so a conflicting
+    /// upstream reassignment is resolved by last-writer-wins against this
+    /// edit rather than against the task's general `updated_at`.
+    pub fn set_external_assignee(&self, work_item_id: &str, assignee: &str) -> Result<()> {
+        let conn = self.connect()?;
+        conn.execute(
+            "INSERT INTO external_tracker_field_state (work_item_id, field, boss_value, boss_updated_at)
+             VALUES (?1, 'assignee', ?2, ?3)
+             ON CONFLICT(work_item_id, field) DO UPDATE
+             SET boss_value = excluded.boss_value, boss_updated_at = excluded.boss_updated_at",
+            params![work_item_id, assignee, now_string()],
+        )?;
+        Ok(())
+    }
+
+    /// Every comment link recorded for `work_item_id`, oldest first.
+    pub fn reconciler_list_comment_links(&self, work_item_id: &str) -> Result<Vec<ExternalCommentLink>> {
+        let conn = self.connect()?;
+        let mut stmt = conn.prepare(
+            "SELECT upstream_comment_id, work_comment_id, origin
+             FROM external_tracker_comment_links
+             WHERE work_item_id = ?1
+             ORDER BY linked_at, upstream_comment_id",
+        )?;
+        let rows = stmt.query_map(params![work_item_id], |row| {
+            Ok(ExternalCommentLink {
+                upstream_comment_id: row.get(0)?,
+                work_comment_id: row.get(1)?,
+                origin: row.get(2)?,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+    }
+
+    /// Import an upstream comment as a Boss comment and link the two, in one
+    /// transaction so a crash can't leave an unlinked copy behind to be
+    /// imported again next tick.
+    ///
+    /// Returns `Ok(None)` without inserting when `upstream_comment_id` is
+    /// already linked on this work item.
+    pub fn reconciler_import_upstream_comment(
+        &self,
+        work_item_id: &str,
+        upstream_comment_id: &str,
+        input: CreateCommentInput,
+    ) -> Result<Option<WorkComment>> {
+        let mut conn = self.connect()?;
+        let tx = conn.transaction()?;
+        let already_linked = tx
+            .query_row(
+                "SELECT 1 FROM external_tracker_comment_links
+                 WHERE work_item_id = ?1 AND upstream_comment_id = ?2",
+                params![work_item_id, upstream_comment_id],
+                |_| Ok(()),
+            )
+            .optional()?
+            .is_some();
+        if already_linked {
+            return Ok(None);
+        }
+        let comment = comments::insert_comment(&tx, &input)?;
+        insert_comment_link(&tx, work_item_id, upstream_comment_id, &comment.id, "upstream")?;
+        tx.commit()?;
+        Ok(Some(comment))
+    }
+
+    /// Link a Boss comment to the upstream comment it was pushed as.
+    /// Idempotent on either id.
+    pub fn reconciler_link_pushed_comment(
+        &self,
+        work_item_id: &str,
+        upstream_comment_id: &str,
+        work_comment_id: &str,
+    ) -> Result<()> {
+        let conn = self.connect()?;
+        insert_comment_link(&conn, work_item_id, upstream_comment_id, work_comment_id, "boss")
+    }
+}
+
+fn upsert_field_baseline(
+    conn: &Connection,
+    work_item_id: &str,
+    field: &str,
+    upstream_value: &str,
+    boss_value: &str,
+) -> Result<()> {
+    conn.execute(
+        "INSERT INTO external_tracker_field_state
+             (work_item_id, field, upstream_checksum, boss_checksum, synced_at)
+         VALUES (?1, ?2, ?3, ?4, ?5)
+         ON CONFLICT(work_item_id, field) DO UPDATE
+         SET upstream_checksum = excluded.upstream_checksum,
+             boss_checksum     = excluded.boss_checksum,
+             synced_at         = excluded.synced_at",
+        params![
+            work_item_id,
+            field,
+            field_checksum(upstream_value),
+            field_checksum(boss_value),
+            now_string()
+        ],
+    )?;
+    Ok(())
+}
+
+fn insert_comment_link(
+    conn: &Connection,
+    work_item_id: &str,
+    upstream_comment_id: &str,
+    work_comment_id: &str,
+    origin: &str,
+) -> Result<()> {
+    conn.execute(
+        "INSERT OR IGNORE INTO external_tracker_comment_links
+             (work_item_id, upstream_comment_id, work_comment_id, origin, linked_at)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![work_item_id, upstream_comment_id, work_comment_id, origin, now_string()],
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "external_field_sync_tests.rs"]
+mod tests;
@@ -0,0 +1,154 @@
+//! Behavior tests for the two-way external-tracker sync storage in
+//! `external_field_sync.rs`: per-field baselines, applying upstream values,
+//! the Boss-side assignee, and comment links.
+
+use boss_protocol::{CommentAnchor, CreateCommentInput, WorkItem};
+
+use super::*;
+use crate::test_support::{create_test_chore, create_test_product, open_db};
+
+fn item_task(item: WorkItem) -> Task {
+    match item {
+        WorkItem::Chore(t) | WorkItem::Task(t) => t,
+        other => panic!("expected a task/chore work item, got {other:?}"),
+    }
+}
+
+fn imported_comment_input(work_item_id: &str, body: &str) -> CreateCommentInput {
+    CreateCommentInput {
+        artifact_kind: "work_item".to_owned(),
+        artifact_id: work_item_id.to_owned(),
+        doc_version: String::new(),
+        anchor: CommentAnchor {
+            exact: "Imported from".to_owned(),
+            prefix: String::new(),
+            suffix: String::new(),
+        },
+        body: body.to_owned(),
+        author: "github:octocat".to_owned(),
+        plain_text_projection_version: 0,
+    }
+}
+
+/// Nothing is stored until a baseline is recorded; afterwards the state
+/// carries the checksums of exactly the values passed in.
+#[test]
+fn field_baseline_round_trip() {
+    let (_dir, db) = open_db();
+    let product = create_test_product(&db);
+    let chore = create_test_chore(&db, product.id.clone(), "Field baseline");
+
+    assert!(db.reconciler_get_field_sync_states(&chore.id).unwrap().is_empty());
+
+    db.reconciler_record_field_baseline(&chore.id, "priority", "high", "medium")
+        .unwrap();
+
+    let states = db.reconciler_get_field_sync_states(&chore.id).unwrap();
+    let state = &states["priority"];
+    assert_eq!(
+        state.upstream_checksum.as_deref(),
+        Some(field_checksum("high").as_str())
+    );
+    assert_eq!(state.boss_checksum.as_deref(), Some(field_checksum("medium").as_str()));
+    assert!(state.boss_value.is_none(), "task-column fields never store boss_value");
+}
+
+/// Applying an upstream title writes `tasks.name` and re-baselines both
+/// checksums on the new value.
+#[test]
+fn apply_upstream_field_writes_task_column() {
+    let (_dir, db) = open_db();
+    let product = create_test_product(&db);
+    let chore = create_test_chore(&db, product.id.clone(), "Old title");
+
+    assert!(
+        db.reconciler_apply_upstream_field(&chore.id, "title", "New title", "New title")
+            .unwrap()
+    );
+
+    let task = item_task(db.get_work_item(&chore.id).unwrap());
+    assert_eq!(task.name, "New title");
+    let states = db.reconciler_get_field_sync_states(&chore.id).unwrap();
+    assert_eq!(
+        states["title"].boss_checksum.as_deref(),
+        Some(field_checksum("New title").as_str())
+    );
+}
+
+/// Fields without a `tasks` column land in `boss_value`, and an upstream
+/// write never stamps `boss_updated_at` — that is reserved for operators.
+#[test]
+fn apply_upstream_assignee_writes_boss_value_only() {
+    let (_dir, db) = open_db();
+    let product = create_test_product(&db);
+    let chore = create_test_chore(&db, product.id.clone(), "Assignee");
+
+    db.reconciler_apply_upstream_field(&chore.id, "assignee", "octocat", "octocat")
+        .unwrap();
+    let state = db.reconciler_get_field_sync_states(&chore.id).unwrap()["assignee"].clone();
+    assert_eq!(state.boss_value.as_deref(), Some("octocat"));
+    assert!(state.boss_updated_at.is_none());
+
+    db.set_external_assignee(&chore.id, "hubot").unwrap();
+    let state = db.reconciler_get_field_sync_states(&chore.id).unwrap()["assignee"].clone();
+    assert_eq!(state.boss_value.as_deref(), Some("hubot"));
+    assert!(state.boss_updated_at.is_some());
+    assert_eq!(
+        state.boss_checksum.as_deref(),
+        Some(field_checksum("octocat").as_str()),
+        "an operator edit must not move the baseline, or the change would go undetected"
+    );
+}
+
+#[test]
+fn apply_upstream_field_on_deleted_row_is_noop() {
+    let (_dir, db) = open_db();
+    assert!(
+        !db.reconciler_apply_upstream_field("missing", "title", "x", "x")
+            .unwrap()
+    );
+    assert!(db.reconciler_get_field_sync_states("missing").unwrap().is_empty());
+}
+
+/// An upstream comment is imported exactly once: the second import of the
+/// same upstream id is a no-op and the link records the origin.
+#[test]
+fn import_upstream_comment_is_idempotent() {
+    let (_dir, db) = open_db();
+    let product = create_test_product(&db);
+    let chore = create_test_chore(&db, product.id.clone(), "Comments");
+
+    let first = db
+        .reconciler_import_upstream_comment(&chore.id, "c1", imported_comment_input(&chore.id, "hello"))
+        .unwrap()
+        .expect("first import inserts");
+    let second = db
+        .reconciler_import_upstream_comment(&chore.id, "c1", imported_comment_input(&chore.id, "hello"))
+        .unwrap();
+    assert!(second.is_none());
+
+    assert_eq!(db.list_comments("work_item", &chore.id, true).unwrap().len(), 1);
+    let links = db.reconciler_list_comment_links(&chore.id).unwrap();
+    assert_eq!(
+        links,
+        vec![ExternalCommentLink {
+            upstream_comment_id: "c1".to_owned(),
+            work_comment_id: first.id,
+            origin: "upstream".to_owned(),
+        }]
+    );
+}
+
+#[test]
+fn link_pushed_comment_is_idempotent() {
+    let (_dir, db) = open_db();
+    let product = create_test_product(&db);
+    let chore = create_test_chore(&db, product.id.clone(), "Pushed");
+
+    db.reconciler_link_pushed_comment(&chore.id, "u9", "cmt-1").unwrap();
+    db.reconciler_link_pushed_comment(&chore.id, "u9", "cmt-1").unwrap();
+
+    let links = db.reconciler_list_comment_links(&chore.id).unwrap();
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0].origin, "boss");
+}
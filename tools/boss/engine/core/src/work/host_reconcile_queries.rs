@@ -1,10 +1,13 @@
 //! `WorkDb` reads for the host-reconcile sweep ([`crate::host_reconcile`]):
 //! find the executions bound to a host that has gone offline, and answer
 //! whether a single execution's bound host is offline (the heartbeat
-//! auto-reap's positive-evidence signal). Kept in their own submodule so
-//! the sweep's query surface is cohesive and self-contained.
+//! auto-reap's positive-evidence signal). Also the host-load sweep's
+//! ([`crate::host_load`]) view of executions parked because no host could
+//! take them. Kept in their own submodule so the host sweeps' query
+//! surface is cohesive and self-contained.
 
 use super::*;
+use crate::host_scheduling::HOST_PARKED_WAIT_REASON_PREFIX;
 
 impl WorkDb {
     /// Non-terminal executions whose latest run landed on a host that is
@@ -91,4 +94,49 @@ impl WorkDb {
             .context("execution_bound_host_offline query")?;
         Ok(offline.unwrap_or(0) != 0)
     }
+
+    /// `ready` executions sitting out a pre-start backoff because host
+    /// selection found no eligible host — the `dispatch_wait_reason`
+    /// prefix [`HOST_PARKED_WAIT_REASON_PREFIX`] marks them. These are the
+    /// host-load sweep's work-stealing candidates: once a host frees up
+    /// they can be placed now rather than when the backoff expires.
+    /// Oldest first, so the longest-waiting work is re-placed first.
+    pub fn list_host_parked_executions(&self) -> Result<Vec<WorkExecution>> {
+        let conn = self.connect()?;
+        let now = boss_engine_utils::epoch_time::now_epoch_secs();
+        let mut stmt = conn.prepare(
+            "SELECT id, work_item_id, kind, status, repo_remote_url, cube_repo_id, cube_lease_id,
+                    cube_workspace_id, workspace_path, priority, preferred_workspace_id,
+                    created_at, started_at, finished_at,
+                    pre_start_failure_count, dispatch_not_before, pr_url, pr_head_before, prefer_is_soft,
+                    worker_branch_prefix, transient_failure_count, allow_dirty, branch_naming,
+                    dispatch_wait_reason, dispatch_wait_since, driver_runtime_state, driver, model, effort_level, pr_head_after
+             FROM work_executions
+             WHERE status = 'ready'
+               AND dispatch_not_before IS NOT NULL
+               AND CAST(dispatch_not_before AS INTEGER) > ?1
+               AND substr(dispatch_wait_reason, 1, length(?2)) = ?2
+             ORDER BY COALESCE(dispatch_wait_since, created_at) ASC, id ASC",
+        )?;
+        let rows = stmt.query_map(params![now, HOST_PARKED_WAIT_REASON_PREFIX], map_execution)?;
+        collect_rows(rows)
+    }
+
+    /// End a host-parked execution's backoff early so the next scheduler
+    /// pass dispatches it. Guarded on the row still being `ready` and
+    /// host-parked, so a racing claim or cancel is left alone; returns
+    /// whether the row was released.
+    pub fn release_host_parked_execution(&self, execution_id: &str) -> Result<bool> {
+        let conn = self.connect()?;
+        let changed = conn.execute(
+            "UPDATE work_executions
+             SET dispatch_not_before = NULL
+             WHERE id = ?1
+               AND status = 'ready'
+               AND dispatch_not_before IS NOT NULL
+               AND substr(dispatch_wait_reason, 1, length(?2)) = ?2",
+            params![execution_id, HOST_PARKED_WAIT_REASON_PREFIX],
+        )?;
+        Ok(changed > 0)
+    }
 }
@@ -1008,7 +1008,7 @@ pub(crate) fn map_automation(row: &Row<'_>) -> rusqlite::Result<boss_protocol::A
 /// Map a row from the canonical `automation_runs` SELECT column order:
 /// 0 id, 1 automation_id, 2 scheduled_for, 3 started_at, 4 finished_at,
 /// 5 triage_execution_id, 6 outcome, 7 produced_task_id, 8 detail,
-/// 9 first_attempted_at
+/// 9 first_attempted_at, 10 trigger_event
 pub(crate) fn map_automation_run(row: &Row<'_>) -> rusqlite::Result<boss_protocol::AutomationRun> {
     Ok(boss_protocol::AutomationRun {
         id: row.get(0)?,
@@ -1022,6 +1022,7 @@ pub(crate) fn map_automation_run(row: &Row<'_>) -> rusqlite::Result<boss_protoco
         detail: row.get::<_, Option<String>>(8)?.filter(|s| !s.is_empty()),
         first_attempted_at: row.get::<_, Option<String>>(9)?.filter(|s| !s.is_empty()),
         repeat_count: 1,
+        trigger_event: row.get::<_, Option<String>>(10)?.filter(|s| !s.is_empty()),
     })
 }
 
@@ -747,6 +747,108 @@ pub(crate) fn migrate_work_comments_reopened_at_column(conn: &Connection) -> Res
     Ok(())
 }
 
+/// Create the two side tables behind the external-tracker reconciler's
+/// opt-in two-way field and comment sync.
+///
+/// `external_tracker_field_state` holds one row per (work item, synced
+/// field) with the checksums of the last value both sides agreed on — the
+/// per-field generalisation of `tasks.external_ref_upstream_checksum` /
+/// `external_ref_boss_checksum`. `boss_value` / `boss_updated_at` carry the
+/// Boss-side value for fields that have no `tasks` column (today only the
+/// assignee); both are `NULL` for every other field.
+///
+/// `external_tracker_comment_links` maps upstream comment ids onto the
+/// `work_comments` rows they were imported as (or pushed from), so a
+/// comment is never mirrored twice. Side tables rather than `tasks`
+/// columns because both are sparse: only items on a product with `sync`
+/// configured ever get a row.
+pub(crate) fn migrate_external_tracker_field_sync_tables(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS external_tracker_field_state (
+             work_item_id      TEXT NOT NULL,
+             field             TEXT NOT NULL,
+             upstream_checksum TEXT,
+             boss_checksum     TEXT,
+             boss_value        TEXT,
+             boss_updated_at   TEXT,
+             synced_at         TEXT,
+             PRIMARY KEY (work_item_id, field)
+         );
+         CREATE TABLE IF NOT EXISTS external_tracker_comment_links (
+             work_item_id        TEXT NOT NULL,
+             upstream_comment_id TEXT NOT NULL,
+             work_comment_id     TEXT NOT NULL UNIQUE,
+             origin              TEXT NOT NULL CHECK (origin IN ('upstream', 'boss')),
+             linked_at           TEXT NOT NULL,
+             PRIMARY KEY (work_item_id, upstream_comment_id)
+         );",
+    )?;
+    Ok(())
+}
+
+/// Add `automation_runs.trigger_event`: a short human-readable description
+/// of the engine event that fired an event-triggered automation (a merged
+/// PR, a status transition, a webhook name, ...). `NULL` for cron runs and
+/// manual fires. Purely additive, idempotent `ALTER TABLE ADD COLUMN`.
+pub(crate) fn migrate_automation_runs_trigger_event_column(conn: &Connection) -> Result<()> {
+    if !table_has_column(conn, "automation_runs", "trigger_event")? {
+        conn.execute("ALTER TABLE automation_runs ADD COLUMN trigger_event TEXT", [])?;
+    }
+    Ok(())
+}
+
+/// Create `spend_budgets`: per-product and per-project token/USD limits
+/// the dispatch budget gate ([`crate::spend_budget`]) enforces. One row
+/// per (scope kind, scope, metric, period). `soft_notified_period_start` /
+/// `hard_notified_period_start` record the period whose attention item
+/// has already been filed, so a limit is announced once per period rather
+/// than on every drain pass. Additive, independent of every other table.
+pub(crate) fn migrate_spend_budgets_table(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS spend_budgets (
+             id                         TEXT PRIMARY KEY,
+             scope_kind                 TEXT NOT NULL CHECK (scope_kind IN ('product', 'project')),
+             scope_id                   TEXT NOT NULL,
+             metric                     TEXT NOT NULL CHECK (metric IN ('tokens', 'usd')),
+             period                     TEXT NOT NULL CHECK (period IN ('day', 'week', 'month')),
+             soft_limit                 REAL,
+             hard_limit                 REAL,
+             soft_notified_period_start INTEGER,
+             hard_notified_period_start INTEGER,
+             created_at                 TEXT NOT NULL,
+             updated_at                 TEXT NOT NULL,
+             UNIQUE (scope_kind, scope_id, metric, period)
+         );",
+    )?;
+    Ok(())
+}
+
+pub(crate) fn migrate_product_dispatch_shares_table(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS product_dispatch_shares (
+             product_id     TEXT PRIMARY KEY REFERENCES products(id),
+             weight         INTEGER NOT NULL DEFAULT 1 CHECK (weight >= 1),
+             max_concurrent INTEGER CHECK (max_concurrent IS NULL OR max_concurrent >= 1),
+             updated_at     TEXT NOT NULL
+         );",
+    )?;
+    Ok(())
+}
+
+pub(crate) fn migrate_product_dispatch_calendars_table(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS product_dispatch_calendars (
+             product_id                 TEXT PRIMARY KEY REFERENCES products(id),
+             timezone                   TEXT NOT NULL,
+             windows_json               TEXT NOT NULL DEFAULT '[]',
+             quiet_hours_json           TEXT NOT NULL DEFAULT '[]',
+             drain_before_close_minutes INTEGER NOT NULL DEFAULT 0 CHECK (drain_before_close_minutes >= 0),
+             updated_at                 TEXT NOT NULL
+         );",
+    )?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod churn_guard_migration_tests {
     use super::*;
@@ -239,6 +239,33 @@ pub struct StoredExternalRef {
     pub unbound_at: Option<String>,
 }
 
+/// One `external_tracker_field_state` row: the two-way sync baseline for a
+/// single field of an externally-tracked work item. Returned by
+/// [`WorkDb::reconciler_get_field_sync_states`].
+///
+/// A `None` checksum means no baseline has been recorded yet (the field was
+/// only just added to the product's `sync` config, or only `boss_value` has
+/// been written so far).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExternalFieldSyncState {
+    pub upstream_checksum: Option<String>,
+    pub boss_checksum: Option<String>,
+    /// Boss-side value for fields with no `tasks` column (the assignee).
+    pub boss_value: Option<String>,
+    /// When an operator last set `boss_value`; the Boss side of the
+    /// last-writer-wins comparison for those fields.
+    pub boss_updated_at: Option<String>,
+}
+
+/// One `external_tracker_comment_links` row. `origin` is `"upstream"` for a
+/// comment imported into Boss, `"boss"` for one pushed to the tracker.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExternalCommentLink {
+    pub upstream_comment_id: String,
+    pub work_comment_id: String,
+    pub origin: String,
+}
+
 /// A `ci_remediations` row that is `pending` but has no live execution
 /// (`kind='ci_remediation'` with status in `'ready'`, `'running'`, or
 /// `'waiting_human'`). This arises when two merge-queue dequeue events
@@ -864,6 +864,12 @@ impl WorkDb {
             params![task.id, pr_url, now],
         )?;
         cascade_dependents_after_prereq_status_change(&mut pending, &tx, &task.id, "done", &now)?;
+        // Announce the merge itself (distinct from the `done` transition the
+        // cascade just staged) for `pr_merged` automations.
+        pending.push(Event::PrMerged {
+            pr_url: pr_url.to_owned(),
+            task_id: task.id.clone(),
+        });
         // merge_order sequencing (direction 2): order the pair. Any in-flight
         // merge_order sibling of this just-merged task is now the "later" side
         // and owes a preserving forward-port when its base moves. This never
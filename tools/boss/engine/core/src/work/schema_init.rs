@@ -406,6 +406,8 @@ impl WorkDb {
         // `record_host_dispatch_failure` / `_success` to auto-disable a
         // host that fails every dispatch instead of retrying it forever.
         crate::host_registry::migrate_hosts_health_columns(conn)?;
+        crate::host_registry::migrate_hosts_container_columns(conn)?;
+        crate::host_registry::migrate_host_load_tables(conn)?;
         crate::host_registry::ensure_local_host(conn)?;
         crate::host_registry::refresh_local_host_auto_capabilities(conn)?;
         // Revision tasks (Phase 1): parent linkage column + index on tasks,
@@ -801,6 +803,23 @@ impl WorkDb {
         // `Reopened` outcome so the sidebar can tell "never claimed" apart
         // from "claimed, then abandoned". Purely additive.
         migrate_work_comments_reopened_at_column(conn)?;
+        // Per-field sync baselines and comment links for the external-tracker
+        // reconciler's opt-in two-way sync. New side tables; purely additive.
+        migrate_external_tracker_field_sync_tables(conn)?;
+        // `automation_runs.trigger_event`, recorded by event-triggered
+        // automations so run history shows what fired them. Purely additive.
+        migrate_automation_runs_trigger_event_column(conn)?;
+        // `spend_budgets`: per-product/project token and USD limits checked
+        // at dispatch time. New table; purely additive.
+        migrate_spend_budgets_table(conn)?;
+        // `product_dispatch_shares`: fair-share weight and concurrency
+        // ceiling per product. New table; a product with no row dispatches
+        // at the default weight with no ceiling.
+        migrate_product_dispatch_shares_table(conn)?;
+        // `product_dispatch_calendars`: per-product dispatch windows, quiet
+        // hours and drain-before-close lead. New table; a product with no
+        // row dispatches around the clock.
+        migrate_product_dispatch_calendars_table(conn)?;
         conn.execute(
             "INSERT INTO metadata (key, value) VALUES ('schema_version', '31')
              ON CONFLICT(key) DO UPDATE SET value = excluded.value",
@@ -0,0 +1,339 @@
+//! DB operations for `spend_budgets` and the per-scope spend sums the
+//! dispatch budget gate compares against them. All period arithmetic and
+//! pricing lives in [`crate::spend_budget`]; this file only stores rows
+//! and sums `work_runs` token columns.
+
+use super::*;
+
+use boss_protocol::{SpendBudget, SpendBudgetMetric, SpendBudgetPeriod};
+
+/// A stored budget plus the bookkeeping the gate needs to announce each
+/// limit once per period.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpendBudgetRow {
+    pub budget: SpendBudget,
+    pub soft_notified_period_start: Option<i64>,
+    pub hard_notified_period_start: Option<i64>,
+}
+
+/// Measured token sums for one model within a scope and window. Runs with
+/// NULL `input_tokens` are excluded, per the cost report's
+/// NULL-is-unmeasured contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModelSpend {
+    pub model: Option<String>,
+    pub input_tokens: i64,
+    pub output_tokens: i64,
+    pub cache_creation_tokens: i64,
+    pub cache_read_tokens: i64,
+}
+
+/// Which limit a notification marker belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpendBudgetLimit {
+    Soft,
+    Hard,
+}
+
+const SPEND_BUDGET_COLUMNS: &str = "
+    b.id,
+    b.scope_id,
+    b.metric,
+    b.period,
+    b.scope_kind,
+    COALESCE(p.name, pj.name, b.scope_id),
+    b.hard_limit,
+    b.soft_limit,
+    b.soft_notified_period_start,
+    b.hard_notified_period_start
+";
+
+const SPEND_BUDGET_FROM: &str = "
+    FROM spend_budgets b
+    LEFT JOIN products p ON b.scope_kind = 'product' AND p.id = b.scope_id
+    LEFT JOIN projects pj ON b.scope_kind = 'project' AND pj.id = b.scope_id
+";
+
+fn map_spend_budget_row(row: &Row) -> rusqlite::Result<SpendBudgetRow> {
+    let metric: String = row.get(2)?;
+    let period: String = row.get(3)?;
+    // The table's CHECK constraints keep both columns inside the vocabulary.
+    let metric = SpendBudgetMetric::parse(&metric).unwrap_or(SpendBudgetMetric::Tokens);
+    let period = SpendBudgetPeriod::parse(&period).unwrap_or(SpendBudgetPeriod::Day);
+    Ok(SpendBudgetRow {
+        budget: SpendBudget {
+            id: row.get(0)?,
+            scope_id: row.get(1)?,
+            metric,
+            period,
+            scope_kind: row.get(4)?,
+            scope_name: row.get(5)?,
+            hard_limit: row.get(6)?,
+            soft_limit: row.get(7)?,
+        },
+        soft_notified_period_start: row.get(8)?,
+        hard_notified_period_start: row.get(9)?,
+    })
+}
+
+fn query_spend_budget(conn: &Connection, id: &str) -> Result<Option<SpendBudgetRow>> {
+    let sql = format!("SELECT {SPEND_BUDGET_COLUMNS} {SPEND_BUDGET_FROM} WHERE b.id = ?1");
+    Ok(conn.query_row(&sql, params![id], map_spend_budget_row).optional()?)
+}
+
+impl WorkDb {
+    /// Create the budget for (`scope_kind`, `scope_id`, `metric`, `period`), or replace
+    /// the limits of the one that exists. Replacing keeps the notification
+    /// markers: the next evaluation resolves an item a raised limit no
+    /// longer justifies, and does not re-announce one already filed this
+    /// period.
+    pub fn upsert_spend_budget(
+        &self,
+        scope_kind: &str,
+        scope_id: &str,
+        metric: SpendBudgetMetric,
+        period: SpendBudgetPeriod,
+        soft_limit: Option<f64>,
+        hard_limit: Option<f64>,
+    ) -> Result<SpendBudgetRow> {
+        let conn = self.connect()?;
+        let now = now_string();
+        conn.execute(
+            "INSERT INTO spend_budgets (
+                 id, scope_kind, scope_id, metric, period, soft_limit, hard_limit, created_at, updated_at
+             ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?8)
+             ON CONFLICT(scope_kind, scope_id, metric, period) DO UPDATE SET
+                 soft_limit = excluded.soft_limit,
+                 hard_limit = excluded.hard_limit,
+                 updated_at = excluded.updated_at",
+            params![
+                next_id("bgt"),
+                scope_kind,
+                scope_id,
+                metric.as_str(),
+                period.as_str(),
+                soft_limit,
+                hard_limit,
+                now,
+            ],
+        )?;
+        let id: String = conn.query_row(
+            "SELECT id FROM spend_budgets
+             WHERE scope_kind = ?1 AND scope_id = ?2 AND metric = ?3 AND period = ?4",
+            params![scope_kind, scope_id, metric.as_str(), period.as_str()],
+            |row| row.get(0),
+        )?;
+        query_spend_budget(&conn, &id).require("spend budget", &id)
+    }
+
+    /// Every budget, or only those on `scope_id`, ordered by scope then
+    /// period.
+    pub fn list_spend_budgets(&self, scope_id: Option<&str>) -> Result<Vec<SpendBudgetRow>> {
+        let conn = self.connect()?;
+        let sql = format!(
+            "SELECT {SPEND_BUDGET_COLUMNS} {SPEND_BUDGET_FROM}
+             WHERE ?1 IS NULL OR b.scope_id = ?1
+             ORDER BY b.scope_id, b.period, b.metric"
+        );
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt.query_map(params![scope_id], map_spend_budget_row)?;
+        let mut out = Vec::new();
+        for row in rows {
+            out.push(row?);
+        }
+        Ok(out)
+    }
+
+    /// Delete a budget. Errors when `id` does not exist.
+    pub fn delete_spend_budget(&self, id: &str) -> Result<SpendBudgetRow> {
+        let conn = self.connect()?;
+        let existing = query_spend_budget(&conn, id).require("spend budget", id)?;
+        conn.execute("DELETE FROM spend_budgets WHERE id = ?1", params![id])?;
+        Ok(existing)
+    }
+
+    /// Record (or, with `None`, clear) the period whose `limit` attention
+    /// item has been filed.
+    pub fn set_spend_budget_notified(
+        &self,
+        id: &str,
+        limit: SpendBudgetLimit,
+        period_start_epoch_s: Option<i64>,
+    ) -> Result<()> {
+        let column = match limit {
+            SpendBudgetLimit::Soft => "soft_notified_period_start",
+            SpendBudgetLimit::Hard => "hard_notified_period_start",
+        };
+        let conn = self.connect()?;
+        conn.execute(
+            &format!("UPDATE spend_budgets SET {column} = ?2 WHERE id = ?1"),
+            params![id, period_start_epoch_s],
+        )?;
+        Ok(())
+    }
+
+    /// Measured token sums per model for runs created in
+    /// `[since_epoch_s, until_epoch_s)` on tasks in the scope. A product
+    /// scope counts every task in the product; a project scope only the
+    /// tasks filed under that project.
+    pub fn spend_by_model(
+        &self,
+        scope_kind: &str,
+        scope_id: &str,
+        since_epoch_s: i64,
+        until_epoch_s: i64,
+    ) -> Result<Vec<ModelSpend>> {
+        let scope_column = match scope_kind {
+            "product" => "t.product_id",
+            "project" => "t.project_id",
+            other => bail!("unknown spend budget scope kind: {other}"),
+        };
+        let conn = self.connect()?;
+        let sql = format!(
+            "SELECT wr.model,
+                    SUM(wr.input_tokens),
+                    SUM(COALESCE(wr.output_tokens, 0)),
+                    SUM(COALESCE(wr.cache_creation_tokens, 0)),
+                    SUM(COALESCE(wr.cache_read_tokens, 0))
+             FROM work_runs wr
+             JOIN work_executions we ON we.id = wr.execution_id
+             JOIN tasks t ON t.id = we.work_item_id
+             WHERE {scope_column} = ?1
+               AND wr.input_tokens IS NOT NULL
+               AND CAST(wr.created_at AS INTEGER) >= ?2 AND CAST(wr.created_at AS INTEGER) < ?3
+             GROUP BY wr.model
+             ORDER BY wr.model"
+        );
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt.query_map(params![scope_id, since_epoch_s, until_epoch_s], |row| {
+            Ok(ModelSpend {
+                model: row.get(0)?,
+                input_tokens: row.get(1)?,
+                output_tokens: row.get(2)?,
+                cache_creation_tokens: row.get(3)?,
+                cache_read_tokens: row.get(4)?,
+            })
+        })?;
+        let mut out = Vec::new();
+        for row in rows {
+            out.push(row?);
+        }
+        Ok(out)
+    }
+
+    /// The product and (when filed under one) project a task belongs to —
+    /// the scopes whose budgets apply to its executions. `None` for an id
+    /// that is not a task row, e.g. an automation-triage execution's
+    /// `auto_…` id.
+    pub fn spend_budget_scopes_for_work_item(&self, work_item_id: &str) -> Result<Option<(String, Option<String>)>> {
+        let conn = self.connect()?;
+        Ok(conn
+            .query_row(
+                "SELECT product_id, project_id FROM tasks WHERE id = ?1",
+                params![work_item_id],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_ready_chore_execution, create_test_chore, create_test_product, open_db};
+    use boss_protocol::CreateRunInput;
+
+    fn record_run(db: &WorkDb, execution_id: &str, model: &str, input_tokens: Option<i64>, created_at: i64) {
+        let run_id = db
+            .create_run(
+                CreateRunInput::builder()
+                    .agent_id("worker-1")
+                    .execution_id(execution_id)
+                    .status("completed")
+                    .build(),
+            )
+            .unwrap()
+            .id;
+        db.connect()
+            .unwrap()
+            .execute(
+                "UPDATE work_runs SET model = ?2, input_tokens = ?3, output_tokens = 10, created_at = ?4 WHERE id = ?1",
+                params![run_id, model, input_tokens, created_at.to_string()],
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn upsert_replaces_limits_in_place() {
+        let (_dir, db) = open_db();
+        let product = create_test_product(&db);
+        let first = db
+            .upsert_spend_budget(
+                "product",
+                &product.id,
+                SpendBudgetMetric::Usd,
+                SpendBudgetPeriod::Day,
+                Some(10.0),
+                Some(20.0),
+            )
+            .unwrap();
+        assert_eq!(first.budget.scope_name, product.name);
+        db.set_spend_budget_notified(&first.budget.id, SpendBudgetLimit::Soft, Some(100))
+            .unwrap();
+
+        let second = db
+            .upsert_spend_budget(
+                "product",
+                &product.id,
+                SpendBudgetMetric::Usd,
+                SpendBudgetPeriod::Day,
+                None,
+                Some(30.0),
+            )
+            .unwrap();
+        assert_eq!(second.budget.id, first.budget.id);
+        assert_eq!(second.budget.soft_limit, None);
+        assert_eq!(second.budget.hard_limit, Some(30.0));
+        assert_eq!(second.soft_notified_period_start, Some(100));
+        assert_eq!(db.list_spend_budgets(Some(&product.id)).unwrap().len(), 1);
+
+        db.delete_spend_budget(&first.budget.id).unwrap();
+        assert!(db.list_spend_budgets(None).unwrap().is_empty());
+        assert!(db.delete_spend_budget(&first.budget.id).is_err());
+    }
+
+    #[test]
+    fn spend_by_model_sums_measured_runs_in_scope_and_window() {
+        let (_dir, db) = open_db();
+        let product = create_test_product(&db);
+        let chore = create_test_chore(&db, &product.id, "Spender");
+        let execution = create_ready_chore_execution(&db, &chore.id);
+        record_run(&db, &execution.id, "claude-sonnet-5", Some(100), 1_000);
+        record_run(&db, &execution.id, "claude-sonnet-5", Some(50), 1_500);
+        // Unmeasured and out-of-window runs add nothing.
+        record_run(&db, &execution.id, "claude-sonnet-5", None, 1_200);
+        record_run(&db, &execution.id, "claude-opus-5", Some(999), 5_000);
+
+        let spend = db.spend_by_model("product", &product.id, 1_000, 2_000).unwrap();
+        assert_eq!(
+            spend,
+            vec![ModelSpend {
+                model: Some("claude-sonnet-5".to_owned()),
+                input_tokens: 150,
+                output_tokens: 20,
+                cache_creation_tokens: 0,
+                cache_read_tokens: 0,
+            }]
+        );
+        assert!(
+            db.spend_by_model("product", "prod_other", 0, 10_000)
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            db.spend_budget_scopes_for_work_item(&chore.id).unwrap(),
+            Some((product.id.clone(), None))
+        );
+        assert_eq!(db.spend_budget_scopes_for_work_item("auto_1").unwrap(), None);
+    }
+}
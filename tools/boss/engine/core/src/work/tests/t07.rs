@@ -188,6 +188,7 @@ fn create_automation_round_trips() {
             assert_eq!(cron, "0 14 * * 1-5");
             assert_eq!(timezone, "America/Los_Angeles");
         }
+        other => panic!("expected a schedule trigger, got {other:?}"),
     }
 }
 
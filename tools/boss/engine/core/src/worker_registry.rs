@@ -11,11 +11,12 @@
 //! peer pid, walk up the process tree until we hit a registered
 //! ancestor. That's the run the hook event belongs to.
 //!
-//! macOS-only walk via `proc_pidinfo` for reliability — `getppid()`
-//! is per-process and would require IPC.
+//! The walk asks the kernel for each ancestor's parent via
+//! [`crate::process_probe::parent_pid`] (`proc_pidinfo` on macOS,
+//! `/proc/<pid>/stat` on Linux) — `getppid()` is per-process and would
+//! require IPC.
 
 use std::collections::HashMap;
-use std::io;
 use std::sync::{Arc, Mutex};
 
 const ANCESTOR_WALK_DEPTH: usize = 8;
@@ -276,22 +277,7 @@ impl WorkerRegistry {
     }
 }
 
-/// Look up the parent pid of `pid` via macOS `proc_pidinfo`. Returns
-/// `Ok(None)` when `pid` is the root of the process tree (parent is
-/// 0 / launchd).
-#[cfg(target_os = "macos")]
-pub fn parent_pid(pid: libc::pid_t) -> io::Result<Option<libc::pid_t>> {
-    let ppid = crate::libproc::proc_bsd_info(pid)?.ppid;
-    if ppid == 0 { Ok(None) } else { Ok(Some(ppid)) }
-}
-
-#[cfg(not(target_os = "macos"))]
-pub fn parent_pid(_pid: libc::pid_t) -> io::Result<Option<libc::pid_t>> {
-    Err(io::Error::new(
-        io::ErrorKind::Unsupported,
-        "parent_pid is only implemented on macOS",
-    ))
-}
+pub use crate::process_probe::parent_pid;
 
 #[cfg(test)]
 mod tests {
@@ -414,7 +400,7 @@ mod tests {
         assert!(fresh);
     }
 
-    #[cfg(target_os = "macos")]
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
     #[test]
     fn parent_pid_of_self_is_some() {
         let self_pid = std::process::id() as libc::pid_t;
@@ -422,7 +408,7 @@ mod tests {
         assert!(parent.is_some(), "expected a parent pid for the test process");
     }
 
-    #[cfg(target_os = "macos")]
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
     #[test]
     fn ancestor_walk_finds_self_pid_when_registered() {
         let reg = WorkerRegistry::new();
@@ -432,7 +418,7 @@ mod tests {
         assert_eq!(reg.lookup_with_ancestor_walk(self_pid).as_deref(), Some("self-run"));
     }
 
-    #[cfg(target_os = "macos")]
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
     #[test]
     fn ancestor_walk_finds_parent_when_only_parent_registered() {
         let reg = WorkerRegistry::new();
@@ -446,7 +432,7 @@ mod tests {
         assert_eq!(reg.lookup_with_ancestor_walk(self_pid).as_deref(), Some("parent-run"));
     }
 
-    #[cfg(target_os = "macos")]
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
     #[test]
     fn ancestor_walk_returns_none_when_no_ancestor_registered() {
         let reg = WorkerRegistry::new();
@@ -3,9 +3,9 @@
 //! Shared by the engine's `ipc_log` and `population_timing` modules: both
 //! need the same shape — fire-and-forget records queued over a channel to a
 //! background task that owns a single rotating file handle, rotating and
-//! pruning by UTC calendar day. This module owns that machinery once,
-//! parameterized over the record type, its filename prefix, and the target
-//! directory.
+//! pruning by UTC calendar day (and, optionally, capping each day file's
+//! size). This module owns that machinery once, parameterized over the
+//! record type, its filename prefix, and the target directory.
 
 use std::path::{Path, PathBuf};
 
@@ -41,9 +41,21 @@ where
     /// not spawned — entries queue up and are silently dropped when the
     /// sender is dropped.
     pub fn new(dir: impl Into<PathBuf>, file_prefix: &'static str) -> Self {
+        Self::spawn(dir.into(), file_prefix, None)
+    }
+
+    /// Like [`new`](Self::new), but a day file stops growing at
+    /// `max_file_bytes`: records that would take it past the cap are
+    /// dropped (with one WARN per day) until the next day's file opens.
+    /// Together with [`RETAIN_DAYS`] this bounds the log's total size.
+    pub fn with_max_file_bytes(dir: impl Into<PathBuf>, file_prefix: &'static str, max_file_bytes: u64) -> Self {
+        Self::spawn(dir.into(), file_prefix, Some(max_file_bytes))
+    }
+
+    fn spawn(dir: PathBuf, file_prefix: &'static str, max_file_bytes: Option<u64>) -> Self {
         let (tx, rx) = mpsc::unbounded_channel();
         if tokio::runtime::Handle::try_current().is_ok() {
-            tokio::spawn(writer_task(dir.into(), file_prefix, rx));
+            tokio::spawn(writer_task(dir, file_prefix, max_file_bytes, rx));
         }
         Self { tx }
     }
@@ -55,14 +67,21 @@ where
     }
 }
 
-async fn writer_task<T>(dir: PathBuf, file_prefix: &'static str, mut rx: mpsc::UnboundedReceiver<T>)
-where
+async fn writer_task<T>(
+    dir: PathBuf,
+    file_prefix: &'static str,
+    max_file_bytes: Option<u64>,
+    mut rx: mpsc::UnboundedReceiver<T>,
+) where
     T: Serialize + TimestampedRecord,
 {
     use std::io::Write;
 
     let mut current_date = String::new();
     let mut file: Option<std::fs::File> = None;
+    // Bytes in the open day file, and whether its cap has been reported.
+    let mut file_len = 0u64;
+    let mut cap_reported = false;
 
     while let Some(rec) = rx.recv().await {
         let date_str = epoch_ms_to_date(rec.ts_epoch_ms());
@@ -85,6 +104,8 @@ where
             let path = dir.join(format!("{file_prefix}{date_str}.jsonl"));
             match std::fs::OpenOptions::new().create(true).append(true).open(&path) {
                 Ok(f) => {
+                    file_len = f.metadata().map_or(0, |meta| meta.len());
+                    cap_reported = false;
                     file = Some(f);
                     current_date = date_str;
                 }
@@ -103,8 +124,22 @@ where
         match serde_json::to_vec(&rec) {
             Ok(mut bytes) => {
                 bytes.push(b'\n');
-                if let Err(err) = f.write_all(&bytes) {
-                    tracing::warn!(?err, "day_rotated_log: write failed; dropping entry");
+                let len = bytes.len() as u64;
+                if max_file_bytes.is_some_and(|max| file_len + len > max) {
+                    if !cap_reported {
+                        cap_reported = true;
+                        tracing::warn!(
+                            prefix = file_prefix,
+                            date = %current_date,
+                            max_file_bytes,
+                            "day_rotated_log: day file reached its size cap; dropping entries until the next day"
+                        );
+                    }
+                    continue;
+                }
+                match f.write_all(&bytes) {
+                    Ok(()) => file_len += len,
+                    Err(err) => tracing::warn!(?err, "day_rotated_log: write failed; dropping entry"),
                 }
             }
             Err(err) => {
@@ -325,4 +360,43 @@ mod tests {
             "stale pre-seeded file should be pruned when the rollover fires"
         );
     }
+
+    #[tokio::test]
+    async fn writer_task_drops_records_past_the_file_cap() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let ts_epoch_ms = 4_070_000_000_000u128;
+        let path = dir.path().join(format!("test-{}.jsonl", epoch_ms_to_date(ts_epoch_ms)));
+        let line_len = serde_json::to_vec(&TestRec {
+            ts_epoch_ms,
+            msg: "one",
+        })
+        .unwrap()
+        .len() as u64
+            + 1;
+
+        let logger = DayRotatedLogger::with_max_file_bytes(dir.path(), "test-", 2 * line_len);
+        for msg in ["one", "two", "six", "ten"] {
+            logger.emit(TestRec { ts_epoch_ms, msg });
+        }
+        // A sentinel on the next day marks the writer as past the capped
+        // records.
+        let next_day = dir
+            .path()
+            .join(format!("test-{}.jsonl", epoch_ms_to_date(ts_epoch_ms + 86_400_000)));
+        logger.emit(TestRec {
+            ts_epoch_ms: ts_epoch_ms + 86_400_000,
+            msg: "end",
+        });
+        for _ in 0..200 {
+            if next_day.exists() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(content.lines().count(), 2, "only two records fit: {content}");
+        assert!(content.contains("\"two\"") && !content.contains("\"six\""));
+        assert!(next_day.exists(), "the next day's file starts uncapped");
+    }
 }
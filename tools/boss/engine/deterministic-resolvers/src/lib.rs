@@ -10,7 +10,17 @@
 //! recipe mechanism (design §"Extension mechanism — declarative
 //! resolution recipes", tracked as T13) — for user-configured formulas
 //! that don't warrant a compiled-in resolver. See [`recipe_config`] for
-//! the recipe format and its boss-side config file. This crate is
+//! the recipe format and its boss-side config file.
+//!
+//! The built-ins cover Rust/Bazel lockfiles, the JS and Go lockfiles
+//! (`package-lock.json`, `pnpm-lock.yaml`, `yarn.lock`, `go.sum`), checked-in
+//! Go generated code, parallel `Cargo.toml` version bumps and pure-append
+//! registry unions. Formats with a safe key-wise merge union first
+//! (`markers` + `union`) and only regenerate when that isn't possible;
+//! everything that runs a tool does so through a [`SandboxedCommandRunner`]
+//! (see `sandbox`), and every result must pass a [`ParseCheck`] before it
+//! counts as resolved. [`RegenerateCommandResolver`] is the generic
+//! "regenerate via command" class for generators a repo defines itself. This crate is
 //! standalone and unit-tested against fixture conflicts; it is wired into
 //! `conflict_watch` via `boss_engine_core::conflict_ladder::attempt_rung0`,
 //! live by default (see that module's `RUNG0_APPLY_LIVE`) whenever the
@@ -18,9 +28,13 @@
 
 mod command;
 mod lockfile;
+mod markers;
 mod recipe_config;
 mod registry;
 mod resolvers;
+mod sandbox;
+mod union;
+mod verify;
 
 use std::path::Path;
 
@@ -30,8 +44,12 @@ pub use boss_conflict_diagnosis::ConflictedFile;
 pub use recipe_config::{ConflictRecipe, ConflictRecipesStore};
 pub use registry::{DeclinedFile, FailedFile, RegistryResolution, ResolvedFile, ResolverRegistry};
 pub use resolvers::{
-    BazelModuleLockResolver, CargoLockResolver, InvalidRecipe, RecipeResolver, RegistryAppendUnionResolver,
+    BazelModuleLockResolver, CargoLockResolver, CargoWorkspaceVersionResolver, GoGeneratedCodeResolver, GoSumResolver,
+    InvalidRecipe, NpmLockResolver, PnpmLockResolver, RecipeResolver, RegenerateCommandResolver,
+    RegistryAppendUnionResolver, YarnLockResolver,
 };
+pub use sandbox::{SandboxPolicy, SandboxedCommandRunner};
+pub use verify::ParseCheck;
 
 /// Coarse classification of a conflicted file's resolution strategy, kept
 /// for telemetry attribution (`conflict_resolutions.conflict_class`,
@@ -45,6 +63,17 @@ pub enum ConflictClass {
     /// recipe rather than a compiled-in resolver. Coarse on purpose;
     /// [`ResolvedFile::summary`] carries the specific recipe name.
     Recipe,
+    NpmLock,
+    PnpmLock,
+    YarnLock,
+    GoSum,
+    /// Checked-in generated source regenerated by its generator
+    /// ([`GoGeneratedCodeResolver`]).
+    GeneratedCode,
+    CargoWorkspaceVersion,
+    /// Resolved by a [`RegenerateCommandResolver`]; like [`Self::Recipe`]
+    /// the summary names the specific generator.
+    RegenerateCommand,
 }
 
 impl ConflictClass {
@@ -54,6 +83,13 @@ impl ConflictClass {
             ConflictClass::BazelModuleLock => "bazel_module_lock",
             ConflictClass::RegistryAppendUnion => "registry_append_union",
             ConflictClass::Recipe => "recipe",
+            ConflictClass::NpmLock => "npm_lock",
+            ConflictClass::PnpmLock => "pnpm_lock",
+            ConflictClass::YarnLock => "yarn_lock",
+            ConflictClass::GoSum => "go_sum",
+            ConflictClass::GeneratedCode => "generated_code",
+            ConflictClass::CargoWorkspaceVersion => "cargo_workspace_version",
+            ConflictClass::RegenerateCommand => "regenerate_command",
         }
     }
 }
@@ -74,6 +110,14 @@ pub trait DeterministicResolver: Send + Sync {
     /// match.
     fn applies_to(&self, file: &ConflictedFile) -> bool;
 
+    /// Whether this resolver resolves a *manifest* other resolvers
+    /// regenerate from (e.g. `Cargo.toml` for `Cargo.lock`). The registry
+    /// resolves every manifest file before any other file, so lockfile
+    /// regeneration never reads a manifest that still has markers in it.
+    fn edits_manifest(&self) -> bool {
+        false
+    }
+
     /// Attempt the resolution. `workspace_path` is the root of the leased
     /// workspace; `file.path` is relative to it.
     async fn resolve(&self, workspace_path: &Path, file: &ConflictedFile) -> ResolveOutcome;
@@ -116,5 +160,12 @@ mod tests {
         assert_eq!(ConflictClass::CargoLock.as_str(), "cargo_lock");
         assert_eq!(ConflictClass::BazelModuleLock.as_str(), "bazel_module_lock");
         assert_eq!(ConflictClass::RegistryAppendUnion.as_str(), "registry_append_union");
+        assert_eq!(ConflictClass::NpmLock.as_str(), "npm_lock");
+        assert_eq!(ConflictClass::PnpmLock.as_str(), "pnpm_lock");
+        assert_eq!(ConflictClass::YarnLock.as_str(), "yarn_lock");
+        assert_eq!(ConflictClass::GoSum.as_str(), "go_sum");
+        assert_eq!(ConflictClass::GeneratedCode.as_str(), "generated_code");
+        assert_eq!(ConflictClass::CargoWorkspaceVersion.as_str(), "cargo_workspace_version");
+        assert_eq!(ConflictClass::RegenerateCommand.as_str(), "regenerate_command");
     }
 }
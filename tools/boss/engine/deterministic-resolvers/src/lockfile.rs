@@ -1,11 +1,13 @@
 //! Shared "discard the conflicted lockfile, regenerate it from the merged
-//! manifest" strategy used by every built-in lockfile resolver.
+//! manifest" strategy used by every built-in lockfile resolver, plus the
+//! parse-verified variant the JS lockfile resolvers use.
 
 use std::path::Path;
 
 use crate::ConflictedFile;
 use crate::ResolveOutcome;
 use crate::command::{CommandRunner, run_or_fail};
+use crate::verify::ParseCheck;
 
 /// `manifest_filename` is the sibling manifest (`Cargo.toml`,
 /// `MODULE.bazel`) the regeneration command reads; it must already be
@@ -66,6 +68,35 @@ pub(crate) async fn regenerate_lockfile(
     }
 }
 
+/// [`regenerate_lockfile`], then require the regenerated file to pass
+/// `check`. A tool that exits 0 but writes something unparseable is an
+/// operational failure of the tool, so it maps to
+/// [`ResolveOutcome::Failed`], not a decline.
+pub(crate) async fn regenerate_lockfile_verified(
+    runner: &dyn CommandRunner,
+    workspace_path: &Path,
+    file: &ConflictedFile,
+    manifest_filename: &str,
+    program: &str,
+    args: &[&str],
+    check: ParseCheck,
+) -> ResolveOutcome {
+    let outcome = regenerate_lockfile(runner, workspace_path, file, manifest_filename, program, args).await;
+    if !matches!(outcome, ResolveOutcome::Resolved { .. }) {
+        return outcome;
+    }
+    verify_file(workspace_path, file, check).map_or_else(|reason| ResolveOutcome::Failed { reason }, |()| outcome)
+}
+
+/// Reads `file` back from the workspace and runs `check` over it.
+pub(crate) fn verify_file(workspace_path: &Path, file: &ConflictedFile, check: ParseCheck) -> Result<(), String> {
+    let content = std::fs::read_to_string(workspace_path.join(&file.path))
+        .map_err(|e| format!("failed to read resolved {}: {e}", file.path))?;
+    check
+        .check(&content)
+        .map_err(|e| format!("resolved {} does not parse: {e}", file.path))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -224,4 +255,47 @@ mod tests {
 
         assert!(matches!(outcome, ResolveOutcome::Resolved { .. }));
     }
+
+    #[tokio::test]
+    async fn verified_regeneration_fails_when_the_output_does_not_parse() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("package.json"), "{}").unwrap();
+
+        let runner = FakeCommandRunner::success_writing_file("package-lock.json", "{\"truncated\": ");
+        let outcome = regenerate_lockfile_verified(
+            &runner,
+            dir.path(),
+            &file("package-lock.json"),
+            "package.json",
+            "npm",
+            &["install", "--package-lock-only"],
+            ParseCheck::NpmLock,
+        )
+        .await;
+
+        match outcome {
+            ResolveOutcome::Failed { reason } => assert!(reason.contains("does not parse"), "reason was: {reason}"),
+            other => panic!("expected Failed, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn verified_regeneration_resolves_when_the_output_parses() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("package.json"), "{}").unwrap();
+
+        let runner = FakeCommandRunner::success_writing_file("package-lock.json", "{\"lockfileVersion\": 3}");
+        let outcome = regenerate_lockfile_verified(
+            &runner,
+            dir.path(),
+            &file("package-lock.json"),
+            "package.json",
+            "npm",
+            &["install", "--package-lock-only"],
+            ParseCheck::NpmLock,
+        )
+        .await;
+
+        assert!(matches!(outcome, ResolveOutcome::Resolved { .. }), "got {outcome:?}");
+    }
 }
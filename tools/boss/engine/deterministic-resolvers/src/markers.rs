@@ -0,0 +1,344 @@
+//! Conflict-marker parsing shared by the union-style resolvers
+//! (`yarn.lock`, `go.sum`, `Cargo.toml` version bumps, generated code).
+//!
+//! A conflicted file on disk is one text with merge markers in it; the
+//! union resolvers instead want each side's *whole* file so they can
+//! parse both into structured entries and merge those. [`split_sides`]
+//! reconstructs "ours", "theirs" and (when every hunk carries one) the
+//! base from either marker dialect a workspace can contain:
+//!
+//! - jj's materialized conflicts: `<<<<<<< Conflict N of M`, then any mix
+//!   of `%%%%%%%` diff sections (` `/`-`/`+` prefixed lines relative to
+//!   the base), `+++++++` side snapshots and `-------` base snapshots,
+//!   closed by `>>>>>>> Conflict N of M ends`.
+//! - git's: `<<<<<<< ours`, optional `||||||| base` (diff3), `=======`,
+//!   `>>>>>>> theirs`.
+//!
+//! Only two-sided conflicts are reconstructed; anything else is an `Err`
+//! the caller turns into a decline.
+
+const START: &str = "<<<<<<<";
+const END: &str = ">>>>>>>";
+const JJ_DIFF: &str = "%%%%%%%";
+const JJ_SIDE: &str = "+++++++";
+const JJ_BASE: &str = "-------";
+const JJ_HEADER_CONTINUATION: &str = "\\\\\\\\\\\\\\";
+const GIT_BASE: &str = "|||||||";
+const GIT_SEPARATOR: &str = "=======";
+
+/// Each side of a two-sided conflicted file, reassembled in full.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ConflictSides {
+    pub(crate) ours: String,
+    pub(crate) theirs: String,
+    /// `None` when at least one hunk didn't record its base (plain git
+    /// markers without diff3) — callers must then fall back to a
+    /// base-less union.
+    pub(crate) base: Option<String>,
+}
+
+/// One conflict hunk's sides, in the order they appear in the file.
+#[derive(Debug, Default)]
+struct Hunk {
+    sides: Vec<Vec<String>>,
+    base: Option<Vec<String>>,
+}
+
+impl Hunk {
+    fn set_base(&mut self, lines: Vec<String>) -> Result<(), String> {
+        match &self.base {
+            Some(existing) if *existing != lines => {
+                Err("conflict hunk records two different bases (more than two sides)".to_owned())
+            }
+            _ => {
+                self.base = Some(lines);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Whether `content` still contains a conflict-marker line of either
+/// dialect. Used by verification to reject output that kept markers.
+pub(crate) fn has_conflict_markers(content: &str) -> bool {
+    content
+        .lines()
+        .any(|line| line.starts_with(START) || line.starts_with(END) || line == GIT_SEPARATOR)
+}
+
+/// Reassemble both sides (and the base, when known) of a two-sided
+/// conflicted file. `Err` carries a decline reason.
+pub(crate) fn split_sides(content: &str) -> Result<ConflictSides, String> {
+    let trailing_newline = content.ends_with('\n');
+    let lines: Vec<&str> = content.lines().collect();
+
+    let mut ours = Vec::new();
+    let mut theirs = Vec::new();
+    let mut base = Some(Vec::new());
+    let mut saw_conflict = false;
+
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+        if !line.starts_with(START) {
+            ours.push(line.to_owned());
+            theirs.push(line.to_owned());
+            if let Some(base) = base.as_mut() {
+                base.push(line.to_owned());
+            }
+            i += 1;
+            continue;
+        }
+
+        saw_conflict = true;
+        let is_jj = lines
+            .get(i + 1)
+            .is_some_and(|next| next.starts_with(JJ_DIFF) || next.starts_with(JJ_SIDE) || next.starts_with(JJ_BASE));
+        let (hunk, next) = if is_jj {
+            parse_jj_hunk(&lines, i + 1)?
+        } else {
+            parse_git_hunk(&lines, i + 1)?
+        };
+        i = next;
+
+        let [side_a, side_b] = <[Vec<String>; 2]>::try_from(hunk.sides).map_err(|sides| {
+            format!(
+                "conflict hunk near line {} has {} sides; only two-sided conflicts are supported",
+                i,
+                sides.len()
+            )
+        })?;
+        ours.extend(side_a);
+        theirs.extend(side_b);
+        match (base.as_mut(), hunk.base) {
+            (Some(base), Some(hunk_base)) => base.extend(hunk_base),
+            _ => base = None,
+        }
+    }
+
+    if !saw_conflict {
+        return Err("no conflict markers found".to_owned());
+    }
+
+    let join = |lines: Vec<String>| {
+        let mut text = lines.join("\n");
+        if trailing_newline && !text.is_empty() {
+            text.push('\n');
+        }
+        text
+    };
+    Ok(ConflictSides {
+        ours: join(ours),
+        theirs: join(theirs),
+        base: base.map(join),
+    })
+}
+
+/// Parses a jj hunk body starting just after its `<<<<<<<` line; returns
+/// the hunk and the index of the line after its `>>>>>>>` marker.
+fn parse_jj_hunk(lines: &[&str], mut i: usize) -> Result<(Hunk, usize), String> {
+    enum Section {
+        Diff { base: Vec<String>, side: Vec<String> },
+        Side(Vec<String>),
+        Base(Vec<String>),
+    }
+
+    fn close(hunk: &mut Hunk, section: Option<Section>) -> Result<(), String> {
+        match section {
+            Some(Section::Diff { base, side }) => {
+                hunk.sides.push(side);
+                hunk.set_base(base)
+            }
+            Some(Section::Side(side)) => {
+                hunk.sides.push(side);
+                Ok(())
+            }
+            Some(Section::Base(base)) => hunk.set_base(base),
+            None => Ok(()),
+        }
+    }
+
+    let mut hunk = Hunk::default();
+    let mut section: Option<Section> = None;
+    while let Some(&line) = lines.get(i) {
+        i += 1;
+        if line.starts_with(END) {
+            close(&mut hunk, section.take())?;
+            return Ok((hunk, i));
+        }
+        if line.starts_with(JJ_HEADER_CONTINUATION) {
+            continue;
+        }
+        if line.starts_with(JJ_DIFF) {
+            close(&mut hunk, section.take())?;
+            section = Some(Section::Diff {
+                base: Vec::new(),
+                side: Vec::new(),
+            });
+            continue;
+        }
+        if line.starts_with(JJ_SIDE) {
+            close(&mut hunk, section.take())?;
+            section = Some(Section::Side(Vec::new()));
+            continue;
+        }
+        if line.starts_with(JJ_BASE) {
+            close(&mut hunk, section.take())?;
+            section = Some(Section::Base(Vec::new()));
+            continue;
+        }
+        match section.as_mut() {
+            Some(Section::Diff { base, side }) => {
+                if let Some(added) = line.strip_prefix('+') {
+                    side.push(added.to_owned());
+                } else if let Some(removed) = line.strip_prefix('-') {
+                    base.push(removed.to_owned());
+                } else {
+                    let context = line.strip_prefix(' ').unwrap_or(line);
+                    base.push(context.to_owned());
+                    side.push(context.to_owned());
+                }
+            }
+            Some(Section::Side(side)) => side.push(line.to_owned()),
+            Some(Section::Base(base)) => base.push(line.to_owned()),
+            None => {
+                return Err(format!(
+                    "unexpected line inside jj conflict before any section header: {line:?}"
+                ));
+            }
+        }
+    }
+    Err("jj conflict hunk ended before its closing marker".to_owned())
+}
+
+/// Parses a git hunk body starting just after its `<<<<<<<` line; returns
+/// the hunk and the index of the line after its `>>>>>>>` marker.
+fn parse_git_hunk(lines: &[&str], mut i: usize) -> Result<(Hunk, usize), String> {
+    let mut ours = Vec::new();
+    let mut base: Option<Vec<String>> = None;
+    let mut theirs: Option<Vec<String>> = None;
+    while let Some(&line) = lines.get(i) {
+        i += 1;
+        if line.starts_with(START) {
+            return Err("nested git conflict markers".to_owned());
+        }
+        if line.starts_with(END) {
+            let Some(theirs) = theirs else {
+                return Err("git conflict hunk closed without a `=======` separator".to_owned());
+            };
+            let mut hunk = Hunk {
+                sides: vec![ours, theirs],
+                base: None,
+            };
+            if let Some(base) = base {
+                hunk.set_base(base)?;
+            }
+            return Ok((hunk, i));
+        }
+        if theirs.is_none() && line.starts_with(GIT_BASE) {
+            base = Some(Vec::new());
+            continue;
+        }
+        if theirs.is_none() && line == GIT_SEPARATOR {
+            theirs = Some(Vec::new());
+            continue;
+        }
+        match (&mut theirs, &mut base) {
+            (Some(theirs), _) => theirs.push(line.to_owned()),
+            (None, Some(base)) => base.push(line.to_owned()),
+            (None, None) => ours.push(line.to_owned()),
+        }
+    }
+    Err("git conflict hunk ended before its closing marker".to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn jj_diff_style_reconstructs_both_sides_and_base() {
+        let content = "\
+head
+<<<<<<< Conflict 1 of 1
+%%%%%%% Changes from base to side #1
+ shared
+-old
++ours
++++++++ Contents of side #2
+shared
+theirs
+>>>>>>> Conflict 1 of 1 ends
+tail
+";
+        let sides = split_sides(content).unwrap();
+        assert_eq!(sides.ours, "head\nshared\nours\ntail\n");
+        assert_eq!(sides.theirs, "head\nshared\ntheirs\ntail\n");
+        assert_eq!(sides.base.as_deref(), Some("head\nshared\nold\ntail\n"));
+    }
+
+    #[test]
+    fn jj_snapshot_style_reconstructs_both_sides_and_base() {
+        let content = "\
+<<<<<<< Conflict 1 of 1
++++++++ Contents of side #1
+a
+------- Contents of base
+b
++++++++ Contents of side #2
+c
+>>>>>>> Conflict 1 of 1 ends
+";
+        let sides = split_sides(content).unwrap();
+        assert_eq!(sides.ours, "a\n");
+        assert_eq!(sides.theirs, "c\n");
+        assert_eq!(sides.base.as_deref(), Some("b\n"));
+    }
+
+    #[test]
+    fn git_markers_without_diff3_have_no_base() {
+        let content = "x\n<<<<<<< HEAD\na\n=======\nb\n>>>>>>> branch\ny\n";
+        let sides = split_sides(content).unwrap();
+        assert_eq!(sides.ours, "x\na\ny\n");
+        assert_eq!(sides.theirs, "x\nb\ny\n");
+        assert_eq!(sides.base, None);
+    }
+
+    #[test]
+    fn git_diff3_markers_carry_the_base() {
+        let content = "<<<<<<< HEAD\na\n||||||| base\nold\n=======\nb\n>>>>>>> branch\n";
+        let sides = split_sides(content).unwrap();
+        assert_eq!(sides.base.as_deref(), Some("old\n"));
+    }
+
+    #[test]
+    fn three_sided_jj_conflict_is_rejected() {
+        let content = "\
+<<<<<<< Conflict 1 of 1
++++++++ Contents of side #1
+a
+------- Contents of base
+b
++++++++ Contents of side #2
+c
++++++++ Contents of side #3
+d
+>>>>>>> Conflict 1 of 1 ends
+";
+        let err = split_sides(content).unwrap_err();
+        assert!(err.contains("3 sides"), "reason was: {err}");
+    }
+
+    #[test]
+    fn unterminated_hunk_and_clean_file_are_rejected() {
+        assert!(split_sides("<<<<<<< HEAD\na\n=======\nb\n").is_err());
+        assert!(split_sides("clean\n").is_err());
+    }
+
+    #[test]
+    fn has_conflict_markers_spots_either_dialect() {
+        assert!(has_conflict_markers("a\n<<<<<<< Conflict 1 of 1\n"));
+        assert!(has_conflict_markers("a\n=======\n"));
+        assert!(!has_conflict_markers("a\n==\nb\n"));
+    }
+}
@@ -1,6 +1,9 @@
 use std::path::Path;
 
-use crate::resolvers::{BazelModuleLockResolver, CargoLockResolver, RegistryAppendUnionResolver};
+use crate::resolvers::{
+    BazelModuleLockResolver, CargoLockResolver, CargoWorkspaceVersionResolver, GoGeneratedCodeResolver, GoSumResolver,
+    NpmLockResolver, PnpmLockResolver, RegistryAppendUnionResolver, YarnLockResolver,
+};
 use crate::{ConflictClass, ConflictedFile, DeterministicResolver, ResolveOutcome};
 
 /// A file some resolver successfully resolved.
@@ -78,7 +81,13 @@ impl ResolverRegistry {
     pub fn with_builtins() -> Self {
         let mut registry = Self::empty();
         registry.register(Box::new(CargoLockResolver::new()));
+        registry.register(Box::new(CargoWorkspaceVersionResolver::new()));
         registry.register(Box::new(BazelModuleLockResolver::new()));
+        registry.register(Box::new(NpmLockResolver::new()));
+        registry.register(Box::new(PnpmLockResolver::new()));
+        registry.register(Box::new(YarnLockResolver::new()));
+        registry.register(Box::new(GoSumResolver::new()));
+        registry.register(Box::new(GoGeneratedCodeResolver::new()));
         registry.register(Box::new(RegistryAppendUnionResolver::new()));
         registry
     }
@@ -90,17 +99,29 @@ impl ResolverRegistry {
     /// Attempt every conflicted file against the registry. Returns
     /// `AllResolved` only if every file matched some resolver and that
     /// resolver resolved it; the first matching resolver in registration
-    /// order is used per file.
+    /// order is used per file. Files claimed by a manifest resolver
+    /// ([`DeterministicResolver::edits_manifest`]) go first, so a lockfile
+    /// is only regenerated once its manifest is conflict-free; otherwise
+    /// files are attempted in the order given.
     pub async fn resolve_all(&self, workspace_path: &Path, files: &[ConflictedFile]) -> RegistryResolution {
         let mut resolved = Vec::new();
         let mut declined = Vec::new();
         let mut failed = Vec::new();
 
+        let mut ordered: Vec<&ConflictedFile> = files.iter().collect();
+        ordered.sort_by_key(|file| {
+            !self
+                .resolvers
+                .iter()
+                .find(|resolver| resolver.applies_to(file))
+                .is_some_and(|resolver| resolver.edits_manifest())
+        });
+
         // One INFO trace line per resolver invocation (conflict class,
         // resolver id, outcome) so rung-0 activity is answerable from the
         // engine trace — the "runs silently" logging gap this crate had
         // before (mono#1398/#1764 diagnosis).
-        for file in files {
+        for file in ordered {
             match self.resolvers.iter().find(|resolver| resolver.applies_to(file)) {
                 Some(resolver) => {
                     let class = resolver.class();
@@ -558,6 +579,53 @@ mod tests {
         assert!(!registry.resolvers.iter().any(|r| r.applies_to(&file("random.txt"))));
     }
 
+    #[tokio::test]
+    async fn with_builtins_registers_js_go_and_manifest_resolvers() {
+        let registry = ResolverRegistry::with_builtins();
+        let class_for = |path: &str| {
+            registry
+                .resolvers
+                .iter()
+                .find(|r| r.applies_to(&file(path)))
+                .map(|r| r.class())
+        };
+        assert_eq!(class_for("web/package-lock.json"), Some(ConflictClass::NpmLock));
+        assert_eq!(class_for("pnpm-lock.yaml"), Some(ConflictClass::PnpmLock));
+        assert_eq!(class_for("yarn.lock"), Some(ConflictClass::YarnLock));
+        assert_eq!(class_for("svc/go.sum"), Some(ConflictClass::GoSum));
+        assert_eq!(class_for("api/v1/service.pb.go"), Some(ConflictClass::GeneratedCode));
+        assert_eq!(class_for("Cargo.toml"), Some(ConflictClass::CargoWorkspaceVersion));
+        assert_eq!(class_for("Cargo.lock"), Some(ConflictClass::CargoLock));
+    }
+
+    #[tokio::test]
+    async fn manifest_files_resolve_before_the_lockfiles_regenerated_from_them() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("Cargo.toml"),
+            "[package]\nname = \"x\"\n<<<<<<< HEAD\nversion = \"0.3.1\"\n=======\nversion = \"0.4.0\"\n>>>>>>> b\n",
+        )
+        .unwrap();
+
+        let cargo_runner = Arc::new(FakeCommandRunner::success_writing_file("Cargo.lock", "[[package]]\n"));
+        let mut registry = ResolverRegistry::empty();
+        registry.register(Box::new(CargoLockResolver::with_runner(cargo_runner)));
+        registry.register(Box::new(CargoWorkspaceVersionResolver::new()));
+
+        // Listed lockfile-first on purpose.
+        let result = registry
+            .resolve_all(dir.path(), &[file("Cargo.lock"), file("Cargo.toml")])
+            .await;
+
+        match result {
+            RegistryResolution::AllResolved(resolved) => {
+                let order: Vec<&str> = resolved.iter().map(|r| r.path.as_str()).collect();
+                assert_eq!(order, vec!["Cargo.toml", "Cargo.lock"]);
+            }
+            other => panic!("expected AllResolved, got {other:?}"),
+        }
+    }
+
     #[tokio::test]
     async fn cargo_lock_and_bazel_module_lock_both_resolve_deterministically_together() {
         // Regression coverage for spinyfin/mono#2032 (chore T2680): a
@@ -0,0 +1,357 @@
+use std::cmp::Ordering;
+use std::path::Path;
+
+use async_trait::async_trait;
+
+use crate::markers::split_sides;
+use crate::verify::ParseCheck;
+use crate::{ConflictClass, ConflictedFile, DeterministicResolver, ResolveOutcome};
+
+/// Placeholder a version literal's contents are replaced with when
+/// comparing sides structurally.
+const MASK: &str = "\u{0}";
+
+/// Resolves parallel version bumps in a `Cargo.toml` — the shape two
+/// release-train PRs produce when each bumps `[workspace.package]
+/// version` (and the matching `version = "..."` pins of path
+/// dependencies in `[workspace.dependencies]`) from the same base.
+///
+/// Fires only when the two sides are identical once every `version`
+/// string literal is masked out, i.e. the conflict is *nothing but*
+/// version bumps. Each differing literal then resolves three-way: a side
+/// that left it at the base yields to the side that bumped it, and two
+/// different bumps take the higher version (both releases' intent is
+/// "at least this"). Literals whose requirement operators differ
+/// (`^0.3` vs `=0.4`) or don't parse as versions decline. The merged
+/// manifest must still parse as TOML.
+///
+/// This is a manifest resolver ([`DeterministicResolver::edits_manifest`]):
+/// the registry runs it before `Cargo.lock` regeneration, which reads
+/// the merged manifest.
+pub struct CargoWorkspaceVersionResolver;
+
+impl CargoWorkspaceVersionResolver {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for CargoWorkspaceVersionResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl DeterministicResolver for CargoWorkspaceVersionResolver {
+    fn class(&self) -> ConflictClass {
+        ConflictClass::CargoWorkspaceVersion
+    }
+
+    fn applies_to(&self, file: &ConflictedFile) -> bool {
+        Path::new(&file.path).file_name().and_then(|name| name.to_str()) == Some("Cargo.toml")
+    }
+
+    fn edits_manifest(&self) -> bool {
+        true
+    }
+
+    async fn resolve(&self, workspace_path: &Path, file: &ConflictedFile) -> ResolveOutcome {
+        let full_path = workspace_path.join(&file.path);
+        let content = match std::fs::read_to_string(&full_path) {
+            Ok(content) => content,
+            Err(e) => {
+                return ResolveOutcome::Declined {
+                    reason: format!("failed to read {}: {e}", file.path),
+                };
+            }
+        };
+
+        let (merged, bumped) = match merge_version_bumps(&content) {
+            Ok(merged) => merged,
+            Err(reason) => return ResolveOutcome::Declined { reason },
+        };
+        match std::fs::write(&full_path, merged) {
+            Ok(()) => ResolveOutcome::Resolved {
+                summary: format!("merged {bumped} parallel version bump(s) in {}", file.path),
+            },
+            Err(e) => ResolveOutcome::Declined {
+                reason: format!("failed to write resolved {}: {e}", file.path),
+            },
+        }
+    }
+}
+
+/// Byte ranges of the contents of every string literal assigned to a
+/// bare `version` key on `line` — `version = "1.2.3"` at the start of a
+/// line or inside an inline table — but not `rust-version` or
+/// `version.workspace = true`.
+fn version_literal_spans(line: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut search_from = 0;
+    while let Some(offset) = line[search_from..].find("version") {
+        let key_start = search_from + offset;
+        let key_end = key_start + "version".len();
+        search_from = key_end;
+
+        let preceded_ok = line[..key_start]
+            .chars()
+            .next_back()
+            .is_none_or(|c| c.is_whitespace() || c == '{' || c == ',');
+        if !preceded_ok {
+            continue;
+        }
+        let rest = line[key_end..].trim_start();
+        let Some(rest) = rest.strip_prefix('=') else {
+            continue;
+        };
+        let rest = rest.trim_start();
+        let Some(literal) = rest.strip_prefix('"') else {
+            continue;
+        };
+        let Some(len) = literal.find('"') else {
+            continue;
+        };
+        let start = line.len() - literal.len();
+        spans.push((start, start + len));
+        search_from = start + len;
+    }
+    spans
+}
+
+fn mask_versions(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            let mut masked = String::new();
+            let mut cursor = 0;
+            for (start, end) in version_literal_spans(line) {
+                masked.push_str(&line[cursor..start]);
+                masked.push_str(MASK);
+                cursor = end;
+            }
+            masked.push_str(&line[cursor..]);
+            masked
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn literals(line: &str) -> Vec<&str> {
+    version_literal_spans(line)
+        .into_iter()
+        .map(|(start, end)| &line[start..end])
+        .collect()
+}
+
+/// Splits a Cargo version requirement into its operator prefix and its
+/// `major.minor.patch[-pre]` components (build metadata ignored).
+fn parse_version(literal: &str) -> Option<(&str, Vec<u64>, Option<&str>)> {
+    let numeric_start = literal.find(|c: char| c.is_ascii_digit())?;
+    let (operator, version) = literal.split_at(numeric_start);
+    if !operator.chars().all(|c| matches!(c, '^' | '~' | '=' | '<' | '>' | ' ')) {
+        return None;
+    }
+    let version = version.split('+').next()?;
+    let (release, pre) = match version.split_once('-') {
+        Some((release, pre)) => (release, Some(pre)),
+        None => (version, None),
+    };
+    let components = release
+        .split('.')
+        .map(|part| part.parse::<u64>().ok())
+        .collect::<Option<Vec<_>>>()?;
+    Some((operator.trim(), components, pre))
+}
+
+fn compare_versions(a: &str, b: &str) -> Result<Ordering, String> {
+    let (op_a, mut nums_a, pre_a) = parse_version(a).ok_or_else(|| format!("{a:?} is not a version"))?;
+    let (op_b, mut nums_b, pre_b) = parse_version(b).ok_or_else(|| format!("{b:?} is not a version"))?;
+    if op_a != op_b {
+        return Err(format!("version requirements {a:?} and {b:?} use different operators"));
+    }
+    let width = nums_a.len().max(nums_b.len());
+    nums_a.resize(width, 0);
+    nums_b.resize(width, 0);
+    // A release outranks any prerelease of the same numbers.
+    let ordering = nums_a.cmp(&nums_b).then_with(|| match (pre_a, pre_b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a), Some(b)) => a.cmp(b),
+    });
+    Ok(ordering)
+}
+
+/// Returns the merged manifest and how many literals needed a decision,
+/// or the reason the conflict isn't version-only.
+fn merge_version_bumps(content: &str) -> Result<(String, usize), String> {
+    let sides = split_sides(content)?;
+    if mask_versions(&sides.ours) != mask_versions(&sides.theirs) {
+        return Err("conflict is not version-only: the sides differ outside `version` literals".to_owned());
+    }
+    // The base only helps if it lines up with the sides structurally.
+    let base = sides
+        .base
+        .as_deref()
+        .filter(|base| mask_versions(base) == mask_versions(&sides.ours));
+    let base_lines: Option<Vec<&str>> = base.map(|base| base.lines().collect());
+
+    let mut bumped = 0;
+    let mut out = Vec::new();
+    for (index, (ours, theirs)) in sides.ours.lines().zip(sides.theirs.lines()).enumerate() {
+        if ours == theirs {
+            out.push(ours.to_owned());
+            continue;
+        }
+        let base_literals = base_lines.as_ref().map(|lines| literals(lines[index]));
+        let mut line = String::new();
+        let mut cursor = 0;
+        for (position, ((start, end), theirs_literal)) in version_literal_spans(ours)
+            .into_iter()
+            .zip(literals(theirs))
+            .enumerate()
+        {
+            let ours_literal = &ours[start..end];
+            let base_literal = base_literals.as_ref().map(|literals| literals[position]);
+            let chosen = if ours_literal == theirs_literal || base_literal == Some(theirs_literal) {
+                ours_literal
+            } else if base_literal == Some(ours_literal) {
+                theirs_literal
+            } else {
+                match compare_versions(ours_literal, theirs_literal)? {
+                    Ordering::Less => theirs_literal,
+                    _ => ours_literal,
+                }
+            };
+            if ours_literal != theirs_literal {
+                bumped += 1;
+            }
+            line.push_str(&ours[cursor..start]);
+            line.push_str(chosen);
+            cursor = end;
+        }
+        line.push_str(&ours[cursor..]);
+        out.push(line);
+    }
+
+    let mut merged = out.join("\n");
+    if sides.ours.ends_with('\n') {
+        merged.push('\n');
+    }
+    ParseCheck::Toml
+        .check(&merged)
+        .map_err(|e| format!("merged Cargo.toml does not verify: {e}"))?;
+    Ok((merged, bumped))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(path: &str) -> ConflictedFile {
+        ConflictedFile {
+            path: path.to_owned(),
+            marker_count: Some(1),
+            shape: "content".to_owned(),
+        }
+    }
+
+    #[test]
+    fn version_spans_skip_rust_version_and_workspace_inheritance() {
+        assert_eq!(literals("version = \"0.3.1\""), vec!["0.3.1"]);
+        assert_eq!(
+            literals("boss-x = { path = \"x\", version = \"=0.3.1\" }"),
+            vec!["=0.3.1"]
+        );
+        assert!(literals("rust-version = \"1.85\"").is_empty());
+        assert!(literals("version.workspace = true").is_empty());
+    }
+
+    #[test]
+    fn compare_versions_orders_releases_and_prereleases() {
+        assert_eq!(compare_versions("0.3.1", "0.4.0"), Ok(Ordering::Less));
+        assert_eq!(compare_versions("1.0.0", "1.0.0-rc.1"), Ok(Ordering::Greater));
+        assert_eq!(compare_versions("=0.10.0", "=0.9.9"), Ok(Ordering::Greater));
+        assert!(compare_versions("^0.3", "=0.4").is_err());
+        assert!(compare_versions("0.3.x", "0.4.0").is_err());
+    }
+
+    #[tokio::test]
+    async fn parallel_bumps_take_the_higher_version_and_one_sided_pins_win() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("Cargo.toml"),
+            "\
+[workspace.package]
+<<<<<<< Conflict 1 of 2
+%%%%%%% Changes from base to side #1
+-version = \"0.3.0\"
++version = \"0.3.1\"
++++++++ Contents of side #2
+version = \"0.4.0\"
+>>>>>>> Conflict 1 of 2 ends
+edition = \"2024\"
+
+[workspace.dependencies]
+<<<<<<< Conflict 2 of 2
+%%%%%%% Changes from base to side #1
+ boss-a = { path = \"a\", version = \"=0.3.0\" }
+-boss-b = { path = \"b\", version = \"=0.3.0\" }
++boss-b = { path = \"b\", version = \"=0.3.1\" }
++++++++ Contents of side #2
+boss-a = { path = \"a\", version = \"=0.4.0\" }
+boss-b = { path = \"b\", version = \"=0.3.0\" }
+>>>>>>> Conflict 2 of 2 ends
+",
+        )
+        .unwrap();
+
+        let outcome = CargoWorkspaceVersionResolver::new()
+            .resolve(dir.path(), &file("Cargo.toml"))
+            .await;
+
+        assert!(matches!(outcome, ResolveOutcome::Resolved { .. }), "got {outcome:?}");
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("Cargo.toml")).unwrap(),
+            "\
+[workspace.package]
+version = \"0.4.0\"
+edition = \"2024\"
+
+[workspace.dependencies]
+boss-a = { path = \"a\", version = \"=0.4.0\" }
+boss-b = { path = \"b\", version = \"=0.3.1\" }
+"
+        );
+    }
+
+    #[tokio::test]
+    async fn declines_conflicts_that_are_not_version_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let content = "\
+[package]
+<<<<<<< HEAD
+version = \"0.3.1\"
+=======
+version = \"0.4.0\"
+edition = \"2024\"
+>>>>>>> branch
+";
+        std::fs::write(dir.path().join("Cargo.toml"), content).unwrap();
+
+        let outcome = CargoWorkspaceVersionResolver::new()
+            .resolve(dir.path(), &file("Cargo.toml"))
+            .await;
+
+        assert!(matches!(outcome, ResolveOutcome::Declined { reason } if reason.contains("not version-only")));
+        assert_eq!(std::fs::read_to_string(dir.path().join("Cargo.toml")).unwrap(), content);
+    }
+
+    #[test]
+    fn is_a_manifest_resolver() {
+        let resolver = CargoWorkspaceVersionResolver::new();
+        assert!(resolver.edits_manifest());
+        assert!(resolver.applies_to(&file("crates/x/Cargo.toml")));
+        assert!(!resolver.applies_to(&file("Cargo.lock")));
+    }
+}
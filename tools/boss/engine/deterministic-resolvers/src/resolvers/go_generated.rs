@@ -0,0 +1,239 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::command::{CommandRunner, run_or_fail};
+use crate::lockfile::verify_file;
+use crate::markers::split_sides;
+use crate::sandbox::{SandboxPolicy, SandboxedCommandRunner};
+use crate::verify::ParseCheck;
+use crate::{ConflictClass, ConflictedFile, DeterministicResolver, ResolveOutcome};
+
+/// Basename suffixes of the Go generators we see checked in (protoc,
+/// grpc-gateway, stringer, mockgen and the `*_gen.go` convention).
+const GENERATED_SUFFIXES: &[&str] = &[
+    ".pb.go",
+    ".pb.gw.go",
+    ".gen.go",
+    "_gen.go",
+    "_generated.go",
+    "_string.go",
+    "_mock.go",
+];
+/// Basename prefixes: Kubernetes' deepcopy/conversion gens and mockgen.
+const GENERATED_PREFIXES: &[&str] = &["zz_generated", "mock_"];
+
+/// Regenerates checked-in Go generated code with `go generate` in the
+/// file's package directory, inside a [`SandboxedCommandRunner`].
+///
+/// `applies_to` only sees a path, so it matches on the generator naming
+/// conventions above; `resolve` then requires *both* sides of the
+/// conflict to carry Go's standard `// Code generated ... DO NOT EDIT.`
+/// header before discarding anything, so a hand-written file that merely
+/// looks generated is declined untouched. After `go generate` the file
+/// must exist again, still carry the header, and be marker-free.
+///
+/// `go generate` runs every directive in the package, so sibling
+/// generated files are rewritten too; generators are deterministic, so
+/// those rewrites are no-ops unless the merged inputs changed them — in
+/// which case they are exactly the regeneration the merge needed.
+pub struct GoGeneratedCodeResolver {
+    runner: Arc<dyn CommandRunner>,
+}
+
+impl GoGeneratedCodeResolver {
+    pub fn new() -> Self {
+        Self {
+            // Generators are often `go run`s of module tools, which must
+            // download into the sandbox's cold module cache.
+            runner: Arc::new(SandboxedCommandRunner::new(SandboxPolicy::package_manager())),
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn with_runner(runner: Arc<dyn CommandRunner>) -> Self {
+        Self { runner }
+    }
+}
+
+impl Default for GoGeneratedCodeResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Go's generated-file convention (`go help generate`): a line matching
+/// `^// Code generated .* DO NOT EDIT\.$`.
+fn has_generated_header(content: &str) -> bool {
+    content
+        .lines()
+        .any(|line| line.starts_with("// Code generated ") && line.ends_with(" DO NOT EDIT."))
+}
+
+#[async_trait]
+impl DeterministicResolver for GoGeneratedCodeResolver {
+    fn class(&self) -> ConflictClass {
+        ConflictClass::GeneratedCode
+    }
+
+    fn applies_to(&self, file: &ConflictedFile) -> bool {
+        let Some(name) = Path::new(&file.path).file_name().and_then(|name| name.to_str()) else {
+            return false;
+        };
+        name.ends_with(".go")
+            && (GENERATED_SUFFIXES.iter().any(|suffix| name.ends_with(suffix))
+                || GENERATED_PREFIXES.iter().any(|prefix| name.starts_with(prefix)))
+    }
+
+    async fn resolve(&self, workspace_path: &Path, file: &ConflictedFile) -> ResolveOutcome {
+        let full_path = workspace_path.join(&file.path);
+        let Some(dir) = full_path.parent() else {
+            return ResolveOutcome::Declined {
+                reason: format!("{} has no parent directory", file.path),
+            };
+        };
+        let content = match std::fs::read_to_string(&full_path) {
+            Ok(content) => content,
+            Err(e) => {
+                return ResolveOutcome::Declined {
+                    reason: format!("failed to read {}: {e}", file.path),
+                };
+            }
+        };
+        let sides = match split_sides(&content) {
+            Ok(sides) => sides,
+            Err(reason) => return ResolveOutcome::Declined { reason },
+        };
+        if !has_generated_header(&sides.ours) || !has_generated_header(&sides.theirs) {
+            return ResolveOutcome::Declined {
+                reason: format!(
+                    "{} lacks a `// Code generated ... DO NOT EDIT.` header on at least one side",
+                    file.path
+                ),
+            };
+        }
+
+        if let Err(e) = std::fs::remove_file(&full_path) {
+            return ResolveOutcome::Declined {
+                reason: format!("failed to remove conflicted {}: {e}", file.path),
+            };
+        }
+        if let Err(outcome) = run_or_fail(self.runner.as_ref(), dir, "go", &["generate", "."], "").await {
+            return outcome;
+        }
+        if !full_path.is_file() {
+            return ResolveOutcome::Declined {
+                reason: format!("`go generate .` succeeded but did not regenerate {}", file.path),
+            };
+        }
+        if let Err(reason) = verify_file(workspace_path, file, ParseCheck::NoConflictMarkers) {
+            return ResolveOutcome::Failed { reason };
+        }
+        match std::fs::read_to_string(&full_path) {
+            Ok(regenerated) if has_generated_header(&regenerated) => ResolveOutcome::Resolved {
+                summary: format!("regenerated {} via `go generate .`", file.path),
+            },
+            Ok(_) => ResolveOutcome::Failed {
+                reason: format!("regenerated {} lost its generated-code header", file.path),
+            },
+            Err(e) => ResolveOutcome::Failed {
+                reason: format!("failed to read regenerated {}: {e}", file.path),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::FakeCommandRunner;
+
+    fn file(path: &str) -> ConflictedFile {
+        ConflictedFile {
+            path: path.to_owned(),
+            marker_count: Some(1),
+            shape: "content".to_owned(),
+        }
+    }
+
+    const HEADER: &str = "// Code generated by protoc-gen-go. DO NOT EDIT.";
+
+    fn conflicted_generated(header_on_theirs: bool) -> String {
+        let theirs_header = if header_on_theirs { HEADER } else { "// hand written" };
+        format!(
+            "<<<<<<< HEAD\n{HEADER}\npackage api\nconst A = 1\n=======\n{theirs_header}\npackage api\nconst B = 2\n>>>>>>> branch\n"
+        )
+    }
+
+    #[test]
+    fn applies_to_matches_generator_naming_conventions() {
+        let resolver = GoGeneratedCodeResolver::new();
+        for path in [
+            "api/v1/service.pb.go",
+            "api/v1/service_grpc.pb.go",
+            "pkg/kind_string.go",
+            "pkg/apis/zz_generated.deepcopy.go",
+            "internal/mock_store.go",
+            "gen/schema_gen.go",
+        ] {
+            assert!(resolver.applies_to(&file(path)), "{path} should match");
+        }
+        for path in ["main.go", "service.proto", "pkg/strings.go", "web/schema.gen.ts"] {
+            assert!(!resolver.applies_to(&file(path)), "{path} should not match");
+        }
+    }
+
+    #[tokio::test]
+    async fn declines_without_touching_a_file_missing_the_generated_header() {
+        let dir = tempfile::tempdir().unwrap();
+        let content = conflicted_generated(false);
+        std::fs::write(dir.path().join("kind_string.go"), &content).unwrap();
+
+        let runner = Arc::new(FakeCommandRunner::sequence(Vec::new()));
+        let resolver = GoGeneratedCodeResolver::with_runner(runner.clone());
+        let outcome = resolver.resolve(dir.path(), &file("kind_string.go")).await;
+
+        assert!(matches!(outcome, ResolveOutcome::Declined { reason } if reason.contains("DO NOT EDIT")));
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("kind_string.go")).unwrap(),
+            content
+        );
+        assert!(runner.calls.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn regenerates_in_the_package_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("api")).unwrap();
+        std::fs::write(dir.path().join("api/service.pb.go"), conflicted_generated(true)).unwrap();
+
+        let runner = Arc::new(FakeCommandRunner::success_writing_file(
+            "service.pb.go",
+            &format!("{HEADER}\npackage api\nconst A = 1\nconst B = 2\n"),
+        ));
+        let resolver = GoGeneratedCodeResolver::with_runner(runner.clone());
+        let outcome = resolver.resolve(dir.path(), &file("api/service.pb.go")).await;
+
+        assert!(matches!(outcome, ResolveOutcome::Resolved { .. }), "got {outcome:?}");
+        let calls = runner.calls.lock().unwrap();
+        assert_eq!(calls[0].0, "go");
+        assert_eq!(calls[0].1, vec!["generate", "."]);
+        assert_eq!(calls[0].2, dir.path().join("api"));
+    }
+
+    #[tokio::test]
+    async fn fails_when_regenerated_file_loses_its_header() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("service.pb.go"), conflicted_generated(true)).unwrap();
+
+        let runner = Arc::new(FakeCommandRunner::success_writing_file(
+            "service.pb.go",
+            "package api\n",
+        ));
+        let resolver = GoGeneratedCodeResolver::with_runner(runner);
+        let outcome = resolver.resolve(dir.path(), &file("service.pb.go")).await;
+
+        assert!(matches!(outcome, ResolveOutcome::Failed { reason } if reason.contains("header")));
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use async_trait::async_trait;
+
+use crate::markers::split_sides;
+use crate::union::merge_keyed;
+use crate::verify::ParseCheck;
+use crate::{ConflictClass, ConflictedFile, DeterministicResolver, ResolveOutcome};
+
+/// Resolves `go.sum` by three-way set union of its checksum lines. Each
+/// line is an independent `<module> <version>[/go.mod] <hash>` fact, and
+/// `go` tolerates a superset (it only ever adds or checks lines), so a
+/// line either side added survives and a line a side deliberately
+/// dropped from the base stays dropped. The result is sorted (`go`
+/// re-sorts on its next write anyway) and must pass
+/// [`ParseCheck::GoSum`].
+///
+/// No regeneration fallback: the only way to rebuild `go.sum` from
+/// scratch is `go mod tidy`, which also rewrites `go.mod` — not a
+/// single-file resolution. A `go.sum` whose markers can't be split is
+/// declined instead, as is a union in which one module version ends up
+/// with two different hashes — that is a checksum dispute for a human,
+/// not a formula.
+pub struct GoSumResolver;
+
+impl GoSumResolver {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for GoSumResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl DeterministicResolver for GoSumResolver {
+    fn class(&self) -> ConflictClass {
+        ConflictClass::GoSum
+    }
+
+    fn applies_to(&self, file: &ConflictedFile) -> bool {
+        Path::new(&file.path).file_name().and_then(|name| name.to_str()) == Some("go.sum")
+    }
+
+    async fn resolve(&self, workspace_path: &Path, file: &ConflictedFile) -> ResolveOutcome {
+        let full_path = workspace_path.join(&file.path);
+        let content = match std::fs::read_to_string(&full_path) {
+            Ok(content) => content,
+            Err(e) => {
+                return ResolveOutcome::Declined {
+                    reason: format!("failed to read {}: {e}", file.path),
+                };
+            }
+        };
+
+        let merged = match union_go_sum(&content) {
+            Ok(merged) => merged,
+            Err(reason) => return ResolveOutcome::Declined { reason },
+        };
+        let line_count = merged.lines().count();
+        match std::fs::write(&full_path, merged) {
+            Ok(()) => ResolveOutcome::Resolved {
+                summary: format!("unioned {line_count} go.sum lines in {}", file.path),
+            },
+            Err(e) => ResolveOutcome::Declined {
+                reason: format!("failed to write resolved {}: {e}", file.path),
+            },
+        }
+    }
+}
+
+fn go_sum_lines(content: &str) -> BTreeMap<String, ()> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| (line.to_owned(), ()))
+        .collect()
+}
+
+fn union_go_sum(content: &str) -> Result<String, String> {
+    let sides = split_sides(content)?;
+    let base = sides.base.as_deref().map(go_sum_lines);
+    let merged = merge_keyed(base.as_ref(), &go_sum_lines(&sides.ours), &go_sum_lines(&sides.theirs))
+        .map_err(|line| format!("go.sum line {line:?} could not be merged"))?;
+
+    let mut out: String = merged.into_keys().map(|line| line + "\n").collect();
+    if out.is_empty() {
+        out.push('\n');
+    }
+    ParseCheck::GoSum
+        .check(&out)
+        .map_err(|e| format!("unioned go.sum does not verify: {e}"))?;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(path: &str) -> ConflictedFile {
+        ConflictedFile {
+            path: path.to_owned(),
+            marker_count: Some(1),
+            shape: "content".to_owned(),
+        }
+    }
+
+    #[test]
+    fn applies_to_matches_go_sum_by_basename() {
+        let resolver = GoSumResolver::new();
+        assert!(resolver.applies_to(&file("go.sum")));
+        assert!(resolver.applies_to(&file("services/api/go.sum")));
+        assert!(!resolver.applies_to(&file("go.mod")));
+        assert!(!resolver.applies_to(&file("go.work.sum")));
+    }
+
+    #[tokio::test]
+    async fn unions_additions_and_honours_one_sided_removals() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("go.sum"),
+            "\
+github.com/a/a v1.0.0 h1:aaa=
+<<<<<<< Conflict 1 of 1
+%%%%%%% Changes from base to side #1
+-github.com/old/old v0.1.0 h1:old=
++github.com/b/b v1.2.0 h1:bbb=
++github.com/b/b v1.2.0/go.mod h1:bbm=
++++++++ Contents of side #2
+github.com/old/old v0.1.0 h1:old=
+github.com/c/c v0.3.0 h1:ccc=
+>>>>>>> Conflict 1 of 1 ends
+",
+        )
+        .unwrap();
+
+        let outcome = GoSumResolver::new().resolve(dir.path(), &file("go.sum")).await;
+
+        assert!(matches!(outcome, ResolveOutcome::Resolved { .. }), "got {outcome:?}");
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("go.sum")).unwrap(),
+            "\
+github.com/a/a v1.0.0 h1:aaa=
+github.com/b/b v1.2.0 h1:bbb=
+github.com/b/b v1.2.0/go.mod h1:bbm=
+github.com/c/c v0.3.0 h1:ccc=
+"
+        );
+    }
+
+    #[tokio::test]
+    async fn declines_malformed_union_and_leaves_file_untouched() {
+        let dir = tempfile::tempdir().unwrap();
+        let content = "<<<<<<< HEAD\nnot a checksum line\n=======\ngithub.com/c/c v0.3.0 h1:ccc=\n>>>>>>> x\n";
+        std::fs::write(dir.path().join("go.sum"), content).unwrap();
+
+        let outcome = GoSumResolver::new().resolve(dir.path(), &file("go.sum")).await;
+
+        assert!(matches!(outcome, ResolveOutcome::Declined { reason } if reason.contains("malformed")));
+        assert_eq!(std::fs::read_to_string(dir.path().join("go.sum")).unwrap(), content);
+    }
+}
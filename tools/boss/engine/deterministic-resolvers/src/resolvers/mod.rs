@@ -1,9 +1,24 @@
 mod bazel_module_lock;
 mod cargo_lock;
+mod cargo_workspace_version;
+mod go_generated;
+mod go_sum;
+mod npm_lock;
+mod pnpm_lock;
 mod recipe;
+mod regenerate_command;
 mod registry_append_union;
+mod yarn_lock;
 
 pub use bazel_module_lock::BazelModuleLockResolver;
 pub use cargo_lock::CargoLockResolver;
+pub use cargo_workspace_version::CargoWorkspaceVersionResolver;
+pub use go_generated::GoGeneratedCodeResolver;
+pub use go_sum::GoSumResolver;
+pub use npm_lock::NpmLockResolver;
+pub use pnpm_lock::PnpmLockResolver;
 pub use recipe::{InvalidRecipe, RecipeResolver};
+pub use regenerate_command::RegenerateCommandResolver;
 pub use registry_append_union::RegistryAppendUnionResolver;
+pub use yarn_lock::YarnLockResolver;
+pub(crate) use yarn_lock::{parse_yarn_lock, yarn_descriptors};
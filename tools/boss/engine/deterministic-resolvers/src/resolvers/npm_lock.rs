@@ -0,0 +1,116 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::command::CommandRunner;
+use crate::lockfile::regenerate_lockfile_verified;
+use crate::sandbox::{SandboxPolicy, SandboxedCommandRunner};
+use crate::verify::ParseCheck;
+use crate::{ConflictClass, ConflictedFile, DeterministicResolver, ResolveOutcome};
+
+/// Regenerates `package-lock.json` from the (already merged) sibling
+/// `package.json` via `npm install --package-lock-only`, then requires
+/// the result to parse as a lockfile. There is no union path: npm's
+/// nested `packages` tree encodes hoisting decisions that a key-wise
+/// merge of two lockfiles cannot reproduce.
+///
+/// `--ignore-scripts` keeps lifecycle scripts from the PR's manifest from
+/// running at all; `npm` itself runs inside a
+/// [`SandboxedCommandRunner`] with network access (it must resolve
+/// versions against the registry).
+pub struct NpmLockResolver {
+    runner: Arc<dyn CommandRunner>,
+}
+
+impl NpmLockResolver {
+    pub fn new() -> Self {
+        Self {
+            runner: Arc::new(SandboxedCommandRunner::new(SandboxPolicy::package_manager())),
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn with_runner(runner: Arc<dyn CommandRunner>) -> Self {
+        Self { runner }
+    }
+}
+
+impl Default for NpmLockResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl DeterministicResolver for NpmLockResolver {
+    fn class(&self) -> ConflictClass {
+        ConflictClass::NpmLock
+    }
+
+    fn applies_to(&self, file: &ConflictedFile) -> bool {
+        Path::new(&file.path).file_name().and_then(|name| name.to_str()) == Some("package-lock.json")
+    }
+
+    async fn resolve(&self, workspace_path: &Path, file: &ConflictedFile) -> ResolveOutcome {
+        regenerate_lockfile_verified(
+            self.runner.as_ref(),
+            workspace_path,
+            file,
+            "package.json",
+            "npm",
+            &[
+                "install",
+                "--package-lock-only",
+                "--ignore-scripts",
+                "--no-audit",
+                "--no-fund",
+            ],
+            ParseCheck::NpmLock,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::FakeCommandRunner;
+
+    fn file(path: &str) -> ConflictedFile {
+        ConflictedFile {
+            path: path.to_owned(),
+            marker_count: Some(1),
+            shape: "content".to_owned(),
+        }
+    }
+
+    #[test]
+    fn applies_to_matches_package_lock_by_basename() {
+        let resolver = NpmLockResolver::new();
+        assert!(resolver.applies_to(&file("package-lock.json")));
+        assert!(resolver.applies_to(&file("web/app/package-lock.json")));
+        assert!(!resolver.applies_to(&file("package.json")));
+        assert!(!resolver.applies_to(&file("yarn.lock")));
+    }
+
+    #[tokio::test]
+    async fn resolve_regenerates_with_scripts_disabled() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("package.json"), "{\"name\": \"x\"}").unwrap();
+
+        let runner = Arc::new(FakeCommandRunner::success_writing_file(
+            "package-lock.json",
+            "{\"name\": \"x\", \"lockfileVersion\": 3, \"packages\": {}}",
+        ));
+        let resolver = NpmLockResolver::with_runner(runner.clone());
+
+        let outcome = resolver.resolve(dir.path(), &file("package-lock.json")).await;
+
+        assert!(matches!(outcome, ResolveOutcome::Resolved { .. }), "got {outcome:?}");
+        let calls = runner.calls.lock().unwrap();
+        assert_eq!(calls[0].0, "npm");
+        assert!(calls[0].1.contains(&"--package-lock-only".to_owned()));
+        assert!(calls[0].1.contains(&"--ignore-scripts".to_owned()));
+    }
+}
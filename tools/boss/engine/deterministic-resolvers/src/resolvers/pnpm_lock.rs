@@ -0,0 +1,108 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::command::CommandRunner;
+use crate::lockfile::regenerate_lockfile_verified;
+use crate::sandbox::{SandboxPolicy, SandboxedCommandRunner};
+use crate::verify::ParseCheck;
+use crate::{ConflictClass, ConflictedFile, DeterministicResolver, ResolveOutcome};
+
+/// Regenerates `pnpm-lock.yaml` from the (already merged) sibling
+/// `package.json` via `pnpm install --lockfile-only`, then requires the
+/// result to parse as a lockfile — the pnpm analogue of
+/// [`crate::NpmLockResolver`]. In a pnpm workspace the lockfile sits next
+/// to the root `package.json`, so the sibling-manifest precondition holds
+/// there too; pnpm reads `pnpm-workspace.yaml` itself.
+pub struct PnpmLockResolver {
+    runner: Arc<dyn CommandRunner>,
+}
+
+impl PnpmLockResolver {
+    pub fn new() -> Self {
+        Self {
+            runner: Arc::new(SandboxedCommandRunner::new(SandboxPolicy::package_manager())),
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn with_runner(runner: Arc<dyn CommandRunner>) -> Self {
+        Self { runner }
+    }
+}
+
+impl Default for PnpmLockResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl DeterministicResolver for PnpmLockResolver {
+    fn class(&self) -> ConflictClass {
+        ConflictClass::PnpmLock
+    }
+
+    fn applies_to(&self, file: &ConflictedFile) -> bool {
+        Path::new(&file.path).file_name().and_then(|name| name.to_str()) == Some("pnpm-lock.yaml")
+    }
+
+    async fn resolve(&self, workspace_path: &Path, file: &ConflictedFile) -> ResolveOutcome {
+        regenerate_lockfile_verified(
+            self.runner.as_ref(),
+            workspace_path,
+            file,
+            "package.json",
+            "pnpm",
+            &["install", "--lockfile-only", "--ignore-scripts"],
+            ParseCheck::PnpmLock,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::FakeCommandRunner;
+
+    fn file(path: &str) -> ConflictedFile {
+        ConflictedFile {
+            path: path.to_owned(),
+            marker_count: Some(1),
+            shape: "content".to_owned(),
+        }
+    }
+
+    #[test]
+    fn applies_to_matches_pnpm_lock_by_basename() {
+        let resolver = PnpmLockResolver::new();
+        assert!(resolver.applies_to(&file("pnpm-lock.yaml")));
+        assert!(resolver.applies_to(&file("frontend/pnpm-lock.yaml")));
+        assert!(!resolver.applies_to(&file("pnpm-workspace.yaml")));
+    }
+
+    #[tokio::test]
+    async fn fails_when_regenerated_lockfile_is_not_a_pnpm_lockfile() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("package.json"), "{}").unwrap();
+
+        let runner = Arc::new(FakeCommandRunner::success_writing_file(
+            "pnpm-lock.yaml",
+            "importers: {}\n",
+        ));
+        let resolver = PnpmLockResolver::with_runner(runner.clone());
+
+        let outcome = resolver.resolve(dir.path(), &file("pnpm-lock.yaml")).await;
+
+        match outcome {
+            ResolveOutcome::Failed { reason } => assert!(reason.contains("lockfileVersion"), "reason was: {reason}"),
+            other => panic!("expected Failed, got {other:?}"),
+        }
+        assert_eq!(
+            runner.calls.lock().unwrap()[0].1,
+            vec!["install", "--lockfile-only", "--ignore-scripts"]
+        );
+    }
+}
@@ -51,21 +51,7 @@ impl RecipeResolver {
     /// `ConflictRecipe` by hand still gets a clear error instead of a
     /// panic.
     pub fn from_recipe(recipe: ConflictRecipe) -> Result<Self, InvalidRecipe> {
-        if recipe.resolve_command.is_empty() {
-            return Err(InvalidRecipe(format!(
-                "recipe {:?}: resolve_command must not be empty",
-                recipe.name
-            )));
-        }
-        if recipe.verify_command.as_ref().is_some_and(Vec::is_empty) {
-            return Err(InvalidRecipe(format!(
-                "recipe {:?}: verify_command must not be empty when set",
-                recipe.name
-            )));
-        }
-        let matcher = globset::Glob::new(&recipe.glob)
-            .map_err(|e| InvalidRecipe(format!("recipe {:?}: invalid glob {:?}: {e}", recipe.name, recipe.glob)))?
-            .compile_matcher();
+        let matcher = compile_recipe(&recipe)?;
         Ok(Self {
             recipe,
             matcher,
@@ -81,6 +67,26 @@ impl RecipeResolver {
     }
 }
 
+/// Validates `recipe` and compiles its glob — shared with
+/// [`crate::RegenerateCommandResolver`], which takes the same recipe shape.
+pub(crate) fn compile_recipe(recipe: &ConflictRecipe) -> Result<GlobMatcher, InvalidRecipe> {
+    if recipe.resolve_command.is_empty() {
+        return Err(InvalidRecipe(format!(
+            "recipe {:?}: resolve_command must not be empty",
+            recipe.name
+        )));
+    }
+    if recipe.verify_command.as_ref().is_some_and(Vec::is_empty) {
+        return Err(InvalidRecipe(format!(
+            "recipe {:?}: verify_command must not be empty when set",
+            recipe.name
+        )));
+    }
+    globset::Glob::new(&recipe.glob)
+        .map(|glob| glob.compile_matcher())
+        .map_err(|e| InvalidRecipe(format!("recipe {:?}: invalid glob {:?}: {e}", recipe.name, recipe.glob)))
+}
+
 #[async_trait]
 impl DeterministicResolver for RecipeResolver {
     fn class(&self) -> ConflictClass {
@@ -113,7 +119,7 @@ impl DeterministicResolver for RecipeResolver {
 /// relative to the workspace root (needed for recipes whose command
 /// must run at the workspace root, e.g. a top-level `make
 /// regen-schema`).
-async fn resolve_recipe(
+pub(crate) async fn resolve_recipe(
     runner: &dyn CommandRunner,
     workspace_path: &Path,
     file: &ConflictedFile,
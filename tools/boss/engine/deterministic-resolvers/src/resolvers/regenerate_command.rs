@@ -0,0 +1,198 @@
+//! [`RegenerateCommandResolver`] — the generic "regenerate via command"
+//! class: discard the conflicted file, run a generator, verify the output
+//! parses. It takes the same [`ConflictRecipe`] shape as
+//! [`crate::RecipeResolver`] (glob, `resolve_command`, optional
+//! `verify_command`, optional `workdir`) but differs in two ways that
+//! make it fit for commands a repo's own build defines (`buf generate`,
+//! `make regen-schema`, an `openapi-generator` invocation):
+//!
+//! - every command runs inside a [`SandboxedCommandRunner`] under the
+//!   caller's [`SandboxPolicy`], so the generator sees a scrubbed
+//!   environment and can write only beneath its working directory;
+//! - the regenerated file must pass a [`ParseCheck`] before the
+//!   resolution counts, on top of any `verify_command`.
+//!
+//! Its telemetry class is [`ConflictClass::RegenerateCommand`]; the
+//! summary names the recipe so per-generator attribution survives.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use globset::GlobMatcher;
+
+use crate::command::CommandRunner;
+use crate::lockfile::verify_file;
+use crate::recipe_config::ConflictRecipe;
+use crate::resolvers::recipe::{InvalidRecipe, compile_recipe, resolve_recipe};
+use crate::sandbox::{SandboxPolicy, SandboxedCommandRunner};
+use crate::verify::ParseCheck;
+use crate::{ConflictClass, ConflictedFile, DeterministicResolver, ResolveOutcome};
+
+pub struct RegenerateCommandResolver {
+    recipe: ConflictRecipe,
+    matcher: GlobMatcher,
+    check: ParseCheck,
+    runner: Arc<dyn CommandRunner>,
+}
+
+impl RegenerateCommandResolver {
+    /// Compile `recipe` into a sandboxed resolver whose output must pass
+    /// `check`. Fails on the same invalid recipes
+    /// [`crate::RecipeResolver::from_recipe`] rejects.
+    pub fn new(recipe: ConflictRecipe, check: ParseCheck, policy: SandboxPolicy) -> Result<Self, InvalidRecipe> {
+        let matcher = compile_recipe(&recipe)?;
+        Ok(Self {
+            recipe,
+            matcher,
+            check,
+            runner: Arc::new(SandboxedCommandRunner::new(policy)),
+        })
+    }
+
+    #[cfg(test)]
+    pub(crate) fn with_runner(recipe: ConflictRecipe, check: ParseCheck, runner: Arc<dyn CommandRunner>) -> Self {
+        let mut resolver = Self::new(recipe, check, SandboxPolicy::offline()).expect("test recipe must be valid");
+        resolver.runner = runner;
+        resolver
+    }
+}
+
+#[async_trait]
+impl DeterministicResolver for RegenerateCommandResolver {
+    fn class(&self) -> ConflictClass {
+        ConflictClass::RegenerateCommand
+    }
+
+    fn applies_to(&self, file: &ConflictedFile) -> bool {
+        self.matcher.is_match(&file.path)
+    }
+
+    async fn resolve(&self, workspace_path: &Path, file: &ConflictedFile) -> ResolveOutcome {
+        let outcome = resolve_recipe(
+            self.runner.as_ref(),
+            workspace_path,
+            file,
+            &self.recipe.name,
+            &self.recipe.resolve_command,
+            self.recipe.verify_command.as_deref(),
+            self.recipe.workdir.as_deref(),
+        )
+        .await;
+        if !matches!(outcome, ResolveOutcome::Resolved { .. }) {
+            return outcome;
+        }
+        // `verify_command` may pass without the file existing (it checks
+        // something else); the parse check needs the file itself.
+        if let Err(reason) = verify_file(workspace_path, file, self.check) {
+            return ResolveOutcome::Failed {
+                reason: format!("recipe {:?}: {reason}", self.recipe.name),
+            };
+        }
+        ResolveOutcome::Resolved {
+            summary: format!(
+                "recipe {:?} regenerated {} via sandboxed `{}`",
+                self.recipe.name,
+                file.path,
+                self.recipe.resolve_command.join(" ")
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::FakeCommandRunner;
+
+    fn file(path: &str) -> ConflictedFile {
+        ConflictedFile {
+            path: path.to_owned(),
+            marker_count: Some(1),
+            shape: "content".to_owned(),
+        }
+    }
+
+    fn recipe() -> ConflictRecipe {
+        ConflictRecipe {
+            name: "openapi".to_owned(),
+            glob: "**/openapi.gen.json".to_owned(),
+            resolve_command: vec!["make".to_owned(), "openapi".to_owned()],
+            verify_command: None,
+            workdir: None,
+        }
+    }
+
+    #[test]
+    fn new_rejects_invalid_recipes() {
+        let mut bad = recipe();
+        bad.resolve_command.clear();
+        assert!(RegenerateCommandResolver::new(bad, ParseCheck::Json, SandboxPolicy::offline()).is_err());
+    }
+
+    #[tokio::test]
+    async fn resolves_when_the_regenerated_file_parses() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("api")).unwrap();
+        std::fs::write(dir.path().join("api/openapi.gen.json"), "<<<<<<< a\n").unwrap();
+
+        let runner = Arc::new(FakeCommandRunner::success_writing_file(
+            "openapi.gen.json",
+            "{\"openapi\": \"3.1.0\"}",
+        ));
+        let resolver = RegenerateCommandResolver::with_runner(recipe(), ParseCheck::Json, runner.clone());
+        assert!(resolver.applies_to(&file("api/openapi.gen.json")));
+
+        let outcome = resolver.resolve(dir.path(), &file("api/openapi.gen.json")).await;
+
+        match outcome {
+            ResolveOutcome::Resolved { summary } => assert!(summary.contains("\"openapi\""), "summary was: {summary}"),
+            other => panic!("expected Resolved, got {other:?}"),
+        }
+        assert_eq!(runner.calls.lock().unwrap()[0].2, dir.path().join("api"));
+    }
+
+    #[tokio::test]
+    async fn fails_when_the_regenerated_file_does_not_parse() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("openapi.gen.json"), "<<<<<<< a\n").unwrap();
+
+        let runner = Arc::new(FakeCommandRunner::success_writing_file(
+            "openapi.gen.json",
+            "{\"openapi\": ",
+        ));
+        let resolver = RegenerateCommandResolver::with_runner(recipe(), ParseCheck::Json, runner);
+
+        let outcome = resolver.resolve(dir.path(), &file("openapi.gen.json")).await;
+
+        match outcome {
+            ResolveOutcome::Failed { reason } => {
+                assert!(
+                    reason.contains("openapi.gen.json does not parse"),
+                    "reason was: {reason}"
+                )
+            }
+            other => panic!("expected Failed, got {other:?}"),
+        }
+    }
+
+    /// The real sandbox end to end: a shell generator writes the file in
+    /// its working directory and the JSON check accepts it.
+    #[tokio::test]
+    async fn real_sandboxed_command_regenerates_the_file() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("openapi.gen.json"), "<<<<<<< a\n").unwrap();
+
+        let mut recipe = recipe();
+        recipe.resolve_command = vec![
+            "/bin/sh".to_owned(),
+            "-c".to_owned(),
+            "printf '{\"openapi\": \"3.1.0\"}' > openapi.gen.json".to_owned(),
+        ];
+        let resolver = RegenerateCommandResolver::new(recipe, ParseCheck::Json, SandboxPolicy::offline()).unwrap();
+
+        let outcome = resolver.resolve(dir.path(), &file("openapi.gen.json")).await;
+
+        assert!(matches!(outcome, ResolveOutcome::Resolved { .. }), "got {outcome:?}");
+    }
+}
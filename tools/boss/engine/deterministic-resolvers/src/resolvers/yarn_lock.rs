@@ -0,0 +1,366 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::command::CommandRunner;
+use crate::lockfile::regenerate_lockfile_verified;
+use crate::markers::split_sides;
+use crate::sandbox::{SandboxPolicy, SandboxedCommandRunner};
+use crate::union::merge_keyed;
+use crate::verify::ParseCheck;
+use crate::{ConflictClass, ConflictedFile, DeterministicResolver, ResolveOutcome};
+
+/// Berry's metadata block, which always leads the file.
+const BERRY_METADATA: &str = "__metadata";
+
+/// Resolves `yarn.lock` (classic v1 and berry) union-first: when both
+/// sides only added, removed or changed *different* entries, the three
+/// sides' entries merge key-wise (see [`crate::union`]) with no
+/// toolchain and no network. When that isn't possible — both sides
+/// re-resolved the same descriptor differently, the markers can't be
+/// split, or the union leaves one descriptor in two entries — it falls
+/// back to regenerating from the merged sibling `package.json` with
+/// `yarn` inside a [`SandboxedCommandRunner`], lifecycle scripts off.
+/// Either way the written file must pass [`ParseCheck::YarnLock`].
+pub struct YarnLockResolver {
+    runner: Arc<dyn CommandRunner>,
+}
+
+impl YarnLockResolver {
+    pub fn new() -> Self {
+        Self {
+            runner: Arc::new(SandboxedCommandRunner::new(sandbox_policy())),
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn with_runner(runner: Arc<dyn CommandRunner>) -> Self {
+        Self { runner }
+    }
+}
+
+/// [`SandboxPolicy::package_manager`] with lifecycle scripts disabled.
+/// Classic also gets `--ignore-scripts`; berry's `install` has no such
+/// flag, so `YARN_ENABLE_SCRIPTS` is its only off switch.
+fn sandbox_policy() -> SandboxPolicy {
+    SandboxPolicy {
+        env_overrides: vec![("YARN_ENABLE_SCRIPTS".to_owned(), "0".to_owned())],
+        ..SandboxPolicy::package_manager()
+    }
+}
+
+impl Default for YarnLockResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl DeterministicResolver for YarnLockResolver {
+    fn class(&self) -> ConflictClass {
+        ConflictClass::YarnLock
+    }
+
+    fn applies_to(&self, file: &ConflictedFile) -> bool {
+        Path::new(&file.path).file_name().and_then(|name| name.to_str()) == Some("yarn.lock")
+    }
+
+    async fn resolve(&self, workspace_path: &Path, file: &ConflictedFile) -> ResolveOutcome {
+        let full_path = workspace_path.join(&file.path);
+        let union_declined = match std::fs::read_to_string(&full_path) {
+            Ok(content) => match union_yarn_lock(&content) {
+                Ok((merged, entry_count)) => {
+                    return match std::fs::write(&full_path, merged) {
+                        Ok(()) => ResolveOutcome::Resolved {
+                            summary: format!("unioned {entry_count} yarn.lock entries in {}", file.path),
+                        },
+                        Err(e) => ResolveOutcome::Declined {
+                            reason: format!("failed to write resolved {}: {e}", file.path),
+                        },
+                    };
+                }
+                Err(reason) => reason,
+            },
+            Err(e) => format!("failed to read {}: {e}", file.path),
+        };
+
+        // Berry projects carry a `.yarnrc.yml`; its install has a
+        // lockfile-only mode. Classic has none, so it does a full
+        // (script-free) install, confined to the project directory.
+        let is_berry = full_path.parent().is_some_and(|dir| dir.join(".yarnrc.yml").is_file());
+        let args: &[&str] = if is_berry {
+            &["install", "--mode=update-lockfile"]
+        } else {
+            &["install", "--ignore-scripts", "--non-interactive", "--no-progress"]
+        };
+        match regenerate_lockfile_verified(
+            self.runner.as_ref(),
+            workspace_path,
+            file,
+            "package.json",
+            "yarn",
+            args,
+            ParseCheck::YarnLock,
+        )
+        .await
+        {
+            ResolveOutcome::Resolved { summary } => ResolveOutcome::Resolved {
+                summary: format!("{summary} (union not possible: {union_declined})"),
+            },
+            other => other,
+        }
+    }
+}
+
+/// A parsed `yarn.lock`: the leading comment/header lines, verbatim, and
+/// every entry keyed by its descriptor line (without the trailing `:`),
+/// valued by the entry's full text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct YarnLockFile {
+    pub(crate) header: Vec<String>,
+    pub(crate) entries: BTreeMap<String, String>,
+}
+
+/// Parses classic or berry `yarn.lock` text. An entry starts at an
+/// unindented line ending in `:` and runs until the next unindented
+/// line; anything else unindented after the header is an error.
+pub(crate) fn parse_yarn_lock(content: &str) -> Result<YarnLockFile, String> {
+    let mut header = Vec::new();
+    let mut entries = BTreeMap::new();
+    let mut current: Option<(String, Vec<&str>)> = None;
+
+    for (index, line) in content.lines().enumerate() {
+        let indented = line.starts_with(' ') || line.starts_with('\t');
+        if line.trim().is_empty() || indented {
+            match current.as_mut() {
+                Some((_, lines)) => lines.push(line),
+                None if line.trim().is_empty() => header.push(line.to_owned()),
+                None => return Err(format!("yarn.lock line {} is indented outside any entry", index + 1)),
+            }
+            continue;
+        }
+        if line.starts_with('#') && current.is_none() && entries.is_empty() {
+            header.push(line.to_owned());
+            continue;
+        }
+        let Some(key) = line.strip_suffix(':') else {
+            return Err(format!("yarn.lock line {} is not an entry header: {line:?}", index + 1));
+        };
+        finish_entry(current.take(), &mut entries)?;
+        current = Some((key.to_owned(), vec![line]));
+    }
+    finish_entry(current.take(), &mut entries)?;
+
+    while header.last().is_some_and(|line| line.trim().is_empty()) {
+        header.pop();
+    }
+    Ok(YarnLockFile { header, entries })
+}
+
+fn finish_entry(current: Option<(String, Vec<&str>)>, entries: &mut BTreeMap<String, String>) -> Result<(), String> {
+    if let Some((key, mut lines)) = current {
+        while lines.last().is_some_and(|line| line.trim().is_empty()) {
+            lines.pop();
+        }
+        if entries.insert(key.clone(), lines.join("\n")).is_some() {
+            return Err(format!("yarn.lock entry {key:?} appears twice"));
+        }
+    }
+    Ok(())
+}
+
+/// The individual descriptors an entry header claims: classic writes
+/// `"a@^1", "a@^1.2"`, berry `"a@npm:^1, a@npm:^1.2"`.
+pub(crate) fn yarn_descriptors(header: &str) -> Vec<String> {
+    if header == BERRY_METADATA {
+        return Vec::new();
+    }
+    header
+        .split(',')
+        .map(|descriptor| descriptor.trim().trim_matches('"').to_owned())
+        .filter(|descriptor| !descriptor.is_empty())
+        .collect()
+}
+
+/// Renders entries the way yarn orders them: berry's metadata first,
+/// then by descriptor with quoting ignored.
+fn render_yarn_lock(header: &[String], entries: &BTreeMap<String, String>) -> String {
+    let mut ordered: Vec<(&String, &String)> = entries.iter().collect();
+    ordered.sort_by_key(|(key, _)| (key.as_str() != BERRY_METADATA, key.trim_start_matches('"').to_owned()));
+
+    let mut out = String::new();
+    if !header.is_empty() {
+        out.push_str(&header.join("\n"));
+        out.push_str("\n\n");
+        // Classic separates its banner from the entries by two blank lines.
+        if header.iter().any(|line| line.contains("yarn lockfile v1")) {
+            out.push('\n');
+        }
+    }
+    let bodies: Vec<&str> = ordered.iter().map(|(_, body)| body.as_str()).collect();
+    out.push_str(&bodies.join("\n\n"));
+    out.push('\n');
+    out
+}
+
+/// The union half: split the conflicted file into its sides, merge their
+/// entries, render, and verify. Returns the merged text and its entry
+/// count, or the reason union isn't possible.
+fn union_yarn_lock(content: &str) -> Result<(String, usize), String> {
+    let sides = split_sides(content)?;
+    let ours = parse_yarn_lock(&sides.ours).map_err(|e| format!("side #1: {e}"))?;
+    let theirs = parse_yarn_lock(&sides.theirs).map_err(|e| format!("side #2: {e}"))?;
+    let base = sides
+        .base
+        .as_deref()
+        .map(parse_yarn_lock)
+        .transpose()
+        .map_err(|e| format!("base: {e}"))?;
+
+    let merged = merge_keyed(base.as_ref().map(|base| &base.entries), &ours.entries, &theirs.entries)
+        .map_err(|key| format!("both sides changed entry {key:?} differently"))?;
+    let rendered = render_yarn_lock(&ours.header, &merged);
+    ParseCheck::YarnLock
+        .check(&rendered)
+        .map_err(|e| format!("unioned yarn.lock does not verify: {e}"))?;
+    Ok((rendered, merged.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::FakeCommandRunner;
+
+    fn file(path: &str) -> ConflictedFile {
+        ConflictedFile {
+            path: path.to_owned(),
+            marker_count: Some(1),
+            shape: "content".to_owned(),
+        }
+    }
+
+    const CLASSIC_CONFLICT: &str = include_str!("../testdata/yarn_classic_conflict.lock");
+
+    #[test]
+    fn parse_reads_header_and_entries() {
+        let parsed = parse_yarn_lock(
+            "# yarn lockfile v1\n\n\nabbrev@1:\n  version \"1.1.1\"\n\n\"@babel/core@^7.0.0\":\n  version \"7.0.0\"\n",
+        )
+        .unwrap();
+        assert_eq!(parsed.header, vec!["# yarn lockfile v1"]);
+        assert_eq!(parsed.entries.len(), 2);
+        assert_eq!(parsed.entries["abbrev@1"], "abbrev@1:\n  version \"1.1.1\"");
+    }
+
+    #[test]
+    fn descriptors_split_classic_and_berry_headers() {
+        assert_eq!(
+            yarn_descriptors("\"a@^1.0.0\", \"a@^1.2.0\""),
+            vec!["a@^1.0.0", "a@^1.2.0"]
+        );
+        assert_eq!(
+            yarn_descriptors("\"a@npm:^1.0.0, a@npm:^1.2.0\""),
+            vec!["a@npm:^1.0.0", "a@npm:^1.2.0"]
+        );
+        assert!(yarn_descriptors("__metadata").is_empty());
+    }
+
+    #[tokio::test]
+    async fn disjoint_additions_union_without_running_yarn() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("yarn.lock"), CLASSIC_CONFLICT).unwrap();
+
+        // An empty outcome queue: any command would panic the fake.
+        let runner = Arc::new(FakeCommandRunner::sequence(Vec::new()));
+        let resolver = YarnLockResolver::with_runner(runner.clone());
+
+        let outcome = resolver.resolve(dir.path(), &file("yarn.lock")).await;
+
+        assert!(matches!(outcome, ResolveOutcome::Resolved { .. }), "got {outcome:?}");
+        let resolved = std::fs::read_to_string(dir.path().join("yarn.lock")).unwrap();
+        assert!(resolved.starts_with("# THIS IS AN AUTOGENERATED FILE"));
+        assert!(
+            resolved.contains("\n\n\nabbrev@1:"),
+            "classic banner spacing kept:\n{resolved}"
+        );
+        for entry in ["abbrev@1:", "left-pad@^1.3.0:", "right-pad@^1.0.1:"] {
+            assert!(resolved.contains(entry), "missing {entry}:\n{resolved}");
+        }
+        assert!(runner.calls.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn divergent_entry_falls_back_to_regeneration() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("package.json"), "{}").unwrap();
+        std::fs::write(
+            dir.path().join("yarn.lock"),
+            "\
+<<<<<<< Conflict 1 of 1
+%%%%%%% Changes from base to side #1
+ left-pad@^1.0.0:
+-  version \"1.0.0\"
++  version \"1.1.0\"
++++++++ Contents of side #2
+left-pad@^1.0.0:
+  version \"1.2.0\"
+>>>>>>> Conflict 1 of 1 ends
+",
+        )
+        .unwrap();
+
+        let runner = Arc::new(FakeCommandRunner::success_writing_file(
+            "yarn.lock",
+            "# yarn lockfile v1\n\n\nleft-pad@^1.0.0:\n  version \"1.2.0\"\n",
+        ));
+        let resolver = YarnLockResolver::with_runner(runner.clone());
+
+        let outcome = resolver.resolve(dir.path(), &file("yarn.lock")).await;
+
+        match outcome {
+            ResolveOutcome::Resolved { summary } => {
+                assert!(summary.contains("regenerated"), "summary was: {summary}");
+                assert!(summary.contains("left-pad@^1.0.0"), "summary was: {summary}");
+            }
+            other => panic!("expected Resolved, got {other:?}"),
+        }
+        let calls = runner.calls.lock().unwrap();
+        assert_eq!(calls[0].0, "yarn");
+        assert!(calls[0].1.contains(&"--ignore-scripts".to_owned()));
+    }
+
+    #[tokio::test]
+    async fn berry_projects_regenerate_in_lockfile_only_mode() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("package.json"), "{}").unwrap();
+        std::fs::write(dir.path().join(".yarnrc.yml"), "nodeLinker: node-modules\n").unwrap();
+        std::fs::write(dir.path().join("yarn.lock"), "<<<<<<< a\n").unwrap();
+
+        let runner = Arc::new(FakeCommandRunner::success_writing_file(
+            "yarn.lock",
+            "__metadata:\n  version: 8\n",
+        ));
+        let resolver = YarnLockResolver::with_runner(runner.clone());
+
+        let outcome = resolver.resolve(dir.path(), &file("yarn.lock")).await;
+
+        assert!(matches!(outcome, ResolveOutcome::Resolved { .. }), "got {outcome:?}");
+        assert_eq!(
+            runner.calls.lock().unwrap()[0].1,
+            vec!["install", "--mode=update-lockfile"]
+        );
+    }
+
+    #[test]
+    fn regeneration_runs_with_scripts_disabled() {
+        let policy = sandbox_policy();
+        assert!(policy.allow_network);
+        assert!(
+            policy
+                .env_overrides
+                .contains(&("YARN_ENABLE_SCRIPTS".to_owned(), "0".to_owned()))
+        );
+    }
+}
@@ -0,0 +1,541 @@
+//! [`SandboxedCommandRunner`] — the [`CommandRunner`] the regenerate-style
+//! resolvers (`npm`/`pnpm`/`yarn`, `go generate`,
+//! [`crate::RegenerateCommandResolver`]) run their tools through.
+//!
+//! Those tools execute code the PR under resolution controls (package
+//! manifests, `//go:generate` directives), so a resolver must not hand
+//! them the engine's environment or the rest of the host's filesystem.
+//! Every command gets:
+//!
+//! - a scrubbed environment: only [`SandboxPolicy::env_passthrough`]
+//!   survives, and `HOME`/`TMPDIR` point at a private scratch directory
+//!   deleted afterwards (so no credentials or caches leak in, and tool
+//!   caches start cold);
+//! - a wall-clock [`SandboxPolicy::timeout`], after which the child is
+//!   killed and the run reports [`std::io::ErrorKind::TimedOut`];
+//! - filesystem confinement where the host offers it — `bwrap` on Linux,
+//!   `sandbox-exec` on macOS: the command sees only its working directory
+//!   and the scratch directory (both writable) plus, read-only, the system
+//!   directories and the toolchains on `PATH` (see [`toolchain_dirs`]).
+//!   The real `$HOME` is an empty tmpfs under `bwrap` and unreadable under
+//!   Seatbelt, so `~/.ssh`, the `gh` token and cloud credentials stay out
+//!   of reach even when the network is on. The network is cut unless
+//!   [`SandboxPolicy::allow_network`] is set. Hosts with neither get the
+//!   environment and timeout guarantees only; that degradation is logged
+//!   once at WARN.
+//!
+//! A command may therefore write only beneath the directory it runs in,
+//! which is exactly what the resolvers need: the regenerated file lives
+//! there.
+
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use boss_command_runner::CommandOutput;
+
+use crate::command::CommandRunner;
+
+/// Environment variables a sandboxed command inherits by default —
+/// enough to find toolchains and reach a registry through a proxy.
+const DEFAULT_ENV_PASSTHROUGH: &[&str] = &[
+    "PATH",
+    "LANG",
+    "LC_ALL",
+    "HTTP_PROXY",
+    "HTTPS_PROXY",
+    "NO_PROXY",
+    "http_proxy",
+    "https_proxy",
+    "no_proxy",
+    "GOPROXY",
+    "GOPRIVATE",
+    "GOFLAGS",
+    "npm_config_registry",
+];
+
+/// Host directories a `bwrap`-confined command may read: enough to exec
+/// a toolchain, resolve names and verify TLS. Missing ones are skipped.
+const BWRAP_SYSTEM_DIRS: &[&str] = &[
+    "/usr",
+    "/bin",
+    "/sbin",
+    "/lib",
+    "/lib32",
+    "/lib64",
+    "/etc",
+    "/opt",
+    "/nix",
+    "/run/systemd/resolve",
+];
+
+/// The Seatbelt counterpart of [`BWRAP_SYSTEM_DIRS`], as real paths
+/// (`/etc` is `/private/etc`).
+const SEATBELT_SYSTEM_DIRS: &[&str] = &[
+    "/usr",
+    "/bin",
+    "/sbin",
+    "/opt",
+    "/System",
+    "/Library/Developer",
+    "/Applications/Xcode.app",
+    "/private/etc",
+    "/private/var/db",
+    "/dev",
+];
+
+/// What a [`SandboxedCommandRunner`] lets its commands do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SandboxPolicy {
+    /// Whether the command may use the network. Lockfile regeneration
+    /// must resolve versions against a registry, so the package-manager
+    /// resolvers allow it; pure code generators don't need it.
+    pub allow_network: bool,
+    /// Wall-clock limit for one command.
+    pub timeout: Duration,
+    /// Variables copied from the engine's environment into the command's.
+    pub env_passthrough: Vec<String>,
+    /// Variables set to fixed values in the command's environment, after
+    /// the passthrough — e.g. a tool switch that has no flag spelling.
+    pub env_overrides: Vec<(String, String)>,
+}
+
+impl SandboxPolicy {
+    /// Policy for package managers regenerating a lockfile: network on,
+    /// ten minutes.
+    pub fn package_manager() -> Self {
+        Self {
+            allow_network: true,
+            timeout: Duration::from_secs(600),
+            env_passthrough: DEFAULT_ENV_PASSTHROUGH.iter().map(|name| name.to_string()).collect(),
+            env_overrides: Vec::new(),
+        }
+    }
+
+    /// Policy for code generators: network off, five minutes.
+    pub fn offline() -> Self {
+        Self {
+            allow_network: false,
+            timeout: Duration::from_secs(300),
+            ..Self::package_manager()
+        }
+    }
+}
+
+/// How commands are confined on this host, probed once per process.
+#[derive(Debug, Clone)]
+enum Confinement {
+    Bubblewrap(PathBuf),
+    SandboxExec(PathBuf),
+    EnvironmentOnly,
+}
+
+fn confinement() -> &'static Confinement {
+    static CONFINEMENT: OnceLock<Confinement> = OnceLock::new();
+    CONFINEMENT.get_or_init(|| {
+        if cfg!(target_os = "linux")
+            && let Some(bwrap) = find_on_path("bwrap")
+        {
+            // `bwrap` can be installed yet unusable (no unprivileged user
+            // namespaces in a container); probe before trusting it.
+            let usable = std::process::Command::new(&bwrap)
+                .args(["--ro-bind", "/", "/", "true"])
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .status()
+                .is_ok_and(|status| status.success());
+            if usable {
+                return Confinement::Bubblewrap(bwrap);
+            }
+        }
+        if cfg!(target_os = "macos") {
+            let sandbox_exec = PathBuf::from("/usr/bin/sandbox-exec");
+            if sandbox_exec.is_file() {
+                return Confinement::SandboxExec(sandbox_exec);
+            }
+        }
+        tracing::warn!(
+            "deterministic_resolvers: no usable bwrap/sandbox-exec on this host; resolver commands get a scrubbed environment and timeout but no filesystem confinement",
+        );
+        Confinement::EnvironmentOnly
+    })
+}
+
+/// Directories holding the toolchains on `path_var` (and `program`'s own
+/// directory, when it is absolute), readable inside the sandbox. A `bin`
+/// directory brings its install prefix along — `node` under
+/// `~/.nvm/versions/node/v20/bin` needs the prefix's `lib` — unless that
+/// prefix is `home`, an ancestor of it, or a directory directly inside
+/// it (`~/.local`, `~/go`), where it would expose unrelated dotfiles.
+/// Then only the `bin` directory itself is readable. `home` and its
+/// ancestors are never included.
+fn toolchain_dirs(path_var: Option<&OsStr>, home: Option<&Path>, program: &Path) -> Vec<PathBuf> {
+    let exposes_home = |dir: &Path| home.is_some_and(|home| home.starts_with(dir) || dir.parent() == Some(home));
+    let program_dir = program.is_absolute().then(|| program.parent()).flatten();
+    let mut dirs: Vec<PathBuf> = path_var
+        .into_iter()
+        .flat_map(std::env::split_paths)
+        .chain(program_dir.map(Path::to_path_buf))
+        .filter(|dir| dir.is_absolute())
+        .filter_map(|dir| {
+            let prefix = dir.parent().filter(|_| dir.file_name() == Some(OsStr::new("bin")));
+            match prefix {
+                Some(prefix) if prefix != Path::new("/") && !exposes_home(prefix) => Some(prefix.to_path_buf()),
+                _ if home.is_some_and(|home| home.starts_with(&dir)) => None,
+                _ => Some(dir),
+            }
+        })
+        .collect();
+    dirs.sort();
+    dirs.dedup();
+    dirs
+}
+
+fn find_on_path(program: &str) -> Option<PathBuf> {
+    let path = std::env::var_os("PATH")?;
+    std::env::split_paths(&path)
+        .map(|dir| dir.join(program))
+        .find(|candidate| candidate.is_file())
+}
+
+/// A [`CommandRunner`] that confines each command per its
+/// [`SandboxPolicy`]; see the module docs for the guarantees.
+#[derive(Debug, Clone)]
+pub struct SandboxedCommandRunner {
+    policy: SandboxPolicy,
+}
+
+impl SandboxedCommandRunner {
+    pub fn new(policy: SandboxPolicy) -> Self {
+        Self { policy }
+    }
+
+    pub fn policy(&self) -> &SandboxPolicy {
+        &self.policy
+    }
+
+    fn command(&self, program: &Path, args: &[OsString], cwd: &Path, scratch: &Path) -> tokio::process::Command {
+        let home = std::env::var_os("HOME").map(PathBuf::from);
+        let toolchains = toolchain_dirs(std::env::var_os("PATH").as_deref(), home.as_deref(), program);
+        let mut command = match confinement() {
+            Confinement::Bubblewrap(bwrap) => {
+                let mut command = tokio::process::Command::new(bwrap);
+                command.args(["--dev", "/dev", "--proc", "/proc"]);
+                // The tmpfs goes first so toolchains and a workspace
+                // beneath `$HOME` are bound on top of it.
+                if let Some(home) = home.as_deref().filter(|home| *home != Path::new("/")) {
+                    command.arg("--tmpfs").arg(home);
+                }
+                let readable = BWRAP_SYSTEM_DIRS
+                    .iter()
+                    .map(Path::new)
+                    .chain(toolchains.iter().map(PathBuf::as_path));
+                for dir in readable {
+                    command.arg("--ro-bind-try").args([dir, dir]);
+                }
+                command
+                    .arg("--bind")
+                    .args([scratch, scratch])
+                    .arg("--bind")
+                    .args([cwd, cwd])
+                    .args(["--unshare-pid", "--die-with-parent"]);
+                if !self.policy.allow_network {
+                    command.arg("--unshare-net");
+                }
+                command.arg("--chdir").arg(cwd).arg("--").arg(program).args(args);
+                command
+            }
+            Confinement::SandboxExec(sandbox_exec) => {
+                let mut command = tokio::process::Command::new(sandbox_exec);
+                command
+                    .arg("-p")
+                    .arg(seatbelt_profile(cwd, scratch, &toolchains, self.policy.allow_network))
+                    .arg(program)
+                    .args(args);
+                command
+            }
+            Confinement::EnvironmentOnly => {
+                let mut command = tokio::process::Command::new(program);
+                command.args(args);
+                command
+            }
+        };
+        command.current_dir(cwd).env_clear();
+        for name in &self.policy.env_passthrough {
+            if let Some(value) = std::env::var_os(name) {
+                command.env(name, value);
+            }
+        }
+        for (name, value) in &self.policy.env_overrides {
+            command.env(name, value);
+        }
+        command
+            .env("HOME", scratch)
+            .env("TMPDIR", scratch)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+        command
+    }
+}
+
+/// Seatbelt profile: everything allowed except reads outside the system
+/// directories, `toolchains`, `cwd` and `scratch`; writes outside `cwd`
+/// and `scratch`; and, unless allowed, the network. Metadata stays
+/// readable everywhere so path lookups work. Paths are canonicalized
+/// because Seatbelt matches real paths (`/var` is `/private/var`).
+fn seatbelt_profile(cwd: &Path, scratch: &Path, toolchains: &[PathBuf], allow_network: bool) -> String {
+    let real = |path: &Path| std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let subpath = |path: &Path| format!(" (subpath {:?})", real(path).display().to_string());
+    let readable: String = SEATBELT_SYSTEM_DIRS
+        .iter()
+        .map(Path::new)
+        .chain(toolchains.iter().map(PathBuf::as_path))
+        .map(subpath)
+        .collect();
+    let mut profile = format!(
+        "(version 1)(allow default)(deny file-read* file-write*)(allow file-read-metadata)\
+         (allow file-read* (literal \"/\"){readable})\
+         (allow file-read* file-write*{}{} (literal \"/dev/null\"))",
+        subpath(cwd),
+        subpath(scratch),
+    );
+    if !allow_network {
+        profile.push_str("(deny network*)");
+    }
+    profile
+}
+
+/// A private scratch directory used as the command's `HOME`/`TMPDIR`,
+/// removed on drop.
+struct Scratch(PathBuf);
+
+impl Scratch {
+    fn create() -> std::io::Result<Self> {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        let path = std::env::temp_dir().join(format!(
+            "boss-resolver-sandbox-{}-{}",
+            std::process::id(),
+            NEXT.fetch_add(1, Ordering::Relaxed)
+        ));
+        std::fs::create_dir_all(&path)?;
+        Ok(Self(path))
+    }
+}
+
+impl Drop for Scratch {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+#[async_trait]
+impl CommandRunner for SandboxedCommandRunner {
+    async fn run(&self, program: &Path, args: &[OsString], cwd: Option<&Path>) -> std::io::Result<CommandOutput> {
+        let Some(cwd) = cwd else {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "sandboxed commands need a working directory to confine writes to",
+            ));
+        };
+        let scratch = Scratch::create()?;
+        let child = self.command(program, args, cwd, &scratch.0).spawn()?;
+        let output = match tokio::time::timeout(self.policy.timeout, child.wait_with_output()).await {
+            Ok(output) => output?,
+            // Dropping the wait future drops the child, which
+            // `kill_on_drop` turns into a SIGKILL.
+            Err(_) => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    format!(
+                        "`{}` exceeded its {}s sandbox timeout",
+                        program.display(),
+                        self.policy.timeout.as_secs()
+                    ),
+                ));
+            }
+        };
+        Ok(CommandOutput {
+            success: output.status.success(),
+            code: output.status.code(),
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sh(script: &str) -> Vec<OsString> {
+        vec![OsString::from("-c"), OsString::from(script)]
+    }
+
+    #[tokio::test]
+    async fn environment_is_scrubbed_to_the_passthrough_list() {
+        let dir = tempfile::tempdir().unwrap();
+        let runner = SandboxedCommandRunner::new(SandboxPolicy::offline());
+        let output = runner
+            .run(Path::new("/bin/sh"), &sh("env"), Some(dir.path()))
+            .await
+            .unwrap();
+        assert!(output.success, "stderr={}", output.stderr);
+
+        // Variables the shell itself maintains are fine; anything else must
+        // have come from the passthrough list or the sandbox.
+        let shell_owned = ["PWD", "OLDPWD", "SHLVL", "_"];
+        for line in output.stdout.lines() {
+            let name = line.split('=').next().unwrap_or_default();
+            assert!(
+                DEFAULT_ENV_PASSTHROUGH.contains(&name)
+                    || name == "HOME"
+                    || name == "TMPDIR"
+                    || shell_owned.contains(&name),
+                "unexpected variable leaked into the sandbox: {line}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn home_is_a_private_scratch_directory_removed_afterwards() {
+        let dir = tempfile::tempdir().unwrap();
+        let runner = SandboxedCommandRunner::new(SandboxPolicy::offline());
+        let output = runner
+            .run(Path::new("/bin/sh"), &sh("echo \"$HOME\""), Some(dir.path()))
+            .await
+            .unwrap();
+        let home = PathBuf::from(output.stdout.trim());
+        assert!(
+            home.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("boss-resolver-sandbox-")),
+            "HOME should be the sandbox scratch dir, was {}",
+            home.display()
+        );
+        assert!(!home.exists(), "scratch dir should be removed after the run");
+    }
+
+    #[tokio::test]
+    async fn commands_run_in_the_given_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let runner = SandboxedCommandRunner::new(SandboxPolicy::offline());
+        let output = runner
+            .run(Path::new("/bin/sh"), &sh("echo hi > out.txt"), Some(dir.path()))
+            .await
+            .unwrap();
+        assert!(output.success, "stderr={}", output.stderr);
+        assert_eq!(std::fs::read_to_string(dir.path().join("out.txt")).unwrap(), "hi\n");
+    }
+
+    #[tokio::test]
+    async fn overrunning_commands_are_killed_with_timed_out() {
+        let dir = tempfile::tempdir().unwrap();
+        let runner = SandboxedCommandRunner::new(SandboxPolicy {
+            timeout: Duration::from_millis(100),
+            ..SandboxPolicy::offline()
+        });
+        let err = runner
+            .run(Path::new("/bin/sh"), &sh("sleep 5"), Some(dir.path()))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::TimedOut);
+    }
+
+    #[tokio::test]
+    async fn a_working_directory_is_required() {
+        let runner = SandboxedCommandRunner::new(SandboxPolicy::offline());
+        let err = runner.run(Path::new("/bin/true"), &[], None).await.unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    #[tokio::test]
+    async fn writes_outside_the_working_directory_are_refused_when_confined() {
+        if matches!(confinement(), Confinement::EnvironmentOnly) {
+            eprintln!("skipping: no filesystem confinement on this host");
+            return;
+        }
+        let outside = tempfile::tempdir().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let target = outside.path().join("escaped.txt");
+        let runner = SandboxedCommandRunner::new(SandboxPolicy::offline());
+        let output = runner
+            .run(
+                Path::new("/bin/sh"),
+                &sh(&format!("echo x > {:?}", target.display().to_string())),
+                Some(dir.path()),
+            )
+            .await
+            .unwrap();
+        assert!(!output.success);
+        assert!(!target.exists());
+    }
+
+    #[tokio::test]
+    async fn reads_outside_the_allowed_directories_are_refused_when_confined() {
+        if matches!(confinement(), Confinement::EnvironmentOnly) {
+            eprintln!("skipping: no filesystem confinement on this host");
+            return;
+        }
+        let outside = tempfile::tempdir().unwrap();
+        let secret = outside.path().join("token");
+        std::fs::write(&secret, "hunter2").unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let runner = SandboxedCommandRunner::new(SandboxPolicy::package_manager());
+        let output = runner
+            .run(
+                Path::new("/bin/sh"),
+                &sh(&format!("cat {:?}", secret.display().to_string())),
+                Some(dir.path()),
+            )
+            .await
+            .unwrap();
+        assert!(!output.success);
+        assert!(!output.stdout.contains("hunter2"));
+    }
+
+    #[tokio::test]
+    async fn env_overrides_are_set_after_the_passthrough() {
+        let dir = tempfile::tempdir().unwrap();
+        let runner = SandboxedCommandRunner::new(SandboxPolicy {
+            env_overrides: vec![("YARN_ENABLE_SCRIPTS".to_owned(), "0".to_owned())],
+            ..SandboxPolicy::offline()
+        });
+        let output = runner
+            .run(
+                Path::new("/bin/sh"),
+                &sh("echo \"$YARN_ENABLE_SCRIPTS\""),
+                Some(dir.path()),
+            )
+            .await
+            .unwrap();
+        assert_eq!(output.stdout.trim(), "0");
+    }
+
+    #[test]
+    fn toolchain_dirs_bring_install_prefixes_but_never_home() {
+        let home = Path::new("/home/dev");
+        let path_var = OsString::from(
+            "/home/dev/.nvm/versions/node/v20/bin:/home/dev/.cargo/bin:/home/dev/bin:/home/dev:/usr/local/go/bin:/usr/bin:/bin:relative/bin",
+        );
+        let dirs = toolchain_dirs(Some(&path_var), Some(home), Path::new("/opt/yarn/bin/yarn"));
+        assert_eq!(
+            dirs,
+            [
+                "/bin",
+                "/home/dev/.cargo/bin",
+                "/home/dev/.nvm/versions/node/v20",
+                "/home/dev/bin",
+                "/opt/yarn",
+                "/usr",
+                "/usr/local/go",
+            ]
+            .map(PathBuf::from)
+        );
+    }
+}
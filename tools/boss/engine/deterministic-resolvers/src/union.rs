@@ -0,0 +1,110 @@
+//! Three-way merge of keyed entries — the "union" half of the
+//! regenerate-or-union lockfile resolvers. Each resolver parses every
+//! side of the conflict (see [`crate::markers::split_sides`]) into a map
+//! from entry key (a `go.sum` line, a `yarn.lock` descriptor list) to
+//! entry body, and this merges the maps.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Merge `ours` and `theirs` against `base`, entry by entry:
+///
+/// - both sides agree (including both dropping it) → that value;
+/// - only one side changed it relative to `base` → that side's value;
+/// - with no base (plain git markers), an entry present on one side only
+///   is kept — a base-less merge can only union;
+/// - both sides changed it differently → `Err(key)`: a real conflict the
+///   caller must hand to regeneration or decline.
+pub(crate) fn merge_keyed<K, V>(
+    base: Option<&BTreeMap<K, V>>,
+    ours: &BTreeMap<K, V>,
+    theirs: &BTreeMap<K, V>,
+) -> Result<BTreeMap<K, V>, K>
+where
+    K: Ord + Clone,
+    V: PartialEq + Clone,
+{
+    let keys: BTreeSet<&K> = ours
+        .keys()
+        .chain(theirs.keys())
+        .chain(base.into_iter().flat_map(BTreeMap::keys))
+        .collect();
+
+    let mut merged = BTreeMap::new();
+    for key in keys {
+        let o = ours.get(key);
+        let t = theirs.get(key);
+        let chosen = if o == t {
+            o
+        } else {
+            match base {
+                Some(base) => {
+                    let b = base.get(key);
+                    if o == b {
+                        t
+                    } else if t == b {
+                        o
+                    } else {
+                        return Err(key.clone());
+                    }
+                }
+                None => match (o, t) {
+                    (Some(_), None) => o,
+                    (None, Some(_)) => t,
+                    _ => return Err(key.clone()),
+                },
+            }
+        };
+        if let Some(value) = chosen {
+            merged.insert(key.clone(), value.clone());
+        }
+    }
+    Ok(merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map(entries: &[(&str, &str)]) -> BTreeMap<String, String> {
+        entries.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn disjoint_additions_union() {
+        let base = map(&[("a", "1")]);
+        let ours = map(&[("a", "1"), ("b", "2")]);
+        let theirs = map(&[("a", "1"), ("c", "3")]);
+        let merged = merge_keyed(Some(&base), &ours, &theirs).unwrap();
+        assert_eq!(merged, map(&[("a", "1"), ("b", "2"), ("c", "3")]));
+    }
+
+    #[test]
+    fn one_sided_removal_and_change_win_over_the_base() {
+        let base = map(&[("a", "1"), ("b", "1")]);
+        let ours = map(&[("b", "1")]);
+        let theirs = map(&[("a", "1"), ("b", "2")]);
+        let merged = merge_keyed(Some(&base), &ours, &theirs).unwrap();
+        assert_eq!(merged, map(&[("b", "2")]));
+    }
+
+    #[test]
+    fn divergent_changes_are_a_conflict() {
+        let base = map(&[("a", "1")]);
+        let ours = map(&[("a", "2")]);
+        let theirs = map(&[("a", "3")]);
+        assert_eq!(merge_keyed(Some(&base), &ours, &theirs), Err("a".to_owned()));
+    }
+
+    #[test]
+    fn without_a_base_one_sided_entries_are_kept_but_divergence_conflicts() {
+        let ours = map(&[("a", "1"), ("b", "2")]);
+        let theirs = map(&[("a", "1"), ("c", "3")]);
+        assert_eq!(
+            merge_keyed(None, &ours, &theirs).unwrap(),
+            map(&[("a", "1"), ("b", "2"), ("c", "3")])
+        );
+
+        let theirs = map(&[("a", "9")]);
+        assert_eq!(merge_keyed(None, &ours, &theirs), Err("a".to_owned()));
+    }
+}
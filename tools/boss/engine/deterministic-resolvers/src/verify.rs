@@ -0,0 +1,149 @@
+//! Post-resolution verification: a regenerated or unioned file must
+//! parse as its format before a resolver reports it resolved. Exit 0
+//! from a package manager, or a union that merged cleanly, is not proof
+//! the file on disk is usable — a truncated write or a union that
+//! produced duplicate descriptors would otherwise ride rung 0 straight
+//! into a push.
+
+use std::collections::BTreeSet;
+
+use crate::markers::has_conflict_markers;
+
+/// How to check a resolved file's content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseCheck {
+    /// Only that no conflict markers remain — for formats with no parser
+    /// here (generated source).
+    NoConflictMarkers,
+    Json,
+    Yaml,
+    Toml,
+    /// `package-lock.json`: a JSON object with a `lockfileVersion`.
+    NpmLock,
+    /// `pnpm-lock.yaml`: a YAML mapping with a `lockfileVersion`.
+    PnpmLock,
+    /// `yarn.lock` (classic or berry): well-formed entries, no
+    /// descriptor claimed by two entries.
+    YarnLock,
+    /// `go.sum`: every line is `<module> <version>[/go.mod] <hash>`, one
+    /// hash per module version.
+    GoSum,
+}
+
+impl ParseCheck {
+    /// `Err` carries a human-readable reason naming what failed to parse.
+    pub fn check(self, content: &str) -> Result<(), String> {
+        if has_conflict_markers(content) {
+            return Err("conflict markers remain".to_owned());
+        }
+        match self {
+            ParseCheck::NoConflictMarkers => Ok(()),
+            ParseCheck::Json => serde_json::from_str::<serde_json::Value>(content)
+                .map(drop)
+                .map_err(|e| format!("invalid JSON: {e}")),
+            ParseCheck::Yaml => serde_yaml::from_str::<serde_yaml::Value>(content)
+                .map(drop)
+                .map_err(|e| format!("invalid YAML: {e}")),
+            ParseCheck::Toml => toml::from_str::<toml::Table>(content)
+                .map(drop)
+                .map_err(|e| format!("invalid TOML: {e}")),
+            ParseCheck::NpmLock => {
+                let value: serde_json::Value =
+                    serde_json::from_str(content).map_err(|e| format!("invalid JSON: {e}"))?;
+                if value.get("lockfileVersion").is_none() {
+                    return Err("package-lock.json has no lockfileVersion".to_owned());
+                }
+                Ok(())
+            }
+            ParseCheck::PnpmLock => {
+                let value: serde_yaml::Value =
+                    serde_yaml::from_str(content).map_err(|e| format!("invalid YAML: {e}"))?;
+                if value.get("lockfileVersion").is_none() {
+                    return Err("pnpm-lock.yaml has no lockfileVersion".to_owned());
+                }
+                Ok(())
+            }
+            ParseCheck::YarnLock => {
+                let entries = crate::resolvers::parse_yarn_lock(content)?;
+                let mut seen = BTreeSet::new();
+                for header in entries.entries.keys() {
+                    for descriptor in crate::resolvers::yarn_descriptors(header) {
+                        if !seen.insert(descriptor.clone()) {
+                            return Err(format!("descriptor {descriptor} appears in more than one entry"));
+                        }
+                    }
+                }
+                Ok(())
+            }
+            ParseCheck::GoSum => {
+                let mut hashes = std::collections::BTreeMap::new();
+                for (index, line) in content.lines().enumerate() {
+                    if line.trim().is_empty() {
+                        continue;
+                    }
+                    let fields: Vec<&str> = line.split_whitespace().collect();
+                    let well_formed = fields.len() == 3 && fields[1].starts_with('v') && fields[2].contains(':');
+                    if !well_formed {
+                        return Err(format!("go.sum line {} is malformed: {line:?}", index + 1));
+                    }
+                    if let Some(previous) = hashes.insert((fields[0], fields[1]), fields[2])
+                        && previous != fields[2]
+                    {
+                        return Err(format!("go.sum records two hashes for {} {}", fields[0], fields[1]));
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_check_rejects_leftover_markers() {
+        let content = "<<<<<<< HEAD\n{}\n=======\n{}\n>>>>>>> x\n";
+        for check in [ParseCheck::NoConflictMarkers, ParseCheck::Json, ParseCheck::GoSum] {
+            assert!(check.check(content).is_err(), "{check:?} accepted markers");
+        }
+    }
+
+    #[test]
+    fn lockfile_checks_require_a_lockfile_version() {
+        assert!(ParseCheck::NpmLock.check("{\"lockfileVersion\": 3}").is_ok());
+        assert!(ParseCheck::NpmLock.check("{\"name\": \"x\"}").is_err());
+        assert!(ParseCheck::NpmLock.check("{\"lockfileVersion\": ").is_err());
+        assert!(ParseCheck::PnpmLock.check("lockfileVersion: '9.0'\n").is_ok());
+        assert!(ParseCheck::PnpmLock.check("importers: {}\n").is_err());
+    }
+
+    #[test]
+    fn go_sum_check_rejects_malformed_lines() {
+        let good = "golang.org/x/text v0.14.0 h1:abc=\ngolang.org/x/text v0.14.0/go.mod h1:def=\n";
+        assert!(ParseCheck::GoSum.check(good).is_ok());
+        assert!(ParseCheck::GoSum.check("golang.org/x/text v0.14.0\n").is_err());
+        let disputed = "golang.org/x/text v0.14.0 h1:abc=\ngolang.org/x/text v0.14.0 h1:xyz=\n";
+        assert!(ParseCheck::GoSum.check(disputed).unwrap_err().contains("two hashes"));
+    }
+
+    #[test]
+    fn yarn_lock_check_rejects_a_descriptor_claimed_twice() {
+        let duplicated = "\
+\"left-pad@^1.0.0\":
+  version \"1.0.0\"
+
+\"left-pad@^1.0.0\", \"left-pad@^1.1.0\":
+  version \"1.1.0\"
+";
+        let err = ParseCheck::YarnLock.check(duplicated).unwrap_err();
+        assert!(err.contains("left-pad@^1.0.0"), "reason was: {err}");
+    }
+
+    #[test]
+    fn toml_check_parses() {
+        assert!(ParseCheck::Toml.check("[package]\nname = \"x\"\n").is_ok());
+        assert!(ParseCheck::Toml.check("[package\n").is_err());
+    }
+}
@@ -356,6 +356,14 @@ pub enum Stage {
     /// (`host_disabled` / `host_removed`) so the re-route is diagnosable
     /// from `bossctl dispatch tail`.
     HostDrainReconcile,
+    /// The host-load sweep (`boss_engine::host_load`) found a `ready`
+    /// execution backing off because no host was eligible, and a dry-run
+    /// placement now finds one — a host freed a slot, its load dropped, or
+    /// its disk recovered. The backoff is cut short so the next scheduler
+    /// pass dispatches it instead of waiting out the remaining delay. The
+    /// `details` object carries the `host_id` the dry run picked and its
+    /// `score`; dispatch-time selection still makes the real pick.
+    HostWorkSteal,
     /// The periodic spawn-ack sweep (`boss_engine::spawn_ack_sweep`) found a
     /// slot stuck in `Spawning` that never reported a shell pid AND never
     /// received a single hook event, past the grace window — proof no
@@ -808,6 +816,7 @@ impl Stage {
             Stage::CubeLeaseAutoReap => "cube_lease_auto_reap",
             Stage::RemoteLeaseReconcile => "remote_lease_reconcile",
             Stage::HostDrainReconcile => "host_drain_reconcile",
+            Stage::HostWorkSteal => "host_work_steal",
             Stage::SpawnAckTimeout => "spawn_ack_timeout",
             Stage::DriverStartTimeout => "driver_start_timeout",
             Stage::DispatchFailureRecoveryRedispatch => "dispatch_failure_recovery_redispatch",
@@ -1577,6 +1586,7 @@ mod tests {
         assert_eq!(Stage::CubeLeaseAutoReap.as_str(), "cube_lease_auto_reap");
         assert_eq!(Stage::RemoteLeaseReconcile.as_str(), "remote_lease_reconcile");
         assert_eq!(Stage::HostDrainReconcile.as_str(), "host_drain_reconcile");
+        assert_eq!(Stage::HostWorkSteal.as_str(), "host_work_steal");
         assert_eq!(Stage::SpawnAckTimeout.as_str(), "spawn_ack_timeout");
         assert_eq!(Stage::DriverStartTimeout.as_str(), "driver_start_timeout");
         assert_eq!(
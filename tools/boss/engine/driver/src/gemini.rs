@@ -0,0 +1,722 @@
+//! `GeminiDriver` — Google Gemini CLI agent driver.
+//!
+//! Descriptor, capability set, model menu, Boss-owned `GEMINI_CLI_HOME`
+//! provisioning, interactive-TUI pane spawn, hook wiring (progress forwarder
+//! plus the five `BeforeTool` guards behind a fail-closed adapter — see
+//! [`hooks`]), `tools.exclude` permission posture ([`permissions`]), the four
+//! ControlVerbs, and Gemini API error classification ([`classify_error`]).
+//! `TranscriptAccess` reads the `transcript_path` Gemini stamps on every
+//! hook payload; [`transcript`] reshapes its chat-recording records.
+//!
+//! Gemini is a pinned-only driver: it carries no share of the default
+//! traffic split and is reached only by an explicit `driver: gemini` pin.
+//! See `tools/boss/docs/designs/antigravity-driver-fourth-driver-google-gemini-cli.md`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, bail};
+use async_trait::async_trait;
+use boss_engine_structured_output::StructuredOutputKind;
+use boss_engine_structured_output::fallback::FallbackCandidate;
+use boss_protocol::{NormalizeError, PaneMonitorSpec, WorkerEvent};
+use boss_ssh_transport::shell_quote;
+use serde_json::Value;
+
+mod classify_error;
+mod home;
+mod hooks;
+mod model_menu;
+mod permissions;
+mod progress;
+mod transcript;
+
+pub use home::{
+    GEMINI_AUTH_SOURCE_ENV, GEMINI_HOMES_ENV_TEST_LOCK, GEMINI_HOMES_ROOT_ENV, GeminiRuntimeState,
+    assert_gemini_home_safe_to_delete, gemini_home_for_run, gemini_homes_root, reclaim_gemini_home,
+};
+
+use classify_error::classify_gemini_error;
+use home::{GEMINI_DIR, gemini_dir, provision_gemini_home};
+use progress::GeminiProgressSession;
+
+use super::{
+    AgentDriver, Capability, CapabilitySet, DriverDescriptor, DriverRuntimeState, EnvDirective, HookWiringDestination,
+    InterruptDelivery, ModelMenu, PermissionArtifacts, PermissionInput, PrUrlCaptureFeed, ProbeDelivery,
+    ProgressFidelity, ProgressIngress, ProgressObservationConfig, ProgressObservationWiring, ProgressSessionNormalizer,
+    ReapDelivery, SpawnPlan, SpawnRequest, StopDelivery, StructuredOutputArtifacts, StructuredOutputRequest,
+    ToolUseInterceptionConfig, ToolUseInterceptionWiring, TurnEnd, WorkerErrorClass, WorkerKind,
+    default_structured_output_wiring,
+};
+
+// ---------------------------------------------------------------------------
+// Descriptor
+// ---------------------------------------------------------------------------
+
+static GEMINI_DESCRIPTOR: DriverDescriptor = DriverDescriptor {
+    name: "gemini",
+    label: "Gemini CLI",
+    binary: "gemini",
+    config_dir: GEMINI_DIR,
+    // Gemini reads `GEMINI.md` as its context file. Boss worker rules go to
+    // the global `$GEMINI_CLI_HOME/.gemini/GEMINI.md` via
+    // [`GeminiDriver::agent_rules_destination`], so a repo's own root
+    // `GEMINI.md` is read alongside them rather than overwritten.
+    agent_rules_filename: "GEMINI.md",
+    initial_prompt_filename: "initial-prompt.txt",
+    model_menu: ModelMenu {
+        engine_default: model_menu::PRO_MODEL,
+        effort_value_for_level: model_menu::effort_value_for_level,
+        default_model_for_level: model_menu::default_model_for_level,
+        model_for_reasoning: model_menu::model_for_reasoning,
+        prompt_addendum_for_level: model_menu::prompt_addendum_for_level,
+        model_requires_auto_permissions: model_menu::model_requires_auto_permissions,
+        model_belongs_to_driver: model_menu::model_belongs_to_driver,
+    },
+};
+
+/// Preamble for the agent-rules file (`GEMINI.md`). Names Gemini hooks
+/// rather than Claude's so the shared body below it describes the mechanism
+/// this session actually uses, and forbids the interactive affordances a
+/// pane nobody watches cannot answer.
+const GEMINI_AGENT_RULES_PREAMBLE: &str = "You are running inside a Boss-managed worker session. The engine\n\
+     spawned you in a leased cube workspace and observes this session\n\
+     via Gemini CLI hooks under a Boss-owned GEMINI_CLI_HOME.\n\
+     For ordinary pre-push validation, run `checkleft run` with no flags; use\n\
+     `checkleft --all` only in CI, when modifying checkleft itself, or with a\n\
+     strong stated justification.\n\
+     \n\
+     You run autonomously with nobody watching the pane. Never wait on a\n\
+     human keypress: do not open an approval, confirmation, or login prompt\n\
+     yourself. Proceed on a reasonable assumption and record it in your PR\n\
+     body or final summary; stop and emit a blocked marker only when\n\
+     proceeding would be unsafe, destructive, or irreversible, or a\n\
+     credential is missing.";
+
+// ---------------------------------------------------------------------------
+// GeminiDriver
+// ---------------------------------------------------------------------------
+
+/// Google Gemini CLI driver.
+///
+/// Registered under the `"gemini"` slug. Workspace provisioning, pane spawn,
+/// progress and guard wiring, permission posture, transcripts, and the
+/// ControlVerbs surface are live.
+#[derive(Default)]
+pub struct GeminiDriver {
+    // Keep this type non-unit so callers can use `Default` uniformly with
+    // stateful drivers without tripping clippy's unit-default lint.
+    _private: (),
+}
+
+/// Build the interactive TUI command line for a GhosttyKit pane.
+///
+/// ```text
+/// gemini --model … --approval-mode yolo
+///        --prompt-interactive "$(cat .gemini/initial-prompt.txt)"
+/// ```
+///
+/// `--prompt-interactive` submits the initial prompt and then stays in the
+/// TUI, so probes and follow-up turns reach the same session. Gemini has no
+/// effort flag; the row's effort is carried by the model choice alone.
+/// `GEMINI_CLI_HOME` is an env directive on the [`SpawnPlan`], not a flag,
+/// and the pane is already `cd`'d into the workspace.
+pub fn build_gemini_pane_command(request: &SpawnRequest<'_>) -> String {
+    let SpawnRequest {
+        model,
+        effort: _,
+        settings_path: _,
+        non_opus_auto_mode: _,
+        permission_mode_override: _,
+        run_id: _,
+    } = request;
+
+    let mut cmd = String::from("gemini");
+    cmd.push_str(" --model ");
+    cmd.push_str(&shell_quote(model));
+    cmd.push_str(" --approval-mode ");
+    cmd.push_str(permissions::APPROVAL_MODE);
+    cmd.push_str(&format!(
+        " --prompt-interactive \"$(cat {GEMINI_DIR}/{})\"\n",
+        GEMINI_DESCRIPTOR.initial_prompt_filename
+    ));
+    cmd
+}
+
+/// The `transcript_path` Gemini stamps on every hook payload, pointing at
+/// the session's chat recording under `$GEMINI_CLI_HOME/.gemini/tmp/`.
+/// Empty strings are treated as missing, mirroring
+/// `ClaudeDriver::transcript_path_for_session`.
+pub(super) fn transcript_path_from_payload(raw: &Value) -> Option<String> {
+    let s = raw.get("transcript_path")?.as_str()?;
+    if s.is_empty() { None } else { Some(s.to_owned()) }
+}
+
+fn gemini_home_or_fallback(run_id: &str) -> PathBuf {
+    gemini_home_for_run(run_id).unwrap_or_else(|_| gemini_homes_root().join("unknown-run"))
+}
+
+#[async_trait]
+impl AgentDriver for GeminiDriver {
+    fn descriptor(&self) -> &DriverDescriptor {
+        &GEMINI_DESCRIPTOR
+    }
+
+    fn capabilities(&self) -> CapabilitySet {
+        // Provided: all except ToolProvisioning + AwaitingInputSignal +
+        // CommandOutcomeObservation. Each omission takes its default
+        // absence disposition (Degrade) and notes why below.
+        CapabilitySet::new([
+            Capability::Spawn,
+            Capability::WorkspaceProvisioning,
+            Capability::PermissionPolicy,
+            Capability::ModelAndEffortMenu,
+            Capability::ProgressObservation,
+            Capability::ToolUseInterception,
+            Capability::TurnBoundary,
+            Capability::StructuredOutput,
+            Capability::TranscriptAccess,
+            Capability::ControlVerbs,
+            Capability::PromptComposition,
+            // ToolProvisioning — omitted. Boss injects no MCP servers or
+            // extensions into any driver; declaring it would overclaim.
+            //
+            // AwaitingInputSignal — omitted. Gemini's `Notification` hook
+            // fires for tool-permission prompts, which `--approval-mode yolo`
+            // suppresses, so there is nothing honest to bind to.
+            //
+            // CommandOutcomeObservation — omitted. `run_shell_command`'s
+            // `tool_response` carries its exit status only inside free-text
+            // `llmContent`, not as a structured field; until that is
+            // characterised, conflict-resolution and CI-remediation work is
+            // refused on this driver rather than guessed at.
+        ])
+    }
+
+    fn pane_monitor_spec(&self) -> Option<PaneMonitorSpec> {
+        // Each driver owns its surface strings; do not merge these into
+        // another driver's marker sets.
+        Some(PaneMonitorSpec {
+            // The footer shows the approval mode and the model id; "gemini-"
+            // matches the model without pinning a generation.
+            agent_markers: vec!["YOLO mode".into(), "gemini-".into()],
+            // "(esc to cancel, 12s)" trails the spinner while a turn runs.
+            busy_markers: vec!["esc to cancel".into()],
+            starting_markers: vec!["Initializing".into()],
+            // Boxed composer only, so history lines never read as a prompt.
+            prompt_prefixes: vec!["│ > ".into()],
+            idle_debounce_polls: 2,
+        })
+    }
+
+    fn spawn_invocation(&self, request: SpawnRequest<'_>) -> SpawnPlan {
+        let run_id = request.run_id.filter(|id| !id.is_empty()).unwrap_or("unknown-run");
+        let gemini_home = gemini_home_or_fallback(run_id);
+        SpawnPlan {
+            env: vec![EnvDirective::Set(
+                "GEMINI_CLI_HOME".into(),
+                gemini_home.display().to_string(),
+            )],
+            command: build_gemini_pane_command(&request),
+        }
+    }
+
+    async fn provision_workspace(
+        &self,
+        workspace: &Path,
+        prompt_text: &str,
+        run_id: &str,
+    ) -> anyhow::Result<Option<DriverRuntimeState>> {
+        let runtime = provision_gemini_home(workspace, prompt_text, run_id)
+            .with_context(|| format!("provisioning Boss-owned GEMINI_CLI_HOME for run_id {run_id:?}"))?;
+        Ok(Some(runtime.to_driver_runtime_state()))
+    }
+
+    async fn teardown_workspace(
+        &self,
+        _workspace: Option<&Path>,
+        _run_id: &str,
+        runtime_state: Option<&DriverRuntimeState>,
+    ) -> anyhow::Result<()> {
+        let Some(state) = runtime_state else {
+            return Ok(());
+        };
+        let runtime = GeminiRuntimeState::from_driver_runtime_state(state)?;
+        // Containment check only: the chat recordings live in this home and
+        // stay readable until retention reclaims it.
+        assert_gemini_home_safe_to_delete(&runtime.gemini_home)?;
+        Ok(())
+    }
+
+    /// Layer the hook wiring and `tools.exclude` posture onto the per-run
+    /// `settings.json` that `provision_workspace` wrote. Refuses
+    /// [`WorkerKind::AnswerAgent`] (see [`permissions::excluded_tools`]).
+    async fn write_permission_config(
+        &self,
+        input: &PermissionInput,
+        _dest_dir: &Path,
+    ) -> anyhow::Result<PermissionArtifacts> {
+        let gemini_home = gemini_home_for_run(&input.run_id).with_context(|| {
+            format!(
+                "GeminiDriver::write_permission_config: resolving GEMINI_CLI_HOME for run_id {:?}",
+                input.run_id
+            )
+        })?;
+        if !gemini_home.exists() {
+            bail!(
+                "GeminiDriver::write_permission_config: GEMINI_CLI_HOME {} does not exist; \
+                 call provision_workspace first",
+                gemini_home.display()
+            );
+        }
+        let excluded_tools = permissions::excluded_tools(input.worker_kind)?;
+
+        let obs_config = ProgressObservationConfig {
+            events_socket_path: input.events_socket_path.clone(),
+            lease_id: input.lease_id.clone(),
+            run_id: input.run_id.clone(),
+            workspace_path: input.workspace_path.clone(),
+            forwarder_binary: input.boss_event_path.clone(),
+        };
+        // Same construction as GrokDriver — remote workers get no local
+        // guard scripts (never shipped there).
+        let interception = ToolUseInterceptionConfig {
+            data_dir: if input.is_remote {
+                None
+            } else {
+                input.events_socket_path.parent().map(|p| p.to_path_buf())
+            },
+            path_guard_script: if input.is_remote {
+                None
+            } else {
+                input.path_guard_script.clone()
+            },
+            checkleft_guard_script: if input.is_remote {
+                None
+            } else {
+                input.checkleft_guard_script.clone()
+            },
+            is_revision: input.execution_kind == "revision_implementation"
+                || input.task_kind.as_deref() == Some("revision"),
+            is_standard_worker: input.worker_kind == WorkerKind::Standard,
+            run_id: Some(input.run_id.clone()),
+            workspace_path: Some(input.workspace_path.clone()),
+        };
+
+        let config_files = hooks::write_settings(&gemini_home, &obs_config, &interception, &excluded_tools)
+            .with_context(|| format!("writing Gemini settings under {}", gemini_home.display()))?;
+
+        Ok(PermissionArtifacts {
+            config_files,
+            extra_args: vec!["--approval-mode".into(), permissions::APPROVAL_MODE.into()],
+            env: vec![("GEMINI_CLI_HOME".into(), gemini_home.display().to_string())],
+        })
+    }
+
+    fn progress_fidelity(&self) -> ProgressFidelity {
+        // Per-tool BeforeTool/AfterTool events — same tier as Claude.
+        ProgressFidelity::Rich
+    }
+
+    fn progress_observation_wiring(&self, config: &ProgressObservationConfig) -> ProgressIngress {
+        // DriverOwned: `write_permission_config` writes this wiring into the
+        // per-run settings.json; the engine must never merge it into the
+        // Claude worker settings file Gemini does not read.
+        ProgressIngress::HookCallback(ProgressObservationWiring {
+            hooks: hooks::forwarder_hooks_map(config),
+            destination: HookWiringDestination::DriverOwned,
+        })
+    }
+
+    fn normalize_progress_event(&self, raw: &serde_json::Value) -> Result<WorkerEvent, NormalizeError> {
+        GeminiProgressSession::new().normalize_progress_event(raw)
+    }
+
+    /// Gemini's `AfterTool` `tool_response` for `run_shell_command` is
+    /// `{llmContent, returnDisplay}` rather than Claude's
+    /// `{stdout, stderr}`, so the default feed would scan nothing. Read both
+    /// text fields instead.
+    fn pr_url_capture_feed(
+        &self,
+        tool_name: &str,
+        tool_input: &serde_json::Value,
+        tool_response: &serde_json::Value,
+    ) -> Option<PrUrlCaptureFeed> {
+        if tool_name != "Bash" {
+            return None;
+        }
+        Some(PrUrlCaptureFeed {
+            output_text: gemini_shell_output_text(tool_response),
+            command: crate::command_from_tool_input(tool_input),
+        })
+    }
+
+    fn turn_boundary(&self, event: &WorkerEvent) -> Option<TurnEnd> {
+        // `AfterAgent` canonicalises to `Stop` (see [`progress`]) and carries
+        // `stop_hook_active` under the same name.
+        match event {
+            WorkerEvent::Stop {
+                session_id,
+                stop_hook_active,
+                stop_reason,
+            } => Some(TurnEnd {
+                session_id: session_id.clone(),
+                reason: *stop_reason,
+                continuation: *stop_hook_active,
+            }),
+            _ => None,
+        }
+    }
+
+    fn tool_use_interception_wiring(&self, _config: &ToolUseInterceptionConfig) -> ToolUseInterceptionWiring {
+        // Never called for a DriverOwned destination; the real guards are
+        // written by `hooks::write_settings`. Empty so a future generic
+        // caller fails safe.
+        ToolUseInterceptionWiring {
+            pre_tool_use_hooks: Vec::new(),
+        }
+    }
+
+    fn agent_rules_preamble(&self) -> &'static str {
+        GEMINI_AGENT_RULES_PREAMBLE
+    }
+
+    /// Route Boss worker rules to `$GEMINI_CLI_HOME/.gemini/GEMINI.md`
+    /// (global scope), leaving the repo's own `GEMINI.md` untouched.
+    fn agent_rules_destination(&self, _workspace: &Path, run_id: &str) -> PathBuf {
+        gemini_dir(&gemini_home_or_fallback(run_id)).join(GEMINI_DESCRIPTOR.agent_rules_filename)
+    }
+
+    fn transcript_path_for_session(&self, raw: &serde_json::Value) -> Option<String> {
+        transcript_path_from_payload(raw)
+    }
+
+    fn transcript_containment_root(&self, run_id: &str) -> anyhow::Result<Option<PathBuf>> {
+        let gemini_home = gemini_home_for_run(run_id)?;
+        match fs::canonicalize(&gemini_home) {
+            Ok(canonical) if canonical.is_dir() => Ok(Some(canonical)),
+            _ => Ok(None),
+        }
+    }
+
+    fn normalize_transcript_entry(&self, raw: serde_json::Value) -> serde_json::Value {
+        transcript::normalize_chat_record(raw)
+    }
+
+    fn extract_error_from_transcript(&self, _lines: &[serde_json::Value]) -> Option<String> {
+        None
+    }
+
+    fn classify_error(&self, raw_output: &str) -> WorkerErrorClass {
+        classify_gemini_error(raw_output)
+    }
+
+    /// Probe is typed pane input — the TUI reads it as the next user turn.
+    fn probe(&self) -> ProbeDelivery {
+        ProbeDelivery::PaneText
+    }
+
+    /// Interrupt is Esc into the pane, which cancels the in-flight turn
+    /// while the process survives.
+    fn interrupt(&self) -> InterruptDelivery {
+        InterruptDelivery::PaneEsc
+    }
+
+    /// Stop is `/quit` typed into the pane, then pane release.
+    fn stop(&self) -> StopDelivery {
+        StopDelivery::PaneCommand { command: "/quit" }
+    }
+
+    /// Reap is the universal SIGTERM→SIGKILL process-group ladder, which
+    /// covers the shells `run_shell_command` spawns.
+    fn reap(&self) -> ReapDelivery {
+        ReapDelivery::ProcessGroup
+    }
+
+    fn structured_output_wiring(
+        &self,
+        request: &StructuredOutputRequest<'_>,
+    ) -> anyhow::Result<StructuredOutputArtifacts> {
+        Ok(default_structured_output_wiring(request))
+    }
+
+    fn structured_output_fallback(&self, _kind: StructuredOutputKind, _text: &str) -> Vec<FallbackCandidate> {
+        Vec::new()
+    }
+}
+
+/// Extract the free text [`AgentDriver::pr_url_capture_feed`] scans from a
+/// Gemini `run_shell_command` `tool_response`: `llmContent` (the combined
+/// output handed back to the model) then `returnDisplay`, joined. A bare
+/// string is used as-is, mirroring [`crate::default_pr_url_capture_feed`].
+fn gemini_shell_output_text(tool_response: &Value) -> String {
+    if let Some(text) = tool_response.as_str() {
+        return text.to_owned();
+    }
+    ["llmContent", "returnDisplay"]
+        .iter()
+        .filter_map(|key| tool_response.get(*key).and_then(Value::as_str))
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AbsenceDisposition;
+    use serde_json::json;
+    use std::sync::Mutex;
+    use tempfile::TempDir;
+
+    static ENV_LOCK: &Mutex<()> = &GEMINI_HOMES_ENV_TEST_LOCK;
+
+    struct EnvGuard {
+        _lock: std::sync::MutexGuard<'static, ()>,
+    }
+
+    impl Drop for EnvGuard {
+        fn drop(&mut self) {
+            // SAFETY: serialised by ENV_LOCK.
+            unsafe {
+                std::env::remove_var(GEMINI_HOMES_ROOT_ENV);
+                std::env::remove_var(GEMINI_AUTH_SOURCE_ENV);
+            }
+        }
+    }
+
+    fn env_for(homes: &Path, auth: &Path) -> EnvGuard {
+        let lock = ENV_LOCK.lock().unwrap_or_else(|p| p.into_inner());
+        // SAFETY: serialised by ENV_LOCK.
+        unsafe {
+            std::env::set_var(GEMINI_HOMES_ROOT_ENV, homes);
+            std::env::set_var(GEMINI_AUTH_SOURCE_ENV, auth);
+        }
+        EnvGuard { _lock: lock }
+    }
+
+    fn spawn_request<'a>(model: &'a str, run_id: &'a str) -> SpawnRequest<'a> {
+        SpawnRequest {
+            model,
+            effort: None,
+            settings_path: None,
+            non_opus_auto_mode: false,
+            permission_mode_override: None,
+            run_id: Some(run_id),
+        }
+    }
+
+    fn permission_input(tmp: &Path, workspace: &Path, worker_kind: WorkerKind) -> PermissionInput {
+        PermissionInput {
+            worker_kind,
+            workspace_path: workspace.to_path_buf(),
+            events_socket_path: tmp.join("boss-data").join("events.sock"),
+            boss_event_path: tmp.join("boss-event"),
+            run_id: "run-1".into(),
+            lease_id: "lease-1".into(),
+            execution_kind: "task_implementation".into(),
+            task_kind: None,
+            is_remote: false,
+            path_guard_script: Some(tmp.join("boss-path-guard.py")),
+            checkleft_guard_script: None,
+            codex_sandbox_enforced: false,
+        }
+    }
+
+    /// Provision a run against a temp homes root and a fake OAuth login.
+    fn provisioned(tmp: &TempDir) -> (EnvGuard, PathBuf) {
+        let auth = tmp.path().join("auth");
+        fs::create_dir_all(&auth).unwrap();
+        fs::write(auth.join("oauth_creds.json"), "{}").unwrap();
+        let workspace = tmp.path().join("ws");
+        fs::create_dir_all(&workspace).unwrap();
+        let guard = env_for(&tmp.path().join("homes"), &auth);
+        provision_gemini_home(&workspace, "do the work", "run-1").unwrap();
+        (guard, workspace)
+    }
+
+    #[test]
+    fn gemini_descriptor_matches_design() {
+        let d = GeminiDriver::default();
+        let desc = d.descriptor();
+        assert_eq!(desc.name, "gemini");
+        assert_eq!(desc.binary, "gemini");
+        assert_eq!(desc.config_dir, ".gemini");
+        assert_eq!(desc.agent_rules_filename, "GEMINI.md");
+        assert_eq!(desc.model_menu.engine_default, "gemini-2.5-pro");
+        assert!((desc.model_menu.model_belongs_to_driver)("gemini-2.5-flash"));
+        assert!(!(desc.model_menu.model_belongs_to_driver)("grok-4.6"));
+    }
+
+    #[test]
+    fn gemini_declares_design_capability_set() {
+        let caps = GeminiDriver::default().capabilities();
+        for cap in [
+            Capability::ToolProvisioning,
+            Capability::AwaitingInputSignal,
+            Capability::CommandOutcomeObservation,
+        ] {
+            assert!(!caps.provides(cap), "{cap:?} must stay undeclared");
+            assert_eq!(caps.absence_disposition(cap), AbsenceDisposition::Degrade);
+        }
+        for cap in [
+            Capability::Spawn,
+            Capability::ToolUseInterception,
+            Capability::StructuredOutput,
+            Capability::TranscriptAccess,
+        ] {
+            assert!(caps.provides(cap), "{cap:?} must be declared");
+        }
+    }
+
+    #[test]
+    fn spawn_invocation_scopes_gemini_cli_home_and_reads_the_prompt_file() {
+        let tmp = TempDir::new().unwrap();
+        let _env = env_for(&tmp.path().join("homes"), &tmp.path().join("auth"));
+        let plan = GeminiDriver::default().spawn_invocation(spawn_request("gemini-2.5-flash", "run-1"));
+        assert_eq!(
+            plan.command,
+            "gemini --model 'gemini-2.5-flash' --approval-mode yolo \
+             --prompt-interactive \"$(cat .gemini/initial-prompt.txt)\"\n"
+        );
+        assert_eq!(
+            plan.env,
+            vec![EnvDirective::Set(
+                "GEMINI_CLI_HOME".into(),
+                gemini_home_for_run("run-1").unwrap().display().to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn agent_rules_destination_is_the_global_gemini_md() {
+        let tmp = TempDir::new().unwrap();
+        let _env = env_for(&tmp.path().join("homes"), &tmp.path().join("auth"));
+        let dest = GeminiDriver::default().agent_rules_destination(Path::new("/ws"), "run-1");
+        assert_eq!(dest, gemini_home_for_run("run-1").unwrap().join(".gemini/GEMINI.md"));
+    }
+
+    #[tokio::test]
+    async fn write_permission_config_requires_prior_provision() {
+        let tmp = TempDir::new().unwrap();
+        let _env = env_for(&tmp.path().join("homes"), &tmp.path().join("auth"));
+        let input = permission_input(tmp.path(), &tmp.path().join("ws"), WorkerKind::Standard);
+        let err = GeminiDriver::default()
+            .write_permission_config(&input, tmp.path())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("does not exist"), "{err:#}");
+    }
+
+    #[tokio::test]
+    async fn write_permission_config_writes_hooks_and_exclusions() {
+        let tmp = TempDir::new().unwrap();
+        let (_env, workspace) = provisioned(&tmp);
+        let input = permission_input(tmp.path(), &workspace, WorkerKind::Standard);
+        let artifacts = GeminiDriver::default()
+            .write_permission_config(&input, tmp.path())
+            .await
+            .unwrap();
+        assert_eq!(artifacts.extra_args, vec!["--approval-mode", "yolo"]);
+        assert_eq!(artifacts.env[0].0, "GEMINI_CLI_HOME");
+
+        let settings: Value = serde_json::from_str(&fs::read_to_string(&artifacts.config_files[0]).unwrap()).unwrap();
+        assert_eq!(settings["security"]["auth"]["selectedType"], "oauth-personal");
+        assert_eq!(settings["tools"]["enableHooks"], true);
+        let guards: Vec<&str> = settings["hooks"]["BeforeTool"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|entry| entry["hooks"][0]["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            guards,
+            [
+                "boss-event",
+                "boss-path-guard",
+                "boss-launch-guard",
+                "boss-pr-redirect-guard"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn write_permission_config_reviewer_loses_file_mutation_tools() {
+        let tmp = TempDir::new().unwrap();
+        let (_env, workspace) = provisioned(&tmp);
+        let input = permission_input(tmp.path(), &workspace, WorkerKind::Reviewer);
+        let artifacts = GeminiDriver::default()
+            .write_permission_config(&input, tmp.path())
+            .await
+            .unwrap();
+        let settings: Value = serde_json::from_str(&fs::read_to_string(&artifacts.config_files[0]).unwrap()).unwrap();
+        let excluded = settings["tools"]["exclude"].as_array().unwrap();
+        assert!(excluded.contains(&json!("write_file")));
+        assert!(excluded.contains(&json!("replace")));
+    }
+
+    #[tokio::test]
+    async fn write_permission_config_refuses_the_answer_agent() {
+        let tmp = TempDir::new().unwrap();
+        let (_env, workspace) = provisioned(&tmp);
+        let input = permission_input(tmp.path(), &workspace, WorkerKind::AnswerAgent);
+        let err = GeminiDriver::default()
+            .write_permission_config(&input, tmp.path())
+            .await
+            .unwrap_err();
+        assert!(format!("{err:#}").contains("answer agent"), "{err:#}");
+    }
+
+    #[test]
+    fn pr_url_capture_feed_reads_llm_content() {
+        let feed = GeminiDriver::default()
+            .pr_url_capture_feed(
+                "Bash",
+                &json!({"command": "cube pr create"}),
+                &json!({"llmContent": "Output: https://github.com/o/r/pull/7\nExit Code: 0", "returnDisplay": ""}),
+            )
+            .unwrap();
+        assert!(feed.output_text.contains("https://github.com/o/r/pull/7"));
+        assert!(
+            GeminiDriver::default()
+                .pr_url_capture_feed("Write", &json!({}), &json!({}))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn transcript_path_for_session_reads_transcript_path_field() {
+        let d = GeminiDriver::default();
+        assert_eq!(
+            d.transcript_path_for_session(&json!({"transcript_path": "/tmp/chat.json"})),
+            Some("/tmp/chat.json".into())
+        );
+        assert_eq!(d.transcript_path_for_session(&json!({"transcript_path": ""})), None);
+    }
+
+    #[test]
+    fn control_verb_delivery_plans() {
+        let d = GeminiDriver::default();
+        assert_eq!(d.probe(), ProbeDelivery::PaneText);
+        assert_eq!(d.interrupt(), InterruptDelivery::PaneEsc);
+        assert_eq!(d.stop(), StopDelivery::PaneCommand { command: "/quit" });
+        assert_eq!(d.reap(), ReapDelivery::ProcessGroup);
+    }
+
+    #[test]
+    fn classify_error_separates_quota_from_rate_limit() {
+        let d = GeminiDriver::default();
+        assert_eq!(
+            d.classify_error("Quota exceeded for quota metric 'Requests per day'"),
+            WorkerErrorClass::Permanent
+        );
+        assert_eq!(
+            d.classify_error("429 RESOURCE_EXHAUSTED: rate limit"),
+            WorkerErrorClass::Transient
+        );
+    }
+}
@@ -0,0 +1,190 @@
+//! Gemini / Google API error classification for
+//! [`crate::AgentDriver::classify_error`]. Must not route through
+//! `boss_engine_transient_error::classify_claude_error` — the Google error
+//! surface speaks gRPC status names (`RESOURCE_EXHAUSTED`, `UNAVAILABLE`,
+//! `UNAUTHENTICATED`, …) alongside HTTP codes, and its quota model differs
+//! from Anthropic's.
+//!
+//! ## Quota exhaustion is its own case
+//!
+//! Google reports both a per-minute rate limit and an exhausted daily quota
+//! as `429 RESOURCE_EXHAUSTED`. Treating them alike is the failure this
+//! module exists to avoid: a per-minute limit clears on its own and is worth
+//! retrying, but a worker that has burned the account's daily allowance
+//! would be redispatched straight back into the same wall. The daily/plan
+//! markers below are therefore checked first and classify as
+//! [`WorkerErrorClass::Permanent`]; a bare `RESOURCE_EXHAUSTED` / `429`
+//! without them falls through to [`WorkerErrorClass::Transient`].
+//!
+//! ## Classification rules
+//!
+//! - [`WorkerErrorClass::Permanent`] — exhausted daily/plan quota, failed
+//!   authentication (`UNAUTHENTICATED`, `PERMISSION_DENIED`, a rejected API
+//!   key or OAuth login), `INVALID_ARGUMENT` / unknown model, and an input
+//!   over the model's token limit.
+//! - [`WorkerErrorClass::Transient`] — per-minute rate limits, `UNAVAILABLE`
+//!   / `INTERNAL` / 5xx and overload, `DEADLINE_EXCEEDED`, plus generic
+//!   transport failures.
+//! - [`WorkerErrorClass::Indeterminate`] — anything else.
+
+use crate::WorkerErrorClass;
+
+/// Substrings (matched against a lowercased, space-padded haystack) marking
+/// an exhausted daily or plan quota. Checked before every other rule.
+const QUOTA_EXHAUSTED_MARKERS: &[&str] = &[
+    "per day",
+    "perday",
+    "daily quota",
+    "daily limit",
+    "exhausted your daily",
+    "quota for today",
+    "check your plan and billing",
+    "billing details",
+];
+
+/// Substrings marking any other **permanent**, non-retryable failure.
+const PERMANENT_MARKERS: &[&str] = &[
+    "unauthenticated",
+    "permission_denied",
+    "api key not valid",
+    "invalid api key",
+    "api_key_invalid",
+    "failed to login",
+    "login required",
+    " 401 ",
+    " 403 ",
+    "http 401",
+    "http 403",
+    "invalid_argument",
+    " 400 ",
+    "http 400",
+    "not_found",
+    "model not found",
+    " 404 ",
+    "http 404",
+    "exceeds the maximum number of tokens",
+    "input token count",
+    "context window",
+];
+
+/// Substrings marking a **transient**, retryable failure.
+const TRANSIENT_MARKERS: &[&str] = &[
+    "resource_exhausted",
+    "rate limit",
+    "ratelimit",
+    "too many requests",
+    "per minute",
+    " 429 ",
+    "http 429",
+    "unavailable",
+    "internal error",
+    "internal server error",
+    " 500 ",
+    " 502 ",
+    " 503 ",
+    " 504 ",
+    "http 500",
+    "http 502",
+    "http 503",
+    "http 504",
+    "overloaded",
+    "service unavailable",
+    "bad gateway",
+    "deadline_exceeded",
+    "deadline exceeded",
+    // Transport / connection — provider-agnostic.
+    "socket hang up",
+    "connection reset",
+    "econnreset",
+    "connection refused",
+    "econnrefused",
+    "network is unreachable",
+    "enetunreach",
+    "getaddrinfo",
+    "enotfound",
+    "name resolution",
+    "fetch failed",
+    "etimedout",
+    "timed out",
+];
+
+/// Classify a raw Gemini CLI / Google API error string. See the module docs
+/// for the rule set. Case-insensitive, substring-based; the haystack is
+/// space-padded so a bare status code at either end still matches `" 429 "`.
+pub fn classify_gemini_error(text: &str) -> WorkerErrorClass {
+    let haystack = format!(" {} ", text.to_lowercase());
+    if QUOTA_EXHAUSTED_MARKERS.iter().any(|m| haystack.contains(m)) {
+        return WorkerErrorClass::Permanent;
+    }
+    if PERMANENT_MARKERS.iter().any(|m| haystack.contains(m)) {
+        return WorkerErrorClass::Permanent;
+    }
+    if TRANSIENT_MARKERS.iter().any(|m| haystack.contains(m)) {
+        return WorkerErrorClass::Transient;
+    }
+    WorkerErrorClass::Indeterminate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn per_minute_rate_limits_and_server_errors_are_transient() {
+        for s in [
+            "[API Error: 429 Too Many Requests] RESOURCE_EXHAUSTED",
+            "Quota exceeded for quota metric 'Generate Content API requests per minute'",
+            "503 UNAVAILABLE: The model is overloaded. Please try again later.",
+            "500 INTERNAL: An internal error has occurred",
+            "DEADLINE_EXCEEDED",
+            "TypeError: fetch failed (ECONNRESET)",
+        ] {
+            assert_eq!(
+                classify_gemini_error(s),
+                WorkerErrorClass::Transient,
+                "expected transient for: {s}"
+            );
+        }
+    }
+
+    #[test]
+    fn daily_quota_exhaustion_is_permanent_not_a_rate_limit() {
+        for s in [
+            "429 RESOURCE_EXHAUSTED: Quota exceeded for quota metric 'Gemini 2.5 Pro Requests' \
+             and limit 'Gemini 2.5 Pro Requests per day'",
+            "You have exhausted your daily quota on this model.",
+            "You exceeded your current quota, please check your plan and billing details.",
+        ] {
+            assert_eq!(
+                classify_gemini_error(s),
+                WorkerErrorClass::Permanent,
+                "expected permanent for: {s}"
+            );
+        }
+    }
+
+    #[test]
+    fn auth_and_bad_request_failures_are_permanent() {
+        for s in [
+            "401 UNAUTHENTICATED: Request had invalid authentication credentials",
+            "400 INVALID_ARGUMENT: API key not valid. Please pass a valid API key.",
+            "403 PERMISSION_DENIED",
+            "404 NOT_FOUND: models/gemini-0.1 is not found",
+            "The input token count (1200000) exceeds the maximum number of tokens allowed (1048576).",
+        ] {
+            assert_eq!(
+                classify_gemini_error(s),
+                WorkerErrorClass::Permanent,
+                "expected permanent for: {s}"
+            );
+        }
+    }
+
+    #[test]
+    fn unrecognised_text_is_indeterminate() {
+        assert_eq!(
+            classify_gemini_error("something odd happened"),
+            WorkerErrorClass::Indeterminate
+        );
+    }
+}
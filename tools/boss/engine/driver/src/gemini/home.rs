@@ -0,0 +1,399 @@
+//! Boss-owned per-run `GEMINI_CLI_HOME`.
+//!
+//! Gemini CLI resolves every user-scope file — `settings.json`, the global
+//! `GEMINI.md`, `trustedFolders.json`, OAuth credentials and the chat
+//! recordings under `tmp/` — from `$GEMINI_CLI_HOME/.gemini/` when that
+//! variable is set, falling back to `~/.gemini/`. Pointing it at a per-run
+//! directory gives each worker its own hooks, rules and transcripts without
+//! scoping the process `HOME`, so the host `gh` / `jj` / `git` / `cube`
+//! state keeps working unchanged.
+//!
+//! Credentials are delegated by link, never copied: `oauth_creds.json` and
+//! `google_accounts.json` are symlinked from the operator's interactive
+//! Gemini home so every worker shares the one signed-in account and a token
+//! refresh written through any link is seen by all of them. An API-key
+//! login (`GEMINI_API_KEY` / `GOOGLE_API_KEY` in the engine environment)
+//! needs no file at all and is inherited by the pane.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, bail};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+/// Override for the root that holds per-run homes (tests).
+pub const GEMINI_HOMES_ROOT_ENV: &str = "BOSS_GEMINI_HOMES_DIR";
+
+/// Override for the directory the shared credentials are linked from
+/// (tests); defaults to the interactive `~/.gemini`.
+pub const GEMINI_AUTH_SOURCE_ENV: &str = "BOSS_GEMINI_AUTH_SOURCE_DIR";
+
+/// Serialises tests that mutate [`GEMINI_HOMES_ROOT_ENV`] /
+/// [`GEMINI_AUTH_SOURCE_ENV`].
+#[doc(hidden)]
+pub static GEMINI_HOMES_ENV_TEST_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+/// Directory name under `$TMPDIR` when [`GEMINI_HOMES_ROOT_ENV`] is unset.
+const GEMINI_HOMES_DIR_NAME: &str = "boss-gemini-homes";
+
+/// Gemini's user-scope directory name, under `GEMINI_CLI_HOME`.
+pub(super) const GEMINI_DIR: &str = ".gemini";
+
+/// Credential files linked from the interactive Gemini home. Only the first
+/// is required for an OAuth login; the second records the signed-in account
+/// and is linked when present.
+const CREDENTIAL_FILES: &[&str] = &["oauth_creds.json", "google_accounts.json"];
+
+/// Environment variables that authenticate Gemini CLI without a credential
+/// file.
+const API_KEY_ENVS: &[&str] = &["GEMINI_API_KEY", "GOOGLE_API_KEY"];
+
+// ---------------------------------------------------------------------------
+// Path resolution
+// ---------------------------------------------------------------------------
+
+/// Root directory that holds Boss-owned per-run Gemini homes.
+///
+/// [`GEMINI_HOMES_ROOT_ENV`] when set; otherwise `$TMPDIR/boss-gemini-homes`.
+/// Never the operator's interactive home.
+pub fn gemini_homes_root() -> PathBuf {
+    match std::env::var_os(GEMINI_HOMES_ROOT_ENV) {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => std::env::temp_dir().join(GEMINI_HOMES_DIR_NAME),
+    }
+}
+
+/// Absolute path of the per-run `GEMINI_CLI_HOME` for `run_id`: a strict
+/// child of [`gemini_homes_root`], one sanitised path segment deep.
+///
+/// Deterministic so `spawn_invocation`, `provision_workspace` and
+/// `write_permission_config` agree without threading the path through
+/// [`crate::SpawnRequest`].
+pub fn gemini_home_for_run(run_id: &str) -> anyhow::Result<PathBuf> {
+    if run_id.is_empty() {
+        bail!("empty run_id refused for Boss-owned GEMINI_CLI_HOME");
+    }
+    let safe: String = run_id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let root = gemini_homes_root();
+    let home = root.join(safe);
+    if !home.starts_with(&root) || home == root {
+        bail!(
+            "resolved Gemini home {} is not a strict child of homes root {}",
+            home.display(),
+            root.display()
+        );
+    }
+    Ok(home)
+}
+
+/// The `.gemini/` directory Gemini reads under `gemini_home`.
+pub fn gemini_dir(gemini_home: &Path) -> PathBuf {
+    gemini_home.join(GEMINI_DIR)
+}
+
+/// Directory the shared credential files are linked from.
+pub fn resolve_gemini_auth_source() -> PathBuf {
+    if let Ok(path) = std::env::var(GEMINI_AUTH_SOURCE_ENV) {
+        let path = path.trim();
+        if !path.is_empty() {
+            return PathBuf::from(path);
+        }
+    }
+    match std::env::var_os("HOME") {
+        Some(home) if !home.is_empty() => PathBuf::from(home).join(GEMINI_DIR),
+        _ => PathBuf::from(GEMINI_DIR),
+    }
+}
+
+fn api_key_in_environment() -> bool {
+    API_KEY_ENVS
+        .iter()
+        .any(|name| std::env::var_os(name).is_some_and(|value| !value.is_empty()))
+}
+
+// ---------------------------------------------------------------------------
+// Runtime state
+// ---------------------------------------------------------------------------
+
+/// Opaque payload persisted on the execution as [`crate::DriverRuntimeState`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GeminiRuntimeState {
+    pub gemini_home: PathBuf,
+    pub workspace_path: PathBuf,
+    /// Directory the credential links point into; `None` for an API-key
+    /// login that linked nothing.
+    pub auth_source_dir: Option<PathBuf>,
+}
+
+impl GeminiRuntimeState {
+    pub fn to_driver_runtime_state(&self) -> crate::DriverRuntimeState {
+        crate::DriverRuntimeState::new(serde_json::to_value(self).expect("GeminiRuntimeState is serializable"))
+    }
+
+    pub fn from_driver_runtime_state(state: &crate::DriverRuntimeState) -> anyhow::Result<Self> {
+        serde_json::from_value(state.as_value().clone()).context("decoding GeminiRuntimeState from DriverRuntimeState")
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Rendering
+// ---------------------------------------------------------------------------
+
+/// The user-scope `settings.json` every worker starts from. Hooks and the
+/// per-kind tool exclusions are layered on by `write_permission_config`
+/// (see [`super::hooks::write_settings`]).
+///
+/// - Auto-update, update nags, telemetry and checkpointing are off: a worker
+///   pane must not restart itself mid-run or write shadow git repos.
+/// - `selectedType` pins the auth flow so the first-run auth picker never
+///   opens in a pane nobody is watching.
+pub fn base_settings(oauth: bool) -> Value {
+    json!({
+        "general": {
+            "disableAutoUpdate": true,
+            "disableUpdateNag": true,
+            "checkpointing": {"enabled": false},
+        },
+        "privacy": {"usageStatisticsEnabled": false},
+        "security": {
+            "auth": {"selectedType": if oauth { "oauth-personal" } else { "gemini-api-key" }},
+        },
+        "ui": {"hideTips": true},
+    })
+}
+
+/// `trustedFolders.json` pre-trusting the leased workspace (both the path
+/// as given and its canonical form), so Gemini's folder-trust gate never
+/// disables project settings or prompts in the pane.
+pub fn render_trusted_folders_json(workspace: &Path) -> Value {
+    let mut trusted = serde_json::Map::new();
+    trusted.insert(workspace.display().to_string(), json!("TRUST_FOLDER"));
+    if let Ok(canonical) = fs::canonicalize(workspace) {
+        trusted.insert(canonical.display().to_string(), json!("TRUST_FOLDER"));
+    }
+    Value::Object(trusted)
+}
+
+// ---------------------------------------------------------------------------
+// Provision / teardown
+// ---------------------------------------------------------------------------
+
+/// Replace whatever sits at `dest` with a symlink to `source`.
+fn link_credential(source: &Path, dest: &Path) -> anyhow::Result<()> {
+    match fs::symlink_metadata(dest) {
+        Ok(metadata) if metadata.is_dir() => {
+            bail!(
+                "refusing to replace directory {} with a credential link",
+                dest.display()
+            )
+        }
+        Ok(_) => fs::remove_file(dest).with_context(|| format!("removing stale {}", dest.display()))?,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err).with_context(|| format!("stat {}", dest.display())),
+    }
+    #[cfg(unix)]
+    std::os::unix::fs::symlink(source, dest)
+        .with_context(|| format!("linking {} -> {}", dest.display(), source.display()))?;
+    #[cfg(not(unix))]
+    fs::copy(source, dest).with_context(|| format!("copying {} -> {}", source.display(), dest.display()))?;
+    Ok(())
+}
+
+/// Create / refresh the Boss-owned per-run Gemini home for `run_id`.
+///
+/// Idempotent: rewrites settings, trust, credential links and the prompt
+/// on every call, so a reused cube workspace always starts from Boss's
+/// posture. Refuses when no credential is available — an unauthenticated
+/// Gemini pane opens an interactive login nobody will complete.
+pub fn provision_gemini_home(workspace: &Path, prompt_text: &str, run_id: &str) -> anyhow::Result<GeminiRuntimeState> {
+    let gemini_home = gemini_home_for_run(run_id)?;
+    let dot_gemini = gemini_dir(&gemini_home);
+    let auth_source = resolve_gemini_auth_source();
+    if fs::canonicalize(&dot_gemini).ok() == fs::canonicalize(&auth_source).ok() && dot_gemini.exists() {
+        bail!("refusing to use the interactive Gemini home as Boss-owned GEMINI_CLI_HOME");
+    }
+    fs::create_dir_all(&dot_gemini).with_context(|| format!("creating {}", dot_gemini.display()))?;
+
+    let oauth_source = auth_source.join(CREDENTIAL_FILES[0]);
+    let auth_source_dir = if oauth_source.is_file() {
+        for name in CREDENTIAL_FILES {
+            let source = auth_source.join(name);
+            if source.is_file() {
+                link_credential(&source, &dot_gemini.join(name))?;
+            }
+        }
+        Some(auth_source.clone())
+    } else if api_key_in_environment() {
+        None
+    } else {
+        bail!(
+            "no Gemini credential: {} does not exist and neither {} is set; sign in once with \
+             interactive `gemini` or set {GEMINI_AUTH_SOURCE_ENV} for tests",
+            oauth_source.display(),
+            API_KEY_ENVS.join(" nor "),
+        );
+    };
+
+    let settings_path = dot_gemini.join("settings.json");
+    fs::write(
+        &settings_path,
+        serde_json::to_string_pretty(&base_settings(auth_source_dir.is_some()))?,
+    )
+    .with_context(|| format!("writing {}", settings_path.display()))?;
+    let trusted_path = dot_gemini.join("trustedFolders.json");
+    fs::write(
+        &trusted_path,
+        serde_json::to_string_pretty(&render_trusted_folders_json(workspace))?,
+    )
+    .with_context(|| format!("writing {}", trusted_path.display()))?;
+
+    let workspace_abs = fs::canonicalize(workspace).unwrap_or_else(|_| workspace.to_path_buf());
+    // Workspace-local `.gemini/`: initial prompt + catch-all gitignore.
+    // Worker rules go to `$GEMINI_CLI_HOME/.gemini/GEMINI.md` (global scope)
+    // via agent_rules_destination, so a repo's own `.gemini/` content and
+    // root `GEMINI.md` are left alone.
+    let config_dir = workspace.join(GEMINI_DIR);
+    fs::create_dir_all(&config_dir).with_context(|| format!("creating {}", config_dir.display()))?;
+    fs::write(config_dir.join("initial-prompt.txt"), prompt_text)
+        .with_context(|| format!("writing initial prompt to {}/initial-prompt.txt", config_dir.display()))?;
+    fs::write(config_dir.join(".gitignore"), "*\n")
+        .with_context(|| format!("writing gitignore under {}", config_dir.display()))?;
+
+    Ok(GeminiRuntimeState {
+        gemini_home,
+        workspace_path: workspace_abs,
+        auth_source_dir,
+    })
+}
+
+/// Refuse any `gemini_home` that is not a strict descendant of
+/// [`gemini_homes_root`] — the containment check teardown and reclaim run
+/// before touching a path decoded from persisted runtime state.
+pub fn assert_gemini_home_safe_to_delete(gemini_home: &Path) -> anyhow::Result<()> {
+    let root = gemini_homes_root();
+    let root_canon = fs::canonicalize(&root).unwrap_or_else(|_| root.clone());
+    let home_canon = fs::canonicalize(gemini_home).unwrap_or_else(|_| gemini_home.to_path_buf());
+    let contained = |base: &Path| home_canon.starts_with(base) && home_canon != base;
+    if gemini_home.as_os_str().is_empty() || !(contained(&root) || contained(&root_canon)) {
+        bail!(
+            "refusing teardown: gemini_home {} is not inside homes root {}",
+            gemini_home.display(),
+            root_canon.display()
+        );
+    }
+    Ok(())
+}
+
+/// Reclaim a per-run Gemini home once retention says it is eligible.
+/// Idempotent when already gone. `remove_dir_all` unlinks the credential
+/// symlinks rather than following them, so the shared login is untouched.
+pub fn reclaim_gemini_home(gemini_home: &Path) -> anyhow::Result<()> {
+    assert_gemini_home_safe_to_delete(gemini_home)?;
+    match fs::remove_dir_all(gemini_home) {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(err).with_context(|| format!("removing Boss-owned Gemini home {}", gemini_home.display())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct EnvGuard {
+        _lock: std::sync::MutexGuard<'static, ()>,
+    }
+
+    impl Drop for EnvGuard {
+        fn drop(&mut self) {
+            // SAFETY: serialised by GEMINI_HOMES_ENV_TEST_LOCK.
+            unsafe {
+                std::env::remove_var(GEMINI_HOMES_ROOT_ENV);
+                std::env::remove_var(GEMINI_AUTH_SOURCE_ENV);
+            }
+        }
+    }
+
+    fn env_for(homes: &Path, auth: &Path) -> EnvGuard {
+        let lock = GEMINI_HOMES_ENV_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        // SAFETY: serialised by GEMINI_HOMES_ENV_TEST_LOCK.
+        unsafe {
+            std::env::set_var(GEMINI_HOMES_ROOT_ENV, homes);
+            std::env::set_var(GEMINI_AUTH_SOURCE_ENV, auth);
+        }
+        EnvGuard { _lock: lock }
+    }
+
+    #[test]
+    fn provision_links_credentials_and_trusts_the_workspace() {
+        let tmp = tempfile::tempdir().unwrap();
+        let auth = tmp.path().join("auth");
+        fs::create_dir_all(&auth).unwrap();
+        fs::write(auth.join("oauth_creds.json"), "{}").unwrap();
+        let workspace = tmp.path().join("ws");
+        fs::create_dir_all(&workspace).unwrap();
+        let _env = env_for(&tmp.path().join("homes"), &auth);
+
+        let state = provision_gemini_home(&workspace, "do the work", "run-1").unwrap();
+        let dot_gemini = gemini_dir(&state.gemini_home);
+        let link = dot_gemini.join("oauth_creds.json");
+        assert!(fs::symlink_metadata(&link).unwrap().file_type().is_symlink());
+        assert_eq!(fs::read_link(&link).unwrap(), auth.join("oauth_creds.json"));
+        assert!(!dot_gemini.join("google_accounts.json").exists());
+
+        let trusted: Value =
+            serde_json::from_str(&fs::read_to_string(dot_gemini.join("trustedFolders.json")).unwrap()).unwrap();
+        assert_eq!(trusted[state.workspace_path.display().to_string()], "TRUST_FOLDER");
+        let settings: Value =
+            serde_json::from_str(&fs::read_to_string(dot_gemini.join("settings.json")).unwrap()).unwrap();
+        assert_eq!(settings["security"]["auth"]["selectedType"], "oauth-personal");
+        assert_eq!(
+            fs::read_to_string(workspace.join(".gemini/initial-prompt.txt")).unwrap(),
+            "do the work"
+        );
+
+        // Re-provisioning replaces the link in place.
+        provision_gemini_home(&workspace, "again", "run-1").unwrap();
+        assert!(fs::symlink_metadata(&link).unwrap().file_type().is_symlink());
+    }
+
+    #[test]
+    fn provision_refuses_without_any_credential() {
+        if api_key_in_environment() {
+            return;
+        }
+        let tmp = tempfile::tempdir().unwrap();
+        let workspace = tmp.path().join("ws");
+        fs::create_dir_all(&workspace).unwrap();
+        let _env = env_for(&tmp.path().join("homes"), &tmp.path().join("missing"));
+        let err = provision_gemini_home(&workspace, "p", "run-1").unwrap_err();
+        assert!(format!("{err:#}").contains("no Gemini credential"), "{err:#}");
+    }
+
+    #[test]
+    fn reclaim_refuses_paths_outside_the_homes_root() {
+        let tmp = tempfile::tempdir().unwrap();
+        let _env = env_for(&tmp.path().join("homes"), &tmp.path().join("auth"));
+        let outside = tmp.path().join("elsewhere");
+        fs::create_dir_all(&outside).unwrap();
+        assert!(reclaim_gemini_home(&outside).is_err());
+        assert!(outside.exists());
+        assert!(reclaim_gemini_home(&gemini_homes_root()).is_err());
+
+        let home = gemini_home_for_run("run/../x").unwrap();
+        assert!(home.starts_with(gemini_homes_root()));
+        fs::create_dir_all(&home).unwrap();
+        reclaim_gemini_home(&home).unwrap();
+        assert!(!home.exists());
+    }
+}
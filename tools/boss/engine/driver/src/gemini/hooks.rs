@@ -0,0 +1,527 @@
+//! Gemini hook wiring: the `boss-event` progress forwarder on every
+//! lifecycle event plus Boss's five `BeforeTool` guards, written into the
+//! per-run user-scope `settings.json` (never merged into the Claude worker
+//! settings file — see [`crate::HookWiringDestination::DriverOwned`]).
+//!
+//! The forwarder hands the raw Gemini payload to the unmodified `boss-event`
+//! shim, exactly as Claude's and Grok's do; [`super::progress`] decodes the
+//! dialect on the engine side.
+//!
+//! The guards need an adapter for the same reason Grok's do. Gemini's
+//! payload keys are already Claude-shaped, but its tool names are not
+//! (`run_shell_command`, `write_file`, `replace`), so a guard keyed on
+//! `tool_name == "Bash"` would wave every shell call through. And Gemini's
+//! `BeforeTool` verdicts are `allow` / `deny` (plus `ask`); a guard's
+//! `approve` or `block` is not Gemini vocabulary and must not be left to the
+//! CLI to interpret. [`GEMINI_HOOK_ADAPTER_SCRIPT`] translates in both
+//! directions and denies anything it cannot positively recognise — an
+//! unknown verdict, `ask`, unparseable output, a crash — so the failure mode
+//! is a refused tool call, never a guard that silently approves.
+//!
+//! Hooks are off in Gemini CLI unless `tools.enableHooks` is set; the
+//! settings written here always set it, and the per-run home is the only
+//! user-scope settings file the worker reads.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, bail};
+use boss_ssh_transport::shell_quote;
+use serde_json::{Value, json};
+
+use super::home::gemini_dir;
+use crate::claude::{BOSS_LAUNCH_GUARD_COMMAND, PR_REDIRECT_GUARD_COMMAND, REVISION_PR_GUARD_COMMAND};
+use crate::{ProgressObservationConfig, ToolUseInterceptionConfig};
+
+/// Filename of the guard adapter, under `$GEMINI_CLI_HOME/hooks/` — outside
+/// the repo and outside `.gemini/` so no project setting can shadow it.
+const ADAPTER_FILENAME: &str = "boss-gemini-hook-adapter.py";
+
+/// Every Gemini lifecycle event Boss forwards to `boss-event`: the seven
+/// that [`super::progress`] maps onto a `WorkerEvent`.
+const GEMINI_HOOK_EVENTS: &[&str] = &[
+    "SessionStart",
+    "BeforeAgent",
+    "BeforeTool",
+    "AfterTool",
+    "AfterAgent",
+    "Notification",
+    "SessionEnd",
+];
+
+/// Per-handler timeout for the passive forwarder, in **milliseconds**
+/// (Gemini's unit). Covers `boss-event`'s ~10.2s connect-retry budget plus
+/// headroom; on timeout Gemini logs and continues, which is right for a
+/// forwarder that gates nothing.
+pub const FORWARDER_HOOK_TIMEOUT_MS: u64 = 15_000;
+
+/// Gemini matcher that fires on every tool.
+const ALL_TOOLS: &str = "*";
+
+/// Gemini matcher for the shell tool the Bash-keyed guards care about.
+const SHELL_TOOL: &str = "run_shell_command";
+
+/// Same env-prefix shape [`crate::claude::ClaudeDriver`] and
+/// [`crate::grok::GrokDriver`] use for their forwarders.
+fn forwarder_command(config: &ProgressObservationConfig) -> String {
+    format!(
+        "BOSS_EVENTS_SOCKET={socket} BOSS_LEASE_ID={lease} BOSS_RUN_ID={run_id} BOSS_WORKSPACE={workspace} {shim}",
+        socket = shell_quote(&config.events_socket_path.display().to_string()),
+        lease = shell_quote(&config.lease_id),
+        run_id = shell_quote(&config.run_id),
+        workspace = shell_quote(&config.workspace_path.display().to_string()),
+        shim = shell_quote(&config.forwarder_binary.display().to_string()),
+    )
+}
+
+/// The forwarder-only hooks map. Returned by
+/// [`super::GeminiDriver::progress_observation_wiring`] and used as the
+/// starting point for [`write_settings`], which appends the guards after the
+/// forwarder on `BeforeTool` so every tool call is observed before a guard
+/// can block it.
+pub fn forwarder_hooks_map(config: &ProgressObservationConfig) -> serde_json::Map<String, Value> {
+    let forward_hook = json!({
+        "matcher": ALL_TOOLS,
+        "hooks": [{
+            "name": "boss-event",
+            "type": "command",
+            "command": forwarder_command(config),
+            "timeout": FORWARDER_HOOK_TIMEOUT_MS,
+        }],
+    });
+    GEMINI_HOOK_EVENTS
+        .iter()
+        .map(|event| ((*event).to_owned(), json!([forward_hook.clone()])))
+        .collect()
+}
+
+/// One unchanged Boss guard command plus the Gemini matcher that fires it.
+struct Guard {
+    name: &'static str,
+    command: String,
+    matcher: &'static str,
+}
+
+/// The five Boss guard commands, unchanged from Claude's and in the same
+/// order, omitted under the same rules (see `grok/hooks.rs`'s
+/// `guard_commands`). The Boss-launch guard is always present.
+fn guard_commands(config: &ToolUseInterceptionConfig) -> Vec<Guard> {
+    let mut out = Vec::new();
+    if let (Some(data_dir), Some(guard_script)) = (&config.data_dir, &config.path_guard_script) {
+        out.push(Guard {
+            name: "boss-path-guard",
+            command: format!(
+                "BOSS_DATA_DIR={dir} python3 {script}",
+                dir = shell_quote(&data_dir.display().to_string()),
+                script = shell_quote(&guard_script.display().to_string()),
+            ),
+            matcher: ALL_TOOLS,
+        });
+    }
+    out.push(Guard {
+        name: "boss-launch-guard",
+        command: BOSS_LAUNCH_GUARD_COMMAND.to_owned(),
+        matcher: SHELL_TOOL,
+    });
+    if config.is_standard_worker {
+        out.push(Guard {
+            name: "boss-pr-redirect-guard",
+            command: PR_REDIRECT_GUARD_COMMAND.to_owned(),
+            matcher: SHELL_TOOL,
+        });
+    }
+    if config.is_standard_worker
+        && let Some(checkleft_script) = &config.checkleft_guard_script
+    {
+        out.push(Guard {
+            name: "boss-checkleft-push-guard",
+            command: format!(
+                "python3 {script}",
+                script = shell_quote(&checkleft_script.display().to_string())
+            ),
+            matcher: SHELL_TOOL,
+        });
+    }
+    if config.is_revision {
+        out.push(Guard {
+            name: "boss-revision-pr-guard",
+            command: REVISION_PR_GUARD_COMMAND.to_owned(),
+            matcher: SHELL_TOOL,
+        });
+    }
+    out
+}
+
+/// Write the guard adapter and layer the full hook wiring plus
+/// `tools.exclude` onto the per-run `settings.json` that provisioning wrote.
+/// Returns the absolute paths written (for
+/// [`crate::PermissionArtifacts::config_files`]).
+pub fn write_settings(
+    gemini_home: &Path,
+    obs_config: &ProgressObservationConfig,
+    interception: &ToolUseInterceptionConfig,
+    excluded_tools: &[String],
+) -> anyhow::Result<Vec<PathBuf>> {
+    let hooks_dir = gemini_home.join("hooks");
+    fs::create_dir_all(&hooks_dir).with_context(|| format!("creating {}", hooks_dir.display()))?;
+    let adapter_path = hooks_dir.join(ADAPTER_FILENAME);
+    write_executable(&adapter_path, GEMINI_HOOK_ADAPTER_SCRIPT)?;
+
+    let guards = guard_commands(interception);
+    if guards.is_empty() {
+        bail!("GeminiDriver refuses to arm zero BeforeTool guards (ToolUseInterception declared)");
+    }
+    let mut hooks = forwarder_hooks_map(obs_config);
+    let before_tool = hooks
+        .entry("BeforeTool".to_owned())
+        .or_insert_with(|| Value::Array(Vec::new()))
+        .as_array_mut()
+        .expect("BeforeTool hook entry is always inserted as a JSON array");
+    for guard in guards {
+        before_tool.push(json!({
+            "matcher": guard.matcher,
+            "hooks": [{
+                "name": guard.name,
+                "type": "command",
+                "command": format!(
+                    "{adapter} {guard}",
+                    adapter = shell_quote(&adapter_path.display().to_string()),
+                    guard = shell_quote(&guard.command),
+                ),
+            }],
+        }));
+    }
+
+    let settings_path = gemini_dir(gemini_home).join("settings.json");
+    let mut settings: Value = match fs::read_to_string(&settings_path) {
+        Ok(raw) => serde_json::from_str(&raw).with_context(|| format!("parsing {}", settings_path.display()))?,
+        Err(err) => {
+            return Err(err)
+                .with_context(|| format!("reading {}; call provision_workspace first", settings_path.display()));
+        }
+    };
+    let Some(root) = settings.as_object_mut() else {
+        bail!("{} is not a JSON object", settings_path.display());
+    };
+    root.insert(
+        "tools".to_owned(),
+        json!({"enableHooks": true, "exclude": excluded_tools}),
+    );
+    root.insert("hooks".to_owned(), Value::Object(hooks));
+    fs::write(&settings_path, serde_json::to_string_pretty(&settings)?)
+        .with_context(|| format!("writing {}", settings_path.display()))?;
+
+    Ok(vec![settings_path, adapter_path])
+}
+
+fn write_executable(path: &Path, body: &str) -> anyhow::Result<()> {
+    fs::write(path, body).with_context(|| format!("writing {}", path.display()))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = fs::metadata(path)?.permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(path, perms).with_context(|| format!("chmod +x {}", path.display()))?;
+    }
+    Ok(())
+}
+
+/// The driver-owned guard adapter. Invoked as `<adapter> <guard-command>`
+/// with Gemini's raw `BeforeTool` payload on stdin; see the module docs.
+const GEMINI_HOOK_ADAPTER_SCRIPT: &str = r#"#!/usr/bin/env python3
+"""Gemini BeforeTool guard-script adapter.
+
+Boss's guard scripts are written for Claude Code's hook dialect: Claude tool
+names (Bash, Write, Edit, Read) and a {"decision": "block"|"approve"}
+vocabulary. Gemini CLI's payload keys already match, but its tool names do
+not (run_shell_command, write_file, replace, read_file), and its BeforeTool
+verdicts are allow / deny / ask.
+
+This script sits in front of an UNCHANGED guard command (argv[1], a full
+shell command line): it rewrites the payload into Boss's canonical shape,
+feeds it to the guard on stdin, and translates the guard's verdict into
+Gemini's vocabulary.
+
+Fails CLOSED (deny) whenever it cannot positively recognise something: a
+payload that is not a BeforeTool JSON object, a guard that crashes or
+prints no parseable verdict, or any verdict other than block / approve /
+deny / allow -- including "ask", which a pane nobody watches cannot answer.
+"""
+import json
+import subprocess
+import sys
+
+TOOL_NAME_MAP = {
+    "run_shell_command": "Bash",
+    "write_file": "Write",
+    "replace": "Edit",
+    "read_file": "Read",
+    "glob": "Glob",
+    "search_file_content": "Grep",
+}
+
+
+def fail_closed(reason):
+    sys.stderr.write("boss-gemini-hook-adapter: DENY (fail-closed): %s\n" % (reason,))
+    sys.stdout.write(json.dumps({"decision": "deny", "reason": reason}))
+    sys.exit(0)
+
+
+def canonicalise(payload):
+    out = dict(payload)
+    out["hook_event_name"] = "PreToolUse"
+    tool = out.get("tool_name")
+    out["tool_name"] = TOOL_NAME_MAP.get(tool, tool)
+    tool_input = out.get("tool_input")
+    # read_file names its path `absolute_path`; the path guard reads
+    # file_path / notebook_path / path.
+    if isinstance(tool_input, dict) and "file_path" not in tool_input and "absolute_path" in tool_input:
+        tool_input = dict(tool_input)
+        tool_input["file_path"] = tool_input["absolute_path"]
+        out["tool_input"] = tool_input
+    return out
+
+
+def translate_decision(raw_stdout, guard_exit_code, guard_stderr):
+    if guard_exit_code == 2:
+        fail_closed(guard_stderr.strip() or "guard exited 2")
+    try:
+        decision_obj = json.loads(raw_stdout)
+    except Exception:
+        fail_closed("guard produced non-JSON stdout (exit=%s): %r" % (guard_exit_code, raw_stdout[:500]))
+
+    decision = decision_obj.get("decision") if isinstance(decision_obj, dict) else None
+    reason = decision_obj.get("reason") if isinstance(decision_obj, dict) else None
+
+    if decision in ("block", "deny"):
+        out = {"decision": "deny"}
+        if reason is not None:
+            out["reason"] = reason
+        sys.stdout.write(json.dumps(out))
+        sys.exit(0)
+
+    if decision in ("approve", "allow"):
+        sys.stdout.write(json.dumps({"decision": "allow"}))
+        sys.exit(0)
+
+    fail_closed("guard emitted unrecognised decision %r (exit=%s)" % (decision, guard_exit_code))
+
+
+def main():
+    if len(sys.argv) != 2:
+        fail_closed("adapter invoked without exactly one guard-command argument")
+
+    raw_stdin = sys.stdin.read()
+    try:
+        payload = json.loads(raw_stdin)
+    except Exception:
+        fail_closed("stdin payload was not valid JSON: %r" % (raw_stdin[:500],))
+
+    if not isinstance(payload, dict):
+        fail_closed("stdin payload was not a JSON object")
+    if payload.get("hook_event_name") != "BeforeTool":
+        fail_closed("refusing to run a guard outside a Gemini BeforeTool hook (got %r)" % (payload.get("hook_event_name"),))
+
+    try:
+        result = subprocess.run(
+            ["/bin/sh", "-c", sys.argv[1]],
+            input=json.dumps(canonicalise(payload)),
+            capture_output=True,
+            text=True,
+        )
+    except Exception as exc:
+        fail_closed("failed to exec guard command: %r" % (exc,))
+        return
+
+    if result.stderr:
+        sys.stderr.write(result.stderr)
+
+    translate_decision(result.stdout, result.returncode, result.stderr)
+
+
+if __name__ == "__main__":
+    main()
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn obs_config() -> ProgressObservationConfig {
+        ProgressObservationConfig {
+            events_socket_path: PathBuf::from("/tmp/events.sock"),
+            lease_id: "lease-1".into(),
+            run_id: "run-1".into(),
+            workspace_path: PathBuf::from("/tmp/ws"),
+            forwarder_binary: PathBuf::from("/tmp/boss-event"),
+        }
+    }
+
+    fn interception_config(overrides: impl FnOnce(&mut ToolUseInterceptionConfig)) -> ToolUseInterceptionConfig {
+        let mut config = ToolUseInterceptionConfig {
+            data_dir: None,
+            path_guard_script: None,
+            checkleft_guard_script: None,
+            is_revision: false,
+            is_standard_worker: true,
+            run_id: Some("run-1".into()),
+            workspace_path: Some(PathBuf::from("/tmp/ws")),
+        };
+        overrides(&mut config);
+        config
+    }
+
+    #[test]
+    fn forwarder_hooks_map_covers_every_mapped_event_with_a_short_timeout() {
+        let hooks = forwarder_hooks_map(&obs_config());
+        for event in GEMINI_HOOK_EVENTS {
+            let entry = &hooks[*event][0];
+            let command = entry["hooks"][0]["command"].as_str().unwrap();
+            assert!(command.contains("BOSS_RUN_ID='run-1'"), "{command}");
+            assert_eq!(entry["hooks"][0]["timeout"], FORWARDER_HOOK_TIMEOUT_MS);
+        }
+    }
+
+    #[test]
+    fn guard_commands_match_the_claude_set_with_gemini_matchers() {
+        let config = interception_config(|c| {
+            c.data_dir = Some(PathBuf::from("/tmp/boss-data"));
+            c.path_guard_script = Some(PathBuf::from("/tmp/settings/boss-path-guard.py"));
+            c.checkleft_guard_script = Some(PathBuf::from("/tmp/settings/boss-checkleft-push-guard.py"));
+            c.is_revision = true;
+        });
+        let guards = guard_commands(&config);
+        assert_eq!(guards.len(), 5);
+        assert_eq!(guards[0].matcher, ALL_TOOLS);
+        assert_eq!(guards[1].command, BOSS_LAUNCH_GUARD_COMMAND);
+        assert!(guards[1..].iter().all(|g| g.matcher == SHELL_TOOL));
+        assert_eq!(guards[4].command, REVISION_PR_GUARD_COMMAND);
+
+        let reviewer = guard_commands(&interception_config(|c| c.is_standard_worker = false));
+        assert_eq!(reviewer.len(), 1, "only the boss-launch guard");
+    }
+
+    #[test]
+    fn write_settings_layers_hooks_and_exclusions_onto_provisioned_settings() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let home = tmp.path().join("home");
+        fs::create_dir_all(gemini_dir(&home)).unwrap();
+        fs::write(
+            gemini_dir(&home).join("settings.json"),
+            r#"{"general":{"disableAutoUpdate":true}}"#,
+        )
+        .unwrap();
+
+        let excluded = vec!["run_shell_command(sudo)".to_owned()];
+        let written = write_settings(
+            &home,
+            &obs_config(),
+            &interception_config(|c| c.is_standard_worker = false),
+            &excluded,
+        )
+        .unwrap();
+        assert_eq!(written.len(), 2);
+
+        let settings: Value = serde_json::from_str(&fs::read_to_string(&written[0]).unwrap()).unwrap();
+        assert_eq!(
+            settings["general"]["disableAutoUpdate"], true,
+            "base settings preserved"
+        );
+        assert_eq!(settings["tools"]["enableHooks"], true);
+        assert_eq!(settings["tools"]["exclude"], json!(["run_shell_command(sudo)"]));
+        let before_tool = settings["hooks"]["BeforeTool"].as_array().unwrap();
+        assert_eq!(before_tool.len(), 2, "forwarder + boss-launch guard");
+        let guard = before_tool[1]["hooks"][0]["command"].as_str().unwrap();
+        assert!(
+            guard.starts_with(&shell_quote(&written[1].display().to_string())),
+            "{guard}"
+        );
+    }
+
+    #[test]
+    fn write_settings_requires_provisioned_settings() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let err = write_settings(tmp.path(), &obs_config(), &interception_config(|_| {}), &[]).unwrap_err();
+        assert!(format!("{err:#}").contains("provision_workspace"), "{err:#}");
+    }
+
+    fn python3_available() -> bool {
+        std::process::Command::new("python3")
+            .arg("--version")
+            .output()
+            .map(|o| o.status.success())
+            .unwrap_or(false)
+    }
+
+    /// Run the real adapter against a stub guard command the way Gemini
+    /// invokes it, returning the verdict it printed.
+    fn adapter_verdict(guard_command: &str, payload: &Value) -> Value {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let adapter = tmp.path().join(ADAPTER_FILENAME);
+        write_executable(&adapter, GEMINI_HOOK_ADAPTER_SCRIPT).unwrap();
+        let mut child = std::process::Command::new("python3")
+            .arg(&adapter)
+            .arg(guard_command)
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .spawn()
+            .expect("spawn python3 adapter");
+        {
+            use std::io::Write;
+            child
+                .stdin
+                .as_mut()
+                .unwrap()
+                .write_all(payload.to_string().as_bytes())
+                .unwrap();
+        }
+        let output = child.wait_with_output().unwrap();
+        assert_eq!(output.status.code(), Some(0));
+        serde_json::from_slice(&output.stdout).expect("adapter prints a JSON verdict")
+    }
+
+    fn shell_payload(command: &str) -> Value {
+        json!({
+            "session_id": "s-1",
+            "hook_event_name": "BeforeTool",
+            "tool_name": "run_shell_command",
+            "tool_input": {"command": command},
+        })
+    }
+
+    #[test]
+    fn adapter_runs_the_unchanged_pr_redirect_guard_on_gemini_shell_calls() {
+        if !python3_available() {
+            eprintln!("python3 not available; skipping adapter test");
+            return;
+        }
+        let denied = adapter_verdict(PR_REDIRECT_GUARD_COMMAND, &shell_payload("gh pr create --title x"));
+        assert_eq!(denied["decision"], "deny", "{denied}");
+        let allowed = adapter_verdict(PR_REDIRECT_GUARD_COMMAND, &shell_payload("echo hello"));
+        assert_eq!(allowed["decision"], "allow", "{allowed}");
+    }
+
+    #[test]
+    fn adapter_fails_closed_on_unknown_verdicts_and_bad_input() {
+        if !python3_available() {
+            eprintln!("python3 not available; skipping adapter test");
+            return;
+        }
+        for guard in [
+            r#"echo '{"decision":"ask"}'"#,
+            r#"echo '{"decision":"force_ask"}'"#,
+            r#"echo '{"decision":"maybe"}'"#,
+            "echo not-json",
+            "exit 2",
+        ] {
+            let verdict = adapter_verdict(guard, &shell_payload("ls"));
+            assert_eq!(verdict["decision"], "deny", "{guard}: {verdict}");
+        }
+        let verdict = adapter_verdict(
+            r#"echo '{"decision":"approve"}'"#,
+            &json!({"hook_event_name": "AfterTool", "tool_name": "run_shell_command"}),
+        );
+        assert_eq!(verdict["decision"], "deny", "non-BeforeTool payloads are refused");
+    }
+}
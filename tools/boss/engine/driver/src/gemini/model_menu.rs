@@ -0,0 +1,83 @@
+//! Gemini model / effort menu tables.
+//!
+//! Gemini CLI takes a model via `--model` and has **no** reasoning-effort
+//! flag: thinking budget is a model-side setting the CLI does not expose per
+//! invocation. Boss's effort ladder is therefore expressed through model
+//! choice alone — the cheaper `flash` tier for the small rungs, `pro` for
+//! everything else — and [`effort_value_for_level`] returns `None` for every
+//! level so `resolve_spawn_config` never emits an effort knob Gemini would
+//! reject.
+//!
+//! # Refresh path
+//!
+//! `ModelMenu` is static function pointers, so this is a baked snapshot of
+//! the `gemini` model picker rather than a live listing. When Google moves
+//! the stable generation, update [`super::GEMINI_DESCRIPTOR`]'s
+//! `engine_default` together with [`PRO_MODEL`] / [`FLASH_MODEL`]. Preview
+//! models are never pinned here: they rotate without notice and a retired
+//! preview id fails the turn in-pane after spawn has already succeeded.
+
+use boss_protocol::{EffortLevel, ReasoningMode};
+
+/// Current stable `pro` tier — the engine default.
+pub(super) const PRO_MODEL: &str = "gemini-2.5-pro";
+
+/// Current stable `flash` tier — used for the Trivial/Small rungs.
+pub(super) const FLASH_MODEL: &str = "gemini-2.5-flash";
+
+/// Gemini CLI has no effort flag. Always `None`, so no rung is silently
+/// collapsed onto a knob that does not exist; see the module docs for how
+/// the level is expressed instead.
+pub(super) fn effort_value_for_level(_level: EffortLevel) -> Option<&'static str> {
+    None
+}
+
+/// Capability-lever model choice. Both reasoning modes resolve to `pro`:
+/// `flash` is a cost tier, not a capability lever, and design-family work
+/// must not land on it.
+pub(super) fn model_for_reasoning(_reasoning: ReasoningMode) -> &'static str {
+    PRO_MODEL
+}
+
+/// Legacy size-derived table, consulted only for rows with no
+/// [`ReasoningMode`].
+///
+/// | Boss [`EffortLevel`] | Model          |
+/// | -------------------- | -------------- |
+/// | `Trivial`            | [`FLASH_MODEL`] |
+/// | `Small`              | [`FLASH_MODEL`] |
+/// | `Medium`             | [`PRO_MODEL`]   |
+/// | `Large`              | [`PRO_MODEL`]   |
+/// | `Max`                | [`PRO_MODEL`]   |
+pub(super) fn default_model_for_level(level: EffortLevel) -> &'static str {
+    match level {
+        EffortLevel::Trivial | EffortLevel::Small => FLASH_MODEL,
+        EffortLevel::Medium | EffortLevel::Large | EffortLevel::Max => PRO_MODEL,
+    }
+}
+
+/// Optional per-level worker-prompt addendum. Same shape as the other
+/// drivers so operator-facing effort guidance is consistent.
+pub(super) fn prompt_addendum_for_level(level: EffortLevel) -> Option<&'static str> {
+    match level {
+        EffortLevel::Trivial | EffortLevel::Small => None,
+        EffortLevel::Medium => Some("Sketch a brief plan before you start editing."),
+        EffortLevel::Large | EffortLevel::Max => Some(
+            "Begin with a written plan. Identify the files you expect to touch and the \
+             order you'll touch them in. Confirm the approach against the work item's \
+             description before writing code.",
+        ),
+    }
+}
+
+/// Gemini has no Claude-style "auto permissions" model family. Always `false`.
+pub(super) fn model_requires_auto_permissions(_model: &str) -> bool {
+    false
+}
+
+/// Returns `true` iff `model` names a Gemini model (`"gemini-*"`).
+/// Case-insensitive. Guards against a Claude/Codex/Grok alias reaching the
+/// Gemini CLI verbatim.
+pub(super) fn model_belongs_to_driver(model: &str) -> bool {
+    model.to_ascii_lowercase().starts_with("gemini-")
+}
@@ -0,0 +1,83 @@
+//! Gemini permission posture: `--approval-mode` plus the `tools.exclude`
+//! list written into the per-run `settings.json`.
+//!
+//! Every pane runs `--approval-mode yolo` — an approval prompt in a pane
+//! nobody watches is a wedged worker — so the posture is expressed by what
+//! Gemini refuses to *offer* the model rather than what it asks about.
+//! `tools.exclude` accepts a bare tool name (removed from the model's tool
+//! list) or `run_shell_command(<prefix>)`, which rejects any shell command
+//! starting with that prefix.
+//!
+//! These exclusions complement the `BeforeTool` guards ([`super::hooks`]);
+//! the path guard is what fences off the Boss data dir, since Gemini has no
+//! path-scoped rule grammar.
+
+use anyhow::bail;
+
+use crate::WorkerKind;
+
+/// Shell prefixes no worker may run, mirroring the structural deny set the
+/// other drivers carry (`rm -rf`, `sudo`, `bossctl`).
+const STRUCTURAL_SHELL_EXCLUSIONS: &[&str] = &["rm -rf", "sudo", "bossctl"];
+
+/// File-mutation tools a read-only reviewer must not be offered. A
+/// shell-driven write still evades this — the same gap Grok's reviewer belt
+/// documents — which is why the reviewer's workspace is disposable.
+const REVIEWER_EXCLUDED_TOOLS: &[&str] = &["write_file", "replace"];
+
+/// The `tools.exclude` list for `worker_kind`.
+///
+/// Refuses [`WorkerKind::AnswerAgent`]: its posture is a deny-by-default
+/// allowlist, and Gemini's `tools.core` allowlist has not been characterised
+/// against the answer agent's reply command. Failing here keeps an
+/// allowlist-shaped worker from silently launching with a blocklist.
+pub fn excluded_tools(worker_kind: WorkerKind) -> anyhow::Result<Vec<String>> {
+    let mut out: Vec<String> = STRUCTURAL_SHELL_EXCLUSIONS
+        .iter()
+        .map(|prefix| format!("run_shell_command({prefix})"))
+        .collect();
+    match worker_kind {
+        WorkerKind::Standard | WorkerKind::Triage => {}
+        WorkerKind::Reviewer => out.extend(REVIEWER_EXCLUDED_TOOLS.iter().map(|tool| (*tool).to_owned())),
+        WorkerKind::AnswerAgent => {
+            bail!("GeminiDriver has no allowlist posture for the answer agent; dispatch it on another driver")
+        }
+    }
+    Ok(out)
+}
+
+/// The `--approval-mode` value for every pane. See the module docs.
+pub const APPROVAL_MODE: &str = "yolo";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_kind_carries_the_structural_shell_exclusions() {
+        for kind in [WorkerKind::Standard, WorkerKind::Triage, WorkerKind::Reviewer] {
+            let excluded = excluded_tools(kind).unwrap();
+            for rule in [
+                "run_shell_command(rm -rf)",
+                "run_shell_command(sudo)",
+                "run_shell_command(bossctl)",
+            ] {
+                assert!(excluded.iter().any(|r| r == rule), "{kind:?} missing {rule}");
+            }
+        }
+    }
+
+    #[test]
+    fn reviewer_loses_file_mutation_tools_and_standard_keeps_them() {
+        let reviewer = excluded_tools(WorkerKind::Reviewer).unwrap();
+        assert!(reviewer.iter().any(|r| r == "write_file"));
+        assert!(reviewer.iter().any(|r| r == "replace"));
+        let standard = excluded_tools(WorkerKind::Standard).unwrap();
+        assert!(!standard.iter().any(|r| r == "write_file" || r == "replace"));
+    }
+
+    #[test]
+    fn answer_agent_is_refused() {
+        assert!(excluded_tools(WorkerKind::AnswerAgent).is_err());
+    }
+}
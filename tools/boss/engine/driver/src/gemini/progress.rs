@@ -0,0 +1,223 @@
+//! Gemini progress normalisation: hook payload dialect -> `WorkerEvent`.
+//!
+//! Gemini CLI's hook payload is already snake_case (`session_id`,
+//! `transcript_path`, `hook_event_name`, `tool_name`, `tool_input`,
+//! `tool_response`), so unlike Grok there are no key renames. What differs
+//! is the vocabulary: Gemini names its lifecycle events after the agent loop
+//! (`BeforeAgent` / `AfterAgent` / `BeforeTool` / `AfterTool`) and its tools
+//! after its own built-ins (`run_shell_command`, `write_file`, `replace`, …).
+//! This module translates both and then delegates to the shared
+//! [`boss_protocol::normalize_hook_event`], the same entry point Claude uses
+//! directly.
+//!
+//! The `BeforeTool` guard adapter (`gemini/hooks.rs`) carries its own copy of
+//! the tool-name table: it runs as a separate Python process with its own
+//! trust boundary, and the two must not import from each other.
+
+use serde_json::{Map, Value};
+
+use boss_protocol::{NormalizeError, WorkerEvent, normalize_hook_event};
+
+use crate::ProgressSessionNormalizer;
+
+/// Gemini's `hook_event_name` values -> Boss's canonical names.
+///
+/// `AfterAgent` fires once the agent loop has produced its final response
+/// for a prompt — the same point Claude's `Stop` marks, and it carries
+/// `stop_hook_active` under the same name. `BeforeAgent` fires after the
+/// user submits a prompt and before planning, i.e. `UserPromptSubmit`.
+/// `BeforeModel` / `AfterModel` / `BeforeToolSelection` / `PreCompress` are
+/// wired by no one and fall through unchanged, coming back from
+/// [`normalize_hook_event`] as [`NormalizeError::UnknownEvent`].
+const EVENT_NAME_MAP: &[(&str, &str)] = &[
+    ("SessionStart", "SessionStart"),
+    ("BeforeAgent", "UserPromptSubmit"),
+    ("BeforeTool", "PreToolUse"),
+    ("AfterTool", "PostToolUse"),
+    ("AfterAgent", "Stop"),
+    ("Notification", "Notification"),
+    ("SessionEnd", "SessionEnd"),
+];
+
+/// Gemini built-in tool names -> Claude-canonical names, for the tools a
+/// downstream consumer keys on (`Bash` for PR-URL capture and the shell
+/// guards; `Write` / `Edit` / `Read` for the path guard and live status).
+/// Every other tool name is passed through unchanged.
+///
+/// `pub(super)`: `gemini/transcript.rs` reuses this table for the chat
+/// record dialect — Gemini's own vocabulary, not a cross-driver reuse.
+pub(super) const TOOL_NAME_MAP: &[(&str, &str)] = &[
+    ("run_shell_command", "Bash"),
+    ("write_file", "Write"),
+    ("replace", "Edit"),
+    ("read_file", "Read"),
+    ("glob", "Glob"),
+    ("search_file_content", "Grep"),
+];
+
+pub(super) fn mapped<'a>(table: &[(&'a str, &'a str)], key: &'a str) -> &'a str {
+    table
+        .iter()
+        .find(|(from, _)| *from == key)
+        .map(|(_, to)| *to)
+        .unwrap_or(key)
+}
+
+/// Rewrite one raw Gemini hook payload into the canonical shape
+/// [`normalize_hook_event`] expects: `hook_event_name` and `tool_name`
+/// translated, everything else copied verbatim.
+fn canonicalize(raw: &Value) -> Result<Value, NormalizeError> {
+    let obj = raw
+        .as_object()
+        .ok_or_else(|| NormalizeError::Malformed("expected Gemini hook JSON object".into()))?;
+
+    let mut out = Map::with_capacity(obj.len());
+    for (key, value) in obj {
+        let value = match key.as_str() {
+            "hook_event_name" => Value::String(mapped(EVENT_NAME_MAP, value.as_str().unwrap_or_default()).to_owned()),
+            "tool_name" => Value::String(mapped(TOOL_NAME_MAP, value.as_str().unwrap_or_default()).to_owned()),
+            _ => value.clone(),
+        };
+        out.insert(key.clone(), value);
+    }
+    Ok(Value::Object(out))
+}
+
+/// Canonicalise one raw Gemini hook payload and decode it via
+/// [`normalize_hook_event`]. An event with no `WorkerEvent` mapping is
+/// logged and returned as [`NormalizeError::UnknownEvent`] — ignored, not
+/// rejected; every other error propagates.
+fn normalize_gemini_hook_event(raw: &Value) -> Result<WorkerEvent, NormalizeError> {
+    let canonical = canonicalize(raw)?;
+    normalize_hook_event(&canonical).inspect_err(|err| {
+        if let NormalizeError::UnknownEvent(name) = err {
+            tracing::debug!(event = name, "gemini hook: ignoring event with no WorkerEvent mapping");
+        }
+    })
+}
+
+/// Per-reader normaliser for Gemini's hook-callback progress stream. Every
+/// payload is self-describing (own `session_id`, own `hook_event_name`), so
+/// there is no cross-record state to own.
+#[derive(Default)]
+pub(super) struct GeminiProgressSession {
+    _private: (),
+}
+
+impl GeminiProgressSession {
+    pub(super) fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ProgressSessionNormalizer for GeminiProgressSession {
+    fn normalize_progress_event(&mut self, raw: &Value) -> Result<WorkerEvent, NormalizeError> {
+        normalize_gemini_hook_event(raw)
+    }
+
+    fn transcript_path_for_session(&mut self, raw: &Value) -> Option<String> {
+        super::transcript_path_from_payload(raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use boss_protocol::StopReason;
+    use serde_json::json;
+
+    fn normalize(raw: Value) -> Result<WorkerEvent, NormalizeError> {
+        GeminiProgressSession::new().normalize_progress_event(&raw)
+    }
+
+    #[test]
+    fn before_tool_maps_to_pre_tool_use_with_canonical_tool_name() {
+        let event = normalize(json!({
+            "session_id": "s-1",
+            "transcript_path": "/tmp/chats/session.jsonl",
+            "hook_event_name": "BeforeTool",
+            "tool_name": "run_shell_command",
+            "tool_input": {"command": "ls"},
+        }))
+        .expect("normalizes");
+        assert_eq!(
+            event,
+            WorkerEvent::PreToolUse {
+                session_id: "s-1".into(),
+                tool_name: "Bash".into(),
+                tool_input: json!({"command": "ls"}),
+            }
+        );
+    }
+
+    #[test]
+    fn after_tool_keeps_tool_response() {
+        let event = normalize(json!({
+            "session_id": "s-1",
+            "hook_event_name": "AfterTool",
+            "tool_name": "write_file",
+            "tool_input": {"file_path": "a.txt", "content": "x"},
+            "tool_response": {"llmContent": "ok"},
+        }))
+        .expect("normalizes");
+        let WorkerEvent::PostToolUse {
+            tool_name,
+            tool_response,
+            ..
+        } = event
+        else {
+            panic!("expected PostToolUse, got {event:?}");
+        };
+        assert_eq!(tool_name, "Write");
+        assert_eq!(tool_response, json!({"llmContent": "ok"}));
+    }
+
+    #[test]
+    fn after_agent_is_the_turn_end() {
+        let event = normalize(json!({
+            "session_id": "s-1",
+            "hook_event_name": "AfterAgent",
+            "prompt": "do it",
+            "prompt_response": "done",
+            "stop_hook_active": false,
+        }))
+        .expect("normalizes");
+        assert_eq!(
+            event,
+            WorkerEvent::Stop {
+                session_id: "s-1".into(),
+                stop_hook_active: false,
+                stop_reason: StopReason::Completed,
+            }
+        );
+    }
+
+    #[test]
+    fn before_agent_is_the_prompt_submit() {
+        let event = normalize(json!({
+            "session_id": "s-1",
+            "hook_event_name": "BeforeAgent",
+            "prompt": "do it",
+        }))
+        .expect("normalizes");
+        assert_eq!(
+            event,
+            WorkerEvent::UserPromptSubmit {
+                session_id: "s-1".into(),
+                prompt: "do it".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn model_level_events_are_unknown_not_errors() {
+        let err = normalize(json!({"session_id": "s-1", "hook_event_name": "BeforeModel"})).unwrap_err();
+        assert!(matches!(err, NormalizeError::UnknownEvent(name) if name == "BeforeModel"));
+    }
+
+    #[test]
+    fn missing_session_id_is_rejected() {
+        let err = normalize(json!({"hook_event_name": "SessionStart"})).unwrap_err();
+        assert!(matches!(err, NormalizeError::MissingField("session_id")));
+    }
+}
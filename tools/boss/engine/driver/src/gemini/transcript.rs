@@ -0,0 +1,141 @@
+//! Gemini transcript normalisation: the chat-recording dialect Gemini CLI
+//! writes under `$GEMINI_CLI_HOME/.gemini/tmp/<project>/chats/` (the path
+//! stamped on every hook payload as `transcript_path`) -> the canonical
+//! redactable shape `transcript-markdown` and the live-status summariser
+//! expect (`content[].type` of `text`, `thinking`, or `tool_use`).
+//!
+//! One record per message:
+//!
+//! ```jsonl
+//! {"id":"…","timestamp":"…","type":"user","content":"do the work"}
+//! {"id":"…","timestamp":"…","type":"gemini","content":"Done.",
+//!  "thoughts":[{"subject":"Planning","description":"…"}],
+//!  "toolCalls":[{"id":"…","name":"run_shell_command","args":{"command":"ls"},
+//!                "status":"success","result":[…]}]}
+//! {"id":"…","timestamp":"…","type":"info","content":"…"}
+//! ```
+//!
+//! A `gemini` record carries its tool calls inline — input and result in one
+//! place — so there is no cross-record join to own and the normaliser is
+//! stateless, unlike Grok's `tool_call` / `tool_call_update` pair. `content`
+//! is either a bare string or a list of `{"text": …}` parts; both are
+//! accepted. `info` / `warning` / `error` records are CLI chrome and become
+//! `system` fillers so marker scans never read them as worker prose.
+
+use serde_json::{Map, Value, json};
+
+use super::progress::{TOOL_NAME_MAP, mapped};
+
+/// Flatten a chat record's `content` into plain text: a bare string, or the
+/// concatenated `text` of every part in a parts list.
+fn content_text(content: Option<&Value>) -> String {
+    match content {
+        Some(Value::String(text)) => text.clone(),
+        Some(Value::Array(parts)) => parts
+            .iter()
+            .filter_map(|part| part.get("text").and_then(Value::as_str))
+            .collect::<Vec<_>>()
+            .join(""),
+        _ => String::new(),
+    }
+}
+
+fn thought_text(thought: &Value) -> Option<String> {
+    let subject = thought.get("subject").and_then(Value::as_str).unwrap_or_default();
+    let description = thought.get("description").and_then(Value::as_str).unwrap_or_default();
+    match (subject.is_empty(), description.is_empty()) {
+        (true, true) => None,
+        (false, true) => Some(subject.to_owned()),
+        (true, false) => Some(description.to_owned()),
+        (false, false) => Some(format!("{subject}: {description}")),
+    }
+}
+
+fn assistant_record(obj: &Map<String, Value>) -> Value {
+    let mut blocks = Vec::new();
+    for thought in obj.get("thoughts").and_then(Value::as_array).into_iter().flatten() {
+        if let Some(text) = thought_text(thought) {
+            blocks.push(json!({"type": "thinking", "thinking": text}));
+        }
+    }
+    let text = content_text(obj.get("content"));
+    if !text.is_empty() {
+        blocks.push(json!({"type": "text", "text": text}));
+    }
+    for call in obj.get("toolCalls").and_then(Value::as_array).into_iter().flatten() {
+        let name = call.get("name").and_then(Value::as_str).unwrap_or_default();
+        blocks.push(json!({
+            "type": "tool_use",
+            "name": mapped(TOOL_NAME_MAP, name),
+            "input": call.get("args").cloned().unwrap_or(Value::Null),
+        }));
+    }
+    json!({"type": "assistant", "content": blocks})
+}
+
+/// Reshape one Gemini chat record into the canonical entry shape. Records
+/// this normaliser does not recognise (the session header, a future record
+/// type) are returned unchanged, which the canonical parser ignores.
+pub(super) fn normalize_chat_record(raw: Value) -> Value {
+    let Some(obj) = raw.as_object() else {
+        return raw;
+    };
+    match obj.get("type").and_then(Value::as_str) {
+        Some("user") => json!({"type": "user", "text": content_text(obj.get("content"))}),
+        Some("gemini") => assistant_record(obj),
+        Some(kind @ ("info" | "warning" | "error")) => json!({
+            "type": "system",
+            "subtype": kind,
+            "message": content_text(obj.get("content")),
+        }),
+        other => {
+            tracing::debug!(record_type = ?other, "gemini chat record: passing through unrecognised record");
+            raw
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gemini_record_becomes_assistant_thinking_text_and_tool_use() {
+        let out = normalize_chat_record(json!({
+            "id": "m-2",
+            "type": "gemini",
+            "content": [{"text": "All "}, {"text": "done."}],
+            "thoughts": [{"subject": "Planning", "description": "look at the tree"}],
+            "toolCalls": [{"id": "c-1", "name": "run_shell_command", "args": {"command": "ls"}, "status": "success"}],
+        }));
+        assert_eq!(
+            out,
+            json!({"type": "assistant", "content": [
+                {"type": "thinking", "thinking": "Planning: look at the tree"},
+                {"type": "text", "text": "All done."},
+                {"type": "tool_use", "name": "Bash", "input": {"command": "ls"}},
+            ]})
+        );
+    }
+
+    #[test]
+    fn user_record_becomes_user_text() {
+        assert_eq!(
+            normalize_chat_record(json!({"type": "user", "content": "do the work"})),
+            json!({"type": "user", "text": "do the work"})
+        );
+    }
+
+    #[test]
+    fn cli_chrome_records_become_system_fillers() {
+        let out = normalize_chat_record(json!({"type": "error", "content": "[API Error: 503]"}));
+        assert_eq!(out["type"], "system");
+        assert_eq!(out["subtype"], "error");
+    }
+
+    #[test]
+    fn session_header_passes_through() {
+        let header = json!({"sessionId": "s-1", "projectHash": "abc", "startTime": "2026-10-01T00:00:00Z"});
+        assert_eq!(normalize_chat_record(header.clone()), header);
+    }
+}
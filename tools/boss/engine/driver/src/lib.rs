@@ -2128,15 +2128,19 @@ pub trait AgentDriver: Send + Sync {
 
 pub mod claude;
 pub mod codex;
+pub mod gemini;
 pub mod grok;
 pub mod registry;
+pub mod scripted;
 
 // Also available in-module for trait signatures (pub use is an import + re-export).
 pub use boss_protocol::DriverRuntimeState;
 pub use claude::ClaudeDriver;
 pub use codex::CodexDriver;
+pub use gemini::GeminiDriver;
 pub use grok::GrokDriver;
 pub use registry::{DriverRegistry, UnknownDriverSlug};
+pub use scripted::ScriptedDriver;
 
 pub mod test_support;
 
@@ -6,9 +6,10 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use super::{
-    AgentDriver, CapabilityResolver, ClaudeDriver, CodexDriver, GrokDriver, ProgressIngress, ProgressObservationConfig,
-    WorkerProcessLifetime,
+    AgentDriver, CapabilityResolver, ClaudeDriver, CodexDriver, GeminiDriver, GrokDriver, ProgressIngress,
+    ProgressObservationConfig, ScriptedDriver, WorkerProcessLifetime,
 };
+use crate::scripted::{SCRIPTED_AGENT_SCRIPTS_ENV, SCRIPTED_DRIVER_SLUG};
 
 /// A driver slug the current binary's [`DriverRegistry`] does not recognise.
 ///
@@ -37,7 +38,8 @@ impl std::error::Error for UnknownDriverSlug {}
 /// normalise) looks the same slug up again rather than constructing a
 /// concrete driver type.
 ///
-/// The default registry contains all built-in drivers. Future drivers
+/// The default registry contains all built-in drivers, plus the test-only
+/// [`ScriptedDriver`] when `BOSS_SCRIPTED_AGENT_SCRIPTS` is set. Future drivers
 /// (e.g. `CopilotDriver`) register themselves here when added via
 /// [`Self::with_driver`] or by extending [`Default`].
 pub struct DriverRegistry {
@@ -50,6 +52,10 @@ impl Default for DriverRegistry {
         drivers.insert("claude", Arc::new(ClaudeDriver));
         drivers.insert("codex", Arc::new(CodexDriver::default()));
         drivers.insert("grok", Arc::new(GrokDriver::default()));
+        drivers.insert("gemini", Arc::new(GeminiDriver::default()));
+        if std::env::var_os(SCRIPTED_AGENT_SCRIPTS_ENV).is_some() {
+            drivers.insert(SCRIPTED_DRIVER_SLUG, Arc::new(ScriptedDriver::default()));
+        }
         for (slug, driver) in &drivers {
             refuse_stdout_jsonl_ingress(slug, driver.as_ref());
             refuse_one_turn_per_process(slug, driver.as_ref());
@@ -69,7 +75,7 @@ impl Default for DriverRegistry {
 /// named. Panicking here at registration turns that into a loud failure at
 /// startup instead of a silent hang discovered only via the staleness sweep.
 ///
-/// Deliberately probes only the three built-in slugs constructed above, not
+/// Deliberately probes only the built-in slugs constructed above, not
 /// [`DriverRegistry::with_driver`] generally: the shared JSONL reader crate
 /// (`boss-engine-stdout-progress`) legitimately registers synthetic
 /// `StdoutJsonl`-declaring test drivers to exercise that still-supported
@@ -106,7 +112,7 @@ fn refuse_stdout_jsonl_ingress(slug: &str, driver: &dyn AgentDriver) {
 /// driver author's untested assumption into a loud startup failure instead
 /// of a redispatch storm discovered in production.
 ///
-/// Deliberately probes only the three built-in slugs constructed above, not
+/// Deliberately probes only the built-in slugs constructed above, not
 /// [`DriverRegistry::with_driver`] generally, mirroring
 /// [`refuse_stdout_jsonl_ingress`].
 fn refuse_one_turn_per_process(slug: &str, driver: &dyn AgentDriver) {
@@ -210,6 +216,7 @@ impl DriverRegistry {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::{ScriptedAgentScriptsOverride, scripted_agent_scripts_override};
     use crate::test_support::{StubDriver, stub_descriptor};
     use crate::{Capability, CapabilitySet, SpawnRequest};
 
@@ -239,6 +246,16 @@ mod tests {
         assert_eq!(driver.descriptor().model_menu.engine_default, "grok-4.6");
     }
 
+    #[test]
+    fn default_registry_contains_gemini() {
+        let reg = DriverRegistry::default();
+        let driver = reg.require("gemini").expect("default registry must contain 'gemini'");
+        assert_eq!(driver.descriptor().name, "gemini");
+        assert_eq!(driver.descriptor().config_dir, ".gemini");
+        assert_eq!(driver.descriptor().agent_rules_filename, "GEMINI.md");
+        assert_eq!(driver.descriptor().binary, "gemini");
+    }
+
     /// Acceptance: `--driver codex` resolves in the registry and the
     /// dispatch capability gate evaluates against Codex's real `CapabilitySet`
     /// — not a registry-lookup failure.
@@ -458,7 +475,7 @@ mod tests {
     /// A driver that lacks a capability a kind marks required-strict is
     /// excluded — the mechanism the document-producing kinds rely on. Uses a
     /// stub with a deliberately thin `CapabilitySet` so the case is covered
-    /// even while all three built-in drivers happen to clear every gate.
+    /// even while every built-in driver happens to clear every gate.
     #[test]
     fn eligible_drivers_for_kind_excludes_a_driver_the_kind_refuses() {
         use boss_protocol::TaskKind;
@@ -504,7 +521,7 @@ mod tests {
         use boss_protocol::{ExecutionKind, TaskKind};
 
         let reg = DriverRegistry::default();
-        let candidates = ["codex", "claude", "grok"];
+        let candidates = ["codex", "claude", "grok", "gemini"];
         for execution_kind in [ExecutionKind::ConflictResolution, ExecutionKind::CiRemediation] {
             // The underlying task row can be any ordinary implementation
             // kind — the point is that the escalation comes from the
@@ -521,25 +538,40 @@ mod tests {
         // an ordinary chore.
         assert_eq!(
             reg.eligible_drivers_for_kind(&TaskKind::Chore, None, &candidates),
-            vec!["codex", "claude", "grok"],
+            vec!["codex", "claude", "grok", "gemini"],
         );
         assert_eq!(
             reg.eligible_drivers_for_kind(&TaskKind::Chore, Some(&ExecutionKind::ChoreImplementation), &candidates),
-            vec!["codex", "claude", "grok"],
+            vec!["codex", "claude", "grok", "gemini"],
         );
     }
 
     #[test]
     fn slugs_enumerates_every_registered_driver() {
+        let _scripts = ScriptedAgentScriptsOverride::unset();
         let reg = DriverRegistry::default();
         let mut slugs: Vec<&str> = reg.slugs().collect();
         slugs.sort_unstable();
-        assert_eq!(slugs, vec!["claude", "codex", "grok"]);
+        assert_eq!(slugs, vec!["claude", "codex", "gemini", "grok"]);
         for slug in reg.slugs() {
             assert!(reg.require(slug).is_ok(), "enumerated slug must resolve: {slug}");
         }
     }
 
+    #[test]
+    fn scripted_driver_registers_only_with_a_scripts_dir() {
+        {
+            let _scripts = ScriptedAgentScriptsOverride::unset();
+            assert!(DriverRegistry::default().get("scripted").is_none());
+        }
+        let reg = {
+            let _scripts = scripted_agent_scripts_override(std::path::Path::new("/tmp/scripts"));
+            DriverRegistry::default()
+        };
+        let driver = reg.require("scripted").expect("scripts dir must register 'scripted'");
+        assert_eq!(driver.descriptor().binary, "boss-scripted-agent");
+    }
+
     #[test]
     fn unknown_slug_returns_none() {
         let reg = DriverRegistry::default();
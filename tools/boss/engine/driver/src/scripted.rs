@@ -0,0 +1,498 @@
+//! `ScriptedDriver` — deterministic fake agent for end-to-end engine tests.
+//!
+//! Drives `boss-scripted-agent` (`tools/boss/scripted-agent`), which replays
+//! a YAML script instead of calling a model while speaking Claude's hook and
+//! transcript dialect. Progress and guard wiring therefore delegate to
+//! [`ClaudeDriver`] and land in the engine-rendered worker settings file, so
+//! the real `boss-event` forwarder, `PreToolUse` guards, structured-output
+//! env files, and PR-URL capture all run unchanged against it.
+//!
+//! The slug is test-only: [`crate::DriverRegistry::default`] registers it
+//! only when [`SCRIPTED_AGENT_SCRIPTS_ENV`] names a scripts directory, so a
+//! production engine never offers it.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context as _, bail};
+use async_trait::async_trait;
+use boss_engine_structured_output::StructuredOutputKind;
+use boss_engine_structured_output::fallback::FallbackCandidate;
+use boss_protocol::{EffortLevel, NormalizeError, PaneMonitorSpec, ReasoningMode, WorkerEvent, normalize_hook_event};
+use boss_ssh_transport::shell_quote;
+
+use super::{
+    AgentDriver, Capability, CapabilitySet, ClaudeDriver, DriverDescriptor, DriverRuntimeState, InterruptDelivery,
+    MidTurnPaneInput, ModelMenu, PermissionArtifacts, PermissionInput, ProbeDelivery, ProgressFidelity,
+    ProgressIngress, ProgressObservationConfig, ReapDelivery, SpawnPlan, SpawnRequest, StopDelivery,
+    StructuredOutputArtifacts, StructuredOutputRequest, ToolUseInterceptionConfig, ToolUseInterceptionWiring, TurnEnd,
+    WorkerErrorClass, default_structured_output_wiring,
+};
+
+/// Registry slug. Only registered when [`SCRIPTED_AGENT_SCRIPTS_ENV`] is set.
+pub const SCRIPTED_DRIVER_SLUG: &str = "scripted";
+
+/// Directory of `<name>.yaml` scripts. Setting it is what registers the
+/// driver.
+pub const SCRIPTED_AGENT_SCRIPTS_ENV: &str = "BOSS_SCRIPTED_AGENT_SCRIPTS";
+
+/// Optional absolute path to the `boss-scripted-agent` binary, for test
+/// harnesses that build it outside `PATH`.
+pub const SCRIPTED_AGENT_BIN_ENV: &str = "BOSS_SCRIPTED_AGENT_BIN";
+
+/// Prompt line that selects `<name>.yaml` from the scripts directory.
+/// Without one, `default.yaml` is replayed.
+pub const SCRIPT_SELECTOR_PREFIX: &str = "boss-scripted-agent-script:";
+
+const DEFAULT_SCRIPT: &str = "default";
+const SCRIPT_FILENAME: &str = "script.yaml";
+const SCRIPTED_MODEL: &str = "scripted";
+
+/// Crash-message markers a script's `exit` step uses to steer
+/// [`ScriptedDriver::classify_error`].
+const TRANSIENT_MARKER: &str = "[scripted:transient]";
+const PERMANENT_MARKER: &str = "[scripted:permanent]";
+
+// ---------------------------------------------------------------------------
+// Descriptor
+// ---------------------------------------------------------------------------
+
+fn effort_value_for_level(_level: EffortLevel) -> Option<&'static str> {
+    None
+}
+
+fn default_model_for_level(_level: EffortLevel) -> &'static str {
+    SCRIPTED_MODEL
+}
+
+fn model_for_reasoning(_reasoning: ReasoningMode) -> &'static str {
+    SCRIPTED_MODEL
+}
+
+fn prompt_addendum_for_level(_level: EffortLevel) -> Option<&'static str> {
+    None
+}
+
+fn model_requires_auto_permissions(_model: &str) -> bool {
+    false
+}
+
+/// `scripted` or any `scripted-<variant>`, so a test can pin a distinct
+/// model id per row and assert it reached the spawn command.
+fn model_belongs_to_driver(model: &str) -> bool {
+    model == SCRIPTED_MODEL || model.starts_with("scripted-")
+}
+
+static SCRIPTED_DESCRIPTOR: DriverDescriptor = DriverDescriptor {
+    name: SCRIPTED_DRIVER_SLUG,
+    label: "Scripted agent",
+    binary: "boss-scripted-agent",
+    config_dir: ".scripted",
+    agent_rules_filename: "AGENTS.md",
+    initial_prompt_filename: "initial-prompt.txt",
+    model_menu: ModelMenu {
+        engine_default: SCRIPTED_MODEL,
+        effort_value_for_level,
+        default_model_for_level,
+        model_for_reasoning,
+        prompt_addendum_for_level,
+        model_requires_auto_permissions,
+        model_belongs_to_driver,
+    },
+};
+
+const SCRIPTED_AGENT_RULES_PREAMBLE: &str = "You are running inside a Boss-managed worker session. The engine\n\
+     spawned a scripted test agent in a leased cube workspace and observes\n\
+     this session via Claude-compatible hooks. The agent replays a fixed\n\
+     script and does not read these rules.";
+
+// ---------------------------------------------------------------------------
+// ScriptedDriver
+// ---------------------------------------------------------------------------
+
+/// Deterministic fake-agent driver. Registered under `"scripted"` only when
+/// [`SCRIPTED_AGENT_SCRIPTS_ENV`] is set.
+#[derive(Default)]
+pub struct ScriptedDriver {
+    // Keep this type non-unit so callers can use `Default` uniformly with
+    // stateful drivers without tripping clippy's unit-default lint.
+    _private: (),
+}
+
+/// Build the pane command line.
+///
+/// ```text
+/// boss-scripted-agent --model … --settings '…'
+///     --script .scripted/script.yaml --prompt-file .scripted/initial-prompt.txt
+/// ```
+pub fn build_scripted_pane_command(request: &SpawnRequest<'_>) -> String {
+    let binary = std::env::var(SCRIPTED_AGENT_BIN_ENV).unwrap_or_else(|_| SCRIPTED_DESCRIPTOR.binary.to_owned());
+    let config_dir = SCRIPTED_DESCRIPTOR.config_dir;
+    let mut cmd = shell_quote(&binary);
+    cmd.push_str(" --model ");
+    cmd.push_str(&shell_quote(request.model));
+    if let Some(settings) = request.settings_path {
+        cmd.push_str(" --settings ");
+        cmd.push_str(&shell_quote(&settings.display().to_string()));
+    }
+    cmd.push_str(&format!(
+        " --script {config_dir}/{SCRIPT_FILENAME} --prompt-file {config_dir}/{}\n",
+        SCRIPTED_DESCRIPTOR.initial_prompt_filename
+    ));
+    cmd
+}
+
+/// Resolve the script for `prompt_text` inside `scripts_dir`: the first
+/// `boss-scripted-agent-script: <name>` line picks `<name>.yaml`, otherwise
+/// `default.yaml`. Names are bare file stems — anything that could escape
+/// the directory is refused.
+pub fn resolve_script(scripts_dir: &Path, prompt_text: &str) -> anyhow::Result<PathBuf> {
+    let name = prompt_text
+        .lines()
+        .find_map(|line| line.trim().strip_prefix(SCRIPT_SELECTOR_PREFIX))
+        .map(str::trim)
+        .unwrap_or(DEFAULT_SCRIPT);
+    if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\']) {
+        bail!("invalid scripted-agent script name {name:?}");
+    }
+    Ok(scripts_dir.join(format!("{name}.yaml")))
+}
+
+#[async_trait]
+impl AgentDriver for ScriptedDriver {
+    fn descriptor(&self) -> &DriverDescriptor {
+        &SCRIPTED_DESCRIPTOR
+    }
+
+    fn capabilities(&self) -> CapabilitySet {
+        // Everything Claude provides except ToolProvisioning: the scripted
+        // agent has no tool registry to inject into.
+        CapabilitySet::new([
+            Capability::Spawn,
+            Capability::WorkspaceProvisioning,
+            Capability::PermissionPolicy,
+            Capability::ModelAndEffortMenu,
+            Capability::ProgressObservation,
+            Capability::ToolUseInterception,
+            Capability::TurnBoundary,
+            Capability::StructuredOutput,
+            Capability::TranscriptAccess,
+            Capability::ControlVerbs,
+            Capability::PromptComposition,
+            Capability::AwaitingInputSignal,
+            Capability::CommandOutcomeObservation,
+        ])
+    }
+
+    fn pane_monitor_spec(&self) -> Option<PaneMonitorSpec> {
+        // Matches the banner, running line, and idle prompt
+        // `boss-scripted-agent` prints.
+        Some(PaneMonitorSpec {
+            agent_markers: vec!["boss-scripted-agent".into()],
+            busy_markers: vec!["[scripted: running]".into()],
+            starting_markers: Vec::new(),
+            prompt_prefixes: vec!["scripted> ".into()],
+            idle_debounce_polls: 2,
+        })
+    }
+
+    fn spawn_invocation(&self, request: SpawnRequest<'_>) -> SpawnPlan {
+        SpawnPlan {
+            env: Vec::new(),
+            command: build_scripted_pane_command(&request),
+        }
+    }
+
+    /// Copy the selected script to `.scripted/script.yaml` and write the
+    /// initial prompt and catch-all `.gitignore` beside it.
+    async fn provision_workspace(
+        &self,
+        workspace: &Path,
+        prompt_text: &str,
+        _run_id: &str,
+    ) -> anyhow::Result<Option<DriverRuntimeState>> {
+        let scripts_dir = std::env::var_os(SCRIPTED_AGENT_SCRIPTS_ENV)
+            .map(PathBuf::from)
+            .with_context(|| format!("{SCRIPTED_AGENT_SCRIPTS_ENV} is not set"))?;
+        let script = resolve_script(&scripts_dir, prompt_text)?;
+
+        let config_dir = workspace.join(SCRIPTED_DESCRIPTOR.config_dir);
+        std::fs::create_dir_all(&config_dir).with_context(|| format!("creating {}", config_dir.display()))?;
+        let script_dest = config_dir.join(SCRIPT_FILENAME);
+        std::fs::copy(&script, &script_dest)
+            .with_context(|| format!("copying script {} to {}", script.display(), script_dest.display()))?;
+
+        let prompt_path = config_dir.join(SCRIPTED_DESCRIPTOR.initial_prompt_filename);
+        std::fs::write(&prompt_path, prompt_text)
+            .with_context(|| format!("writing initial prompt to {}", prompt_path.display()))?;
+
+        let gitignore_path = config_dir.join(".gitignore");
+        std::fs::write(&gitignore_path, self.config_dir_gitignore())
+            .with_context(|| format!("writing gitignore to {}", gitignore_path.display()))?;
+        Ok(None)
+    }
+
+    /// No-op: all per-run state lives in the workspace.
+    async fn teardown_workspace(
+        &self,
+        _workspace: Option<&Path>,
+        _run_id: &str,
+        _runtime_state: Option<&DriverRuntimeState>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Same as Claude: the engine renders the worker settings file itself.
+    async fn write_permission_config(
+        &self,
+        _input: &PermissionInput,
+        _dest_dir: &Path,
+    ) -> anyhow::Result<PermissionArtifacts> {
+        Ok(PermissionArtifacts::default())
+    }
+
+    fn progress_fidelity(&self) -> ProgressFidelity {
+        ProgressFidelity::Rich
+    }
+
+    /// Claude's forwarder wiring, merged into the `--settings` file the
+    /// agent reads its hooks from.
+    fn progress_observation_wiring(&self, config: &ProgressObservationConfig) -> ProgressIngress {
+        ClaudeDriver.progress_observation_wiring(config)
+    }
+
+    fn normalize_progress_event(&self, raw: &serde_json::Value) -> Result<WorkerEvent, NormalizeError> {
+        normalize_hook_event(raw)
+    }
+
+    fn turn_boundary(&self, event: &WorkerEvent) -> Option<TurnEnd> {
+        ClaudeDriver.turn_boundary(event)
+    }
+
+    /// Claude's guards, so a script can assert a blocked tool call.
+    fn tool_use_interception_wiring(&self, config: &ToolUseInterceptionConfig) -> ToolUseInterceptionWiring {
+        ClaudeDriver.tool_use_interception_wiring(config)
+    }
+
+    fn agent_rules_preamble(&self) -> &'static str {
+        SCRIPTED_AGENT_RULES_PREAMBLE
+    }
+
+    fn transcript_path_for_session(&self, raw: &serde_json::Value) -> Option<String> {
+        let s = raw.get("transcript_path")?.as_str()?;
+        if s.is_empty() { None } else { Some(s.to_owned()) }
+    }
+
+    /// The agent writes Claude-shaped transcript lines.
+    fn normalize_transcript_entry(&self, raw: serde_json::Value) -> serde_json::Value {
+        raw
+    }
+
+    fn extract_error_from_transcript(&self, lines: &[serde_json::Value]) -> Option<String> {
+        boss_engine_transient_error::extract_worker_error(lines)
+    }
+
+    fn classify_error(&self, raw_output: &str) -> WorkerErrorClass {
+        if raw_output.contains(PERMANENT_MARKER) {
+            WorkerErrorClass::Permanent
+        } else if raw_output.contains(TRANSIENT_MARKER) {
+            WorkerErrorClass::Transient
+        } else {
+            WorkerErrorClass::Indeterminate
+        }
+    }
+
+    /// Typed lines reach the agent's idle prompt as a new turn.
+    fn probe(&self) -> ProbeDelivery {
+        ProbeDelivery::PaneText
+    }
+
+    fn interrupt(&self) -> InterruptDelivery {
+        InterruptDelivery::PaneEsc
+    }
+
+    /// `/quit` at the idle prompt fires `SessionEnd` and exits.
+    fn stop(&self) -> StopDelivery {
+        StopDelivery::PaneCommand { command: "/quit" }
+    }
+
+    fn reap(&self) -> ReapDelivery {
+        ReapDelivery::ProcessGroup
+    }
+
+    /// Lines typed while the script is still replaying wait in the pty
+    /// until the agent reaches its idle prompt.
+    fn mid_turn_pane_input(&self) -> MidTurnPaneInput {
+        MidTurnPaneInput::Buffers
+    }
+
+    fn structured_output_wiring(
+        &self,
+        request: &StructuredOutputRequest<'_>,
+    ) -> anyhow::Result<StructuredOutputArtifacts> {
+        Ok(default_structured_output_wiring(request))
+    }
+
+    fn structured_output_fallback(&self, _kind: StructuredOutputKind, _text: &str) -> Vec<FallbackCandidate> {
+        Vec::new()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AbsenceDisposition, HookWiringDestination};
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    fn spawn_request<'a>(model: &'a str, settings: Option<&'a Path>) -> SpawnRequest<'a> {
+        SpawnRequest {
+            model,
+            effort: None,
+            settings_path: settings,
+            non_opus_auto_mode: false,
+            permission_mode_override: None,
+            run_id: Some("run-1"),
+        }
+    }
+
+    #[test]
+    fn scripted_descriptor_and_model_menu() {
+        let desc = ScriptedDriver::default().descriptor().clone();
+        assert_eq!(desc.name, "scripted");
+        assert_eq!(desc.binary, "boss-scripted-agent");
+        assert_eq!(desc.config_dir, ".scripted");
+        assert_eq!(desc.model_menu.engine_default, "scripted");
+        assert!((desc.model_menu.model_belongs_to_driver)("scripted-fast"));
+        assert!(!(desc.model_menu.model_belongs_to_driver)("claude-opus-4"));
+        assert_eq!((desc.model_menu.effort_value_for_level)(EffortLevel::Large), None);
+    }
+
+    #[test]
+    fn scripted_omits_only_tool_provisioning() {
+        let caps = ScriptedDriver::default().capabilities();
+        assert!(!caps.provides(Capability::ToolProvisioning));
+        assert_eq!(
+            caps.absence_disposition(Capability::ToolProvisioning),
+            AbsenceDisposition::Degrade
+        );
+        assert!(caps.provides(Capability::CommandOutcomeObservation));
+        assert!(caps.provides(Capability::StructuredOutput));
+    }
+
+    #[test]
+    fn spawn_invocation_passes_settings_script_and_prompt() {
+        let settings = Path::new("/tmp/boss worker/settings.json");
+        let plan = ScriptedDriver::default().spawn_invocation(spawn_request("scripted", Some(settings)));
+        assert!(plan.env.is_empty());
+        assert!(
+            plan.command.ends_with(
+                " --model 'scripted' --settings '/tmp/boss worker/settings.json' \
+                 --script .scripted/script.yaml --prompt-file .scripted/initial-prompt.txt\n"
+            ),
+            "{}",
+            plan.command
+        );
+    }
+
+    #[test]
+    fn hook_wiring_lands_in_the_worker_settings_file() {
+        let config = ProgressObservationConfig {
+            events_socket_path: "/tmp/events.sock".into(),
+            lease_id: "lease-1".into(),
+            run_id: "run-1".into(),
+            workspace_path: "/ws".into(),
+            forwarder_binary: "/bin/boss-event".into(),
+        };
+        let ProgressIngress::HookCallback(wiring) = ScriptedDriver::default().progress_observation_wiring(&config)
+        else {
+            panic!("expected hook callback ingress");
+        };
+        assert_eq!(wiring.destination, HookWiringDestination::WorkerSettingsFile);
+        assert!(wiring.hooks.contains_key("Stop"));
+        assert!(wiring.hooks.contains_key("PreToolUse"));
+    }
+
+    #[test]
+    fn resolve_script_honours_the_prompt_selector() {
+        let dir = Path::new("/scripts");
+        assert_eq!(resolve_script(dir, "Fix it.\n").unwrap(), dir.join("default.yaml"));
+        assert_eq!(
+            resolve_script(dir, "Fix it.\nboss-scripted-agent-script: crash-transient\n").unwrap(),
+            dir.join("crash-transient.yaml")
+        );
+        for bad in ["../etc", "a/b", ".hidden", ""] {
+            let prompt = format!("{SCRIPT_SELECTOR_PREFIX} {bad}");
+            assert!(resolve_script(dir, &prompt).is_err(), "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn classify_error_reads_script_markers() {
+        let d = ScriptedDriver::default();
+        assert_eq!(
+            d.classify_error("[scripted:transient] boom"),
+            WorkerErrorClass::Transient
+        );
+        assert_eq!(
+            d.classify_error("[scripted:permanent] boom"),
+            WorkerErrorClass::Permanent
+        );
+        assert_eq!(d.classify_error("boom"), WorkerErrorClass::Indeterminate);
+    }
+
+    #[test]
+    fn transcript_path_for_session_reads_transcript_path_field() {
+        let d = ScriptedDriver::default();
+        assert_eq!(
+            d.transcript_path_for_session(&json!({"transcript_path": "/ws/.scripted/transcript.jsonl"})),
+            Some("/ws/.scripted/transcript.jsonl".into())
+        );
+        assert_eq!(d.transcript_path_for_session(&json!({})), None);
+    }
+
+    /// A plain `#[test]` driving its own runtime so the env override guard
+    /// is never held across an `.await` (`clippy::await_holding_lock`).
+    #[test]
+    fn provision_workspace_copies_the_selected_script() {
+        let tmp = TempDir::new().unwrap();
+        let scripts = tmp.path().join("scripts");
+        std::fs::create_dir_all(&scripts).unwrap();
+        std::fs::write(scripts.join("happy.yaml"), "steps: [stop]\n").unwrap();
+        let workspace = tmp.path().join("ws");
+        let _scripts = crate::test_support::scripted_agent_scripts_override(&scripts);
+        let state = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(ScriptedDriver::default().provision_workspace(
+                &workspace,
+                "boss-scripted-agent-script: happy\n",
+                "run-1",
+            ))
+            .unwrap();
+        assert!(state.is_none());
+
+        let dir = workspace.join(".scripted");
+        assert_eq!(
+            std::fs::read_to_string(dir.join("script.yaml")).unwrap(),
+            "steps: [stop]\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(dir.join("initial-prompt.txt")).unwrap(),
+            "boss-scripted-agent-script: happy\n"
+        );
+        assert_eq!(std::fs::read_to_string(dir.join(".gitignore")).unwrap(), "*\n");
+    }
+
+    #[test]
+    fn control_verb_delivery_plans() {
+        let d = ScriptedDriver::default();
+        assert_eq!(d.probe(), ProbeDelivery::PaneText);
+        assert_eq!(d.stop(), StopDelivery::PaneCommand { command: "/quit" });
+        assert_eq!(d.mid_turn_pane_input(), MidTurnPaneInput::Buffers);
+    }
+}
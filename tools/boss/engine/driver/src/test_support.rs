@@ -142,6 +142,54 @@ impl Drop for TranscriptStoreOverride {
     }
 }
 
+/// RAII override of [`crate::scripted::SCRIPTED_AGENT_SCRIPTS_ENV`],
+/// obtained from [`scripted_agent_scripts_override`]. Setting that variable
+/// is what registers the scripted driver in [`crate::DriverRegistry::default`],
+/// so any test asserting the exact default slug set takes
+/// [`ScriptedAgentScriptsOverride::unset`] first.
+pub struct ScriptedAgentScriptsOverride {
+    _lock: std::sync::MutexGuard<'static, ()>,
+    prior: Option<std::ffi::OsString>,
+}
+
+static SCRIPTED_AGENT_SCRIPTS_ENV_TEST_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+/// Point scripted-agent script resolution at `dir` (and register the
+/// scripted driver) for as long as the returned guard lives.
+pub fn scripted_agent_scripts_override(dir: &Path) -> ScriptedAgentScriptsOverride {
+    ScriptedAgentScriptsOverride::set(Some(dir))
+}
+
+impl ScriptedAgentScriptsOverride {
+    /// Clear the variable while retaining the shared environment lock.
+    pub fn unset() -> Self {
+        Self::set(None)
+    }
+
+    fn set(dir: Option<&Path>) -> Self {
+        let lock = SCRIPTED_AGENT_SCRIPTS_ENV_TEST_LOCK
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let prior = std::env::var_os(crate::scripted::SCRIPTED_AGENT_SCRIPTS_ENV);
+        // SAFETY: the process-wide lock above is held until this guard restores the variable.
+        match dir {
+            Some(dir) => unsafe { std::env::set_var(crate::scripted::SCRIPTED_AGENT_SCRIPTS_ENV, dir) },
+            None => unsafe { std::env::remove_var(crate::scripted::SCRIPTED_AGENT_SCRIPTS_ENV) },
+        }
+        Self { _lock: lock, prior }
+    }
+}
+
+impl Drop for ScriptedAgentScriptsOverride {
+    fn drop(&mut self) {
+        // SAFETY: this guard still holds the process-wide lock.
+        match self.prior.as_ref() {
+            Some(value) => unsafe { std::env::set_var(crate::scripted::SCRIPTED_AGENT_SCRIPTS_ENV, value) },
+            None => unsafe { std::env::remove_var(crate::scripted::SCRIPTED_AGENT_SCRIPTS_ENV) },
+        }
+    }
+}
+
 impl Drop for GrokHomesOverride {
     fn drop(&mut self) {
         // SAFETY: same as in `grok_homes_override` — the lock this guard
@@ -8,6 +8,7 @@ use tokio::sync::Notify;
 
 use crate::event::{Event, EventKind};
 use crate::filter::TopicFilter;
+use crate::journal::EventJournal;
 
 /// Default bounded mailbox size for a subscriber that doesn't request one
 /// explicitly. Matches the design doc's starting point; tunable per
@@ -34,10 +35,12 @@ enum SendOutcome {
 }
 
 struct MailboxState {
-    /// Queued (coalesce key, event) pairs, oldest first. The key travels
-    /// with its event so a pop can evict the matching `pending` entry in
-    /// O(1) instead of rebuilding the whole map.
-    queue: VecDeque<((EventKind, String), Event)>,
+    /// Queued (coalesce key, journal seq, event) triples, oldest first.
+    /// The key travels with its event so a pop can evict the matching
+    /// `pending` entry in O(1) instead of rebuilding the whole map. The
+    /// seq is the event's [`EventJournal`] position, or 0 on a bus
+    /// without one.
+    queue: VecDeque<((EventKind, String), u64, Event)>,
     /// For each (kind, coalesce key) with a pending event, its absolute
     /// sequence index (see `base`). At most one pending entry per key at
     /// any time: a later event for the same key always overwrites the
@@ -77,14 +80,20 @@ impl Mailbox {
         }
     }
 
-    fn try_send(&self, event: Event) -> SendOutcome {
+    fn try_send(&self, seq: u64, event: Event) -> SendOutcome {
         let mut state = self.state.lock().expect("event bus mailbox lock poisoned");
         let coalesce_key = (event.kind(), event.coalesce_key());
 
         if let Some(&abs_idx) = state.pending.get(&coalesce_key) {
             let idx = abs_idx - state.base;
             debug_assert!(idx < state.queue.len(), "pending index must stay in range");
-            state.queue[idx].1 = event;
+            // A journal replay can offer an event older than a live one
+            // already pending for the same key; newest still wins.
+            if state.queue[idx].1 > seq {
+                return SendOutcome::Coalesced;
+            }
+            state.queue[idx].1 = seq;
+            state.queue[idx].2 = event;
             drop(state);
             self.notify.notify_one();
             return SendOutcome::Coalesced;
@@ -95,7 +104,7 @@ impl Mailbox {
         }
 
         let abs_idx = state.base + state.queue.len();
-        state.queue.push_back((coalesce_key.clone(), event));
+        state.queue.push_back((coalesce_key.clone(), seq, event));
         state.pending.insert(coalesce_key, abs_idx);
         drop(state);
         self.notify.notify_one();
@@ -117,7 +126,7 @@ impl Mailbox {
         self.notify.notify_one();
     }
 
-    async fn recv(&self) -> Option<Event> {
+    async fn recv(&self) -> Option<(u64, Event)> {
         loop {
             {
                 let mut state = self.state.lock().expect("event bus mailbox lock poisoned");
@@ -135,11 +144,19 @@ impl Mailbox {
     /// Pop the oldest queued event (if any) and evict its `pending`
     /// entry, if still present, in O(1) — no rebuild of `pending` needed
     /// since indices are absolute and `base` tracks the front offset.
-    fn pop_front_locked(state: &mut MailboxState) -> Option<Event> {
-        let (key, event) = state.queue.pop_front()?;
+    fn pop_front_locked(state: &mut MailboxState) -> Option<(u64, Event)> {
+        let (key, seq, event) = state.queue.pop_front()?;
         state.pending.remove(&key);
         state.base += 1;
-        Some(event)
+        Some((seq, event))
+    }
+
+    /// Lowest journal seq still queued. Not necessarily the front's: a
+    /// coalesced overwrite keeps its older queue slot but takes the newer
+    /// event's seq.
+    fn min_pending_seq(&self) -> Option<u64> {
+        let state = self.state.lock().expect("event bus mailbox lock poisoned");
+        state.queue.iter().map(|(_, seq, _)| *seq).min()
     }
 }
 
@@ -156,6 +173,11 @@ struct Subscriber {
 /// publisher — the bus is best-effort by design, and every subscriber is
 /// expected to keep its own periodic backstop reconcile for whatever the
 /// bus drops.
+///
+/// A bus built with [`EventBus::durable`] additionally journals every
+/// publish to disk, and [`EventBus::subscribe_durable`] subscribers
+/// resume from a persisted cursor across engine restarts — see
+/// [`EventJournal`].
 pub struct EventBus {
     subscribers: Mutex<Vec<Subscriber>>,
     /// When set, every dropped event increments
@@ -163,6 +185,8 @@ pub struct EventBus {
     /// [`EventBus::new`]) skips metrics entirely, e.g. for unit tests
     /// that don't wire up a [`Registry`].
     metrics: Option<Arc<Registry>>,
+    /// Durable mode's on-disk log. `None` for the default in-memory bus.
+    journal: Option<Arc<EventJournal>>,
 }
 
 impl Default for EventBus {
@@ -176,6 +200,7 @@ impl EventBus {
         Self {
             subscribers: Mutex::new(Vec::new()),
             metrics: None,
+            journal: None,
         }
     }
 
@@ -184,9 +209,26 @@ impl EventBus {
         Self {
             subscribers: Mutex::new(Vec::new()),
             metrics: Some(registry),
+            journal: None,
         }
     }
 
+    /// Like [`EventBus::new`], but appends every published event to
+    /// `journal` and lets [`EventBus::subscribe_durable`] subscribers
+    /// replay what they missed across a restart.
+    pub fn durable(journal: EventJournal) -> Self {
+        Self {
+            subscribers: Mutex::new(Vec::new()),
+            metrics: None,
+            journal: Some(Arc::new(journal)),
+        }
+    }
+
+    /// Whether this bus was built with [`EventBus::durable`].
+    pub fn is_durable(&self) -> bool {
+        self.journal.is_some()
+    }
+
     /// Subscribe with the default mailbox capacity.
     pub fn subscribe(&self, filter: TopicFilter) -> Subscription {
         self.subscribe_with_capacity(filter, DEFAULT_MAILBOX_CAPACITY)
@@ -208,7 +250,115 @@ impl EventBus {
                 filter,
                 mailbox: mailbox.clone(),
             });
-        Subscription { mailbox }
+        Subscription { mailbox, cursor: None }
+    }
+
+    /// Subscribe as the durable subscriber `name` (a stable,
+    /// filename-safe identifier such as `automation_events`), with the
+    /// default mailbox capacity.
+    ///
+    /// On a durable bus, journaled events matching `filter` that were
+    /// published after `name`'s persisted cursor — i.e. ones a previous
+    /// engine process published but this subscriber never
+    /// [checkpointed](Subscription::checkpoint) — are queued ahead of live
+    /// traffic. A `name` with no cursor yet starts from the live tail
+    /// rather than replaying the whole retained log. Replay reads the
+    /// on-disk log, so a resubscribe within the same process (a
+    /// supervisor restart) can miss records the background writer has not
+    /// flushed; the restarted loop's own reconcile pass covers those, as
+    /// it always has.
+    ///
+    /// On an in-memory bus this is exactly [`EventBus::subscribe`], so a
+    /// call site need not care which mode the engine booted in.
+    pub fn subscribe_durable(&self, name: &str, filter: TopicFilter) -> Subscription {
+        let Some(journal) = &self.journal else {
+            return self.subscribe(filter);
+        };
+        debug_assert!(
+            !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-'),
+            "durable subscriber name must be filename-safe: {name:?}"
+        );
+        let mailbox = Arc::new(Mailbox::new(DEFAULT_MAILBOX_CAPACITY));
+        // Register before reading the journal: everything from `live_from`
+        // on reaches the mailbox live, everything before it via replay.
+        let live_from = {
+            let mut subscribers = self.subscribers.lock().expect("event bus subscriber lock poisoned");
+            subscribers.push(Subscriber {
+                filter: filter.clone(),
+                mailbox: mailbox.clone(),
+            });
+            journal.next_seq()
+        };
+        let tail = live_from.saturating_sub(1);
+
+        let committed = match journal.load_cursor(name) {
+            Ok(Some(cursor)) => {
+                self.replay(journal, name, &filter, &mailbox, cursor, live_from);
+                cursor
+            }
+            Ok(None) => {
+                if let Err(err) = journal.store_cursor(name, tail) {
+                    tracing::warn!(subscriber = name, ?err, "event bus: failed to seed durable cursor");
+                }
+                tail
+            }
+            Err(err) => {
+                tracing::warn!(
+                    subscriber = name,
+                    ?err,
+                    "event bus: unreadable durable cursor; resuming from the live tail",
+                );
+                tail
+            }
+        };
+
+        Subscription {
+            mailbox,
+            cursor: Some(DurableCursor {
+                journal: Arc::clone(journal),
+                name: name.to_owned(),
+                delivered: committed,
+                committed,
+            }),
+        }
+    }
+
+    fn replay(
+        &self,
+        journal: &EventJournal,
+        name: &str,
+        filter: &TopicFilter,
+        mailbox: &Mailbox,
+        after: u64,
+        before: u64,
+    ) {
+        let read = match journal.records_between(after, before) {
+            Ok(read) => read,
+            Err(err) => {
+                tracing::warn!(subscriber = name, ?err, "event bus: failed to read journal for replay");
+                return;
+            }
+        };
+        let mut replayed = 0usize;
+        for record in read.records {
+            if !filter.matches(&record.event) {
+                continue;
+            }
+            let kind = record.event.kind();
+            match mailbox.try_send(record.seq, record.event) {
+                SendOutcome::Dropped => self.record_drop(kind, 1),
+                SendOutcome::Sent | SendOutcome::Coalesced => replayed += 1,
+            }
+        }
+        if replayed > 0 || read.unreadable_lines > 0 {
+            tracing::info!(
+                subscriber = name,
+                after,
+                replayed,
+                unreadable_lines = read.unreadable_lines,
+                "event bus: replayed journal for durable subscriber",
+            );
+        }
     }
 
     /// Number of live subscribers currently registered, regardless of
@@ -237,6 +387,9 @@ impl EventBus {
         // fanning out so the drop counter stays a signal of real
         // backpressure, not orphaned subscribers.
         subscribers.retain(|subscriber| Arc::strong_count(&subscriber.mailbox) > 1);
+        // Journal under the subscribers lock so `seq` order is fan-out
+        // order — see `Subscription::checkpoint`.
+        let seq = self.journal.as_ref().map_or(0, |journal| journal.append(&event));
         // Tally drops locally instead of touching the registry (a heap
         // allocation plus its counters write lock) on every dropped
         // event while still holding the subscribers lock -- under a
@@ -249,7 +402,7 @@ impl EventBus {
             if !subscriber.filter.matches(&event) {
                 continue;
             }
-            if let SendOutcome::Dropped = subscriber.mailbox.try_send(event.clone()) {
+            if let SendOutcome::Dropped = subscriber.mailbox.try_send(seq, event.clone()) {
                 dropped += 1;
             }
         }
@@ -277,15 +430,62 @@ impl Drop for EventBus {
     }
 }
 
+/// A durable subscription's position in the journal.
+struct DurableCursor {
+    journal: Arc<EventJournal>,
+    name: String,
+    /// Highest seq `recv` has returned.
+    delivered: u64,
+    /// Last seq persisted.
+    committed: u64,
+}
+
 /// A reconciler's handle onto the events it subscribed for.
 pub struct Subscription {
     mailbox: Arc<Mailbox>,
+    /// `Some` only for [`EventBus::subscribe_durable`] on a durable bus.
+    cursor: Option<DurableCursor>,
 }
 
 impl Subscription {
     /// Await the next matching event. Returns `None` once the bus itself
     /// has been dropped and this subscription's mailbox has drained.
     pub async fn recv(&mut self) -> Option<Event> {
-        self.mailbox.recv().await
+        let (seq, event) = self.mailbox.recv().await?;
+        if let Some(cursor) = self.cursor.as_mut() {
+            cursor.delivered = cursor.delivered.max(seq);
+        }
+        Some(event)
+    }
+
+    /// Persist that every event `recv` has returned so far is handled, so
+    /// a restarted engine resumes after it. Call once the last event is
+    /// fully processed, not merely received. A no-op for a non-durable
+    /// subscription; a failed write is logged and retried on the next
+    /// call.
+    ///
+    /// The persisted cursor never passes an event still queued in the
+    /// mailbox: a coalesced overwrite can leave a higher-seq event ahead
+    /// of a lower-seq one, so having delivered seq N does not mean
+    /// everything below N was delivered.
+    pub fn checkpoint(&mut self) {
+        let Some(cursor) = self.cursor.as_mut() else {
+            return;
+        };
+        let safe = match self.mailbox.min_pending_seq() {
+            Some(pending) => cursor.delivered.min(pending.saturating_sub(1)),
+            None => cursor.delivered,
+        };
+        if safe <= cursor.committed {
+            return;
+        }
+        match cursor.journal.store_cursor(&cursor.name, safe) {
+            Ok(()) => cursor.committed = safe,
+            Err(err) => tracing::warn!(
+                subscriber = cursor.name.as_str(),
+                ?err,
+                "event bus: failed to persist durable cursor",
+            ),
+        }
     }
 }
@@ -1,9 +1,15 @@
+use serde::{Deserialize, Serialize};
+
 /// A state-transition fact published onto the bus. Events are hints, not
 /// commands — a subscriber re-reads authoritative state from the DB before
 /// acting, which is what keeps at-most-once, possibly-reordered delivery
 /// safe. Initial taxonomy from the event-bus design doc; new transitions
 /// add a variant here without touching unrelated topics.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Serialized (internally tagged by [`EventKind::topic_name`]) only by the
+/// optional [`crate::EventJournal`]; the in-memory path never touches serde.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "topic", rename_all = "snake_case")]
 pub enum Event {
     /// A task reached a terminal status (`done`/`archived`).
     TaskTerminal { task_id: String, project_id: String },
@@ -38,6 +44,27 @@ pub enum Event {
     /// today (the automation scheduler) reacts by recomputing its
     /// min-next-fire sleep from the DB rather than trusting event contents.
     AutomationMutation,
+    /// A task or project's stored status changed to `status`. Staged by
+    /// every status-transition write path alongside its dependency cascade.
+    WorkItemStatusChanged { work_item_id: String, status: String },
+    /// The product repo's default branch went from green (or unobserved) to
+    /// failing at `head_sha`.
+    DefaultBranchCiRed { product_id: String, head_sha: String },
+    /// The external-tracker reconciler imported a new upstream item as
+    /// `work_item_id`. `labels` are the upstream labels at import time.
+    UpstreamItemImported {
+        product_id: String,
+        work_item_id: String,
+        upstream_url: String,
+        labels: Vec<String>,
+    },
+    /// A local webhook named `name` was posted to the engine socket for
+    /// `product_id`, with an optional opaque `payload`.
+    AutomationWebhook {
+        product_id: String,
+        name: String,
+        payload: Option<String>,
+    },
 }
 
 /// The discriminant of an [`Event`], with no payload — what
@@ -56,6 +83,10 @@ pub enum EventKind {
     DispatchReady,
     Timer,
     AutomationMutation,
+    WorkItemStatusChanged,
+    DefaultBranchCiRed,
+    UpstreamItemImported,
+    AutomationWebhook,
 }
 
 impl EventKind {
@@ -77,6 +108,10 @@ impl EventKind {
             EventKind::DispatchReady => "dispatch_ready",
             EventKind::Timer => "timer",
             EventKind::AutomationMutation => "automation_mutation",
+            EventKind::WorkItemStatusChanged => "work_item_status_changed",
+            EventKind::DefaultBranchCiRed => "default_branch_ci_red",
+            EventKind::UpstreamItemImported => "upstream_item_imported",
+            EventKind::AutomationWebhook => "automation_webhook",
         }
     }
 }
@@ -96,6 +131,10 @@ impl Event {
             Event::DispatchReady => EventKind::DispatchReady,
             Event::Timer { .. } => EventKind::Timer,
             Event::AutomationMutation => EventKind::AutomationMutation,
+            Event::WorkItemStatusChanged { .. } => EventKind::WorkItemStatusChanged,
+            Event::DefaultBranchCiRed { .. } => EventKind::DefaultBranchCiRed,
+            Event::UpstreamItemImported { .. } => EventKind::UpstreamItemImported,
+            Event::AutomationWebhook { .. } => EventKind::AutomationWebhook,
         }
     }
 
@@ -107,6 +146,12 @@ impl Event {
     /// anyway). Events with no natural entity id (singleton signals
     /// like `DispatchReady`) share one fixed key so at most one stays
     /// pending at a time.
+    ///
+    /// `WorkItemStatusChanged` keys on the item *and* the status: each
+    /// status is a distinct fact an automation may be waiting for, so a
+    /// quick `in_review` → `done` must not let the later transition
+    /// overwrite the earlier one. `AutomationWebhook` likewise keys on the
+    /// webhook name, so two different hooks never collapse together.
     pub fn coalesce_key(&self) -> String {
         match self {
             Event::TaskTerminal { task_id, .. } => task_id.clone(),
@@ -121,6 +166,10 @@ impl Event {
             Event::DispatchReady => String::new(),
             Event::Timer { deadline_id } => deadline_id.clone(),
             Event::AutomationMutation => String::new(),
+            Event::WorkItemStatusChanged { work_item_id, status } => format!("{work_item_id}:{status}"),
+            Event::DefaultBranchCiRed { product_id, .. } => product_id.clone(),
+            Event::UpstreamItemImported { work_item_id, .. } => work_item_id.clone(),
+            Event::AutomationWebhook { product_id, name, .. } => format!("{product_id}:{name}"),
         }
     }
 }
@@ -36,6 +36,10 @@ impl TopicFilter {
             EventKind::DispatchReady,
             EventKind::Timer,
             EventKind::AutomationMutation,
+            EventKind::WorkItemStatusChanged,
+            EventKind::DefaultBranchCiRed,
+            EventKind::UpstreamItemImported,
+            EventKind::AutomationWebhook,
         ])
     }
 
@@ -0,0 +1,260 @@
+//! Optional durable mode for the bus: an append-only, day-rotated on-disk
+//! log of every published [`Event`] plus one persisted cursor per named
+//! durable subscriber.
+//!
+//! The journal reuses `boss-engine-day-rotated-log`: `publish` stays
+//! non-blocking because a journal append is a channel send to that crate's
+//! background writer. The log is one `events-<date>.jsonl` file per UTC
+//! day, pruned after [`RETAIN_DAYS`] like `ipc_log`, and each day file
+//! is capped at [`MAX_DAY_FILE_BYTES`]: a publish storm drops the rest of
+//! that day's records rather than growing the journal without bound. Each
+//! record carries a monotonically increasing `seq` that survives engine
+//! restarts (recovered from the newest retained record or cursor at
+//! [`EventJournal::open`]).
+//!
+//! A cursor is the highest `seq` a durable subscriber has fully handled;
+//! on resubscribe after a restart the bus replays retained records past
+//! it (see [`crate::EventBus::subscribe_durable`]), reading from the day
+//! file that holds the cursor onward rather than the whole journal. That
+//! is not exactly-once: a record handled after the last checkpoint is
+//! replayed again, and a crash can lose records the background writer had
+//! not flushed yet (as can the size cap). Events remain hints —
+//! subscribers keep re-reading authoritative state and keep their
+//! backstop sweeps; the journal only narrows the window those sweeps have
+//! to cover.
+
+use std::io::{self, BufRead};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use boss_engine_day_rotated_log::{DayRotatedLogger, RETAIN_DAYS, TimestampedRecord, now_ms, prune_old_files};
+use serde::{Deserialize, Serialize};
+
+use crate::event::Event;
+
+/// Directory under the engine state root the journal lives in.
+pub const JOURNAL_DIR_NAME: &str = "event-bus";
+/// Day-file prefix: `events-YYYY-MM-DD.jsonl`.
+const FILE_PREFIX: &str = "events-";
+/// Subdirectory holding one `<name>.cursor` file per durable subscriber.
+const CURSOR_DIR_NAME: &str = "cursors";
+/// Size cap for one day file; with [`RETAIN_DAYS`] it bounds the journal.
+const MAX_DAY_FILE_BYTES: u64 = 64 * 1024 * 1024;
+
+/// One journaled publish.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JournalRecord {
+    /// Position in the bus's publish order, unique across restarts.
+    pub seq: u64,
+    pub ts_epoch_ms: u128,
+    pub event: Event,
+}
+
+impl TimestampedRecord for JournalRecord {
+    fn ts_epoch_ms(&self) -> u128 {
+        self.ts_epoch_ms
+    }
+}
+
+/// Every retained record, in `seq` order, plus a count of lines that did
+/// not parse (a torn final line from a crash mid-write, or a record
+/// written by a newer engine with an unknown topic).
+#[derive(Debug, Default)]
+pub struct JournalRead {
+    pub records: Vec<JournalRecord>,
+    pub unreadable_lines: usize,
+}
+
+/// Handle the bus appends through. Construct with [`EventJournal::open`]
+/// and hand to [`crate::EventBus::durable`].
+pub struct EventJournal {
+    dir: PathBuf,
+    logger: DayRotatedLogger<JournalRecord>,
+    /// `seq` the next publish is assigned.
+    next_seq: AtomicU64,
+}
+
+impl EventJournal {
+    /// Open (creating if needed) the journal under `dir`, prune day files
+    /// past retention, and resume `seq` numbering after the highest value
+    /// found in any retained record or cursor — cursors count too, so a
+    /// journal whose files were all pruned never reissues a `seq` a
+    /// subscriber has already checkpointed past.
+    ///
+    /// Must be called inside a Tokio runtime: outside one the underlying
+    /// [`DayRotatedLogger`] spawns no writer and appends are dropped.
+    pub fn open(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(dir.join(CURSOR_DIR_NAME))?;
+        prune_old_files(&dir, FILE_PREFIX, RETAIN_DAYS);
+
+        let last_record = last_seq(&journal_files(&dir)?)?;
+        let last_cursor = max_cursor(&dir.join(CURSOR_DIR_NAME))?;
+        let next_seq = last_record.max(last_cursor).map_or(1, |seq| seq + 1);
+
+        Ok(Self {
+            logger: DayRotatedLogger::with_max_file_bytes(dir.clone(), FILE_PREFIX, MAX_DAY_FILE_BYTES),
+            dir,
+            next_seq: AtomicU64::new(next_seq),
+        })
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Assign `event` the next `seq` and queue it for the background
+    /// writer. The bus calls this under its subscriber lock, so `seq`
+    /// order is exactly fan-out order.
+    pub(crate) fn append(&self, event: &Event) -> u64 {
+        let seq = self.next_seq.fetch_add(1, Ordering::Relaxed);
+        self.logger.emit(JournalRecord {
+            seq,
+            ts_epoch_ms: now_ms(),
+            event: event.clone(),
+        });
+        seq
+    }
+
+    /// The `seq` the next publish will be assigned; every record below it
+    /// has already been handed to the writer.
+    pub(crate) fn next_seq(&self) -> u64 {
+        self.next_seq.load(Ordering::Relaxed)
+    }
+
+    /// Retained records with `seq` in `(after, before)`. Day files hold
+    /// ascending `seq` ranges, so reading starts at the newest file whose
+    /// first record is at or before `after + 1`; older files are never
+    /// opened past their first line.
+    pub(crate) fn records_between(&self, after: u64, before: u64) -> io::Result<JournalRead> {
+        let files = journal_files(&self.dir)?;
+        let mut start = 0;
+        for (index, file) in files.iter().enumerate().rev() {
+            if first_seq(file)?.is_some_and(|seq| seq <= after.saturating_add(1)) {
+                start = index;
+                break;
+            }
+        }
+        let mut read = read_files(&files[start..])?;
+        read.records.retain(|record| record.seq > after && record.seq < before);
+        Ok(read)
+    }
+
+    /// The persisted cursor for `name`, or `None` for a subscriber that
+    /// has never checkpointed.
+    pub(crate) fn load_cursor(&self, name: &str) -> io::Result<Option<u64>> {
+        read_cursor(&self.dir, name)
+    }
+
+    /// Persist `seq` as `name`'s cursor via write-then-rename, so a crash
+    /// mid-write leaves the previous cursor rather than a torn one.
+    pub(crate) fn store_cursor(&self, name: &str, seq: u64) -> io::Result<()> {
+        let path = self.cursor_path(name);
+        let tmp = path.with_extension("cursor.tmp");
+        std::fs::write(&tmp, format!("{seq}\n"))?;
+        std::fs::rename(&tmp, &path)
+    }
+
+    fn cursor_path(&self, name: &str) -> PathBuf {
+        cursor_path(&self.dir, name)
+    }
+}
+
+fn cursor_path(dir: &Path, name: &str) -> PathBuf {
+    dir.join(CURSOR_DIR_NAME).join(format!("{name}.cursor"))
+}
+
+/// The persisted cursor of durable subscriber `name` under journal
+/// directory `dir`, or `None` if it has never checkpointed.
+pub fn read_cursor(dir: &Path, name: &str) -> io::Result<Option<u64>> {
+    match std::fs::read_to_string(cursor_path(dir, name)) {
+        Ok(raw) => raw
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, format!("cursor {name:?}: {err}"))),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Read every retained record under `dir` (the journal directory, i.e.
+/// `<state_root>/`[`JOURNAL_DIR_NAME`]), sorted by `seq`. A missing
+/// directory reads as empty. Used by the bus for replay and by
+/// `bossctl events replay` for forensics; it never writes.
+pub fn read_journal(dir: &Path) -> io::Result<JournalRead> {
+    read_files(&journal_files(dir)?)
+}
+
+/// The journal's day files, oldest first. A missing directory has none.
+fn journal_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    match std::fs::read_dir(dir) {
+        Ok(entries) => {
+            for entry in entries {
+                let entry = entry?;
+                let name = entry.file_name().to_string_lossy().into_owned();
+                if name.starts_with(FILE_PREFIX) && name.ends_with(".jsonl") {
+                    files.push(entry.path());
+                }
+            }
+        }
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err),
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn read_files(files: &[PathBuf]) -> io::Result<JournalRead> {
+    let mut read = JournalRead::default();
+    for file in files {
+        let content = std::fs::read_to_string(file)?;
+        for line in content.lines().filter(|line| !line.trim().is_empty()) {
+            match serde_json::from_str::<JournalRecord>(line) {
+                Ok(record) => read.records.push(record),
+                Err(_) => read.unreadable_lines += 1,
+            }
+        }
+    }
+    read.records.sort_by_key(|record| record.seq);
+    Ok(read)
+}
+
+/// `seq` of the first readable record in `file`, reading no further.
+fn first_seq(file: &Path) -> io::Result<Option<u64>> {
+    for line in io::BufReader::new(std::fs::File::open(file)?).lines() {
+        if let Ok(record) = serde_json::from_str::<JournalRecord>(&line?) {
+            return Ok(Some(record.seq));
+        }
+    }
+    Ok(None)
+}
+
+/// The highest `seq` in the newest day file that has a readable record.
+fn last_seq(files: &[PathBuf]) -> io::Result<Option<u64>> {
+    for file in files.iter().rev() {
+        let last = read_files(std::slice::from_ref(file))?
+            .records
+            .last()
+            .map(|record| record.seq);
+        if last.is_some() {
+            return Ok(last);
+        }
+    }
+    Ok(None)
+}
+
+fn max_cursor(cursor_dir: &Path) -> io::Result<Option<u64>> {
+    let mut max = None;
+    for entry in std::fs::read_dir(cursor_dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("cursor") {
+            continue;
+        }
+        if let Ok(seq) = std::fs::read_to_string(&path)?.trim().parse::<u64>() {
+            max = max.max(Some(seq));
+        }
+    }
+    Ok(max)
+}
@@ -8,17 +8,21 @@
 //! `engine-event-bus-event-driven-reconcilers-via-an-in-process-message-queue.md`.
 //! No transition may depend on the bus alone for correctness; every
 //! subscriber keeps its existing periodic sweep as a backstop that
-//! recovers any event the bus drops.
+//! recovers any event the bus drops. The optional durable mode
+//! ([`EventJournal`]) narrows what a restart loses without changing that
+//! contract.
 
 mod bus;
 mod event;
 mod filter;
+mod journal;
 mod supervisor;
 
 pub use boss_engine_metrics_registry::Registry;
 pub use bus::{EventBus, Subscription};
 pub use event::{Event, EventKind};
 pub use filter::TopicFilter;
+pub use journal::{EventJournal, JOURNAL_DIR_NAME, JournalRead, JournalRecord, read_cursor, read_journal};
 pub use supervisor::spawn_supervised;
 
 #[cfg(test)]
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 use std::time::Duration;
 
-use crate::{Event, EventBus, EventKind, Registry, TopicFilter};
+use crate::{Event, EventBus, EventJournal, EventKind, Registry, TopicFilter, read_journal};
 
 #[tokio::test]
 async fn subscriber_receives_matching_event() {
@@ -127,6 +127,32 @@ async fn full_mailbox_coalesces_same_key_event_instead_of_dropping() {
     );
 }
 
+#[tokio::test]
+async fn distinct_statuses_of_one_work_item_do_not_coalesce() {
+    // An automation may be waiting for `in_review` specifically; a quick
+    // follow-on `done` for the same item must queue behind it rather than
+    // overwrite it.
+    let bus = EventBus::new();
+    let mut sub = bus.subscribe(TopicFilter::kind(EventKind::WorkItemStatusChanged));
+
+    for status in ["in_review", "done"] {
+        bus.publish(Event::WorkItemStatusChanged {
+            work_item_id: "task_1".to_string(),
+            status: status.to_string(),
+        });
+    }
+
+    for status in ["in_review", "done"] {
+        assert_eq!(
+            sub.recv().await,
+            Some(Event::WorkItemStatusChanged {
+                work_item_id: "task_1".to_string(),
+                status: status.to_string(),
+            })
+        );
+    }
+}
+
 #[tokio::test]
 async fn full_mailbox_drops_distinct_key_event_and_counts_it() {
     // Capacity 1, filled with a pending event for task_1. A distinct
@@ -418,3 +444,197 @@ async fn subscription_recv_returns_none_after_bus_dropped_and_drained() {
     // instead of hanging forever with no producer left.
     assert_eq!(sub.recv().await, None);
 }
+
+fn pr_merged(n: u32) -> Event {
+    Event::PrMerged {
+        pr_url: format!("https://github.com/o/r/pull/{n}"),
+        task_id: format!("task_{n}"),
+    }
+}
+
+/// Poll until the journal's background writer has flushed `count`
+/// records under `dir`.
+async fn wait_for_journal(dir: &std::path::Path, count: usize) {
+    for _ in 0..200 {
+        if read_journal(dir).unwrap().records.len() >= count {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    panic!("journal never reached {count} records");
+}
+
+#[test]
+fn event_serialization_tag_is_the_topic_name() {
+    for event in [
+        Event::DispatchReady,
+        Event::AutomationMutation,
+        pr_merged(1),
+        Event::WorkItemStatusChanged {
+            work_item_id: "task_1".to_string(),
+            status: "done".to_string(),
+        },
+    ] {
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["topic"], event.kind().topic_name());
+        assert_eq!(serde_json::from_value::<Event>(json).unwrap(), event);
+    }
+}
+
+#[tokio::test]
+async fn durable_bus_journals_every_publish_in_seq_order() {
+    let dir = tempfile::tempdir().unwrap();
+    let bus = EventBus::durable(EventJournal::open(dir.path()).unwrap());
+    assert!(bus.is_durable());
+
+    // Journaled whether or not anyone is subscribed.
+    bus.publish(pr_merged(1));
+    bus.publish(Event::DispatchReady);
+    wait_for_journal(dir.path(), 2).await;
+
+    let read = read_journal(dir.path()).unwrap();
+    assert_eq!(read.unreadable_lines, 0);
+    assert_eq!(
+        read.records
+            .iter()
+            .map(|record| (record.seq, record.event.clone()))
+            .collect::<Vec<_>>(),
+        vec![(1, pr_merged(1)), (2, Event::DispatchReady)]
+    );
+}
+
+#[tokio::test]
+async fn durable_subscriber_resumes_after_its_cursor_across_a_restart() {
+    let dir = tempfile::tempdir().unwrap();
+    {
+        let bus = EventBus::durable(EventJournal::open(dir.path()).unwrap());
+        let mut sub = bus.subscribe_durable("reconciler", TopicFilter::kind(EventKind::PrMerged));
+        bus.publish(pr_merged(1));
+        bus.publish(pr_merged(2));
+        bus.publish(pr_merged(3));
+
+        assert_eq!(sub.recv().await, Some(pr_merged(1)));
+        sub.checkpoint();
+        // pr 2 is received but the "engine" dies before it is handled.
+        assert_eq!(sub.recv().await, Some(pr_merged(2)));
+        wait_for_journal(dir.path(), 3).await;
+    }
+
+    let bus = EventBus::durable(EventJournal::open(dir.path()).unwrap());
+    let mut sub = bus.subscribe_durable("reconciler", TopicFilter::kind(EventKind::PrMerged));
+    bus.publish(pr_merged(4));
+
+    // The unhandled tail is replayed ahead of new live traffic.
+    assert_eq!(sub.recv().await, Some(pr_merged(2)));
+    assert_eq!(sub.recv().await, Some(pr_merged(3)));
+    assert_eq!(sub.recv().await, Some(pr_merged(4)));
+    sub.checkpoint();
+    drop(bus);
+    assert_eq!(sub.recv().await, None);
+
+    // `seq` numbering continued past the first process's records.
+    wait_for_journal(dir.path(), 4).await;
+    let seqs: Vec<u64> = read_journal(dir.path())
+        .unwrap()
+        .records
+        .iter()
+        .map(|r| r.seq)
+        .collect();
+    assert_eq!(seqs, vec![1, 2, 3, 4]);
+}
+
+#[tokio::test]
+async fn new_durable_subscriber_starts_at_the_live_tail() {
+    let dir = tempfile::tempdir().unwrap();
+    {
+        let bus = EventBus::durable(EventJournal::open(dir.path()).unwrap());
+        bus.publish(pr_merged(1));
+        wait_for_journal(dir.path(), 1).await;
+    }
+
+    let bus = EventBus::durable(EventJournal::open(dir.path()).unwrap());
+    let mut sub = bus.subscribe_durable("newcomer", TopicFilter::kind(EventKind::PrMerged));
+    bus.publish(pr_merged(2));
+    drop(bus);
+
+    // History from before the subscriber first existed is not replayed.
+    assert_eq!(sub.recv().await, Some(pr_merged(2)));
+    assert_eq!(sub.recv().await, None);
+}
+
+#[tokio::test]
+async fn checkpoint_never_passes_a_still_pending_lower_seq() {
+    let dir = tempfile::tempdir().unwrap();
+    {
+        let bus = EventBus::durable(EventJournal::open(dir.path()).unwrap());
+        let mut sub = bus.subscribe_durable("reconciler", TopicFilter::kind(EventKind::PrMerged));
+        bus.publish(pr_merged(1)); // seq 1
+        bus.publish(pr_merged(2)); // seq 2
+        bus.publish(pr_merged(1)); // seq 3, coalesces into pr 1's front slot
+
+        assert_eq!(sub.recv().await, Some(pr_merged(1)));
+        // Delivered seq 3, but seq 2 is still queued: the cursor must stay
+        // below it.
+        sub.checkpoint();
+        wait_for_journal(dir.path(), 3).await;
+    }
+
+    let bus = EventBus::durable(EventJournal::open(dir.path()).unwrap());
+    let mut sub = bus.subscribe_durable("reconciler", TopicFilter::kind(EventKind::PrMerged));
+    drop(bus);
+    assert_eq!(sub.recv().await, Some(pr_merged(2)));
+    assert_eq!(sub.recv().await, Some(pr_merged(1)), "seq 3 is past the cursor too");
+    assert_eq!(sub.recv().await, None);
+}
+
+#[tokio::test]
+async fn subscribe_durable_on_an_in_memory_bus_is_a_plain_subscription() {
+    let bus = EventBus::new();
+    assert!(!bus.is_durable());
+    let mut sub = bus.subscribe_durable("reconciler", TopicFilter::kind(EventKind::DispatchReady));
+    bus.publish(Event::DispatchReady);
+    assert_eq!(sub.recv().await, Some(Event::DispatchReady));
+    sub.checkpoint();
+}
+
+#[test]
+fn read_journal_skips_torn_lines_and_treats_a_missing_dir_as_empty() {
+    let dir = tempfile::tempdir().unwrap();
+    assert!(read_journal(&dir.path().join("missing")).unwrap().records.is_empty());
+
+    std::fs::write(
+        dir.path().join("events-2026-05-14.jsonl"),
+        "{\"seq\":7,\"ts_epoch_ms\":1,\"event\":{\"topic\":\"dispatch_ready\"}}\n{\"seq\":8,\"ts_ep",
+    )
+    .unwrap();
+    let read = read_journal(dir.path()).unwrap();
+    assert_eq!(read.records.len(), 1);
+    assert_eq!(read.records[0].seq, 7);
+    assert_eq!(read.unreadable_lines, 1);
+}
+
+#[tokio::test]
+async fn replay_reads_from_the_day_file_holding_the_cursor() {
+    use boss_engine_day_rotated_log::{epoch_ms_to_date, now_ms};
+
+    let dir = tempfile::tempdir().unwrap();
+    let day = |days_ago: u128| {
+        let date = epoch_ms_to_date(now_ms() - days_ago * 86_400_000);
+        dir.path().join(format!("events-{date}.jsonl"))
+    };
+    let record = |seq: u64| format!("{{\"seq\":{seq},\"ts_epoch_ms\":1,\"event\":{{\"topic\":\"dispatch_ready\"}}}}\n");
+    std::fs::write(day(2), format!("torn\n{}{}", record(1), record(2))).unwrap();
+    std::fs::write(day(1), format!("{}{}", record(3), record(4))).unwrap();
+    std::fs::write(day(0), record(5)).unwrap();
+    let journal = EventJournal::open(dir.path()).unwrap();
+    assert_eq!(journal.next_seq(), 6);
+
+    let read = journal.records_between(3, u64::MAX).unwrap();
+    let seqs: Vec<u64> = read.records.iter().map(|record| record.seq).collect();
+    assert_eq!(seqs, [4, 5]);
+    assert_eq!(read.unreadable_lines, 0, "the oldest day file must not be read");
+
+    let read = journal.records_between(0, 5).unwrap();
+    assert_eq!(read.records.len(), 4);
+    assert_eq!(read.unreadable_lines, 1);
+}
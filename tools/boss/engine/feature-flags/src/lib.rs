@@ -235,6 +235,21 @@ pub const REGISTRY: &[FeatureFlagSpec] = &[
         default_enabled: false,
         capability_id: None,
     },
+    FeatureFlagSpec {
+        name: "pr_review_publish",
+        description: "Publish each completed automated review pass to its GitHub PR as a pull-request review \
+             (design: automated-reviewer-pass-on-every-agent-authored-pr.md). Findings that resolve to a line of the PR's diff become inline \
+             comments anchored to that line; the rest are listed in the review body. Every comment and body is run \
+             through the product's editorial rules first, and a finding whose text the rules block is left out. \
+             On a re-review at a new head sha, a finding still reported updates its earlier comment instead of \
+             posting another, and one no longer reported has its thread resolved; a pass for a head sha that \
+             already has a published review posts nothing. The reviewer itself stays read-only — the engine \
+             posts, using the ambient `gh` auth. DEFAULT OFF. Kill switch: set false to stop publishing \
+             immediately; the internal revision decision is unaffected either way.",
+        category: "review",
+        default_enabled: false,
+        capability_id: None,
+    },
     FeatureFlagSpec {
         name: "worker_proposals",
         description: "Master kill switch for the mediated worker-proposal API (design: \
@@ -853,6 +868,18 @@ mod tests {
         assert!(store2.is_enabled("attentions_followups_backstop"));
     }
 
+    #[test]
+    fn pr_review_publish_defaults_off_and_can_be_enabled() {
+        let tmp = TempDir::new().unwrap();
+        let store = make_store(&tmp);
+        store.load().unwrap();
+        assert!(!store.is_enabled("pr_review_publish"));
+        store.set("pr_review_publish", true).unwrap();
+        let store2 = make_store(&tmp);
+        store2.load().unwrap();
+        assert!(store2.is_enabled("pr_review_publish"));
+    }
+
     #[test]
     fn codex_sandbox_enforced_defaults_off_and_can_be_enabled() {
         let tmp = TempDir::new().unwrap();
@@ -1,5 +1,6 @@
-//! In-memory metrics registry: counter / gauge primitives and the
-//! `register_counter!` / `register_gauge!` declaration macros.
+//! In-memory metrics registry: counter / gauge / histogram primitives
+//! and the `register_counter!` / `register_gauge!` /
+//! `register_histogram!` declaration macros.
 //!
 //! This crate holds the storage and declaration half of the engine's
 //! metrics framework. Persisting snapshots to a store (`boss-metrics`)
@@ -11,7 +12,10 @@
 //!
 //! Counters are strictly monotonic `u64`s; the only mutator is
 //! `inc` / `inc_by`. Gauges are signed `i64`s overwritten by the
-//! producer on each publication.
+//! producer on each publication. Histograms count `u64` observations
+//! into fixed, handle-declared buckets (plus a running sum) and are
+//! in-memory only: they are not persisted, so they restart from zero
+//! with the engine — a scraper sees that as an ordinary counter reset.
 //!
 //! Counter names must be lowercase ASCII letters, digits, dots or
 //! underscores; dot-separated namespaces by convention
@@ -24,6 +28,17 @@ use std::sync::Arc;
 use std::sync::RwLock;
 use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
 
+/// Bucket upper bounds, in milliseconds, for sub-second to
+/// several-minute latencies (a merge-poller pass, a drain pass).
+pub const LATENCY_BUCKETS_MS: &[u64] = &[
+    10, 25, 50, 100, 250, 500, 1_000, 2_500, 5_000, 10_000, 30_000, 60_000, 300_000,
+];
+
+/// Bucket upper bounds, in seconds, for waits measured against
+/// epoch-second timestamps (queue wait before dispatch, spawn to first
+/// driver signal).
+pub const WAIT_BUCKETS_SECS: &[u64] = &[1, 2, 5, 10, 30, 60, 120, 300, 600, 1_800, 3_600];
+
 /// Static descriptor for a counter, produced by [`register_counter!`].
 ///
 /// Holds the canonical name and one-line description only. The
@@ -93,6 +108,46 @@ impl GaugeHandle {
     }
 }
 
+/// Static descriptor for a histogram, produced by [`register_histogram!`].
+///
+/// Besides the name and description it carries the bucket upper bounds
+/// (inclusive, strictly increasing, in the unit the name states —
+/// `_ms`, `_seconds`). Observations above the last bound land in the
+/// implicit `+Inf` bucket.
+pub struct HistogramHandle {
+    name: &'static str,
+    description: &'static str,
+    buckets: &'static [u64],
+}
+
+impl HistogramHandle {
+    pub const fn new(name: &'static str, description: &'static str, buckets: &'static [u64]) -> Self {
+        Self {
+            name,
+            description,
+            buckets,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn description(&self) -> &'static str {
+        self.description
+    }
+
+    pub fn buckets(&self) -> &'static [u64] {
+        self.buckets
+    }
+
+    /// Record one observation of `value` in `registry`. Panics if the
+    /// handle was not registered.
+    pub fn observe(&self, registry: &Registry, value: u64) {
+        registry.histogram_observe(self.name, value);
+    }
+}
+
 /// Declare a static [`CounterHandle`].
 ///
 /// Engine modules reach this macro through the `boss_engine` crate-root
@@ -128,7 +183,27 @@ macro_rules! register_gauge {
     };
 }
 
-/// In-memory store of every counter and gauge plus persisted rows
+/// Declare a static [`HistogramHandle`]. The fourth argument is the
+/// bucket bound slice, usually [`LATENCY_BUCKETS_MS`] or
+/// [`WAIT_BUCKETS_SECS`]:
+///
+/// ```ignore
+/// crate::register_histogram!(
+///     MERGE_POLL_PASS_DURATION_MS,
+///     "merge_poller.pass_duration_ms",
+///     "Wall-clock duration of one merge-poller detection pass.",
+///     crate::metrics::LATENCY_BUCKETS_MS,
+/// );
+/// ```
+#[macro_export]
+macro_rules! register_histogram {
+    ($static_name:ident, $name:literal, $description:literal, $buckets:expr $(,)?) => {
+        #[allow(dead_code)]
+        pub static $static_name: $crate::HistogramHandle = $crate::HistogramHandle::new($name, $description, $buckets);
+    };
+}
+
+/// In-memory store of every counter, gauge and histogram plus persisted rows
 /// that no live handle currently owns (rehydrated as "stale").
 ///
 /// Thread safety: counter / gauge values are atomic, the maps are
@@ -139,6 +214,7 @@ macro_rules! register_gauge {
 pub struct Registry {
     counters: RwLock<HashMap<String, Arc<CounterEntry>>>,
     gauges: RwLock<HashMap<String, Arc<GaugeEntry>>>,
+    histograms: RwLock<HashMap<String, Arc<HistogramEntry>>>,
 }
 
 struct CounterEntry {
@@ -161,6 +237,17 @@ struct GaugeEntry {
     stale: AtomicBool,
 }
 
+struct HistogramEntry {
+    name: String,
+    description: String,
+    bounds: Vec<u64>,
+    /// Per-bucket (not cumulative) counts; one longer than `bounds`,
+    /// the last slot being `+Inf`.
+    buckets: Vec<AtomicU64>,
+    sum: AtomicU64,
+    updated_at_ms: AtomicI64,
+}
+
 /// Read-only snapshot of a counter row, suitable for serialising.
 #[derive(Debug, Clone)]
 pub struct CounterSnapshot {
@@ -180,6 +267,19 @@ pub struct GaugeSnapshot {
     pub stale: bool,
 }
 
+/// Read-only snapshot of a histogram. `cumulative_counts[i]` is the
+/// number of observations `<= bounds[i]`; `count` is the `+Inf` bucket.
+#[derive(Debug, Clone)]
+pub struct HistogramSnapshot {
+    pub name: String,
+    pub description: String,
+    pub bounds: Vec<u64>,
+    pub cumulative_counts: Vec<u64>,
+    pub sum: u64,
+    pub count: u64,
+    pub updated_at_ms: i64,
+}
+
 impl Default for Registry {
     fn default() -> Self {
         Self::new()
@@ -191,6 +291,7 @@ impl Registry {
         Self {
             counters: RwLock::new(HashMap::new()),
             gauges: RwLock::new(HashMap::new()),
+            histograms: RwLock::new(HashMap::new()),
         }
     }
 
@@ -305,6 +406,34 @@ impl Registry {
         }
     }
 
+    /// Register a [`HistogramHandle`]. Panics on duplicate name, invalid
+    /// name, or bucket bounds that are empty or not strictly increasing.
+    /// There is no stale-row adoption: histograms are never persisted.
+    pub fn register_histogram(&self, handle: &HistogramHandle) {
+        validate_name(handle.name);
+        if handle.buckets.is_empty() || handle.buckets.windows(2).any(|pair| pair[0] >= pair[1]) {
+            panic!(
+                "histogram {} bucket bounds must be non-empty and strictly increasing",
+                handle.name
+            );
+        }
+        let mut histograms = self.histograms.write().expect("metrics histograms lock poisoned");
+        if histograms.contains_key(handle.name) {
+            panic!("duplicate histogram registration: {} already registered", handle.name);
+        }
+        histograms.insert(
+            handle.name.to_owned(),
+            Arc::new(HistogramEntry {
+                name: handle.name.to_owned(),
+                description: handle.description.to_owned(),
+                bounds: handle.buckets.to_vec(),
+                buckets: (0..=handle.buckets.len()).map(|_| AtomicU64::new(0)).collect(),
+                sum: AtomicU64::new(0),
+                updated_at_ms: AtomicI64::new(now_ms()),
+            }),
+        );
+    }
+
     /// Insert a rehydrated counter row from `state.db` whose name
     /// does not match any currently-registered handle. Surfaced as
     /// "stale: not registered by current engine" so the operator can
@@ -396,6 +525,17 @@ impl Registry {
         entry.observed_at_ms.store(now_ms(), Ordering::Relaxed);
     }
 
+    fn histogram_observe(&self, name: &str, value: u64) {
+        let histograms = self.histograms.read().expect("metrics histograms lock poisoned");
+        let entry = histograms
+            .get(name)
+            .unwrap_or_else(|| panic!("histogram not registered: {name}"));
+        let bucket = entry.bounds.partition_point(|bound| *bound < value);
+        entry.buckets[bucket].fetch_add(1, Ordering::Relaxed);
+        entry.sum.fetch_add(value, Ordering::Relaxed);
+        entry.updated_at_ms.store(now_ms(), Ordering::Relaxed);
+    }
+
     /// Snapshot every counter (registered and stale) for the flush
     /// task or a future `bossctl metrics list` reader.
     pub fn counter_snapshots(&self) -> Vec<CounterSnapshot> {
@@ -430,6 +570,38 @@ impl Registry {
         out
     }
 
+    /// Snapshot every histogram, sorted by name. `count` is derived from
+    /// the buckets so it always equals the `+Inf` cumulative count; only
+    /// `sum` can lag or lead it by an `observe` racing the snapshot.
+    pub fn histogram_snapshots(&self) -> Vec<HistogramSnapshot> {
+        let histograms = self.histograms.read().expect("metrics histograms lock poisoned");
+        let mut out: Vec<HistogramSnapshot> = histograms
+            .values()
+            .map(|entry| {
+                let mut running = 0;
+                let cumulative_counts = entry.buckets[..entry.bounds.len()]
+                    .iter()
+                    .map(|bucket| {
+                        running += bucket.load(Ordering::Relaxed);
+                        running
+                    })
+                    .collect();
+                let overflow = entry.buckets[entry.bounds.len()].load(Ordering::Relaxed);
+                HistogramSnapshot {
+                    name: entry.name.clone(),
+                    description: entry.description.clone(),
+                    bounds: entry.bounds.clone(),
+                    cumulative_counts,
+                    sum: entry.sum.load(Ordering::Relaxed),
+                    count: running + overflow,
+                    updated_at_ms: entry.updated_at_ms.load(Ordering::Relaxed),
+                }
+            })
+            .collect();
+        out.sort_by(|a, b| a.name.cmp(&b.name));
+        out
+    }
+
     /// Snapshot a single counter by name. Returns `None` if the name
     /// is not registered (registered or stale).
     pub fn counter_snapshot_one(&self, name: &str) -> Option<CounterSnapshot> {
@@ -551,6 +723,12 @@ mod tests {
     register_counter!(TEST_COUNTER_A, "test.counter_a", "Phase 1 unit-test counter A.");
     register_counter!(TEST_COUNTER_B, "test.counter_b", "Phase 1 unit-test counter B.");
     register_gauge!(TEST_GAUGE_A, "test.gauge_a", "Phase 1 unit-test gauge A.");
+    register_histogram!(
+        TEST_HISTOGRAM_A,
+        "test.histogram_a_ms",
+        "Unit-test histogram A.",
+        &[10, 100, 1_000]
+    );
 
     #[test]
     fn register_and_increment_counter() {
@@ -870,4 +1048,44 @@ mod tests {
         assert_eq!(registry.counter_value("test.counter_b"), Some(0));
         assert_eq!(registry.gauge_value("test.gauge_a"), Some(0));
     }
+
+    #[test]
+    fn histogram_observations_fill_cumulative_buckets() {
+        let registry = Registry::new();
+        registry.register_histogram(&TEST_HISTOGRAM_A);
+        for value in [5, 10, 11, 100, 999, 5_000] {
+            TEST_HISTOGRAM_A.observe(&registry, value);
+        }
+
+        let snaps = registry.histogram_snapshots();
+        assert_eq!(snaps.len(), 1);
+        let snap = &snaps[0];
+        assert_eq!(snap.name, "test.histogram_a_ms");
+        assert_eq!(snap.bounds, vec![10, 100, 1_000]);
+        // Bounds are inclusive: 10 lands in `le=10`, 100 in `le=100`.
+        assert_eq!(snap.cumulative_counts, vec![2, 4, 5]);
+        assert_eq!(snap.count, 6, "the 5_000 observation only reaches +Inf");
+        assert_eq!(snap.sum, 5 + 10 + 11 + 100 + 999 + 5_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate histogram registration")]
+    fn duplicate_histogram_registration_panics() {
+        let registry = Registry::new();
+        registry.register_histogram(&TEST_HISTOGRAM_A);
+        registry.register_histogram(&TEST_HISTOGRAM_A);
+    }
+
+    #[test]
+    #[should_panic(expected = "strictly increasing")]
+    fn histogram_with_unsorted_buckets_panics() {
+        static BAD: HistogramHandle = HistogramHandle::new("test.bad_histogram", "unsorted", &[10, 10, 5]);
+        Registry::new().register_histogram(&BAD);
+    }
+
+    #[test]
+    #[should_panic(expected = "histogram not registered")]
+    fn observe_before_registration_panics() {
+        TEST_HISTOGRAM_A.observe(&Registry::new(), 1);
+    }
 }
@@ -0,0 +1,514 @@
+//! Opt-in scrape endpoint: serves every metric in a [`Registry`] as
+//! OpenMetrics text, so a local Prometheus can chart the fleet next to
+//! `cube` and CI data instead of reading `state.db` rows.
+//!
+//! The endpoint is a loopback TCP port or a Unix socket — see
+//! [`MetricsEndpoint`]. Like the evidence surface
+//! (`boss_engine_attachments::http`) the HTTP handling is hand-rolled:
+//! the only request worth answering is `GET /metrics`, which does not
+//! justify pulling `hyper` into the process that owns the engine
+//! database.
+//!
+//! # Naming
+//!
+//! Registry names are dot-namespaced (`dispatch.queue_depth.main`);
+//! OpenMetrics names are not. [`render`] prefixes `boss_` and maps dots
+//! to underscores. Dynamically-named counter families encode their
+//! labels positionally (`github_api.calls.<caller>.<api>`), which no
+//! generic rule can recover, so the caller passes [`LabelPattern`]s
+//! naming each such family: a matching name is exported as one series
+//! of the pattern's family with the placeholder segments as labels.
+//! Names that match no pattern export as unlabelled families.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::{TcpListener, UnixListener};
+use tokio::task::JoinHandle;
+
+use boss_engine_metrics_registry::Registry;
+
+/// Prefix on every exported family, so engine metrics don't collide
+/// with anything else a shared Prometheus scrapes.
+pub const NAME_PREFIX: &str = "boss_";
+
+/// `Content-Type` of a scrape response.
+pub const CONTENT_TYPE: &str = "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+/// Largest request head read before the connection is dropped. A
+/// Prometheus scrape request is a few hundred bytes.
+const MAX_REQUEST_HEAD_BYTES: usize = 8 * 1024;
+
+/// Where the exporter listens, parsed from `BOSS_METRICS_EXPORTER_ADDR`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetricsEndpoint {
+    /// `127.0.0.1:9464`, `[::1]:9464`, or `0.0.0.0:9464` for a
+    /// Prometheus on another host (the operator's call to make).
+    Tcp(SocketAddr),
+    /// `unix:/path/to/metrics.sock`, or any absolute path.
+    Unix(PathBuf),
+}
+
+impl MetricsEndpoint {
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let raw = raw.trim();
+        if let Some(path) = raw.strip_prefix("unix:") {
+            if path.is_empty() {
+                return Err("unix: endpoint needs a socket path".to_owned());
+            }
+            return Ok(Self::Unix(PathBuf::from(path)));
+        }
+        if raw.starts_with('/') {
+            return Ok(Self::Unix(PathBuf::from(raw)));
+        }
+        raw.parse::<SocketAddr>()
+            .map(Self::Tcp)
+            .map_err(|err| format!("{raw:?} is neither host:port nor unix:<path> ({err})"))
+    }
+}
+
+impl std::fmt::Display for MetricsEndpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Tcp(addr) => write!(f, "http://{addr}/metrics"),
+            Self::Unix(path) => write!(f, "unix:{}", path.display()),
+        }
+    }
+}
+
+/// Maps a positionally-labelled dynamic family onto one exported
+/// family. `pattern` is a registry name with `{label}` placeholder
+/// segments, e.g. `github_api.calls.{caller}.{api}`; `family` is the
+/// registry-style name the matches export under, and must not be the
+/// name of any other metric.
+#[derive(Debug, Clone, Copy)]
+pub struct LabelPattern {
+    pub family: &'static str,
+    pub pattern: &'static str,
+}
+
+impl LabelPattern {
+    /// The `(label, value)` pairs when `name` matches, in pattern order.
+    fn captures<'a>(&self, name: &'a str) -> Option<Vec<(&'static str, &'a str)>> {
+        let mut pattern = self.pattern.split('.');
+        let mut segments = name.split('.');
+        let mut labels = Vec::new();
+        loop {
+            match (pattern.next(), segments.next()) {
+                (None, None) => return Some(labels),
+                (Some(want), Some(got)) => match want.strip_prefix('{').and_then(|p| p.strip_suffix('}')) {
+                    Some(label) => labels.push((label, got)),
+                    None if want == got => {}
+                    None => return None,
+                },
+                _ => return None,
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Counter,
+    Gauge,
+}
+
+struct Family<'a> {
+    kind: Kind,
+    help: &'a str,
+    /// `(rendered label set, value)`, in registry-name order.
+    series: Vec<(String, String)>,
+}
+
+/// Render every counter, gauge and histogram in `registry` as an
+/// OpenMetrics exposition, terminated by `# EOF`.
+///
+/// Stale rows (persisted by an older binary, no live handle) are
+/// skipped: nothing updates them, and a frozen series reads as a flat
+/// line on a dashboard rather than as "gone".
+pub fn render(registry: &Registry, patterns: &[LabelPattern]) -> String {
+    let mut families: BTreeMap<String, Family<'_>> = BTreeMap::new();
+    let counters = registry.counter_snapshots();
+    let gauges = registry.gauge_snapshots();
+    let samples = counters
+        .iter()
+        .filter(|snap| !snap.stale)
+        .map(|snap| {
+            (
+                Kind::Counter,
+                snap.name.as_str(),
+                snap.description.as_str(),
+                snap.value.to_string(),
+            )
+        })
+        .chain(gauges.iter().filter(|snap| !snap.stale).map(|snap| {
+            (
+                Kind::Gauge,
+                snap.name.as_str(),
+                snap.description.as_str(),
+                snap.value.to_string(),
+            )
+        }));
+    for (kind, name, help, value) in samples {
+        let (family, labels) = match patterns.iter().find_map(|p| p.captures(name).map(|c| (p.family, c))) {
+            Some((family, labels)) => (exported_name(family, kind), render_labels(&labels)),
+            None => (exported_name(name, kind), String::new()),
+        };
+        let entry = families.entry(family).or_insert_with(|| Family {
+            kind,
+            help,
+            series: Vec::new(),
+        });
+        if entry.kind != kind || entry.series.iter().any(|(existing, _)| *existing == labels) {
+            // Two registry names mapping to one exported series (a
+            // counter and a gauge, or `x` and `x_total`) is a naming bug
+            // upstream; exporting both would produce an exposition
+            // Prometheus rejects outright.
+            tracing::warn!(
+                name,
+                "metrics exporter: exported series collides with another metric; skipped"
+            );
+            continue;
+        }
+        entry.series.push((labels, value));
+    }
+
+    let mut out = String::new();
+    for (family, body) in &families {
+        let (type_name, suffix) = match body.kind {
+            Kind::Counter => ("counter", "_total"),
+            Kind::Gauge => ("gauge", ""),
+        };
+        let _ = writeln!(out, "# TYPE {family} {type_name}");
+        let _ = writeln!(out, "# HELP {family} {}", escape(body.help));
+        for (labels, value) in &body.series {
+            let _ = writeln!(out, "{family}{suffix}{labels} {value}");
+        }
+    }
+    for snap in registry.histogram_snapshots() {
+        let family = format!("{NAME_PREFIX}{}", snap.name.replace('.', "_"));
+        let _ = writeln!(out, "# TYPE {family} histogram");
+        let _ = writeln!(out, "# HELP {family} {}", escape(&snap.description));
+        for (bound, count) in snap.bounds.iter().zip(&snap.cumulative_counts) {
+            let _ = writeln!(out, "{family}_bucket{{le=\"{bound}\"}} {count}");
+        }
+        let _ = writeln!(out, "{family}_bucket{{le=\"+Inf\"}} {}", snap.count);
+        let _ = writeln!(out, "{family}_sum {}", snap.sum);
+        let _ = writeln!(out, "{family}_count {}", snap.count);
+    }
+    out.push_str("# EOF\n");
+    out
+}
+
+/// `dispatch.completed` → `boss_dispatch_completed`. A counter's
+/// trailing `_total` is dropped because OpenMetrics appends it to the
+/// sample, not the family (`bus_events_dropped_total` would otherwise
+/// export as `…_total_total`).
+fn exported_name(name: &str, kind: Kind) -> String {
+    let base = name.replace('.', "_");
+    let base = match kind {
+        Kind::Counter => base.strip_suffix("_total").map(str::to_owned).unwrap_or(base),
+        Kind::Gauge => base,
+    };
+    format!("{NAME_PREFIX}{base}")
+}
+
+fn render_labels(labels: &[(&str, &str)]) -> String {
+    if labels.is_empty() {
+        return String::new();
+    }
+    let rendered: Vec<String> = labels
+        .iter()
+        .map(|(label, value)| format!("{label}=\"{}\"", escape(value)))
+        .collect();
+    format!("{{{}}}", rendered.join(","))
+}
+
+/// Escape a label value or `HELP` text per the exposition format.
+fn escape(raw: &str) -> String {
+    raw.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+// ── Serving ─────────────────────────────────────────────────────────────────
+
+/// Bind `endpoint` and answer scrapes until the task is aborted. Bind
+/// failure is returned for the caller to log — a missing exporter must
+/// never stop the engine booting. A Unix socket path left behind by a
+/// previous run is removed before binding.
+pub async fn spawn(
+    registry: Arc<Registry>,
+    endpoint: &MetricsEndpoint,
+    patterns: &'static [LabelPattern],
+) -> std::io::Result<JoinHandle<()>> {
+    match endpoint {
+        MetricsEndpoint::Tcp(addr) => {
+            let listener = TcpListener::bind(addr).await?;
+            Ok(tokio::spawn(async move {
+                loop {
+                    match listener.accept().await {
+                        Ok((stream, _peer)) => answer(stream, Arc::clone(&registry), patterns),
+                        Err(err) => tracing::warn!(error = %err, "metrics exporter: accept failed"),
+                    }
+                }
+            }))
+        }
+        MetricsEndpoint::Unix(path) => {
+            match std::fs::remove_file(path) {
+                Ok(()) => {}
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err),
+            }
+            let listener = UnixListener::bind(path)?;
+            Ok(tokio::spawn(async move {
+                loop {
+                    match listener.accept().await {
+                        Ok((stream, _peer)) => answer(stream, Arc::clone(&registry), patterns),
+                        Err(err) => tracing::warn!(error = %err, "metrics exporter: accept failed"),
+                    }
+                }
+            }))
+        }
+    }
+}
+
+fn answer<S>(stream: S, registry: Arc<Registry>, patterns: &'static [LabelPattern])
+where
+    S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    tokio::spawn(async move {
+        if let Err(err) = handle_connection(stream, &registry, patterns).await {
+            tracing::debug!(error = %err, "metrics exporter: connection ended early");
+        }
+    });
+}
+
+async fn handle_connection<S>(mut stream: S, registry: &Registry, patterns: &[LabelPattern]) -> std::io::Result<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let mut head = Vec::with_capacity(512);
+    let mut chunk = [0u8; 512];
+    while !head.windows(4).any(|window| window == b"\r\n\r\n") {
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            break;
+        }
+        head.extend_from_slice(&chunk[..read]);
+        if head.len() > MAX_REQUEST_HEAD_BYTES {
+            return Ok(());
+        }
+    }
+    let head = String::from_utf8_lossy(&head);
+    let Some((method, target)) = request_line(&head) else {
+        return Ok(());
+    };
+    let response = respond(method, target, registry, patterns);
+    stream.write_all(&response).await?;
+    stream.shutdown().await
+}
+
+fn request_line(head: &str) -> Option<(&str, &str)> {
+    let mut parts = head.split("\r\n").next()?.split(' ');
+    let method = parts.next()?;
+    let target = parts.next()?;
+    (!method.is_empty() && target.starts_with('/')).then_some((method, target))
+}
+
+/// Route one request to a serialised response.
+fn respond(method: &str, target: &str, registry: &Registry, patterns: &[LabelPattern]) -> Vec<u8> {
+    let path = target.split('?').next().unwrap_or(target);
+    let (status, content_type, body) = if !matches!(method, "GET" | "HEAD") {
+        (
+            "405 Method Not Allowed",
+            "text/plain; charset=utf-8",
+            "read-only\n".to_owned(),
+        )
+    } else if path == "/metrics" {
+        ("200 OK", CONTENT_TYPE, render(registry, patterns))
+    } else {
+        (
+            "404 Not Found",
+            "text/plain; charset=utf-8",
+            "scrape /metrics\n".to_owned(),
+        )
+    };
+    let mut out = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        body.len()
+    )
+    .into_bytes();
+    if method != "HEAD" {
+        out.extend_from_slice(body.as_bytes());
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use boss_engine_metrics_registry::{register_counter, register_gauge, register_histogram};
+
+    register_counter!(DROPPED, "bus_events_dropped_total", "Bus events dropped.");
+    register_gauge!(
+        DEPTH,
+        "dispatch.queue_depth.main",
+        "Ready executions in the \"main\" pool."
+    );
+    register_histogram!(
+        PASS_MS,
+        "merge_poller.pass_duration_ms",
+        "Pass duration.",
+        &[100, 1_000]
+    );
+
+    const PATTERNS: &[LabelPattern] = &[LabelPattern {
+        family: "github_api.calls_by_caller",
+        pattern: "github_api.calls.{caller}.{api}",
+    }];
+
+    fn registry() -> Registry {
+        let registry = Registry::new();
+        registry.register_counter(&DROPPED);
+        registry.register_gauge(&DEPTH);
+        registry.register_histogram(&PASS_MS);
+        registry
+    }
+
+    #[test]
+    fn endpoint_parses_tcp_and_unix_forms() {
+        assert_eq!(
+            MetricsEndpoint::parse("127.0.0.1:9464"),
+            Ok(MetricsEndpoint::Tcp("127.0.0.1:9464".parse().unwrap()))
+        );
+        assert_eq!(
+            MetricsEndpoint::parse("unix:/tmp/m.sock"),
+            Ok(MetricsEndpoint::Unix(PathBuf::from("/tmp/m.sock")))
+        );
+        assert_eq!(
+            MetricsEndpoint::parse("/tmp/m.sock"),
+            Ok(MetricsEndpoint::Unix(PathBuf::from("/tmp/m.sock")))
+        );
+        assert!(MetricsEndpoint::parse("localhost").is_err());
+        assert!(MetricsEndpoint::parse("unix:").is_err());
+    }
+
+    #[test]
+    fn render_exports_counters_gauges_and_histograms() {
+        let registry = registry();
+        DROPPED.inc_by(&registry, 3);
+        DEPTH.set(&registry, 7);
+        for value in [50, 500, 5_000] {
+            PASS_MS.observe(&registry, value);
+        }
+
+        let text = render(&registry, PATTERNS);
+
+        assert!(text.contains("# TYPE boss_bus_events_dropped counter\n"), "{text}");
+        assert!(text.contains("boss_bus_events_dropped_total 3\n"), "{text}");
+        assert!(text.contains("# TYPE boss_dispatch_queue_depth_main gauge\n"), "{text}");
+        assert!(
+            text.contains("# HELP boss_dispatch_queue_depth_main Ready executions in the \\\"main\\\" pool.\n"),
+            "{text}"
+        );
+        assert!(text.contains("boss_dispatch_queue_depth_main 7\n"), "{text}");
+        assert!(
+            text.contains("# TYPE boss_merge_poller_pass_duration_ms histogram\n"),
+            "{text}"
+        );
+        assert!(
+            text.contains("boss_merge_poller_pass_duration_ms_bucket{le=\"100\"} 1\n"),
+            "{text}"
+        );
+        assert!(
+            text.contains("boss_merge_poller_pass_duration_ms_bucket{le=\"1000\"} 2\n"),
+            "{text}"
+        );
+        assert!(
+            text.contains("boss_merge_poller_pass_duration_ms_bucket{le=\"+Inf\"} 3\n"),
+            "{text}"
+        );
+        assert!(text.contains("boss_merge_poller_pass_duration_ms_sum 5550\n"), "{text}");
+        assert!(text.ends_with("# EOF\n"), "{text}");
+    }
+
+    #[test]
+    fn render_turns_pattern_placeholders_into_labels() {
+        let registry = registry();
+        registry.counter_inc_by_dynamic("github_api.calls.merge_poller.graphql", "Calls by caller.", 2);
+        registry.counter_inc_by_dynamic("github_api.calls.review_watch.rest", "Calls by caller.", 5);
+        // Same prefix, wrong arity: not part of the family.
+        registry.counter_inc_by_dynamic("github_api.calls.total", "All calls.", 7);
+
+        let text = render(&registry, PATTERNS);
+
+        assert_eq!(
+            text.matches("# TYPE boss_github_api_calls_by_caller counter\n").count(),
+            1
+        );
+        assert!(
+            text.contains("boss_github_api_calls_by_caller_total{caller=\"merge_poller\",api=\"graphql\"} 2\n"),
+            "{text}"
+        );
+        assert!(
+            text.contains("boss_github_api_calls_by_caller_total{caller=\"review_watch\",api=\"rest\"} 5\n"),
+            "{text}"
+        );
+        assert!(text.contains("boss_github_api_calls_total 7\n"), "{text}");
+    }
+
+    #[test]
+    fn render_skips_stale_rows() {
+        let registry = registry();
+        registry.insert_stale_counter("retired.counter", "gone", 9, 0);
+        assert!(!render(&registry, &[]).contains("retired"));
+    }
+
+    #[test]
+    fn respond_serves_metrics_and_refuses_everything_else() {
+        let registry = registry();
+        let ok = String::from_utf8(respond("GET", "/metrics", &registry, &[])).unwrap();
+        assert!(ok.starts_with("HTTP/1.1 200 OK\r\n"), "{ok}");
+        assert!(ok.contains(CONTENT_TYPE));
+        assert!(ok.ends_with("# EOF\n"));
+
+        let head = String::from_utf8(respond("HEAD", "/metrics", &registry, &[])).unwrap();
+        assert!(head.ends_with("\r\n\r\n"), "{head}");
+
+        assert!(
+            String::from_utf8(respond("GET", "/", &registry, &[]))
+                .unwrap()
+                .starts_with("HTTP/1.1 404")
+        );
+        assert!(
+            String::from_utf8(respond("POST", "/metrics", &registry, &[]))
+                .unwrap()
+                .starts_with("HTTP/1.1 405")
+        );
+    }
+
+    #[tokio::test]
+    async fn unix_endpoint_answers_a_scrape() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("metrics.sock");
+        let registry = Arc::new(registry());
+        DEPTH.set(&registry, 4);
+
+        let handle = spawn(registry, &MetricsEndpoint::Unix(path.clone()), &[])
+            .await
+            .unwrap();
+        let mut stream = tokio::net::UnixStream::connect(&path).await.unwrap();
+        stream
+            .write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        handle.abort();
+
+        assert!(response.contains("boss_dispatch_queue_depth_main 4\n"), "{response}");
+    }
+}
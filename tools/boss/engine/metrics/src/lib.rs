@@ -1,7 +1,8 @@
 //! Engine counter / gauge metrics framework.
 //!
 //! Declaring a new metric is a one- or two-line change at the call
-//! site via [`register_counter!`] / [`register_gauge!`]. Values are
+//! site via [`register_counter!`] / [`register_gauge!`] /
+//! [`register_histogram!`]. Values are
 //! held in in-memory atomics for the hot path and flushed to a
 //! [`MetricsStore`] every 30 seconds (and on graceful shutdown). On
 //! engine startup the framework reads the persisted rows back so
@@ -26,15 +27,21 @@
 //! `WorkDb` implements. That keeps the dependency edge one-directional
 //! (`boss_engine` -> `boss_metrics` -> `boss_engine_metrics_registry`).
 //!
+//! Counters and gauges can also be scraped live: [`exporter`] serves
+//! the whole registry, histograms included, as OpenMetrics text on an
+//! opt-in TCP port or Unix socket.
+//!
 //! Registering the engine's own handles is the engine's job: see
 //! `boss_engine::metrics_init::init_all`, which must name every
 //! module that declares a handle.
 
+pub mod exporter;
 pub mod persistence;
 pub mod store;
 
 pub use boss_engine_metrics_registry::{
-    CounterHandle, CounterSnapshot, GaugeHandle, GaugeSnapshot, Registry, now_ms, register_counter, register_gauge,
+    CounterHandle, CounterSnapshot, GaugeHandle, GaugeSnapshot, HistogramHandle, HistogramSnapshot, LATENCY_BUCKETS_MS,
+    Registry, WAIT_BUCKETS_SECS, now_ms, register_counter, register_gauge, register_histogram,
 };
 pub use persistence::{FLUSH_INTERVAL, flush_all, seed_from_db, spawn_flush_task};
 pub use store::{MetricsCounterRow, MetricsGaugeRow, MetricsStore};
@@ -8,12 +8,14 @@
 
 use std::collections::BTreeMap;
 use std::fmt;
+use std::time::Duration;
 
 use boss_claude_client as claude_client;
 
 use crate::error::UtilityModelError;
+use crate::overrides;
 use crate::task::{ALL_TASKS, UtilityTask};
-use crate::{UtilityCall, UtilityModel};
+use crate::{UtilityApi, UtilityCall, UtilityModel};
 
 /// Provider id reported by [`AnthropicUtilityModel::provider_id`] and matched
 /// by [`crate::selection`] against `BOSS_UTILITY_MODEL_PROVIDER`.
@@ -24,7 +26,8 @@ pub const PROVIDER_ID: &str = "anthropic";
 /// [`claude_client::ANTHROPIC_MESSAGES_URL`], which stays hard-coded for the
 /// worker-facing transport: this seam exists precisely so an operator can
 /// redirect the engine's helper calls without touching worker dispatch.
-pub const ENDPOINT_ENV: &str = "BOSS_UTILITY_MODEL_ENDPOINT";
+/// Shared with every other provider; see [`crate::overrides`].
+pub use crate::overrides::ENDPOINT_ENV;
 
 /// One task's fully-resolved answer, computed once at construction.
 ///
@@ -35,6 +38,8 @@ pub const ENDPOINT_ENV: &str = "BOSS_UTILITY_MODEL_ENDPOINT";
 #[derive(Clone)]
 struct Entry {
     model: String,
+    /// Per-task endpoint override; `None` uses the provider-wide endpoint.
+    endpoint: Option<String>,
     api_key: Option<String>,
     /// Credential env vars consulted, in order, for this task. Carried so a
     /// missing-key error can name them instead of guessing.
@@ -49,6 +54,7 @@ struct Entry {
 #[derive(Clone)]
 pub struct AnthropicUtilityModel {
     endpoint: String,
+    timeout: Option<Duration>,
     entries: BTreeMap<UtilityTask, Entry>,
 }
 
@@ -69,9 +75,8 @@ impl AnthropicUtilityModel {
     /// injected, so credential- and model-precedence coverage never mutates
     /// the process environment.
     pub fn from_lookup(base_api_key: Option<String>, lookup: impl Fn(&str) -> Option<String>) -> Self {
-        let endpoint = lookup(ENDPOINT_ENV)
-            .filter(|value| !value.trim().is_empty())
-            .unwrap_or_else(|| claude_client::ANTHROPIC_MESSAGES_URL.to_owned());
+        let endpoint = overrides::base_endpoint(&lookup, claude_client::ANTHROPIC_MESSAGES_URL);
+        let timeout = overrides::timeout(&lookup);
 
         let entries = ALL_TASKS
             .into_iter()
@@ -96,21 +101,36 @@ impl AnthropicUtilityModel {
                     claude_client::resolve_api_key_from(task.key_env(), claude_client::DEFAULT_API_KEY_ENV, &lookup)
                         .or_else(|| base_api_key.clone());
 
-                (task, Entry { model, api_key, tried })
+                let endpoint = overrides::task_endpoint(&lookup, task);
+
+                (
+                    task,
+                    Entry {
+                        model,
+                        endpoint,
+                        api_key,
+                        tried,
+                    },
+                )
             })
             .collect();
 
-        Self { endpoint, entries }
+        Self {
+            endpoint,
+            timeout,
+            entries,
+        }
     }
 
     /// Point this provider at a different endpoint. Used by tests to drive a
-    /// mock server without setting [`ENDPOINT_ENV`] process-wide.
+    /// mock server without setting [`ENDPOINT_ENV`] process-wide. Per-task
+    /// endpoint overrides still win.
     pub fn with_endpoint(mut self, endpoint: impl Into<String>) -> Self {
         self.endpoint = endpoint.into();
         self
     }
 
-    /// The endpoint every task on this provider resolves to.
+    /// The endpoint every task without its own override resolves to.
     pub fn endpoint(&self) -> &str {
         &self.endpoint
     }
@@ -121,8 +141,9 @@ impl fmt::Debug for AnthropicUtilityModel {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut out = f.debug_struct("AnthropicUtilityModel");
         out.field("endpoint", &self.endpoint);
+        out.field("timeout", &self.timeout);
         for (task, entry) in &self.entries {
-            out.field(task.slug(), &(&entry.model, entry.api_key.is_some()));
+            out.field(task.slug(), &(&entry.model, &entry.endpoint, entry.api_key.is_some()));
         }
         out.finish()
     }
@@ -147,9 +168,11 @@ impl UtilityModel for AnthropicUtilityModel {
         };
         Ok(UtilityCall {
             provider: PROVIDER_ID.to_owned(),
-            endpoint: self.endpoint.clone(),
+            api: UtilityApi::AnthropicMessages,
+            endpoint: entry.endpoint.clone().unwrap_or_else(|| self.endpoint.clone()),
             model: entry.model.clone(),
             api_key,
+            timeout: self.timeout,
         })
     }
 }
@@ -219,6 +242,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn per_task_endpoint_override_wins_over_the_provider_wide_one() {
+        let provider = AnthropicUtilityModel::from_lookup(
+            Some("k".to_owned()),
+            lookup_of(&[
+                (ENDPOINT_ENV, "http://gateway/v1/messages"),
+                ("BOSS_UTILITY_MODEL_PLANNER_ENDPOINT", "http://big-box/v1/messages"),
+            ]),
+        )
+        .with_endpoint("http://mock/v1/messages");
+        assert_eq!(
+            provider.resolve(UtilityTask::Planner).unwrap().endpoint,
+            "http://big-box/v1/messages"
+        );
+        assert_eq!(
+            provider.resolve(UtilityTask::LiveStatus).unwrap().endpoint,
+            "http://mock/v1/messages"
+        );
+    }
+
+    #[test]
+    fn timeout_override_is_carried_on_every_call() {
+        let provider = AnthropicUtilityModel::from_lookup(
+            Some("k".to_owned()),
+            lookup_of(&[("BOSS_UTILITY_MODEL_TIMEOUT_SECS", "45")]),
+        );
+        let call = provider.resolve(UtilityTask::PaneSummary).unwrap();
+        assert_eq!(call.timeout, Some(Duration::from_secs(45)));
+        assert_eq!(call.api, UtilityApi::AnthropicMessages);
+    }
+
     #[test]
     fn per_feature_billing_key_wins_over_the_base_key() {
         let provider = AnthropicUtilityModel::from_lookup(
@@ -242,7 +296,9 @@ mod tests {
     fn missing_credentials_name_the_env_vars_that_were_tried() {
         let provider = AnthropicUtilityModel::from_lookup(None, lookup_of(&[]));
         let err = provider.resolve(UtilityTask::AttentionsBackstop).unwrap_err();
-        let UtilityModelError::NoCredentials { task, tried, .. } = &err;
+        let UtilityModelError::NoCredentials { task, tried, .. } = &err else {
+            panic!("expected NoCredentials, got {err:?}");
+        };
         assert_eq!(*task, UtilityTask::AttentionsBackstop);
         assert_eq!(tried, &["BOSS_BACKSTOP_API_KEY", "ANTHROPIC_API_KEY"]);
         // The message is the operator's only clue; it must be actionable.
@@ -19,13 +19,23 @@ pub enum UtilityModelError {
         provider: String,
         tried: Vec<String>,
     },
+    /// The provider has no built-in model for this task and none was
+    /// configured. Only providers that front arbitrary servers (`local`)
+    /// hit this: there is no model name that every vLLM, llama.cpp or
+    /// Ollama install is guaranteed to serve.
+    #[error("utility model {provider}: no model configured for {task} (set {env})")]
+    NoModel {
+        task: UtilityTask,
+        provider: String,
+        env: String,
+    },
 }
 
 impl UtilityModelError {
     /// The task whose resolution failed.
     pub fn task(&self) -> UtilityTask {
         match self {
-            Self::NoCredentials { task, .. } => *task,
+            Self::NoCredentials { task, .. } | Self::NoModel { task, .. } => *task,
         }
     }
 
@@ -33,6 +43,7 @@ impl UtilityModelError {
     pub fn tag(&self) -> &'static str {
         match self {
             Self::NoCredentials { .. } => "no_credentials",
+            Self::NoModel { .. } => "no_model",
         }
     }
 }
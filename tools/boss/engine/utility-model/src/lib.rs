@@ -25,12 +25,16 @@
 //!
 //! # Layering
 //!
-//! This crate yields *endpoint + model + auth*. It does **not** send anything:
-//! transport stays in `boss_claude_client`, which remains the single place an
-//! Anthropic request is made. A call site resolves a [`UtilityCall`], then
-//! hands `call.model` / `call.endpoint` / `call.api_key` to that pipeline. The
-//! edge is one-way — `engine/core` → `boss-engine-utility-model` →
-//! `boss-claude-client` — and nothing here may import from the engine.
+//! A provider yields *endpoint + model + auth* (plus which wire the endpoint
+//! speaks). Sending goes through the resolved [`UtilityCall`] —
+//! [`UtilityCall::send_messages`] and friends — which routes on
+//! [`UtilityCall::api`]: Anthropic requests go to `boss_claude_client`, which
+//! remains the single place an Anthropic request is made; chat-completions
+//! requests are translated and sent by [`openai`]. Call sites build one
+//! (Anthropic-shaped) request and parse one response shape whichever
+//! provider is selected. The edge is one-way — `engine/core` →
+//! `boss-engine-utility-model` → `boss-claude-client` — and nothing here may
+//! import from the engine.
 //!
 //! # Using it
 //!
@@ -40,9 +44,9 @@
 //! # async fn example(utility: &dyn UtilityModel) {
 //! match utility.resolve(UtilityTask::LiveStatus) {
 //!     Ok(call) => {
-//!         // build a request with `call.model`, send it to `call.endpoint`
-//!         // with `call.api_key`
-//!         let _ = (&call.model, &call.endpoint, &call.api_key);
+//!         // build a request with `call.model`, then
+//!         // `call.send_messages(&request, &config)`
+//!         let _ = &call.model;
 //!     }
 //!     Err(err) => tracing::warn!(%err, "live_status: utility model unavailable"),
 //! }
@@ -61,9 +65,12 @@
 //!
 //! | Env var | Effect |
 //! | ------- | ------ |
-//! | `BOSS_UTILITY_MODEL_PROVIDER` | Which provider to use. Unset ⇒ `anthropic`. |
-//! | `BOSS_UTILITY_MODEL_ENDPOINT` | Endpoint override for the Anthropic provider. |
-//! | `BOSS_UTILITY_MODEL_<TASK>` | Per-task model override, e.g. `BOSS_UTILITY_MODEL_LIVE_STATUS`. |
+//! | `BOSS_UTILITY_MODEL_PROVIDER` | Which provider to use: `anthropic` (default), `openai`, `local`. |
+//! | `BOSS_UTILITY_MODEL_ENDPOINT` | Endpoint override for the selected provider. |
+//! | `BOSS_UTILITY_MODEL_<TASK>_ENDPOINT` | Per-task endpoint override, e.g. `BOSS_UTILITY_MODEL_PLANNER_ENDPOINT`. |
+//! | `BOSS_UTILITY_MODEL_<TASK>` | Per-task model override, e.g. `BOSS_UTILITY_MODEL_LIVE_STATUS`. Required for every task under `local`. |
+//! | `BOSS_UTILITY_MODEL_API_KEY` | Credential for `openai` / `local` (then `OPENAI_API_KEY` for `openai`). Optional for `local`. |
+//! | `BOSS_UTILITY_MODEL_TIMEOUT_SECS` | Per-attempt timeout for every utility call, for slow local backends. |
 //!
 //! Model overrides are **per task**, never global. These calls are short,
 //! frequent, and on interactive paths, with cost/latency profiles quite unlike
@@ -73,31 +80,50 @@
 //! field.
 
 use std::sync::{Arc, OnceLock};
+use std::time::Duration;
 
 pub mod anthropic;
 mod error;
+pub mod openai;
+pub mod overrides;
 pub mod selection;
 mod task;
+mod transport;
 
 pub use anthropic::AnthropicUtilityModel;
 pub use error::UtilityModelError;
+pub use openai::OpenAiCompatibleUtilityModel;
 pub use selection::{ProviderSelection, ProviderSource, select};
 pub use task::{ALL_TASKS, UtilityTask};
 
+/// Which wire protocol a [`UtilityCall::endpoint`] speaks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UtilityApi {
+    /// Anthropic Messages API, sent by `boss_claude_client`.
+    AnthropicMessages,
+    /// OpenAI-compatible `/v1/chat/completions`, sent by [`openai`].
+    OpenAiChatCompletions,
+}
+
 /// A resolved utility call: everything needed to issue one short completion,
 /// and nothing else.
 ///
-/// `max_tokens`, the prompt, the timeout and any response parsing stay with
-/// the feature — the seam owns *where to send it and as whom*, matching the
-/// caller/transport split `boss_claude_client` already documents.
+/// `max_tokens`, the prompt, the retry policy and any response parsing stay
+/// with the feature — the seam owns *where to send it and as whom*, matching
+/// the caller/transport split `boss_claude_client` already documents.
 #[derive(Clone)]
 pub struct UtilityCall {
     /// Id of the provider that resolved this call. Logged by call sites so a
     /// misrouted call is diagnosable from the engine log alone.
     pub provider: String,
+    pub api: UtilityApi,
     pub endpoint: String,
     pub model: String,
+    /// Empty for a keyless `local` server; no auth header is sent then.
     pub api_key: String,
+    /// Operator override of the feature's per-attempt timeout
+    /// ([`overrides::TIMEOUT_ENV`]). `None` keeps the feature's own.
+    pub timeout: Option<Duration>,
 }
 
 /// Manual so a credential can never reach a log line through `{:?}`.
@@ -105,9 +131,11 @@ impl std::fmt::Debug for UtilityCall {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("UtilityCall")
             .field("provider", &self.provider)
+            .field("api", &self.api)
             .field("endpoint", &self.endpoint)
             .field("model", &self.model)
             .field("api_key", &"<redacted>")
+            .field("timeout", &self.timeout)
             .finish()
     }
 }
@@ -117,7 +145,7 @@ impl std::fmt::Debug for UtilityCall {
 /// Implementors are cheap, immutable value types resolved once at startup;
 /// [`resolve`](UtilityModel::resolve) must not perform I/O.
 pub trait UtilityModel: std::fmt::Debug + Send + Sync {
-    /// Stable provider id, e.g. `anthropic`. Matched against
+    /// Stable provider id, e.g. `anthropic` or `local`. Matched against
     /// [`selection::PROVIDER_ENV`] and carried into [`UtilityCall::provider`].
     fn provider_id(&self) -> &str;
 
@@ -179,9 +207,11 @@ mod tests {
     fn utility_call_debug_redacts_the_credential() {
         let call = UtilityCall {
             provider: "anthropic".to_owned(),
+            api: UtilityApi::AnthropicMessages,
             endpoint: "https://example.invalid/v1/messages".to_owned(),
             model: "claude-haiku-4-5-20251001".to_owned(),
             api_key: "super-secret".to_owned(),
+            timeout: None,
         };
         let rendered = format!("{call:?}");
         assert!(!rendered.contains("super-secret"), "{rendered}");
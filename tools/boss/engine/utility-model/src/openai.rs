@@ -0,0 +1,650 @@
+//! OpenAI-compatible chat-completions providers: hosted OpenAI (`openai`)
+//! and self-hosted servers that speak the same wire (`local` — vLLM,
+//! llama.cpp's `llama-server`, Ollama).
+//!
+//! Call sites keep building Anthropic-shaped requests; the provider-neutral
+//! [`UtilityCall::send_messages`](crate::UtilityCall::send_messages) family
+//! hands them to [`send_chat`] here, which translates the body to a
+//! `/v1/chat/completions` request and the reply back into a
+//! [`MessagesResponse`]. Features therefore parse one response shape no
+//! matter which provider answered.
+//!
+//! # Structured output
+//!
+//! The planner's forced tool call (`tool_choice: {"type": "tool"}`) becomes
+//! `response_format: {"type": "json_schema"}` with the tool's input schema,
+//! and the JSON reply comes back as a `tool_use` block under the tool's
+//! name. Schema-constrained decoding is what the local servers implement
+//! reliably; their tool-call support varies by model and chat template.
+//! A plain JSON-mode request (the comment classifier) becomes
+//! `response_format: {"type": "json_object"}`.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::Duration;
+
+use boss_claude_client::{CallConfig, ClaudeError, ContentBlock, MessagesResponse, Usage};
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use crate::error::UtilityModelError;
+use crate::overrides;
+use crate::task::{ALL_TASKS, UtilityTask};
+use crate::{UtilityApi, UtilityCall, UtilityModel};
+
+/// Provider id for hosted OpenAI.
+pub const OPENAI_PROVIDER_ID: &str = "openai";
+
+/// Provider id for a self-hosted OpenAI-compatible server.
+pub const LOCAL_PROVIDER_ID: &str = "local";
+
+/// Hosted OpenAI chat-completions endpoint.
+pub const OPENAI_CHAT_URL: &str = "https://api.openai.com/v1/chat/completions";
+
+/// Default `local` endpoint: Ollama's OpenAI-compatible route on its
+/// default port. vLLM (`:8000`) and `llama-server` (`:8080`) are pointed at
+/// with `BOSS_UTILITY_MODEL_ENDPOINT`.
+pub const LOCAL_CHAT_URL: &str = "http://127.0.0.1:11434/v1/chat/completions";
+
+/// Credential env var consulted first by both providers, so a gateway or an
+/// authenticated vLLM deployment can get a key that is not an OpenAI one.
+pub const API_KEY_ENV: &str = "BOSS_UTILITY_MODEL_API_KEY";
+
+/// Hosted OpenAI's conventional credential env var.
+pub const OPENAI_API_KEY_ENV: &str = "OPENAI_API_KEY";
+
+/// Default hosted-OpenAI model per task. Same split as the Anthropic
+/// defaults: a small model for the frequent one-liners and the classifier,
+/// a large one for the planner.
+pub const fn openai_default_model(task: UtilityTask) -> &'static str {
+    match task {
+        UtilityTask::LiveStatus
+        | UtilityTask::PaneSummary
+        | UtilityTask::AttentionsBackstop
+        | UtilityTask::CommentIntent => "gpt-4.1-mini",
+        UtilityTask::Planner => "gpt-4.1",
+    }
+}
+
+#[derive(Clone)]
+struct Entry {
+    model: Option<String>,
+    endpoint: Option<String>,
+}
+
+/// A chat-completions provider. One type serves both `openai` and `local`;
+/// they differ only in defaults (endpoint, models) and in whether a
+/// credential is required.
+#[derive(Clone)]
+pub struct OpenAiCompatibleUtilityModel {
+    provider_id: &'static str,
+    endpoint: String,
+    /// `None` is only valid when `key_required` is false: the request is
+    /// then sent without an `Authorization` header.
+    api_key: Option<String>,
+    key_required: bool,
+    key_tried: Vec<String>,
+    timeout: Option<Duration>,
+    entries: BTreeMap<UtilityTask, Entry>,
+}
+
+impl OpenAiCompatibleUtilityModel {
+    /// Hosted OpenAI, built from the process environment.
+    pub fn openai_from_env() -> Self {
+        Self::openai_from_lookup(|name| std::env::var(name).ok())
+    }
+
+    /// Testable core of [`Self::openai_from_env`]. Every task has a default
+    /// model; a credential is required ([`API_KEY_ENV`], then
+    /// [`OPENAI_API_KEY_ENV`]).
+    pub fn openai_from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Self {
+        let api_key =
+            overrides::non_blank(&lookup, API_KEY_ENV).or_else(|| overrides::non_blank(&lookup, OPENAI_API_KEY_ENV));
+        Self::build(
+            OPENAI_PROVIDER_ID,
+            OPENAI_CHAT_URL,
+            Some(openai_default_model),
+            api_key,
+            vec![API_KEY_ENV.to_owned(), OPENAI_API_KEY_ENV.to_owned()],
+            &lookup,
+        )
+    }
+
+    /// A self-hosted server, built from the process environment.
+    pub fn local_from_env() -> Self {
+        Self::local_from_lookup(|name| std::env::var(name).ok())
+    }
+
+    /// Testable core of [`Self::local_from_env`]. No task has a default
+    /// model — each must be named with `BOSS_UTILITY_MODEL_<TASK>` — and the
+    /// credential ([`API_KEY_ENV`]) is optional.
+    pub fn local_from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Self {
+        let api_key = overrides::non_blank(&lookup, API_KEY_ENV);
+        let mut provider = Self::build(
+            LOCAL_PROVIDER_ID,
+            LOCAL_CHAT_URL,
+            None,
+            api_key,
+            vec![API_KEY_ENV.to_owned()],
+            &lookup,
+        );
+        provider.key_required = false;
+        provider
+    }
+
+    fn build(
+        provider_id: &'static str,
+        default_endpoint: &str,
+        default_model: Option<fn(UtilityTask) -> &'static str>,
+        api_key: Option<String>,
+        key_tried: Vec<String>,
+        lookup: &impl Fn(&str) -> Option<String>,
+    ) -> Self {
+        let entries = ALL_TASKS
+            .into_iter()
+            .map(|task| {
+                let model = overrides::non_blank(lookup, task.model_env())
+                    .or_else(|| default_model.map(|default| default(task).to_owned()));
+                let endpoint = overrides::task_endpoint(lookup, task);
+                (task, Entry { model, endpoint })
+            })
+            .collect();
+        Self {
+            provider_id,
+            endpoint: overrides::base_endpoint(lookup, default_endpoint),
+            api_key,
+            key_required: true,
+            key_tried,
+            timeout: overrides::timeout(lookup),
+            entries,
+        }
+    }
+
+    /// Point this provider at a different endpoint. Used by tests to drive a
+    /// stand-in server. Per-task endpoint overrides still win.
+    pub fn with_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoint = endpoint.into();
+        self
+    }
+
+    /// The endpoint every task without its own override resolves to.
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+}
+
+/// Manual so a credential can never reach a log line through `{:?}`.
+impl fmt::Debug for OpenAiCompatibleUtilityModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut out = f.debug_struct("OpenAiCompatibleUtilityModel");
+        out.field("provider", &self.provider_id);
+        out.field("endpoint", &self.endpoint);
+        out.field("api_key", &self.api_key.is_some());
+        out.field("timeout", &self.timeout);
+        for (task, entry) in &self.entries {
+            out.field(task.slug(), &(&entry.model, &entry.endpoint));
+        }
+        out.finish()
+    }
+}
+
+impl UtilityModel for OpenAiCompatibleUtilityModel {
+    fn provider_id(&self) -> &str {
+        self.provider_id
+    }
+
+    fn resolve(&self, task: UtilityTask) -> Result<UtilityCall, UtilityModelError> {
+        let entry = self
+            .entries
+            .get(&task)
+            .expect("OpenAiCompatibleUtilityModel is built from ALL_TASKS, so every task has an entry");
+        let Some(model) = entry.model.clone() else {
+            return Err(UtilityModelError::NoModel {
+                task,
+                provider: self.provider_id.to_owned(),
+                env: task.model_env().to_owned(),
+            });
+        };
+        let api_key = match (&self.api_key, self.key_required) {
+            (Some(key), _) => key.clone(),
+            (None, false) => String::new(),
+            (None, true) => {
+                return Err(UtilityModelError::NoCredentials {
+                    task,
+                    provider: self.provider_id.to_owned(),
+                    tried: self.key_tried.clone(),
+                });
+            }
+        };
+        Ok(UtilityCall {
+            provider: self.provider_id.to_owned(),
+            api: UtilityApi::OpenAiChatCompletions,
+            endpoint: entry.endpoint.clone().unwrap_or_else(|| self.endpoint.clone()),
+            model,
+            api_key,
+            timeout: self.timeout,
+        })
+    }
+}
+
+// ── Wire translation ──────────────────────────────────────────────────────────
+
+/// Which structured-output mode a request asks for on the chat wire.
+#[derive(Debug, Clone, PartialEq)]
+enum OutputMode {
+    Text,
+    JsonObject,
+    /// A forced tool call, carried as `json_schema`; the reply is re-wrapped
+    /// as a `tool_use` block with this name.
+    Tool(String),
+}
+
+/// Translate an Anthropic Messages body into a chat-completions body.
+///
+/// `system` becomes a leading `system` message; `model`, `max_tokens`,
+/// `temperature` and `messages` carry over. A forced single tool call
+/// becomes a `json_schema` response format. Anthropic-only knobs
+/// (`output_config`, unforced `tools`) have no chat-completions equivalent
+/// and are dropped.
+fn chat_request(body: &Value, json_object: bool) -> (Value, OutputMode) {
+    let mut messages = Vec::new();
+    if let Some(system) = body.get("system").and_then(Value::as_str) {
+        messages.push(json!({ "role": "system", "content": system }));
+    }
+    if let Some(turns) = body.get("messages").and_then(Value::as_array) {
+        messages.extend(turns.iter().cloned());
+    }
+
+    let mut request = json!({
+        "model": body.get("model").cloned().unwrap_or(Value::Null),
+        "messages": messages,
+    });
+    for key in ["max_tokens", "temperature"] {
+        if let Some(value) = body.get(key) {
+            request[key] = value.clone();
+        }
+    }
+
+    let forced_tool = body
+        .get("tool_choice")
+        .filter(|choice| choice.get("type").and_then(Value::as_str) == Some("tool"))
+        .and_then(|choice| choice.get("name"))
+        .and_then(Value::as_str);
+    let tool_schema = forced_tool.and_then(|name| {
+        body.get("tools")?
+            .as_array()?
+            .iter()
+            .find(|tool| tool.get("name").and_then(Value::as_str) == Some(name))?
+            .get("input_schema")
+            .cloned()
+    });
+
+    let mode = match (forced_tool, tool_schema) {
+        (Some(name), Some(schema)) => {
+            request["response_format"] = json!({
+                "type": "json_schema",
+                "json_schema": { "name": name, "schema": schema },
+            });
+            OutputMode::Tool(name.to_owned())
+        }
+        _ if json_object => {
+            request["response_format"] = json!({ "type": "json_object" });
+            OutputMode::JsonObject
+        }
+        _ => OutputMode::Text,
+    };
+    (request, mode)
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatResponse {
+    #[serde(default)]
+    choices: Vec<ChatChoice>,
+    #[serde(default)]
+    usage: Option<ChatUsage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatChoice {
+    message: ChatMessage,
+    #[serde(default)]
+    finish_reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatMessage {
+    #[serde(default)]
+    content: Option<String>,
+    #[serde(default)]
+    tool_calls: Vec<ChatToolCall>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatToolCall {
+    #[serde(default)]
+    id: Option<String>,
+    function: ChatFunction,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatFunction {
+    name: String,
+    #[serde(default)]
+    arguments: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatUsage {
+    #[serde(default)]
+    prompt_tokens: i64,
+    #[serde(default)]
+    completion_tokens: i64,
+}
+
+/// Translate a chat-completions reply into the Messages shape features
+/// already parse.
+///
+/// Under [`OutputMode::Tool`] the reply text is parsed as the tool's input.
+/// Text that is not JSON comes back as a plain text block with no
+/// `tool_use`, which the caller's own validation treats like a model that
+/// ignored the forced tool — the planner, for one, re-prompts.
+fn messages_response(chat: ChatResponse, mode: &OutputMode) -> MessagesResponse {
+    let usage = chat.usage.map(|usage| Usage {
+        input_tokens: usage.prompt_tokens,
+        output_tokens: usage.completion_tokens,
+    });
+    let Some(choice) = chat.choices.into_iter().next() else {
+        return MessagesResponse {
+            content: Vec::new(),
+            usage,
+            stop_reason: None,
+        };
+    };
+
+    let mut content = Vec::new();
+    let text = choice.message.content.unwrap_or_default();
+    let tool_input = match mode {
+        OutputMode::Tool(name) => serde_json::from_str::<Value>(text.trim())
+            .ok()
+            .map(|input| (name.clone(), input)),
+        OutputMode::Text | OutputMode::JsonObject => None,
+    };
+    match tool_input {
+        Some((name, input)) => content.push(tool_use_block(name, input, None)),
+        None if !text.is_empty() => content.push(ContentBlock {
+            block_type: "text".to_owned(),
+            text,
+            name: None,
+            input: None,
+            id: None,
+        }),
+        None => {}
+    }
+    // A server that answered with a real tool call anyway is honoured too.
+    for call in choice.message.tool_calls {
+        if let Ok(input) = serde_json::from_str::<Value>(&call.function.arguments) {
+            content.push(tool_use_block(call.function.name, input, call.id));
+        }
+    }
+
+    let stop_reason = choice.finish_reason.map(|reason| {
+        match reason.as_str() {
+            "stop" => "end_turn",
+            "length" => "max_tokens",
+            "tool_calls" => "tool_use",
+            other => other,
+        }
+        .to_owned()
+    });
+    MessagesResponse {
+        content,
+        usage,
+        stop_reason,
+    }
+}
+
+fn tool_use_block(name: String, input: Value, id: Option<String>) -> ContentBlock {
+    ContentBlock {
+        block_type: "tool_use".to_owned(),
+        text: String::new(),
+        name: Some(name),
+        input: Some(input),
+        id,
+    }
+}
+
+// ── Sending ───────────────────────────────────────────────────────────────────
+
+/// Send an Anthropic-shaped `body` to a chat-completions endpoint, retrying
+/// transient failures per `config.retry` with exactly the classification
+/// [`ClaudeError::is_retryable`] applies on the Anthropic path, so the retry
+/// policy does not depend on which provider is selected.
+pub(crate) async fn send_chat(
+    api_key: &str,
+    body: &Value,
+    json_object: bool,
+    config: &CallConfig,
+) -> Result<MessagesResponse, ClaudeError> {
+    let (request, mode) = chat_request(body, json_object);
+    let url = config
+        .endpoint
+        .as_deref()
+        .ok_or_else(|| ClaudeError::Transport("no chat-completions endpoint resolved".to_owned()))?;
+    let attempts = config.retry.max_attempts.max(1);
+    for attempt in 1..=attempts {
+        match call_once(api_key, url, &request, config.timeout).await {
+            Ok(chat) => return Ok(messages_response(chat, &mode)),
+            Err(err) if err.is_retryable() && attempt < attempts => {
+                let delay = boss_http_retry::backoff_delay(&config.retry, attempt);
+                tracing::warn!(
+                    attempt,
+                    max_attempts = attempts,
+                    backoff_ms = delay.as_millis() as u64,
+                    err = %err,
+                    "utility_model: chat-completions transient failure; retrying",
+                );
+                if !delay.is_zero() {
+                    tokio::time::sleep(delay).await;
+                }
+            }
+            Err(err) => return Err(err),
+        }
+    }
+    unreachable!("retry loop always returns on the final attempt")
+}
+
+async fn call_once(api_key: &str, url: &str, body: &Value, timeout: Duration) -> Result<ChatResponse, ClaudeError> {
+    let mut request = boss_http_retry::http_client()
+        .post(url)
+        .header("content-type", "application/json")
+        .timeout(timeout)
+        .json(body);
+    if !api_key.is_empty() {
+        request = request.bearer_auth(api_key);
+    }
+    let response = request
+        .send()
+        .await
+        .map_err(|err| ClaudeError::Transport(err.to_string()))?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(ClaudeError::Api {
+            status: status.as_u16(),
+            body,
+        });
+    }
+    response
+        .json::<ChatResponse>()
+        .await
+        .map_err(|err| ClaudeError::Decode(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn lookup_of(pairs: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> + use<> {
+        let map: HashMap<String, String> = pairs.iter().map(|(k, v)| ((*k).to_owned(), (*v).to_owned())).collect();
+        move |name: &str| map.get(name).cloned()
+    }
+
+    fn chat(content: &str) -> ChatResponse {
+        serde_json::from_value(json!({
+            "choices": [{ "message": { "content": content }, "finish_reason": "stop" }],
+            "usage": { "prompt_tokens": 12, "completion_tokens": 3 },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn openai_defaults_every_model_but_requires_a_key() {
+        let keyless = OpenAiCompatibleUtilityModel::openai_from_lookup(lookup_of(&[]));
+        let err = keyless.resolve(UtilityTask::LiveStatus).unwrap_err();
+        assert_eq!(err.tag(), "no_credentials");
+        assert!(err.to_string().contains("OPENAI_API_KEY"), "{err}");
+
+        let keyed = OpenAiCompatibleUtilityModel::openai_from_lookup(lookup_of(&[("OPENAI_API_KEY", "sk")]));
+        for task in ALL_TASKS {
+            let call = keyed.resolve(task).unwrap();
+            assert_eq!(call.model, openai_default_model(task), "{task}");
+            assert_eq!(call.endpoint, OPENAI_CHAT_URL);
+            assert_eq!(call.api, UtilityApi::OpenAiChatCompletions);
+            assert_eq!(call.provider, OPENAI_PROVIDER_ID);
+        }
+    }
+
+    #[test]
+    fn boss_key_wins_over_the_openai_key() {
+        let provider = OpenAiCompatibleUtilityModel::openai_from_lookup(lookup_of(&[
+            (API_KEY_ENV, "gateway"),
+            (OPENAI_API_KEY_ENV, "sk"),
+        ]));
+        assert_eq!(provider.resolve(UtilityTask::Planner).unwrap().api_key, "gateway");
+    }
+
+    #[test]
+    fn local_needs_a_model_per_task_but_no_key() {
+        let provider = OpenAiCompatibleUtilityModel::local_from_lookup(lookup_of(&[(
+            "BOSS_UTILITY_MODEL_LIVE_STATUS",
+            "qwen2.5:3b",
+        )]));
+        let call = provider.resolve(UtilityTask::LiveStatus).unwrap();
+        assert_eq!(call.model, "qwen2.5:3b");
+        assert_eq!(call.endpoint, LOCAL_CHAT_URL);
+        assert!(call.api_key.is_empty());
+
+        let err = provider.resolve(UtilityTask::Planner).unwrap_err();
+        assert_eq!(
+            err,
+            UtilityModelError::NoModel {
+                task: UtilityTask::Planner,
+                provider: LOCAL_PROVIDER_ID.to_owned(),
+                env: "BOSS_UTILITY_MODEL_PLANNER".to_owned(),
+            }
+        );
+    }
+
+    #[test]
+    fn per_task_endpoint_splits_one_task_off() {
+        let provider = OpenAiCompatibleUtilityModel::local_from_lookup(lookup_of(&[
+            ("BOSS_UTILITY_MODEL_ENDPOINT", "http://vllm:8000/v1/chat/completions"),
+            (
+                "BOSS_UTILITY_MODEL_PLANNER_ENDPOINT",
+                "http://big:8000/v1/chat/completions",
+            ),
+            ("BOSS_UTILITY_MODEL_PLANNER", "llama-70b"),
+            ("BOSS_UTILITY_MODEL_PANE_SUMMARY", "llama-8b"),
+        ]));
+        assert_eq!(
+            provider.resolve(UtilityTask::Planner).unwrap().endpoint,
+            "http://big:8000/v1/chat/completions"
+        );
+        assert_eq!(
+            provider.resolve(UtilityTask::PaneSummary).unwrap().endpoint,
+            "http://vllm:8000/v1/chat/completions"
+        );
+    }
+
+    #[test]
+    fn debug_never_renders_a_credential() {
+        let provider =
+            OpenAiCompatibleUtilityModel::openai_from_lookup(lookup_of(&[(OPENAI_API_KEY_ENV, "super-secret")]));
+        let rendered = format!("{provider:?}");
+        assert!(!rendered.contains("super-secret"), "{rendered}");
+    }
+
+    #[test]
+    fn chat_request_moves_system_into_messages() {
+        let body = json!({
+            "model": "m",
+            "max_tokens": 64,
+            "system": "be brief",
+            "output_config": { "effort": "high" },
+            "messages": [{ "role": "user", "content": "hi" }],
+        });
+        let (request, mode) = chat_request(&body, false);
+        assert_eq!(mode, OutputMode::Text);
+        assert_eq!(
+            request,
+            json!({
+                "model": "m",
+                "max_tokens": 64,
+                "messages": [
+                    { "role": "system", "content": "be brief" },
+                    { "role": "user", "content": "hi" },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn forced_tool_becomes_a_json_schema_response_format() {
+        let schema = json!({ "type": "object", "properties": { "tasks": { "type": "array" } } });
+        let body = json!({
+            "model": "m",
+            "tools": [{ "name": "emit", "description": "d", "input_schema": schema }],
+            "tool_choice": { "type": "tool", "name": "emit" },
+            "messages": [{ "role": "user", "content": "plan" }],
+        });
+        let (request, mode) = chat_request(&body, false);
+        assert_eq!(mode, OutputMode::Tool("emit".to_owned()));
+        assert_eq!(
+            request["response_format"],
+            json!({ "type": "json_schema", "json_schema": { "name": "emit", "schema": schema } })
+        );
+        assert!(request.get("tools").is_none());
+    }
+
+    #[test]
+    fn json_mode_sets_a_json_object_response_format() {
+        let body = json!({ "model": "m", "messages": [] });
+        let (request, mode) = chat_request(&body, true);
+        assert_eq!(mode, OutputMode::JsonObject);
+        assert_eq!(request["response_format"], json!({ "type": "json_object" }));
+    }
+
+    #[test]
+    fn text_reply_maps_to_a_text_block_with_usage() {
+        let response = messages_response(chat("Reading the config"), &OutputMode::Text);
+        assert_eq!(response.first_text(), Some("Reading the config"));
+        assert_eq!(response.usage().input_tokens, 12);
+        assert_eq!(response.usage().output_tokens, 3);
+        assert_eq!(response.stop_reason.as_deref(), Some("end_turn"));
+    }
+
+    #[test]
+    fn tool_mode_reply_maps_to_a_tool_use_block() {
+        let response = messages_response(chat(r#" {"tasks": []} "#), &OutputMode::Tool("emit".to_owned()));
+        assert_eq!(response.tool_use_input("emit"), Some(&json!({ "tasks": [] })));
+        assert_eq!(response.first_text(), None);
+    }
+
+    #[test]
+    fn tool_mode_non_json_reply_stays_text() {
+        let response = messages_response(chat("I cannot do that"), &OutputMode::Tool("emit".to_owned()));
+        assert_eq!(response.tool_use_input("emit"), None);
+        assert_eq!(response.first_text(), Some("I cannot do that"));
+    }
+}
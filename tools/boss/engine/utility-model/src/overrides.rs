@@ -0,0 +1,75 @@
+//! Operator overrides every provider honours the same way.
+//!
+//! Kept out of the individual providers so "which endpoint / how long to
+//! wait" means one thing regardless of which backend is selected: a
+//! per-task endpoint beats the provider-wide one, which beats the
+//! provider's own default, and a blank value is the same as unset.
+
+use std::time::Duration;
+
+use crate::task::UtilityTask;
+
+/// Provider-wide endpoint override. Per-task overrides
+/// ([`UtilityTask::endpoint_env`]) win over it.
+pub const ENDPOINT_ENV: &str = "BOSS_UTILITY_MODEL_ENDPOINT";
+
+/// Per-attempt timeout override, in seconds, applied to every utility call
+/// regardless of provider. Each feature still picks its own timeout; this
+/// exists for slow backends (a local model on CPU) that cannot answer
+/// inside budgets sized for a hosted API.
+pub const TIMEOUT_ENV: &str = "BOSS_UTILITY_MODEL_TIMEOUT_SECS";
+
+/// `lookup(name)`, with a blank value treated as unset.
+pub(crate) fn non_blank(lookup: &impl Fn(&str) -> Option<String>, name: &str) -> Option<String> {
+    lookup(name)
+        .map(|value| value.trim().to_owned())
+        .filter(|value| !value.is_empty())
+}
+
+/// The provider-wide endpoint: [`ENDPOINT_ENV`], else `default`.
+pub(crate) fn base_endpoint(lookup: &impl Fn(&str) -> Option<String>, default: &str) -> String {
+    non_blank(lookup, ENDPOINT_ENV).unwrap_or_else(|| default.to_owned())
+}
+
+/// This task's own endpoint override, if any. `None` means "use the
+/// provider-wide endpoint", which is resolved separately so a test can
+/// still redirect the whole provider with `with_endpoint`.
+pub(crate) fn task_endpoint(lookup: &impl Fn(&str) -> Option<String>, task: UtilityTask) -> Option<String> {
+    non_blank(lookup, task.endpoint_env())
+}
+
+/// [`TIMEOUT_ENV`] parsed as whole seconds. Unparseable or zero values are
+/// ignored with a warning rather than failing startup: the feature's own
+/// timeout is a safe fallback.
+pub(crate) fn timeout(lookup: &impl Fn(&str) -> Option<String>) -> Option<Duration> {
+    let raw = non_blank(lookup, TIMEOUT_ENV)?;
+    match raw.parse::<u64>() {
+        Ok(secs) if secs > 0 => Some(Duration::from_secs(secs)),
+        _ => {
+            tracing::warn!(
+                value = raw.as_str(),
+                "utility_model: {TIMEOUT_ENV} is not a positive whole number of seconds; ignoring it",
+            );
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timeout_accepts_positive_seconds_only() {
+        assert_eq!(timeout(&|_: &str| Some("90".to_owned())), Some(Duration::from_secs(90)));
+        assert_eq!(timeout(&|_: &str| Some("0".to_owned())), None);
+        assert_eq!(timeout(&|_: &str| Some("soon".to_owned())), None);
+        assert_eq!(timeout(&|_: &str| None), None);
+    }
+
+    #[test]
+    fn task_endpoint_ignores_blank_values() {
+        let lookup = |name: &str| (name == "BOSS_UTILITY_MODEL_PLANNER_ENDPOINT").then(|| "  ".to_owned());
+        assert_eq!(task_endpoint(&lookup, UtilityTask::Planner), None);
+    }
+}
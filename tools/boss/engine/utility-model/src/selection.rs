@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use crate::UtilityModel;
 use crate::anthropic::{self, AnthropicUtilityModel};
+use crate::openai::{self, OpenAiCompatibleUtilityModel};
 
 /// Env var naming the utility provider.
 pub const PROVIDER_ENV: &str = "BOSS_UTILITY_MODEL_PROVIDER";
@@ -23,10 +24,14 @@ pub const PROVIDER_ENV: &str = "BOSS_UTILITY_MODEL_PROVIDER";
 /// Provider used when [`PROVIDER_ENV`] is unset or unrecognised.
 pub const DEFAULT_PROVIDER: &str = anthropic::PROVIDER_ID;
 
-/// Every provider id this build understands. One entry today; the list exists
-/// so an unknown-name diagnostic can enumerate the real options rather than
+/// Every provider id this build understands. The list exists so an
+/// unknown-name diagnostic can enumerate the real options rather than
 /// leaving an operator to guess at the spelling.
-pub const KNOWN_PROVIDERS: [&str; 1] = [anthropic::PROVIDER_ID];
+pub const KNOWN_PROVIDERS: [&str; 3] = [
+    anthropic::PROVIDER_ID,
+    openai::OPENAI_PROVIDER_ID,
+    openai::LOCAL_PROVIDER_ID,
+];
 
 /// How the selected provider was arrived at.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -112,11 +117,18 @@ pub fn select_from(base_api_key: Option<String>, lookup: impl Fn(&str) -> Option
         },
     };
 
-    // One provider exists today, so every branch builds the same thing. The
-    // `match` on `source` above is what will grow a second arm; keeping the
-    // construction separate means adding a provider does not also have to
-    // rework how the fallback is reported.
-    let provider: Arc<dyn UtilityModel> = Arc::new(AnthropicUtilityModel::from_lookup(base_api_key, lookup));
+    // Construction is keyed on the id actually chosen, kept separate from
+    // `source` so adding a provider does not also have to rework how the
+    // fallback is reported.
+    let chosen = match &source {
+        ProviderSource::Configured => requested.as_deref().unwrap_or(DEFAULT_PROVIDER),
+        ProviderSource::Defaulted | ProviderSource::UnknownFallback { .. } => DEFAULT_PROVIDER,
+    };
+    let provider: Arc<dyn UtilityModel> = match chosen {
+        openai::OPENAI_PROVIDER_ID => Arc::new(OpenAiCompatibleUtilityModel::openai_from_lookup(lookup)),
+        openai::LOCAL_PROVIDER_ID => Arc::new(OpenAiCompatibleUtilityModel::local_from_lookup(lookup)),
+        _ => Arc::new(AnthropicUtilityModel::from_lookup(base_api_key, lookup)),
+    };
 
     ProviderSelection { provider, source }
 }
@@ -152,6 +164,29 @@ mod tests {
         assert_eq!(selection.provider.provider_id(), "anthropic");
     }
 
+    #[test]
+    fn openai_compatible_providers_are_selectable() {
+        let selection = select_from(
+            Some("anthropic-key".to_owned()),
+            lookup_of(&[
+                (PROVIDER_ENV, "local"),
+                ("BOSS_UTILITY_MODEL_COMMENT_INTENT", "llama3.2"),
+            ]),
+        );
+        assert_eq!(selection.source, ProviderSource::Configured);
+        assert_eq!(selection.provider.provider_id(), "local");
+        let call = selection.provider.resolve(UtilityTask::CommentIntent).unwrap();
+        assert_eq!(call.api, crate::UtilityApi::OpenAiChatCompletions);
+        assert_eq!(call.model, "llama3.2");
+        assert!(
+            call.api_key.is_empty(),
+            "the Anthropic key must never be sent to another provider"
+        );
+
+        let selection = select_from(None, lookup_of(&[(PROVIDER_ENV, "openai"), ("OPENAI_API_KEY", "sk")]));
+        assert_eq!(selection.provider.provider_id(), "openai");
+    }
+
     #[test]
     fn unknown_provider_falls_back_visibly_rather_than_silently() {
         let selection = select_from(Some("k".to_owned()), lookup_of(&[(PROVIDER_ENV, "ollama")]));
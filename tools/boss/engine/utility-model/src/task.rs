@@ -83,6 +83,20 @@ impl UtilityTask {
         }
     }
 
+    /// Env var that points this task alone at a different endpoint, e.g.
+    /// `BOSS_UTILITY_MODEL_PLANNER_ENDPOINT`. Wins over the provider-wide
+    /// `BOSS_UTILITY_MODEL_ENDPOINT`, so the planner can go to a large
+    /// hosted model while the per-tick summaries stay on a local server.
+    pub const fn endpoint_env(self) -> &'static str {
+        match self {
+            Self::LiveStatus => "BOSS_UTILITY_MODEL_LIVE_STATUS_ENDPOINT",
+            Self::PaneSummary => "BOSS_UTILITY_MODEL_PANE_SUMMARY_ENDPOINT",
+            Self::AttentionsBackstop => "BOSS_UTILITY_MODEL_ATTENTIONS_BACKSTOP_ENDPOINT",
+            Self::CommentIntent => "BOSS_UTILITY_MODEL_COMMENT_INTENT_ENDPOINT",
+            Self::Planner => "BOSS_UTILITY_MODEL_PLANNER_ENDPOINT",
+        }
+    }
+
     /// The model this task runs on when nothing overrides it.
     ///
     /// These are exactly the constants the call sites pinned before the seam
@@ -124,6 +138,11 @@ mod tests {
         for task in ALL_TASKS {
             let expected = format!("BOSS_UTILITY_MODEL_{}", task.slug().to_uppercase());
             assert_eq!(task.model_env(), expected, "{task} model env drifted from its slug");
+            assert_eq!(
+                task.endpoint_env(),
+                format!("{expected}_ENDPOINT"),
+                "{task} endpoint env drifted from its slug"
+            );
         }
     }
 
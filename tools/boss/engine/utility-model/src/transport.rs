@@ -0,0 +1,197 @@
+//! Provider-neutral sending for a resolved [`UtilityCall`].
+//!
+//! Features build Anthropic Messages requests (typed or raw) exactly as they
+//! did before other providers existed, and send them through the call
+//! rather than straight to `boss_claude_client`. The call routes on
+//! [`UtilityCall::api`]: the Anthropic wire goes to `boss_claude_client`
+//! untouched; the chat-completions wire is translated in [`crate::openai`].
+//!
+//! Both wires take the feature's [`CallConfig`] — its retry policy and
+//! per-attempt timeout — as-is, and both report failures as a
+//! [`ClaudeError`], so a feature's retry and error-mapping logic does not
+//! branch on the provider. Two things are imposed from the call: the
+//! endpoint it resolved, and the operator's timeout override, if set.
+
+use boss_claude_client::{self as claude_client, CallConfig, ClaudeError, MessagesRequest, MessagesResponse};
+use serde_json::Value;
+
+use crate::{UtilityApi, UtilityCall, openai};
+
+impl UtilityCall {
+    /// Send a typed request and return the parsed response.
+    pub async fn send_messages(
+        &self,
+        request: &MessagesRequest,
+        config: &CallConfig,
+    ) -> Result<MessagesResponse, ClaudeError> {
+        let body = serde_json::to_value(request).map_err(|err| ClaudeError::Decode(err.to_string()))?;
+        self.send(&body, false, config).await
+    }
+
+    /// Like [`Self::send_messages`], for a feature that needs a bare JSON
+    /// object back. Backends with a JSON mode are asked to enforce it; on
+    /// the Anthropic wire, which has none, the feature's prompt is what
+    /// keeps the reply JSON, so the request goes out unchanged.
+    pub async fn send_messages_json(
+        &self,
+        request: &MessagesRequest,
+        config: &CallConfig,
+    ) -> Result<MessagesResponse, ClaudeError> {
+        let body = serde_json::to_value(request).map_err(|err| ClaudeError::Decode(err.to_string()))?;
+        self.send(&body, true, config).await
+    }
+
+    /// Send a caller-built Anthropic Messages body — the forced-tool-call
+    /// structured-output shape the planner uses. See [`crate::openai`] for
+    /// how that shape is carried on the chat-completions wire.
+    pub async fn send_messages_raw(&self, body: &Value, config: &CallConfig) -> Result<MessagesResponse, ClaudeError> {
+        self.send(body, false, config).await
+    }
+
+    async fn send(
+        &self,
+        body: &Value,
+        json_object: bool,
+        config: &CallConfig,
+    ) -> Result<MessagesResponse, ClaudeError> {
+        let config = self.effective_config(config);
+        match self.api {
+            UtilityApi::AnthropicMessages => claude_client::send_messages_raw(&self.api_key, body, &config).await,
+            UtilityApi::OpenAiChatCompletions => openai::send_chat(&self.api_key, body, json_object, &config).await,
+        }
+    }
+
+    /// The feature's config with this call's endpoint and timeout override
+    /// applied.
+    fn effective_config(&self, config: &CallConfig) -> CallConfig {
+        let mut config = config.clone().with_endpoint(self.endpoint.clone());
+        if let Some(timeout) = self.timeout {
+            config.timeout = timeout;
+        }
+        config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use boss_claude_client::{Message, RetryPolicy};
+    use serde_json::json;
+    use wiremock::matchers::{body_partial_json, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    fn call(api: UtilityApi, endpoint: String) -> UtilityCall {
+        UtilityCall {
+            provider: "test".to_owned(),
+            api,
+            endpoint,
+            model: "m".to_owned(),
+            api_key: "k".to_owned(),
+            timeout: None,
+        }
+    }
+
+    fn request() -> MessagesRequest {
+        MessagesRequest::builder()
+            .model("m")
+            .max_tokens(16)
+            .system("sys")
+            .messages(vec![Message::user("hi")])
+            .build()
+    }
+
+    fn config() -> CallConfig {
+        CallConfig::new(Duration::from_secs(5)).with_retry(RetryPolicy::new(2, Duration::ZERO, Duration::ZERO))
+    }
+
+    #[test]
+    fn timeout_override_replaces_the_features_timeout_and_endpoint_is_the_calls() {
+        let mut call = call(UtilityApi::AnthropicMessages, "http://resolved".to_owned());
+        let effective = call.effective_config(&config().with_endpoint("http://stale"));
+        assert_eq!(effective.timeout, Duration::from_secs(5));
+        assert_eq!(effective.endpoint.as_deref(), Some("http://resolved"));
+
+        call.timeout = Some(Duration::from_secs(120));
+        assert_eq!(call.effective_config(&config()).timeout, Duration::from_secs(120));
+    }
+
+    #[tokio::test]
+    async fn chat_call_against_a_stand_in_server() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/chat/completions"))
+            .and(header("authorization", "Bearer k"))
+            .and(body_partial_json(json!({
+                "model": "m",
+                "response_format": { "type": "json_object" },
+                "messages": [{ "role": "system", "content": "sys" }, { "role": "user", "content": "hi" }],
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "choices": [{ "message": { "role": "assistant", "content": "{\"ok\":true}" }, "finish_reason": "stop" }],
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let call = call(
+            UtilityApi::OpenAiChatCompletions,
+            format!("{}/v1/chat/completions", server.uri()),
+        );
+        let response = call.send_messages_json(&request(), &config()).await.unwrap();
+        assert_eq!(response.first_text(), Some("{\"ok\":true}"));
+    }
+
+    /// A 503 from a chat-completions backend is retried under the feature's
+    /// policy exactly as it would be on the Anthropic wire; a 400 is not.
+    #[tokio::test]
+    async fn chat_retry_classification_matches_the_anthropic_wire() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "choices": [{ "message": { "content": "done" } }],
+            })))
+            .mount(&server)
+            .await;
+        let chat = call(UtilityApi::OpenAiChatCompletions, server.uri());
+        assert_eq!(
+            chat.send_messages(&request(), &config()).await.unwrap().first_text(),
+            Some("done")
+        );
+
+        let rejecting = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(400).set_body_string("bad model"))
+            .expect(1)
+            .mount(&rejecting)
+            .await;
+        let chat = call(UtilityApi::OpenAiChatCompletions, rejecting.uri());
+        let err = chat.send_messages(&request(), &config()).await.unwrap_err();
+        assert_eq!(err.status(), Some(400));
+    }
+
+    #[tokio::test]
+    async fn anthropic_call_goes_to_the_messages_wire() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(header("x-api-key", "k"))
+            .and(header("anthropic-version", claude_client::ANTHROPIC_API_VERSION))
+            .and(body_partial_json(json!({ "system": "sys" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "content": [{ "type": "text", "text": "hello" }],
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let call = call(UtilityApi::AnthropicMessages, server.uri());
+        let response = call.send_messages_json(&request(), &config()).await.unwrap();
+        assert_eq!(response.first_text(), Some("hello"));
+    }
+}
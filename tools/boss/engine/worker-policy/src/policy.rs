@@ -369,6 +369,7 @@ pub fn worker_verb_decision(request: &FrontendRequest) -> WorkerVerbDecision {
         | FrontendRequest::CreateRun { .. }
         | FrontendRequest::DebugLiveStatusPipeline
         | FrontendRequest::ExecutionTranscript { .. }
+        | FrontendRequest::ExplainDispatch { .. }
         | FrontendRequest::FocusWorkerPane { .. }
         | FrontendRequest::GetDriverQuotaUsage { .. }
         | FrontendRequest::GetDriverTrafficSplit
@@ -383,6 +384,8 @@ pub fn worker_verb_decision(request: &FrontendRequest) -> WorkerVerbDecision {
         | FrontendRequest::ListFeatureFlags
         | FrontendRequest::ListHostedPaneStatuses
         | FrontendRequest::ListLiveStatusDisabledSlots
+        | FrontendRequest::ListProductDispatchCalendars
+        | FrontendRequest::ListProductDispatchShares
         | FrontendRequest::ListWorkerLiveStates
         | FrontendRequest::MetricsListLive
         | FrontendRequest::MetricsReset { .. }
@@ -404,6 +407,8 @@ pub fn worker_verb_decision(request: &FrontendRequest) -> WorkerVerbDecision {
         | FrontendRequest::SetDispatchPaused { .. }
         | FrontendRequest::SetFeatureFlag { .. }
         | FrontendRequest::SetLiveStatusEnabled { .. }
+        | FrontendRequest::SetProductDispatchCalendar { .. }
+        | FrontendRequest::SetProductDispatchShare { .. }
         | FrontendRequest::SetSetting { .. }
         | FrontendRequest::StopRun { .. }
         | FrontendRequest::Subscribe { .. }
@@ -425,8 +430,11 @@ pub fn worker_verb_decision(request: &FrontendRequest) -> WorkerVerbDecision {
         //
         // Registration verbs *establish* the trust roots this gate is built
         // on. A worker calling one would be repointing the engine's notion
-        // of who the app or the Boss session is.
-        FrontendRequest::EngineResponse { .. }
+        // of who the app or the Boss session is. `DeclareRemotePeer` could
+        // not reclassify a worker anyway, but a worker has no reason to
+        // present as remote, so it is refused rather than ignored.
+        FrontendRequest::DeclareRemotePeer { .. }
+        | FrontendRequest::EngineResponse { .. }
         | FrontendRequest::RecreateCoordinator { .. }
         | FrontendRequest::RegisterAppSession
         | FrontendRequest::RegisterCapabilities { .. }
@@ -482,6 +490,11 @@ pub fn worker_verb_decision(request: &FrontendRequest) -> WorkerVerbDecision {
         | FrontendRequest::GetCostWindowReport { .. }
         | FrontendRequest::GetTopCostConsumers { .. }
         | FrontendRequest::GetWorkItemCostReport { .. }
+        // Spend budgets gate dispatch; a worker raising its own product's
+        // budget would defeat the point of having one.
+        | FrontendRequest::DeleteSpendBudget { .. }
+        | FrontendRequest::ListSpendBudgets { .. }
+        | FrontendRequest::SetSpendBudget { .. }
         | FrontendRequest::GitHubAuthCancel
         | FrontendRequest::GitHubAuthDisconnect
         | FrontendRequest::GitHubAuthStart
@@ -498,6 +511,7 @@ pub fn worker_verb_decision(request: &FrontendRequest) -> WorkerVerbDecision {
         | FrontendRequest::ListPlannerRuns { .. }
         | FrontendRequest::MergeWhenReady { .. }
         | FrontendRequest::PlanProject { .. }
+        | FrontendRequest::PostAutomationWebhook { .. }
         | FrontendRequest::ReleaseHoldRun { .. }
         | FrontendRequest::ReleaseProject { .. }
         | FrontendRequest::RetryCiRemediation { .. }
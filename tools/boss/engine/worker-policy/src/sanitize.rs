@@ -120,6 +120,7 @@ pub fn sanitize_event_for_worker(event: FrontendEvent) -> FrontendEvent {
         passthrough @ (FrontendEvent::Hello { .. }
         | FrontendEvent::Subscribed { .. }
         | FrontendEvent::Unsubscribed { .. }
+        | FrontendEvent::RemotePeerDeclared { .. }
         | FrontendEvent::TopicEvent { .. }
         | FrontendEvent::ProductsList { .. }
         | FrontendEvent::ProjectsList { .. }
@@ -216,6 +217,9 @@ pub fn sanitize_event_for_worker(event: FrontendEvent) -> FrontendEvent {
         | FrontendEvent::EffortEscalationRecorded { .. }
         | FrontendEvent::WorkItemCostReport { .. }
         | FrontendEvent::CostWindowReport { .. }
+        | FrontendEvent::SpendBudgetsList { .. }
+        | FrontendEvent::SpendBudgetSet { .. }
+        | FrontendEvent::SpendBudgetDeleted { .. }
         | FrontendEvent::TopCostConsumers { .. }
         | FrontendEvent::PlannerRunsList { .. }
         | FrontendEvent::PlanProjectResult { .. }
@@ -250,6 +254,11 @@ pub fn sanitize_event_for_worker(event: FrontendEvent) -> FrontendEvent {
         | FrontendEvent::MetricsResetDone { .. }
         | FrontendEvent::PrReconcilersKicked { .. }
         | FrontendEvent::DispatchConcurrencyResult { .. }
+        | FrontendEvent::DispatchExplained { .. }
+        | FrontendEvent::ProductDispatchCalendarsList { .. }
+        | FrontendEvent::ProductDispatchCalendarSet { .. }
+        | FrontendEvent::ProductDispatchSharesList { .. }
+        | FrontendEvent::ProductDispatchShareSet { .. }
         | FrontendEvent::DriverQuotaUsageResult { .. }
         | FrontendEvent::DriverTrafficSplitResult { .. }
         | FrontendEvent::DispatchStateResult { .. }
@@ -291,6 +300,7 @@ pub fn sanitize_event_for_worker(event: FrontendEvent) -> FrontendEvent {
         | FrontendEvent::AutomationDedupSuppressionsList { .. }
         | FrontendEvent::AutomationTasksList { .. }
         | FrontendEvent::AutomationRunEnqueued { .. }
+        | FrontendEvent::AutomationWebhookAccepted { .. }
         | FrontendEvent::RunHeld { .. }
         | FrontendEvent::RunHoldReleased { .. }
         | FrontendEvent::DecisionCreated { .. }
@@ -311,6 +311,12 @@ fn coordinator_verbs_stay_closed() {
             expected_spawn_token: "token".into(),
             reason: CoordinatorRecreateReason::OperatorReset,
         },
+        FrontendRequest::DeclareRemotePeer {
+            transport: "ssh".into(),
+        },
+        FrontendRequest::DeleteSpendBudget {
+            budget_id: "bgt_1".into(),
+        },
     ] {
         let denial = assert_denied(request);
         assert_eq!(denial.reason, WorkerTierDenialReason::CoordinatorOnly);
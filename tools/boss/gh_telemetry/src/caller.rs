@@ -143,6 +143,11 @@ pub mod callers {
     pub const HOST_REGISTRY: &str = "host_registry";
     /// PR open/merged state checks issued outside the poller.
     pub const PR_STATE_CHECK: &str = "pr_state_check";
+    /// Event-triggered automations: merged-PR file lists and the
+    /// default-branch CI poll.
+    pub const AUTOMATION_EVENTS: &str = "automation_events";
+    /// Publishing a completed automated review pass to its PR.
+    pub const PR_REVIEW_PUBLISH: &str = "pr_review_publish";
 }
 
 #[cfg(test)]
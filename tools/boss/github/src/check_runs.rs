@@ -42,13 +42,16 @@ pub fn classify_status_context_state(state: &str) -> StatusContextVerdict {
 }
 
 /// CI provider inferred from a check's `targetUrl` host. The CI-watch
-/// `CiLogReader` impls (Buildkite + GitHub Actions) dispatch on this;
-/// the `Other` variant captures anything we don't know how to read
-/// (status contexts from third-party services like Codecov, Sonar, etc.).
+/// `CiLogReader` impls (Buildkite, GitHub Actions, GitLab CI, Jenkins)
+/// dispatch on this; the `Other` variant captures anything we don't know
+/// how to read (status contexts from third-party services like Codecov,
+/// Sonar, etc.).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CiProvider {
     Buildkite,
     GithubActions,
+    GitlabCi,
+    Jenkins,
     Other,
 }
 
@@ -87,14 +90,33 @@ pub fn provider_for_url(url: &str) -> CiProvider {
     if lower.contains("github.com") && (lower.contains("/actions/") || lower.contains("/check-runs/")) {
         return CiProvider::GithubActions;
     }
+    // GitLab (gitlab.com or self-managed) posts commit statuses pointing at
+    //   https://<host>/<group>/<project>/-/jobs/<job-id>
+    //   https://<host>/<group>/<project>/-/pipelines/<pipeline-id>
+    // The `/-/` separator is GitLab-specific, so the path alone is enough.
+    if lower.contains("/-/jobs/") || lower.contains("/-/pipelines/") {
+        return CiProvider::GitlabCi;
+    }
+    // Jenkins has no canonical host. The GitHub plugins post either a
+    // classic build URL (`…/job/<name>/<n>/display/redirect`) or a Blue
+    // Ocean one (`…/blue/organizations/jenkins/<pipeline>/detail/…`); a
+    // bare `/job/` path only counts on a host that names itself Jenkins.
+    let host = lower.split("://").nth(1).unwrap_or(&lower).split('/').next().unwrap_or("");
+    if lower.contains("/blue/organizations/jenkins/")
+        || (lower.contains("/job/") && (lower.contains("/display/redirect") || host.contains("jenkins")))
+    {
+        return CiProvider::Jenkins;
+    }
     CiProvider::Other
 }
 
 /// Extract the provider's job id from a `targetUrl`. Buildkite job
 /// ids ride in the URL fragment (`…/builds/<n>#<job-uuid>`); GitHub
-/// Actions job ids are the last path segment after `/job/`. Returns
-/// `None` for URLs that don't match either pattern — the worker
-/// prompt then shows the raw URL and the worker shells out manually.
+/// Actions job ids are the last path segment after `/job/`; GitLab job
+/// ids follow `/-/jobs/` (a pipeline URL names no single job); Jenkins
+/// has no job id finer than the build, so the build number stands in.
+/// Returns `None` for URLs that don't match — the worker prompt then
+/// shows the raw URL and the worker shells out manually.
 ///
 /// This is the single canonical implementation — `ci_log_reader`'s
 /// `parse_buildkite_job_id`/`parse_gha_job_id` delegate here rather than
@@ -114,10 +136,41 @@ pub fn parse_provider_job_id(provider: CiProvider, url: &str) -> Option<String>
             let id = tail.trim_end_matches('/');
             if id.is_empty() { None } else { Some(id.to_owned()) }
         }
+        CiProvider::GitlabCi => {
+            // …/-/jobs/<job-id>[/…][?…][#…]
+            let (_, tail) = url.split_once("/-/jobs/")?;
+            let id = tail.split(['/', '?', '#']).next().unwrap_or(tail);
+            numeric_segment(id)
+        }
+        CiProvider::Jenkins => jenkins_build_number(url),
         CiProvider::Other => None,
     }
 }
 
+/// Build number of a Jenkins build URL, classic or Blue Ocean:
+///   …/job/<a>/job/<b>/<n>[/display/redirect|/console|…]
+///   …/blue/organizations/jenkins/<pipeline>/detail/<branch>/<n>/pipeline
+/// In the classic shape the number is the segment right after the last
+/// job name; in the Blue Ocean shape it follows the branch.
+fn jenkins_build_number(url: &str) -> Option<String> {
+    let stripped = url.split(['?', '#']).next().unwrap_or(url);
+    let segments: Vec<&str> = stripped.split('/').collect();
+    let index = if let Some(detail) = segments.iter().position(|s| *s == "detail") {
+        detail + 2
+    } else {
+        segments.iter().rposition(|s| *s == "job")? + 2
+    };
+    numeric_segment(segments.get(index)?)
+}
+
+fn numeric_segment(segment: &str) -> Option<String> {
+    if !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit()) {
+        Some(segment.to_owned())
+    } else {
+        None
+    }
+}
+
 /// Fetch the failing CI checks for a specific commit SHA via the GitHub
 /// REST API. Used for merge-queue rebounce detection where the failing SHA is
 /// the synthetic merge commit (`before_commit_sha`) assembled by the queue on
@@ -405,7 +458,9 @@ mod tests {
     /// `provider_for_url` infers the CI provider purely from the host /
     /// path of the check's `targetUrl`. Buildkite is host-only; GitHub
     /// Actions additionally requires an `/actions/` or `/check-runs/`
-    /// segment; everything else (including a bare github.com URL and the
+    /// segment; GitLab is path-only (`/-/jobs/`, `/-/pipelines/`); Jenkins
+    /// needs a Blue Ocean path, a `display/redirect` link, or a Jenkins
+    /// host; everything else (including a bare github.com URL and the
     /// empty string) is `Other`. Matching is case-insensitive.
     #[test]
     fn provider_for_url_classifies_hosts() {
@@ -428,6 +483,19 @@ mod tests {
             ("", Other),
             ("https://app.codecov.io/gh/anthropic/mono", Other),
             ("https://sonarcloud.io/dashboard?id=mono", Other),
+            // GitLab — the `/-/jobs/` or `/-/pipelines/` path, any host.
+            ("https://gitlab.com/acme/mono/-/jobs/4242", GitlabCi),
+            ("https://gitlab.acme.internal/platform/mono/-/pipelines/99", GitlabCi),
+            // Jenkins — Blue Ocean path, the GitHub plugins' classic
+            // `display/redirect` link, or a `/job/` path on a Jenkins host.
+            (
+                "https://ci.acme.dev/blue/organizations/jenkins/mono/detail/main/42/pipeline",
+                Jenkins,
+            ),
+            ("https://ci.acme.dev/job/mono/job/PR-7/3/display/redirect", Jenkins),
+            ("https://jenkins.acme.dev/job/mono/42/", Jenkins),
+            // A `/job/` path alone on an unrelated host stays unknown.
+            ("https://ci.acme.dev/job/mono/42/", Other),
             // Case-insensitivity: an upper/mixed-case host still matches.
             ("HTTPS://BuildKite.COM/Acme/Mono/Builds/42", Buildkite),
             ("https://GITHUB.com/anthropic/mono/ACTIONS/runs/1/job/2", GithubActions),
@@ -553,7 +621,8 @@ mod tests {
     /// `parse_provider_job_id` extracts the provider-native job id from the
     /// `targetUrl`. Buildkite ids ride in the URL fragment (after `#`);
     /// GitHub Actions ids are the last path segment after `/job/` (with any
-    /// `?query` stripped and a trailing `/` trimmed). Anything that doesn't
+    /// `?query` stripped and a trailing `/` trimmed); GitLab ids follow
+    /// `/-/jobs/`; Jenkins yields the build number. Anything that doesn't
     /// match — or `CiProvider::Other` — yields `None`.
     #[test]
     fn parse_provider_job_id_extracts_or_none() {
@@ -622,6 +691,28 @@ mod tests {
             ),
             None,
         );
+        // GitLab: the numeric id after '/-/jobs/'; a pipeline URL has none.
+        assert_eq!(
+            super::parse_provider_job_id(GitlabCi, "https://gitlab.com/acme/mono/-/jobs/4242/raw"),
+            Some("4242".to_owned()),
+        );
+        assert_eq!(
+            super::parse_provider_job_id(GitlabCi, "https://gitlab.com/acme/mono/-/pipelines/99"),
+            None,
+        );
+        // Jenkins: the build number, from either URL shape.
+        assert_eq!(
+            super::parse_provider_job_id(Jenkins, "https://ci.acme.dev/job/mono/job/PR-7/3/display/redirect"),
+            Some("3".to_owned()),
+        );
+        assert_eq!(
+            super::parse_provider_job_id(
+                Jenkins,
+                "https://ci.acme.dev/blue/organizations/jenkins/mono/detail/main/42/pipeline",
+            ),
+            Some("42".to_owned()),
+        );
+        assert_eq!(super::parse_provider_job_id(Jenkins, "https://ci.acme.dev/job/mono/"), None);
         // CiProvider::Other never parses a job id, regardless of the URL.
         assert_eq!(
             super::parse_provider_job_id(Other, "https://buildkite.com/acme/mono/builds/1#x"),
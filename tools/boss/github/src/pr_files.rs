@@ -8,11 +8,23 @@
 //! [`crate::gh_runner::run_gh`]'s existing spawn/exit-code boilerplate) for
 //! any field set; [`parse_changed_file_paths`] and [`fetch_pr_changed_files`]
 //! cover the common paths-only case.
+//!
+//! Callers that need the per-file unified patch — to anchor a review
+//! comment to a diff line — use [`fetch_pr_file_patches`], which reads
+//! the REST `pulls/{n}/files` listing (`gh pr view` does not expose
+//! patches) through an injectable [`GhRunner`].
 
 use anyhow::{Context, Result};
 use serde_json::Value;
 
-use crate::gh_runner::run_gh;
+use crate::gh_runner::{GhRunner, GhRunnerError, run_gh};
+
+/// Page size for `GET /repos/{slug}/pulls/{n}/files` — GitHub's maximum.
+const PR_FILES_PAGE_SIZE: usize = 100;
+
+/// GitHub stops listing a PR's files after 3000; paging past that returns
+/// empty pages, so stop there.
+const PR_FILES_MAX_PAGES: usize = 30;
 
 /// Run `gh pr view <pr_url> --json <fields>` and parse stdout as JSON.
 /// `fields` is a comma-separated list, e.g. `"files"` or
@@ -47,6 +59,123 @@ pub async fn fetch_pr_changed_files(pr_url: &str) -> Result<Vec<String>> {
     Ok(parse_changed_file_paths(&root))
 }
 
+/// One changed file from `GET /repos/{slug}/pulls/{n}/files`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrFilePatch {
+    /// Path relative to the repo root, at the PR head.
+    pub path: String,
+    /// Unified-diff hunks for the file. `None` when GitHub omits the
+    /// patch: binary files, and files whose diff is too large to inline.
+    pub patch: Option<String>,
+}
+
+/// One line on the right-hand (PR head) side of a patch — a line a
+/// review comment with `side: RIGHT` can anchor to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffLine {
+    /// 1-based line number in the file at the PR head.
+    pub line: u32,
+    /// The line's text, without the leading diff marker.
+    pub text: String,
+    /// `true` for an added (`+`) line, `false` for context.
+    pub added: bool,
+}
+
+impl PrFilePatch {
+    /// Every right-hand line the patch shows, in file order. GitHub only
+    /// accepts review comments on these lines, so anything else must be
+    /// moved onto one of them or left out of the inline comments.
+    pub fn right_side_lines(&self) -> Vec<DiffLine> {
+        let Some(patch) = self.patch.as_deref() else {
+            return Vec::new();
+        };
+        let mut lines = Vec::new();
+        let mut next_line: Option<u32> = None;
+        for raw in patch.lines() {
+            if let Some(header) = raw.strip_prefix("@@") {
+                next_line = parse_hunk_new_start(header);
+                continue;
+            }
+            let Some(line) = next_line.as_mut() else {
+                continue;
+            };
+            if let Some(text) = raw.strip_prefix('+') {
+                lines.push(DiffLine {
+                    line: *line,
+                    text: text.to_owned(),
+                    added: true,
+                });
+                *line += 1;
+            } else if let Some(text) = raw.strip_prefix(' ') {
+                lines.push(DiffLine {
+                    line: *line,
+                    text: text.to_owned(),
+                    added: false,
+                });
+                *line += 1;
+            } else if raw.is_empty() {
+                // A context line whose content is empty loses its leading
+                // space in some patch renderings.
+                lines.push(DiffLine {
+                    line: *line,
+                    text: String::new(),
+                    added: false,
+                });
+                *line += 1;
+            }
+            // `-` lines and `\ No newline at end of file` have no
+            // right-hand line.
+        }
+        lines
+    }
+}
+
+/// `+c` out of a hunk header's ` -a,b +c,d @@ ...` tail.
+fn parse_hunk_new_start(header: &str) -> Option<u32> {
+    let new_range = header.split_whitespace().find(|part| part.starts_with('+'))?;
+    new_range[1..].split(',').next()?.parse().ok()
+}
+
+/// Pure extraction of `[{filename, patch}]` from one page of a
+/// `GET /repos/{slug}/pulls/{n}/files` response. Entries without a
+/// `filename` are skipped; a non-array body yields an empty vec.
+pub fn parse_pr_file_patches(root: &Value) -> Vec<PrFilePatch> {
+    root.as_array()
+        .map(|files| {
+            files
+                .iter()
+                .filter_map(|f| {
+                    Some(PrFilePatch {
+                        path: f.get("filename")?.as_str()?.to_owned(),
+                        patch: f.get("patch").and_then(|p| p.as_str()).map(str::to_owned),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Fetch every changed file of PR `pr_number` in `repo_slug` with its
+/// patch, paging through `GET /repos/{slug}/pulls/{n}/files`.
+pub async fn fetch_pr_file_patches(
+    gh: &dyn GhRunner,
+    repo_slug: &str,
+    pr_number: u64,
+) -> std::result::Result<Vec<PrFilePatch>, GhRunnerError> {
+    let mut files = Vec::new();
+    for page in 1..=PR_FILES_MAX_PAGES {
+        let path = format!("repos/{repo_slug}/pulls/{pr_number}/files?per_page={PR_FILES_PAGE_SIZE}&page={page}");
+        let response = gh.rest_get(&path, None).await?;
+        let page_files = parse_pr_file_patches(&response.body);
+        let last_page = page_files.len() < PR_FILES_PAGE_SIZE;
+        files.extend(page_files);
+        if last_page {
+            break;
+        }
+    }
+    Ok(files)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -79,4 +208,47 @@ mod tests {
         let root = serde_json::json!({"files": [{"changeType": "MODIFIED"}, {"path": "src/c.rs"}]});
         assert_eq!(parse_changed_file_paths(&root), vec!["src/c.rs"]);
     }
+
+    #[test]
+    fn parse_pr_file_patches_keeps_missing_patches_as_none() {
+        let root = serde_json::json!([
+            {"filename": "src/a.rs", "patch": "@@ -1 +1 @@\n-a\n+b"},
+            {"filename": "logo.png"},
+            {"status": "removed"},
+        ]);
+        let files = parse_pr_file_patches(&root);
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].patch.as_deref(), Some("@@ -1 +1 @@\n-a\n+b"));
+        assert_eq!(files[1].path, "logo.png");
+        assert!(files[1].patch.is_none());
+    }
+
+    #[test]
+    fn right_side_lines_number_context_and_additions_across_hunks() {
+        let file = PrFilePatch {
+            path: "src/lib.rs".to_owned(),
+            patch: Some(
+                "@@ -10,3 +10,4 @@ fn init() {\n     let a = 1;\n-    let b = 2;\n+    let b = 3;\n+    let c = 4;\n \
+                 }\n@@ -40,2 +41,2 @@\n-old\n+new\n tail\n\\ No newline at end of file"
+                    .to_owned(),
+            ),
+        };
+        let lines: Vec<(u32, bool)> = file
+            .right_side_lines()
+            .iter()
+            .map(|line| (line.line, line.added))
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                (10, false),
+                (11, true),
+                (12, true),
+                (13, false),
+                (41, true),
+                (42, false)
+            ]
+        );
+        assert_eq!(file.right_side_lines()[1].text, "    let b = 3;");
+    }
 }
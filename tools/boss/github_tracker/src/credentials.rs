@@ -1,12 +1,14 @@
 //! Credential resolution for external trackers.
 //!
-//! Two resolvers are provided:
+//! Three resolvers are provided:
 //!
 //! - [`GhAuthStatusResolver`] — v1: delegates to `gh auth status` and returns
 //!   an ambient credential (no stored token).
+//! - [`ApiTokenResolver`] — Linear and Jira: reads a per-kind API token from
+//!   an environment override or the OS keychain.
 //! - [`KeychainOAuthResolver`] — v2: checks the OS keychain for a stored OAuth
 //!   token first, and falls back to [`GhAuthStatusResolver`] when none is
-//!   present.
+//!   present. Linear and Jira kinds are routed to [`ApiTokenResolver`].
 
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
@@ -22,7 +24,7 @@ use crate::github_oauth::KeychainTokenStore;
 
 #[derive(Debug, Error)]
 pub enum TrackerCredentialError {
-    #[error("gh auth status failed for {host}: {detail}")]
+    #[error("credential check failed for {host}: {detail}")]
     AuthFailed { host: String, detail: String },
     #[error("unsupported tracker kind: {0}")]
     UnsupportedKind(String),
@@ -138,13 +140,172 @@ impl TrackerCredentialResolver for GhAuthStatusResolver {
     }
 }
 
+// ── ApiTokenResolver ──────────────────────────────────────────────────────────
+
+/// Environment override for the Linear API key. Takes precedence over the
+/// keychain, mirroring `BOSS_TRUNK_API_TOKEN`.
+pub const LINEAR_API_TOKEN_ENV: &str = "BOSS_LINEAR_API_TOKEN";
+
+/// Environment override for the Jira API token (Cloud) or personal access
+/// token (Server). Takes precedence over the keychain.
+pub const JIRA_API_TOKEN_ENV: &str = "BOSS_JIRA_API_TOKEN";
+
+/// Keychain entry holding one tracker API token.
+///
+/// Entries are keyed by kind and host — service
+/// `dev.spinyfin.boss.<kind>`, account `api-token@<host>` — so two Jira
+/// sites keep separate tokens.
+pub struct ApiTokenStore {
+    inner: boss_keychain::KeychainStore,
+}
+
+impl ApiTokenStore {
+    /// Opens the entry for `kind` at `host` in the platform credential store.
+    pub fn new(kind: &str, host: &str) -> Self {
+        Self {
+            inner: boss_keychain::KeychainStore::new(
+                &format!("dev.spinyfin.boss.{kind}"),
+                &format!("api-token@{host}"),
+                &format!("{kind}-api-token-{host}"),
+            ),
+        }
+    }
+
+    /// Creates a store backed by the given test fake.  Only available in
+    /// `#[cfg(test)]` builds.
+    #[cfg(test)]
+    pub(crate) fn with_backend(backend: impl boss_keychain::KeystoreBackend + 'static) -> Self {
+        Self {
+            inner: boss_keychain::KeychainStore::with_backend(backend),
+        }
+    }
+
+    /// Returns the stored token, or `None` if the entry is absent or blank.
+    pub fn get(&self) -> Result<Option<String>, boss_keychain::TokenStoreError> {
+        Ok(self.inner.get_raw()?.filter(|t| !t.trim().is_empty()))
+    }
+
+    pub fn set(&self, token: &str) -> Result<(), boss_keychain::TokenStoreError> {
+        self.inner.set_raw(token)
+    }
+
+    pub fn delete(&self) -> Result<(), boss_keychain::TokenStoreError> {
+        self.inner.delete_raw()
+    }
+}
+
+/// Host an API token is scoped to: Linear is a single SaaS host; Jira
+/// tokens belong to one site, taken from the config's `base_url`.
+pub fn api_token_host(kind: &str, config: &serde_json::Value) -> Result<String, TrackerCredentialError> {
+    match kind {
+        "linear" => Ok("linear.app".to_owned()),
+        "jira" => config
+            .get("base_url")
+            .and_then(|v| v.as_str())
+            .and_then(|u| reqwest::Url::parse(u).ok())
+            .and_then(|u| u.host_str().map(|h| h.to_owned()))
+            .ok_or_else(|| TrackerCredentialError::AuthFailed {
+                host: "jira".to_owned(),
+                detail: "config has no valid 'base_url' to scope the API token to".to_owned(),
+            }),
+        other => Err(TrackerCredentialError::UnsupportedKind(other.to_owned())),
+    }
+}
+
+fn api_token_env_var(kind: &str) -> Option<&'static str> {
+    match kind {
+        "linear" => Some(LINEAR_API_TOKEN_ENV),
+        "jira" => Some(JIRA_API_TOKEN_ENV),
+        _ => None,
+    }
+}
+
+type StoreOpener = Box<dyn Fn(&str, &str) -> ApiTokenStore + Send + Sync>;
+
+/// Resolves API-token credentials for Linear and Jira.
+///
+/// Lookup order: the kind's environment override ([`LINEAR_API_TOKEN_ENV`],
+/// [`JIRA_API_TOKEN_ENV`]), then the [`ApiTokenStore`] entry for the
+/// config's host. A missing token is `AuthFailed`, so the product is skipped
+/// with an attention item exactly like a failed `gh auth status`. Keychain
+/// errors are logged and treated as "no stored token".
+pub struct ApiTokenResolver {
+    env: fn(&str) -> Option<String>,
+    open_store: StoreOpener,
+}
+
+impl Default for ApiTokenResolver {
+    fn default() -> Self {
+        Self {
+            env: |name| std::env::var(name).ok().filter(|v| !v.trim().is_empty()),
+            open_store: Box::new(ApiTokenStore::new),
+        }
+    }
+}
+
+impl ApiTokenResolver {
+    /// Test constructor: replace the environment and keychain lookups.
+    #[cfg(test)]
+    pub(crate) fn with_sources(
+        env: fn(&str) -> Option<String>,
+        open_store: impl Fn(&str, &str) -> ApiTokenStore + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            env,
+            open_store: Box::new(open_store),
+        }
+    }
+}
+
+#[async_trait]
+impl TrackerCredentialResolver for ApiTokenResolver {
+    async fn resolve(
+        &self,
+        kind: &str,
+        config: &serde_json::Value,
+    ) -> Result<TrackerCredential, TrackerCredentialError> {
+        let env_var =
+            api_token_env_var(kind).ok_or_else(|| TrackerCredentialError::UnsupportedKind(kind.to_owned()))?;
+        if let Some(token) = (self.env)(env_var) {
+            return Ok(TrackerCredential { token });
+        }
+
+        let host = api_token_host(kind, config)?;
+        match (self.open_store)(kind, &host).get() {
+            Ok(Some(token)) => return Ok(TrackerCredential { token }),
+            Ok(None) => {}
+            Err(e) => {
+                tracing::warn!(
+                    target: "boss_github_tracker::credentials",
+                    %kind,
+                    %host,
+                    error = %e,
+                    "keychain unavailable while resolving tracker API token"
+                );
+            }
+        }
+
+        tracing::warn!(
+            target: "boss_github_tracker::credentials",
+            %kind,
+            %host,
+            "no API token configured; {kind}-bound products will be skipped until one is stored"
+        );
+        Err(TrackerCredentialError::AuthFailed {
+            host,
+            detail: format!("no API token: set {env_var} or store one in the keychain"),
+        })
+    }
+}
+
 // ── KeychainOAuthResolver ─────────────────────────────────────────────────────
 
 /// Resolves a [`TrackerCredential`] by consulting the OS keychain first.
 ///
 /// When a stored OAuth token is present for GitHub, it is returned directly.
 /// When absent (or if the keychain is unavailable), the resolver delegates to
-/// [`GhAuthStatusResolver`] which relies on the ambient `gh` login.
+/// [`GhAuthStatusResolver`] which relies on the ambient `gh` login. Linear
+/// and Jira never use OAuth here; they go straight to [`ApiTokenResolver`].
 ///
 /// # Keychain errors
 /// A keychain access failure is treated as "no stored token" — a warning is
@@ -154,6 +315,7 @@ impl TrackerCredentialResolver for GhAuthStatusResolver {
 pub struct KeychainOAuthResolver {
     store: KeychainTokenStore,
     fallback: GhAuthStatusResolver,
+    api_tokens: ApiTokenResolver,
 }
 
 impl KeychainOAuthResolver {
@@ -161,6 +323,7 @@ impl KeychainOAuthResolver {
         Self {
             store,
             fallback: GhAuthStatusResolver::default(),
+            api_tokens: ApiTokenResolver::default(),
         }
     }
 
@@ -168,7 +331,18 @@ impl KeychainOAuthResolver {
     /// at a fake `gh` binary).
     #[cfg(test)]
     pub(crate) fn with_fallback(store: KeychainTokenStore, fallback: GhAuthStatusResolver) -> Self {
-        Self { store, fallback }
+        Self {
+            store,
+            fallback,
+            api_tokens: ApiTokenResolver::default(),
+        }
+    }
+
+    /// Test-only: replace the API-token resolver used for Linear and Jira.
+    #[cfg(test)]
+    pub(crate) fn with_api_tokens(mut self, api_tokens: ApiTokenResolver) -> Self {
+        self.api_tokens = api_tokens;
+        self
     }
 }
 
@@ -179,6 +353,9 @@ impl TrackerCredentialResolver for KeychainOAuthResolver {
         kind: &str,
         config: &serde_json::Value,
     ) -> Result<TrackerCredential, TrackerCredentialError> {
+        if api_token_env_var(kind).is_some() {
+            return self.api_tokens.resolve(kind, config).await;
+        }
         if kind == "github" {
             match self.store.get() {
                 Ok(Some(record)) => return Ok(TrackerCredential { token: record.token }),
@@ -326,16 +503,119 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn keychain_resolver_delegates_non_github_kind_to_fallback() {
+    async fn keychain_resolver_delegates_unknown_kind_to_fallback() {
         let store = KeychainTokenStore::with_backend(FakeStore::empty());
         let resolver = KeychainOAuthResolver::new(store);
         let err = resolver
-            .resolve("jira", &serde_json::Value::Null)
+            .resolve("gitlab", &serde_json::Value::Null)
             .await
-            .expect_err("should fail for non-github kind");
+            .expect_err("should fail for unsupported kind");
         assert!(
-            matches!(err, TrackerCredentialError::UnsupportedKind(ref k) if k == "jira"),
-            "expected UnsupportedKind(jira), got {err:?}"
+            matches!(err, TrackerCredentialError::UnsupportedKind(ref k) if k == "gitlab"),
+            "expected UnsupportedKind(gitlab), got {err:?}"
         );
     }
+
+    // ── ApiTokenResolver ──────────────────────────────────────────────────
+
+    /// In-memory keychain entry holding a raw token string.
+    struct RawFake(std::sync::Mutex<Option<String>>);
+
+    impl boss_keychain::KeystoreBackend for RawFake {
+        fn get_raw(&self) -> Result<Option<String>, boss_keychain::TokenStoreError> {
+            Ok(self.0.lock().unwrap().clone())
+        }
+
+        fn set_raw(&self, value: &str) -> Result<(), boss_keychain::TokenStoreError> {
+            *self.0.lock().unwrap() = Some(value.to_owned());
+            Ok(())
+        }
+
+        fn delete_raw(&self) -> Result<(), boss_keychain::TokenStoreError> {
+            *self.0.lock().unwrap() = None;
+            Ok(())
+        }
+    }
+
+    fn no_env(_: &str) -> Option<String> {
+        None
+    }
+
+    /// Keychain stand-in that only holds a token for `jira` at `acme.atlassian.net`.
+    fn jira_site_store(kind: &str, host: &str) -> ApiTokenStore {
+        let token = (kind == "jira" && host == "acme.atlassian.net").then(|| "stored-jira-token".to_owned());
+        ApiTokenStore::with_backend(RawFake(std::sync::Mutex::new(token)))
+    }
+
+    fn jira_config() -> serde_json::Value {
+        serde_json::json!({ "base_url": "https://acme.atlassian.net", "project_key": "OPS" })
+    }
+
+    #[tokio::test]
+    async fn api_token_resolver_prefers_env_override() {
+        let resolver = ApiTokenResolver::with_sources(
+            |name| (name == JIRA_API_TOKEN_ENV).then(|| "env-jira-token".to_owned()),
+            jira_site_store,
+        );
+        let cred = resolver.resolve("jira", &jira_config()).await.unwrap();
+        assert_eq!(cred.token, "env-jira-token");
+    }
+
+    #[tokio::test]
+    async fn api_token_resolver_reads_keychain_entry_for_config_host() {
+        let resolver = ApiTokenResolver::with_sources(no_env, jira_site_store);
+        let cred = resolver.resolve("jira", &jira_config()).await.unwrap();
+        assert_eq!(cred.token, "stored-jira-token");
+
+        let other_site = serde_json::json!({ "base_url": "https://jira.other.dev", "project_key": "OPS" });
+        let err = resolver.resolve("jira", &other_site).await.unwrap_err();
+        assert!(
+            matches!(err, TrackerCredentialError::AuthFailed { ref host, .. } if host == "jira.other.dev"),
+            "expected AuthFailed for jira.other.dev, got {err:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn api_token_resolver_reports_missing_linear_token_as_auth_failed() {
+        let resolver = ApiTokenResolver::with_sources(no_env, jira_site_store);
+        let err = resolver
+            .resolve("linear", &serde_json::json!({ "team_key": "ENG" }))
+            .await
+            .unwrap_err();
+        match err {
+            TrackerCredentialError::AuthFailed { host, detail } => {
+                assert_eq!(host, "linear.app");
+                assert!(detail.contains(LINEAR_API_TOKEN_ENV), "{detail}");
+            }
+            other => panic!("expected AuthFailed, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn api_token_resolver_rejects_non_token_kinds() {
+        let resolver = ApiTokenResolver::with_sources(no_env, jira_site_store);
+        let err = resolver.resolve("github", &serde_json::Value::Null).await.unwrap_err();
+        assert!(matches!(err, TrackerCredentialError::UnsupportedKind(_)), "got {err:?}");
+    }
+
+    #[tokio::test]
+    async fn keychain_resolver_routes_linear_and_jira_to_api_tokens() {
+        let store = KeychainTokenStore::with_backend(FakeStore::prefilled(&sample_record()));
+        let resolver =
+            KeychainOAuthResolver::new(store).with_api_tokens(ApiTokenResolver::with_sources(no_env, jira_site_store));
+        let cred = resolver.resolve("jira", &jira_config()).await.unwrap();
+        assert_eq!(cred.token, "stored-jira-token");
+        let err = resolver.resolve("linear", &serde_json::Value::Null).await.unwrap_err();
+        assert!(matches!(err, TrackerCredentialError::AuthFailed { .. }), "got {err:?}");
+    }
+
+    #[test]
+    fn api_token_store_round_trips_and_ignores_blank_entries() {
+        let store = ApiTokenStore::with_backend(RawFake(std::sync::Mutex::new(Some("  ".to_owned()))));
+        assert_eq!(store.get().unwrap(), None);
+        store.set("lin_api_abc").unwrap();
+        assert_eq!(store.get().unwrap().as_deref(), Some("lin_api_abc"));
+        store.delete().unwrap();
+        assert_eq!(store.get().unwrap(), None);
+    }
 }
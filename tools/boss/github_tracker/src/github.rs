@@ -8,8 +8,8 @@ use boss_github::gh_runner::{CommandGhRunner, GhRunner, GhRunnerError};
 use serde_json::Value;
 
 use crate::{
-    CloseReason, ClosedReason, ExternalTracker, Result, TrackerConfigError, TrackerContext, TrackerError, UpstreamItem,
-    UpstreamPrAssociation, UpstreamRef, UpstreamStatus,
+    CloseReason, ClosedReason, ExternalTracker, Result, TrackerConfigError, TrackerContext, TrackerError,
+    UpstreamComment, UpstreamFieldUpdate, UpstreamItem, UpstreamPrAssociation, UpstreamRef, UpstreamStatus,
 };
 
 // ── Config ────────────────────────────────────────────────────────────────────
@@ -134,6 +134,9 @@ mutation($projectId: ID!, $itemId: ID!, $fieldId: ID!, $optionId: String!) {
 }
 ";
 
+/// Safety cap on issue-comment pages: 10 × 100 = 1000 comments per issue.
+const COMMENT_MAX_PAGES: u32 = 10;
+
 // ── Parsing helpers ───────────────────────────────────────────────────────────
 
 /// Pull and validate the `issue_number` stored in an [`UpstreamRef`]'s raw blob.
@@ -144,6 +147,34 @@ fn extract_issue_number(ref_: &UpstreamRef) -> Result<u64> {
         .ok_or_else(|| TrackerError::ConfigInvalid("upstream ref missing 'issue_number' in raw blob".to_owned()))
 }
 
+/// The `owner/repo` an issue lives in. Taken from the canonical_id
+/// (`"owner/repo#number"`) rather than the config, because GitHub Projects
+/// items can reference issues across repos in the same org.
+fn issue_repo<'a>(ref_: &'a UpstreamRef, config: &'a GitHubConfig) -> std::borrow::Cow<'a, str> {
+    match ref_.canonical_id.split_once('#') {
+        Some((repo, _)) => repo.into(),
+        None => format!("{}/{}", config.org, config.repo).into(),
+    }
+}
+
+/// Parse one entry of the REST issue-comments list.
+fn parse_rest_comment(node: &Value) -> Option<UpstreamComment> {
+    Some(UpstreamComment {
+        id: node.get("id")?.as_u64()?.to_string(),
+        author: node
+            .pointer("/user/login")
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_owned(),
+        body: node.get("body").and_then(|b| b.as_str()).unwrap_or("").to_owned(),
+        created_at: node
+            .get("created_at")
+            .and_then(|v| v.as_str())
+            .and_then(boss_engine_utils::iso8601::parse_iso8601_lenient)
+            .unwrap_or(0),
+    })
+}
+
 /// Parse one project item node from the GraphQL `items.nodes` array.
 /// Returns `None` for non-Issue content (DraftIssue, PullRequest, etc.) and
 /// for items excluded by `label_filter`.
@@ -267,6 +298,7 @@ fn parse_project_item(node: &Value, config: &GitHubConfig) -> Option<UpstreamIte
         pr_associations,
         updated_at,
         project_status,
+        priority: None,
     })
 }
 
@@ -327,6 +359,7 @@ fn parse_rest_issue(body: &Value, org: &str, repo: &str) -> Option<UpstreamItem>
         pr_associations: vec![],
         updated_at,
         project_status: None,
+        priority: None,
     })
 }
 
@@ -778,16 +811,7 @@ impl ExternalTracker for GitHubTracker {
         let config = GitHubConfig::from_ctx(ctx)?;
         let issue_number = extract_issue_number(ref_)?;
 
-        // The repo lives in the canonical_id ("owner/repo#number") rather
-        // than the config, because GitHub Projects items can reference
-        // issues across repos in the same org. Parse it back out.
-        let repo_with_owner = ref_
-            .canonical_id
-            .split_once('#')
-            .map(|(r, _)| r)
-            .unwrap_or(&config.repo);
-
-        let path = format!("repos/{}/issues/{}/labels", repo_with_owner, issue_number);
+        let path = format!("repos/{}/issues/{}/labels", issue_repo(ref_, &config), issue_number);
         let body = serde_json::json!({ "labels": [label] });
 
         match self.runner.rest_post(&path, &body, opt_token(ctx)).await {
@@ -798,6 +822,84 @@ impl ExternalTracker for GitHubTracker {
             Err(e) => Err(map_write_error(e)),
         }
     }
+
+    async fn fetch_comments(&self, ctx: &TrackerContext, ref_: &UpstreamRef) -> Result<Vec<UpstreamComment>> {
+        let config = GitHubConfig::from_ctx(ctx)?;
+        let issue_number = extract_issue_number(ref_)?;
+        let repo = issue_repo(ref_, &config);
+
+        let mut comments = Vec::new();
+        for page in 1..=COMMENT_MAX_PAGES {
+            let path = format!("repos/{repo}/issues/{issue_number}/comments?per_page=100&page={page}");
+            let resp = match self.runner.rest_get(&path, opt_token(ctx)).await {
+                Ok(resp) => resp,
+                Err(e) if e.http_status == Some(404) => return Ok(vec![]),
+                Err(e) => return Err(map_write_error(e)),
+            };
+            let nodes = resp.body.as_array().cloned().unwrap_or_default();
+            comments.extend(nodes.iter().filter_map(parse_rest_comment));
+            if nodes.len() < 100 {
+                break;
+            }
+        }
+        Ok(comments)
+    }
+
+    async fn post_comment(&self, ctx: &TrackerContext, ref_: &UpstreamRef, body: &str) -> Result<UpstreamComment> {
+        let config = GitHubConfig::from_ctx(ctx)?;
+        let issue_number = extract_issue_number(ref_)?;
+        let path = format!("repos/{}/issues/{}/comments", issue_repo(ref_, &config), issue_number);
+
+        let resp = self
+            .runner
+            .rest_post(&path, &serde_json::json!({ "body": body }), opt_token(ctx))
+            .await
+            .map_err(map_write_error)?;
+        parse_rest_comment(&resp.body)
+            .ok_or_else(|| TrackerError::Transient("unexpected response shape: created comment has no id".to_owned()))
+    }
+
+    async fn update_fields(
+        &self,
+        ctx: &TrackerContext,
+        ref_: &UpstreamRef,
+        update: &UpstreamFieldUpdate,
+    ) -> Result<()> {
+        let config = GitHubConfig::from_ctx(ctx)?;
+        let issue_number = extract_issue_number(ref_)?;
+
+        if update.priority.is_some() {
+            return Err(TrackerError::Unsupported(
+                "GitHub Issues have no priority field".to_owned(),
+            ));
+        }
+        let mut fields: Vec<(&str, &str)> = Vec::new();
+        if let Some(title) = &update.title {
+            fields.push(("title", title));
+        }
+        if let Some(body) = &update.body {
+            fields.push(("body", body));
+        }
+        if let Some(assignees) = &update.assignees {
+            // `gh api -f` can append to an array but cannot express an
+            // empty one, so clearing every assignee is out of reach.
+            if assignees.is_empty() {
+                return Err(TrackerError::Unsupported(
+                    "clearing GitHub assignees is not supported".to_owned(),
+                ));
+            }
+            fields.extend(assignees.iter().map(|a| ("assignees[]", a.as_str())));
+        }
+        if fields.is_empty() {
+            return Ok(());
+        }
+
+        let path = format!("repos/{}/issues/{}", issue_repo(ref_, &config), issue_number);
+        match self.runner.rest_patch(&path, &fields, opt_token(ctx)).await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(map_write_error(e)),
+        }
+    }
 }
 
 // ── Tests ─────────────────────────────────────────────────────────────────────
@@ -1902,6 +2004,107 @@ mod tests {
         assert!(matches!(err, TrackerError::Transient(_)), "{err:?}");
     }
 
+    // ── Comment and field sync ────────────────────────────────────────────────
+
+    fn rest_comment(id: u64, login: &str, body: &str) -> Value {
+        json!({
+            "id": id,
+            "user": { "login": login },
+            "body": body,
+            "created_at": "2026-05-01T12:00:00Z"
+        })
+    }
+
+    #[tokio::test]
+    async fn fetch_comments_parses_rest_comments() {
+        let mut fake = FakeGhRunner::new();
+        fake.push_rest_get_ok(json!([
+            rest_comment(11, "octocat", "first"),
+            rest_comment(12, "hubot", "second"),
+        ]));
+        let tracker = GitHubTracker::with_runner(fake);
+        let comments = tracker.fetch_comments(&github_ctx(), &issue_ref(560)).await.unwrap();
+        assert_eq!(comments.len(), 2);
+        assert_eq!(comments[0].id, "11");
+        assert_eq!(comments[0].author, "octocat");
+        assert_eq!(comments[0].body, "first");
+        assert!(comments[0].created_at > 0);
+        assert_eq!(comments[1].id, "12");
+    }
+
+    #[tokio::test]
+    async fn fetch_comments_returns_empty_on_404() {
+        let mut fake = FakeGhRunner::new();
+        fake.push_rest_get_err(404, "Not Found (HTTP 404)");
+        let tracker = GitHubTracker::with_runner(fake);
+        let comments = tracker.fetch_comments(&github_ctx(), &issue_ref(999)).await.unwrap();
+        assert!(comments.is_empty());
+    }
+
+    #[tokio::test]
+    async fn post_comment_returns_created_comment() {
+        let mut fake = FakeGhRunner::new();
+        fake.push_rest_post_ok(rest_comment(77, "boss-bot", "hello"));
+        let tracker = GitHubTracker::with_runner(fake);
+        let comment = tracker
+            .post_comment(&github_ctx(), &issue_ref(560), "hello")
+            .await
+            .unwrap();
+        assert_eq!(comment.id, "77");
+        assert_eq!(comment.body, "hello");
+    }
+
+    #[tokio::test]
+    async fn post_comment_returns_permission_denied_on_403() {
+        let mut fake = FakeGhRunner::new();
+        fake.push_rest_post_err(403, "Forbidden (HTTP 403)");
+        let tracker = GitHubTracker::with_runner(fake);
+        let err = tracker
+            .post_comment(&github_ctx(), &issue_ref(560), "hello")
+            .await
+            .expect_err("should fail on 403");
+        assert!(matches!(err, TrackerError::PermissionDenied(_)), "{err:?}");
+    }
+
+    #[tokio::test]
+    async fn update_fields_patches_title() {
+        let mut fake = FakeGhRunner::new();
+        fake.push_rest_patch_ok(rest_issue(560, "open", None));
+        let tracker = GitHubTracker::with_runner(fake);
+        let update = UpstreamFieldUpdate {
+            title: Some("Renamed".into()),
+            ..Default::default()
+        };
+        tracker
+            .update_fields(&github_ctx(), &issue_ref(560), &update)
+            .await
+            .expect("title update should succeed");
+    }
+
+    #[tokio::test]
+    async fn update_fields_rejects_priority_and_assignee_clear() {
+        let tracker = GitHubTracker::with_runner(FakeGhRunner::new());
+        let priority = UpstreamFieldUpdate {
+            priority: Some("high".into()),
+            ..Default::default()
+        };
+        let err = tracker
+            .update_fields(&github_ctx(), &issue_ref(560), &priority)
+            .await
+            .expect_err("priority is unsupported");
+        assert!(matches!(err, TrackerError::Unsupported(_)), "{err:?}");
+
+        let clear = UpstreamFieldUpdate {
+            assignees: Some(vec![]),
+            ..Default::default()
+        };
+        let err = tracker
+            .update_fields(&github_ctx(), &issue_ref(560), &clear)
+            .await
+            .expect_err("clearing assignees is unsupported");
+        assert!(matches!(err, TrackerError::Unsupported(_)), "{err:?}");
+    }
+
     #[tokio::test]
     async fn add_label_returns_config_invalid_on_missing_issue_number() {
         let ref_without_number = UpstreamRef {
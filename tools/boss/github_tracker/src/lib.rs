@@ -1,13 +1,13 @@
 //! External issue tracker abstraction and its GitHub transport.
 //!
 //! The `ExternalTracker` trait is the seam between Boss's work-item taxonomy
-//! and any upstream tracker (GitHub Projects today; Jira, Linear later).
-//! All tracker-specific logic lives in a sub-module; the reconciler only
-//! touches the types and trait defined here.
+//! and any upstream tracker (GitHub Projects, Linear, Jira). All
+//! tracker-specific logic lives in a sub-module; the reconciler only touches
+//! the types and trait defined here.
 //!
 //! This crate is deliberately transport-only and sits *below* the engine:
-//! it holds the tracker vocabulary, the GitHub REST client, the device-flow
-//! OAuth implementation, and credential resolution. It must never import
+//! it holds the tracker vocabulary, the GitHub, Linear and Jira clients, the
+//! device-flow OAuth implementation, and credential resolution. It must never import
 //! from `boss_engine` — reconciliation policy (which needs the work DB, task
 //! statuses, and content checksums) stays up in the engine and depends on
 //! this crate, never the other way round. Where a transport operation needs
@@ -17,6 +17,8 @@
 pub mod credentials;
 pub mod github;
 pub mod github_oauth;
+pub mod jira;
+pub mod linear;
 
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -57,6 +59,44 @@ pub struct UpstreamItem {
     /// don't have a board column, where the Status field hasn't been
     /// set on the item, or where fetching it is not supported.
     pub project_status: Option<String>,
+    /// Priority normalised onto Boss's vocabulary (`"low"`, `"medium"`,
+    /// `"high"`). `None` for trackers without a native priority field
+    /// (GitHub Issues) or when the upstream item has no priority set.
+    pub priority: Option<String>,
+}
+
+/// One comment on an upstream issue, as returned by
+/// [`ExternalTracker::fetch_comments`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpstreamComment {
+    /// Tracker-native comment id, stable across edits.
+    pub id: String,
+    /// Display handle of the author (GitHub login, Linear or Jira display name).
+    pub author: String,
+    /// Comment body as plain text / markdown.
+    pub body: String,
+    /// Creation time as Unix seconds.
+    pub created_at: i64,
+}
+
+/// Field edits pushed from Boss to an upstream item by
+/// [`ExternalTracker::update_fields`]. `None` leaves a field untouched.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UpstreamFieldUpdate {
+    pub title: Option<String>,
+    pub body: Option<String>,
+    /// Replacement assignee set. An empty vec clears every assignee.
+    pub assignees: Option<Vec<String>>,
+    /// Normalised priority (`"low"`, `"medium"`, `"high"`), same vocabulary
+    /// as [`UpstreamItem::priority`].
+    pub priority: Option<String>,
+}
+
+impl UpstreamFieldUpdate {
+    /// `true` when no field is set, i.e. the update would be a no-op.
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none() && self.body.is_none() && self.assignees.is_none() && self.priority.is_none()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -170,8 +210,8 @@ pub enum TrackerError {
     #[error("not found: {0}")]
     NotFound(String),
 
-    /// The operation is not supported by this tracker.
-    /// Reserved for future read-only tracker variants.
+    /// The operation is not supported by this tracker (e.g. a field it has
+    /// no representation for).
     #[error("unsupported: {0}")]
     Unsupported(String),
 }
@@ -252,6 +292,44 @@ pub trait ExternalTracker: Send + Sync {
     async fn post_closing_pr_comment(&self, _ctx: &TrackerContext, _ref_: &UpstreamRef, _pr_url: &str) -> Result<()> {
         Ok(())
     }
+
+    /// Fetch every comment on an upstream issue, oldest first.
+    ///
+    /// Called by the reconciler's comment mirror when a product opts into
+    /// comment sync.  A deleted issue returns an empty list.  The default
+    /// returns `Unsupported`, which the reconciler treats as "this tracker
+    /// has no comment surface" and skips comment sync for the product.
+    async fn fetch_comments(&self, _ctx: &TrackerContext, _ref_: &UpstreamRef) -> Result<Vec<UpstreamComment>> {
+        Err(TrackerError::Unsupported("comments".to_owned()))
+    }
+
+    /// Post a new comment on an upstream issue and return it as stored.
+    ///
+    /// Unlike `post_closing_pr_comment` this is NOT idempotent: the caller
+    /// records the returned comment id and never re-posts the same Boss
+    /// comment.
+    ///
+    /// Error classification: same as `close_issue`, except `NotFound` is
+    /// surfaced rather than swallowed since there is no comment to return.
+    async fn post_comment(&self, _ctx: &TrackerContext, _ref_: &UpstreamRef, _body: &str) -> Result<UpstreamComment> {
+        Err(TrackerError::Unsupported("comments".to_owned()))
+    }
+
+    /// Push Boss-side edits of title, body, assignees, or priority upstream.
+    ///
+    /// Only the fields set on `update` are written.  Implementations return
+    /// `Unsupported` for a field the tracker cannot represent (e.g. priority
+    /// on GitHub Issues); the reconciler then stops pushing that field.
+    ///
+    /// Error classification: same as `close_issue`.
+    async fn update_fields(
+        &self,
+        _ctx: &TrackerContext,
+        _ref_: &UpstreamRef,
+        _update: &UpstreamFieldUpdate,
+    ) -> Result<()> {
+        Err(TrackerError::Unsupported("field updates".to_owned()))
+    }
 }
 
 // ── Registry ─────────────────────────────────────────────────────────────────
@@ -408,6 +486,7 @@ mod tests {
             pr_associations: vec![],
             updated_at: 0,
             project_status: None,
+            priority: None,
         };
         let tracker = EchoTracker::new(vec![item]);
         let ctx = TrackerContext {
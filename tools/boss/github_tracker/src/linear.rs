@@ -0,0 +1,1333 @@
+//! Linear backend for [`ExternalTracker`].
+//!
+//! Talks to Linear's GraphQL API directly over HTTPS with a personal API key
+//! (resolved by [`crate::credentials::ApiTokenResolver`]). A product binds to
+//! one Linear team; every issue in that team is in scope, optionally narrowed
+//! by `label_filter`. Linear has no separate board column concept — the
+//! issue's workflow state *is* the column — so `project_status` carries the
+//! state name and `set_project_status` moves the issue between states.
+
+use async_trait::async_trait;
+use serde_json::{Value, json};
+
+use crate::{
+    CloseReason, ClosedReason, ExternalTracker, Result, TrackerConfigError, TrackerContext, TrackerError,
+    UpstreamComment, UpstreamFieldUpdate, UpstreamItem, UpstreamPrAssociation, UpstreamRef, UpstreamStatus,
+};
+
+/// Production GraphQL endpoint.
+pub const LINEAR_API_URL: &str = "https://api.linear.app/graphql";
+
+// ── Config ────────────────────────────────────────────────────────────────────
+
+/// Deserialized shape of `products.external_tracker_config` for Linear.
+#[derive(Debug, serde::Deserialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct LinearConfig {
+    /// Team key, the prefix of issue identifiers (`"ENG"` for `ENG-123`).
+    pub team_key: String,
+    /// If set, only issues that carry at least one of these labels are returned.
+    pub label_filter: Option<Vec<String>>,
+    #[serde(default)]
+    pub reverse_close: bool,
+    /// Name of the team workflow state to move an issue to when a linked
+    /// Boss task becomes active. Defaults to `"In Progress"` when absent.
+    pub in_progress_column: Option<String>,
+}
+
+impl LinearConfig {
+    fn in_progress_column_name(&self) -> &str {
+        self.in_progress_column.as_deref().unwrap_or("In Progress")
+    }
+
+    fn from_ctx(ctx: &TrackerContext) -> Result<Self> {
+        serde_json::from_value(ctx.config.clone())
+            .map_err(|e| TrackerError::ConfigInvalid(format!("invalid Linear tracker config: {e}")))
+    }
+}
+
+/// Write-time validation of a Linear tracker config. Shared by
+/// [`LinearTracker::validate_config`] and the engine's RPC handlers, which
+/// validate before a tracker instance is in reach.
+pub fn validate_config(config: &Value) -> std::result::Result<(), TrackerConfigError> {
+    let obj = config
+        .as_object()
+        .ok_or_else(|| TrackerConfigError::new("config must be a JSON object"))?;
+    if !obj.contains_key("team_key") {
+        return Err(TrackerConfigError::new("missing required field 'team_key'"));
+    }
+    let parsed = serde_json::from_value::<LinearConfig>(config.clone())
+        .map_err(|e| TrackerConfigError::new(format!("invalid config shape: {e}")))?;
+    if parsed.team_key.trim().is_empty() {
+        return Err(TrackerConfigError::new("'team_key' must not be empty"));
+    }
+    Ok(())
+}
+
+// ── GraphQL documents ─────────────────────────────────────────────────────────
+
+/// Issue fields shared by the list and single-issue queries.
+const ISSUE_FIELDS: &str = "
+  id
+  identifier
+  title
+  description
+  url
+  updatedAt
+  priority
+  state { name type }
+  labels(first: 50) { nodes { name } }
+  assignee { name }
+  attachments(first: 20) { nodes { url sourceType metadata } }
+";
+
+fn issues_query() -> String {
+    format!(
+        "query($teamKey: String!, $after: String) {{
+  issues(first: 100, after: $after, filter: {{ team: {{ key: {{ eq: $teamKey }} }} }}) {{
+    pageInfo {{ hasNextPage endCursor }}
+    nodes {{ {ISSUE_FIELDS} }}
+  }}
+}}"
+    )
+}
+
+fn issue_query() -> String {
+    format!("query($id: String!) {{ issue(id: $id) {{ {ISSUE_FIELDS} }} }}")
+}
+
+const ISSUE_STATE_QUERY: &str = "
+query($id: String!) {
+  issue(id: $id) {
+    state { name type }
+  }
+}
+";
+
+const WORKFLOW_STATES_QUERY: &str = "
+query($teamKey: String!) {
+  workflowStates(first: 100, filter: { team: { key: { eq: $teamKey } } }) {
+    nodes { id name type position }
+  }
+}
+";
+
+const ISSUE_UPDATE_STATE_MUTATION: &str = "
+mutation($id: String!, $stateId: String!) {
+  issueUpdate(id: $id, input: { stateId: $stateId }) { success }
+}
+";
+
+const LABELS_QUERY: &str = "
+query($teamKey: String!, $name: String!) {
+  teams(filter: { key: { eq: $teamKey } }) { nodes { id } }
+  issueLabels(first: 50, filter: { name: { eq: $name } }) {
+    nodes { id team { key } }
+  }
+}
+";
+
+const LABEL_CREATE_MUTATION: &str = "
+mutation($name: String!, $teamId: String!) {
+  issueLabelCreate(input: { name: $name, teamId: $teamId }) {
+    issueLabel { id }
+  }
+}
+";
+
+const ISSUE_ADD_LABEL_MUTATION: &str = "
+mutation($id: String!, $labelId: String!) {
+  issueAddLabel(id: $id, labelId: $labelId) { success }
+}
+";
+
+const ISSUE_COMMENTS_QUERY: &str = "
+query($id: String!) {
+  issue(id: $id) {
+    comments(first: 100) { nodes { body } }
+  }
+}
+";
+
+const ISSUE_COMMENT_THREAD_QUERY: &str = "
+query($id: String!, $after: String) {
+  issue(id: $id) {
+    comments(first: 100, after: $after) {
+      pageInfo { hasNextPage endCursor }
+      nodes { id body createdAt user { name } }
+    }
+  }
+}
+";
+
+const COMMENT_CREATE_MUTATION: &str = "
+mutation($issueId: String!, $body: String!) {
+  commentCreate(input: { issueId: $issueId, body: $body }) {
+    success
+    comment { id body createdAt user { name } }
+  }
+}
+";
+
+const ISSUE_UPDATE_MUTATION: &str = "
+mutation($id: String!, $input: IssueUpdateInput!) {
+  issueUpdate(id: $id, input: $input) { success }
+}
+";
+
+const USERS_QUERY: &str = "
+query($name: String!) {
+  users(filter: { or: [
+    { name: { eq: $name } }
+    { displayName: { eq: $name } }
+    { email: { eq: $name } }
+  ] }) {
+    nodes { id }
+  }
+}
+";
+
+// ── Parsing helpers ───────────────────────────────────────────────────────────
+
+/// Pull and validate the `issue_id` stored in an [`UpstreamRef`]'s raw blob.
+fn extract_issue_id(ref_: &UpstreamRef) -> Result<&str> {
+    ref_.raw
+        .get("issue_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| TrackerError::ConfigInvalid("upstream ref missing 'issue_id' in raw blob".to_owned()))
+}
+
+/// Map a Linear workflow state type onto the tracker-neutral status.
+/// `completed` and `canceled` are the two terminal types; every other type
+/// (`triage`, `backlog`, `unstarted`, `started`) is open.
+fn status_for_state_type(state_type: &str) -> UpstreamStatus {
+    match state_type {
+        "completed" => UpstreamStatus::Closed {
+            reason: ClosedReason::Completed,
+        },
+        "canceled" => UpstreamStatus::Closed {
+            reason: ClosedReason::NotPlanned,
+        },
+        _ => UpstreamStatus::Open,
+    }
+}
+
+fn is_terminal_state_type(state_type: &str) -> bool {
+    matches!(state_type, "completed" | "canceled")
+}
+
+/// Map Linear's numeric priority onto Boss's three levels. `0` is "No
+/// priority"; urgent folds into high.
+fn priority_from_linear(priority: i64) -> Option<&'static str> {
+    match priority {
+        1 | 2 => Some("high"),
+        3 => Some("medium"),
+        4 => Some("low"),
+        _ => None,
+    }
+}
+
+fn priority_to_linear(priority: &str) -> Result<i64> {
+    match priority {
+        "high" => Ok(2),
+        "medium" => Ok(3),
+        "low" => Ok(4),
+        other => Err(TrackerError::Unsupported(format!("unknown priority '{other}'"))),
+    }
+}
+
+fn parse_comment(node: &Value) -> Option<UpstreamComment> {
+    Some(UpstreamComment {
+        id: node.get("id")?.as_str()?.to_owned(),
+        author: node
+            .pointer("/user/name")
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_owned(),
+        body: node.get("body").and_then(|b| b.as_str()).unwrap_or("").to_owned(),
+        created_at: node
+            .get("createdAt")
+            .and_then(|v| v.as_str())
+            .and_then(boss_engine_utils::iso8601::parse_iso8601_lenient)
+            .unwrap_or(0),
+    })
+}
+
+/// Parse one issue node. Returns `None` for malformed nodes and for issues
+/// excluded by `label_filter`.
+fn parse_issue(node: &Value, config: &LinearConfig) -> Option<UpstreamItem> {
+    let issue_id = node.get("id")?.as_str()?.to_owned();
+    let identifier = node.get("identifier")?.as_str()?.to_owned();
+    let title = node.get("title")?.as_str()?.to_owned();
+    let body = node
+        .get("description")
+        .and_then(|b| b.as_str())
+        .unwrap_or("")
+        .to_owned();
+    let url = node.get("url")?.as_str()?.to_owned();
+    let updated_at = node
+        .get("updatedAt")
+        .and_then(|v| v.as_str())
+        .and_then(boss_engine_utils::iso8601::parse_iso8601_lenient)
+        .unwrap_or(0);
+
+    let labels: Vec<String> = node
+        .pointer("/labels/nodes")
+        .and_then(|n| n.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|n| n.get("name")?.as_str().map(|s| s.to_owned()))
+        .collect();
+
+    if let Some(filter) = &config.label_filter
+        && !filter.iter().any(|f| labels.iter().any(|l| l == f))
+    {
+        return None;
+    }
+
+    let assignees: Vec<String> = node
+        .pointer("/assignee/name")
+        .and_then(|v| v.as_str())
+        .map(|s| vec![s.to_owned()])
+        .unwrap_or_default();
+
+    // PRs linked through Linear's GitHub integration arrive as attachments
+    // whose metadata carries the PR state.
+    let pr_associations: Vec<UpstreamPrAssociation> = node
+        .pointer("/attachments/nodes")
+        .and_then(|n| n.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|n| {
+            let pr_url = n.get("url")?.as_str()?;
+            if !pr_url.contains("/pull/") {
+                return None;
+            }
+            let metadata = n.get("metadata");
+            let merged = metadata.and_then(|m| m.get("status")).and_then(|s| s.as_str()) == Some("merged");
+            let merged_at = metadata
+                .and_then(|m| m.get("mergedAt"))
+                .and_then(|v| v.as_str())
+                .and_then(boss_engine_utils::iso8601::parse_iso8601_lenient);
+            Some(UpstreamPrAssociation {
+                pr_url: pr_url.to_owned(),
+                merged,
+                merged_at,
+            })
+        })
+        .collect();
+
+    let state_type = node.pointer("/state/type").and_then(|v| v.as_str()).unwrap_or("");
+    let project_status = node
+        .pointer("/state/name")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_owned());
+
+    let raw = json!({
+        "issue_id": issue_id,
+        "identifier": identifier,
+    });
+
+    Some(UpstreamItem {
+        upstream_ref: UpstreamRef {
+            kind: "linear".to_owned(),
+            canonical_id: identifier,
+            raw,
+        },
+        title,
+        body,
+        status: status_for_state_type(state_type),
+        upstream_url: url,
+        labels,
+        assignees,
+        pr_associations,
+        updated_at,
+        project_status,
+        priority: node
+            .get("priority")
+            .and_then(|v| v.as_i64())
+            .and_then(priority_from_linear)
+            .map(|p| p.to_owned()),
+    })
+}
+
+/// Check a GraphQL response for errors, classifying by Linear's
+/// `extensions.code`. "Entity not found" surfaces as `NotFound` so write
+/// paths can treat a deleted issue as success.
+fn check_graphql_errors(response: &Value) -> Result<()> {
+    let Some(errors) = response.get("errors").and_then(|e| e.as_array()) else {
+        return Ok(());
+    };
+    if errors.is_empty() {
+        return Ok(());
+    }
+    let msg = errors
+        .iter()
+        .filter_map(|e| e.get("message")?.as_str())
+        .collect::<Vec<_>>()
+        .join("; ");
+    let codes: Vec<&str> = errors
+        .iter()
+        .filter_map(|e| e.pointer("/extensions/code")?.as_str())
+        .collect();
+    if codes.contains(&"AUTHENTICATION_ERROR") {
+        Err(TrackerError::TokenRevoked(msg))
+    } else if codes.contains(&"FORBIDDEN") {
+        Err(TrackerError::PermissionDenied(msg))
+    } else if msg.contains("Entity not found") {
+        Err(TrackerError::NotFound(msg))
+    } else {
+        Err(TrackerError::Transient(msg))
+    }
+}
+
+/// Map a non-success HTTP status whose body carried no GraphQL errors.
+fn map_http_status(status: reqwest::StatusCode, body: &str) -> TrackerError {
+    let msg = format!("Linear API returned {status}: {body}");
+    match status.as_u16() {
+        401 => TrackerError::TokenRevoked(msg),
+        403 => TrackerError::Auth(msg),
+        400 => TrackerError::ConfigInvalid(msg),
+        _ => TrackerError::Transient(msg),
+    }
+}
+
+// ── LinearTracker ─────────────────────────────────────────────────────────────
+
+/// `ExternalTracker` implementation for Linear issues.
+pub struct LinearTracker {
+    client: reqwest::Client,
+    api_url: String,
+}
+
+impl LinearTracker {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self {
+            client,
+            api_url: LINEAR_API_URL.to_owned(),
+        }
+    }
+
+    /// Point the tracker at a stand-in server. Only available in
+    /// `#[cfg(test)]` builds.
+    #[cfg(test)]
+    pub(crate) fn with_api_url(client: reqwest::Client, api_url: impl Into<String>) -> Self {
+        Self {
+            client,
+            api_url: api_url.into(),
+        }
+    }
+
+    /// POST one GraphQL document and return the decoded response, with
+    /// GraphQL-level errors already classified.
+    async fn graphql(&self, ctx: &TrackerContext, query: &str, variables: Value) -> Result<Value> {
+        if ctx.credential.token.is_empty() {
+            return Err(TrackerError::Auth("no Linear API key configured".to_owned()));
+        }
+        let response = self
+            .client
+            .post(&self.api_url)
+            .header(reqwest::header::AUTHORIZATION, &ctx.credential.token)
+            .json(&json!({ "query": query, "variables": variables }))
+            .send()
+            .await
+            .map_err(|e| TrackerError::Transient(format!("Linear request failed: {e}")))?;
+
+        let status = response.status();
+        let text = response
+            .text()
+            .await
+            .map_err(|e| TrackerError::Transient(format!("Linear response read failed: {e}")))?;
+        // Linear reports most failures as GraphQL errors, sometimes with a
+        // 4xx status; prefer the structured classification when present.
+        let body: Value = match serde_json::from_str(&text) {
+            Ok(v) => v,
+            Err(_) if !status.is_success() => return Err(map_http_status(status, &text)),
+            Err(e) => return Err(TrackerError::Transient(format!("invalid Linear response: {e}"))),
+        };
+        check_graphql_errors(&body)?;
+        if !status.is_success() {
+            return Err(map_http_status(status, &text));
+        }
+        Ok(body)
+    }
+
+    /// Current workflow state type of an issue, or `None` if it is gone.
+    async fn issue_state_type(&self, ctx: &TrackerContext, issue_id: &str) -> Result<Option<String>> {
+        let response = match self.graphql(ctx, ISSUE_STATE_QUERY, json!({ "id": issue_id })).await {
+            Ok(r) => r,
+            Err(TrackerError::NotFound(_)) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        Ok(response
+            .pointer("/data/issue/state/type")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_owned()))
+    }
+
+    /// The team's workflow states as `(id, name, type)`, ordered by position.
+    async fn workflow_states(
+        &self,
+        ctx: &TrackerContext,
+        config: &LinearConfig,
+    ) -> Result<Vec<(String, String, String)>> {
+        let response = self
+            .graphql(ctx, WORKFLOW_STATES_QUERY, json!({ "teamKey": config.team_key }))
+            .await?;
+        let mut states: Vec<(f64, String, String, String)> = response
+            .pointer("/data/workflowStates/nodes")
+            .and_then(|n| n.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|n| {
+                Some((
+                    n.get("position").and_then(|p| p.as_f64()).unwrap_or(0.0),
+                    n.get("id")?.as_str()?.to_owned(),
+                    n.get("name")?.as_str()?.to_owned(),
+                    n.get("type")?.as_str()?.to_owned(),
+                ))
+            })
+            .collect();
+        if states.is_empty() {
+            return Err(TrackerError::ConfigInvalid(format!(
+                "Linear team '{}' not found or has no workflow states",
+                config.team_key
+            )));
+        }
+        states.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Ok(states.into_iter().map(|(_, id, name, ty)| (id, name, ty)).collect())
+    }
+
+    async fn move_to_state(&self, ctx: &TrackerContext, issue_id: &str, state_id: &str) -> Result<()> {
+        match self
+            .graphql(
+                ctx,
+                ISSUE_UPDATE_STATE_MUTATION,
+                json!({ "id": issue_id, "stateId": state_id }),
+            )
+            .await
+        {
+            Ok(_) => Ok(()),
+            // Issue deleted since we last saw it — nothing left to move.
+            Err(TrackerError::NotFound(_)) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[async_trait]
+impl ExternalTracker for LinearTracker {
+    fn kind(&self) -> &'static str {
+        "linear"
+    }
+
+    fn validate_config(&self, config: &Value) -> std::result::Result<(), TrackerConfigError> {
+        validate_config(config)
+    }
+
+    async fn fetch_items(&self, ctx: &TrackerContext) -> Result<Vec<UpstreamItem>> {
+        let config = LinearConfig::from_ctx(ctx)?;
+        let query = issues_query();
+        let mut items: Vec<UpstreamItem> = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let response = self
+                .graphql(ctx, &query, json!({ "teamKey": config.team_key, "after": cursor }))
+                .await?;
+
+            let nodes = response
+                .pointer("/data/issues/nodes")
+                .and_then(|n| n.as_array())
+                .ok_or_else(|| {
+                    TrackerError::Transient("unexpected GraphQL response shape: missing issues.nodes".to_owned())
+                })?;
+
+            items.extend(nodes.iter().filter_map(|node| parse_issue(node, &config)));
+
+            let page_info = response.pointer("/data/issues/pageInfo");
+            let has_next = page_info
+                .and_then(|p| p.get("hasNextPage"))
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            if !has_next {
+                break;
+            }
+            cursor = page_info
+                .and_then(|p| p.get("endCursor"))
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_owned());
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        Ok(items)
+    }
+
+    async fn fetch_item(&self, ctx: &TrackerContext, ref_: &UpstreamRef) -> Result<Option<UpstreamItem>> {
+        let config = LinearConfig::from_ctx(ctx)?;
+        let issue_id = extract_issue_id(ref_)?;
+        let response = match self.graphql(ctx, &issue_query(), json!({ "id": issue_id })).await {
+            Ok(r) => r,
+            Err(TrackerError::NotFound(_)) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        // A single-item lookup ignores `label_filter`: the ref is already bound.
+        let unfiltered = LinearConfig {
+            label_filter: None,
+            ..config
+        };
+        Ok(response
+            .pointer("/data/issue")
+            .filter(|v| !v.is_null())
+            .and_then(|node| parse_issue(node, &unfiltered)))
+    }
+
+    async fn close_issue(&self, ctx: &TrackerContext, ref_: &UpstreamRef, reason: CloseReason) -> Result<()> {
+        let config = LinearConfig::from_ctx(ctx)?;
+        let issue_id = extract_issue_id(ref_)?;
+
+        match self.issue_state_type(ctx, issue_id).await? {
+            None => return Ok(()),
+            Some(ty) if is_terminal_state_type(&ty) => return Ok(()),
+            Some(_) => {}
+        }
+
+        let target_type = match reason {
+            CloseReason::Completed => "completed",
+            CloseReason::NotPlanned => "canceled",
+        };
+        let states = self.workflow_states(ctx, &config).await?;
+        let (state_id, _, _) = states.iter().find(|(_, _, ty)| ty == target_type).ok_or_else(|| {
+            TrackerError::ConfigInvalid(format!(
+                "Linear team '{}' has no workflow state of type '{target_type}'",
+                config.team_key
+            ))
+        })?;
+        self.move_to_state(ctx, issue_id, state_id).await
+    }
+
+    async fn set_project_status(&self, ctx: &TrackerContext, ref_: &UpstreamRef) -> Result<()> {
+        let config = LinearConfig::from_ctx(ctx)?;
+        let issue_id = extract_issue_id(ref_)?;
+        let target = config.in_progress_column_name();
+
+        let states = self.workflow_states(ctx, &config).await?;
+        let (state_id, _, _) = states.iter().find(|(_, name, _)| name == target).ok_or_else(|| {
+            TrackerError::ConfigInvalid(format!(
+                "Linear team '{}' has no workflow state named '{target}'",
+                config.team_key
+            ))
+        })?;
+        self.move_to_state(ctx, issue_id, state_id).await
+    }
+
+    async fn add_label(&self, ctx: &TrackerContext, ref_: &UpstreamRef, label: &str) -> Result<()> {
+        let config = LinearConfig::from_ctx(ctx)?;
+        let issue_id = extract_issue_id(ref_)?;
+
+        let lookup = self
+            .graphql(ctx, LABELS_QUERY, json!({ "teamKey": config.team_key, "name": label }))
+            .await?;
+        // Prefer a label scoped to this team, then a workspace-wide one.
+        let existing = lookup
+            .pointer("/data/issueLabels/nodes")
+            .and_then(|n| n.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|n| {
+                let id = n.get("id")?.as_str()?.to_owned();
+                let team = n.pointer("/team/key").and_then(|k| k.as_str());
+                match team {
+                    Some(key) if key == config.team_key => Some((0, id)),
+                    None => Some((1, id)),
+                    Some(_) => None,
+                }
+            })
+            .min_by_key(|(rank, _)| *rank)
+            .map(|(_, id)| id);
+
+        let label_id = match existing {
+            Some(id) => id,
+            None => {
+                let team_id = lookup
+                    .pointer("/data/teams/nodes/0/id")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| {
+                        TrackerError::ConfigInvalid(format!("Linear team '{}' not found", config.team_key))
+                    })?;
+                let created = self
+                    .graphql(ctx, LABEL_CREATE_MUTATION, json!({ "name": label, "teamId": team_id }))
+                    .await?;
+                created
+                    .pointer("/data/issueLabelCreate/issueLabel/id")
+                    .and_then(|v| v.as_str())
+                    .map(|s| s.to_owned())
+                    .ok_or_else(|| TrackerError::Transient("issueLabelCreate returned no label id".to_owned()))?
+            }
+        };
+
+        // issueAddLabel is a no-op for a label the issue already carries.
+        match self
+            .graphql(
+                ctx,
+                ISSUE_ADD_LABEL_MUTATION,
+                json!({ "id": issue_id, "labelId": label_id }),
+            )
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(TrackerError::NotFound(_)) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn post_closing_pr_comment(&self, ctx: &TrackerContext, ref_: &UpstreamRef, pr_url: &str) -> Result<()> {
+        let issue_id = extract_issue_id(ref_)?;
+
+        // Idempotency: skip if any existing comment already mentions this PR URL.
+        let comments = match self.graphql(ctx, ISSUE_COMMENTS_QUERY, json!({ "id": issue_id })).await {
+            Ok(r) => r,
+            Err(TrackerError::NotFound(_)) => return Ok(()),
+            Err(e) => return Err(e),
+        };
+        if comments.pointer("/data/issue").is_none_or(|v| v.is_null()) {
+            return Ok(());
+        }
+        let already_present = comments
+            .pointer("/data/issue/comments/nodes")
+            .and_then(|n| n.as_array())
+            .into_iter()
+            .flatten()
+            .any(|c| {
+                c.get("body")
+                    .and_then(|b| b.as_str())
+                    .is_some_and(|b| b.contains(pr_url))
+            });
+        if already_present {
+            return Ok(());
+        }
+
+        match self
+            .graphql(
+                ctx,
+                COMMENT_CREATE_MUTATION,
+                json!({ "issueId": issue_id, "body": format!("Closed by {pr_url}") }),
+            )
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(TrackerError::NotFound(_)) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn fetch_comments(&self, ctx: &TrackerContext, ref_: &UpstreamRef) -> Result<Vec<UpstreamComment>> {
+        let issue_id = extract_issue_id(ref_)?;
+        let mut comments = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let response = match self
+                .graphql(
+                    ctx,
+                    ISSUE_COMMENT_THREAD_QUERY,
+                    json!({ "id": issue_id, "after": cursor }),
+                )
+                .await
+            {
+                Ok(r) => r,
+                Err(TrackerError::NotFound(_)) => return Ok(vec![]),
+                Err(e) => return Err(e),
+            };
+            let Some(connection) = response.pointer("/data/issue/comments") else {
+                return Ok(vec![]);
+            };
+            comments.extend(
+                connection
+                    .pointer("/nodes")
+                    .and_then(|n| n.as_array())
+                    .into_iter()
+                    .flatten()
+                    .filter_map(parse_comment),
+            );
+            let has_next = connection
+                .pointer("/pageInfo/hasNextPage")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            cursor = connection
+                .pointer("/pageInfo/endCursor")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_owned());
+            if !has_next || cursor.is_none() {
+                break;
+            }
+        }
+
+        // Sort explicitly: the trait contract is oldest first.
+        comments.sort_by_key(|c| c.created_at);
+        Ok(comments)
+    }
+
+    async fn post_comment(&self, ctx: &TrackerContext, ref_: &UpstreamRef, body: &str) -> Result<UpstreamComment> {
+        let issue_id = extract_issue_id(ref_)?;
+        let response = self
+            .graphql(
+                ctx,
+                COMMENT_CREATE_MUTATION,
+                json!({ "issueId": issue_id, "body": body }),
+            )
+            .await?;
+        response
+            .pointer("/data/commentCreate/comment")
+            .and_then(parse_comment)
+            .ok_or_else(|| TrackerError::Transient("commentCreate returned no comment".to_owned()))
+    }
+
+    async fn update_fields(
+        &self,
+        ctx: &TrackerContext,
+        ref_: &UpstreamRef,
+        update: &UpstreamFieldUpdate,
+    ) -> Result<()> {
+        let issue_id = extract_issue_id(ref_)?;
+
+        let mut input = serde_json::Map::new();
+        if let Some(title) = &update.title {
+            input.insert("title".into(), json!(title));
+        }
+        if let Some(body) = &update.body {
+            input.insert("description".into(), json!(body));
+        }
+        if let Some(priority) = &update.priority {
+            input.insert("priority".into(), json!(priority_to_linear(priority)?));
+        }
+        if let Some(assignees) = &update.assignees {
+            // Linear issues carry a single assignee; extra names are dropped.
+            let assignee_id = match assignees.first() {
+                None => Value::Null,
+                Some(name) => {
+                    let users = self.graphql(ctx, USERS_QUERY, json!({ "name": name })).await?;
+                    let id = users
+                        .pointer("/data/users/nodes/0/id")
+                        .and_then(|v| v.as_str())
+                        .ok_or_else(|| TrackerError::ConfigInvalid(format!("no Linear user named '{name}'")))?;
+                    json!(id)
+                }
+            };
+            input.insert("assigneeId".into(), assignee_id);
+        }
+        if input.is_empty() {
+            return Ok(());
+        }
+
+        match self
+            .graphql(ctx, ISSUE_UPDATE_MUTATION, json!({ "id": issue_id, "input": input }))
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(TrackerError::NotFound(_)) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TrackerCredential;
+    use wiremock::matchers::{body_string_contains, header, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn test_client() -> reqwest::Client {
+        let _ = rustls::crypto::ring::default_provider().install_default();
+        reqwest::Client::new()
+    }
+
+    fn ctx(config: Value) -> TrackerContext {
+        TrackerContext {
+            product_id: "p1".into(),
+            config,
+            credential: TrackerCredential {
+                token: "lin_api_test".into(),
+            },
+        }
+    }
+
+    fn default_ctx() -> TrackerContext {
+        ctx(json!({ "team_key": "ENG" }))
+    }
+
+    fn tracker(server: &MockServer) -> LinearTracker {
+        LinearTracker::with_api_url(test_client(), format!("{}/graphql", server.uri()))
+    }
+
+    fn issue_ref() -> UpstreamRef {
+        UpstreamRef {
+            kind: "linear".into(),
+            canonical_id: "ENG-12".into(),
+            raw: json!({ "issue_id": "issue-uuid-12", "identifier": "ENG-12" }),
+        }
+    }
+
+    fn fixture(name: &str) -> Value {
+        let raw = match name {
+            "page1" => include_str!("testdata/linear_fetch_items_page1.json"),
+            "page2" => include_str!("testdata/linear_fetch_items_page2.json"),
+            other => panic!("unknown fixture {other}"),
+        };
+        serde_json::from_str(raw).unwrap()
+    }
+
+    fn workflow_states() -> Value {
+        json!({ "data": { "workflowStates": { "nodes": [
+            { "id": "st-done", "name": "Done", "type": "completed", "position": 4.0 },
+            { "id": "st-todo", "name": "Todo", "type": "unstarted", "position": 1.0 },
+            { "id": "st-cancel", "name": "Canceled", "type": "canceled", "position": 5.0 },
+            { "id": "st-doing", "name": "In Progress", "type": "started", "position": 2.0 }
+        ] } } })
+    }
+
+    #[test]
+    fn validate_config_requires_team_key() {
+        assert!(validate_config(&json!({ "team_key": "ENG" })).is_ok());
+        let err = validate_config(&json!({})).unwrap_err();
+        assert!(err.message.contains("team_key"), "{}", err.message);
+        assert!(validate_config(&json!({ "team_key": " " })).is_err());
+        assert!(validate_config(&json!("ENG")).is_err());
+        assert!(validate_config(&json!({ "team_key": "ENG", "label_filter": "boss" })).is_err());
+    }
+
+    #[test]
+    fn state_types_map_to_upstream_status() {
+        assert_eq!(status_for_state_type("started"), UpstreamStatus::Open);
+        assert_eq!(status_for_state_type("backlog"), UpstreamStatus::Open);
+        assert_eq!(
+            status_for_state_type("completed"),
+            UpstreamStatus::Closed {
+                reason: ClosedReason::Completed
+            }
+        );
+        assert_eq!(
+            status_for_state_type("canceled"),
+            UpstreamStatus::Closed {
+                reason: ClosedReason::NotPlanned
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_items_follows_cursor_pagination() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(header("authorization", "lin_api_test"))
+            .and(body_string_contains("\"after\":null"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(fixture("page1")))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("\"after\":\"cursor-1\""))
+            .respond_with(ResponseTemplate::new(200).set_body_json(fixture("page2")))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let items = tracker(&server).fetch_items(&default_ctx()).await.unwrap();
+        let ids: Vec<&str> = items.iter().map(|i| i.upstream_ref.canonical_id.as_str()).collect();
+        assert_eq!(ids, ["ENG-12", "ENG-13", "ENG-14"]);
+
+        let first = &items[0];
+        assert_eq!(first.upstream_ref.kind, "linear");
+        assert_eq!(first.upstream_ref.raw["issue_id"], "issue-uuid-12");
+        assert_eq!(first.title, "Wire up tracker sync");
+        assert_eq!(first.status, UpstreamStatus::Open);
+        assert_eq!(first.project_status.as_deref(), Some("In Progress"));
+        assert_eq!(first.labels, ["boss", "backend"]);
+        assert_eq!(first.assignees, ["Ada"]);
+        assert_eq!(first.priority.as_deref(), Some("high"));
+        assert_eq!(first.upstream_url, "https://linear.app/acme/issue/ENG-12");
+        assert_eq!(first.updated_at, 1_779_012_000);
+        assert_eq!(
+            first.pr_associations,
+            [UpstreamPrAssociation {
+                pr_url: "https://github.com/acme/widgets/pull/41".into(),
+                merged: true,
+                merged_at: Some(1_779_015_600),
+            }]
+        );
+
+        assert_eq!(
+            items[1].status,
+            UpstreamStatus::Closed {
+                reason: ClosedReason::NotPlanned
+            }
+        );
+        assert_eq!(
+            items[2].status,
+            UpstreamStatus::Closed {
+                reason: ClosedReason::Completed
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_items_applies_label_filter() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("\"after\":null"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(fixture("page1")))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("\"after\":\"cursor-1\""))
+            .respond_with(ResponseTemplate::new(200).set_body_json(fixture("page2")))
+            .mount(&server)
+            .await;
+
+        let ctx = ctx(json!({ "team_key": "ENG", "label_filter": ["boss"] }));
+        let items = tracker(&server).fetch_items(&ctx).await.unwrap();
+        let ids: Vec<&str> = items.iter().map(|i| i.upstream_ref.canonical_id.as_str()).collect();
+        assert_eq!(ids, ["ENG-12", "ENG-14"]);
+    }
+
+    #[tokio::test]
+    async fn authentication_error_maps_to_token_revoked() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+                "errors": [{ "message": "Authentication required", "extensions": { "code": "AUTHENTICATION_ERROR" } }]
+            })))
+            .mount(&server)
+            .await;
+
+        let err = tracker(&server).fetch_items(&default_ctx()).await.unwrap_err();
+        assert!(matches!(err, TrackerError::TokenRevoked(_)), "got {err:?}");
+    }
+
+    #[tokio::test]
+    async fn server_error_is_transient() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("upstream unavailable"))
+            .mount(&server)
+            .await;
+
+        let err = tracker(&server).fetch_items(&default_ctx()).await.unwrap_err();
+        assert!(matches!(err, TrackerError::Transient(_)), "got {err:?}");
+    }
+
+    #[tokio::test]
+    async fn missing_token_is_auth_error_without_network() {
+        let server = MockServer::start().await;
+        let mut ctx = default_ctx();
+        ctx.credential = TrackerCredential::ambient();
+        let err = tracker(&server).fetch_items(&ctx).await.unwrap_err();
+        assert!(matches!(err, TrackerError::Auth(_)), "got {err:?}");
+        assert!(server.received_requests().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn fetch_item_returns_none_for_deleted_issue() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": null,
+                "errors": [{ "message": "Entity not found: Issue", "extensions": { "code": "INVALID_INPUT" } }]
+            })))
+            .mount(&server)
+            .await;
+
+        let item = tracker(&server).fetch_item(&default_ctx(), &issue_ref()).await.unwrap();
+        assert!(item.is_none());
+    }
+
+    #[tokio::test]
+    async fn close_issue_moves_to_first_completed_state() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("state { name type }"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "issue": { "state": { "name": "In Progress", "type": "started" } } }
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("workflowStates"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(workflow_states()))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("issueUpdate"))
+            .and(body_string_contains("\"stateId\":\"st-done\""))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "issueUpdate": { "success": true } }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        tracker(&server)
+            .close_issue(&default_ctx(), &issue_ref(), CloseReason::Completed)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn close_issue_skips_already_terminal_issue() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("state { name type }"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "issue": { "state": { "name": "Canceled", "type": "canceled" } } }
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("issueUpdate"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        tracker(&server)
+            .close_issue(&default_ctx(), &issue_ref(), CloseReason::Completed)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn set_project_status_targets_configured_state_name() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("workflowStates"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(workflow_states()))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("issueUpdate"))
+            .and(body_string_contains("\"stateId\":\"st-doing\""))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "issueUpdate": { "success": true } }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        tracker(&server)
+            .set_project_status(&default_ctx(), &issue_ref())
+            .await
+            .unwrap();
+
+        let ctx = ctx(json!({ "team_key": "ENG", "in_progress_column": "Doing" }));
+        let err = tracker(&server)
+            .set_project_status(&ctx, &issue_ref())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, TrackerError::ConfigInvalid(_)), "got {err:?}");
+    }
+
+    #[tokio::test]
+    async fn add_label_creates_missing_label_then_attaches_it() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("issueLabels"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": {
+                    "teams": { "nodes": [{ "id": "team-eng" }] },
+                    "issueLabels": { "nodes": [{ "id": "other-team-label", "team": { "key": "OPS" } }] }
+                }
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("issueLabelCreate"))
+            .and(body_string_contains("\"teamId\":\"team-eng\""))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "issueLabelCreate": { "issueLabel": { "id": "label-new" } } }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("issueAddLabel"))
+            .and(body_string_contains("\"labelId\":\"label-new\""))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "issueAddLabel": { "success": true } }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        tracker(&server)
+            .add_label(&default_ctx(), &issue_ref(), "boss-tracked")
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn post_closing_pr_comment_is_idempotent() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("comments(first: 100)"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "issue": { "comments": { "nodes": [
+                    { "body": "Closed by https://github.com/acme/widgets/pull/41" }
+                ] } } }
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("commentCreate"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "commentCreate": { "success": true } }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let t = tracker(&server);
+        t.post_closing_pr_comment(&default_ctx(), &issue_ref(), "https://github.com/acme/widgets/pull/41")
+            .await
+            .unwrap();
+        t.post_closing_pr_comment(&default_ctx(), &issue_ref(), "https://github.com/acme/widgets/pull/42")
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn fetch_comments_follows_pagination_oldest_first() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("comments(first: 100, after: $after)"))
+            .and(body_string_contains("\"after\":null"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "issue": { "comments": {
+                    "pageInfo": { "hasNextPage": true, "endCursor": "c-1" },
+                    "nodes": [{ "id": "cm-2", "body": "later", "createdAt": "2026-05-02T00:00:00.000Z", "user": { "name": "Ada" } }]
+                } } }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("\"after\":\"c-1\""))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "issue": { "comments": {
+                    "pageInfo": { "hasNextPage": false, "endCursor": null },
+                    "nodes": [{ "id": "cm-1", "body": "earlier", "createdAt": "2026-05-01T00:00:00.000Z", "user": null }]
+                } } }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let comments = tracker(&server)
+            .fetch_comments(&default_ctx(), &issue_ref())
+            .await
+            .unwrap();
+        let ids: Vec<&str> = comments.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, ["cm-1", "cm-2"]);
+        assert_eq!(comments[0].author, "");
+        assert_eq!(comments[1].author, "Ada");
+    }
+
+    #[tokio::test]
+    async fn post_comment_returns_created_comment() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("commentCreate"))
+            .and(body_string_contains("\"body\":\"from boss\""))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "commentCreate": { "success": true, "comment": {
+                    "id": "cm-9", "body": "from boss", "createdAt": "2026-05-03T00:00:00.000Z", "user": { "name": "Boss" }
+                } } }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let comment = tracker(&server)
+            .post_comment(&default_ctx(), &issue_ref(), "from boss")
+            .await
+            .unwrap();
+        assert_eq!(comment.id, "cm-9");
+        assert_eq!(comment.author, "Boss");
+    }
+
+    #[tokio::test]
+    async fn update_fields_maps_priority_and_resolves_assignee() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("users(filter"))
+            .and(body_string_contains("\"name\":\"Grace\""))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "users": { "nodes": [{ "id": "user-grace" }] } }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("issueUpdate(id: $id, input: $input)"))
+            .and(body_string_contains("\"priority\":4"))
+            .and(body_string_contains("\"assigneeId\":\"user-grace\""))
+            .and(body_string_contains("\"title\":\"Renamed\""))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "issueUpdate": { "success": true } }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let update = UpstreamFieldUpdate {
+            title: Some("Renamed".into()),
+            priority: Some("low".into()),
+            assignees: Some(vec!["Grace".into()]),
+            ..Default::default()
+        };
+        tracker(&server)
+            .update_fields(&default_ctx(), &issue_ref(), &update)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn update_fields_clears_assignee_with_null() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("\"assigneeId\":null"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "issueUpdate": { "success": true } }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let update = UpstreamFieldUpdate {
+            assignees: Some(vec![]),
+            ..Default::default()
+        };
+        tracker(&server)
+            .update_fields(&default_ctx(), &issue_ref(), &update)
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn priority_round_trips_through_linear_scale() {
+        assert_eq!(priority_from_linear(0), None);
+        assert_eq!(priority_from_linear(1), Some("high"));
+        assert_eq!(priority_from_linear(3), Some("medium"));
+        for level in ["low", "medium", "high"] {
+            let n = priority_to_linear(level).unwrap();
+            assert_eq!(priority_from_linear(n), Some(level));
+        }
+        assert!(matches!(
+            priority_to_linear("urgent"),
+            Err(TrackerError::Unsupported(_))
+        ));
+    }
+}
@@ -13,6 +13,14 @@ pub struct HostSnapshot {
     /// `None` for the `local` host.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ssh_target: Option<String>,
+    /// Container runtime (`podman`, `docker`, `bubblewrap`) for a host
+    /// that runs workers isolated on the engine's machine. `None` for
+    /// `local` and SSH hosts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container_runtime: Option<String>,
+    /// Image a `podman` / `docker` container host runs workers in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container_image: Option<String>,
     /// Maximum concurrent worker slots on this host.
     pub pool_size: i64,
     /// Whether the host will accept new work dispatches.
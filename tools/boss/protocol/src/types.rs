@@ -34,6 +34,8 @@ mod cost_report;
 mod decision;
 mod dependency;
 mod design_tree;
+mod dispatch_calendar;
+mod dispatch_share;
 mod driver_quota;
 mod driver_split;
 mod execution;
@@ -43,6 +45,7 @@ mod probe;
 mod product;
 mod project;
 mod proposal;
+mod spend_budget;
 mod task;
 mod work_item;
 mod worker_tier;
@@ -62,6 +65,8 @@ pub use cost_report::*;
 pub use decision::*;
 pub use dependency::*;
 pub use design_tree::*;
+pub use dispatch_calendar::*;
+pub use dispatch_share::*;
 pub use driver_quota::*;
 pub use driver_split::*;
 pub use execution::*;
@@ -71,6 +76,7 @@ pub use probe::*;
 pub use product::*;
 pub use project::*;
 pub use proposal::*;
+pub use spend_budget::*;
 pub use task::*;
 pub use work_item::*;
 pub use worker_tier::*;
@@ -46,7 +46,7 @@ pub struct Automation {
     /// The standing instruction passed verbatim to the triage agent.
     pub standing_instruction: String,
 
-    /// Deserialized trigger — schedule cron+tz for the `schedule` variant.
+    /// Deserialized trigger — cron+tz for `schedule`, or an event filter.
     /// Stored in the DB as two columns (`trigger_kind` + `trigger_config`).
     pub trigger: AutomationTrigger,
 
@@ -68,8 +68,9 @@ pub struct Automation {
     pub last_outcome: Option<String>,
 
     /// UTC RFC 3339 timestamp of the next scheduled fire, computed from the
-    /// cron expression + timezone. `None` for disabled automations or before
-    /// the first `next_due_at` computation runs.
+    /// cron expression + timezone. `None` for disabled automations, for
+    /// event-triggered automations, or before the first `next_due_at`
+    /// computation runs.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub next_due_at: Option<String>,
 
@@ -134,7 +135,8 @@ pub struct AutomationRun {
     pub id: String,
     pub automation_id: String,
     pub outcome: String,
-    /// UTC RFC 3339 timestamp of the cron occurrence this run satisfies.
+    /// UTC RFC 3339 timestamp of the cron occurrence this run satisfies, or
+    /// of the first event in a debounced burst for an event-triggered run.
     /// Used as the dedup key (at most one run per occurrence per automation).
     pub scheduled_for: String,
 
@@ -177,6 +179,12 @@ pub struct AutomationRun {
     #[serde(default = "one_repeat_count")]
     #[builder(default = 1)]
     pub repeat_count: u32,
+
+    /// One-line description of the engine event that fired this run (e.g.
+    /// `pr_merged https://github.com/o/r/pull/12`). `None` for cron fires
+    /// and manual runs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trigger_event: Option<String>,
 }
 
 fn one_repeat_count() -> u32 {
@@ -213,11 +221,16 @@ pub const AUTOMATION_OUTCOME_FAILED_GAVE_UP: &str = "failed_gave_up";
 pub const AUTOMATION_OUTCOME_POOL_THROTTLED: &str = "pool_throttled";
 pub const AUTOMATION_OUTCOME_TRIAGE_RUNNING: &str = "triage_running";
 
-/// Trigger specification for an automation. The `schedule` variant is
-/// the only implemented trigger in v1; the enum is open to future
-/// variants (`Event`, `Manual`, etc.) without a schema migration because
-/// the DB stores the tagged JSON representation across two columns
-/// (`trigger_kind` discriminator + `trigger_config` body).
+/// Trigger specification for an automation. The DB stores the tagged JSON
+/// representation across two columns (`trigger_kind` discriminator +
+/// `trigger_config` body), so a new variant needs no schema migration.
+///
+/// `schedule` is the cron trigger the automation scheduler owns. Every other
+/// variant is an *event* trigger: it fires when a matching engine event
+/// arrives on the event bus rather than on a clock, and is owned by the
+/// engine's `automation_events` subscriber. Event-triggered fires share the
+/// scheduler's run history, open-task cap, and dedup gate; a burst of
+/// matching events inside the debounce window collapses onto one run.
 ///
 /// IANA timezone names (e.g. `"America/Los_Angeles"`) are stored alongside
 /// the cron expression so "every weekday at 2pm" means 2pm *local* across
@@ -231,6 +244,54 @@ pub enum AutomationTrigger {
         /// IANA timezone name (e.g. `"America/Los_Angeles"`).
         timezone: String,
     },
+    /// A PR tracked by this product merged. With `paths` set, only a PR
+    /// whose changed files match at least one of the globs fires
+    /// (`globset` syntax, repo-relative, e.g. `tools/boss/engine/**`).
+    /// Empty `paths` fires on every merge.
+    PrMerged {
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        paths: Vec<String>,
+    },
+    /// CI on the product repo's default branch went red: the head commit
+    /// has at least one failing check and the previous observed head did
+    /// not.
+    CiRed,
+    /// A task or project on this product reached `status` (a stored status
+    /// value such as `in_review` or `done`).
+    WorkItemStatus { status: String },
+    /// The external tracker reconciler imported a new upstream item
+    /// carrying `label`.
+    UpstreamLabel { label: String },
+    /// A local `boss automation webhook <name>` POST to the engine socket
+    /// named `name` on this product.
+    Webhook { name: String },
+}
+
+impl AutomationTrigger {
+    /// The `trigger_kind` discriminator this variant is stored under.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            AutomationTrigger::Schedule { .. } => "schedule",
+            AutomationTrigger::PrMerged { .. } => "pr_merged",
+            AutomationTrigger::CiRed => "ci_red",
+            AutomationTrigger::WorkItemStatus { .. } => "work_item_status",
+            AutomationTrigger::UpstreamLabel { .. } => "upstream_label",
+            AutomationTrigger::Webhook { .. } => "webhook",
+        }
+    }
+
+    /// One-line, human-readable form for list views and `automation show`.
+    pub fn describe(&self) -> String {
+        match self {
+            AutomationTrigger::Schedule { cron, timezone } => format!("{cron} ({timezone})"),
+            AutomationTrigger::PrMerged { paths } if paths.is_empty() => "on PR merged".to_owned(),
+            AutomationTrigger::PrMerged { paths } => format!("on PR merged touching {}", paths.join(", ")),
+            AutomationTrigger::CiRed => "on default-branch CI red".to_owned(),
+            AutomationTrigger::WorkItemStatus { status } => format!("on work item -> {status}"),
+            AutomationTrigger::UpstreamLabel { label } => format!("on upstream item labelled {label}"),
+            AutomationTrigger::Webhook { name } => format!("on webhook {name}"),
+        }
+    }
 }
 
 /// Input to `CreateAutomation`. Carries only the caller-supplied fields;
@@ -0,0 +1,183 @@
+//! Per-product dispatch calendars — backs `bossctl dispatch calendar`.
+//!
+//! A calendar says *when* a product's work may start, in the product's
+//! own IANA timezone:
+//!
+//! - **Windows** — if any are set, new workers start only inside one
+//!   (e.g. weekdays 08:00–20:00). Outside them the product's rows stay
+//!   `ready`; reviews are exempt, as they are from an operator pause.
+//! - **Quiet hours** — nothing new starts at all, reviews included. Work
+//!   already running carries on.
+//! - **Drain before close** — within this many minutes of a window
+//!   closing or quiet hours beginning, new workers are held too, so a
+//!   worker is not started that cannot finish before the cut-off.
+//!
+//! A held row records a `dispatch_wait_reason` naming the calendar and
+//! when it next opens, and `bossctl dispatch explain` reports the
+//! product's [`DispatchCalendarState`], so a calendar hold reads as a
+//! decision rather than a stall.
+
+use serde::{Deserialize, Serialize};
+
+/// One recurring weekly window, as the operator wrote it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, bon::Builder)]
+#[builder(on(String, into))]
+pub struct DispatchWindow {
+    /// Cron day-of-week field (`1-5`, `0,6`, `*`) or `weekdays`,
+    /// `weekends`, `daily`. Names the day the window opens on.
+    pub days: String,
+    /// `HH:MM` local start.
+    pub start: String,
+    /// `HH:MM` local end, up to `24:00`. An end not after `start` wraps
+    /// past midnight.
+    pub end: String,
+}
+
+impl DispatchWindow {
+    /// Split the CLI form `<days> <HH:MM>-<HH:MM>` (e.g. `weekdays
+    /// 08:00-20:00`). Only the shape is checked here; the engine
+    /// validates the days and times when the calendar is set.
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let bad = || format!("window must look like `weekdays 08:00-20:00`, got {spec:?}");
+        let (days, times) = spec.trim().split_once(char::is_whitespace).ok_or_else(bad)?;
+        let (start, end) = times.trim().split_once('-').ok_or_else(bad)?;
+        if days.is_empty() || start.trim().is_empty() || end.trim().is_empty() {
+            return Err(bad());
+        }
+        Ok(Self {
+            days: days.to_owned(),
+            start: start.trim().to_owned(),
+            end: end.trim().to_owned(),
+        })
+    }
+}
+
+impl std::fmt::Display for DispatchWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}-{}", self.days, self.start, self.end)
+    }
+}
+
+/// Where a product's calendar stands right now.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DispatchCalendarPhase {
+    /// New work may start.
+    Open,
+    /// Inside a window, but too close to its end (or to quiet hours) to
+    /// start anything new.
+    Draining,
+    /// Outside every window.
+    Closed,
+    /// Inside quiet hours.
+    QuietHours,
+}
+
+impl DispatchCalendarPhase {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Open => "open",
+            Self::Draining => "draining",
+            Self::Closed => "closed",
+            Self::QuietHours => "quiet_hours",
+        }
+    }
+
+    /// Whether a row in this phase is held. Reviews are held only in
+    /// quiet hours.
+    pub fn holds(self, is_review: bool) -> bool {
+        match self {
+            Self::Open => false,
+            Self::Draining | Self::Closed => !is_review,
+            Self::QuietHours => true,
+        }
+    }
+}
+
+/// A calendar's phase as of one instant.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, bon::Builder)]
+#[builder(on(String, into))]
+pub struct DispatchCalendarState {
+    pub phase: DispatchCalendarPhase,
+    /// When the phase next changes: the window's close (or quiet hours'
+    /// start) while open, the opening while held. `None` when that is
+    /// more than a week away.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub until_epoch_s: Option<i64>,
+    /// Operator-facing sentence, with `until_epoch_s` in the calendar's
+    /// timezone.
+    pub message: String,
+}
+
+/// A product's stored calendar plus its phase right now.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, bon::Builder)]
+#[builder(on(String, into))]
+pub struct ProductDispatchCalendar {
+    pub product_id: String,
+    pub product_name: String,
+    /// IANA timezone every window is read in.
+    pub timezone: String,
+    #[serde(default)]
+    #[builder(default)]
+    pub windows: Vec<DispatchWindow>,
+    #[serde(default)]
+    #[builder(default)]
+    pub quiet_hours: Vec<DispatchWindow>,
+    /// Minutes before a window closes or quiet hours begin during which
+    /// no new worker starts. `0` turns draining off.
+    #[serde(default)]
+    #[builder(default)]
+    pub drain_before_close_minutes: u32,
+    pub state: DispatchCalendarState,
+}
+
+/// Input for `SetProductDispatchCalendar`. Replaces the product's
+/// calendar outright; no windows and no quiet hours removes it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, bon::Builder)]
+#[builder(on(String, into))]
+pub struct SetProductDispatchCalendarInput {
+    /// Product id or slug.
+    pub product_id: String,
+    /// IANA timezone, e.g. `America/Los_Angeles`.
+    pub timezone: String,
+    #[serde(default)]
+    #[builder(default)]
+    pub windows: Vec<DispatchWindow>,
+    #[serde(default)]
+    #[builder(default)]
+    pub quiet_hours: Vec<DispatchWindow>,
+    #[serde(default)]
+    #[builder(default)]
+    pub drain_before_close_minutes: u32,
+}
+
+/// Longest drain lead accepted: a lead of a whole day or more would hold
+/// every window shut.
+pub const MAX_DRAIN_BEFORE_CLOSE_MINUTES: u32 = 24 * 60 - 1;
+
+impl SetProductDispatchCalendarInput {
+    /// Whether this input removes the calendar rather than storing one.
+    pub fn clears(&self) -> bool {
+        self.windows.is_empty() && self.quiet_hours.is_empty()
+    }
+
+    /// Shape checks that need no timezone database; the engine parses the
+    /// timezone and every window on top of these.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.clears() {
+            if self.drain_before_close_minutes > 0 {
+                return Err("drain before close needs a window or quiet hours to drain before".to_owned());
+            }
+            return Ok(());
+        }
+        if self.timezone.trim().is_empty() {
+            return Err("a dispatch calendar needs an IANA timezone, e.g. America/Los_Angeles".to_owned());
+        }
+        if self.drain_before_close_minutes > MAX_DRAIN_BEFORE_CLOSE_MINUTES {
+            return Err(format!(
+                "drain before close must be under a day ({MAX_DRAIN_BEFORE_CLOSE_MINUTES} minutes at most)"
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+//! Fair-share dispatch across products — backs `bossctl dispatch share`
+//! and `bossctl dispatch explain`.
+//!
+//! Within each dispatch class the engine interleaves ready rows by
+//! product, always offering the next slot to the product with the lowest
+//! live-workers-per-weight, so one product filing fifty chores can no
+//! longer starve every other product behind its backlog. Inside a
+//! product, rows order by their aged priority (see
+//! [`DispatchExplanation::effective_priority`]). A product can also carry
+//! a concurrency ceiling: once that many of its workers are live, its
+//! remaining rows stay `ready` with a `dispatch_wait_reason` naming the
+//! ceiling.
+
+use serde::{Deserialize, Serialize};
+
+use crate::DispatchAdmission;
+
+/// Weight a product without a stored share dispatches with.
+pub const DEFAULT_DISPATCH_SHARE_WEIGHT: u32 = 1;
+
+/// A product's fair-share settings plus the live numbers they apply to.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, bon::Builder)]
+#[builder(on(String, into))]
+pub struct ProductDispatchShare {
+    pub product_id: String,
+    pub product_name: String,
+    /// Relative share of dispatch slots. A weight-2 product is offered
+    /// twice as many concurrent workers as a weight-1 product while both
+    /// have ready work.
+    pub weight: u32,
+    /// Hard ceiling on this product's live workers, or `None` for no
+    /// ceiling beyond the global caps.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_concurrent: Option<u32>,
+    /// Workers running for this product right now.
+    #[serde(default)]
+    #[builder(default)]
+    pub live_workers: u32,
+    /// `ready` executions waiting for this product right now.
+    #[serde(default)]
+    #[builder(default)]
+    pub ready_count: u32,
+}
+
+/// Input for `SetProductDispatchShare`. Replaces the product's settings
+/// outright; weight [`DEFAULT_DISPATCH_SHARE_WEIGHT`] with no ceiling is
+/// the same as never having set one.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, bon::Builder)]
+#[builder(on(String, into))]
+pub struct SetProductDispatchShareInput {
+    /// Product id or slug.
+    pub product_id: String,
+    pub weight: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_concurrent: Option<u32>,
+}
+
+impl SetProductDispatchShareInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.weight == 0 {
+            return Err("weight must be at least 1".to_owned());
+        }
+        if self.max_concurrent == Some(0) {
+            return Err("max concurrent must be at least 1; pause the product to stop its dispatch".to_owned());
+        }
+        Ok(())
+    }
+}
+
+/// Why a work item is (or is not) waiting to dispatch, as of one instant.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, bon::Builder)]
+#[builder(on(String, into))]
+pub struct DispatchExplanation {
+    /// Pause state and every admission blocker, exactly as
+    /// `EvaluateDispatchAdmission` reports them.
+    pub admission: DispatchAdmission,
+    /// The work item's newest execution, if it has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execution_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execution_status: Option<String>,
+    /// The operator-facing reason the last drain pass recorded for
+    /// holding the execution.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_reason: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_since: Option<String>,
+    /// Dispatch class label (`merge_conflict_revision` … `other_work`).
+    /// Class always outranks product share and priority.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dispatch_class: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_priority: Option<i64>,
+    /// `base_priority` plus one point per aging interval the execution
+    /// has waited, so low-priority work eventually outranks a steady
+    /// stream of fresh higher-priority rows.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effective_priority: Option<i64>,
+    /// 1-based position in the fair-share order the next drain pass
+    /// walks, when the execution is `ready`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub queue_position: Option<u32>,
+    /// `ready` executions across every product.
+    pub queue_len: u32,
+    /// The fair-share settings of the work item's product.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product_share: Option<ProductDispatchShare>,
+    /// The phase of the work item's product's dispatch calendar, when it
+    /// has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub calendar: Option<crate::DispatchCalendarState>,
+}
@@ -475,8 +475,9 @@ pub enum DispatchAdmissionEntryPoint {
 /// dispatch. This list mixes two different kinds of constraint — see the
 /// design doc's "Exactly what force overrides" section:
 ///
-/// - `INTERACTIVE_CONCURRENCY_CAP`, `UNMET_DEPENDENCY`, and
-///   `INELIGIBLE_STATUS` are genuinely enforced: force never bypasses them.
+/// - `INTERACTIVE_CONCURRENCY_CAP`, `UNMET_DEPENDENCY`, `INELIGIBLE_STATUS`,
+///   `SPEND_BUDGET_EXHAUSTED` and `DISPATCH_CALENDAR` are genuinely
+///   enforced: force never bypasses them.
 /// - `CHURN_GUARD_PARKED` and `AUTOSTART_DISABLED` are informational only
 ///   (see `INFORMATIONAL_ONLY_BLOCKER_CODES` in
 ///   `coordinator/dispatch_admission.rs`) — an explicit dispatch request has
@@ -491,6 +492,8 @@ pub const ADMISSION_BLOCKER_UNMET_DEPENDENCY: &str = "unmet_dependency";
 pub const ADMISSION_BLOCKER_CHURN_GUARD_PARKED: &str = "churn_guard_parked";
 pub const ADMISSION_BLOCKER_AUTOSTART_DISABLED: &str = "autostart_disabled";
 pub const ADMISSION_BLOCKER_INELIGIBLE_STATUS: &str = "ineligible_status";
+pub const ADMISSION_BLOCKER_SPEND_BUDGET_EXHAUSTED: &str = "spend_budget_exhausted";
+pub const ADMISSION_BLOCKER_DISPATCH_CALENDAR: &str = "dispatch_calendar";
 
 /// One non-overridable reason [`DispatchAdmission`] found dispatch would
 /// currently refuse for, independent of the dispatch pause.
@@ -0,0 +1,161 @@
+//! Per-product and per-project spend budgets — backs `boss cost budget`
+//! and the engine's dispatch-time budget gate.
+//!
+//! A budget caps what a product or project may spend in one calendar
+//! period (UTC day, ISO week starting Monday, or month), measured either
+//! in tokens or in estimated USD. Spend is summed from the same
+//! `work_runs` token columns `boss cost` reports, with the same
+//! NULL-is-unmeasured rule: a run with no recorded tokens adds nothing.
+//!
+//! Each budget carries up to two limits. Crossing the soft limit files
+//! one attention item on the scoped product/project for that period.
+//! Reaching the hard limit holds every new dispatch in scope until the
+//! period rolls over or the budget is raised. Workers already running
+//! are never interrupted, and their spend only counts once their run
+//! records its tokens, so a period can overshoot a hard limit by
+//! whatever was in flight when it was reached.
+
+use serde::{Deserialize, Serialize};
+
+/// What a budget counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpendBudgetMetric {
+    /// Input + output + cache-write + cache-read tokens.
+    Tokens,
+    /// Estimated USD against the engine's pricing table. Runs on a model
+    /// the table does not price add nothing, so the figure can only ever
+    /// understate — see [`SpendBudgetStatus::spent_partial`].
+    Usd,
+}
+
+impl SpendBudgetMetric {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Tokens => "tokens",
+            Self::Usd => "usd",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "tokens" => Some(Self::Tokens),
+            "usd" => Some(Self::Usd),
+            _ => None,
+        }
+    }
+}
+
+/// The calendar period a budget resets on, in UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpendBudgetPeriod {
+    Day,
+    /// Monday 00:00 UTC to the next Monday.
+    Week,
+    Month,
+}
+
+impl SpendBudgetPeriod {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Day => "day",
+            Self::Week => "week",
+            Self::Month => "month",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "day" => Some(Self::Day),
+            "week" => Some(Self::Week),
+            "month" => Some(Self::Month),
+            _ => None,
+        }
+    }
+}
+
+/// Where a period's spend stands against its budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpendBudgetState {
+    UnderLimit,
+    /// At or past the soft limit: an attention item is open, dispatch
+    /// continues.
+    SoftLimitReached,
+    /// At or past the hard limit: new dispatch in scope is held.
+    HardLimitReached,
+}
+
+/// One stored budget. At most one exists per (scope, metric, period);
+/// setting it again replaces the limits.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, bon::Builder)]
+#[builder(on(String, into))]
+pub struct SpendBudget {
+    pub id: String,
+    /// The product or project the budget covers. A product budget counts
+    /// every task in the product, whatever project it sits in.
+    pub scope_id: String,
+    pub metric: SpendBudgetMetric,
+    pub period: SpendBudgetPeriod,
+    /// `"product"` or `"project"`.
+    pub scope_kind: String,
+    /// Display name of the scoped product/project at read time.
+    pub scope_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hard_limit: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub soft_limit: Option<f64>,
+}
+
+/// A budget with the current period's spend against it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SpendBudgetStatus {
+    pub budget: SpendBudget,
+    pub period_end_epoch_s: i64,
+    pub period_start_epoch_s: i64,
+    /// Spend this period, in the budget's metric.
+    pub spent: f64,
+    /// `true` for a USD budget when at least one run this period used a
+    /// model the pricing table does not price, so `spent` understates.
+    pub spent_partial: bool,
+    pub state: SpendBudgetState,
+}
+
+/// Input for `SetSpendBudget`. At least one limit is required; when both
+/// are given the soft limit must be below the hard limit.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, bon::Builder)]
+#[builder(on(String, into))]
+pub struct SetSpendBudgetInput {
+    /// Product or project id (a slug or short id resolves the same way it
+    /// does for other work-item requests).
+    pub scope_id: String,
+    pub metric: SpendBudgetMetric,
+    pub period: SpendBudgetPeriod,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hard_limit: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub soft_limit: Option<f64>,
+}
+
+impl SetSpendBudgetInput {
+    /// Reject inputs no budget should be stored from.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.soft_limit.is_none() && self.hard_limit.is_none() {
+            return Err("a budget needs a soft limit, a hard limit, or both".to_owned());
+        }
+        for (name, limit) in [("soft", self.soft_limit), ("hard", self.hard_limit)] {
+            if let Some(limit) = limit
+                && !(limit.is_finite() && limit > 0.0)
+            {
+                return Err(format!("{name} limit must be a positive number, got {limit}"));
+            }
+        }
+        if let (Some(soft), Some(hard)) = (self.soft_limit, self.hard_limit)
+            && soft >= hard
+        {
+            return Err(format!("soft limit ({soft}) must be below the hard limit ({hard})"));
+        }
+        Ok(())
+    }
+}
@@ -1254,6 +1254,35 @@ fn automation_trigger_schedule_roundtrips() {
     assert_eq!(trigger, back);
 }
 
+#[test]
+fn automation_trigger_event_variants_roundtrip_under_their_kind() {
+    let triggers = [
+        AutomationTrigger::PrMerged {
+            paths: vec!["tools/boss/engine/**".to_owned()],
+        },
+        AutomationTrigger::PrMerged { paths: Vec::new() },
+        AutomationTrigger::CiRed,
+        AutomationTrigger::WorkItemStatus {
+            status: "in_review".to_owned(),
+        },
+        AutomationTrigger::UpstreamLabel {
+            label: "security".to_owned(),
+        },
+        AutomationTrigger::Webhook {
+            name: "nightly-deploy".to_owned(),
+        },
+    ];
+    for trigger in triggers {
+        let encoded = serde_json::to_value(&trigger).unwrap();
+        assert_eq!(encoded["kind"], trigger.kind(), "{encoded}");
+        let back: AutomationTrigger = serde_json::from_value(encoded).unwrap();
+        assert_eq!(trigger, back);
+    }
+    // An empty glob list is omitted on the wire and restored by default.
+    let bare: AutomationTrigger = serde_json::from_value(json!({ "kind": "pr_merged" })).unwrap();
+    assert_eq!(bare, AutomationTrigger::PrMerged { paths: Vec::new() });
+}
+
 #[test]
 fn automation_roundtrips() {
     let trigger = AutomationTrigger::Schedule {
@@ -2100,3 +2129,106 @@ fn selected_product_state_reports_a_reason_for_every_unavailable_case() {
         assert_eq!(state.product_id(), None, "{state:?} must not offer a product id");
     }
 }
+
+#[test]
+fn set_spend_budget_input_rejects_missing_or_inverted_limits() {
+    let input = |soft: Option<f64>, hard: Option<f64>| SetSpendBudgetInput {
+        scope_id: "prod_1".into(),
+        metric: SpendBudgetMetric::Usd,
+        period: SpendBudgetPeriod::Day,
+        soft_limit: soft,
+        hard_limit: hard,
+    };
+    assert!(input(None, None).validate().is_err());
+    assert!(input(Some(0.0), None).validate().is_err());
+    assert!(input(Some(f64::NAN), None).validate().is_err());
+    assert!(input(Some(50.0), Some(50.0)).validate().is_err());
+    assert!(input(Some(40.0), Some(50.0)).validate().is_ok());
+    assert!(input(None, Some(50.0)).validate().is_ok());
+}
+
+#[test]
+fn spend_budget_enums_round_trip_their_wire_names() {
+    for period in [
+        SpendBudgetPeriod::Day,
+        SpendBudgetPeriod::Week,
+        SpendBudgetPeriod::Month,
+    ] {
+        assert_eq!(SpendBudgetPeriod::parse(period.as_str()), Some(period));
+        assert_eq!(serde_json::to_value(period).unwrap(), json!(period.as_str()));
+    }
+    for metric in [SpendBudgetMetric::Tokens, SpendBudgetMetric::Usd] {
+        assert_eq!(SpendBudgetMetric::parse(metric.as_str()), Some(metric));
+        assert_eq!(serde_json::to_value(metric).unwrap(), json!(metric.as_str()));
+    }
+}
+
+#[test]
+fn set_product_dispatch_share_input_rejects_zero_weight_or_ceiling() {
+    let input = |weight: u32, max_concurrent: Option<u32>| SetProductDispatchShareInput {
+        product_id: "prod_1".into(),
+        weight,
+        max_concurrent,
+    };
+    assert!(input(0, None).validate().is_err());
+    assert!(input(1, Some(0)).validate().is_err());
+    assert!(input(DEFAULT_DISPATCH_SHARE_WEIGHT, None).validate().is_ok());
+    assert!(input(3, Some(2)).validate().is_ok());
+}
+
+#[test]
+fn dispatch_window_parses_the_cli_form_and_round_trips_through_display() {
+    let window = DispatchWindow::parse("weekdays 08:00-20:00").unwrap();
+    assert_eq!(
+        window,
+        DispatchWindow::builder()
+            .days("weekdays")
+            .start("08:00")
+            .end("20:00")
+            .build()
+    );
+    assert_eq!(window.to_string(), "weekdays 08:00-20:00");
+    assert!(DispatchWindow::parse("08:00-20:00").is_err());
+    assert!(DispatchWindow::parse("weekdays 08:00").is_err());
+}
+
+#[test]
+fn set_product_dispatch_calendar_input_validates_shape() {
+    let window = DispatchWindow::parse("1-5 08:00-20:00").unwrap();
+    let input = |timezone: &str, windows: Vec<DispatchWindow>, drain: u32| SetProductDispatchCalendarInput {
+        product_id: "prod_1".into(),
+        timezone: timezone.into(),
+        windows,
+        quiet_hours: Vec::new(),
+        drain_before_close_minutes: drain,
+    };
+    assert!(
+        input("America/Los_Angeles", vec![window.clone()], 30)
+            .validate()
+            .is_ok()
+    );
+    assert!(input("", vec![window.clone()], 0).validate().is_err());
+    assert!(
+        input("UTC", vec![window], MAX_DRAIN_BEFORE_CLOSE_MINUTES + 1)
+            .validate()
+            .is_err()
+    );
+    // No windows and no quiet hours clears the calendar; a drain lead
+    // with nothing to drain before is a mistake.
+    assert!(input("", Vec::new(), 0).clears());
+    assert!(input("", Vec::new(), 0).validate().is_ok());
+    assert!(input("", Vec::new(), 15).validate().is_err());
+}
+
+#[test]
+fn dispatch_calendar_phases_hold_reviews_only_in_quiet_hours() {
+    assert!(!DispatchCalendarPhase::Open.holds(false));
+    assert!(DispatchCalendarPhase::Closed.holds(false));
+    assert!(!DispatchCalendarPhase::Closed.holds(true));
+    assert!(!DispatchCalendarPhase::Draining.holds(true));
+    assert!(DispatchCalendarPhase::QuietHours.holds(true));
+    assert_eq!(
+        serde_json::to_value(DispatchCalendarPhase::QuietHours).unwrap(),
+        json!(DispatchCalendarPhase::QuietHours.as_str())
+    );
+}
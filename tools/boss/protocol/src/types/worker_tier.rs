@@ -13,6 +13,11 @@
 //! usually a `boss propose …` — so the remediation is mechanical rather than
 //! inferred from prose.
 //!
+//! The same refusal is reused for the narrower scope of a *remote* connection
+//! (one relayed by `boss stdio-proxy`), which is refused only the verbs that
+//! must come from the engine host itself — see
+//! [`WorkerTierDenialReason::LocalOnly`].
+//!
 //! Design: `tools/boss/docs/designs/worker-proposal-api-replace-fragile-worker-to-engine-seams.md`
 //! §"Transport and authn: the worker RPC tier".
 
@@ -42,6 +47,11 @@ pub enum WorkerTierDenialReason {
     /// surfaces, planner control, automation management, GitHub/trunk auth.
     /// The worker answers to the coordinator, it does not drive it.
     CoordinatorOnly,
+    /// Refused to a remote connection rather than a worker: trust-root
+    /// registration, the app's pane and session reports, and the `BossOnly`
+    /// break-glass verbs. Each one is about the engine host's own processes,
+    /// so it has to be called from that host.
+    LocalOnly,
 }
 
 impl WorkerTierDenialReason {
@@ -49,6 +59,7 @@ impl WorkerTierDenialReason {
         WorkerTierDenialReason::MutatingTaxonomy,
         WorkerTierDenialReason::RuntimeIsolation,
         WorkerTierDenialReason::CoordinatorOnly,
+        WorkerTierDenialReason::LocalOnly,
     ];
 
     pub fn as_str(self) -> &'static str {
@@ -56,6 +67,7 @@ impl WorkerTierDenialReason {
             WorkerTierDenialReason::MutatingTaxonomy => "mutating_taxonomy",
             WorkerTierDenialReason::RuntimeIsolation => "runtime_isolation",
             WorkerTierDenialReason::CoordinatorOnly => "coordinator_only",
+            WorkerTierDenialReason::LocalOnly => "local_only",
         }
     }
 }
@@ -116,6 +128,23 @@ impl WorkerTierDenial {
             use_instead: None,
         }
     }
+
+    /// A verb refused to a remote connection. Unlike [`Self::closed`] the
+    /// caller is a human or tool on another machine, not a worker, so the
+    /// message points at the engine host instead of at `boss propose`.
+    pub fn remote(verb: impl Into<String>) -> Self {
+        let verb = verb.into();
+        let reason = WorkerTierDenialReason::LocalOnly;
+        Self {
+            message: format!(
+                "`{verb}` is not available over a remote connection: {}. Run it on the engine host.",
+                reason.explanation()
+            ),
+            verb,
+            reason,
+            use_instead: None,
+        }
+    }
 }
 
 impl WorkerTierDenialReason {
@@ -127,6 +156,7 @@ impl WorkerTierDenialReason {
                 "engine runtime state (dispatch, panes, transcripts, other executions) is off-limits to workers"
             }
             WorkerTierDenialReason::CoordinatorOnly => "this is a coordinator/app verb",
+            WorkerTierDenialReason::LocalOnly => "it acts on the engine host's own sessions and panes",
         }
     }
 }
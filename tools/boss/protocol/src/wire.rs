@@ -20,9 +20,10 @@ use crate::types::{
     GitHubAuthStateDto, LinkExternalRefInput, ListDependenciesInput, PrBodyView, PrStatusView, PrWorkItemMatch,
     ProbeDeliveryExpectation, ProbeDeliveryState, Product, Project, ProposalKind, ProposalState,
     ProposalSubmissionError, RemoveDependencyInput, RequestExecutionInput, ResolveProjectDesignDocOutput,
-    ResolvedComment, ReviseDocInput, ReviseDocOutcome, SelectedProductState, SetProductEditorialRulesInput,
-    SetProductExternalTrackerInput, SetProjectDesignDocInput, SetTaskDocPointerInput, Task, TaskRuntime,
-    TranscriptSegment, WorkAttachment, WorkAttentionItem, WorkComment, WorkExecution, WorkItem, WorkItemDependency,
+    ResolvedComment, ReviseDocInput, ReviseDocOutcome, SelectedProductState, SetProductDispatchCalendarInput,
+    SetProductDispatchShareInput, SetProductEditorialRulesInput, SetProductExternalTrackerInput,
+    SetProjectDesignDocInput, SetSpendBudgetInput, SetTaskDocPointerInput, Task, TaskRuntime, TranscriptSegment,
+    WorkAttachment, WorkAttentionItem, WorkComment, WorkExecution, WorkItem, WorkItemDependency,
     WorkItemDependencyDetail, WorkItemDependencyView, WorkItemPatch, WorkRun, WorkerContextBundle, WorkerProposal,
     WorkerTierDenial,
 };
@@ -598,12 +599,28 @@ pub enum FrontendRequest {
     /// `bossctl live-status debug`. Read-only; no side effects.
     DebugLiveStatusPipeline,
 
+    /// Sent first by `boss stdio-proxy`, the far end of an SSH tunnel, to
+    /// mark its connection as a remote peer. The engine classifies the
+    /// connection `Remote` unless its process ancestry already made it a
+    /// worker, which the declaration cannot override. Replies with
+    /// [`FrontendEvent::RemotePeerDeclared`].
+    DeclareRemotePeer {
+        /// How the peer arrived, e.g. `ssh`. Logged only.
+        transport: String,
+    },
+
     /// Permanently delete an automation and its run history.
     /// Replies with [`FrontendEvent::AutomationDeleted`].
     DeleteAutomation {
         id: String,
     },
 
+    /// Remove a spend budget. Dispatch it was holding resumes on the next
+    /// drain pass. Replies with [`FrontendEvent::SpendBudgetDeleted`].
+    DeleteSpendBudget {
+        budget_id: String,
+    },
+
     DeleteWorkItem {
         id: String,
     },
@@ -684,6 +701,17 @@ pub enum FrontendRequest {
         execution_id: String,
     },
 
+    /// Why a work item is or is not dispatching right now: the admission
+    /// verdict `EvaluateDispatchAdmission` gives, plus its execution's
+    /// recorded wait reason, dispatch class, aged priority, position in
+    /// the fair-share order, its product's share settings, and its
+    /// product's dispatch-calendar phase. Read-only. Backs `bossctl
+    /// dispatch explain`. Replies with
+    /// [`FrontendEvent::DispatchExplained`].
+    ExplainDispatch {
+        work_item_id: String,
+    },
+
     /// Look up the work item(s) bound to a GitHub PR number, spanning
     /// the *entire* `tasks` table — every kind (`project_task`,
     /// `chore`, `design`, `investigation`, `revision`) across every
@@ -1394,6 +1422,18 @@ pub enum FrontendRequest {
         refresh: bool,
     },
 
+    /// Every product with a stored dispatch calendar, with its windows,
+    /// quiet hours, drain lead and current phase. Backs `bossctl dispatch
+    /// calendar` with no product. Replies with
+    /// [`FrontendEvent::ProductDispatchCalendarsList`].
+    ListProductDispatchCalendars,
+
+    /// Every product with a stored dispatch share or live/ready work,
+    /// with its weight, ceiling and current counts. Backs `bossctl
+    /// dispatch share` with no product. Replies with
+    /// [`FrontendEvent::ProductDispatchSharesList`].
+    ListProductDispatchShares,
+
     ListProducts,
 
     ListProjects {
@@ -1460,6 +1500,14 @@ pub enum FrontendRequest {
         execution_id: String,
     },
 
+    /// Every spend budget with its current period's spend, optionally
+    /// narrowed to one product or project. Read-only; backs `boss cost
+    /// budget list`. Replies with [`FrontendEvent::SpendBudgetsList`].
+    ListSpendBudgets {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        scope_id: Option<String>,
+    },
+
     ListTasks {
         product_id: String,
         project_id: Option<String>,
@@ -1736,6 +1784,19 @@ pub enum FrontendRequest {
         cap: Option<usize>,
     },
 
+    /// Local webhook: announce `name` on `product_id` so every enabled
+    /// `webhook`-triggered automation with that name fires (subject to its
+    /// debounce window and open-task cap). `payload` is an opaque,
+    /// caller-supplied string recorded on the run as part of its triggering
+    /// event. Replies with [`FrontendEvent::AutomationWebhookAccepted`] once
+    /// the event is published; the fire itself happens asynchronously.
+    PostAutomationWebhook {
+        product_id: String,
+        name: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        payload: Option<String>,
+    },
+
     /// Boss-tier RPC: queue a probe prompt for `run_id` and deliver it at the
     /// earliest opportunity the worker's pane actually offers.
     ///
@@ -2271,6 +2332,29 @@ pub enum FrontendRequest {
         model: Option<String>,
     },
 
+    /// Replace a product's dispatch calendar — its timezone, allowed
+    /// windows, quiet hours and drain lead — or remove it when the input
+    /// has no windows and no quiet hours. The scheduler is kicked so a
+    /// widened window releases held rows right away. Replies with
+    /// [`FrontendEvent::ProductDispatchCalendarSet`] (`calendar: None`
+    /// once removed), or [`FrontendEvent::WorkError`] for an unknown
+    /// product, an unknown timezone, or a malformed window.
+    SetProductDispatchCalendar {
+        #[serde(flatten)]
+        input: SetProductDispatchCalendarInput,
+    },
+
+    /// Replace a product's fair-share weight and concurrency ceiling.
+    /// Takes effect on the next drain pass; the scheduler is kicked so a
+    /// raised share or ceiling is used right away. Replies with
+    /// [`FrontendEvent::ProductDispatchShareSet`], or
+    /// [`FrontendEvent::WorkError`] for an unknown product or input
+    /// [`SetProductDispatchShareInput::validate`] rejects.
+    SetProductDispatchShare {
+        #[serde(flatten)]
+        input: SetProductDispatchShareInput,
+    },
+
     /// Set (or clear) a product's editorial rules. `rules = None` in
     /// the input clears the stored blob; the product reverts to the
     /// engine defaults (strip known Boss identifiers, no template
@@ -2329,6 +2413,17 @@ pub enum FrontendRequest {
         enabled: bool,
     },
 
+    /// Create or replace the spend budget for a product or project, one
+    /// per (scope, metric, period). Takes effect on the next drain pass.
+    /// Backs `boss cost budget set`. Replies with
+    /// [`FrontendEvent::SpendBudgetSet`], or [`FrontendEvent::WorkError`]
+    /// for an unknown scope or limits [`SetSpendBudgetInput::validate`]
+    /// rejects.
+    SetSpendBudget {
+        #[serde(flatten)]
+        input: SetSpendBudgetInput,
+    },
+
     /// Set (or clear) a task's per-task doc pointer (investigations and
     /// project-less designs). Persists the three `tasks.doc_*` columns
     /// per [`SetTaskDocPointerInput`]'s semantics and replies with the
@@ -19,6 +19,13 @@ pub enum FrontendEvent {
     Unsubscribed {
         topics: Vec<String>,
     },
+    /// Reply to [`FrontendRequest::DeclareRemotePeer`]. `peer_class` is
+    /// how the engine classified the connection after the declaration:
+    /// `remote`, or `worker` when ancestry had already settled it.
+    RemotePeerDeclared {
+        transport: String,
+        peer_class: String,
+    },
     TopicEvent {
         topic: String,
         revision: u64,
@@ -113,6 +120,29 @@ pub enum FrontendEvent {
     DispatchAdmissionEvaluated {
         admission: DispatchAdmission,
     },
+    /// Reply for [`FrontendRequest::ExplainDispatch`].
+    DispatchExplained {
+        explanation: crate::DispatchExplanation,
+    },
+    /// Reply for [`FrontendRequest::ListProductDispatchCalendars`].
+    ProductDispatchCalendarsList {
+        calendars: Vec<crate::ProductDispatchCalendar>,
+    },
+    /// Reply for [`FrontendRequest::SetProductDispatchCalendar`]: the
+    /// stored calendar with its current phase, or `None` once removed.
+    ProductDispatchCalendarSet {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        calendar: Option<crate::ProductDispatchCalendar>,
+    },
+    /// Reply for [`FrontendRequest::ListProductDispatchShares`].
+    ProductDispatchSharesList {
+        shares: Vec<crate::ProductDispatchShare>,
+    },
+    /// Reply for [`FrontendRequest::SetProductDispatchShare`]: the stored
+    /// settings with the product's current counts.
+    ProductDispatchShareSet {
+        share: crate::ProductDispatchShare,
+    },
     /// Reply for [`FrontendRequest::TriggerPrReview`]. Carries the
     /// freshly-enqueued (or reused, if one was already queued) `pr_review`
     /// execution, the work item it targets, and the PR URL under review.
@@ -865,6 +895,19 @@ pub enum FrontendEvent {
     TopCostConsumers {
         report: crate::TopCostReport,
     },
+    /// Response to [`FrontendRequest::ListSpendBudgets`].
+    SpendBudgetsList {
+        budgets: Vec<crate::SpendBudgetStatus>,
+    },
+    /// Response to [`FrontendRequest::SetSpendBudget`]: the stored budget
+    /// with its current period's spend.
+    SpendBudgetSet {
+        status: crate::SpendBudgetStatus,
+    },
+    /// Response to [`FrontendRequest::DeleteSpendBudget`].
+    SpendBudgetDeleted {
+        budget_id: String,
+    },
     /// Response to [`FrontendRequest::ListPlannerRuns`]: every
     /// `planner_runs` audit row for the project, newest first.
     PlannerRunsList {
@@ -1483,6 +1526,12 @@ pub enum FrontendEvent {
     AutomationRunEnqueued {
         automation_id: String,
     },
+    /// Response to [`FrontendRequest::PostAutomationWebhook`]: the webhook
+    /// event was published onto the engine event bus.
+    AutomationWebhookAccepted {
+        product_id: String,
+        name: String,
+    },
 
     // --- Product decision records (T-B2-decision) ---
     /// Response to [`FrontendRequest::CreateDecision`].
@@ -413,6 +413,14 @@ fn tag_cases() -> Vec<TagCase> {
             },
             expected_tag: "unsubscribed",
         },
+        TagCase {
+            label: "RemotePeerDeclared",
+            event: FrontendEvent::RemotePeerDeclared {
+                transport: "ssh".into(),
+                peer_class: "remote".into(),
+            },
+            expected_tag: "remote_peer_declared",
+        },
         TagCase {
             label: "TopicEvent",
             event: FrontendEvent::TopicEvent {
@@ -579,6 +587,68 @@ fn tag_cases() -> Vec<TagCase> {
             },
             expected_tag: "dispatch_admission_evaluated",
         },
+        TagCase {
+            label: "DispatchExplained",
+            event: FrontendEvent::DispatchExplained {
+                explanation: crate::DispatchExplanation::builder()
+                    .admission(
+                        DispatchAdmission::builder()
+                            .work_item_id("task_1")
+                            .would_dispatch(false)
+                            .pause(DispatchPauseSnapshot::default())
+                            .build(),
+                    )
+                    .execution_id("exec_1")
+                    .wait_reason("Held by the Flunge concurrency ceiling (2/2 workers live)")
+                    .queue_position(3)
+                    .queue_len(7)
+                    .build(),
+            },
+            expected_tag: "dispatch_explained",
+        },
+        TagCase {
+            label: "ProductDispatchCalendarsList",
+            event: FrontendEvent::ProductDispatchCalendarsList { calendars: Vec::new() },
+            expected_tag: "product_dispatch_calendars_list",
+        },
+        TagCase {
+            label: "ProductDispatchCalendarSet",
+            event: FrontendEvent::ProductDispatchCalendarSet {
+                calendar: Some(
+                    crate::ProductDispatchCalendar::builder()
+                        .product_id("prod_1")
+                        .product_name("Flunge")
+                        .timezone("America/Los_Angeles")
+                        .windows(vec![crate::DispatchWindow::parse("weekdays 08:00-20:00").unwrap()])
+                        .state(
+                            crate::DispatchCalendarState::builder()
+                                .phase(crate::DispatchCalendarPhase::Closed)
+                                .until_epoch_s(1_800_000_000)
+                                .message("Outside the Flunge dispatch window — opens Mon 08:00 PDT")
+                                .build(),
+                        )
+                        .build(),
+                ),
+            },
+            expected_tag: "product_dispatch_calendar_set",
+        },
+        TagCase {
+            label: "ProductDispatchSharesList",
+            event: FrontendEvent::ProductDispatchSharesList { shares: Vec::new() },
+            expected_tag: "product_dispatch_shares_list",
+        },
+        TagCase {
+            label: "ProductDispatchShareSet",
+            event: FrontendEvent::ProductDispatchShareSet {
+                share: crate::ProductDispatchShare::builder()
+                    .product_id("prod_1")
+                    .product_name("Flunge")
+                    .weight(2)
+                    .max_concurrent(4)
+                    .build(),
+            },
+            expected_tag: "product_dispatch_share_set",
+        },
         TagCase {
             label: "PrReviewTriggered",
             event: FrontendEvent::PrReviewTriggered {
@@ -1305,6 +1375,41 @@ fn tag_cases() -> Vec<TagCase> {
             },
             expected_tag: "top_cost_consumers",
         },
+        TagCase {
+            label: "SpendBudgetsList",
+            event: FrontendEvent::SpendBudgetsList { budgets: Vec::new() },
+            expected_tag: "spend_budgets_list",
+        },
+        TagCase {
+            label: "SpendBudgetSet",
+            event: FrontendEvent::SpendBudgetSet {
+                status: crate::SpendBudgetStatus {
+                    budget: crate::SpendBudget {
+                        id: "bgt_1".into(),
+                        scope_id: "prod_1".into(),
+                        metric: crate::SpendBudgetMetric::Usd,
+                        period: crate::SpendBudgetPeriod::Day,
+                        scope_kind: "product".into(),
+                        scope_name: "Flunge".into(),
+                        hard_limit: Some(50.0),
+                        soft_limit: None,
+                    },
+                    period_end_epoch_s: 86_400,
+                    period_start_epoch_s: 0,
+                    spent: 12.5,
+                    spent_partial: false,
+                    state: crate::SpendBudgetState::UnderLimit,
+                },
+            },
+            expected_tag: "spend_budget_set",
+        },
+        TagCase {
+            label: "SpendBudgetDeleted",
+            event: FrontendEvent::SpendBudgetDeleted {
+                budget_id: "bgt_1".into(),
+            },
+            expected_tag: "spend_budget_deleted",
+        },
         // --- Planner / project lifecycle ---
         TagCase {
             label: "PlannerRunsList",
@@ -1840,6 +1945,14 @@ fn tag_cases() -> Vec<TagCase> {
             },
             expected_tag: "automation_run_enqueued",
         },
+        TagCase {
+            label: "AutomationWebhookAccepted",
+            event: FrontendEvent::AutomationWebhookAccepted {
+                product_id: "prod_1".into(),
+                name: "deploy".into(),
+            },
+            expected_tag: "automation_webhook_accepted",
+        },
         TagCase {
             label: "AutomationStateResult",
             event: FrontendEvent::AutomationStateResult {
@@ -1912,6 +2025,7 @@ fn every_variant_is_pinned(e: &FrontendEvent) {
         FrontendEvent::Hello { .. }
         | FrontendEvent::Subscribed { .. }
         | FrontendEvent::Unsubscribed { .. }
+        | FrontendEvent::RemotePeerDeclared { .. }
         | FrontendEvent::TopicEvent { .. }
         | FrontendEvent::ProductsList { .. }
         | FrontendEvent::ProjectsList { .. }
@@ -1931,6 +2045,11 @@ fn every_variant_is_pinned(e: &FrontendEvent) {
         | FrontendEvent::ExecutionCreated { .. }
         | FrontendEvent::ExecutionRequested { .. }
         | FrontendEvent::DispatchAdmissionEvaluated { .. }
+        | FrontendEvent::DispatchExplained { .. }
+        | FrontendEvent::ProductDispatchCalendarsList { .. }
+        | FrontendEvent::ProductDispatchCalendarSet { .. }
+        | FrontendEvent::ProductDispatchSharesList { .. }
+        | FrontendEvent::ProductDispatchShareSet { .. }
         | FrontendEvent::PrReviewTriggered { .. }
         | FrontendEvent::RunsList { .. }
         | FrontendEvent::RunResult { .. }
@@ -2016,6 +2135,9 @@ fn every_variant_is_pinned(e: &FrontendEvent) {
         | FrontendEvent::WorkItemCostReport { .. }
         | FrontendEvent::CostWindowReport { .. }
         | FrontendEvent::TopCostConsumers { .. }
+        | FrontendEvent::SpendBudgetsList { .. }
+        | FrontendEvent::SpendBudgetSet { .. }
+        | FrontendEvent::SpendBudgetDeleted { .. }
         | FrontendEvent::PlannerRunsList { .. }
         | FrontendEvent::PlanProjectResult { .. }
         | FrontendEvent::ReleaseProjectResult { .. }
@@ -2082,6 +2204,7 @@ fn every_variant_is_pinned(e: &FrontendEvent) {
         | FrontendEvent::AutomationDedupSuppressionsList { .. }
         | FrontendEvent::AutomationTasksList { .. }
         | FrontendEvent::AutomationRunEnqueued { .. }
+        | FrontendEvent::AutomationWebhookAccepted { .. }
         | FrontendEvent::AutomationStateResult { .. }
         | FrontendEvent::DecisionCreated { .. }
         | FrontendEvent::DecisionResult { .. }
@@ -0,0 +1,195 @@
+//! Claude-compatible hook execution against the engine-rendered worker
+//! settings file.
+//!
+//! The engine merges the `boss-event` forwarder and the `PreToolUse` guards
+//! into the `--settings` file exactly as it does for Claude, so the scripted
+//! agent exercises the real wiring: every command runs under `/bin/sh -c`
+//! with the payload on stdin, and a `PreToolUse` command blocks the call by
+//! exiting 2 or printing `{"decision":"block"}` (or the newer
+//! `hookSpecificOutput.permissionDecision: "deny"`).
+//!
+//! Matchers are the subset Boss emits: empty or `*` matches every tool,
+//! anything else is a `|`-separated list of exact tool names.
+
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use anyhow::Context;
+use serde_json::Value;
+
+/// Result of firing one hook event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HookOutcome {
+    Allowed,
+    Blocked { reason: String },
+}
+
+/// The `hooks` table of a worker settings file.
+#[derive(Debug, Clone, Default)]
+pub struct HookSettings {
+    hooks: serde_json::Map<String, Value>,
+}
+
+impl HookSettings {
+    /// Read the `hooks` table from `path`. A settings file without one
+    /// fires nothing.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let raw = std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        let settings: Value = serde_json::from_str(&raw).with_context(|| format!("parsing {}", path.display()))?;
+        let hooks = settings
+            .get("hooks")
+            .and_then(Value::as_object)
+            .cloned()
+            .unwrap_or_default();
+        Ok(Self { hooks })
+    }
+
+    /// Fire every command registered for `event` whose matcher accepts
+    /// `tool_name`, in order. Only `PreToolUse` honours a block; a failing
+    /// command anywhere else is reported on stderr and ignored, as Claude
+    /// does for non-blocking hooks.
+    pub fn fire(&self, event: &str, tool_name: Option<&str>, payload: &Value) -> HookOutcome {
+        let Some(entries) = self.hooks.get(event).and_then(Value::as_array) else {
+            return HookOutcome::Allowed;
+        };
+        let body = payload.to_string();
+        for entry in entries {
+            let matcher = entry.get("matcher").and_then(Value::as_str).unwrap_or("");
+            if !matcher_accepts(matcher, tool_name) {
+                continue;
+            }
+            for hook in entry.get("hooks").and_then(Value::as_array).into_iter().flatten() {
+                let Some(command) = hook.get("command").and_then(Value::as_str) else {
+                    continue;
+                };
+                let outcome = run_command(command, &body);
+                if event == "PreToolUse"
+                    && let HookOutcome::Blocked { .. } = outcome
+                {
+                    return outcome;
+                }
+            }
+        }
+        HookOutcome::Allowed
+    }
+}
+
+fn matcher_accepts(matcher: &str, tool_name: Option<&str>) -> bool {
+    if matcher.is_empty() || matcher == "*" {
+        return true;
+    }
+    let Some(tool_name) = tool_name else {
+        return false;
+    };
+    matcher.split('|').any(|name| name == tool_name)
+}
+
+fn run_command(command: &str, body: &str) -> HookOutcome {
+    let child = Command::new("/bin/sh")
+        .arg("-c")
+        .arg(command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(err) => {
+            eprintln!("boss-scripted-agent: hook failed to start: {err}");
+            return HookOutcome::Allowed;
+        }
+    };
+    if let Some(mut stdin) = child.stdin.take() {
+        // A hook that exits without reading its stdin is not an error.
+        let _ = stdin.write_all(body.as_bytes());
+    }
+    let output = match child.wait_with_output() {
+        Ok(output) => output,
+        Err(err) => {
+            eprintln!("boss-scripted-agent: hook failed: {err}");
+            return HookOutcome::Allowed;
+        }
+    };
+    let stderr = String::from_utf8_lossy(&output.stderr).trim().to_owned();
+    if output.status.code() == Some(2) {
+        return HookOutcome::Blocked { reason: stderr };
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let Ok(decision) = serde_json::from_str::<Value>(stdout.trim()) else {
+        return HookOutcome::Allowed;
+    };
+    let reason = || {
+        decision
+            .get("reason")
+            .or_else(|| decision.pointer("/hookSpecificOutput/permissionDecisionReason"))
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_owned()
+    };
+    let blocked = decision.get("decision").and_then(Value::as_str) == Some("block")
+        || decision
+            .pointer("/hookSpecificOutput/permissionDecision")
+            .and_then(Value::as_str)
+            == Some("deny");
+    if blocked {
+        HookOutcome::Blocked { reason: reason() }
+    } else {
+        HookOutcome::Allowed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn settings(hooks: Value) -> HookSettings {
+        HookSettings {
+            hooks: hooks.as_object().cloned().unwrap(),
+        }
+    }
+
+    #[test]
+    fn matcher_subset() {
+        assert!(matcher_accepts("*", Some("Bash")));
+        assert!(matcher_accepts("", None));
+        assert!(matcher_accepts("Write|Edit", Some("Edit")));
+        assert!(!matcher_accepts("Bash", Some("Write")));
+        assert!(!matcher_accepts("Bash", None));
+    }
+
+    #[test]
+    fn pre_tool_use_honours_legacy_and_permission_decision_blocks() {
+        let hooks = settings(json!({
+            "PreToolUse": [
+                {"matcher": "*", "hooks": [{"type": "command", "command": "cat >/dev/null"}]},
+                {"matcher": "Bash", "hooks": [{"type": "command", "command": r#"echo '{"decision":"block","reason":"no"}'"#}]},
+                {"matcher": "Write", "hooks": [{"type": "command", "command": r#"echo '{"hookSpecificOutput":{"permissionDecision":"deny"}}'"#}]},
+                {"matcher": "Read", "hooks": [{"type": "command", "command": "echo nope >&2; exit 2"}]},
+            ],
+        }));
+        let payload = json!({});
+        assert_eq!(
+            hooks.fire("PreToolUse", Some("Bash"), &payload),
+            HookOutcome::Blocked { reason: "no".into() }
+        );
+        assert!(matches!(
+            hooks.fire("PreToolUse", Some("Write"), &payload),
+            HookOutcome::Blocked { .. }
+        ));
+        assert_eq!(
+            hooks.fire("PreToolUse", Some("Read"), &payload),
+            HookOutcome::Blocked { reason: "nope".into() }
+        );
+        assert_eq!(hooks.fire("PreToolUse", Some("Glob"), &payload), HookOutcome::Allowed);
+    }
+
+    #[test]
+    fn non_pre_tool_use_events_never_block() {
+        let hooks = settings(json!({
+            "Stop": [{"matcher": "*", "hooks": [{"type": "command", "command": "exit 2"}]}],
+        }));
+        assert_eq!(hooks.fire("Stop", None, &json!({})), HookOutcome::Allowed);
+    }
+}
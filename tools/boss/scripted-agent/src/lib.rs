@@ -0,0 +1,13 @@
+//! `boss-scripted-agent` — a deterministic fake worker agent for
+//! end-to-end engine tests.
+//!
+//! The binary replays a YAML [`script::Script`] while speaking the Claude
+//! hook and transcript dialect: it fires the hooks the engine rendered into
+//! its `--settings` file (so the `boss-event` forwarder and the `PreToolUse`
+//! guards run for real), appends Claude-shaped transcript lines, writes the
+//! structured-output and PR-URL env files, and pushes real commits for
+//! `create_pr`. The engine drives it through `driver::ScriptedDriver`.
+
+pub mod hooks;
+pub mod runner;
+pub mod script;
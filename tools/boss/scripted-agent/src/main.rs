@@ -0,0 +1,98 @@
+//! Command-line entry point for `boss-scripted-agent`.
+//!
+//! ```text
+//! boss-scripted-agent --model <m> --settings <path> \
+//!     --script <path> --prompt-file <path> [--transcript <path>]
+//! ```
+//!
+//! Paths are relative to the working directory (the worker workspace).
+//! The transcript defaults to `.scripted/transcript.jsonl`.
+
+use std::env;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use anyhow::{Context, Result, anyhow, bail};
+use boss_scripted_agent::hooks::HookSettings;
+use boss_scripted_agent::runner::{Ending, Session, SessionConfig};
+use boss_scripted_agent::script::Script;
+
+const DEFAULT_TRANSCRIPT: &str = ".scripted/transcript.jsonl";
+
+#[derive(Debug, Default)]
+struct Args {
+    model: Option<String>,
+    settings: Option<PathBuf>,
+    script: Option<PathBuf>,
+    prompt_file: Option<PathBuf>,
+    transcript: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("boss-scripted-agent: {err:#}");
+            ExitCode::from(1)
+        }
+    }
+}
+
+fn run() -> Result<ExitCode> {
+    let args = parse_args(env::args().skip(1))?;
+    let workspace = env::current_dir().context("resolving workspace")?;
+    let script_path = args.script.ok_or_else(|| anyhow!("--script is required"))?;
+    let script = Script::load(&script_path)?;
+    let hooks = match &args.settings {
+        Some(path) => HookSettings::load(path)?,
+        None => HookSettings::default(),
+    };
+    let prompt = match &args.prompt_file {
+        Some(path) => std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?,
+        None => String::new(),
+    };
+    let transcript = args.transcript.unwrap_or_else(|| PathBuf::from(DEFAULT_TRANSCRIPT));
+    let config = SessionConfig {
+        model: args.model.unwrap_or_else(|| "scripted".to_owned()),
+        transcript_path: absolute(&workspace, &transcript),
+        workspace,
+        prompt,
+        hooks,
+    };
+
+    println!("boss-scripted-agent ({})", script_path.display());
+    let mut session = Session::new(config, &script);
+    session.start()?;
+    match session.replay(&script)? {
+        Ending::Crashed { code } => Ok(ExitCode::from(u8::try_from(code).unwrap_or(1))),
+        Ending::Completed => {
+            session.idle(io::stdin().lock())?;
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args> {
+    let mut parsed = Args::default();
+    while let Some(flag) = args.next() {
+        let mut value = || args.next().ok_or_else(|| anyhow!("{flag} needs a value"));
+        match flag.as_str() {
+            "--model" => parsed.model = Some(value()?),
+            "--settings" => parsed.settings = Some(value()?.into()),
+            "--script" => parsed.script = Some(value()?.into()),
+            "--prompt-file" => parsed.prompt_file = Some(value()?.into()),
+            "--transcript" => parsed.transcript = Some(value()?.into()),
+            other => bail!("unknown argument {other:?}"),
+        }
+    }
+    Ok(parsed)
+}
+
+fn absolute(workspace: &Path, path: &Path) -> PathBuf {
+    if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        workspace.join(path)
+    }
+}
@@ -0,0 +1,360 @@
+//! Replays a [`Script`] as a Claude-dialect worker session: hook callbacks
+//! through [`HookSettings`], a Claude-shaped JSONL transcript, the
+//! structured-output env-file contract, and a real `git push` for PR
+//! creation.
+
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+use anyhow::Context;
+use serde_json::{Value, json};
+
+use crate::hooks::{HookOutcome, HookSettings};
+use crate::script::{CreatePr, Script, Step, ToolUse, WriteFile};
+
+/// Env var naming the structured-output file the engine reads back.
+const STRUCTURED_OUTPUT_ENV: &str = "BOSS_STRUCTURED_OUTPUT";
+/// Env var naming the PR-URL file the engine reads back.
+const PR_URL_OUTPUT_ENV: &str = "BOSS_PR_URL_OUTPUT";
+
+/// Line typed into the pane that ends an idle session.
+const QUIT_COMMAND: &str = "/quit";
+
+/// Author identity for commits `create_pr` makes, so a bare CI runner with
+/// no git config can still commit.
+const GIT_IDENTITY: &[&str] = &[
+    "-c",
+    "user.name=boss-scripted-agent",
+    "-c",
+    "user.email=scripted-agent@boss.invalid",
+];
+
+/// Everything a session needs besides the script itself.
+pub struct SessionConfig {
+    pub model: String,
+    pub workspace: PathBuf,
+    pub transcript_path: PathBuf,
+    pub prompt: String,
+    pub hooks: HookSettings,
+}
+
+/// How a replay ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ending {
+    /// The script ran out; the caller idles on stdin for more turns.
+    Completed,
+    /// An `exit` step: terminate now with this code.
+    Crashed { code: i32 },
+}
+
+pub struct Session {
+    config: SessionConfig,
+    session_id: String,
+    next_tool_id: u64,
+}
+
+impl Session {
+    pub fn new(config: SessionConfig, script: &Script) -> Self {
+        let session_id = script
+            .session_id
+            .clone()
+            .unwrap_or_else(|| format!("scripted-{}", std::process::id()));
+        Self {
+            config,
+            session_id,
+            next_tool_id: 0,
+        }
+    }
+
+    pub fn session_id(&self) -> &str {
+        &self.session_id
+    }
+
+    /// Fire `SessionStart` and submit the initial prompt.
+    pub fn start(&mut self) -> anyhow::Result<()> {
+        if let Some(parent) = self.config.transcript_path.parent() {
+            fs::create_dir_all(parent).with_context(|| format!("creating {}", parent.display()))?;
+        }
+        let model = self.config.model.clone();
+        self.fire("SessionStart", None, json!({"source": "startup", "model": model}));
+        let prompt = self.config.prompt.clone();
+        self.submit_prompt(&prompt)
+    }
+
+    /// Replay every step in order.
+    pub fn replay(&mut self, script: &Script) -> anyhow::Result<Ending> {
+        for step in &script.steps {
+            if let Some(ending) = self.step(step)? {
+                return Ok(ending);
+            }
+        }
+        Ok(Ending::Completed)
+    }
+
+    /// After the script: answer each typed line with a one-line turn until
+    /// `/quit` or EOF, then fire `SessionEnd`.
+    pub fn idle(&mut self, input: impl BufRead) -> anyhow::Result<()> {
+        print_prompt();
+        for line in input.lines() {
+            let line = line.context("reading pane input")?;
+            let line = line.trim();
+            if line == QUIT_COMMAND {
+                break;
+            }
+            if line.is_empty() {
+                print_prompt();
+                continue;
+            }
+            self.submit_prompt(line)?;
+            self.say("The script has no further steps.")?;
+            self.stop();
+            print_prompt();
+        }
+        self.fire("SessionEnd", None, json!({"reason": "prompt_input_exit"}));
+        Ok(())
+    }
+
+    fn step(&mut self, step: &Step) -> anyhow::Result<Option<Ending>> {
+        match step {
+            Step::Say(text) => self.say(text)?,
+            Step::ToolUse(tool_use) => self.tool_use(tool_use)?,
+            Step::WriteFile(write) => self.write_file(write)?,
+            Step::StructuredOutput(value) => write_env_file(STRUCTURED_OUTPUT_ENV, &serde_json::to_string(value)?)?,
+            Step::CreatePr(pr) => self.create_pr(pr)?,
+            Step::Hook(fields) => {
+                let event = fields
+                    .get("hook_event_name")
+                    .and_then(Value::as_str)
+                    .context("hook step needs a hook_event_name")?
+                    .to_owned();
+                let tool_name = fields.get("tool_name").and_then(Value::as_str).map(str::to_owned);
+                self.fire(&event, tool_name.as_deref(), Value::Object(fields.clone()));
+            }
+            Step::Transcript(line) => self.append_transcript(line)?,
+            Step::SleepMs(ms) => std::thread::sleep(Duration::from_millis(*ms)),
+            Step::Stop => self.stop(),
+            Step::Exit(exit) => {
+                if let Some(message) = &exit.message {
+                    eprintln!("{message}");
+                }
+                return Ok(Some(Ending::Crashed { code: exit.code }));
+            }
+            Step::Hang => loop {
+                std::thread::sleep(Duration::from_secs(3600));
+            },
+        }
+        Ok(None)
+    }
+
+    fn submit_prompt(&mut self, prompt: &str) -> anyhow::Result<()> {
+        println!("[scripted: running] {}", first_line(prompt));
+        self.fire("UserPromptSubmit", None, json!({"prompt": prompt}));
+        self.append_transcript(&json!({
+            "type": "user",
+            "message": {"role": "user", "content": prompt},
+        }))
+    }
+
+    fn say(&mut self, text: &str) -> anyhow::Result<()> {
+        println!("{text}");
+        self.append_transcript(&json!({
+            "type": "assistant",
+            "message": {"role": "assistant", "content": [{"type": "text", "text": text}]},
+        }))
+    }
+
+    fn stop(&mut self) {
+        self.fire("Stop", None, json!({"stop_hook_active": false}));
+    }
+
+    /// Shared guarded-tool-call path: transcript `tool_use`, `PreToolUse`,
+    /// then `execute` and `PostToolUse` unless a guard blocked the call.
+    fn guarded_tool(
+        &mut self,
+        name: &str,
+        input: &Value,
+        execute: impl FnOnce(&mut Self) -> anyhow::Result<Value>,
+    ) -> anyhow::Result<()> {
+        self.next_tool_id += 1;
+        let tool_use_id = format!("toolu_scripted_{}", self.next_tool_id);
+        println!("● {name} {}", first_line(&input.to_string()));
+        self.append_transcript(&json!({
+            "type": "assistant",
+            "message": {"role": "assistant", "content": [
+                {"type": "tool_use", "id": tool_use_id, "name": name, "input": input},
+            ]},
+        }))?;
+        let pre = json!({"tool_name": name, "tool_input": input, "tool_use_id": tool_use_id});
+        if let HookOutcome::Blocked { reason } = self.fire("PreToolUse", Some(name), pre) {
+            println!("  ⎿ blocked: {}", first_line(&reason));
+            return self.append_transcript(&json!({
+                "type": "user",
+                "message": {"role": "user", "content": [
+                    {"type": "tool_result", "tool_use_id": tool_use_id, "is_error": true, "content": reason},
+                ]},
+            }));
+        }
+        let response = execute(self)?;
+        self.fire(
+            "PostToolUse",
+            Some(name),
+            json!({"tool_name": name, "tool_input": input, "tool_response": response, "tool_use_id": tool_use_id}),
+        );
+        self.append_transcript(&json!({
+            "type": "user",
+            "message": {"role": "user", "content": [
+                {"type": "tool_result", "tool_use_id": tool_use_id, "content": response.to_string()},
+            ]},
+        }))
+    }
+
+    fn tool_use(&mut self, tool_use: &ToolUse) -> anyhow::Result<()> {
+        let ToolUse {
+            name,
+            input,
+            response,
+            run,
+        } = tool_use;
+        let run = *run;
+        let scripted = response.clone().unwrap_or_else(|| json!({}));
+        let workspace = self.config.workspace.clone();
+        self.guarded_tool(name, input, move |_| {
+            if !run {
+                return Ok(scripted);
+            }
+            let command = input
+                .get("command")
+                .and_then(Value::as_str)
+                .context("tool_use with run: true needs input.command")?;
+            Ok(run_shell(&workspace, command))
+        })
+    }
+
+    fn write_file(&mut self, write: &WriteFile) -> anyhow::Result<()> {
+        let path = self.config.workspace.join(&write.path);
+        let input = json!({"file_path": path.display().to_string(), "content": write.content});
+        let content = write.content.clone();
+        self.guarded_tool("Write", &input, move |_| {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).with_context(|| format!("creating {}", parent.display()))?;
+            }
+            fs::write(&path, &content).with_context(|| format!("writing {}", path.display()))?;
+            Ok(json!({"type": "create", "filePath": path.display().to_string(), "content": content}))
+        })
+    }
+
+    /// Reported to hooks as the `cube pr create` call a real worker makes —
+    /// so the PR-redirect and revision guards judge it exactly as they
+    /// would a real one — and carried out with plain git against the
+    /// scripted remote.
+    fn create_pr(&mut self, pr: &CreatePr) -> anyhow::Result<()> {
+        let command = format!(
+            "cube pr create --branch {} --title {}",
+            shell_quote(&pr.branch),
+            shell_quote(&pr.title)
+        );
+        let input = json!({"command": command, "description": "Open the pull request"});
+        let workspace = self.config.workspace.clone();
+        let pr = pr.clone();
+        self.guarded_tool("Bash", &input, move |_| {
+            let pushed = git(&workspace, &["add", "-A"])
+                .and_then(|_| {
+                    let mut args = GIT_IDENTITY.to_vec();
+                    args.extend(["commit", "--allow-empty", "-m", pr.title.as_str()]);
+                    git(&workspace, &args)
+                })
+                .and_then(|_| {
+                    let refspec = format!("HEAD:refs/heads/{}", pr.branch);
+                    git(&workspace, &["push", "--force", pr.remote.as_str(), refspec.as_str()])
+                });
+            match pushed {
+                Ok(()) => {
+                    write_env_file(PR_URL_OUTPUT_ENV, &pr.url)?;
+                    Ok(json!({"stdout": format!("{}\n", pr.url), "stderr": "", "interrupted": false}))
+                }
+                Err(err) => Ok(json!({"stdout": "", "stderr": format!("{err:#}"), "interrupted": false})),
+            }
+        })
+    }
+
+    /// Fire `event` with the common payload fields filled in.
+    fn fire(&self, event: &str, tool_name: Option<&str>, mut payload: Value) -> HookOutcome {
+        if let Some(obj) = payload.as_object_mut() {
+            obj.entry("session_id").or_insert_with(|| json!(self.session_id));
+            obj.entry("transcript_path")
+                .or_insert_with(|| json!(self.config.transcript_path.display().to_string()));
+            obj.entry("cwd")
+                .or_insert_with(|| json!(self.config.workspace.display().to_string()));
+            obj.insert("hook_event_name".into(), json!(event));
+        }
+        self.config.hooks.fire(event, tool_name, &payload)
+    }
+
+    fn append_transcript(&self, line: &Value) -> anyhow::Result<()> {
+        let path = &self.config.transcript_path;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("opening transcript {}", path.display()))?;
+        writeln!(file, "{line}").with_context(|| format!("appending to {}", path.display()))
+    }
+}
+
+fn print_prompt() {
+    print!("scripted> ");
+    let _ = std::io::stdout().flush();
+}
+
+fn first_line(text: &str) -> &str {
+    text.lines().next().unwrap_or_default()
+}
+
+/// Write `contents` to the file named by `env`, when the engine set one.
+fn write_env_file(env: &str, contents: &str) -> anyhow::Result<()> {
+    let Some(path) = std::env::var_os(env) else {
+        return Ok(());
+    };
+    let path = Path::new(&path);
+    fs::write(path, contents).with_context(|| format!("writing {env} file {}", path.display()))
+}
+
+fn run_shell(workspace: &Path, command: &str) -> Value {
+    match Command::new("/bin/sh")
+        .arg("-c")
+        .arg(command)
+        .current_dir(workspace)
+        .output()
+    {
+        Ok(output) => json!({
+            "stdout": String::from_utf8_lossy(&output.stdout),
+            "stderr": String::from_utf8_lossy(&output.stderr),
+            "interrupted": false,
+            "exit_code": output.status.code(),
+        }),
+        Err(err) => json!({"stdout": "", "stderr": err.to_string(), "interrupted": false}),
+    }
+}
+
+fn git(workspace: &Path, args: &[&str]) -> anyhow::Result<()> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(workspace)
+        .output()
+        .with_context(|| format!("running git {}", args.join(" ")))?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
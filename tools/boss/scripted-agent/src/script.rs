@@ -0,0 +1,181 @@
+//! The YAML script format `boss-scripted-agent` replays.
+//!
+//! A script is an ordered list of steps. Each step is a single-key map (or a
+//! bare word for the argument-free steps):
+//!
+//! ```yaml
+//! session_id: fixture-session        # optional; defaults to scripted-<pid>
+//! steps:
+//!   - say: "Looking at the tree."
+//!   - tool_use:
+//!       name: Bash
+//!       input: { command: "ls" }
+//!       response: { stdout: "README.md\n", stderr: "" }
+//!   - tool_use: { name: Bash, input: { command: "cargo test" }, run: true }
+//!   - write_file: { path: src/lib.rs, content: "pub fn f() {}\n" }
+//!   - structured_output: { verdict: approve }
+//!   - create_pr:
+//!       branch: feature/x
+//!       title: Add x
+//!       url: https://github.com/acme/widgets/pull/7
+//!   - hook: { hook_event_name: Notification, message: "waiting" }
+//!   - transcript: { type: system, subtype: info, message: "raw line" }
+//!   - sleep_ms: 50
+//!   - stop
+//!   - exit: { code: 3, message: "boom" }
+//!   - hang
+//! ```
+//!
+//! Unknown keys are rejected at load time so a typo fails the test that
+//! wrote it rather than silently skipping a step.
+
+use std::path::Path;
+
+use anyhow::Context;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+/// A parsed script.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Script {
+    /// Session id stamped on every hook payload. Defaults to
+    /// `scripted-<pid>` so concurrent agents never collide.
+    #[serde(default)]
+    pub session_id: Option<String>,
+    pub steps: Vec<Step>,
+}
+
+impl Script {
+    pub fn parse(text: &str) -> anyhow::Result<Self> {
+        // serde_yaml only spells enum variants as `!tags`; going through a
+        // JSON value accepts the single-key-map and bare-word forms above.
+        let value: Value = serde_yaml::from_str(text).context("parsing scripted-agent script")?;
+        serde_json::from_value(value).context("parsing scripted-agent script")
+    }
+
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let text = std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        Self::parse(&text).with_context(|| format!("in {}", path.display()))
+    }
+}
+
+/// One replayed action. See the module docs for the YAML spelling of each.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Step {
+    /// Assistant prose: one transcript `text` block, no hook.
+    Say(String),
+    /// A tool call: `PreToolUse` (guards may block it), then — unless
+    /// blocked — `PostToolUse` with the scripted or real response.
+    ToolUse(ToolUse),
+    /// Write a workspace file through a guarded `Write` tool call.
+    WriteFile(WriteFile),
+    /// Write this value as JSON to `$BOSS_STRUCTURED_OUTPUT`.
+    StructuredOutput(Value),
+    /// Commit, push to a local remote, and report a PR URL through a
+    /// guarded `cube pr create` Bash call.
+    CreatePr(CreatePr),
+    /// Fire a raw hook callback. `session_id`, `transcript_path`, and `cwd`
+    /// are filled in when absent; `hook_event_name` is required.
+    Hook(Map<String, Value>),
+    /// Append one raw line to the transcript.
+    Transcript(Value),
+    /// Pause for this many milliseconds.
+    SleepMs(u64),
+    /// End the turn: fire the `Stop` hook.
+    Stop,
+    /// Crash: exit immediately with `code`, printing `message` to stderr.
+    /// No `Stop` or `SessionEnd` fires, exactly as for a real crash.
+    Exit(Exit),
+    /// Never return. The engine's liveness and reap paths own what happens
+    /// next.
+    Hang,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ToolUse {
+    pub name: String,
+    #[serde(default)]
+    pub input: Value,
+    /// Scripted `tool_response`. Ignored when `run` is set.
+    #[serde(default)]
+    pub response: Option<Value>,
+    /// For `Bash` only: actually run `input.command` in the workspace and
+    /// report its real output.
+    #[serde(default)]
+    pub run: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WriteFile {
+    /// Workspace-relative or absolute path.
+    pub path: String,
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CreatePr {
+    pub branch: String,
+    pub title: String,
+    /// Git remote to push to — typically a local bare repository.
+    #[serde(default = "default_remote")]
+    pub remote: String,
+    /// The URL reported as the created PR.
+    pub url: String,
+}
+
+fn default_remote() -> String {
+    "origin".to_owned()
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Exit {
+    pub code: i32,
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_every_step_kind() {
+        let script = Script::parse(
+            r#"
+session_id: s-1
+steps:
+  - say: hello
+  - tool_use: { name: Bash, input: { command: ls }, response: { stdout: "a\n" } }
+  - write_file: { path: a.txt, content: x }
+  - structured_output: { verdict: approve }
+  - create_pr: { branch: b, title: t, url: "https://github.com/o/r/pull/1" }
+  - hook: { hook_event_name: Notification, message: hi }
+  - transcript: { type: system }
+  - sleep_ms: 5
+  - stop
+  - exit: { code: 3 }
+  - hang
+"#,
+        )
+        .unwrap();
+        assert_eq!(script.session_id.as_deref(), Some("s-1"));
+        assert_eq!(script.steps.len(), 11);
+        assert!(matches!(&script.steps[0], Step::Say(text) if text == "hello"));
+        assert!(matches!(&script.steps[4], Step::CreatePr(pr) if pr.remote == "origin"));
+        assert!(matches!(script.steps[8], Step::Stop));
+        assert!(matches!(script.steps[9], Step::Exit(Exit { code: 3, .. })));
+        assert!(matches!(script.steps[10], Step::Hang));
+    }
+
+    #[test]
+    fn unknown_step_or_field_is_rejected() {
+        assert!(Script::parse("steps:\n  - shout: hi\n").is_err());
+        assert!(Script::parse("steps:\n  - write_file: { path: a, content: b, mode: 1 }\n").is_err());
+    }
+}
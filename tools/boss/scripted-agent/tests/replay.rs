@@ -0,0 +1,279 @@
+//! End-to-end replay tests: spawn the binary in a temp workspace with a
+//! settings file whose hooks append each payload to a capture file, and
+//! verify the hook stream, transcript, env-file outputs, git push, and
+//! exit behaviour.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde_json::{Value, json};
+use tempfile::TempDir;
+
+fn agent_binary() -> PathBuf {
+    PathBuf::from(env!("CARGO_BIN_EXE_boss-scripted-agent"))
+}
+
+struct Fixture {
+    dir: TempDir,
+}
+
+impl Fixture {
+    /// A workspace with `script`, a prompt, and a settings file that
+    /// captures every hook payload and blocks any `Bash` command
+    /// mentioning `forbidden`.
+    fn new(script: &str) -> Self {
+        let dir = TempDir::new().unwrap();
+        let fixture = Self { dir };
+        let ws = fixture.workspace();
+        fs::create_dir_all(ws.join(".scripted")).unwrap();
+        fs::write(ws.join(".scripted/script.yaml"), script).unwrap();
+        fs::write(ws.join(".scripted/initial-prompt.txt"), "Do the thing.\n").unwrap();
+        let capture = fixture.capture_path();
+        let record = format!("cat >> '{}'; echo >> '{}'", capture.display(), capture.display());
+        let guard = r#"grep -q forbidden && { echo 'forbidden command' >&2; exit 2; }; exit 0"#;
+        let events = [
+            "SessionStart",
+            "UserPromptSubmit",
+            "PreToolUse",
+            "PostToolUse",
+            "Stop",
+            "SessionEnd",
+            "Notification",
+        ];
+        let mut hooks = serde_json::Map::new();
+        for event in events {
+            let mut entries = vec![json!({"matcher": "*", "hooks": [{"type": "command", "command": record}]})];
+            if event == "PreToolUse" {
+                entries.push(json!({"matcher": "Bash", "hooks": [{"type": "command", "command": guard}]}));
+            }
+            hooks.insert(event.into(), Value::Array(entries));
+        }
+        fs::write(fixture.settings_path(), json!({"hooks": hooks}).to_string()).unwrap();
+        fixture
+    }
+
+    fn workspace(&self) -> PathBuf {
+        self.dir.path().join("ws")
+    }
+
+    fn capture_path(&self) -> PathBuf {
+        self.dir.path().join("hooks.jsonl")
+    }
+
+    fn settings_path(&self) -> PathBuf {
+        self.dir.path().join("settings.json")
+    }
+
+    fn command(&self) -> Command {
+        let mut cmd = Command::new(agent_binary());
+        cmd.current_dir(self.workspace())
+            .args(["--model", "scripted"])
+            .arg("--settings")
+            .arg(self.settings_path())
+            .args(["--script", ".scripted/script.yaml"])
+            .args(["--prompt-file", ".scripted/initial-prompt.txt"])
+            .env_remove("BOSS_STRUCTURED_OUTPUT")
+            .env_remove("BOSS_PR_URL_OUTPUT")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        cmd
+    }
+
+    fn hooks(&self) -> Vec<Value> {
+        let raw = fs::read_to_string(self.capture_path()).unwrap_or_default();
+        raw.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    fn transcript(&self) -> Vec<Value> {
+        let raw = fs::read_to_string(self.workspace().join(".scripted/transcript.jsonl")).unwrap();
+        raw.lines().map(|line| serde_json::from_str(line).unwrap()).collect()
+    }
+}
+
+/// Run to completion, typing `stdin` into the idle prompt.
+fn run(mut cmd: Command, stdin: &str, timeout: Duration) -> Output {
+    let mut child = cmd.spawn().unwrap();
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+    let deadline = Instant::now() + timeout;
+    loop {
+        if child.try_wait().unwrap().is_some() {
+            return child.wait_with_output().unwrap();
+        }
+        if Instant::now() > deadline {
+            child.kill().unwrap();
+            panic!("scripted agent did not exit within {timeout:?}");
+        }
+        thread::sleep(Duration::from_millis(20));
+    }
+}
+
+fn event_names(hooks: &[Value]) -> Vec<String> {
+    hooks
+        .iter()
+        .map(|hook| hook["hook_event_name"].as_str().unwrap().to_owned())
+        .collect()
+}
+
+fn git(dir: &Path, args: &[&str]) -> String {
+    let output = Command::new("git").args(args).current_dir(dir).output().unwrap();
+    assert!(
+        output.status.success(),
+        "git {args:?}: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn replays_hooks_transcript_and_structured_output() {
+    let fixture = Fixture::new(
+        r#"
+session_id: fixture-session
+steps:
+  - say: Looking around.
+  - tool_use: { name: Bash, input: { command: ls }, response: { stdout: "a\n" } }
+  - tool_use: { name: Bash, input: { command: "rm forbidden" } }
+  - write_file: { path: notes/out.txt, content: "hello\n" }
+  - structured_output: { verdict: approve }
+  - stop
+"#,
+    );
+    let structured = fixture.dir.path().join("structured.json");
+    let mut cmd = fixture.command();
+    cmd.env("BOSS_STRUCTURED_OUTPUT", &structured);
+    let output = run(cmd, "/quit\n", Duration::from_secs(30));
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let hooks = fixture.hooks();
+    assert_eq!(
+        event_names(&hooks),
+        [
+            "SessionStart",
+            "UserPromptSubmit",
+            "PreToolUse",
+            "PostToolUse",
+            "PreToolUse",
+            "PreToolUse",
+            "PostToolUse",
+            "Stop",
+            "SessionEnd",
+        ]
+    );
+    let transcript_path = fixture.workspace().join(".scripted/transcript.jsonl");
+    for hook in &hooks {
+        assert_eq!(hook["session_id"], "fixture-session");
+        assert_eq!(hook["transcript_path"], transcript_path.display().to_string());
+    }
+    assert_eq!(hooks[1]["prompt"], "Do the thing.\n");
+    assert_eq!(hooks[3]["tool_response"]["stdout"], "a\n");
+
+    assert_eq!(
+        fs::read_to_string(fixture.workspace().join("notes/out.txt")).unwrap(),
+        "hello\n"
+    );
+    let structured: Value = serde_json::from_str(&fs::read_to_string(structured).unwrap()).unwrap();
+    assert_eq!(structured, json!({"verdict": "approve"}));
+
+    let transcript = fixture.transcript();
+    assert_eq!(transcript[0]["type"], "user");
+    assert_eq!(transcript[1]["message"]["content"][0]["text"], "Looking around.");
+    let blocked = transcript
+        .iter()
+        .find(|line| line["message"]["content"][0]["is_error"] == true)
+        .expect("blocked tool call records an error tool_result");
+    assert_eq!(blocked["message"]["content"][0]["content"], "forbidden command");
+}
+
+#[test]
+fn create_pr_pushes_to_the_remote_and_reports_the_url() {
+    let fixture = Fixture::new(
+        r#"
+steps:
+  - write_file: { path: feature.txt, content: "x\n" }
+  - create_pr: { branch: feature/x, title: "Add x", url: "https://github.com/acme/widgets/pull/7" }
+  - stop
+"#,
+    );
+    let remote = fixture.dir.path().join("remote.git");
+    git(fixture.dir.path(), &["init", "--bare", "-q", remote.to_str().unwrap()]);
+    let ws = fixture.workspace();
+    git(&ws, &["init", "-q"]);
+    git(&ws, &["remote", "add", "origin", remote.to_str().unwrap()]);
+    let pr_url = fixture.dir.path().join("pr-url.txt");
+
+    let mut cmd = fixture.command();
+    cmd.env("BOSS_PR_URL_OUTPUT", &pr_url);
+    let output = run(cmd, "", Duration::from_secs(30));
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    assert_eq!(
+        fs::read_to_string(&pr_url).unwrap(),
+        "https://github.com/acme/widgets/pull/7"
+    );
+    assert_eq!(git(&remote, &["log", "-1", "--format=%s", "feature/x"]).trim(), "Add x");
+    assert!(git(&remote, &["show", "feature/x:feature.txt"]).contains('x'));
+    let bash = fixture
+        .hooks()
+        .into_iter()
+        .find(|hook| hook["hook_event_name"] == "PostToolUse" && hook["tool_name"] == "Bash")
+        .unwrap();
+    assert!(
+        bash["tool_input"]["command"]
+            .as_str()
+            .unwrap()
+            .starts_with("cube pr create")
+    );
+}
+
+#[test]
+fn exit_step_crashes_without_stop_or_session_end() {
+    let fixture = Fixture::new("steps:\n  - exit: { code: 3, message: \"[scripted:transient] boom\" }\n");
+    let output = run(fixture.command(), "", Duration::from_secs(30));
+    assert_eq!(output.status.code(), Some(3));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("boom"));
+    assert_eq!(event_names(&fixture.hooks()), ["SessionStart", "UserPromptSubmit"]);
+}
+
+#[test]
+fn hang_step_never_exits() {
+    let fixture = Fixture::new("steps:\n  - hang\n");
+    let mut child = fixture.command().spawn().unwrap();
+    thread::sleep(Duration::from_millis(500));
+    assert!(child.try_wait().unwrap().is_none());
+    child.kill().unwrap();
+    child.wait().unwrap();
+}
+
+#[test]
+fn idle_prompt_answers_each_line_with_a_turn() {
+    let fixture = Fixture::new("steps:\n  - stop\n");
+    let output = run(fixture.command(), "again\n/quit\n", Duration::from_secs(30));
+    assert!(output.status.success());
+    assert_eq!(
+        event_names(&fixture.hooks()),
+        [
+            "SessionStart",
+            "UserPromptSubmit",
+            "Stop",
+            "UserPromptSubmit",
+            "Stop",
+            "SessionEnd"
+        ]
+    );
+}
+
+#[test]
+fn malformed_script_fails_before_any_hook() {
+    let fixture = Fixture::new("steps:\n  - shout: hi\n");
+    let output = run(fixture.command(), "", Duration::from_secs(30));
+    assert_eq!(output.status.code(), Some(1));
+    assert!(fixture.hooks().is_empty());
+}
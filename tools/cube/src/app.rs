@@ -12,6 +12,7 @@ mod disk;
 mod dispatch;
 mod display;
 mod errors;
+mod events;
 mod excludes;
 mod gc;
 mod gh_pr;
@@ -24,8 +25,11 @@ mod reconcile;
 mod repo;
 mod reset;
 mod salvage;
+mod snapshot;
 mod stage;
+mod stats;
 mod util;
+mod warm;
 mod workspace;
 mod workspace_ops;
 
@@ -9,7 +9,10 @@ use crate::config;
 
 use crate::app::change::{run_change, run_pr, run_stack};
 use crate::app::errors::{CubeError, Result, RunResult};
+use crate::app::events::run_events;
 use crate::app::repo::{RepoEnsureDefaults, run_repo};
+use crate::app::stats::run_stats;
+use crate::app::warm::{run_daemon, run_pool};
 use crate::app::workspace::run_workspace;
 
 pub fn run(cli: Cli) -> Result<RunResult> {
@@ -38,6 +41,10 @@ pub(super) fn run_with_context(
         Command::Change { command } => run_change(command, database_path, runner),
         Command::Stack { command } => run_stack(command),
         Command::Pr { command } => run_pr(command, runner),
+        Command::Pool { command } => run_pool(command, database_path, runner),
+        Command::Daemon(args) => run_daemon(args, database_path, runner),
+        Command::Events(args) => run_events(args, database_path),
+        Command::Stats(args) => run_stats(args, database_path),
         Command::Graph(args) => run_graph(args),
         Command::Doctor(args) => run_doctor(args),
     }
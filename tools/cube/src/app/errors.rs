@@ -107,6 +107,12 @@ pub enum CubeError {
     /// failures rather than smaller records.
     #[error("salvage of workspace `{workspace_id}` is incomplete: {reason}")]
     SalvageIncomplete { workspace_id: String, reason: String },
+    /// A working-copy snapshot could not be written. When the snapshot was
+    /// taken ahead of a forced reset, the reset does not run.
+    #[error("snapshot of workspace `{workspace_id}` failed: {reason}")]
+    SnapshotFailed { workspace_id: String, reason: String },
+    #[error("snapshot `{0}` was not found")]
+    SnapshotNotFound(String),
     /// Growing the pool would have been written onto a volume that is already
     /// below cube's free-space floor, and reclamation could not clear it.
     ///
@@ -198,7 +204,10 @@ impl CubeError {
             Self::InvalidArgument(_) | Self::NotImplemented(_) => ExitCode::from(2),
             Self::RepoNotFound(_) => ExitCode::from(3),
             Self::NoAvailableWorkspace(_) => ExitCode::from(4),
-            Self::WorkspaceNotFound(_) | Self::LeaseNotFound(_) | Self::ChangeNotFound(_) => ExitCode::from(5),
+            Self::WorkspaceNotFound(_)
+            | Self::LeaseNotFound(_)
+            | Self::ChangeNotFound(_)
+            | Self::SnapshotNotFound(_) => ExitCode::from(5),
             Self::SetupStepFailed { .. } => ExitCode::from(6),
             Self::Storage(_)
             | Self::Io(_)
@@ -214,6 +223,7 @@ impl CubeError {
             | Self::CommandTimedOut { .. }
             | Self::DeadlineExceeded { .. }
             | Self::SalvageIncomplete { .. }
+            | Self::SnapshotFailed { .. }
             | Self::Json(_)
             | Self::StaleRecoveryFailed { .. } => ExitCode::FAILURE,
             // Surfaced as its own exit code so the engine's heartbeat
@@ -0,0 +1,80 @@
+//! `cube events` — the audit log read back as a stream of JSON lines.
+
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+
+use serde_json::{Value, json};
+
+use crate::audit::{self, AuditTail};
+use crate::cli::EventsArgs;
+
+use crate::app::errors::{CubeError, Result, RunResult};
+use crate::app::util::current_epoch_s;
+
+/// How far back `cube events` looks when neither `--follow` nor
+/// `--since-secs` says otherwise.
+const DEFAULT_EVENTS_WINDOW_SECS: u64 = 3600;
+
+/// Which events a `cube events` invocation prints.
+pub(super) struct EventFilter<'a> {
+    pub(super) prefixes: &'a [String],
+    pub(super) repo: Option<&'a str>,
+}
+
+impl EventFilter<'_> {
+    pub(super) fn matches(&self, event: &Value) -> bool {
+        let name = event["event"].as_str().unwrap_or_default();
+        let prefix_ok = self.prefixes.is_empty() || self.prefixes.iter().any(|p| name.starts_with(p.as_str()));
+        let repo_ok = self.repo.is_none_or(|want| event["repo"].as_str() == Some(want));
+        prefix_ok && repo_ok
+    }
+}
+
+pub(super) fn run_events(args: EventsArgs, database_path: Option<&Path>) -> Result<RunResult> {
+    let dir = audit::audit_dir(database_path)?;
+    let filter = EventFilter {
+        prefixes: &args.events,
+        repo: args.repo.as_deref(),
+    };
+    let since_secs = match (args.since_secs, args.follow) {
+        (Some(secs), _) => Some(secs),
+        (None, false) => Some(DEFAULT_EVENTS_WINDOW_SECS),
+        (None, true) => None,
+    };
+
+    // Start the tail before reading the backlog so nothing appended in
+    // between is lost; an event landing in that gap can at worst print twice.
+    let mut tail = args.follow.then(|| AuditTail::from_end(&dir));
+    let backlog: Vec<Value> = match since_secs {
+        Some(secs) => {
+            let since = current_epoch_s()?.saturating_sub(secs as i64);
+            audit::read_events_since(&dir, since)
+                .into_iter()
+                .filter(|event| filter.matches(event))
+                .collect()
+        }
+        None => Vec::new(),
+    };
+
+    let Some(tail) = tail.as_mut() else {
+        let message = backlog.iter().map(Value::to_string).collect::<Vec<_>>().join("\n");
+        return RunResult::new(message, json!({ "events": backlog }));
+    };
+
+    // Following never returns on its own: each event goes straight to stdout
+    // as one JSON line, flushed, so a pipe consumer sees it immediately.
+    let stdout = std::io::stdout();
+    let mut out = stdout.lock();
+    for event in &backlog {
+        writeln!(out, "{event}").map_err(CubeError::Io)?;
+    }
+    out.flush().map_err(CubeError::Io)?;
+    loop {
+        for event in tail.poll().into_iter().filter(|event| filter.matches(event)) {
+            writeln!(out, "{event}").map_err(CubeError::Io)?;
+        }
+        out.flush().map_err(CubeError::Io)?;
+        std::thread::sleep(Duration::from_millis(args.poll_ms.max(50)));
+    }
+}
@@ -22,6 +22,7 @@ use crate::app::reset::reset_workspace_after_fetch_within;
 use crate::app::salvage::{
     attributed_holder, attributed_task, discard_salvage_record, gc_aged_salvage_records, salvage_workspace,
 };
+use crate::app::snapshot::{SnapshotReason, discard_snapshot_record, gc_aged_snapshots, snapshot_workspace};
 use crate::app::util::current_epoch_s;
 
 /// Pool-wide gc runs at most once per 24 hours (when there is no known
@@ -535,6 +536,10 @@ pub(super) fn run_pool_gc_background(database_path: Option<std::path::PathBuf>,
         if removed > 0 {
             eprintln!("cube: auto gc: removed {removed} salvage record(s) past their retention window");
         }
+        let removed = gc_aged_snapshots(database_path.as_deref(), now);
+        if removed > 0 {
+            eprintln!("cube: auto gc: removed {removed} snapshot(s) past their retention window");
+        }
     }
 
     // Sweep consumed bookmarks from every non-leased workspace. Each workspace
@@ -737,7 +742,10 @@ pub(super) fn reclaim_quarantined_workspace(
 /// module: manifest, per-commit git-format patches, outside the workspace and
 /// outside the jj store). Only a successful salvage licenses the reset. A
 /// salvage that fails leaves the workspace retained and tries again next pass
-/// — a stuck workspace is a far smaller problem than a lost afternoon.
+/// — a stuck workspace is a far smaller problem than a lost afternoon. The
+/// same candidate also gets a `pre_reclaim` snapshot (see
+/// [`crate::app`]'s snapshot module), which keeps the untracked files a
+/// salvage cannot; it is held to the same rule.
 ///
 /// `quarantined` rows reach the same place by the same route. They exist
 /// because the dirty-reclaim guard refused a reset, so age alone was never
@@ -871,6 +879,7 @@ pub(super) fn gc_aged_unhealthy_workspaces(
         // empty log after a probe that refused reuse, or a stack larger than
         // the export cap — see `salvage_workspace`.
         let mut salvaged_to: Option<PathBuf> = None;
+        let mut snapshotted_to: Option<PathBuf> = None;
         if !status.is_reusable() {
             match salvage_workspace(runner, database_path, &record, &main_branch, now_epoch_s, deadline) {
                 Ok(path) => {
@@ -914,6 +923,36 @@ pub(super) fn gc_aged_unhealthy_workspaces(
                     continue;
                 }
             }
+
+            // Salvage exports commits; the untracked files jj never
+            // snapshotted are only on disk, and the reset is about to delete
+            // them. A snapshot that fails is the same hard stop as a failed
+            // salvage, and the salvage goes with it so the retry next pass
+            // does not leave a second one behind.
+            match snapshot_workspace(runner, database_path, &record, SnapshotReason::PreReclaim, now_epoch_s) {
+                Ok(snapshot) => snapshotted_to = Some(snapshot.path),
+                Err(e) => {
+                    eprintln!(
+                        "cube: retention: {}: snapshot failed, leaving retained rather than \
+                         reclaiming: {e}",
+                        record.workspace_id,
+                    );
+                    if let Some(path) = salvaged_to.take() {
+                        discard_salvage_record(&path);
+                    }
+                    audit!(
+                        database_path,
+                        "workspace.retention_snapshot_failed",
+                        repo = record.repo,
+                        workspace_id = record.workspace_id,
+                        prior_health = prior_health,
+                        age_secs = age_secs,
+                        head_change_id = status.head_change_id(),
+                        error = e.to_string(),
+                    );
+                    continue;
+                }
+            }
         } else if is_quarantined {
             audit!(
                 database_path,
@@ -943,8 +982,8 @@ pub(super) fn gc_aged_unhealthy_workspaces(
             eprintln!("cube: retention: {}: reset failed: {e}", record.workspace_id,);
             // The reclaim this salvage was taken for did not happen, so the
             // live workspace is still the source of truth and still retained.
-            // Drop the copy: keeping it would have the next pass write a
-            // second one, and repeated reset failures would multiply full
+            // Drop the copies: keeping them would have the next pass write a
+            // second set, and repeated reset failures would multiply full
             // copies of the same stack until the salvage TTL.
             if let Some(path) = &salvaged_to {
                 discard_salvage_record(path);
@@ -958,6 +997,9 @@ pub(super) fn gc_aged_unhealthy_workspaces(
                     error = e.to_string(),
                 );
             }
+            if let Some(path) = &snapshotted_to {
+                discard_snapshot_record(path);
+            }
             continue;
         }
 
@@ -980,6 +1022,7 @@ pub(super) fn gc_aged_unhealthy_workspaces(
         }
 
         let salvaged_to = salvaged_to.map(|p| p.display().to_string());
+        let snapshotted_to = snapshotted_to.map(|p| p.display().to_string());
         audit!(
             database_path,
             "workspace.unhealthy_gc_reset",
@@ -993,6 +1036,7 @@ pub(super) fn gc_aged_unhealthy_workspaces(
             age_secs = age_secs,
             max_age_secs = max_age_secs,
             salvage_path = salvaged_to.as_deref(),
+            snapshot_path = snapshotted_to.as_deref(),
         );
 
         eprintln!(
@@ -3,8 +3,10 @@
 
 use std::fs;
 use std::path::Path;
+use std::time::Instant;
 
 use crate::command_runner::{CommandInvocation, CommandRunner};
+use crate::config::{self, ProvisionMode};
 use crate::metadata::{RepoRecord, WorkspaceRecord};
 use crate::setup::{SetupReport, run_setup_engine};
 use crate::store::Store;
@@ -51,6 +53,23 @@ pub(super) fn auto_create_workspace(
     repo_record: &RepoRecord,
     existing: &[crate::metadata::WorkspaceCandidate],
 ) -> Result<crate::metadata::WorkspaceCandidate> {
+    let mode = config::load_config()
+        .unwrap_or_default()
+        .pool
+        .provision_mode(&repo_record.repo);
+    auto_create_workspace_with_mode(runner, database_path, repo_record, existing, mode)
+}
+
+/// [`auto_create_workspace`] with the repo's [`ProvisionMode`] already
+/// resolved, so tests can pick one without a `cube.toml`.
+pub(super) fn auto_create_workspace_with_mode(
+    runner: &dyn CommandRunner,
+    database_path: Option<&Path>,
+    repo_record: &RepoRecord,
+    existing: &[crate::metadata::WorkspaceCandidate],
+    mode: ProvisionMode,
+) -> Result<crate::metadata::WorkspaceCandidate> {
+    let started = Instant::now();
     let existing_ids: Vec<String> = existing.iter().map(|c| c.workspace_id.clone()).collect();
     let workspace_id = next_workspace_id(&repo_record.workspace_prefix, &existing_ids);
     let workspace_path = repo_record.workspace_root.join(&workspace_id);
@@ -65,32 +84,15 @@ pub(super) fn auto_create_workspace(
     // nothing and leaves no partial state.
     assert_mint_headroom(&repo_record.repo, &repo_record.workspace_root)?;
 
-    // The canonical repo is the single shared object store every pool workspace
-    // attaches to via `jj workspace add` — the cube design (R4) chose the
-    // shared-store/worktree model and explicitly REJECTED "fresh clone per
-    // task". `cube repo ensure` materialises it at `repo_record.source`: a
-    // colocated jj repo whose `origin` is the real GitHub upstream and which
-    // carries a local `main` bookmark. Without it there is nothing to attach to,
-    // so surface a clear, actionable error instead of silently regressing to an
-    // independent clone.
-    let canonical = repo_record.source.as_ref().ok_or_else(|| {
-        CubeError::InvalidArgument(format!(
-            "cannot grow the workspace pool for repo `{}`: it has no canonical source repo to \
-             attach to. Run `cube repo ensure` first so cube materialises the shared object store \
-             (default `~/.local/share/cube/repos/{}`); pool workspaces are `jj workspace add` \
-             attachments to it, not independent clones.",
-            repo_record.repo, repo_record.repo
-        ))
-    })?;
-    if !canonical.join(".jj").is_dir() {
-        return Err(CubeError::InvalidArgument(format!(
-            "canonical source repo for `{}` at `{}` has no `.jj/` store — it was never \
-             materialised or has been removed. Run `cube repo ensure` to (re)create the shared \
-             store before leasing.",
-            repo_record.repo,
-            canonical.display()
-        )));
-    }
+    // Only the shared-store mode insists on the canonical repo: the other two
+    // can always fall back to cloning `origin`, so for them a missing
+    // canonical store just narrows the options.
+    let canonical = match mode {
+        ProvisionMode::JjWorkspace => Some(require_canonical_store(repo_record)?),
+        ProvisionMode::Reflink | ProvisionMode::Clone => {
+            repo_record.source.as_deref().filter(|c| c.join(".jj").is_dir())
+        }
+    };
 
     // Attach under a dotted staging name first, then publish it under its final
     // name with an atomic rename. A create interrupted mid-flight leaves only the
@@ -109,52 +111,72 @@ pub(super) fn auto_create_workspace(
         // attach mutated that shared store, so the dangling registration must be
         // forgotten or the re-add below collides with "workspace already exists".
         // Best-effort: tolerate "no such workspace" when there is nothing to forget.
-        let _ = runner.run(&CommandInvocation {
-            cwd: repo_record.workspace_root.clone(),
-            program: "jj".to_string(),
-            args: vec![
-                "-R".to_string(),
-                canonical.display().to_string(),
-                "workspace".to_string(),
-                "forget".to_string(),
-                workspace_id.clone(),
-            ],
-            env: vec![],
-        });
+        if let Some(canonical) = canonical {
+            let _ = runner.run(&CommandInvocation {
+                cwd: repo_record.workspace_root.clone(),
+                program: "jj".to_string(),
+                args: vec![
+                    "-R".to_string(),
+                    canonical.display().to_string(),
+                    "workspace".to_string(),
+                    "forget".to_string(),
+                    workspace_id.clone(),
+                ],
+                env: vec![],
+            });
+        }
         fs::remove_dir_all(&staging_path).map_err(|source| CubeError::WorkspaceDirRemove {
             path: staging_path.clone(),
             source,
         })?;
     }
 
-    // `jj workspace add` attaches a new working copy that SHARES the canonical
-    // store: the new `.jj/repo` is a file POINTER to `<canonical>/.jj/repo`, not a
-    // full history copy (the whole point — see incident: independent clones were
-    // tens of GB each). `--name <workspace_id>` pins the store-side workspace name
-    // to the pool id regardless of the staging basename, so the publish rename
-    // needs no fix-up. This is a local operation; the timeout is only a backstop.
-    runner.run_with_timeout(
-        &CommandInvocation {
-            cwd: repo_record.workspace_root.clone(),
-            program: "jj".to_string(),
-            args: vec![
-                "-R".to_string(),
-                canonical.display().to_string(),
-                "workspace".to_string(),
-                "add".to_string(),
-                "--name".to_string(),
-                workspace_id.clone(),
-                staging_path.display().to_string(),
-            ],
-            env: vec![],
-        },
-        network_cmd_timeout(),
-    )?;
-
-    // No per-workspace remote/bookmark setup is needed: the shared store already
-    // carries `origin` = the real GitHub upstream and a local `main` bookmark, both
-    // established once by `materialize_repo_source_if_missing` at ensure time. Every
-    // attached workspace sees them, so the lease's later `jj new main` resolves.
+    let provisioned_mode = match (mode, canonical) {
+        (ProvisionMode::JjWorkspace, Some(canonical)) => {
+            attach_shared_store(runner, repo_record, canonical, &workspace_id, &staging_path)?;
+            ProvisionMode::JjWorkspace
+        }
+        (ProvisionMode::Reflink, Some(canonical)) => {
+            match reflink_copy(runner, repo_record, canonical, &staging_path) {
+                Ok(()) => ProvisionMode::Reflink,
+                Err(e) => {
+                    // Most often the filesystem has no block cloning (ext4,
+                    // tmpfs, a network mount). A full clone costs more disk but
+                    // always works, and a lease for a reachable repo must.
+                    let _ = fs::remove_dir_all(&staging_path);
+                    eprintln!(
+                        "warning: cube could not reflink-copy {} for `{}`, falling back to a full clone: {e}",
+                        canonical.display(),
+                        repo_record.repo,
+                    );
+                    audit!(
+                        database_path,
+                        "workspace.provision_fallback",
+                        repo = repo_record.repo,
+                        workspace_id = workspace_id,
+                        requested = mode.as_str(),
+                        reason = e.to_string(),
+                    );
+                    clone_independent(runner, repo_record, &staging_path)?;
+                    ProvisionMode::Clone
+                }
+            }
+        }
+        (ProvisionMode::JjWorkspace | ProvisionMode::Reflink | ProvisionMode::Clone, _) => {
+            if mode != ProvisionMode::Clone {
+                audit!(
+                    database_path,
+                    "workspace.provision_fallback",
+                    repo = repo_record.repo,
+                    workspace_id = workspace_id,
+                    requested = mode.as_str(),
+                    reason = "no canonical source repo to copy",
+                );
+            }
+            clone_independent(runner, repo_record, &staging_path)?;
+            ProvisionMode::Clone
+        }
+    };
 
     // Publish atomically. Staging and final live under the same workspace_root
     // (one filesystem), so the rename is atomic and the final path appears
@@ -178,11 +200,13 @@ pub(super) fn auto_create_workspace(
         repo = repo_record.repo,
         workspace_id = workspace_id,
         workspace_path = workspace_path.display().to_string(),
-        canonical_source = canonical.display().to_string(),
+        canonical_source = canonical.map(|c| c.display().to_string()),
+        provision_mode = provisioned_mode.as_str(),
         pool_size_before = existing.len(),
         pool_size_after = existing.len() + 1,
         disk_available_bytes = disk.map(|d| d.available_bytes),
         disk_total_bytes = disk.map(|d| d.total_bytes),
+        duration_ms = started.elapsed().as_millis() as u64,
     );
 
     Ok(crate::metadata::WorkspaceCandidate {
@@ -191,6 +215,131 @@ pub(super) fn auto_create_workspace(
     })
 }
 
+/// The repo's canonical shared store, which a [`ProvisionMode::JjWorkspace`]
+/// workspace attaches to.
+///
+/// The cube design (R4) chose the shared-store/worktree model and explicitly
+/// REJECTED "fresh clone per task" as the default. `cube repo ensure`
+/// materialises the store at `repo_record.source`: a colocated jj repo whose
+/// `origin` is the real GitHub upstream and which carries a local `main`
+/// bookmark. Without it there is nothing to attach to, so surface a clear,
+/// actionable error instead of silently regressing to an independent clone —
+/// a repo that wants clones says so with `provision = "clone"`.
+fn require_canonical_store(repo_record: &RepoRecord) -> Result<&Path> {
+    let canonical = repo_record.source.as_deref().ok_or_else(|| {
+        CubeError::InvalidArgument(format!(
+            "cannot grow the workspace pool for repo `{}`: it has no canonical source repo to \
+             attach to. Run `cube repo ensure` first so cube materialises the shared object store \
+             (default `~/.local/share/cube/repos/{}`); pool workspaces are `jj workspace add` \
+             attachments to it, not independent clones.",
+            repo_record.repo, repo_record.repo
+        ))
+    })?;
+    if !canonical.join(".jj").is_dir() {
+        return Err(CubeError::InvalidArgument(format!(
+            "canonical source repo for `{}` at `{}` has no `.jj/` store — it was never \
+             materialised or has been removed. Run `cube repo ensure` to (re)create the shared \
+             store before leasing.",
+            repo_record.repo,
+            canonical.display()
+        )));
+    }
+    Ok(canonical)
+}
+
+/// `jj workspace add` attaches a new working copy that SHARES the canonical
+/// store: the new `.jj/repo` is a file POINTER to `<canonical>/.jj/repo`, not a
+/// full history copy (the whole point — see incident: independent clones were
+/// tens of GB each). `--name <workspace_id>` pins the store-side workspace name
+/// to the pool id regardless of the staging basename, so the publish rename
+/// needs no fix-up. This is a local operation; the timeout is only a backstop.
+///
+/// No per-workspace remote/bookmark setup is needed: the shared store already
+/// carries `origin` = the real GitHub upstream and a local `main` bookmark, both
+/// established once by `materialize_repo_source_if_missing` at ensure time.
+/// Every attached workspace sees them, so the lease's later `jj new main`
+/// resolves.
+fn attach_shared_store(
+    runner: &dyn CommandRunner,
+    repo_record: &RepoRecord,
+    canonical: &Path,
+    workspace_id: &str,
+    staging_path: &Path,
+) -> Result<()> {
+    runner.run_with_timeout(
+        &CommandInvocation {
+            cwd: repo_record.workspace_root.clone(),
+            program: "jj".to_string(),
+            args: vec![
+                "-R".to_string(),
+                canonical.display().to_string(),
+                "workspace".to_string(),
+                "add".to_string(),
+                "--name".to_string(),
+                workspace_id.to_string(),
+                staging_path.display().to_string(),
+            ],
+            env: vec![],
+        },
+        network_cmd_timeout(),
+    )?;
+    Ok(())
+}
+
+/// Block-clone the whole canonical repo — store, working copy and all — into
+/// `staging_path`. The copy is an independent jj repo that shares every
+/// unmodified block with the canonical one, so it costs almost nothing until
+/// the two diverge. `--reflink=always` (and macOS's `cp -c`) refuse rather
+/// than silently degrade to a byte copy, which is what lets the caller fall
+/// back to a clone instead of paying for a full copy it did not ask for.
+fn reflink_copy(
+    runner: &dyn CommandRunner,
+    repo_record: &RepoRecord,
+    canonical: &Path,
+    staging_path: &Path,
+) -> Result<()> {
+    let source = canonical.display().to_string();
+    let dest = staging_path.display().to_string();
+    let args: Vec<String> = if cfg!(target_os = "macos") {
+        vec!["-c".to_string(), "-R".to_string(), source, dest]
+    } else {
+        vec!["-R".to_string(), "--reflink=always".to_string(), source, dest]
+    };
+    runner.run_with_timeout(
+        &CommandInvocation {
+            cwd: repo_record.workspace_root.clone(),
+            program: "cp".to_string(),
+            args,
+            env: vec![],
+        },
+        network_cmd_timeout(),
+    )?;
+    Ok(())
+}
+
+/// A full, independent `jj git clone --colocate` from `origin`. Cloning from
+/// the real upstream rather than the canonical checkout keeps `origin`
+/// pointing at GitHub, so pushes and the lease's `main@origin` reset behave
+/// exactly as they do in a shared-store workspace.
+fn clone_independent(runner: &dyn CommandRunner, repo_record: &RepoRecord, staging_path: &Path) -> Result<()> {
+    runner.run_with_timeout(
+        &CommandInvocation {
+            cwd: repo_record.workspace_root.clone(),
+            program: "jj".to_string(),
+            args: vec![
+                "git".to_string(),
+                "clone".to_string(),
+                "--colocate".to_string(),
+                repo_record.origin.clone(),
+                staging_path.display().to_string(),
+            ],
+            env: vec![],
+        },
+        network_cmd_timeout(),
+    )?;
+    Ok(())
+}
+
 /// Self-heal a broken-empty pool entry: a workspace directory that exists but
 /// has neither `.jj/` nor `.git/`. Such a husk holds no recoverable work
 /// (no commits, no working copy), so remove the directory and forget its
@@ -228,6 +377,7 @@ pub(super) fn gc_broken_empty_workspace(
 
 pub(super) fn run_setup_for_workspace(
     store: &Store,
+    database_path: Option<&Path>,
     runner: &dyn CommandRunner,
     workspace: &WorkspaceRecord,
 ) -> Result<SetupReport> {
@@ -237,6 +387,23 @@ pub(super) fn run_setup_for_workspace(
     let now = current_epoch_s()?;
     let report = run_setup_engine(store, runner, workspace, &config, now)?;
     emit_tolerated_failure_warnings(&report);
+    // Per-step durations feed `cube stats`; skipped steps are included
+    // because a slow fingerprint check is lease latency too.
+    audit!(
+        database_path,
+        "workspace.setup_ran",
+        repo = workspace.repo,
+        workspace_id = workspace.workspace_id,
+        wall_ms = report.timing.wall_ms,
+        ran = report.ran_count(),
+        skipped = report.skipped_count(),
+        failed = report.failed_count(),
+        steps = report
+            .steps
+            .iter()
+            .map(|step| serde_json::json!({ "id": step.id, "duration_ms": step.duration_ms }))
+            .collect::<Vec<_>>(),
+    );
     Ok(report)
 }
 
@@ -266,8 +433,15 @@ pub(super) fn format_setup_message(workspace_id: &str, report: &SetupReport) ->
     format!("Setup complete for {workspace_id}: {}", format_setup_counts(report))
 }
 
+/// Setup runs shorter than this are reported without a timing suffix: the
+/// breakdown only earns its space in the lease message once setup is a
+/// noticeable part of lease latency.
+const SETUP_TIMING_REPORT_THRESHOLD_MS: u64 = 1_000;
+
 /// `N ran, M skipped.` plus, when any step was a tolerated failure,
 /// `, K warning(s) (id, …).` and a line of that step's captured stderr.
+/// Slow runs also get `in Xs; slowest: id (Ys)` so the step that made the
+/// lease slow is named without reading the `--json` timing breakdown.
 fn format_setup_counts(report: &SetupReport) -> String {
     let mut out = format!("{} ran, {} skipped", report.ran_count(), report.skipped_count());
     let warnings: Vec<_> = report.tolerated_failures().collect();
@@ -284,6 +458,16 @@ fn format_setup_counts(report: &SetupReport) -> String {
         };
         out.push_str(&format!(", {} {noun} ({ids})", report.warning_count()));
     }
+    if report.timing.wall_ms >= SETUP_TIMING_REPORT_THRESHOLD_MS {
+        out.push_str(&format!(" in {:.1}s", report.timing.wall_ms as f64 / 1000.0));
+        if let Some(slowest) = report.slowest_step() {
+            out.push_str(&format!(
+                "; slowest: {} ({:.1}s)",
+                slowest.id,
+                slowest.duration_ms as f64 / 1000.0
+            ));
+        }
+    }
     out.push('.');
     for step in warnings {
         if let Some(detail) = step.warning_detail() {
@@ -373,7 +557,9 @@ mod tests {
                 stderr: Some(stderr.to_string()),
             },
             fingerprint: "abc".to_string(),
+            started_at_ms: 0,
             duration_ms: 1,
+            output: None,
         }
     }
 
@@ -382,7 +568,9 @@ mod tests {
             id: id.to_string(),
             status: StepStatus::Ran,
             fingerprint: "def".to_string(),
+            started_at_ms: 0,
             duration_ms: 1,
+            output: None,
         }
     }
 
@@ -393,6 +581,7 @@ mod tests {
                 failed_warning("copy-config", "cp: /tmp/base/config.toml: No such file or directory"),
                 ran("after"),
             ],
+            timing: Default::default(),
         };
         let message = format_lease_message("Leased mono-agent-001 at /tmp/ws.", &report);
         assert!(
@@ -405,10 +594,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn format_setup_message_names_slowest_step_when_setup_is_slow() {
+        let mut slow = ran("deps");
+        slow.duration_ms = 9_100;
+        let report = SetupReport {
+            steps: vec![ran("secrets"), slow],
+            timing: crate::setup::SetupTiming {
+                wall_ms: 9_200,
+                ..Default::default()
+            },
+        };
+        assert_eq!(
+            format_setup_message("mono-agent-001", &report),
+            "Setup complete for mono-agent-001: 2 ran, 0 skipped in 9.2s; slowest: deps (9.1s)."
+        );
+    }
+
     #[test]
     fn format_setup_message_keeps_legacy_counts_when_no_warnings() {
         let report = SetupReport {
             steps: vec![ran("deps")],
+            timing: Default::default(),
         };
         assert_eq!(
             format_setup_message("mono-agent-001", &report),
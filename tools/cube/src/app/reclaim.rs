@@ -648,6 +648,16 @@ struct TrimContext {
     free_on_disk: usize,
 }
 
+/// Whether the workspace at `path` carries its own object store rather than a
+/// pointer into the canonical one. `jj workspace add` writes `.jj/repo` as a
+/// *file* naming the shared store; a reflink copy or independent clone has a
+/// real `.jj/repo` directory. Asked of the tree on disk rather than of the
+/// repo's configured mode, so a pool that changed modes is still reclaimed
+/// correctly workspace by workspace.
+pub(super) fn has_independent_store(path: &Path) -> bool {
+    path.join(".jj").join("repo").is_dir()
+}
+
 /// The staging basename a workspace is moved to once its registry row is gone
 /// and before its bytes are unlinked. The leading dot is load-bearing: it is
 /// what keeps `discover_workspaces` — which matches on the repo's
@@ -684,6 +694,8 @@ pub(super) fn removal_staging_name(workspace_id: &str) -> String {
 /// `provision.rs` that created the attachment, bounded by
 /// [`LOCAL_JJ_CMD_TIMEOUT`] like every other subprocess here, and best-effort:
 /// a failure leaves cosmetic cruft in the shared store, not a broken pool.
+/// Workspaces provisioned as reflink copies or full clones (see
+/// [`crate::config::ProvisionMode`]) have no such registration and skip it.
 pub(super) fn stage_workspace_for_removal(
     runner: &dyn CommandRunner,
     repo_record: &RepoRecord,
@@ -709,7 +721,12 @@ pub(super) fn stage_workspace_for_removal(
         }
     })?;
 
-    if let Some(canonical) = &repo_record.source {
+    // A reflink copy or full clone owns its store outright and was never
+    // registered with the canonical one, so there is nothing to forget — and
+    // its bytes come back with the unlink alone.
+    if let Some(canonical) = &repo_record.source
+        && !has_independent_store(&staged_path)
+    {
         let invocation = CommandInvocation {
             cwd: repo_record.workspace_root.clone(),
             program: "jj".to_string(),
@@ -760,6 +777,9 @@ fn remove_workspace_from_disk(
     context: TrimContext,
     bazel_root_cache: &BazelUserRootCache<'_>,
 ) -> Result<u64> {
+    // Recorded for the audit line: an independent store frees its whole
+    // history with the unlink, a shared-store attachment only its checkout.
+    let independent_store = has_independent_store(staged_path);
     let before = DiskSpace::probe(&repo_record.workspace_root).ok();
     fs::remove_dir_all(staged_path).map_err(|source| crate::app::errors::CubeError::WorkspaceDirRemove {
         path: staged_path.to_path_buf(),
@@ -825,6 +845,7 @@ fn remove_workspace_from_disk(
         prior_holder = record.last_holder.as_deref(),
         prior_task = record.last_task.as_deref(),
         idle_secs = idle_secs(record, context.now_epoch_s),
+        independent_store = independent_store,
         free_before = context.free_on_disk,
         mark = context.mark,
         available_delta_bytes = freed,
@@ -8,11 +8,14 @@ use git_utils::repo_slug::parse_github_remote;
 
 use crate::audit;
 use crate::command_runner::{CommandRunner, RealCommandRunner};
+use crate::metadata::WorkspaceRecord;
 
 use crate::app::errors::{CubeError, Result};
 use crate::app::health::{audit_jj_op, read_head_status};
 use crate::app::jj::{run_jj, run_jj_network, run_jj_within};
 use crate::app::repo::is_unresolved_remote_target;
+use crate::app::snapshot::{SnapshotReason, SnapshotRecord, snapshot_workspace};
+use crate::app::util::current_epoch_s;
 
 /// Resolve the GitHub remote name and `owner/repo` slug from `jj git remote
 /// list` run inside the given workspace path.
@@ -98,8 +101,9 @@ pub(super) const PRESERVED_UNPUSHED_RELEASE_REASON: &str = "unpushed_work_preser
 /// What [`reset_workspace_on_release`] did to the working copy.
 #[derive(Debug)]
 pub(super) enum ReleaseResetOutcome {
-    /// The workspace was fetched and reset to `<main>@<upstream>`.
-    Reset,
+    /// The workspace was fetched and reset to `<main>@<upstream>`. Carries
+    /// the snapshot taken first when `--force-reset` overrode the guard.
+    Reset { snapshot: Option<Box<SnapshotRecord>> },
     /// The destructive reset was skipped: `@` holds non-empty work that
     /// exists on no remote, so resetting would be the only thing standing
     /// between that work and oblivion.
@@ -142,13 +146,19 @@ pub(super) struct PreservedWorkingCopy {
 /// remote bookmark. A worker that finished and pushed its branch fails that
 /// test (its work is on the remote) and is reset as before, so the steady
 /// state of the pool is unaffected.
+///
+/// `--force-reset` overrides the guard, but not silently: when the probe says
+/// the tree holds work, it is captured as a snapshot first (see
+/// [`crate::app::snapshot`]), and a snapshot that cannot be written fails the
+/// reset rather than licensing it.
 pub(super) fn reset_workspace_on_release(
     runner: &dyn CommandRunner,
     database_path: Option<&Path>,
-    workspace_path: &Path,
+    record: &WorkspaceRecord,
     main_branch: &str,
     force_reset: bool,
 ) -> Result<ReleaseResetOutcome> {
+    let workspace_path = record.workspace_path.as_path();
     audit_jj_op(database_path, workspace_path, "git", &["fetch"], None);
     run_jj_network(
         runner,
@@ -156,13 +166,21 @@ pub(super) fn reset_workspace_on_release(
         &RealCommandRunner::invocation(workspace_path, "jj", &["git", "fetch"]),
     )?;
 
-    if !force_reset {
-        // The fetch above is what makes this verdict trustworthy: the probe
-        // asks "is this work on any remote?", and a stale remote view would
-        // call already-pushed work unpushed and preserve the pool into
-        // uselessness.
-        let head_status = read_head_status(runner, database_path, workspace_path, main_branch)?;
-        if !head_status.is_reusable() {
+    // The fetch above is what makes this verdict trustworthy: the probe asks
+    // "is this work on any remote?", and a stale remote view would call
+    // already-pushed work unpushed and preserve the pool into uselessness.
+    let head_status = read_head_status(runner, database_path, workspace_path, main_branch)?;
+    let mut snapshot = None;
+    if !head_status.is_reusable() {
+        if force_reset {
+            snapshot = Some(Box::new(snapshot_workspace(
+                runner,
+                database_path,
+                record,
+                SnapshotReason::PreForceReset,
+                current_epoch_s()?,
+            )?));
+        } else {
             audit!(
                 database_path,
                 "workspace.release_reset_preserved_dirty",
@@ -182,7 +200,7 @@ pub(super) fn reset_workspace_on_release(
     }
 
     reset_workspace_after_fetch(runner, database_path, workspace_path, main_branch, None)?;
-    Ok(ReleaseResetOutcome::Reset)
+    Ok(ReleaseResetOutcome::Reset { snapshot })
 }
 
 /// The destructive half of a workspace reset, factored out so the
@@ -0,0 +1,586 @@
+//! Durable snapshots of a leased workspace's working-copy state, and replaying
+//! them into another workspace.
+//!
+//! ## What a snapshot is
+//!
+//! A snapshot answers "give me back exactly what this tree held", which is a
+//! different question from the one [`crate::app::salvage`] answers. Salvage
+//! exports the *commit history* no remote has, one patch per commit, because
+//! retention expiry only ever reclaims a workspace whose `@` the reuse guard
+//! refused. A snapshot is taken on demand (or right before a reset the caller
+//! forced past that guard) and captures the tree as a whole:
+//!
+//! - `manifest.json` — repo, workspace, lease, holder, task, why it was taken,
+//!   the base commit, and `@`'s change id and description;
+//! - `working-copy.diff` — one git-format diff from the *base* to `@`, where the
+//!   base is the newest ancestor of `@` that some remote bookmark holds. Any
+//!   checkout that has fetched that remote can re-create it with
+//!   `jj new <base>` and `git apply`, with no access to this workspace's store;
+//! - `untracked/` — files on disk that are not ignored but that jj did not
+//!   snapshot into `@` (over `snapshot.max-new-file-size`, or auto-tracking
+//!   turned off). These are the files a reset destroys that no jj operation can
+//!   bring back.
+//!
+//! The record is built under a `.partial` name and renamed into place only once
+//! `manifest.json` is on disk, the same publish step salvage uses, so listing
+//! never sees half a snapshot.
+//!
+//! ## When one is taken automatically
+//!
+//! Before either path that destroys a tree the reuse guard would have
+//! preserved:
+//!
+//! - `cube workspace release --force-reset`. A failed snapshot fails the reset,
+//!   which the release path already degrades to "free the lease, keep the tree,
+//!   mark it dirty" — never to a reset without the copy.
+//! - Retention expiry, after its salvage record. Salvage keeps the commit
+//!   history; the snapshot keeps the untracked files salvage cannot see. A
+//!   failed snapshot leaves the workspace retained for the next pass, the same
+//!   as a failed salvage, and a reset that fails afterwards discards both.
+//!
+//! The remaining destructive paths never need one: TTL-expiry reclaim refuses
+//! and quarantines, and pool trim and quarantine reclaim only touch workspaces
+//! the guard calls reusable.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::audit;
+use crate::command_runner::{CommandRunner, RealCommandRunner};
+use crate::metadata::WorkspaceRecord;
+use crate::paths;
+
+use crate::app::errors::{CubeError, Result};
+use crate::app::jj::run_jj;
+use crate::app::salvage::{attributed_holder, attributed_task};
+
+/// Schema version of a snapshot's `manifest.json`.
+const SNAPSHOT_MANIFEST_SCHEMA: u32 = 1;
+
+/// How long a snapshot is kept before the pool GC removes it. Matches the
+/// salvage window: both exist so that "what was in that tree" stays
+/// answerable well after the workspace has been handed to someone else.
+pub(super) const SNAPSHOT_RETENTION_SECS: i64 = 30 * 86_400;
+
+/// Grace period before the pool GC removes a `.partial` snapshot directory
+/// left behind by a process that died mid-write.
+const SNAPSHOT_PARTIAL_GRACE_SECS: i64 = 3600;
+
+/// Suffix a snapshot directory carries while it is still being written.
+const SNAPSHOT_PARTIAL_SUFFIX: &str = ".partial";
+
+/// Largest single untracked file copied into a snapshot. Files jj declined to
+/// track are usually the big ones; past this they are listed in the manifest
+/// as skipped rather than silently dropped or allowed to fill the data dir.
+const SNAPSHOT_UNTRACKED_FILE_LIMIT_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Ceiling on the total bytes of untracked files one snapshot copies.
+const SNAPSHOT_UNTRACKED_TOTAL_LIMIT_BYTES: u64 = 256 * 1024 * 1024;
+
+/// jj template for `@`: change id, full commit id, then the full description
+/// (which may itself contain tabs and newlines, so it goes last).
+pub(super) const SNAPSHOT_HEAD_TEMPLATE: &str = r#"change_id.short() ++ "\t" ++ commit_id ++ "\t" ++ description"#;
+
+/// The newest ancestor of `@` that a remote already has — the commit a
+/// restore starts from. Any checkout that has fetched can resolve it.
+pub(super) const SNAPSHOT_BASE_REVSET: &str = "latest(heads(::@ & ::remote_bookmarks()))";
+
+/// jj template rendering just a full commit id.
+pub(super) const SNAPSHOT_BASE_TEMPLATE: &str = r#"commit_id ++ "\n""#;
+
+/// Why a snapshot was taken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(super) enum SnapshotReason {
+    /// `cube workspace snapshot`.
+    Manual,
+    /// Taken automatically before `release --force-reset` discarded a tree
+    /// the reuse guard would have preserved.
+    PreForceReset,
+    /// Taken automatically before retention expiry reclaimed a workspace
+    /// that still held work no remote has.
+    PreReclaim,
+}
+
+impl SnapshotReason {
+    pub(super) fn as_str(self) -> &'static str {
+        match self {
+            Self::Manual => "manual",
+            Self::PreForceReset => "pre_force_reset",
+            Self::PreReclaim => "pre_reclaim",
+        }
+    }
+}
+
+/// `manifest.json` — the index of a snapshot record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(super) struct SnapshotManifest {
+    pub(super) schema: u32,
+    pub(super) snapshot_id: String,
+    pub(super) repo: String,
+    pub(super) workspace_id: String,
+    pub(super) workspace_path: String,
+    pub(super) lease_id: Option<String>,
+    pub(super) holder: Option<String>,
+    pub(super) task: Option<String>,
+    pub(super) reason: SnapshotReason,
+    pub(super) created_at_epoch_s: i64,
+    /// Commit `working-copy.diff` applies on top of.
+    pub(super) base_commit: String,
+    pub(super) head_change_id: String,
+    pub(super) head_commit_id: String,
+    pub(super) description: String,
+    /// Whether `working-copy.diff` holds anything at all.
+    pub(super) has_diff: bool,
+    /// Untracked files copied under `untracked/`, relative to the workspace.
+    pub(super) untracked: Vec<String>,
+    /// Untracked files that were over the size limits and NOT captured.
+    pub(super) skipped_untracked: Vec<String>,
+}
+
+impl SnapshotManifest {
+    /// One-line description for `cube workspace snapshots`.
+    pub(super) fn summary_line(&self) -> String {
+        let description = self.description.lines().next().unwrap_or_default();
+        let description = if description.is_empty() {
+            "(no description)"
+        } else {
+            description
+        };
+        format!(
+            "{}  {}/{}  {} {}  [{}]",
+            self.snapshot_id,
+            self.repo,
+            self.workspace_id,
+            self.head_change_id,
+            description,
+            self.reason.as_str()
+        )
+    }
+}
+
+/// One snapshot record on disk.
+#[derive(Debug, Clone)]
+pub(super) struct SnapshotRecord {
+    pub(super) path: PathBuf,
+    pub(super) manifest: SnapshotManifest,
+}
+
+/// JSON shape of a snapshot record in command payloads.
+pub(super) fn snapshot_payload(record: &SnapshotRecord) -> serde_json::Value {
+    serde_json::json!({
+        "path": record.path.display().to_string(),
+        "manifest": record.manifest,
+    })
+}
+
+/// What [`restore_snapshot`] put into the target workspace.
+#[derive(Debug, Clone, Serialize)]
+pub(super) struct RestoreOutcome {
+    pub(super) applied_diff: bool,
+    pub(super) untracked_restored: Vec<String>,
+    /// Untracked files left alone because the target already had a file
+    /// at that path.
+    pub(super) untracked_conflicts: Vec<String>,
+}
+
+/// Root of the snapshot store, threaded off `database_path` the same way the
+/// salvage store is.
+pub(super) fn snapshot_dir(database_path: Option<&Path>) -> Result<PathBuf> {
+    match database_path.and_then(Path::parent) {
+        Some(parent) => Ok(parent.join("snapshots")),
+        None => Ok(paths::data_dir()?.join("snapshots")),
+    }
+}
+
+/// Capture `record`'s working-copy state into a new snapshot record.
+///
+/// `Ok` means the diff and every untracked file within the size limits are on
+/// disk; files over the limits are named in `skipped_untracked`, never dropped
+/// silently.
+pub(super) fn snapshot_workspace(
+    runner: &dyn CommandRunner,
+    database_path: Option<&Path>,
+    record: &WorkspaceRecord,
+    reason: SnapshotReason,
+    now_epoch_s: i64,
+) -> Result<SnapshotRecord> {
+    let path = &record.workspace_path;
+    let failed = |reason: String| CubeError::SnapshotFailed {
+        workspace_id: record.workspace_id.clone(),
+        reason,
+    };
+
+    // The first jj command also makes jj snapshot the working copy, so
+    // everything below sees the tree as it is on disk right now.
+    let head = run_jj(
+        runner,
+        database_path,
+        &RealCommandRunner::invocation(
+            path,
+            "jj",
+            &["log", "--no-graph", "-r", "@", "-T", SNAPSHOT_HEAD_TEMPLATE],
+        ),
+    )?;
+    let mut parts = head.splitn(3, '\t');
+    let head_change_id = parts.next().unwrap_or_default().trim().to_string();
+    let head_commit_id = parts.next().unwrap_or_default().trim().to_string();
+    let description = parts.next().unwrap_or_default().trim().to_string();
+    if head_change_id.is_empty() || head_commit_id.is_empty() {
+        return Err(failed(format!("could not read `@` from `jj log`: {head:?}")));
+    }
+
+    let base = run_jj(
+        runner,
+        database_path,
+        &RealCommandRunner::invocation(
+            path,
+            "jj",
+            &[
+                "log",
+                "--no-graph",
+                "-r",
+                SNAPSHOT_BASE_REVSET,
+                "-T",
+                SNAPSHOT_BASE_TEMPLATE,
+            ],
+        ),
+    )?;
+    let base_commit = base.lines().next().unwrap_or_default().trim().to_string();
+    if base_commit.is_empty() {
+        return Err(failed(
+            "`@` has no ancestor on any remote, so there is no commit a restore could start from".to_string(),
+        ));
+    }
+
+    let diff = run_jj(
+        runner,
+        database_path,
+        &RealCommandRunner::invocation(
+            path,
+            "jj",
+            &["diff", "--no-pager", "--git", "--from", &base_commit, "--to", "@"],
+        ),
+    )?;
+    let tracked = run_jj(
+        runner,
+        database_path,
+        &RealCommandRunner::invocation(path, "jj", &["file", "list", "-r", "@"]),
+    )?;
+    let tracked: BTreeSet<&str> = tracked.lines().map(str::trim).filter(|l| !l.is_empty()).collect();
+    let untracked = untracked_files(path, &tracked);
+
+    let repo_dir = snapshot_dir(database_path)?.join(&record.repo);
+    let snapshot_id = unused_snapshot_id(&repo_dir, &record.workspace_id, now_epoch_s);
+    let dir = repo_dir.join(&snapshot_id);
+    let partial = repo_dir.join(format!("{snapshot_id}{SNAPSHOT_PARTIAL_SUFFIX}"));
+    let _ = std::fs::remove_dir_all(&partial);
+
+    let mut manifest = SnapshotManifest {
+        schema: SNAPSHOT_MANIFEST_SCHEMA,
+        snapshot_id,
+        repo: record.repo.clone(),
+        workspace_id: record.workspace_id.clone(),
+        workspace_path: path.display().to_string(),
+        lease_id: record.lease_id.clone(),
+        holder: attributed_holder(record).map(str::to_string),
+        task: attributed_task(record).map(str::to_string),
+        reason,
+        created_at_epoch_s: now_epoch_s,
+        base_commit,
+        head_change_id,
+        head_commit_id,
+        description,
+        has_diff: !diff.trim().is_empty(),
+        untracked: Vec::new(),
+        skipped_untracked: Vec::new(),
+    };
+    if let Err(e) = write_snapshot_record(path, &diff, &untracked, &mut manifest, &partial) {
+        let _ = std::fs::remove_dir_all(&partial);
+        return Err(e);
+    }
+    std::fs::rename(&partial, &dir).map_err(|e| {
+        let _ = std::fs::remove_dir_all(&partial);
+        CubeError::Io(e)
+    })?;
+
+    audit!(
+        database_path,
+        "workspace.snapshot_taken",
+        repo = manifest.repo,
+        workspace_id = manifest.workspace_id,
+        lease_id = manifest.lease_id.as_deref(),
+        reason = reason.as_str(),
+        snapshot_id = manifest.snapshot_id,
+        snapshot_path = dir.display().to_string(),
+        head_change_id = manifest.head_change_id,
+        base_commit = manifest.base_commit,
+        untracked_files = manifest.untracked.len(),
+        skipped_untracked = manifest.skipped_untracked,
+    );
+    Ok(SnapshotRecord { path: dir, manifest })
+}
+
+/// Delete a snapshot record written by [`snapshot_workspace`], for the same
+/// reason [`crate::app::salvage::discard_salvage_record`] exists: the reclaim
+/// it was taken for failed, so the live workspace still holds the work and the
+/// next pass would take another copy.
+pub(super) fn discard_snapshot_record(path: &Path) {
+    if let Err(e) = std::fs::remove_dir_all(path)
+        && e.kind() != std::io::ErrorKind::NotFound
+    {
+        eprintln!(
+            "cube: snapshot: failed to discard {} after a failed reclaim: {e}",
+            path.display()
+        );
+    }
+}
+
+/// `<workspace_id>-<epoch>`, suffixed when two snapshots of the same
+/// workspace land in the same second so neither clobbers the other.
+fn unused_snapshot_id(repo_dir: &Path, workspace_id: &str, now_epoch_s: i64) -> String {
+    let base = format!("{workspace_id}-{now_epoch_s}");
+    if !repo_dir.join(&base).exists() {
+        return base;
+    }
+    (2..)
+        .map(|n| format!("{base}-{n}"))
+        .find(|candidate| !repo_dir.join(candidate).exists())
+        .expect("an unused suffix exists")
+}
+
+/// Every regular file under `workspace` that is neither ignored nor tracked
+/// in `@`, relative to the workspace root. Ignore rules are read straight from
+/// the `.gitignore` files on disk because a shared-store workspace has no
+/// `.git` of its own to ask.
+fn untracked_files(workspace: &Path, tracked: &BTreeSet<&str>) -> Vec<PathBuf> {
+    let walker = ignore::WalkBuilder::new(workspace)
+        .hidden(false)
+        .require_git(false)
+        .git_global(false)
+        .filter_entry(|entry| !matches!(entry.file_name().to_str(), Some(".jj" | ".git")))
+        .build();
+    let mut files: Vec<PathBuf> = walker
+        .flatten()
+        .filter(|entry| entry.file_type().is_some_and(|t| t.is_file()))
+        .filter_map(|entry| entry.path().strip_prefix(workspace).ok().map(Path::to_path_buf))
+        .filter(|relative| !tracked.contains(relative.to_string_lossy().as_ref()))
+        .collect();
+    files.sort();
+    files
+}
+
+/// Write the diff, the untracked tree and finally the manifest into `dir`.
+fn write_snapshot_record(
+    workspace: &Path,
+    diff: &str,
+    untracked: &[PathBuf],
+    manifest: &mut SnapshotManifest,
+    dir: &Path,
+) -> Result<()> {
+    std::fs::create_dir_all(dir).map_err(CubeError::Io)?;
+    // The runner trims stdout; `git apply` wants the final newline back.
+    let mut diff = diff.to_string();
+    if !diff.is_empty() && !diff.ends_with('\n') {
+        diff.push('\n');
+    }
+    std::fs::write(dir.join("working-copy.diff"), diff.as_bytes()).map_err(CubeError::Io)?;
+
+    let mut total = 0u64;
+    for relative in untracked {
+        let source = workspace.join(relative);
+        let size = std::fs::metadata(&source).map_err(CubeError::Io)?.len();
+        let display = relative.display().to_string();
+        if size > SNAPSHOT_UNTRACKED_FILE_LIMIT_BYTES || total + size > SNAPSHOT_UNTRACKED_TOTAL_LIMIT_BYTES {
+            manifest.skipped_untracked.push(display);
+            continue;
+        }
+        let target = dir.join("untracked").join(relative);
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent).map_err(CubeError::Io)?;
+        }
+        std::fs::copy(&source, &target).map_err(CubeError::Io)?;
+        total += size;
+        manifest.untracked.push(display);
+    }
+
+    let json = serde_json::to_string_pretty(manifest)?;
+    std::fs::write(dir.join("manifest.json"), json.as_bytes()).map_err(CubeError::Io)?;
+    Ok(())
+}
+
+/// Replay `snapshot` into the workspace at `workspace_path`: start a new
+/// change on the snapshot's base, apply its diff, copy back its untracked
+/// files (never over an existing file), and restore the description.
+///
+/// `jj new` leaves whatever the target's `@` held as a visible head, so this
+/// cannot lose work in the target either.
+pub(super) fn restore_snapshot(
+    runner: &dyn CommandRunner,
+    database_path: Option<&Path>,
+    snapshot: &SnapshotRecord,
+    workspace_path: &Path,
+) -> Result<RestoreOutcome> {
+    let manifest = &snapshot.manifest;
+    run_jj(
+        runner,
+        database_path,
+        &RealCommandRunner::invocation(workspace_path, "jj", &["new", &manifest.base_commit]),
+    )?;
+
+    if manifest.has_diff {
+        let diff_path = snapshot.path.join("working-copy.diff");
+        runner.run(&RealCommandRunner::invocation(
+            workspace_path,
+            "git",
+            &["apply", "--whitespace=nowarn", &diff_path.display().to_string()],
+        ))?;
+    }
+
+    let mut outcome = RestoreOutcome {
+        applied_diff: manifest.has_diff,
+        untracked_restored: Vec::new(),
+        untracked_conflicts: Vec::new(),
+    };
+    for relative in &manifest.untracked {
+        let source = snapshot.path.join("untracked").join(relative);
+        let target = workspace_path.join(relative);
+        if target.exists() {
+            outcome.untracked_conflicts.push(relative.clone());
+            continue;
+        }
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent).map_err(CubeError::Io)?;
+        }
+        std::fs::copy(&source, &target).map_err(CubeError::Io)?;
+        outcome.untracked_restored.push(relative.clone());
+    }
+
+    if !manifest.description.is_empty() {
+        run_jj(
+            runner,
+            database_path,
+            &RealCommandRunner::invocation(workspace_path, "jj", &["describe", "-m", &manifest.description]),
+        )?;
+    }
+    Ok(outcome)
+}
+
+/// Read every snapshot record, newest first, optionally filtered by repo and
+/// source workspace. Malformed records are skipped rather than failing the
+/// listing.
+pub(super) fn list_snapshot_records(
+    database_path: Option<&Path>,
+    repo: Option<&str>,
+    workspace_id: Option<&str>,
+) -> Result<Vec<SnapshotRecord>> {
+    let root = snapshot_dir(database_path)?;
+    let mut records = Vec::new();
+    let Ok(repo_dirs) = std::fs::read_dir(&root) else {
+        return Ok(records);
+    };
+    for repo_entry in repo_dirs.flatten() {
+        if !repo_entry.path().is_dir() {
+            continue;
+        }
+        if let Some(want) = repo
+            && repo_entry.file_name().to_string_lossy() != want
+        {
+            continue;
+        }
+        let Ok(record_dirs) = std::fs::read_dir(repo_entry.path()) else {
+            continue;
+        };
+        for entry in record_dirs.flatten() {
+            let path = entry.path();
+            let Some(manifest) = read_manifest(&path) else {
+                continue;
+            };
+            if let Some(want) = workspace_id
+                && manifest.workspace_id != want
+            {
+                continue;
+            }
+            records.push(SnapshotRecord { path, manifest });
+        }
+    }
+    records.sort_by(|a, b| {
+        b.manifest
+            .created_at_epoch_s
+            .cmp(&a.manifest.created_at_epoch_s)
+            .then_with(|| b.manifest.snapshot_id.cmp(&a.manifest.snapshot_id))
+    });
+    Ok(records)
+}
+
+/// Resolve a snapshot by id (`mono-agent-001-1790000000`) or by the path of
+/// its directory.
+pub(super) fn find_snapshot(database_path: Option<&Path>, snapshot: &str) -> Result<SnapshotRecord> {
+    let as_path = Path::new(snapshot);
+    if as_path.is_absolute()
+        && let Some(manifest) = read_manifest(as_path)
+    {
+        return Ok(SnapshotRecord {
+            path: as_path.to_path_buf(),
+            manifest,
+        });
+    }
+    list_snapshot_records(database_path, None, None)?
+        .into_iter()
+        .find(|record| record.manifest.snapshot_id == snapshot)
+        .ok_or_else(|| CubeError::SnapshotNotFound(snapshot.to_string()))
+}
+
+fn read_manifest(dir: &Path) -> Option<SnapshotManifest> {
+    let raw = std::fs::read_to_string(dir.join("manifest.json")).ok()?;
+    serde_json::from_str(&raw).ok()
+}
+
+/// Delete snapshots older than [`SNAPSHOT_RETENTION_SECS`], plus `.partial`
+/// directories older than [`SNAPSHOT_PARTIAL_GRACE_SECS`]. Returns how many
+/// directories were removed. Best-effort, like salvage GC.
+pub(super) fn gc_aged_snapshots(database_path: Option<&Path>, now_epoch_s: i64) -> usize {
+    let Ok(root) = snapshot_dir(database_path) else {
+        return 0;
+    };
+    let Ok(repo_dirs) = std::fs::read_dir(&root) else {
+        return 0;
+    };
+    let retention_cutoff = now_epoch_s.saturating_sub(SNAPSHOT_RETENTION_SECS);
+    let partial_cutoff = now_epoch_s.saturating_sub(SNAPSHOT_PARTIAL_GRACE_SECS);
+    let mut removed = 0usize;
+    for repo_entry in repo_dirs.flatten() {
+        let Ok(record_dirs) = std::fs::read_dir(repo_entry.path()) else {
+            continue;
+        };
+        for entry in record_dirs.flatten() {
+            let path = entry.path();
+            let expired = match read_manifest(&path) {
+                Some(manifest) => manifest.created_at_epoch_s <= retention_cutoff,
+                None => {
+                    path.to_string_lossy().ends_with(SNAPSHOT_PARTIAL_SUFFIX)
+                        && modified_epoch_s(&path).is_none_or(|modified| modified <= partial_cutoff)
+                }
+            };
+            if !expired {
+                continue;
+            }
+            match std::fs::remove_dir_all(&path) {
+                Ok(()) => removed += 1,
+                Err(e) => eprintln!("cube: snapshot gc: failed to remove {}: {e}", path.display()),
+            }
+        }
+    }
+    removed
+}
+
+fn modified_epoch_s(path: &Path) -> Option<i64> {
+    std::fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .ok()?
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()
+        .map(|d| d.as_secs() as i64)
+}
@@ -0,0 +1,353 @@
+//! `cube stats` — pool state from the registry plus latency and reclaim
+//! metrics folded out of the audit log, as a summary or Prometheus text.
+//!
+//! The registry only knows the pool as it is now; everything with a duration
+//! or a byte count comes from the audit events the lease, provisioning, setup
+//! and reclaim paths already write (`lease.acquired.wait_ms`,
+//! `workspace.created.duration_ms`, `workspace.setup_ran.steps`,
+//! `workspace.gc_removed` / `workspace.compacted` `available_delta_bytes`).
+//! Those are aggregated over `--window-hours`, so every histogram and total
+//! here describes that window rather than the process lifetime a Prometheus
+//! counter usually would.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::Path;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::audit;
+use crate::cli::StatsArgs;
+use crate::metadata::WorkspaceRecord;
+use crate::store::{Store, WorkspaceListFilter};
+
+use crate::app::display::effective_state_display;
+use crate::app::errors::{CubeError, Result, RunResult};
+use crate::app::util::current_epoch_s;
+
+/// Histogram bucket upper bounds, in seconds. Spans a sub-second reuse lease
+/// through a cold clone plus a long setup.
+const BUCKETS_SECS: &[f64] = &[0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 120.0, 300.0, 600.0];
+
+/// Effective workspace states, in the order they are reported.
+const STATES: &[&str] = &["free", "free-dirty", "free-conflicted", "free-quarantined", "leased"];
+
+/// A fixed-bucket latency histogram.
+#[derive(Debug, Clone, Default, Serialize)]
+pub(super) struct Histogram {
+    /// Per-bucket (non-cumulative) counts, one per [`BUCKETS_SECS`] entry
+    /// plus a final overflow bucket.
+    pub(super) counts: Vec<u64>,
+    pub(super) count: u64,
+    pub(super) sum_secs: f64,
+}
+
+impl Histogram {
+    pub(super) fn observe_ms(&mut self, ms: u64) {
+        if self.counts.is_empty() {
+            self.counts = vec![0; BUCKETS_SECS.len() + 1];
+        }
+        let secs = ms as f64 / 1000.0;
+        let index = BUCKETS_SECS
+            .iter()
+            .position(|bound| secs <= *bound)
+            .unwrap_or(BUCKETS_SECS.len());
+        self.counts[index] += 1;
+        self.count += 1;
+        self.sum_secs += secs;
+    }
+
+    /// Upper bound of the bucket holding quantile `q`; `None` when empty or
+    /// when it lands in the overflow bucket.
+    pub(super) fn quantile_bound(&self, q: f64) -> Option<f64> {
+        if self.count == 0 {
+            return None;
+        }
+        let target = (q * self.count as f64).ceil().max(1.0) as u64;
+        let mut seen = 0;
+        for (index, count) in self.counts.iter().enumerate() {
+            seen += count;
+            if seen >= target {
+                return BUCKETS_SECS.get(index).copied();
+            }
+        }
+        None
+    }
+
+    fn summary(&self) -> String {
+        if self.count == 0 {
+            return "none".to_string();
+        }
+        let p90 = match self.quantile_bound(0.9) {
+            Some(bound) => format!("<={bound}s"),
+            None => format!(">{}s", BUCKETS_SECS[BUCKETS_SECS.len() - 1]),
+        };
+        format!(
+            "{} (mean {:.1}s, p90 {p90})",
+            self.count,
+            self.sum_secs / self.count as f64
+        )
+    }
+}
+
+/// Everything `cube stats` knows about one repo.
+#[derive(Debug, Clone, Default, Serialize)]
+pub(super) struct RepoStats {
+    pub(super) repo: String,
+    pub(super) pool_size: usize,
+    /// Workspaces by effective state (`free`, `free-dirty`, …, `leased`).
+    pub(super) states: BTreeMap<String, usize>,
+    pub(super) lease_wait: Histogram,
+    pub(super) provision: Histogram,
+    pub(super) setup_steps: BTreeMap<String, Histogram>,
+    pub(super) reclaimed_bytes: u64,
+    pub(super) reclaimed_workspaces: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(super) struct PoolStats {
+    pub(super) window_secs: i64,
+    pub(super) repos: Vec<RepoStats>,
+}
+
+pub(super) fn run_stats(args: StatsArgs, database_path: Option<&Path>) -> Result<RunResult> {
+    let store = match database_path {
+        Some(path) => Store::open_at(path)?,
+        None => Store::open_default()?,
+    };
+    if let Some(repo) = args.repo.as_deref()
+        && store.get_repo(repo)?.is_none()
+    {
+        return Err(CubeError::RepoNotFound(repo.to_string()));
+    }
+    let workspaces = store.list_workspaces_filtered(&WorkspaceListFilter {
+        repo: args.repo.as_deref(),
+        ..Default::default()
+    })?;
+    let window_secs = (args.window_hours as i64).saturating_mul(3600);
+    let since = current_epoch_s()?.saturating_sub(window_secs);
+    let events = audit::read_events_since(&audit::audit_dir(database_path)?, since);
+
+    let repos: Vec<String> = match args.repo {
+        Some(repo) => vec![repo],
+        None => store.list_repos()?.into_iter().map(|r| r.repo).collect(),
+    };
+    let stats = collect_stats(&repos, &workspaces, &events, window_secs);
+    let message = if args.prometheus {
+        render_prometheus(&stats)
+    } else {
+        render_summary(&stats)
+    };
+    RunResult::new(message, stats)
+}
+
+/// Fold the registry rows and audit events into per-repo stats. Events for
+/// repos outside `repos` are ignored.
+pub(super) fn collect_stats(
+    repos: &[String],
+    workspaces: &[WorkspaceRecord],
+    events: &[Value],
+    window_secs: i64,
+) -> PoolStats {
+    let mut by_repo: BTreeMap<&str, RepoStats> = repos
+        .iter()
+        .map(|repo| {
+            let stats = RepoStats {
+                repo: repo.clone(),
+                states: STATES.iter().map(|s| (s.to_string(), 0)).collect(),
+                ..Default::default()
+            };
+            (repo.as_str(), stats)
+        })
+        .collect();
+
+    for record in workspaces {
+        if let Some(stats) = by_repo.get_mut(record.repo.as_str()) {
+            stats.pool_size += 1;
+            *stats.states.entry(effective_state_display(record)).or_default() += 1;
+        }
+    }
+
+    for event in events {
+        let Some(stats) = event["repo"].as_str().and_then(|repo| by_repo.get_mut(repo)) else {
+            continue;
+        };
+        match event["event"].as_str().unwrap_or_default() {
+            "lease.acquired" | "lease.acquired_dirty" => {
+                if let Some(ms) = event["wait_ms"].as_u64() {
+                    stats.lease_wait.observe_ms(ms);
+                }
+            }
+            "workspace.created" => {
+                if let Some(ms) = event["duration_ms"].as_u64() {
+                    stats.provision.observe_ms(ms);
+                }
+            }
+            "workspace.setup_ran" => {
+                for step in event["steps"].as_array().into_iter().flatten() {
+                    if let (Some(id), Some(ms)) = (step["id"].as_str(), step["duration_ms"].as_u64()) {
+                        stats.setup_steps.entry(id.to_string()).or_default().observe_ms(ms);
+                    }
+                }
+            }
+            "workspace.gc_removed" => {
+                stats.reclaimed_workspaces += 1;
+                stats.reclaimed_bytes += event["available_delta_bytes"].as_u64().unwrap_or(0);
+            }
+            "workspace.compacted" => {
+                stats.reclaimed_bytes += event["available_delta_bytes"].as_u64().unwrap_or(0);
+            }
+            _ => {}
+        }
+    }
+
+    PoolStats {
+        window_secs,
+        repos: by_repo.into_values().collect(),
+    }
+}
+
+fn render_summary(stats: &PoolStats) -> String {
+    if stats.repos.is_empty() {
+        return "No repos registered.".to_string();
+    }
+    let mut out = format!("Window: last {}h\n", stats.window_secs / 3600);
+    for repo in &stats.repos {
+        let states = STATES
+            .iter()
+            .map(|state| format!("{} {state}", repo.states.get(*state).copied().unwrap_or(0)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let _ = writeln!(out, "\n{}: {} workspaces ({states})", repo.repo, repo.pool_size);
+        let _ = writeln!(out, "  leases:      {}", repo.lease_wait.summary());
+        let _ = writeln!(out, "  provisioned: {}", repo.provision.summary());
+        for (step, histogram) in &repo.setup_steps {
+            let _ = writeln!(out, "  setup {step}: {}", histogram.summary());
+        }
+        let _ = writeln!(
+            out,
+            "  reclaimed:   {} workspace(s), {} byte(s)",
+            repo.reclaimed_workspaces, repo.reclaimed_bytes
+        );
+    }
+    out.trim_end().to_string()
+}
+
+/// Render `stats` in the Prometheus text exposition format (0.0.4).
+pub(super) fn render_prometheus(stats: &PoolStats) -> String {
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "# HELP cube_stats_window_seconds Window the histograms and totals cover."
+    );
+    let _ = writeln!(out, "# TYPE cube_stats_window_seconds gauge");
+    let _ = writeln!(out, "cube_stats_window_seconds {}", stats.window_secs);
+
+    header(
+        &mut out,
+        "cube_pool_workspaces",
+        "gauge",
+        "Workspaces by effective state.",
+    );
+    for repo in &stats.repos {
+        for (state, count) in &repo.states {
+            let _ = writeln!(
+                out,
+                "cube_pool_workspaces{{repo=\"{}\",state=\"{}\"}} {count}",
+                escape_label(&repo.repo),
+                escape_label(state)
+            );
+        }
+    }
+
+    header(
+        &mut out,
+        "cube_lease_wait_seconds",
+        "histogram",
+        "Time from `cube workspace lease` starting to the lease being granted.",
+    );
+    for repo in &stats.repos {
+        write_histogram(
+            &mut out,
+            "cube_lease_wait_seconds",
+            &repo_labels(repo),
+            &repo.lease_wait,
+        );
+    }
+    header(
+        &mut out,
+        "cube_provision_seconds",
+        "histogram",
+        "Time to provision a new workspace.",
+    );
+    for repo in &stats.repos {
+        write_histogram(&mut out, "cube_provision_seconds", &repo_labels(repo), &repo.provision);
+    }
+    header(
+        &mut out,
+        "cube_setup_step_seconds",
+        "histogram",
+        "Setup step durations.",
+    );
+    for repo in &stats.repos {
+        for (step, histogram) in &repo.setup_steps {
+            let labels = format!("{},step=\"{}\"", repo_labels(repo), escape_label(step));
+            write_histogram(&mut out, "cube_setup_step_seconds", &labels, histogram);
+        }
+    }
+
+    header(
+        &mut out,
+        "cube_reclaimed_bytes",
+        "gauge",
+        "Bytes freed by pool trim and compaction.",
+    );
+    for repo in &stats.repos {
+        let _ = writeln!(
+            out,
+            "cube_reclaimed_bytes{{{}}} {}",
+            repo_labels(repo),
+            repo.reclaimed_bytes
+        );
+    }
+    header(
+        &mut out,
+        "cube_reclaimed_workspaces",
+        "gauge",
+        "Workspaces removed by pool trim.",
+    );
+    for repo in &stats.repos {
+        let _ = writeln!(
+            out,
+            "cube_reclaimed_workspaces{{{}}} {}",
+            repo_labels(repo),
+            repo.reclaimed_workspaces
+        );
+    }
+    out
+}
+
+fn header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} {kind}");
+}
+
+fn repo_labels(repo: &RepoStats) -> String {
+    format!("repo=\"{}\"", escape_label(&repo.repo))
+}
+
+fn write_histogram(out: &mut String, name: &str, labels: &str, histogram: &Histogram) {
+    let mut cumulative = 0u64;
+    for (index, bound) in BUCKETS_SECS.iter().enumerate() {
+        cumulative += histogram.counts.get(index).copied().unwrap_or(0);
+        let _ = writeln!(out, "{name}_bucket{{{labels},le=\"{bound}\"}} {cumulative}");
+    }
+    let _ = writeln!(out, "{name}_bucket{{{labels},le=\"+Inf\"}} {}", histogram.count);
+    let _ = writeln!(out, "{name}_sum{{{labels}}} {}", histogram.sum_secs);
+    let _ = writeln!(out, "{name}_count{{{labels}}} {}", histogram.count);
+}
+
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
@@ -0,0 +1,173 @@
+use super::support::{FakeRunner, lease_runner_for, seed_mono_repo, with_database_path};
+use clap::Parser;
+use serde_json::{Value, json};
+
+use crate::audit;
+use crate::cli::Cli;
+
+use crate::app::dispatch::run_with_dependencies;
+use crate::app::util::current_epoch_s;
+
+fn append(dir: &std::path::Path, at: i64, event: &str, fields: Value) {
+    let Value::Object(fields) = fields else {
+        panic!("fields must be an object");
+    };
+    audit::append_at(dir, at, event, fields).expect("append audit event");
+}
+
+#[test]
+fn lease_records_wait_ms_and_events_filters_by_prefix_and_repo() {
+    let (tempdir, database_path) = with_database_path();
+    let workspace_root = tempdir.path().join("workspaces");
+    let workspace_path = workspace_root.join("mono-agent-001");
+    std::fs::create_dir_all(workspace_path.join(".jj")).expect("workspace dir");
+    seed_mono_repo(&workspace_root, &database_path);
+
+    let runner = lease_runner_for(&workspace_path, "abc1234");
+    let lease = Cli::parse_from(["cube", "workspace", "lease", "mono", "--task", "t"]);
+    run_with_dependencies(lease, Some(&database_path), &runner).expect("lease");
+    runner.assert_exhausted();
+
+    let dir = tempdir.path().join("audit");
+    let now = current_epoch_s().expect("now");
+    append(&dir, now, "lease.acquired", json!({ "repo": "other", "wait_ms": 5 }));
+
+    let events = Cli::parse_from(["cube", "events", "--event", "lease.", "--repo", "mono"]);
+    let result = run_with_dependencies(events, Some(&database_path), &FakeRunner::default()).expect("events");
+    let events = result.payload["events"].as_array().expect("events array");
+    assert_eq!(events.len(), 1, "{events:?}");
+    assert_eq!(events[0]["event"], "lease.acquired");
+    assert_eq!(events[0]["repo"], "mono");
+    assert!(events[0]["wait_ms"].is_u64(), "lease.acquired carries wait_ms");
+    // One JSON object per line.
+    let line: Value = serde_json::from_str(&result.message).expect("message is a JSON line");
+    assert_eq!(line["workspace_id"], "mono-agent-001");
+}
+
+#[test]
+fn events_since_excludes_older_entries() {
+    let (tempdir, database_path) = with_database_path();
+    let dir = tempdir.path().join("audit");
+    let now = current_epoch_s().expect("now");
+    append(&dir, now - 7200, "lease.released", json!({ "repo": "mono" }));
+    append(&dir, now - 10, "lease.released", json!({ "repo": "mono" }));
+
+    let events = Cli::parse_from(["cube", "events", "--since-secs", "60"]);
+    let result = run_with_dependencies(events, Some(&database_path), &FakeRunner::default()).expect("events");
+    assert_eq!(result.payload["events"].as_array().expect("events").len(), 1);
+}
+
+#[test]
+fn stats_aggregates_pool_counts_latencies_and_reclaim_over_window() {
+    let (tempdir, database_path) = with_database_path();
+    let workspace_root = tempdir.path().join("workspaces");
+    let workspace_path = workspace_root.join("mono-agent-001");
+    std::fs::create_dir_all(workspace_path.join(".jj")).expect("workspace dir");
+    std::fs::create_dir_all(workspace_root.join("mono-agent-002").join(".jj")).expect("workspace dir");
+    seed_mono_repo(&workspace_root, &database_path);
+
+    let runner = lease_runner_for(&workspace_path, "abc1234");
+    let lease = Cli::parse_from(["cube", "workspace", "lease", "mono", "--task", "t"]);
+    run_with_dependencies(lease, Some(&database_path), &runner).expect("lease");
+
+    let dir = tempdir.path().join("audit");
+    let now = current_epoch_s().expect("now");
+    append(
+        &dir,
+        now,
+        "workspace.created",
+        json!({ "repo": "mono", "duration_ms": 4000 }),
+    );
+    append(
+        &dir,
+        now,
+        "workspace.setup_ran",
+        json!({ "repo": "mono", "steps": [{ "id": "deps", "duration_ms": 45000 }] }),
+    );
+    append(
+        &dir,
+        now,
+        "workspace.gc_removed",
+        json!({ "repo": "mono", "available_delta_bytes": 1000 }),
+    );
+    append(
+        &dir,
+        now,
+        "workspace.compacted",
+        json!({ "repo": "mono", "available_delta_bytes": 24 }),
+    );
+    // Outside the one-hour window: ignored.
+    append(
+        &dir,
+        now - 7200,
+        "workspace.created",
+        json!({ "repo": "mono", "duration_ms": 9000 }),
+    );
+
+    let stats = Cli::parse_from(["cube", "stats", "--repo", "mono", "--window-hours", "1"]);
+    let result = run_with_dependencies(stats, Some(&database_path), &FakeRunner::default()).expect("stats");
+    let repo = &result.payload["repos"][0];
+    assert_eq!(repo["repo"], "mono");
+    assert_eq!(repo["pool_size"], 2);
+    assert_eq!(repo["states"]["leased"], 1);
+    assert_eq!(repo["states"]["free"], 1);
+    assert_eq!(repo["lease_wait"]["count"], 1);
+    assert_eq!(repo["provision"]["count"], 1);
+    assert_eq!(repo["provision"]["sum_secs"], 4.0);
+    assert_eq!(repo["setup_steps"]["deps"]["count"], 1);
+    assert_eq!(repo["reclaimed_bytes"], 1024);
+    assert_eq!(repo["reclaimed_workspaces"], 1);
+    assert!(result.message.contains("mono: 2 workspaces"), "{}", result.message);
+}
+
+#[test]
+fn stats_prometheus_renders_cumulative_histogram_buckets() {
+    let (tempdir, database_path) = with_database_path();
+    let workspace_root = tempdir.path().join("workspaces");
+    seed_mono_repo(&workspace_root, &database_path);
+
+    let dir = tempdir.path().join("audit");
+    let now = current_epoch_s().expect("now");
+    for ms in [200, 3000, 700_000] {
+        append(
+            &dir,
+            now,
+            "workspace.created",
+            json!({ "repo": "mono", "duration_ms": ms }),
+        );
+    }
+
+    let stats = Cli::parse_from(["cube", "stats", "--prometheus"]);
+    let result = run_with_dependencies(stats, Some(&database_path), &FakeRunner::default()).expect("stats");
+    let text = &result.message;
+    assert!(text.contains("# TYPE cube_provision_seconds histogram"), "{text}");
+    assert!(
+        text.contains("cube_provision_seconds_bucket{repo=\"mono\",le=\"0.25\"} 1"),
+        "{text}"
+    );
+    assert!(
+        text.contains("cube_provision_seconds_bucket{repo=\"mono\",le=\"5\"} 2"),
+        "{text}"
+    );
+    assert!(
+        text.contains("cube_provision_seconds_bucket{repo=\"mono\",le=\"600\"} 2"),
+        "{text}"
+    );
+    assert!(
+        text.contains("cube_provision_seconds_bucket{repo=\"mono\",le=\"+Inf\"} 3"),
+        "{text}"
+    );
+    assert!(text.contains("cube_provision_seconds_count{repo=\"mono\"} 3"), "{text}");
+    assert!(
+        text.contains("cube_pool_workspaces{repo=\"mono\",state=\"free\"} 0"),
+        "{text}"
+    );
+    assert!(text.contains("cube_stats_window_seconds 86400"), "{text}");
+}
+
+#[test]
+fn stats_unknown_repo_is_an_error() {
+    let (_tempdir, database_path) = with_database_path();
+    let stats = Cli::parse_from(["cube", "stats", "--repo", "nope"]);
+    assert!(run_with_dependencies(stats, Some(&database_path), &FakeRunner::default()).is_err());
+}
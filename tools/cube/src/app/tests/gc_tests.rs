@@ -1,3 +1,4 @@
+use super::snapshot_tests::snapshot_commands;
 use super::support::{
     ExpectedCommand, FakeRunner, audit_events, gc_exec_sweep_command, gc_noop_command, gc_pr_noop_command,
     gc_pr_sweep_command, head_status_command, head_status_output, lease_runner_for, release_guard_reusable_command,
@@ -18,6 +19,7 @@ use crate::app::salvage::{
     SALVAGE_COMMIT_LIMIT, SALVAGE_LOG_TEMPLATE, SALVAGE_RETENTION_SECS, gc_aged_salvage_records, list_salvage_records,
     salvage_dir, salvage_log_limit_arg, salvage_revset,
 };
+use crate::app::snapshot::{SNAPSHOT_HEAD_TEMPLATE, SnapshotReason, list_snapshot_records};
 use crate::app::util::current_epoch_s;
 
 #[test]
@@ -1052,6 +1054,7 @@ fn gc_salvages_unpushed_work_before_reclaiming_past_the_ttl() {
         "abcd1234\t6e6b90bc\thalf-finished refactor\n",
         &[("6e6b90bc", "diff --git a/x b/x\n+one\n")],
     ));
+    script.extend(snapshot_commands(&ws_path));
     script.extend(reset_after_fetch_commands_for(&ws_path));
     let runner = FakeRunner::new(script);
 
@@ -1077,6 +1080,12 @@ fn gc_salvages_unpushed_work_before_reclaiming_past_the_ttl() {
     assert!(patch.contains("+one"), "the patch holds the actual work: {patch}");
     assert!(record.path.join("commits.txt").exists());
 
+    // The tree as a whole is kept too, next to the commit history.
+    let snapshots = list_snapshot_records(Some(&database_path), None, None).expect("snapshot records");
+    assert_eq!(snapshots.len(), 1);
+    assert_eq!(snapshots[0].manifest.reason, SnapshotReason::PreReclaim);
+    assert_eq!(snapshots[0].manifest.workspace_id, "mono-agent-001");
+
     let events = audit_events(&tempdir);
     let salvaged: Vec<_> = events
         .iter()
@@ -1384,6 +1393,69 @@ fn gc_refuses_to_reclaim_when_the_salvage_log_comes_back_empty() {
     );
 }
 
+/// The snapshot is held to the same rule as the salvage: if it cannot be
+/// taken the workspace is not reclaimed, and the salvage already written is
+/// dropped so the retry next pass does not leave a second one.
+#[test]
+fn gc_leaves_workspace_retained_when_the_pre_reclaim_snapshot_fails() {
+    let (tempdir, database_path) = with_database_path();
+    let (store, ws_path) = setup_unhealthy_gc_scenario(&tempdir, &database_path);
+
+    store
+        .update_workspace_health("mono", "mono-agent-001", crate::metadata::WorkspaceHealth::Dirty)
+        .expect("mark dirty");
+
+    let ws = store.get_workspace_by_path(&ws_path).unwrap().unwrap();
+    let fake_now = ws.unhealthy_since_epoch_s.unwrap() + 60 * 86_400;
+    let max_age_secs = 86_400;
+
+    let mut script = vec![
+        ExpectedCommand::ok(ws_path.to_path_buf(), "jj", &["git", "fetch"], ""),
+        head_status_command(
+            &ws_path,
+            &head_status_output("abcd1234", false, "wip-bookmark", "wip-bookmark", ""),
+        ),
+        unpushed_probe_command(&ws_path, "abcd1234\t6e6b90bc\n"),
+    ];
+    script.extend(salvage_commands_for(
+        &ws_path,
+        "abcd1234\t6e6b90bc\thalf-finished refactor\n",
+        &[("6e6b90bc", "diff --git a/x b/x\n+one\n")],
+    ));
+    // The snapshot's first `jj log` fails; no reset commands may follow it.
+    script.push(ExpectedCommand::failing(
+        ws_path.to_path_buf(),
+        "jj",
+        &["log", "--no-graph", "-r", "@", "-T", SNAPSHOT_HEAD_TEMPLATE],
+        "Error: Unexpected error from backend",
+    ));
+    let runner = FakeRunner::new(script);
+
+    let recycled = gc_aged_unhealthy_workspaces(&runner, &store, Some(&database_path), fake_now, max_age_secs, None);
+    runner.assert_exhausted();
+    assert_eq!(recycled, 0, "a workspace whose tree could not be captured is not reset");
+
+    let ws_after = store.get_workspace_by_path(&ws_path).unwrap().unwrap();
+    assert_eq!(
+        ws_after.health_status,
+        Some(crate::metadata::WorkspaceHealth::Dirty),
+        "the workspace stays retained and is retried next pass",
+    );
+    assert!(
+        list_salvage_records(Some(&database_path), None, None)
+            .expect("salvage records")
+            .is_empty(),
+        "the salvage taken for this reclaim is discarded with it",
+    );
+
+    let events = audit_events(&tempdir);
+    let failed: Vec<_> = events
+        .iter()
+        .filter(|e| e["event"] == "workspace.retention_snapshot_failed")
+        .collect();
+    assert_eq!(failed.len(), 1);
+}
+
 /// The salvage is taken *for* a reclaim. If the reclaim then fails the live
 /// workspace is still there, still retained, and still the source of truth —
 /// so the copy is discarded rather than left to be duplicated by every
@@ -1414,6 +1486,7 @@ fn gc_discards_the_salvage_record_when_the_reset_fails() {
         "abcd1234\t6e6b90bc\thalf-finished refactor\n",
         &[("6e6b90bc", "diff --git a/x b/x\n+one\n")],
     ));
+    script.extend(snapshot_commands(&ws_path));
     // The reset's first step fails, so the reclaim never completes.
     script.push(ExpectedCommand::ok(
         ws_path.to_path_buf(),
@@ -1450,6 +1523,12 @@ fn gc_discards_the_salvage_record_when_the_reset_fails() {
             .is_empty(),
         "the orphaned copy is discarded rather than duplicated next pass",
     );
+    assert!(
+        list_snapshot_records(Some(&database_path), None, None)
+            .expect("snapshot records")
+            .is_empty(),
+        "so is the snapshot taken alongside it",
+    );
 
     let events = audit_events(&tempdir);
     let discarded: Vec<_> = events
@@ -1509,6 +1588,7 @@ fn salvage_record_attributes_the_holder_and_task_from_the_prior_lease() {
         "abcd1234\t6e6b90bc\thalf-finished refactor\n",
         &[("6e6b90bc", "diff --git a/x b/x\n+one\n")],
     ));
+    script.extend(snapshot_commands(&ws_path));
     script.extend(reset_after_fetch_commands_for(&ws_path));
     let runner = FakeRunner::new(script);
 
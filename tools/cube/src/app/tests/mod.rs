@@ -4,6 +4,7 @@ mod bazel_output_base_tests;
 mod change_tests;
 mod checkleft_tests;
 mod dispatch_tests;
+mod events_stats_tests;
 mod excludes_tests;
 mod gc_tests;
 mod gh_pr_tests;
@@ -14,6 +15,7 @@ mod lease_tests;
 mod list_status_tests;
 mod pr_push_tests;
 mod pr_tests;
+mod provision_tests;
 mod push_tests;
 mod quarantine_tests;
 mod rebase_tests;
@@ -24,4 +26,6 @@ mod release_tests;
 mod remove_tests;
 mod repo_tests;
 mod setup_tests;
+mod snapshot_tests;
 mod support;
+mod warm_tests;
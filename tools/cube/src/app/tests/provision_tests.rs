@@ -0,0 +1,154 @@
+//! Tests for the per-repo provisioning modes in `auto_create_workspace`.
+
+use std::path::Path;
+
+use super::support::{ExpectedCommand, FakeRunner, audit_events, mono_source_path, seed_mono_repo, with_database_path};
+
+use crate::config::ProvisionMode;
+use crate::store::Store;
+
+use crate::app::errors::CubeError;
+use crate::app::provision::auto_create_workspace_with_mode;
+
+fn clone_command(workspace_root: &Path, staging: &Path) -> ExpectedCommand {
+    ExpectedCommand::ok(
+        workspace_root.to_path_buf(),
+        "jj",
+        &[
+            "git",
+            "clone",
+            "--colocate",
+            "git@github.com:spinyfin/mono.git",
+            &staging.display().to_string(),
+        ],
+        "",
+    )
+    .creating_dir(staging.to_path_buf())
+}
+
+fn reflink_args(source: &Path, staging: &Path) -> Vec<String> {
+    let source = source.display().to_string();
+    let staging = staging.display().to_string();
+    if cfg!(target_os = "macos") {
+        vec!["-c".to_string(), "-R".to_string(), source, staging]
+    } else {
+        vec!["-R".to_string(), "--reflink=always".to_string(), source, staging]
+    }
+}
+
+fn created_event(tempdir: &tempfile::TempDir) -> serde_json::Value {
+    audit_events(tempdir)
+        .into_iter()
+        .find(|e| e["event"] == "workspace.created")
+        .expect("workspace.created event")
+}
+
+#[test]
+fn clone_mode_provisions_an_independent_clone_from_origin() {
+    let (tempdir, database_path) = with_database_path();
+    let workspace_root = tempdir.path().join("workspaces");
+    seed_mono_repo(&workspace_root, &database_path);
+    let repo_record = Store::open_at(&database_path)
+        .unwrap()
+        .get_repo("mono")
+        .unwrap()
+        .unwrap();
+
+    let staging = workspace_root.join(".incoming-mono-agent-001");
+    let runner = FakeRunner::new(vec![clone_command(&workspace_root, &staging)]);
+    let candidate =
+        auto_create_workspace_with_mode(&runner, Some(&database_path), &repo_record, &[], ProvisionMode::Clone)
+            .expect("provision");
+    runner.assert_exhausted();
+
+    assert_eq!(candidate.workspace_id, "mono-agent-001");
+    assert!(candidate.workspace_path.is_dir());
+    assert_eq!(created_event(&tempdir)["provision_mode"], "clone");
+}
+
+#[test]
+fn reflink_mode_block_clones_the_canonical_repo() {
+    let (tempdir, database_path) = with_database_path();
+    let workspace_root = tempdir.path().join("workspaces");
+    seed_mono_repo(&workspace_root, &database_path);
+    let repo_record = Store::open_at(&database_path)
+        .unwrap()
+        .get_repo("mono")
+        .unwrap()
+        .unwrap();
+
+    let staging = workspace_root.join(".incoming-mono-agent-001");
+    let args = reflink_args(&mono_source_path(&workspace_root), &staging);
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let runner = FakeRunner::new(vec![
+        ExpectedCommand::ok(workspace_root.clone(), "cp", &args, "").creating_dir(staging.clone()),
+    ]);
+    auto_create_workspace_with_mode(&runner, Some(&database_path), &repo_record, &[], ProvisionMode::Reflink)
+        .expect("provision");
+    runner.assert_exhausted();
+
+    assert_eq!(created_event(&tempdir)["provision_mode"], "reflink");
+}
+
+#[test]
+fn reflink_mode_falls_back_to_a_clone_when_the_filesystem_cannot_reflink() {
+    let (tempdir, database_path) = with_database_path();
+    let workspace_root = tempdir.path().join("workspaces");
+    seed_mono_repo(&workspace_root, &database_path);
+    let repo_record = Store::open_at(&database_path)
+        .unwrap()
+        .get_repo("mono")
+        .unwrap()
+        .unwrap();
+
+    let staging = workspace_root.join(".incoming-mono-agent-001");
+    let args = reflink_args(&mono_source_path(&workspace_root), &staging);
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let runner = FakeRunner::new(vec![
+        ExpectedCommand::failing(
+            workspace_root.clone(),
+            "cp",
+            &args,
+            "cp: failed to clone: Operation not supported",
+        ),
+        clone_command(&workspace_root, &staging),
+    ]);
+    let candidate =
+        auto_create_workspace_with_mode(&runner, Some(&database_path), &repo_record, &[], ProvisionMode::Reflink)
+            .expect("provision");
+    runner.assert_exhausted();
+
+    assert!(candidate.workspace_path.is_dir());
+    let events = audit_events(&tempdir);
+    let fallback = events
+        .iter()
+        .find(|e| e["event"] == "workspace.provision_fallback")
+        .expect("fallback event");
+    assert_eq!(fallback["requested"], "reflink");
+    assert_eq!(created_event(&tempdir)["provision_mode"], "clone");
+}
+
+#[test]
+fn jj_workspace_mode_still_refuses_to_clone_without_a_canonical_store() {
+    let (tempdir, database_path) = with_database_path();
+    let workspace_root = tempdir.path().join("workspaces");
+    seed_mono_repo(&workspace_root, &database_path);
+    std::fs::remove_dir_all(mono_source_path(&workspace_root).join(".jj")).unwrap();
+    let repo_record = Store::open_at(&database_path)
+        .unwrap()
+        .get_repo("mono")
+        .unwrap()
+        .unwrap();
+
+    let runner = FakeRunner::default();
+    let error = auto_create_workspace_with_mode(
+        &runner,
+        Some(&database_path),
+        &repo_record,
+        &[],
+        ProvisionMode::JjWorkspace,
+    )
+    .expect_err("no canonical store");
+    runner.assert_exhausted();
+    assert!(matches!(error, CubeError::InvalidArgument(msg) if msg.contains("cube repo ensure")));
+}
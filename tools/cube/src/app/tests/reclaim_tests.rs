@@ -16,8 +16,8 @@ use crate::app::errors::CubeError;
 use crate::app::gc::{POOL_GC_LAST_AT_KEY, POOL_GC_STARTED_AT_KEY, POOL_GC_TRIM_PROGRESS_KEY, maybe_trigger_pool_gc};
 use crate::app::provision::discover_workspaces;
 use crate::app::reclaim::{
-    assert_mint_headroom, compact_free_workspaces, has_free_workspace_surplus, is_safe_artifact_dir_name,
-    relieve_disk_pressure, stage_workspace_for_removal, trim_free_workspaces_to_mark,
+    assert_mint_headroom, compact_free_workspaces, has_free_workspace_surplus, has_independent_store,
+    is_safe_artifact_dir_name, relieve_disk_pressure, stage_workspace_for_removal, trim_free_workspaces_to_mark,
 };
 use crate::metadata::{RepoRecord, WorkspaceCandidate};
 use crate::store::Store;
@@ -564,6 +564,32 @@ fn a_staged_workspace_cannot_be_rediscovered_and_re_registered() {
     );
 }
 
+#[test]
+fn staging_an_independent_store_does_not_forget_a_shared_registration() {
+    // A reflink copy or full clone was never `jj workspace add`-ed to the
+    // canonical store, so there is no registration to detach; issuing the
+    // forget anyway would only log a spurious warning on every removal.
+    let _config = ConfigGuard::new("[pool]\n");
+    let (tempdir, database_path) = with_database_path();
+    let source = tempdir.path().join("source/mono");
+    std::fs::create_dir_all(source.join(".jj")).expect("canonical source");
+    let (store, _workspace_root) = seed_pool(&tempdir, &database_path, 1, Some(source));
+    let repo_record = store.get_repo("mono").expect("get repo").expect("repo row");
+    let record = store
+        .list_workspaces("mono")
+        .expect("list")
+        .pop()
+        .expect("one workspace");
+    std::fs::create_dir_all(record.workspace_path.join(".jj/repo/store")).expect("own store");
+    assert!(has_independent_store(&record.workspace_path));
+
+    let runner = FakeRunner::new(vec![]);
+    store.forget_workspace("mono", "mono-agent-001").expect("forget row");
+    let staged = stage_workspace_for_removal(&runner, &repo_record, &record, None).expect("stage");
+    runner.assert_exhausted();
+    assert!(staged.is_dir());
+}
+
 #[test]
 fn staging_clears_a_leftover_from_a_pass_that_died_mid_removal() {
     // A crash between the rename and the unlink leaves a dotted directory. It
@@ -622,8 +622,9 @@ fn workspace_release_still_resets_when_working_copy_is_reusable() {
     );
 }
 
-/// `--force-reset` is the deliberate opt-out: the guard probe is skipped
-/// entirely and the destructive reset runs regardless of the tree.
+/// `--force-reset` is the deliberate opt-out: the destructive reset runs
+/// regardless of the tree. The guard probe still runs, but only to decide
+/// whether a snapshot is needed first; on a reusable tree it is not.
 #[test]
 fn workspace_release_force_reset_overrides_the_preservation_guard() {
     let (tempdir, database_path) = with_database_path();
@@ -633,10 +634,9 @@ fn workspace_release_force_reset_overrides_the_preservation_guard() {
     let workspace_path = workspace_root.join("mono-agent-001");
     let lease_id = lease_agent_001_for_release_test(&workspace_path, &database_path);
 
-    // No head-status probe at all: --force-reset skips it, so the
-    // expectations are the plain fetch+reset sequence.
     let release_runner = FakeRunner::new(vec![
         ExpectedCommand::ok(workspace_path.clone(), "jj", &["git", "fetch"], ""),
+        release_guard_reusable_command(&workspace_path),
         ExpectedCommand::ok(
             workspace_path.clone(),
             "jj",
@@ -661,6 +661,7 @@ fn workspace_release_force_reset_overrides_the_preservation_guard() {
     .expect("release");
     release_runner.assert_exhausted();
     assert_eq!(result.payload["preserved_unpushed_work"], false);
+    assert!(result.payload["snapshot"].is_null());
     assert!(result.message.starts_with("Released mono-agent-001."));
 }
 
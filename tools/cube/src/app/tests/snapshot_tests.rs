@@ -0,0 +1,352 @@
+//! Tests for `cube workspace snapshot` / `restore` and the snapshot taken
+//! before a forced release reset.
+
+use std::path::{Path, PathBuf};
+
+use super::support::{
+    ExpectedCommand, FakeRunner, audit_events, gc_noop_command, gc_pr_noop_command, head_status_command,
+    head_status_output, lease_commands_for, lease_runner_for, seed_mono_repo, unpushed_probe_command,
+    with_database_path,
+};
+use clap::Parser;
+
+use crate::cli::Cli;
+use crate::metadata::WorkspaceHealth;
+use crate::store::Store;
+
+use crate::app::dispatch::run_with_dependencies;
+use crate::app::errors::CubeError;
+use crate::app::snapshot::{SNAPSHOT_BASE_REVSET, SNAPSHOT_BASE_TEMPLATE, SNAPSHOT_HEAD_TEMPLATE};
+
+const DIFF: &str = "diff --git a/src/lib.rs b/src/lib.rs\n--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -1 +1 @@\n-old\n+new";
+const DESCRIPTION: &str = "Add the feature\n\nWith a body.";
+
+fn lease_agent(workspace_path: &Path, database_path: &Path) -> String {
+    let runner = lease_runner_for(workspace_path, "abc1234");
+    let result = run_with_dependencies(
+        Cli::parse_from(["cube", "workspace", "lease", "mono", "--task", "demo"]),
+        Some(database_path),
+        &runner,
+    )
+    .expect("lease");
+    runner.assert_exhausted();
+    result.payload["workspace"]["lease_id"]
+        .as_str()
+        .expect("lease id")
+        .to_string()
+}
+
+/// A tracked file, a `.gitignore`d build output, and one file jj left
+/// untracked — the only one a snapshot has to copy.
+fn populate_working_copy(workspace_path: &Path) {
+    std::fs::write(workspace_path.join(".gitignore"), "target/\n").unwrap();
+    std::fs::create_dir_all(workspace_path.join("src")).unwrap();
+    std::fs::write(workspace_path.join("src/lib.rs"), "new\n").unwrap();
+    std::fs::create_dir_all(workspace_path.join("target")).unwrap();
+    std::fs::write(workspace_path.join("target/out.o"), "junk").unwrap();
+    std::fs::write(workspace_path.join("model.bin"), "weights").unwrap();
+}
+
+/// The four `jj` calls a snapshot makes.
+pub(super) fn snapshot_commands(workspace_path: &Path) -> Vec<ExpectedCommand> {
+    let head = format!("wip1\tc0ffee00\t{DESCRIPTION}");
+    vec![
+        ExpectedCommand::ok(
+            workspace_path.to_path_buf(),
+            "jj",
+            &["log", "--no-graph", "-r", "@", "-T", SNAPSHOT_HEAD_TEMPLATE],
+            &head,
+        ),
+        ExpectedCommand::ok(
+            workspace_path.to_path_buf(),
+            "jj",
+            &[
+                "log",
+                "--no-graph",
+                "-r",
+                SNAPSHOT_BASE_REVSET,
+                "-T",
+                SNAPSHOT_BASE_TEMPLATE,
+            ],
+            "base1234\n",
+        ),
+        ExpectedCommand::ok(
+            workspace_path.to_path_buf(),
+            "jj",
+            &["diff", "--no-pager", "--git", "--from", "base1234", "--to", "@"],
+            DIFF,
+        ),
+        ExpectedCommand::ok(
+            workspace_path.to_path_buf(),
+            "jj",
+            &["file", "list", "-r", "@"],
+            ".gitignore\nsrc/lib.rs\n",
+        ),
+    ]
+}
+
+fn restore_commands(workspace_path: &Path, snapshot_path: &Path) -> Vec<ExpectedCommand> {
+    let diff_path = snapshot_path.join("working-copy.diff").display().to_string();
+    vec![
+        ExpectedCommand::ok(workspace_path.to_path_buf(), "jj", &["new", "base1234"], ""),
+        ExpectedCommand::ok(
+            workspace_path.to_path_buf(),
+            "git",
+            &["apply", "--whitespace=nowarn", &diff_path],
+            "",
+        ),
+        ExpectedCommand::ok(workspace_path.to_path_buf(), "jj", &["describe", "-m", DESCRIPTION], ""),
+    ]
+}
+
+fn take_snapshot(workspace_path: &Path, database_path: &Path, lease_id: &str) -> (String, PathBuf) {
+    let runner = FakeRunner::new(snapshot_commands(workspace_path));
+    let result = run_with_dependencies(
+        Cli::parse_from(["cube", "workspace", "snapshot", lease_id]),
+        Some(database_path),
+        &runner,
+    )
+    .expect("snapshot");
+    runner.assert_exhausted();
+    let snapshot = &result.payload["snapshot"];
+    (
+        snapshot["manifest"]["snapshot_id"].as_str().unwrap().to_string(),
+        PathBuf::from(snapshot["path"].as_str().unwrap()),
+    )
+}
+
+#[test]
+fn snapshot_captures_the_diff_and_untracked_files_but_not_ignored_ones() {
+    let (tempdir, database_path) = with_database_path();
+    let workspace_root = tempdir.path().join("workspaces");
+    let workspace_path = workspace_root.join("mono-agent-001");
+    std::fs::create_dir_all(workspace_path.join(".jj")).unwrap();
+    seed_mono_repo(&workspace_root, &database_path);
+    let lease_id = lease_agent(&workspace_path, &database_path);
+    populate_working_copy(&workspace_path);
+
+    let (snapshot_id, snapshot_path) = take_snapshot(&workspace_path, &database_path, &lease_id);
+
+    assert!(snapshot_id.starts_with("mono-agent-001-"));
+    assert_eq!(snapshot_path, tempdir.path().join("snapshots/mono").join(&snapshot_id));
+    let manifest: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(snapshot_path.join("manifest.json")).unwrap()).unwrap();
+    assert_eq!(manifest["lease_id"], lease_id.as_str());
+    assert_eq!(manifest["task"], "demo");
+    assert_eq!(manifest["reason"], "manual");
+    assert_eq!(manifest["base_commit"], "base1234");
+    assert_eq!(manifest["description"], DESCRIPTION);
+    assert_eq!(manifest["untracked"], serde_json::json!(["model.bin"]));
+    assert_eq!(
+        std::fs::read_to_string(snapshot_path.join("working-copy.diff")).unwrap(),
+        format!("{DIFF}\n")
+    );
+    assert_eq!(
+        std::fs::read_to_string(snapshot_path.join("untracked/model.bin")).unwrap(),
+        "weights"
+    );
+    assert!(!snapshot_path.join("untracked/target").exists());
+
+    let events = audit_events(&tempdir);
+    let taken = events
+        .iter()
+        .find(|e| e["event"] == "workspace.snapshot_taken")
+        .expect("snapshot audit event");
+    assert_eq!(taken["snapshot_id"], snapshot_id.as_str());
+
+    let listing = run_with_dependencies(
+        Cli::parse_from(["cube", "workspace", "snapshots", "--repo", "mono"]),
+        Some(&database_path),
+        &FakeRunner::default(),
+    )
+    .expect("list");
+    assert_eq!(listing.payload["snapshots"].as_array().unwrap().len(), 1);
+}
+
+#[test]
+fn restore_into_a_lease_replays_the_diff_untracked_files_and_description() {
+    let (tempdir, database_path) = with_database_path();
+    let workspace_root = tempdir.path().join("workspaces");
+    let workspace_path = workspace_root.join("mono-agent-001");
+    std::fs::create_dir_all(workspace_path.join(".jj")).unwrap();
+    seed_mono_repo(&workspace_root, &database_path);
+    let lease_id = lease_agent(&workspace_path, &database_path);
+    populate_working_copy(&workspace_path);
+    let (snapshot_id, snapshot_path) = take_snapshot(&workspace_path, &database_path, &lease_id);
+    std::fs::remove_file(workspace_path.join("model.bin")).unwrap();
+
+    let runner = FakeRunner::new(restore_commands(&workspace_path, &snapshot_path));
+    let result = run_with_dependencies(
+        Cli::parse_from(["cube", "workspace", "restore", &snapshot_id, "--into", &lease_id]),
+        Some(&database_path),
+        &runner,
+    )
+    .expect("restore");
+    runner.assert_exhausted();
+
+    assert_eq!(result.payload["leased_for_restore"], false);
+    assert_eq!(
+        result.payload["restore"]["untracked_restored"],
+        serde_json::json!(["model.bin"])
+    );
+    assert_eq!(
+        std::fs::read_to_string(workspace_path.join("model.bin")).unwrap(),
+        "weights"
+    );
+}
+
+#[test]
+fn restore_without_into_leases_a_free_workspace_for_the_snapshot() {
+    let (tempdir, database_path) = with_database_path();
+    let workspace_root = tempdir.path().join("workspaces");
+    let source_path = workspace_root.join("mono-agent-001");
+    let target_path = workspace_root.join("mono-agent-002");
+    std::fs::create_dir_all(source_path.join(".jj")).unwrap();
+    std::fs::create_dir_all(target_path.join(".jj")).unwrap();
+    seed_mono_repo(&workspace_root, &database_path);
+    let lease_id = lease_agent(&source_path, &database_path);
+    populate_working_copy(&source_path);
+    let (snapshot_id, snapshot_path) = take_snapshot(&source_path, &database_path, &lease_id);
+
+    let mut expected = lease_commands_for(&target_path, "def5678");
+    expected.extend(restore_commands(&target_path, &snapshot_path));
+    let runner = FakeRunner::new(expected);
+    let result = run_with_dependencies(
+        Cli::parse_from(["cube", "workspace", "restore", &snapshot_id]),
+        Some(&database_path),
+        &runner,
+    )
+    .expect("restore");
+    runner.assert_exhausted();
+
+    assert_eq!(result.payload["leased_for_restore"], true);
+    assert_eq!(result.payload["workspace"]["workspace_id"], "mono-agent-002");
+    assert_eq!(
+        result.payload["workspace"]["task"],
+        format!("restore snapshot {snapshot_id}").as_str()
+    );
+    assert!(target_path.join("model.bin").is_file());
+}
+
+#[test]
+fn restore_rejects_an_unknown_snapshot() {
+    let (_tempdir, database_path) = with_database_path();
+    let error = run_with_dependencies(
+        Cli::parse_from(["cube", "workspace", "restore", "mono-agent-009-1"]),
+        Some(&database_path),
+        &FakeRunner::default(),
+    )
+    .expect_err("unknown snapshot");
+    assert!(matches!(error, CubeError::SnapshotNotFound(id) if id == "mono-agent-009-1"));
+}
+
+/// The dirty-tree guard probes that make `--force-reset` take a snapshot.
+fn dirty_guard_commands(workspace_path: &Path) -> Vec<ExpectedCommand> {
+    vec![
+        ExpectedCommand::ok(workspace_path.to_path_buf(), "jj", &["git", "fetch"], ""),
+        head_status_command(workspace_path, &head_status_output("wip1", false, "", "", "")),
+        unpushed_probe_command(workspace_path, "wip1\tc0ffee00\n"),
+    ]
+}
+
+#[test]
+fn force_reset_snapshots_unpushed_work_before_discarding_it() {
+    let (tempdir, database_path) = with_database_path();
+    let workspace_root = tempdir.path().join("workspaces");
+    let workspace_path = workspace_root.join("mono-agent-001");
+    std::fs::create_dir_all(workspace_path.join(".jj")).unwrap();
+    seed_mono_repo(&workspace_root, &database_path);
+    let lease_id = lease_agent(&workspace_path, &database_path);
+    populate_working_copy(&workspace_path);
+
+    let mut expected = dirty_guard_commands(&workspace_path);
+    expected.extend(snapshot_commands(&workspace_path));
+    expected.extend([
+        ExpectedCommand::ok(
+            workspace_path.clone(),
+            "jj",
+            &["git", "remote", "list"],
+            "origin\tgit@github.com:spinyfin/mono.git\n",
+        ),
+        ExpectedCommand::ok(
+            workspace_path.clone(),
+            "jj",
+            &["bookmark", "set", "main", "-r", "main@origin", "--allow-backwards"],
+            "",
+        ),
+        ExpectedCommand::ok(workspace_path.clone(), "jj", &["new", "main@origin"], ""),
+        gc_noop_command(&workspace_path),
+        gc_pr_noop_command(&workspace_path),
+    ]);
+    let runner = FakeRunner::new(expected);
+    let result = run_with_dependencies(
+        Cli::parse_from(["cube", "workspace", "release", "--lease", &lease_id, "--force-reset"]),
+        Some(&database_path),
+        &runner,
+    )
+    .expect("release");
+    runner.assert_exhausted();
+
+    let snapshot = &result.payload["snapshot"]["manifest"];
+    assert_eq!(snapshot["reason"], "pre_force_reset");
+    assert_eq!(snapshot["lease_id"], lease_id.as_str());
+    assert!(result.message.contains("snapshotted as"));
+    let released = audit_events(&tempdir)
+        .into_iter()
+        .find(|e| e["event"] == "lease.released")
+        .expect("release event");
+    assert_eq!(released["snapshot_id"], snapshot["snapshot_id"]);
+}
+
+#[test]
+fn force_reset_does_not_reset_when_the_snapshot_fails() {
+    let (tempdir, database_path) = with_database_path();
+    let workspace_root = tempdir.path().join("workspaces");
+    let workspace_path = workspace_root.join("mono-agent-001");
+    std::fs::create_dir_all(workspace_path.join(".jj")).unwrap();
+    seed_mono_repo(&workspace_root, &database_path);
+    let lease_id = lease_agent(&workspace_path, &database_path);
+
+    // `@` has no ancestor on any remote, so there is no base to snapshot
+    // against — and therefore no licence to reset.
+    let mut expected = dirty_guard_commands(&workspace_path);
+    expected.extend([
+        ExpectedCommand::ok(
+            workspace_path.clone(),
+            "jj",
+            &["log", "--no-graph", "-r", "@", "-T", SNAPSHOT_HEAD_TEMPLATE],
+            "wip1\tc0ffee00\twip",
+        ),
+        ExpectedCommand::ok(
+            workspace_path.clone(),
+            "jj",
+            &[
+                "log",
+                "--no-graph",
+                "-r",
+                SNAPSHOT_BASE_REVSET,
+                "-T",
+                SNAPSHOT_BASE_TEMPLATE,
+            ],
+            "",
+        ),
+    ]);
+    let runner = FakeRunner::new(expected);
+    let result = run_with_dependencies(
+        Cli::parse_from(["cube", "workspace", "release", "--lease", &lease_id, "--force-reset"]),
+        Some(&database_path),
+        &runner,
+    )
+    .expect("release");
+    runner.assert_exhausted();
+
+    assert_eq!(result.payload["reset_failed"], true);
+    assert!(result.payload["snapshot"].is_null());
+    let record = Store::open_at(&database_path)
+        .unwrap()
+        .get_workspace_by_path(&workspace_path)
+        .unwrap()
+        .unwrap();
+    assert_eq!(record.health_status, Some(WorkspaceHealth::Dirty));
+    assert!(!tempdir.path().join("snapshots/mono").exists());
+}
@@ -46,7 +46,13 @@ pub(super) fn write_setup_yaml(workspace_path: &std::path::Path, body: &str) {
 }
 
 pub(super) fn lease_runner_for(workspace_path: &std::path::Path, head: &str) -> FakeRunner {
-    FakeRunner::new(vec![
+    FakeRunner::new(lease_commands_for(workspace_path, head))
+}
+
+/// The expectations behind [`lease_runner_for`], for tests that chain a
+/// lease into a longer command sequence.
+pub(super) fn lease_commands_for(workspace_path: &std::path::Path, head: &str) -> Vec<ExpectedCommand> {
+    vec![
         ExpectedCommand::ok(
             workspace_path.to_path_buf(),
             "jj",
@@ -73,7 +79,7 @@ pub(super) fn lease_runner_for(workspace_path: &std::path::Path, head: &str) ->
             &["log", "--no-graph", "-r", "@", "-T", "commit_id.short()"],
             head,
         ),
-    ])
+    ]
 }
 
 /// The dirty-reclaim guard's first probe: what `@` is and what bookmarks
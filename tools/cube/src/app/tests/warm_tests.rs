@@ -0,0 +1,208 @@
+use std::path::Path;
+
+use super::support::{
+    ExpectedCommand, FakeRunner, jj_status_clean, jj_status_dirty, lease_runner_for, seed_mono_repo, with_database_path,
+};
+use clap::Parser;
+
+use crate::cli::Cli;
+use crate::config::{PoolConfig, RepoPoolConfig};
+use crate::metadata::{WorkspaceHealth, WorkspaceState};
+use crate::store::Store;
+
+use crate::app::dispatch::run_with_dependencies;
+use crate::app::errors::CubeError;
+use crate::app::warm::{WARM_RELEASE_REASON, warm_pools};
+
+const NOW: i64 = 1_800_000_000;
+
+fn pool_with_target(target: usize) -> PoolConfig {
+    PoolConfig::builder()
+        .repos(
+            [(
+                "mono".to_string(),
+                RepoPoolConfig {
+                    min_free_workspaces: Some(target),
+                    ..Default::default()
+                },
+            )]
+            .into(),
+        )
+        .build()
+}
+
+/// The reset a lease would run, which warming front-loads.
+fn reset_commands(workspace_path: &Path) -> Vec<ExpectedCommand> {
+    vec![
+        ExpectedCommand::ok(workspace_path.to_path_buf(), "jj", &["git", "fetch"], ""),
+        ExpectedCommand::ok(
+            workspace_path.to_path_buf(),
+            "jj",
+            &["git", "remote", "list"],
+            "origin\tgit@github.com:spinyfin/mono.git\n",
+        ),
+        ExpectedCommand::ok(
+            workspace_path.to_path_buf(),
+            "jj",
+            &["bookmark", "set", "main", "-r", "main@origin", "--allow-backwards"],
+            "",
+        ),
+        ExpectedCommand::ok(workspace_path.to_path_buf(), "jj", &["new", "main@origin"], ""),
+    ]
+}
+
+#[test]
+fn warm_provisions_up_to_the_target_and_the_next_lease_reuses_it() {
+    let (tempdir, database_path) = with_database_path();
+    let workspace_root = tempdir.path().join("workspaces");
+    seed_mono_repo(&workspace_root, &database_path);
+
+    let new_path = workspace_root.join("mono-agent-001");
+    let staging = workspace_root.join(".incoming-mono-agent-001");
+    let mut expected = vec![ExpectedCommand::workspace_add_mono(&workspace_root, &staging)];
+    expected.extend(reset_commands(&new_path));
+    let runner = FakeRunner::new(expected);
+
+    let mut store = Store::open_at(&database_path).expect("store");
+    let reports = warm_pools(
+        &runner,
+        &mut store,
+        Some(&database_path),
+        &pool_with_target(1),
+        None,
+        NOW,
+        false,
+    )
+    .expect("warm");
+    runner.assert_exhausted();
+
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].ready_before, 0);
+    assert_eq!(reports[0].provisioned, vec!["mono-agent-001".to_string()]);
+    assert_eq!(reports[0].ready_after, 1);
+    assert!(reports[0].failed.is_empty());
+
+    let warmed = store.get_workspace_by_path(&new_path).expect("lookup").expect("row");
+    assert_eq!(warmed.state, WorkspaceState::Free);
+    assert_eq!(warmed.last_release_reason.as_deref(), Some(WARM_RELEASE_REASON));
+
+    // The lease claims the warmed workspace instead of minting another. The
+    // fake `jj workspace add` only creates the directory; give it the `.jj/`
+    // the real one would so the lease health scan sees a usable checkout.
+    std::fs::create_dir_all(new_path.join(".jj")).unwrap();
+    let lease_runner = lease_runner_for(&new_path, "abc1234");
+    let result = run_with_dependencies(
+        Cli::parse_from(["cube", "workspace", "lease", "mono", "--task", "demo"]),
+        Some(&database_path),
+        &lease_runner,
+    )
+    .expect("lease");
+    lease_runner.assert_exhausted();
+    assert_eq!(result.payload["workspace"]["workspace_id"], "mono-agent-001");
+}
+
+#[test]
+fn warm_refreshes_idle_free_workspaces_and_skips_dirty_ones() {
+    let (tempdir, database_path) = with_database_path();
+    let workspace_root = tempdir.path().join("workspaces");
+    let clean_path = workspace_root.join("mono-agent-001");
+    let dirty_path = workspace_root.join("mono-agent-002");
+    std::fs::create_dir_all(clean_path.join(".jj")).unwrap();
+    std::fs::create_dir_all(dirty_path.join(".jj")).unwrap();
+    seed_mono_repo(&workspace_root, &database_path);
+
+    // Both were discovered from disk and never leased, so both are past the
+    // refresh window. Only the clean one may be reset.
+    let mut expected = vec![
+        ExpectedCommand::ok(clean_path.clone(), "jj", &["status", "--no-pager"], jj_status_clean()),
+        ExpectedCommand::ok(dirty_path.clone(), "jj", &["status", "--no-pager"], jj_status_dirty()),
+    ];
+    expected.extend(reset_commands(&clean_path));
+    let runner = FakeRunner::new(expected);
+
+    let mut store = Store::open_at(&database_path).expect("store");
+    let reports = warm_pools(
+        &runner,
+        &mut store,
+        Some(&database_path),
+        &pool_with_target(2),
+        Some("mono"),
+        NOW,
+        false,
+    )
+    .expect("warm");
+    runner.assert_exhausted();
+
+    assert_eq!(reports[0].ready_before, 2);
+    assert!(reports[0].provisioned.is_empty());
+    assert_eq!(reports[0].refreshed, vec!["mono-agent-001".to_string()]);
+    // The dirty workspace is now cached as such and no longer counts.
+    assert_eq!(reports[0].ready_after, 1);
+    let dirty = store.get_workspace_by_path(&dirty_path).expect("lookup").expect("row");
+    assert_eq!(dirty.health_status, Some(WorkspaceHealth::Dirty));
+}
+
+#[test]
+fn warm_dry_run_reports_without_touching_the_pool() {
+    let (tempdir, database_path) = with_database_path();
+    let workspace_root = tempdir.path().join("workspaces");
+    seed_mono_repo(&workspace_root, &database_path);
+
+    let runner = FakeRunner::default();
+    let mut store = Store::open_at(&database_path).expect("store");
+    let reports = warm_pools(
+        &runner,
+        &mut store,
+        Some(&database_path),
+        &pool_with_target(3),
+        None,
+        NOW,
+        true,
+    )
+    .expect("warm");
+    runner.assert_exhausted();
+
+    assert_eq!(reports[0].would_provision, Some(3));
+    assert!(reports[0].provisioned.is_empty());
+    assert!(!workspace_root.join("mono-agent-001").exists());
+}
+
+#[test]
+fn warm_skips_repos_without_a_target() {
+    let (tempdir, database_path) = with_database_path();
+    let workspace_root = tempdir.path().join("workspaces");
+    seed_mono_repo(&workspace_root, &database_path);
+
+    let runner = FakeRunner::default();
+    let mut store = Store::open_at(&database_path).expect("store");
+    let reports = warm_pools(
+        &runner,
+        &mut store,
+        Some(&database_path),
+        &PoolConfig::default(),
+        None,
+        NOW,
+        false,
+    )
+    .expect("warm");
+    runner.assert_exhausted();
+    assert!(reports.is_empty());
+}
+
+#[test]
+fn warm_rejects_an_unknown_repo() {
+    let (_tempdir, database_path) = with_database_path();
+    let runner = FakeRunner::default();
+    let mut store = Store::open_at(&database_path).expect("store");
+    let error = warm_pools(
+        &runner,
+        &mut store,
+        Some(&database_path),
+        &pool_with_target(1),
+        Some("nope"),
+        NOW,
+        false,
+    )
+    .expect_err("unknown repo");
+    assert!(matches!(error, CubeError::RepoNotFound(repo) if repo == "nope"));
+}
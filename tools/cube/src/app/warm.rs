@@ -0,0 +1,424 @@
+//! `cube pool warm` and `cube daemon` — keeping a reserve of ready free
+//! workspaces so a lease is a claim rather than a clone.
+//!
+//! `auto_create_workspace` only provisions on demand, under the per-repo lock,
+//! so a burst of leases against an empty pool serializes on `jj workspace add`
+//! and setup. Warming moves that cost off the lease path: a pass tops each
+//! repo up to its [`PoolConfig::min_free_workspaces`] target with fetched,
+//! setup-complete workspaces, and refreshes free workspaces that have idled
+//! past [`PoolConfig::warm_refresh_secs`] so the lease-time reset has little
+//! left to fetch.
+//!
+//! A workspace being warmed is held under an ordinary lease (holder
+//! [`WARM_HOLDER`]) for the duration, so no concurrent lease or GC pass can
+//! touch it, and it is released with [`WARM_RELEASE_REASON`] once ready. As
+//! with leasing, the repo lock is only held while choosing and claiming; the
+//! network-bound reset and setup run without it.
+
+use std::path::Path;
+use std::time::Duration;
+
+use serde::Serialize;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::audit;
+use crate::cli::{DaemonArgs, PoolCommand};
+use crate::command_runner::CommandRunner;
+use crate::config::{self, PoolConfig};
+use crate::lock::RepoLock;
+use crate::metadata::{RepoRecord, WorkspaceHealth, WorkspaceRecord};
+use crate::setup::StepStatus;
+use crate::store::{EffectiveState, Store, WorkspaceListFilter};
+
+use crate::app::errors::{CubeError, Result, RunResult};
+use crate::app::excludes::ensure_boss_infra_excluded;
+use crate::app::health::{WorkspaceHealthOutcome, check_workspace_health};
+use crate::app::jj::workspace_path_exists;
+use crate::app::provision::{auto_create_workspace, discover_workspaces, run_setup_for_workspace};
+use crate::app::reconcile::reconcile_missing_workspaces_in_repo;
+use crate::app::reset::reset_workspace_guarded;
+use crate::app::util::{current_epoch_s, repo_lock_path};
+
+/// Holder recorded on the short internal lease a workspace is warmed under.
+pub(super) const WARM_HOLDER: &str = "cube/pool-warm";
+
+/// `last_release_reason` for a workspace that was warmed successfully.
+pub(super) const WARM_RELEASE_REASON: &str = "pool_warm";
+
+/// `last_release_reason` for a workspace whose reset or setup failed while
+/// being warmed. It goes back to the pool regardless: the next lease resets
+/// and re-runs setup exactly as it would have without warming.
+const WARM_FAILED_RELEASE_REASON: &str = "pool_warm_failed";
+
+const WARM_TASK: &str = "pool warm";
+
+/// TTL on the internal warm lease. Warming is one reset plus setup, so an
+/// hour is generous; keeping it short means a pass killed mid-warm hands the
+/// workspace back to TTL expiry quickly instead of holding it for the 24h a
+/// worker lease gets.
+const WARM_LEASE_TTL_SECS: i64 = 60 * 60;
+
+/// What a warm pass did (or, with `--dry-run`, would do) to one repo.
+#[derive(Debug, Clone, Default, Serialize)]
+pub(super) struct RepoWarmReport {
+    pub repo: String,
+    /// The repo's `min-free-workspaces` target.
+    pub target: usize,
+    /// Free workspaces on disk when the pass started.
+    pub ready_before: usize,
+    /// Free workspaces on disk when the pass finished.
+    pub ready_after: usize,
+    /// Workspaces newly provisioned to close the gap to `target`.
+    pub provisioned: Vec<String>,
+    /// Idle free workspaces brought up to the latest main.
+    pub refreshed: Vec<String>,
+    /// Workspaces whose reset or setup failed; they were released anyway.
+    pub failed: Vec<WarmFailure>,
+    /// With `--dry-run`, how many workspaces would have been provisioned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub would_provision: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(super) struct WarmFailure {
+    pub workspace_id: String,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WarmAction {
+    Provision,
+    Refresh,
+}
+
+pub(super) fn run_pool(
+    command: PoolCommand,
+    database_path: Option<&Path>,
+    runner: &dyn CommandRunner,
+) -> Result<RunResult> {
+    match command {
+        PoolCommand::Warm { repo, dry_run } => {
+            let mut store = open_store(database_path)?;
+            let pool = config::load_config().unwrap_or_default().pool;
+            let reports = warm_pools(
+                runner,
+                &mut store,
+                database_path,
+                &pool,
+                repo.as_deref(),
+                current_epoch_s()?,
+                dry_run,
+            )?;
+            RunResult::new(format_warm_message(&reports, dry_run), json!({ "repos": reports }))
+        }
+    }
+}
+
+pub(super) fn run_daemon(
+    args: DaemonArgs,
+    database_path: Option<&Path>,
+    runner: &dyn CommandRunner,
+) -> Result<RunResult> {
+    let mut passes = 0u64;
+    loop {
+        // Re-read config every pass so a target change takes effect without
+        // restarting the daemon. A failed pass is logged and retried on the
+        // next tick; the daemon itself only exits when asked to.
+        let pass = open_store(database_path).and_then(|mut store| {
+            let pool = config::load_config().unwrap_or_default().pool;
+            warm_pools(
+                runner,
+                &mut store,
+                database_path,
+                &pool,
+                None,
+                current_epoch_s()?,
+                false,
+            )
+        });
+        match pass {
+            Ok(reports) => eprintln!("cube daemon: {}", format_warm_message(&reports, false)),
+            Err(e) => eprintln!("cube daemon: warm pass failed: {e}"),
+        }
+        passes += 1;
+        if args.passes.is_some_and(|limit| passes >= limit) {
+            break;
+        }
+        std::thread::sleep(Duration::from_secs(args.interval_secs));
+    }
+    RunResult::new(
+        format!("cube daemon exiting after {passes} warm pass(es)."),
+        json!({ "passes": passes }),
+    )
+}
+
+fn open_store(database_path: Option<&Path>) -> Result<Store> {
+    match database_path {
+        Some(path) => Store::open_at(path),
+        None => Store::open_default(),
+    }
+}
+
+/// Run one warm pass over every registered repo, or just `repo_filter`.
+///
+/// Repos with a zero target are skipped entirely — warming is opt-in, and a
+/// pass must not fetch into pools nobody asked to keep warm.
+pub(super) fn warm_pools(
+    runner: &dyn CommandRunner,
+    store: &mut Store,
+    database_path: Option<&Path>,
+    pool: &PoolConfig,
+    repo_filter: Option<&str>,
+    now_epoch_s: i64,
+    dry_run: bool,
+) -> Result<Vec<RepoWarmReport>> {
+    let repos = match repo_filter {
+        Some(repo) => vec![
+            store
+                .get_repo(repo)?
+                .ok_or_else(|| CubeError::RepoNotFound(repo.to_string()))?,
+        ],
+        None => store.list_repos()?,
+    };
+
+    let mut reports = Vec::new();
+    for repo_record in &repos {
+        if pool.min_free_workspaces(&repo_record.repo) == 0 {
+            continue;
+        }
+        reports.push(warm_repo(
+            runner,
+            store,
+            database_path,
+            pool,
+            repo_record,
+            now_epoch_s,
+            dry_run,
+        )?);
+    }
+    Ok(reports)
+}
+
+fn warm_repo(
+    runner: &dyn CommandRunner,
+    store: &mut Store,
+    database_path: Option<&Path>,
+    pool: &PoolConfig,
+    repo_record: &RepoRecord,
+    now_epoch_s: i64,
+    dry_run: bool,
+) -> Result<RepoWarmReport> {
+    let repo = repo_record.repo.as_str();
+    let target = pool.min_free_workspaces(repo);
+    let refresh_after_secs = pool.warm_refresh_secs();
+
+    let lock = RepoLock::acquire(&repo_lock_path(repo, database_path)?)?;
+    let mut candidates = discover_workspaces(repo_record)?;
+    store.sync_workspaces(repo, &candidates)?;
+    reconcile_missing_workspaces_in_repo(store, database_path, repo, now_epoch_s)?;
+
+    let free = ready_free_workspaces(store, repo)?;
+    let ready_before = free.len();
+    let deficit = target.saturating_sub(ready_before);
+
+    // Refresh the stalest idle workspaces first, and no more of them than the
+    // reserve itself: free workspaces beyond the target are GC's to trim, not
+    // worth a fetch each pass. A row never leased since discovery has no
+    // activity stamp and counts as maximally idle.
+    let mut stale: Vec<&WorkspaceRecord> = free
+        .iter()
+        .filter(|ws| {
+            ws.last_activity_at_epoch_s
+                .is_none_or(|at| now_epoch_s.saturating_sub(at) >= refresh_after_secs)
+        })
+        .collect();
+    stale.sort_by_key(|ws| ws.last_activity_at_epoch_s.unwrap_or(i64::MIN));
+    stale.truncate(target);
+
+    let mut report = RepoWarmReport {
+        repo: repo.to_string(),
+        target,
+        ready_before,
+        ready_after: ready_before,
+        ..Default::default()
+    };
+
+    if dry_run {
+        report.would_provision = Some(deficit);
+        report.refreshed = stale.iter().map(|ws| ws.workspace_id.clone()).collect();
+        return Ok(report);
+    }
+
+    let lease_expires_at = Some(now_epoch_s + WARM_LEASE_TTL_SECS);
+    let mut claimed: Vec<(WarmAction, String, WorkspaceRecord)> = Vec::new();
+
+    // Probe before claiming, exactly as the lease health scan does: a free
+    // workspace whose working copy picked up changes out-of-band must not be
+    // reset out from under whoever made them.
+    for ws in stale {
+        match check_workspace_health(runner, database_path, &ws.workspace_path) {
+            Ok(WorkspaceHealthOutcome::Clean) => {}
+            Ok(WorkspaceHealthOutcome::DirtyWorkingCopy) => {
+                store.update_workspace_health(repo, &ws.workspace_id, WorkspaceHealth::Dirty)?;
+                continue;
+            }
+            Ok(_) | Err(_) => continue,
+        }
+        let lease_id = Uuid::new_v4().to_string();
+        if let Some(record) = store.claim_specific_workspace(
+            repo,
+            &ws.workspace_id,
+            WARM_HOLDER,
+            WARM_TASK,
+            &lease_id,
+            now_epoch_s,
+            lease_expires_at,
+        )? {
+            claimed.push((WarmAction::Refresh, lease_id, record));
+        }
+    }
+
+    for _ in 0..deficit {
+        // A refusal here is the free-space floor (see
+        // `auto_create_workspace`); minting more this pass would hit it too.
+        let new_candidate = match auto_create_workspace(runner, database_path, repo_record, &candidates) {
+            Ok(candidate) => candidate,
+            Err(e) => {
+                report.failed.push(WarmFailure {
+                    workspace_id: String::new(),
+                    error: e.to_string(),
+                });
+                break;
+            }
+        };
+        let new_id = new_candidate.workspace_id.clone();
+        candidates.push(new_candidate);
+        store.sync_workspaces(repo, &candidates)?;
+        let lease_id = Uuid::new_v4().to_string();
+        if let Some(record) = store.claim_specific_workspace(
+            repo,
+            &new_id,
+            WARM_HOLDER,
+            WARM_TASK,
+            &lease_id,
+            now_epoch_s,
+            lease_expires_at,
+        )? {
+            claimed.push((WarmAction::Provision, lease_id, record));
+        }
+    }
+
+    drop(lock);
+
+    for (action, lease_id, workspace) in claimed {
+        let outcome = prepare_workspace(runner, store, database_path, repo_record, &workspace);
+        let reason = if outcome.is_ok() {
+            WARM_RELEASE_REASON
+        } else {
+            WARM_FAILED_RELEASE_REASON
+        };
+        store.release_workspace(&lease_id, Some(reason))?;
+        match outcome {
+            Ok(()) => match action {
+                WarmAction::Provision => report.provisioned.push(workspace.workspace_id),
+                WarmAction::Refresh => report.refreshed.push(workspace.workspace_id),
+            },
+            Err(e) => report.failed.push(WarmFailure {
+                workspace_id: workspace.workspace_id,
+                error: e.to_string(),
+            }),
+        }
+    }
+
+    report.ready_after = ready_free_workspaces(store, repo)?.len();
+    audit!(
+        database_path,
+        "pool.warmed",
+        repo = repo,
+        target = target,
+        ready_before = report.ready_before,
+        ready_after = report.ready_after,
+        provisioned = report.provisioned.len(),
+        refreshed = report.refreshed.len(),
+        failed = report.failed.len(),
+    );
+    Ok(report)
+}
+
+/// Bring a claimed workspace to the state a lease would hand over: reset to
+/// the latest main, infra paths excluded, setup complete.
+fn prepare_workspace(
+    runner: &dyn CommandRunner,
+    store: &Store,
+    database_path: Option<&Path>,
+    repo_record: &RepoRecord,
+    workspace: &WorkspaceRecord,
+) -> Result<()> {
+    reset_workspace_guarded(
+        runner,
+        database_path,
+        &workspace.workspace_path,
+        &repo_record.main_branch,
+        None,
+    )?;
+    ensure_boss_infra_excluded(&workspace.workspace_path, &workspace.workspace_id);
+    let setup_report = run_setup_for_workspace(store, database_path, runner, workspace)?;
+    if let Some(failure) = setup_report.first_failure() {
+        let StepStatus::Failed { error, .. } = &failure.status else {
+            unreachable!("first_failure returned non-failure step");
+        };
+        return Err(CubeError::SetupStepFailed {
+            step: failure.id.clone(),
+            error: error.clone(),
+        });
+    }
+    Ok(())
+}
+
+/// Free workspaces the lease health scan would consider first: effectively
+/// free (not cached dirty, conflicted or quarantined) and present on disk.
+fn ready_free_workspaces(store: &Store, repo: &str) -> Result<Vec<WorkspaceRecord>> {
+    let mut free = store.list_workspaces_filtered(&WorkspaceListFilter {
+        repo: Some(repo),
+        effective_state: Some(EffectiveState::Free),
+        ..Default::default()
+    })?;
+    free.retain(workspace_path_exists);
+    Ok(free)
+}
+
+fn format_warm_message(reports: &[RepoWarmReport], dry_run: bool) -> String {
+    if reports.is_empty() {
+        return "No repo has a min-free-workspaces target; nothing to warm.".to_string();
+    }
+    reports
+        .iter()
+        .map(|r| {
+            if dry_run {
+                format!(
+                    "{}: {}/{} ready; would provision {}, refresh {}",
+                    r.repo,
+                    r.ready_before,
+                    r.target,
+                    r.would_provision.unwrap_or(0),
+                    r.refreshed.len(),
+                )
+            } else {
+                let mut line = format!(
+                    "{}: {}/{} ready (provisioned {}, refreshed {})",
+                    r.repo,
+                    r.ready_after,
+                    r.target,
+                    r.provisioned.len(),
+                    r.refreshed.len(),
+                );
+                if !r.failed.is_empty() {
+                    line.push_str(&format!("; {} failed", r.failed.len()));
+                }
+                line
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
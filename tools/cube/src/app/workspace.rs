@@ -46,6 +46,10 @@ use crate::app::reset::{
     reset_workspace_on_release,
 };
 use crate::app::salvage::list_salvage_records;
+use crate::app::snapshot::{
+    SnapshotReason, SnapshotRecord, find_snapshot, list_snapshot_records, restore_snapshot, snapshot_payload,
+    snapshot_workspace,
+};
 use crate::app::util::{current_epoch_s, holder_identity, repo_lock_path, resolve_release_lease};
 use crate::app::workspace_ops::{workspace_goto, workspace_push, workspace_rebase};
 
@@ -229,6 +233,10 @@ pub(super) fn run_workspace(
             exclude,
             release_on_setup_failure,
         } => {
+            // Everything from here to `lease.acquired` is what the caller
+            // waited for: lock contention, the health scan, provisioning and
+            // the reset. Recorded as `wait_ms` for `cube stats`.
+            let lease_started = Instant::now();
             let repo_record = store
                 .get_repo(&repo)?
                 .ok_or_else(|| CubeError::RepoNotFound(repo.clone()))?;
@@ -424,9 +432,10 @@ pub(super) fn run_workspace(
                     dirty_head_change_id = recovery_probe.as_ref().ok().map(|s| s.head_change_id()),
                     dirty_unpushed_commits = recovery_probe.as_ref().ok().map(|s| s.unpushed_summary()),
                     dirty_probe_error = dirty_probe_error.as_deref(),
+                    wait_ms = lease_started.elapsed().as_millis() as u64,
                 );
 
-                let setup_report = run_setup_for_workspace(&store, runner, &workspace)?;
+                let setup_report = run_setup_for_workspace(&store, database_path, runner, &workspace)?;
 
                 let lease_message = format!(
                     "Reclaimed {} (dirty) at {}.",
@@ -1203,6 +1212,7 @@ pub(super) fn run_workspace(
                 task = task,
                 head_commit = workspace.head_commit,
                 was_auto_created = was_auto_created,
+                wait_ms = lease_started.elapsed().as_millis() as u64,
             );
 
             // Defense-in-depth (issue #1174): keep Boss/host infra files —
@@ -1212,7 +1222,7 @@ pub(super) fn run_workspace(
             // that drops such a file is already covered.
             ensure_boss_infra_excluded(&workspace.workspace_path, &workspace.workspace_id);
 
-            let setup_report = run_setup_for_workspace(&store, runner, &workspace)?;
+            let setup_report = run_setup_for_workspace(&store, database_path, runner, &workspace)?;
 
             let lease_message = format!(
                 "Leased {} at {}.",
@@ -1325,6 +1335,8 @@ pub(super) fn run_workspace(
             // Set when the release-time reuse guard refused the destructive
             // reset because `@` still holds work that exists on no remote.
             let mut preserved: Option<PreservedWorkingCopy> = None;
+            // Set when `--force-reset` discarded work, which is snapshotted first.
+            let mut snapshotted: Option<Box<SnapshotRecord>> = None;
             if !keep_dirty {
                 let repo_record = store
                     .get_repo(&workspace.repo)?
@@ -1339,11 +1351,19 @@ pub(super) fn run_workspace(
                 match reset_workspace_on_release(
                     runner,
                     database_path,
-                    &workspace.workspace_path,
+                    &workspace,
                     &repo_record.main_branch,
                     force_reset,
                 ) {
-                    Ok(ReleaseResetOutcome::Reset) => {
+                    Ok(ReleaseResetOutcome::Reset { snapshot }) => {
+                        if let Some(taken) = &snapshot {
+                            eprintln!(
+                                "cube: --force-reset discarded unpushed work in {}; it was snapshotted \
+                                 first as {} (restore with `cube workspace restore {}`)",
+                                workspace.workspace_id, taken.manifest.snapshot_id, taken.manifest.snapshot_id,
+                            );
+                        }
+                        snapshotted = snapshot;
                         // Forget consumed boss/exec_* bookmarks and pr/<n>
                         // bookmarks no workspace is positioned on any more. The
                         // fetch above already updated main, so do_fetch = false.
@@ -1456,6 +1476,7 @@ pub(super) fn run_workspace(
                 preserved_unpushed_commits = preserved.as_ref().map(|p| p.unpushed_summary.as_str()),
                 reset_failed = reset_error.is_some(),
                 reset_error = reset_error.as_ref().map(|e| e.to_string()),
+                snapshot_id = snapshotted.as_ref().map(|s| s.manifest.snapshot_id.as_str()),
             );
 
             let message = if keep_dirty {
@@ -1467,6 +1488,11 @@ pub(super) fn run_workspace(
                 )
             } else if reset_error.is_some() {
                 format!("Released {} (reset failed; marked dirty).", released.workspace_id)
+            } else if let Some(taken) = &snapshotted {
+                format!(
+                    "Released {} (unpushed work snapshotted as {} before the reset).",
+                    released.workspace_id, taken.manifest.snapshot_id
+                )
             } else {
                 format!("Released {}.", released.workspace_id)
             };
@@ -1478,6 +1504,7 @@ pub(super) fn run_workspace(
                     "preserved_unpushed_work": preserved.is_some(),
                     "preserved_head_change_id": preserved.as_ref().map(|p| p.head_change_id.clone()),
                     "preserved_unpushed_commits": preserved.as_ref().map(|p| p.unpushed_summary.clone()),
+                    "snapshot": snapshotted.as_deref().map(snapshot_payload),
                 }),
             )
         }
@@ -1664,7 +1691,7 @@ pub(super) fn run_workspace(
             let path = PathBuf::from(&workspace);
             let record = find_workspace_record(&mut store, &path)?
                 .ok_or_else(|| CubeError::WorkspaceNotFound(workspace.clone()))?;
-            let report = run_setup_for_workspace(&store, runner, &record)?;
+            let report = run_setup_for_workspace(&store, database_path, runner, &record)?;
             let payload = json!({
                 "workspace": record,
                 "setup": report,
@@ -1771,6 +1798,152 @@ pub(super) fn run_workspace(
                 }),
             )
         }
+        WorkspaceCommand::Snapshot { lease } => {
+            let record = store
+                .get_workspace_by_lease(&lease)?
+                .ok_or_else(|| CubeError::LeaseNotFound(lease.clone()))?;
+            if !workspace_path_exists(&record) {
+                return Err(CubeError::WorkspaceNotFound(
+                    record.workspace_path.display().to_string(),
+                ));
+            }
+            let taken = snapshot_workspace(
+                runner,
+                database_path,
+                &record,
+                SnapshotReason::Manual,
+                current_epoch_s()?,
+            )?;
+            let mut message = format!(
+                "Snapshotted {} as {} at {}.",
+                record.workspace_id,
+                taken.manifest.snapshot_id,
+                taken.path.display()
+            );
+            if !taken.manifest.skipped_untracked.is_empty() {
+                message.push_str(&format!(
+                    "\nwarning: {} untracked file(s) were over the size limit and NOT captured: {}",
+                    taken.manifest.skipped_untracked.len(),
+                    taken.manifest.skipped_untracked.join(", ")
+                ));
+            }
+            RunResult::new(
+                message,
+                json!({
+                    "workspace": record,
+                    "snapshot": snapshot_payload(&taken),
+                }),
+            )
+        }
+        WorkspaceCommand::Snapshots { repo, workspace } => {
+            let records = list_snapshot_records(database_path, repo.as_deref(), workspace.as_deref())?;
+            let now = current_epoch_s()?;
+            let message = if records.is_empty() {
+                "No snapshots.".to_string()
+            } else {
+                records
+                    .iter()
+                    .map(|record| {
+                        format!(
+                            "{}  ({} ago)",
+                            record.manifest.summary_line(),
+                            format_age(now.saturating_sub(record.manifest.created_at_epoch_s)),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            };
+            RunResult::new(
+                message,
+                json!({
+                    "snapshots": records.iter().map(snapshot_payload).collect::<Vec<_>>(),
+                }),
+            )
+        }
+        WorkspaceCommand::Restore { snapshot, into } => {
+            let found = find_snapshot(database_path, &snapshot)?;
+            let snapshot_id = found.manifest.snapshot_id.clone();
+            let leased_for_restore = into.is_none();
+            let lease_id = match into {
+                Some(lease_id) => lease_id,
+                None => {
+                    let leased = run_workspace(
+                        WorkspaceCommand::Lease {
+                            repo: found.manifest.repo.clone(),
+                            task: format!("restore snapshot {snapshot_id}"),
+                            prefer: None,
+                            allow_dirty: false,
+                            exclude: Vec::new(),
+                            release_on_setup_failure: true,
+                        },
+                        database_path,
+                        runner,
+                    )?;
+                    leased.payload["workspace"]["lease_id"]
+                        .as_str()
+                        .map(str::to_string)
+                        .ok_or_else(|| CubeError::InvalidArgument("lease result carried no lease id".to_string()))?
+                }
+            };
+            let target = store
+                .get_workspace_by_lease(&lease_id)?
+                .ok_or_else(|| CubeError::LeaseNotFound(lease_id.clone()))?;
+            if target.repo != found.manifest.repo {
+                return Err(CubeError::InvalidArgument(format!(
+                    "snapshot `{snapshot_id}` is of repo `{}`, but lease `{lease_id}` is on `{}/{}`",
+                    found.manifest.repo, target.repo, target.workspace_id
+                )));
+            }
+
+            let outcome = match restore_snapshot(runner, database_path, &found, &target.workspace_path) {
+                Ok(outcome) => outcome,
+                Err(e) => {
+                    if leased_for_restore {
+                        eprintln!(
+                            "cube: restore of {snapshot_id} into {} failed; lease {lease_id} is still held so \
+                             the partial restore can be inspected. Release it with \
+                             `cube workspace release --lease {lease_id}` when done.",
+                            target.workspace_id,
+                        );
+                    }
+                    return Err(e);
+                }
+            };
+            audit!(
+                database_path,
+                "workspace.snapshot_restored",
+                repo = target.repo,
+                workspace_id = target.workspace_id,
+                lease_id = lease_id,
+                snapshot_id = snapshot_id,
+                source_workspace_id = found.manifest.workspace_id,
+                leased_for_restore = leased_for_restore,
+                untracked_restored = outcome.untracked_restored.len(),
+                untracked_conflicts = outcome.untracked_conflicts,
+            );
+
+            let mut message = format!(
+                "Restored {snapshot_id} into {} at {} (lease {lease_id}).",
+                target.workspace_id,
+                target.workspace_path.display()
+            );
+            if !outcome.untracked_conflicts.is_empty() {
+                message.push_str(&format!(
+                    "\nwarning: left {} existing file(s) in place instead of restoring them: {}",
+                    outcome.untracked_conflicts.len(),
+                    outcome.untracked_conflicts.join(", ")
+                ));
+            }
+            RunResult::new(
+                message,
+                json!({
+                    "workspace": target,
+                    "snapshot": snapshot_payload(&found),
+                    "leased_for_restore": leased_for_restore,
+                    "restore": outcome,
+                }),
+            )
+        }
         WorkspaceCommand::Remove {
             workspace,
             repo,
@@ -81,7 +81,7 @@ where
     }
 }
 
-fn audit_dir(database_path: Option<&Path>) -> Result<PathBuf, CubeError> {
+pub(crate) fn audit_dir(database_path: Option<&Path>) -> Result<PathBuf, CubeError> {
     match database_path.and_then(Path::parent) {
         Some(parent) => Ok(paths::audit_dir_in(parent)),
         None => paths::audit_dir(),
@@ -132,6 +132,106 @@ pub(crate) fn append_at(
     Ok(())
 }
 
+/// Every event in the audit log at or after `since_epoch_s`, oldest first.
+///
+/// Lines that fail to parse (a torn write from a crashed process) are
+/// skipped: the log is forensic, and one bad line must not hide the rest.
+pub(crate) fn read_events_since(dir: &Path, since_epoch_s: i64) -> Vec<Value> {
+    // A week file only holds events from its Monday onwards, so any file
+    // whose Monday is more than a week before the cutoff is entirely older.
+    let oldest_week = since_epoch_s - 7 * SECS_PER_DAY;
+    let mut events = Vec::new();
+    for path in log_files(dir) {
+        if file_epoch(&path).is_some_and(|epoch| epoch < oldest_week) {
+            continue;
+        }
+        let Ok(contents) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        events.extend(
+            parse_lines(&contents).filter(|event| event["ts_epoch_s"].as_i64().is_some_and(|ts| ts >= since_epoch_s)),
+        );
+    }
+    events
+}
+
+/// Incremental reader behind `cube events --follow`.
+///
+/// Remembers how far into each week file it has read, so a poll returns only
+/// lines appended since the previous one, and a new week's file is picked up
+/// on the poll after it appears. A trailing line without its newline is left
+/// for the next poll rather than parsed half-written.
+pub(crate) struct AuditTail {
+    dir: PathBuf,
+    offsets: std::collections::BTreeMap<PathBuf, u64>,
+}
+
+impl AuditTail {
+    /// Start at the current end of the log: only events appended after this
+    /// call are returned.
+    pub(crate) fn from_end(dir: &Path) -> Self {
+        let offsets = log_files(dir)
+            .into_iter()
+            .filter_map(|path| {
+                let len = std::fs::metadata(&path).ok()?.len();
+                Some((path, len))
+            })
+            .collect();
+        Self {
+            dir: dir.to_path_buf(),
+            offsets,
+        }
+    }
+
+    pub(crate) fn poll(&mut self) -> Vec<Value> {
+        use std::io::{Read, Seek, SeekFrom};
+
+        let mut events = Vec::new();
+        for path in log_files(&self.dir) {
+            let offset = self.offsets.get(&path).copied().unwrap_or(0);
+            let Ok(mut file) = std::fs::File::open(&path) else {
+                continue;
+            };
+            let mut appended = String::new();
+            if file.seek(SeekFrom::Start(offset)).is_err() || file.read_to_string(&mut appended).is_err() {
+                continue;
+            }
+            let Some(complete) = appended.rfind('\n').map(|end| end + 1) else {
+                continue;
+            };
+            events.extend(parse_lines(&appended[..complete]));
+            self.offsets.insert(path, offset + complete as u64);
+        }
+        events
+    }
+}
+
+/// The audit log's week files, oldest first (the names sort by date).
+fn log_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| file_epoch(path).is_some())
+        .collect();
+    files.sort();
+    files
+}
+
+fn file_epoch(path: &Path) -> Option<i64> {
+    let name = path.file_name()?.to_str()?;
+    parse_yyyy_mm_dd(name.strip_prefix(FILE_PREFIX)?.strip_suffix(FILE_SUFFIX)?)
+}
+
+fn parse_lines(contents: &str) -> impl Iterator<Item = Value> + '_ {
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+}
+
 fn week_file_name(now_epoch_s: i64) -> String {
     let (y, m, d) = monday_of_week(now_epoch_s);
     format!("{FILE_PREFIX}{y:04}-{m:02}-{d:02}{FILE_SUFFIX}")
@@ -317,6 +417,58 @@ mod tests {
         assert!(dir.path().join("audit-2026-04-27.log").exists());
     }
 
+    #[test]
+    fn read_events_since_filters_by_time_across_week_files() {
+        let dir = tempdir().unwrap();
+        let week_a = epoch_for(2026, 4, 30, 12, 0, 0);
+        let week_b = epoch_for(2026, 5, 7, 12, 0, 0);
+        append_at(dir.path(), week_a, "lease.acquired", fields()).unwrap();
+        append_at(dir.path(), week_b, "lease.released", fields()).unwrap();
+        fs::write(dir.path().join("audit-2026-05-04.log.bak"), "not json\n").unwrap();
+
+        let all = read_events_since(dir.path(), 0);
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0]["event"], "lease.acquired");
+
+        let recent = read_events_since(dir.path(), week_b);
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0]["event"], "lease.released");
+    }
+
+    #[test]
+    fn tail_returns_only_new_complete_lines_and_picks_up_new_weeks() {
+        let dir = tempdir().unwrap();
+        let week_a = epoch_for(2026, 4, 30, 12, 0, 0);
+        append_at(dir.path(), week_a, "lease.acquired", fields()).unwrap();
+
+        let mut tail = AuditTail::from_end(dir.path());
+        assert!(
+            tail.poll().is_empty(),
+            "events before the tail started are not replayed"
+        );
+
+        append_at(dir.path(), week_a + 60, "lease.released", fields()).unwrap();
+        // A torn write: no trailing newline yet.
+        let file = dir.path().join("audit-2026-04-27.log");
+        let mut handle = OpenOptions::new().append(true).open(&file).unwrap();
+        handle.write_all(b"{\"event\":\"half").unwrap();
+        let polled = tail.poll();
+        assert_eq!(polled.len(), 1);
+        assert_eq!(polled[0]["event"], "lease.released");
+
+        handle.write_all(b"\"}\n").unwrap();
+        append_at(
+            dir.path(),
+            epoch_for(2026, 5, 7, 12, 0, 0),
+            "workspace.created",
+            fields(),
+        )
+        .unwrap();
+        let polled = tail.poll();
+        let names: Vec<_> = polled.iter().map(|e| e["event"].as_str().unwrap()).collect();
+        assert_eq!(names, vec!["half", "workspace.created"]);
+    }
+
     #[test]
     fn prune_ignores_unrelated_files() {
         let dir = tempdir().unwrap();
@@ -33,6 +33,24 @@ pub enum Command {
         #[command(subcommand)]
         command: PrCommand,
     },
+    Pool {
+        #[command(subcommand)]
+        command: PoolCommand,
+    },
+    /// Run `cube pool warm` for every repo on a fixed interval.
+    ///
+    /// Intended for a launchd/systemd unit; a timer invoking `cube pool warm`
+    /// directly is equivalent. Config is re-read on every pass.
+    Daemon(DaemonArgs),
+    /// Print audit-log events as JSON lines.
+    ///
+    /// Lease, release, reclaim, provisioning and setup events are all
+    /// written to the audit log; this reads them back, and with `--follow`
+    /// keeps printing new ones as they are appended.
+    Events(EventsArgs),
+    /// Report per-repo pool state and lease, provisioning, setup and reclaim
+    /// metrics over a time window.
+    Stats(StatsArgs),
     Graph(GraphArgs),
     Doctor(DoctorArgs),
 }
@@ -263,6 +281,41 @@ pub enum WorkspaceCommand {
         #[arg(long)]
         workspace: Option<String>,
     },
+    /// Capture a leased workspace's working copy into a durable snapshot.
+    ///
+    /// The snapshot is written under the data dir and holds a git-format
+    /// diff of `@` against its newest ancestor on a remote, `@`'s
+    /// description, and any untracked-but-not-ignored files jj did not
+    /// snapshot. `release --force-reset` takes one automatically before it
+    /// discards work that exists on no remote.
+    Snapshot {
+        /// Lease id of the workspace to snapshot.
+        lease: String,
+    },
+    /// List workspace snapshots, newest first.
+    Snapshots {
+        /// Filter by repo id.
+        #[arg(long)]
+        repo: Option<String>,
+        /// Filter by the workspace the snapshot was taken from.
+        #[arg(long)]
+        workspace: Option<String>,
+    },
+    /// Replay a snapshot into a workspace.
+    ///
+    /// Starts a new change on the snapshot's base commit, applies its diff,
+    /// copies back its untracked files (never over an existing file) and
+    /// restores the description. Without `--into`, a free workspace of the
+    /// snapshot's repo is leased first and the new lease is returned to the
+    /// caller, who releases it like any other.
+    Restore {
+        /// Snapshot id (as printed by `cube workspace snapshot`) or the
+        /// absolute path of a snapshot directory.
+        snapshot: String,
+        /// Lease id of a workspace to restore into instead of leasing one.
+        #[arg(long)]
+        into: Option<String>,
+    },
     /// Forget consumed boss/exec_* bookmarks from workspace pools.
     ///
     /// A bookmark is "consumed" when its tip is reachable from `main`
@@ -645,6 +698,68 @@ pub struct PrMergeArgs {
     pub stack: Option<String>,
 }
 
+#[derive(Debug, Subcommand)]
+pub enum PoolCommand {
+    /// Top each repo's pool up to its `min-free-workspaces` target.
+    ///
+    /// Provisions, fetches and runs setup for enough new workspaces that the
+    /// target number sit ready and free, then refreshes free workspaces that
+    /// have idled past `warm-refresh-minutes` to the latest main, so a later
+    /// lease only has to claim one.
+    Warm {
+        /// Only warm this repo (default: every registered repo).
+        #[arg(long)]
+        repo: Option<String>,
+        /// Report what would be provisioned and refreshed without doing it.
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Debug, Args)]
+pub struct DaemonArgs {
+    /// Seconds to sleep between warm passes.
+    #[arg(long, default_value_t = 300)]
+    pub interval_secs: u64,
+    /// Stop after this many passes (default: run until killed).
+    #[arg(long)]
+    pub passes: Option<u64>,
+}
+
+#[derive(Debug, Args)]
+pub struct EventsArgs {
+    /// Keep running and print events as they are appended.
+    #[arg(long)]
+    pub follow: bool,
+    /// Also print events from this many seconds back. Defaults to the last
+    /// hour without `--follow` and to nothing (new events only) with it.
+    #[arg(long)]
+    pub since_secs: Option<u64>,
+    /// Only events whose name starts with this prefix (e.g. `lease.`).
+    /// May be repeated.
+    #[arg(long = "event", action = clap::ArgAction::Append)]
+    pub events: Vec<String>,
+    /// Only events for this repo.
+    #[arg(long)]
+    pub repo: Option<String>,
+    /// How often `--follow` checks the log for new lines.
+    #[arg(long, default_value_t = 1000)]
+    pub poll_ms: u64,
+}
+
+#[derive(Debug, Args)]
+pub struct StatsArgs {
+    /// Only this repo.
+    #[arg(long)]
+    pub repo: Option<String>,
+    /// How far back the latency histograms and reclaim totals look.
+    #[arg(long, default_value_t = 24)]
+    pub window_hours: u64,
+    /// Print the Prometheus text exposition format instead of a summary.
+    #[arg(long)]
+    pub prometheus: bool,
+}
+
 #[derive(Debug, Args)]
 pub struct GraphArgs {
     /// Absolute workspace path to inspect.
@@ -664,7 +779,8 @@ mod tests {
     use clap::Parser;
 
     use super::{
-        ChangeCommand, Cli, Command, PrCommand, PrCreateArgs, PrPushArgs, PrUpdateArgs, RepoCommand, WorkspaceCommand,
+        ChangeCommand, Cli, Command, DaemonArgs, EventsArgs, PoolCommand, PrCommand, PrCreateArgs, PrPushArgs,
+        PrUpdateArgs, RepoCommand, StatsArgs, WorkspaceCommand,
     };
 
     #[test]
@@ -1542,4 +1658,72 @@ mod tests {
             _ => panic!("expected pr push command"),
         }
     }
+
+    #[test]
+    fn pool_warm_and_daemon_parse() {
+        let cli = Cli::parse_from(["cube", "pool", "warm", "--repo", "mono", "--dry-run"]);
+        match cli.command {
+            Command::Pool {
+                command: PoolCommand::Warm { repo, dry_run },
+            } => {
+                assert_eq!(repo.as_deref(), Some("mono"));
+                assert!(dry_run);
+            }
+            _ => panic!("expected pool warm command"),
+        }
+
+        let cli = Cli::parse_from(["cube", "daemon"]);
+        match cli.command {
+            Command::Daemon(DaemonArgs { interval_secs, passes }) => {
+                assert_eq!(interval_secs, 300);
+                assert!(passes.is_none());
+            }
+            _ => panic!("expected daemon command"),
+        }
+    }
+
+    #[test]
+    fn events_and_stats_parse() {
+        let cli = Cli::parse_from([
+            "cube",
+            "events",
+            "--follow",
+            "--event",
+            "lease.",
+            "--event",
+            "workspace.gc",
+            "--repo",
+            "mono",
+        ]);
+        match cli.command {
+            Command::Events(EventsArgs {
+                follow,
+                since_secs,
+                events,
+                repo,
+                poll_ms,
+            }) => {
+                assert!(follow);
+                assert!(since_secs.is_none());
+                assert_eq!(events, vec!["lease.".to_string(), "workspace.gc".to_string()]);
+                assert_eq!(repo.as_deref(), Some("mono"));
+                assert_eq!(poll_ms, 1000);
+            }
+            _ => panic!("expected events command"),
+        }
+
+        let cli = Cli::parse_from(["cube", "stats", "--window-hours", "6", "--prometheus"]);
+        match cli.command {
+            Command::Stats(StatsArgs {
+                repo,
+                window_hours,
+                prometheus,
+            }) => {
+                assert!(repo.is_none());
+                assert_eq!(window_hours, 6);
+                assert!(prometheus);
+            }
+            _ => panic!("expected stats command"),
+        }
+    }
 }
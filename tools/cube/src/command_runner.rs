@@ -31,6 +31,16 @@ pub trait CommandRunner {
     fn run_with_timeout(&self, invocation: &CommandInvocation, _timeout: Duration) -> Result<String, CubeError> {
         self.run(invocation)
     }
+
+    /// This runner as a thread-shareable trait object, when it is one.
+    ///
+    /// The setup engine runs independent steps concurrently only when this
+    /// returns `Some`; otherwise it runs them one at a time in dependency
+    /// order. The default is `None` so that test fakes built on `RefCell`
+    /// keep working unchanged and stay deterministic.
+    fn as_sync(&self) -> Option<&(dyn CommandRunner + Sync)> {
+        None
+    }
 }
 
 /// How often [`RealCommandRunner::run_with_timeout`] emits a stderr progress
@@ -116,6 +126,10 @@ impl CommandRunner for RealCommandRunner {
             );
         })
     }
+
+    fn as_sync(&self) -> Option<&(dyn CommandRunner + Sync)> {
+        Some(self)
+    }
 }
 
 impl RealCommandRunner {
@@ -221,12 +235,16 @@ impl RealCommandRunner {
                     let now = Instant::now();
                     if now >= deadline {
                         // Kill the child so cube stops waiting and any lock it
-                        // holds is released; reap it to avoid a zombie, and
-                        // join the drain threads so the pipe fds close.
+                        // holds is released, and reap it to avoid a zombie.
+                        // The drain threads are detached rather than joined:
+                        // a grandchild (e.g. the program a `sh -c` setup step
+                        // launched) can inherit the pipes and keep them open
+                        // long after the child itself is gone, and joining
+                        // would block on it for its whole lifetime.
                         let _ = child.kill();
                         let _ = child.wait();
-                        let _ = out_handle.join();
-                        let _ = err_handle.join();
+                        drop(out_handle);
+                        drop(err_handle);
                         return Ok(None);
                     }
                     // Long-running commands (most importantly `jj git push`
@@ -144,6 +144,16 @@ impl UnhealthyGcConfig {
 /// making a 500-entry pool structurally impossible.
 pub const DEFAULT_MAX_FREE_WORKSPACES: usize = 20;
 
+/// How long a free workspace may sit idle before `cube pool warm` refreshes it
+/// to the latest `main` in the background.
+///
+/// A lease always resets to `main@origin`, so a stale free workspace is never
+/// *wrong* — it is slow: the lease pays for a fetch of everything that landed
+/// since, and setup re-runs if a lockfile moved. An hour keeps that catch-up
+/// small on a busy repo without re-fetching every workspace on every pass of
+/// a daemon ticking every few minutes.
+pub const DEFAULT_WARM_REFRESH_MINUTES: u64 = 60;
+
 /// How long a workspace must have been idle before the *routine* pool GC pass
 /// will reclaim its build-artifact trees.
 ///
@@ -193,6 +203,39 @@ pub fn default_build_artifact_dirs() -> Vec<String> {
     vec!["target".to_string(), ".build".to_string()]
 }
 
+/// How a new pool workspace is materialised from its repo.
+///
+/// The default, [`ProvisionMode::JjWorkspace`], attaches a working copy to the
+/// repo's canonical shared store, so a workspace costs its checkout and nothing
+/// else. The alternatives exist for repos where that is not the best trade:
+/// `reflink` block-clones the whole canonical repo (near-free on APFS, btrfs
+/// and XFS, and each workspace then owns an independent store), and `clone`
+/// is a full independent `jj git clone` from `origin`, which is also the
+/// fallback when a reflink copy is not supported by the filesystem.
+///
+/// Git worktrees are deliberately not offered: every lease drives a workspace
+/// through `jj` (`jj git fetch`, `jj new <main>@origin`), and jj cannot
+/// colocate inside a git worktree. A jj workspace is the shared-store
+/// equivalent that the rest of cube already understands.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ProvisionMode {
+    #[default]
+    JjWorkspace,
+    Reflink,
+    Clone,
+}
+
+impl ProvisionMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::JjWorkspace => "jj-workspace",
+            Self::Reflink => "reflink",
+            Self::Clone => "clone",
+        }
+    }
+}
+
 /// Per-repo overrides for the free-workspace high-water mark.
 ///
 /// mono, flunge and checkleft-sandbox have very different footprints (a built
@@ -204,6 +247,11 @@ pub struct RepoPoolConfig {
     /// Overrides [`PoolConfig::max_free_workspaces`] for this repo.
     #[serde(rename = "max-free-workspaces")]
     pub max_free_workspaces: Option<usize>,
+    /// Overrides [`PoolConfig::min_free_workspaces`] for this repo.
+    #[serde(rename = "min-free-workspaces")]
+    pub min_free_workspaces: Option<usize>,
+    /// Overrides [`PoolConfig::provision`] for this repo.
+    pub provision: Option<ProvisionMode>,
 }
 
 /// Controls how pool GC bounds workspace disk usage.
@@ -223,6 +271,16 @@ pub struct PoolConfig {
     /// [`DEFAULT_MAX_FREE_WORKSPACES`].
     #[serde(rename = "max-free-workspaces")]
     pub max_free_workspaces: Option<usize>,
+    /// Default per-repo pre-warm target: how many ready free workspaces
+    /// `cube pool warm` keeps on hand. Unset means no pre-warming.
+    #[serde(rename = "min-free-workspaces")]
+    pub min_free_workspaces: Option<usize>,
+    /// Idle window before `cube pool warm` refreshes a free workspace. See
+    /// [`DEFAULT_WARM_REFRESH_MINUTES`].
+    #[serde(rename = "warm-refresh-minutes")]
+    pub warm_refresh_minutes: Option<u64>,
+    /// How new workspaces are materialised. See [`ProvisionMode`].
+    pub provision: Option<ProvisionMode>,
     /// Per-repo overrides, keyed by repo id (`[pool.repos.mono]`).
     #[builder(default)]
     pub repos: std::collections::HashMap<String, RepoPoolConfig>,
@@ -259,6 +317,42 @@ impl PoolConfig {
             .unwrap_or(DEFAULT_MAX_FREE_WORKSPACES)
     }
 
+    /// The pre-warm target for `repo`: its own override, else
+    /// `CUBE_POOL_MIN_FREE_WORKSPACES`, else the configured default, else zero
+    /// (pre-warming is opt-in).
+    ///
+    /// Clamped to [`Self::max_free_workspaces`]: warming above the high-water
+    /// mark would only mint workspaces for the next GC pass to trim.
+    pub fn min_free_workspaces(&self, repo: &str) -> usize {
+        let target = self
+            .repos
+            .get(repo)
+            .and_then(|r| r.min_free_workspaces)
+            .or_else(|| env_u64("CUBE_POOL_MIN_FREE_WORKSPACES").map(|v| v as usize))
+            .or(self.min_free_workspaces)
+            .unwrap_or(0);
+        target.min(self.max_free_workspaces(repo))
+    }
+
+    /// Idle window, in seconds, before `cube pool warm` refreshes a free
+    /// workspace to the latest `main`.
+    pub fn warm_refresh_secs(&self) -> i64 {
+        let minutes = env_u64("CUBE_POOL_WARM_REFRESH_MINUTES")
+            .or(self.warm_refresh_minutes)
+            .unwrap_or(DEFAULT_WARM_REFRESH_MINUTES);
+        (minutes as i64).saturating_mul(60)
+    }
+
+    /// How new workspaces for `repo` are materialised: its own override, else
+    /// the configured default, else [`ProvisionMode::JjWorkspace`].
+    pub fn provision_mode(&self, repo: &str) -> ProvisionMode {
+        self.repos
+            .get(repo)
+            .and_then(|r| r.provision)
+            .or(self.provision)
+            .unwrap_or_default()
+    }
+
     /// Directory names compaction reclaims, relative to a workspace root.
     pub fn build_artifact_dirs(&self) -> Vec<String> {
         self.build_artifact_dirs
@@ -453,6 +547,43 @@ mod tests {
         assert_eq!(cfg.pool.max_free_workspaces("mono"), 20);
     }
 
+    #[test]
+    fn pre_warming_is_opt_in() {
+        let pool = PoolConfig::default();
+        assert_eq!(pool.min_free_workspaces("mono"), 0);
+        assert_eq!(pool.warm_refresh_secs(), DEFAULT_WARM_REFRESH_MINUTES as i64 * 60);
+    }
+
+    #[test]
+    fn per_repo_warm_target_overrides_the_default_and_is_clamped_to_the_mark() {
+        let cfg: CubeConfig = toml::from_str(
+            "[pool]\n\
+             min-free-workspaces = 2\n\
+             [pool.repos.flunge]\n\
+             max-free-workspaces = 4\n\
+             min-free-workspaces = 10\n",
+        )
+        .expect("parse");
+        assert_eq!(cfg.pool.min_free_workspaces("mono"), 2);
+        // A target above the high-water mark would be trimmed straight back.
+        assert_eq!(cfg.pool.min_free_workspaces("flunge"), 4);
+    }
+
+    #[test]
+    fn provision_mode_is_per_repo_with_a_shared_store_default() {
+        let cfg: CubeConfig = toml::from_str(
+            "[pool]\n\
+             provision = \"clone\"\n\
+             [pool.repos.mono]\n\
+             provision = \"reflink\"\n",
+        )
+        .expect("parse");
+        assert_eq!(cfg.pool.provision_mode("mono"), ProvisionMode::Reflink);
+        assert_eq!(cfg.pool.provision_mode("flunge"), ProvisionMode::Clone);
+        assert_eq!(PoolConfig::default().provision_mode("mono"), ProvisionMode::JjWorkspace);
+        assert!(toml::from_str::<CubeConfig>("[pool]\nprovision = \"git-worktree\"\n").is_err());
+    }
+
     #[test]
     fn free_space_floor_takes_the_larger_of_absolute_and_proportional() {
         let pool = PoolConfig::default();
@@ -1,7 +1,10 @@
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::{Path, PathBuf};
-use std::time::Instant;
+use std::path::{Component, Path, PathBuf};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
 
+use globset::Glob;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
@@ -13,14 +16,37 @@ use crate::store::{Store, WorkspaceSetupState};
 pub const SETUP_FILE_RELATIVE: &str = ".cube/setup.yaml";
 const SUPPORTED_VERSION: u32 = 1;
 
+/// Default cap on concurrently running setup steps when the config does not
+/// set `max_parallel`. Setup steps are typically installers that saturate
+/// disk and network on their own, so a small fan-out captures most of the
+/// win from overlapping independent steps without thrashing the host.
+pub const DEFAULT_MAX_PARALLEL: usize = 4;
+
+/// How many trailing stdout lines a step with `output: report` keeps in its
+/// [`StepOutcome`]. Enough to show an installer's summary, small enough that
+/// the `--json` lease payload stays readable.
+const REPORTED_OUTPUT_LINES: usize = 40;
+
+/// Directories never descended into when expanding a `glob` fingerprint
+/// input: VCS metadata is not a setup input and walking it is expensive.
+const GLOB_SKIP_DIRS: &[&str] = &[".git", ".jj"];
+
 #[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
 pub struct SetupConfig {
     pub version: u32,
+    /// Upper bound on steps running at the same time. Steps only overlap when
+    /// their `needs` allow it; `1` restores strictly serial execution.
+    #[serde(default = "default_max_parallel")]
+    pub max_parallel: usize,
     #[serde(default)]
     pub steps: Vec<SetupStep>,
 }
 
-#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+fn default_max_parallel() -> usize {
+    DEFAULT_MAX_PARALLEL
+}
+
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, Eq)]
 pub struct SetupStep {
     pub id: String,
     pub command: String,
@@ -33,6 +59,29 @@ pub struct SetupStep {
     /// Setup state is not persisted, so the step retries on the next lease.
     #[serde(default)]
     pub allow_failure: bool,
+    /// Ids of the steps that must finish before this one starts.
+    ///
+    /// Omitting the field keeps the original sequential contract: the step
+    /// depends on the step declared immediately before it. An explicit list
+    /// (including `[]`) replaces that implicit edge, which is what lets
+    /// independent steps run in parallel.
+    #[serde(default)]
+    pub needs: Option<Vec<String>>,
+    /// Wall-clock bound on the step. On expiry the command is killed and the
+    /// step fails (or warns, under `allow_failure`) like any other non-zero
+    /// exit. Unset means no bound.
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    /// Extra environment for this step, applied after `CUBE_WORKSPACE` and
+    /// `CUBE_BASE_REPO` so a step can override them if it really needs to.
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+    /// Directory to run the command in, relative to the workspace root. It
+    /// may not be absolute or climb out of the workspace.
+    #[serde(default)]
+    pub cwd: Option<String>,
+    #[serde(default)]
+    pub output: OutputCapture,
 }
 
 #[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
@@ -48,21 +97,60 @@ pub enum RunPolicy {
     Always,
 }
 
+/// What happens to a step's stdout. stderr is always kept for failures.
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum OutputCapture {
+    /// Drop stdout once the step succeeds. This is the default.
+    #[default]
+    Discard,
+    /// Keep the tail of stdout in the step's [`StepOutcome::output`].
+    Report,
+}
+
 #[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
 #[serde(untagged)]
 pub enum FingerprintInput {
-    File { file: String },
-    Value { value: String },
+    File {
+        file: String,
+    },
+    /// Every file under the workspace matching a glob such as
+    /// `**/package.json`. Both the set of matching paths and their contents
+    /// feed the fingerprint, so adding or removing a match re-runs the step.
+    Glob {
+        glob: String,
+    },
+    Value {
+        value: String,
+    },
 }
 
 #[derive(Debug, Clone, Serialize)]
 pub struct SetupReport {
     pub steps: Vec<StepOutcome>,
+    pub timing: SetupTiming,
+}
+
+/// Where the time in one setup run went.
+///
+/// `wall_ms` is what the lease actually waited; `step_ms_total` is what the
+/// same steps would have cost run back to back. The critical path is the
+/// dependency chain that bounded `wall_ms`, which is the chain worth
+/// shortening when a lease is slow.
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct SetupTiming {
+    pub wall_ms: u64,
+    pub step_ms_total: u64,
+    pub critical_path: Vec<String>,
+    pub critical_path_ms: u64,
 }
 
 impl SetupReport {
     pub fn empty() -> Self {
-        Self { steps: Vec::new() }
+        Self {
+            steps: Vec::new(),
+            timing: SetupTiming::default(),
+        }
     }
 
     pub fn ran_count(&self) -> usize {
@@ -100,6 +188,12 @@ impl SetupReport {
     pub fn tolerated_failures(&self) -> impl Iterator<Item = &StepOutcome> {
         self.steps.iter().filter(|step| step.status.is_tolerated_failure())
     }
+
+    /// The step that took longest, skipped steps included (a slow fingerprint
+    /// glob is as much a lease cost as a slow command).
+    pub fn slowest_step(&self) -> Option<&StepOutcome> {
+        self.steps.iter().max_by_key(|step| step.duration_ms)
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -108,7 +202,12 @@ pub struct StepOutcome {
     #[serde(flatten)]
     pub status: StepStatus,
     pub fingerprint: String,
+    /// Offset from the start of the setup run at which this step started.
+    pub started_at_ms: u64,
     pub duration_ms: u64,
+    /// Tail of the command's stdout, for steps declared `output: report`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -207,6 +306,12 @@ pub fn read_setup_config(workspace_path: &Path) -> Result<Option<SetupConfig>, C
             path.display()
         )));
     }
+    if config.max_parallel == 0 {
+        return Err(CubeError::InvalidArgument(format!(
+            "`max_parallel` must be at least 1 in `{}`",
+            path.display()
+        )));
+    }
     let mut seen = std::collections::HashSet::new();
     for step in &config.steps {
         if step.id.trim().is_empty() {
@@ -229,29 +334,146 @@ pub fn read_setup_config(workspace_path: &Path) -> Result<Option<SetupConfig>, C
                 path.display()
             )));
         }
+        if step.timeout_secs == Some(0) {
+            return Err(CubeError::InvalidArgument(format!(
+                "setup step `{}` has a zero `timeout_secs` in `{}`",
+                step.id,
+                path.display()
+            )));
+        }
+        if let Some(cwd) = &step.cwd
+            && !is_contained_relative_path(cwd)
+        {
+            return Err(CubeError::InvalidArgument(format!(
+                "setup step `{}` has cwd `{cwd}` in `{}`; it must be a relative path inside the workspace",
+                step.id,
+                path.display()
+            )));
+        }
+        for input in &step.fingerprint {
+            if let FingerprintInput::Glob { glob } = input
+                && let Err(error) = Glob::new(glob)
+            {
+                return Err(CubeError::InvalidArgument(format!(
+                    "setup step `{}` has an invalid fingerprint glob `{glob}` in `{}`: {error}",
+                    step.id,
+                    path.display()
+                )));
+            }
+        }
     }
+    resolve_dependencies(&config.steps)
+        .map_err(|reason| CubeError::InvalidArgument(format!("{reason} in `{}`", path.display())))?;
     Ok(Some(config))
 }
 
+/// True for a non-empty relative path with no `..` component, i.e. one that
+/// cannot name anything outside the directory it is joined onto.
+fn is_contained_relative_path(raw: &str) -> bool {
+    let path = Path::new(raw);
+    !raw.trim().is_empty()
+        && path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+}
+
+/// Resolve each step's `needs` into indices of the steps it waits for.
+///
+/// A step without `needs` waits for the step declared before it, which keeps
+/// every pre-`needs` config running in exactly its old order. Unknown ids,
+/// self-references and cycles are rejected with a message naming the step.
+pub(crate) fn resolve_dependencies(steps: &[SetupStep]) -> Result<Vec<Vec<usize>>, String> {
+    let index_of: std::collections::HashMap<&str, usize> = steps
+        .iter()
+        .enumerate()
+        .map(|(index, step)| (step.id.as_str(), index))
+        .collect();
+    let mut deps = Vec::with_capacity(steps.len());
+    for (index, step) in steps.iter().enumerate() {
+        let resolved = match &step.needs {
+            None => index.checked_sub(1).into_iter().collect(),
+            Some(needs) => {
+                let mut resolved = Vec::with_capacity(needs.len());
+                for need in needs {
+                    let Some(&dep) = index_of.get(need.as_str()) else {
+                        return Err(format!("setup step `{}` needs unknown step `{need}`", step.id));
+                    };
+                    if dep == index {
+                        return Err(format!("setup step `{}` needs itself", step.id));
+                    }
+                    if !resolved.contains(&dep) {
+                        resolved.push(dep);
+                    }
+                }
+                resolved
+            }
+        };
+        deps.push(resolved);
+    }
+
+    // Kahn's algorithm: anything left unvisited sits on a cycle.
+    let mut remaining: Vec<usize> = deps.iter().map(Vec::len).collect();
+    let mut ready: Vec<usize> = (0..steps.len()).filter(|&index| remaining[index] == 0).collect();
+    let mut visited = 0;
+    while let Some(index) = ready.pop() {
+        visited += 1;
+        for (dependent, needs) in deps.iter().enumerate() {
+            if needs.contains(&index) {
+                remaining[dependent] -= 1;
+                if remaining[dependent] == 0 {
+                    ready.push(dependent);
+                }
+            }
+        }
+    }
+    if visited != steps.len() {
+        let cyclic: Vec<&str> = steps
+            .iter()
+            .zip(&remaining)
+            .filter(|(_, left)| **left > 0)
+            .map(|(step, _)| step.id.as_str())
+            .collect();
+        return Err(format!("setup steps form a dependency cycle: {}", cyclic.join(", ")));
+    }
+    Ok(deps)
+}
+
 pub fn compute_fingerprint(workspace_path: &Path, step: &SetupStep) -> Result<String, CubeError> {
     let mut hasher = Sha256::new();
     hasher.update(step.command.as_bytes());
     hasher.update([0u8]);
+    // `cwd` and `env` change what the command does, so they are inputs too.
+    // Both are hashed only when set, so steps that declare neither keep the
+    // fingerprints they were recorded with before these fields existed.
+    if let Some(cwd) = &step.cwd {
+        hasher.update(b"cwd:");
+        hasher.update(cwd.as_bytes());
+        hasher.update([0u8]);
+    }
+    for (key, value) in &step.env {
+        hasher.update(b"env:");
+        hasher.update(key.as_bytes());
+        hasher.update(b"=");
+        hasher.update(value.as_bytes());
+        hasher.update([0u8]);
+    }
     for input in &step.fingerprint {
         match input {
             FingerprintInput::File { file } => {
                 hasher.update(b"file:");
                 hasher.update(file.as_bytes());
                 hasher.update([0u8]);
-                let candidate = workspace_path.join(file);
-                match fs::read(&candidate) {
-                    Ok(bytes) => hasher.update(&bytes),
-                    Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
-                        hasher.update(b"<missing>");
-                    }
-                    Err(source) => return Err(CubeError::Io(source)),
-                }
+                hash_file_contents(&mut hasher, &workspace_path.join(file))?;
+            }
+            FingerprintInput::Glob { glob } => {
+                hasher.update(b"glob:");
+                hasher.update(glob.as_bytes());
                 hasher.update([0u8]);
+                for relative in expand_glob(workspace_path, glob)? {
+                    hasher.update(relative.as_bytes());
+                    hasher.update([0u8]);
+                    hash_file_contents(&mut hasher, &workspace_path.join(&relative))?;
+                }
             }
             FingerprintInput::Value { value } => {
                 hasher.update(b"value:");
@@ -264,6 +486,68 @@ pub fn compute_fingerprint(workspace_path: &Path, step: &SetupStep) -> Result<St
     Ok(hex_digest(&digest))
 }
 
+fn hash_file_contents(hasher: &mut Sha256, path: &Path) -> Result<(), CubeError> {
+    match fs::read(path) {
+        Ok(bytes) => hasher.update(&bytes),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+            hasher.update(b"<missing>");
+        }
+        Err(source) => return Err(CubeError::Io(source)),
+    }
+    hasher.update([0u8]);
+    Ok(())
+}
+
+/// Workspace-relative paths (with `/` separators) of the regular files that
+/// match `pattern`, sorted so the fingerprint is independent of directory
+/// iteration order.
+///
+/// The walk starts at the pattern's literal directory prefix — `web/**/*.json`
+/// only walks `web/` — so a narrow glob never pays for a full-tree scan.
+fn expand_glob(workspace_path: &Path, pattern: &str) -> Result<Vec<String>, CubeError> {
+    let matcher = Glob::new(pattern)
+        .map_err(|error| CubeError::InvalidArgument(format!("invalid fingerprint glob `{pattern}`: {error}")))?
+        .compile_matcher();
+    let literal_prefix: Vec<&str> = pattern
+        .split('/')
+        .take_while(|segment| !segment.contains(['*', '?', '[', '{']))
+        .collect();
+    // The last literal segment may be the file name itself (`a/b.lock`).
+    let root = workspace_path.join(literal_prefix.join("/"));
+    let root = if root.is_dir() {
+        root
+    } else {
+        root.parent().map(Path::to_path_buf).unwrap_or(root)
+    };
+    if !root.starts_with(workspace_path) || !root.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut matches = Vec::new();
+    let walker = walkdir::WalkDir::new(&root).into_iter().filter_entry(|entry| {
+        !(entry.file_type().is_dir() && GLOB_SKIP_DIRS.iter().any(|skip| entry.file_name() == *skip))
+    });
+    for entry in walker {
+        let entry = entry.map_err(|error| CubeError::Io(error.into()))?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let Ok(relative) = entry.path().strip_prefix(workspace_path) else {
+            continue;
+        };
+        let relative = relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        if matcher.is_match(&relative) {
+            matches.push(relative);
+        }
+    }
+    matches.sort();
+    Ok(matches)
+}
+
 fn hex_digest(bytes: &[u8]) -> String {
     let mut out = String::with_capacity(bytes.len() * 2);
     for byte in bytes {
@@ -272,6 +556,14 @@ fn hex_digest(bytes: &[u8]) -> String {
     out
 }
 
+/// Run a workspace's setup steps in dependency order, overlapping
+/// independent steps up to `config.max_parallel` when the runner can be
+/// shared across threads.
+///
+/// The store is only touched from the calling thread: worker threads run the
+/// command and hand the result back over a channel. After the first hard
+/// failure no new step starts, already-running steps are allowed to finish,
+/// and steps that never started are left out of the report.
 pub fn run_setup_engine(
     store: &Store,
     runner: &dyn CommandRunner,
@@ -279,32 +571,125 @@ pub fn run_setup_engine(
     config: &SetupConfig,
     now_epoch_s: i64,
 ) -> Result<SetupReport, CubeError> {
+    let deps = resolve_dependencies(&config.steps).map_err(CubeError::InvalidArgument)?;
     let source_path = store.get_repo(&workspace.repo)?.and_then(|r| r.source);
-    let mut step_env = vec![(
+    let mut base_env = vec![(
         "CUBE_WORKSPACE".to_string(),
         workspace.workspace_path.display().to_string(),
     )];
     if let Some(ref source) = source_path {
-        step_env.push(("CUBE_BASE_REPO".to_string(), source.display().to_string()));
+        base_env.push(("CUBE_BASE_REPO".to_string(), source.display().to_string()));
     }
 
-    let mut report = SetupReport::empty();
-    for step in &config.steps {
-        let started = Instant::now();
-        let fingerprint = compute_fingerprint(&workspace.workspace_path, step)?;
-        let stored = store.get_workspace_setup_state(&workspace.repo, &workspace.workspace_id, &step.id)?;
-        let action = decide_action(step.run_when, stored.as_ref(), &fingerprint);
-        match action {
-            StepAction::Skip(reason) => {
-                report.steps.push(StepOutcome {
-                    id: step.id.clone(),
-                    status: StepStatus::Skipped { reason },
-                    fingerprint,
-                    duration_ms: started.elapsed().as_millis() as u64,
-                });
+    let parallel_runner = runner.as_sync();
+    let slots = match parallel_runner {
+        Some(_) => config.max_parallel.max(1),
+        None => 1,
+    };
+
+    let setup_started = Instant::now();
+    let mut progress = vec![StepProgress::Pending; config.steps.len()];
+    let mut outcomes: Vec<Option<StepOutcome>> = vec![None; config.steps.len()];
+    let mut halted = false;
+
+    std::thread::scope(|scope| -> Result<(), CubeError> {
+        let (sender, receiver) = mpsc::channel::<StepCompletion>();
+        let mut running = 0usize;
+        loop {
+            // Start (or skip) everything that is ready, until the slots fill.
+            let mut index = 0;
+            while !halted && running < slots && index < config.steps.len() {
+                let ready = matches!(progress[index], StepProgress::Pending)
+                    && deps[index]
+                        .iter()
+                        .all(|&dep| matches!(progress[dep], StepProgress::Done));
+                if !ready {
+                    index += 1;
+                    continue;
+                }
+                let step = &config.steps[index];
+                let started_at_ms = setup_started.elapsed().as_millis() as u64;
+                let started = Instant::now();
+                let fingerprint = compute_fingerprint(&workspace.workspace_path, step)?;
+                let stored = store.get_workspace_setup_state(&workspace.repo, &workspace.workspace_id, &step.id)?;
+                match decide_action(step.run_when, stored.as_ref(), &fingerprint) {
+                    StepAction::Skip(reason) => {
+                        progress[index] = StepProgress::Done;
+                        outcomes[index] = Some(StepOutcome {
+                            id: step.id.clone(),
+                            status: StepStatus::Skipped { reason },
+                            fingerprint,
+                            started_at_ms,
+                            duration_ms: started.elapsed().as_millis() as u64,
+                            output: None,
+                        });
+                        // A skip can unblock an earlier-declared dependent.
+                        index = 0;
+                    }
+                    StepAction::Run => {
+                        progress[index] = StepProgress::Running {
+                            fingerprint,
+                            started_at_ms,
+                        };
+                        let invocation = step_invocation(&workspace.workspace_path, step, &base_env);
+                        let timeout = step.timeout_secs.map(Duration::from_secs);
+                        running += 1;
+                        match parallel_runner {
+                            Some(shared) => {
+                                let sender = sender.clone();
+                                let step_id = step.id.clone();
+                                scope.spawn(move || {
+                                    // Always report back: the loop below
+                                    // still holds a sender, so a step that
+                                    // died without sending would hang it.
+                                    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                                        invoke_step(shared, &invocation, timeout)
+                                    }))
+                                    .unwrap_or_else(|panic| {
+                                        Err(CubeError::SetupStepFailed {
+                                            step: step_id,
+                                            error: format!("the step panicked: {}", panic_message(&*panic)),
+                                        })
+                                    });
+                                    let _ = sender.send(StepCompletion {
+                                        index,
+                                        result,
+                                        duration: started.elapsed(),
+                                    });
+                                });
+                            }
+                            None => {
+                                let result = invoke_step(runner, &invocation, timeout);
+                                let _ = sender.send(StepCompletion {
+                                    index,
+                                    result,
+                                    duration: started.elapsed(),
+                                });
+                            }
+                        }
+                        index += 1;
+                    }
+                }
+            }
+
+            if running == 0 {
+                return Ok(());
             }
-            StepAction::Run => match invoke_step(runner, &workspace.workspace_path, step, &step_env) {
-                Ok(()) => {
+            let completion = receiver.recv().expect("a running setup step always reports back");
+            running -= 1;
+
+            let index = completion.index;
+            let step = &config.steps[index];
+            let StepProgress::Running {
+                fingerprint,
+                started_at_ms,
+            } = std::mem::replace(&mut progress[index], StepProgress::Done)
+            else {
+                unreachable!("completion for a step that was not running");
+            };
+            let duration_ms = completion.duration.as_millis() as u64;
+            match completion.result {
+                Ok(stdout) => {
                     store.upsert_workspace_setup_state(&WorkspaceSetupState {
                         repo: workspace.repo.clone(),
                         workspace_id: workspace.workspace_id.clone(),
@@ -312,36 +697,140 @@ pub fn run_setup_engine(
                         fingerprint: fingerprint.clone(),
                         last_run_epoch_s: now_epoch_s,
                     })?;
-                    report.steps.push(StepOutcome {
+                    outcomes[index] = Some(StepOutcome {
                         id: step.id.clone(),
                         status: StepStatus::Ran,
                         fingerprint,
-                        duration_ms: started.elapsed().as_millis() as u64,
+                        started_at_ms,
+                        duration_ms,
+                        output: match step.output {
+                            OutputCapture::Discard => None,
+                            OutputCapture::Report => Some(output_tail(&stdout)),
+                        },
                     });
                 }
                 Err(error) => {
-                    let duration_ms = started.elapsed().as_millis() as u64;
-                    let allow_failure = step.allow_failure;
-                    report.steps.push(StepOutcome {
+                    // Tolerated failures leave dependents free to run and do
+                    // not persist workspace_setup state, so the step retries
+                    // on the next lease. A hard failure stops new steps from
+                    // starting: they may depend on this one having run.
+                    if !step.allow_failure {
+                        halted = true;
+                    }
+                    outcomes[index] = Some(StepOutcome {
                         id: step.id.clone(),
-                        status: StepStatus::failed(error, allow_failure),
+                        status: StepStatus::failed(error, step.allow_failure),
                         fingerprint,
+                        started_at_ms,
                         duration_ms,
+                        output: None,
                     });
-                    if allow_failure {
-                        // Tolerated: leave remaining steps to run, and do
-                        // not persist workspace_setup state so this step
-                        // retries on the next lease.
-                        continue;
-                    }
-                    // Stop on first hard failure: subsequent steps may
-                    // depend on this one having run.
-                    return Ok(report);
                 }
-            },
+            }
         }
+    })?;
+
+    let steps: Vec<StepOutcome> = outcomes.into_iter().flatten().collect();
+    let timing = setup_timing(&config.steps, &deps, &steps, setup_started.elapsed());
+    Ok(SetupReport { steps, timing })
+}
+
+#[derive(Debug, Clone)]
+enum StepProgress {
+    Pending,
+    Running { fingerprint: String, started_at_ms: u64 },
+    Done,
+}
+
+struct StepCompletion {
+    index: usize,
+    result: Result<String, CubeError>,
+    duration: Duration,
+}
+
+fn panic_message(panic: &(dyn std::any::Any + Send)) -> &str {
+    panic
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("no message")
+}
+
+/// Summarise a finished run: wall time, summed step time, and the dependency
+/// chain with the largest total duration among the steps that reported.
+fn setup_timing(steps: &[SetupStep], deps: &[Vec<usize>], outcomes: &[StepOutcome], wall: Duration) -> SetupTiming {
+    let durations: Vec<Option<u64>> = steps
+        .iter()
+        .map(|step| {
+            outcomes
+                .iter()
+                .find(|outcome| outcome.id == step.id)
+                .map(|outcome| outcome.duration_ms)
+        })
+        .collect();
+    let mut memo = vec![None; steps.len()];
+    let ends: Vec<ChainEnd> = (0..steps.len())
+        .map(|index| longest_chain_ending_at(index, deps, &durations, &mut memo))
+        .collect();
+
+    let mut critical_path = Vec::new();
+    let mut critical_path_ms = 0;
+    let tail = ends
+        .iter()
+        .enumerate()
+        .filter_map(|(index, end)| end.map(|(total, _)| (index, total)))
+        .max_by_key(|(_, total)| *total);
+    if let Some((mut cursor, total)) = tail {
+        critical_path_ms = total;
+        loop {
+            critical_path.push(steps[cursor].id.clone());
+            match ends[cursor].and_then(|(_, previous)| previous) {
+                Some(previous) => cursor = previous,
+                None => break,
+            }
+        }
+        critical_path.reverse();
+    }
+
+    SetupTiming {
+        wall_ms: wall.as_millis() as u64,
+        step_ms_total: outcomes.iter().map(|outcome| outcome.duration_ms).sum(),
+        critical_path,
+        critical_path_ms,
+    }
+}
+
+/// Total duration of the slowest reported chain ending at a step, and the
+/// dependency it came through. `None` when the step never reported.
+type ChainEnd = Option<(u64, Option<usize>)>;
+
+fn longest_chain_ending_at(
+    index: usize,
+    deps: &[Vec<usize>],
+    durations: &[Option<u64>],
+    memo: &mut [Option<ChainEnd>],
+) -> ChainEnd {
+    if let Some(known) = memo[index] {
+        return known;
     }
-    Ok(report)
+    let result = durations[index].map(|own| {
+        let upstream = deps[index]
+            .iter()
+            .filter_map(|&dep| longest_chain_ending_at(dep, deps, durations, memo).map(|(total, _)| (total, dep)))
+            .max_by_key(|(total, _)| *total);
+        match upstream {
+            Some((total, dep)) => (total + own, Some(dep)),
+            None => (own, None),
+        }
+    });
+    memo[index] = Some(result);
+    result
+}
+
+fn output_tail(stdout: &str) -> String {
+    let lines: Vec<&str> = stdout.lines().collect();
+    let start = lines.len().saturating_sub(REPORTED_OUTPUT_LINES);
+    lines[start..].join("\n")
 }
 
 #[derive(Debug)]
@@ -364,21 +853,32 @@ fn decide_action(policy: RunPolicy, stored: Option<&WorkspaceSetupState>, finger
     }
 }
 
-fn invoke_step(
-    runner: &dyn CommandRunner,
-    workspace_path: &Path,
-    step: &SetupStep,
-    extra_env: &[(String, String)],
-) -> Result<(), CubeError> {
+fn step_invocation(workspace_path: &Path, step: &SetupStep, base_env: &[(String, String)]) -> CommandInvocation {
+    let cwd = match &step.cwd {
+        Some(relative) => workspace_path.join(relative),
+        None => workspace_path.to_path_buf(),
+    };
+    let mut env = base_env.to_vec();
+    env.extend(step.env.iter().map(|(key, value)| (key.clone(), value.clone())));
     // Run setup commands through a shell so that env var references like
     // $CUBE_BASE_REPO are expanded by the shell before the program executes.
-    runner.run(&CommandInvocation {
-        cwd: workspace_path.to_path_buf(),
+    CommandInvocation {
+        cwd,
         program: "sh".to_string(),
         args: vec!["-c".to_string(), step.command.clone()],
-        env: extra_env.to_vec(),
-    })?;
-    Ok(())
+        env,
+    }
+}
+
+fn invoke_step(
+    runner: &dyn CommandRunner,
+    invocation: &CommandInvocation,
+    timeout: Option<Duration>,
+) -> Result<String, CubeError> {
+    match timeout {
+        Some(timeout) => runner.run_with_timeout(invocation, timeout),
+        None => runner.run(invocation),
+    }
 }
 
 #[cfg(test)]
@@ -474,6 +974,7 @@ steps:
                 file: "lock".to_string(),
             }],
             allow_failure: false,
+            ..Default::default()
         };
         let first = compute_fingerprint(temp.path(), &step).unwrap();
 
@@ -491,6 +992,7 @@ steps:
             run_when: RunPolicy::OnFingerprintChange,
             fingerprint: vec![],
             allow_failure: false,
+            ..Default::default()
         };
         let first = compute_fingerprint(temp.path(), &step).unwrap();
         step.command = "b".to_string();
@@ -509,6 +1011,7 @@ steps:
                 file: "lock".to_string(),
             }],
             allow_failure: false,
+            ..Default::default()
         };
         let missing = compute_fingerprint(temp.path(), &step).unwrap();
 
@@ -833,15 +1336,20 @@ steps:
                         stderr: Some("missing".to_string()),
                     },
                     fingerprint: "abc".to_string(),
+                    started_at_ms: 0,
                     duration_ms: 1,
+                    output: None,
                 },
                 StepOutcome {
                     id: "after".to_string(),
                     status: StepStatus::Ran,
                     fingerprint: "def".to_string(),
+                    started_at_ms: 0,
                     duration_ms: 1,
+                    output: None,
                 },
             ],
+            timing: Default::default(),
         };
         assert!(report.first_failure().is_none());
         assert_eq!(report.warning_count(), 1);
@@ -860,8 +1368,11 @@ steps:
                     stderr: Some("pnpm exploded".to_string()),
                 },
                 fingerprint: "abc".to_string(),
+                started_at_ms: 0,
                 duration_ms: 1,
+                output: None,
             }],
+            timing: Default::default(),
         };
         let failure = report.first_failure().expect("hard failure");
         assert_eq!(failure.id, "deps");
@@ -925,6 +1436,35 @@ steps:
         );
     }
 
+    struct PanickingRunner;
+
+    impl CommandRunner for PanickingRunner {
+        fn run(&self, _invocation: &CommandInvocation) -> Result<String, CubeError> {
+            panic!("runner blew up");
+        }
+
+        fn as_sync(&self) -> Option<&(dyn CommandRunner + Sync)> {
+            Some(self)
+        }
+    }
+
+    #[test]
+    fn a_panicking_parallel_step_fails_instead_of_hanging() {
+        let tmp = TempDir::new().unwrap();
+        let store = make_store_with_repo(&tmp, None);
+        let ws = workspace_record(&tmp);
+
+        let report = run_setup_engine(&store, &PanickingRunner, &ws, &two_step_config(false), 0).unwrap();
+
+        let failure = report.first_failure().expect("hard failure");
+        assert_eq!(failure.id, "copy-config");
+        let super::StepStatus::Failed { error, .. } = &failure.status else {
+            panic!("expected a failed step, got {:?}", failure.status);
+        };
+        assert!(error.contains("runner blew up"), "{error}");
+        assert_eq!(report.steps.len(), 1, "later steps must not run");
+    }
+
     #[test]
     fn tolerated_failure_retries_on_next_run() {
         let tmp = TempDir::new().unwrap();
@@ -993,4 +1533,370 @@ steps:
                 .is_none()
         );
     }
+
+    // ── dependency graph, parallelism and per-step settings ─────────────────
+
+    fn write_config(tmp: &TempDir, raw: &str) {
+        fs::create_dir_all(setup_config_path(tmp.path()).parent().unwrap()).unwrap();
+        fs::write(setup_config_path(tmp.path()), raw).unwrap();
+    }
+
+    #[test]
+    fn read_setup_config_parses_graph_and_step_settings() {
+        let temp = TempDir::new().unwrap();
+        write_config(
+            &temp,
+            r#"version: 1
+max_parallel: 2
+steps:
+  - id: secrets
+    command: ./decode.sh
+    needs: []
+  - id: deps
+    command: pnpm install
+    needs: []
+    timeout_secs: 600
+    cwd: web
+    env:
+      CI: "1"
+    output: report
+    fingerprint:
+      - glob: "web/**/package.json"
+  - id: build
+    command: pnpm build
+    needs: [secrets, deps]
+"#,
+        );
+
+        let config = read_setup_config(temp.path()).unwrap().unwrap();
+        assert_eq!(config.max_parallel, 2);
+        assert_eq!(config.steps[0].needs, Some(vec![]));
+        assert_eq!(config.steps[1].timeout_secs, Some(600));
+        assert_eq!(config.steps[1].cwd.as_deref(), Some("web"));
+        assert_eq!(config.steps[1].env.get("CI").map(String::as_str), Some("1"));
+        assert_eq!(config.steps[1].output, super::OutputCapture::Report);
+        assert_eq!(
+            config.steps[1].fingerprint,
+            vec![FingerprintInput::Glob {
+                glob: "web/**/package.json".to_string(),
+            }]
+        );
+        assert_eq!(
+            config.steps[2].needs,
+            Some(vec!["secrets".to_string(), "deps".to_string()])
+        );
+    }
+
+    #[test]
+    fn max_parallel_defaults_when_omitted() {
+        let parsed: SetupConfig = serde_yaml::from_str("version: 1\nsteps: []\n").unwrap();
+        assert_eq!(parsed.max_parallel, super::DEFAULT_MAX_PARALLEL);
+    }
+
+    #[test]
+    fn steps_without_needs_depend_on_the_previous_step() {
+        let parsed: SetupConfig = serde_yaml::from_str(
+            r#"version: 1
+steps:
+  - id: a
+    command: a
+  - id: b
+    command: b
+  - id: c
+    command: c
+    needs: []
+"#,
+        )
+        .unwrap();
+        let deps = super::resolve_dependencies(&parsed.steps).unwrap();
+        assert_eq!(deps, vec![vec![], vec![0], vec![]]);
+    }
+
+    #[test]
+    fn read_setup_config_rejects_unknown_need() {
+        let temp = TempDir::new().unwrap();
+        write_config(
+            &temp,
+            r#"version: 1
+steps:
+  - id: build
+    command: make
+    needs: [deps]
+"#,
+        );
+        let err = read_setup_config(temp.path()).unwrap_err();
+        assert!(err.to_string().contains("needs unknown step `deps`"), "got: {err}");
+    }
+
+    #[test]
+    fn read_setup_config_rejects_dependency_cycle() {
+        let temp = TempDir::new().unwrap();
+        write_config(
+            &temp,
+            r#"version: 1
+steps:
+  - id: a
+    command: a
+    needs: [b]
+  - id: b
+    command: b
+    needs: [a]
+"#,
+        );
+        let err = read_setup_config(temp.path()).unwrap_err();
+        assert!(err.to_string().contains("dependency cycle: a, b"), "got: {err}");
+    }
+
+    #[test]
+    fn read_setup_config_rejects_cwd_outside_workspace() {
+        for cwd in ["../elsewhere", "/tmp", "web/../../x"] {
+            let temp = TempDir::new().unwrap();
+            write_config(
+                &temp,
+                &format!("version: 1\nsteps:\n  - id: a\n    command: a\n    cwd: \"{cwd}\"\n"),
+            );
+            let err = read_setup_config(temp.path()).unwrap_err();
+            assert!(
+                err.to_string().contains("must be a relative path inside the workspace"),
+                "cwd `{cwd}` should be rejected, got: {err}"
+            );
+        }
+    }
+
+    #[test]
+    fn glob_fingerprint_tracks_matching_files_only() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join("web/app")).unwrap();
+        fs::write(temp.path().join("web/app/package.json"), b"{}").unwrap();
+        fs::write(temp.path().join("web/app/README.md"), b"a").unwrap();
+        let step = SetupStep {
+            id: "deps".to_string(),
+            command: "pnpm install".to_string(),
+            fingerprint: vec![FingerprintInput::Glob {
+                glob: "web/**/package.json".to_string(),
+            }],
+            ..Default::default()
+        };
+        let first = compute_fingerprint(temp.path(), &step).unwrap();
+
+        fs::write(temp.path().join("web/app/README.md"), b"b").unwrap();
+        assert_eq!(
+            first,
+            compute_fingerprint(temp.path(), &step).unwrap(),
+            "non-matching files must not affect the fingerprint"
+        );
+
+        fs::create_dir_all(temp.path().join("web/lib")).unwrap();
+        fs::write(temp.path().join("web/lib/package.json"), b"{}").unwrap();
+        let added = compute_fingerprint(temp.path(), &step).unwrap();
+        assert_ne!(first, added, "a new match must change the fingerprint");
+
+        fs::write(temp.path().join("web/lib/package.json"), b"{\"x\":1}").unwrap();
+        assert_ne!(added, compute_fingerprint(temp.path(), &step).unwrap());
+    }
+
+    #[test]
+    fn env_and_cwd_leave_legacy_fingerprints_unchanged_when_unset() {
+        let temp = TempDir::new().unwrap();
+        let mut step = SetupStep {
+            id: "deps".to_string(),
+            command: "echo".to_string(),
+            ..Default::default()
+        };
+        let plain = compute_fingerprint(temp.path(), &step).unwrap();
+        // sha256("echo\0"): the pre-`env`/`cwd` fingerprint of this step.
+        assert_eq!(
+            plain,
+            "093c01db7338fe8f78938c226bfcb66a90c571a5a00ec970bc92a00564ae3d9a"
+        );
+        step.env.insert("CI".to_string(), "1".to_string());
+        assert_ne!(plain, compute_fingerprint(temp.path(), &step).unwrap());
+    }
+
+    #[test]
+    fn setup_engine_applies_step_env_and_cwd() {
+        let tmp = TempDir::new().unwrap();
+        let store = make_store_with_repo(&tmp, None);
+        let ws = workspace_record(&tmp);
+        let runner = CapturingRunner::new();
+        let config: SetupConfig = serde_yaml::from_str(
+            r#"version: 1
+steps:
+  - id: deps
+    command: pnpm install
+    run_when: always
+    cwd: web
+    env:
+      CI: "1"
+"#,
+        )
+        .unwrap();
+
+        run_setup_engine(&store, &runner, &ws, &config, 0).unwrap();
+
+        let invocations = runner.into_invocations();
+        assert_eq!(invocations[0].cwd, ws.workspace_path.join("web"));
+        let env: std::collections::HashMap<_, _> = invocations[0].env.iter().cloned().collect();
+        assert_eq!(env.get("CI").map(String::as_str), Some("1"));
+        assert!(env.contains_key("CUBE_WORKSPACE"));
+    }
+
+    #[test]
+    fn hard_failure_still_runs_nothing_that_needs_it() {
+        let tmp = TempDir::new().unwrap();
+        let store = make_store_with_repo(&tmp, None);
+        let ws = workspace_record(&tmp);
+        let config: SetupConfig = serde_yaml::from_str(
+            r#"version: 1
+steps:
+  - id: deps
+    command: pnpm install
+    run_when: always
+  - id: build
+    command: pnpm build
+    run_when: always
+    needs: [deps]
+"#,
+        )
+        .unwrap();
+        let runner = ScriptedRunner::new(vec![Err(command_failed("boom"))]);
+
+        let report = run_setup_engine(&store, &runner, &ws, &config, 0).unwrap();
+
+        assert_eq!(report.first_failure().map(|step| step.id.as_str()), Some("deps"));
+        assert_eq!(report.steps.len(), 1);
+    }
+
+    #[test]
+    fn independent_steps_overlap_with_a_shareable_runner() {
+        let tmp = TempDir::new().unwrap();
+        let store = make_store_with_repo(&tmp, None);
+        let ws = workspace_record(&tmp);
+        let config: SetupConfig = serde_yaml::from_str(
+            r#"version: 1
+max_parallel: 2
+steps:
+  - id: left
+    command: sleep 1
+    run_when: always
+    needs: []
+  - id: right
+    command: sleep 1
+    run_when: always
+    needs: []
+  - id: join
+    command: 'true'
+    run_when: always
+    needs: [left, right]
+"#,
+        )
+        .unwrap();
+
+        let report = run_setup_engine(&store, &RealCommandRunner, &ws, &config, 0).unwrap();
+
+        assert_eq!(report.ran_count(), 3);
+        let ids: Vec<_> = report.steps.iter().map(|step| step.id.as_str()).collect();
+        assert_eq!(ids, vec!["left", "right", "join"], "report keeps declaration order");
+        assert!(
+            report.timing.wall_ms < 1_800,
+            "two 1s steps should overlap, wall time was {}ms",
+            report.timing.wall_ms
+        );
+        assert!(report.timing.step_ms_total >= 2_000);
+        let join = &report.steps[2];
+        assert!(join.started_at_ms >= 1_000, "join must wait for both dependencies");
+        assert_eq!(report.timing.critical_path.last().map(String::as_str), Some("join"));
+        assert_eq!(report.timing.critical_path.len(), 2);
+    }
+
+    #[test]
+    fn step_timeout_fails_the_step() {
+        let tmp = TempDir::new().unwrap();
+        let store = make_store_with_repo(&tmp, None);
+        let ws = workspace_record(&tmp);
+        let config: SetupConfig = serde_yaml::from_str(
+            r#"version: 1
+steps:
+  - id: wedged
+    command: sleep 30
+    run_when: always
+    timeout_secs: 1
+"#,
+        )
+        .unwrap();
+
+        let started = std::time::Instant::now();
+        let report = run_setup_engine(&store, &RealCommandRunner, &ws, &config, 0).unwrap();
+
+        assert!(started.elapsed() < std::time::Duration::from_secs(10));
+        let failure = report.first_failure().expect("timed-out step is a hard failure");
+        let super::StepStatus::Failed { error, .. } = &failure.status else {
+            unreachable!();
+        };
+        assert!(error.contains("did not complete within 1s"), "got: {error}");
+    }
+
+    #[test]
+    fn report_output_keeps_stdout_tail() {
+        let tmp = TempDir::new().unwrap();
+        let store = make_store_with_repo(&tmp, None);
+        let ws = workspace_record(&tmp);
+        let config: SetupConfig = serde_yaml::from_str(
+            r#"version: 1
+steps:
+  - id: loud
+    command: 'seq 1 100'
+    run_when: always
+    output: report
+  - id: quiet
+    command: 'echo hidden'
+    run_when: always
+"#,
+        )
+        .unwrap();
+
+        let report = run_setup_engine(&store, &RealCommandRunner, &ws, &config, 0).unwrap();
+
+        let output = report.steps[0].output.as_deref().expect("reported output");
+        assert_eq!(output.lines().count(), super::REPORTED_OUTPUT_LINES);
+        assert_eq!(output.lines().last(), Some("100"));
+        assert!(report.steps[1].output.is_none(), "output is discarded by default");
+    }
+
+    #[test]
+    fn critical_path_follows_the_slowest_chain() {
+        use super::{StepOutcome, StepStatus, setup_timing};
+        let parsed: SetupConfig = serde_yaml::from_str(
+            r#"version: 1
+steps:
+  - id: fast
+    command: a
+    needs: []
+  - id: slow
+    command: b
+    needs: []
+  - id: build
+    command: c
+    needs: [fast, slow]
+"#,
+        )
+        .unwrap();
+        let deps = super::resolve_dependencies(&parsed.steps).unwrap();
+        let outcome = |id: &str, duration_ms| StepOutcome {
+            id: id.to_string(),
+            status: StepStatus::Ran,
+            fingerprint: String::new(),
+            started_at_ms: 0,
+            duration_ms,
+            output: None,
+        };
+        let outcomes = vec![outcome("fast", 10), outcome("slow", 500), outcome("build", 100)];
+
+        let timing = setup_timing(&parsed.steps, &deps, &outcomes, std::time::Duration::from_millis(620));
+
+        assert_eq!(timing.wall_ms, 620);
+        assert_eq!(timing.step_ms_total, 610);
+        assert_eq!(timing.critical_path, vec!["slow".to_string(), "build".to_string()]);
+        assert_eq!(timing.critical_path_ms, 600);
+    }
 }